@@ -0,0 +1,151 @@
+//! Measures how long a large mixer takes to build, draw, and render.
+//!
+//! The mixer has 200 knobs and 100 sliders with tick and text marks, and
+//! their values change on every frame like they do under automation.
+//!
+//! ```text
+//! cargo bench --bench mixer --features test-utils
+//! ```
+
+use std::time::{Duration, Instant};
+
+use iced::{
+    widget::{column, Row},
+    Element, Length, Size, Theme,
+};
+use iced_audio::{
+    test_utils::Harness, text_marks, tick_marks, HSlider, Knob, Normal, NormalParam, VSlider,
+};
+
+const KNOBS: usize = 200;
+const H_SLIDERS: usize = 50;
+const V_SLIDERS: usize = 50;
+const PER_ROW: usize = 25;
+
+const FRAMES: u32 = 50;
+const RENDERED_FRAMES: u32 = 5;
+
+const SIZE: Size = Size::new(2000.0, 1400.0);
+
+/// The index of the parameter that changed, and its new value.
+type Message = (usize, Normal);
+
+struct Marks {
+    tick_marks: tick_marks::Group,
+    text_marks: text_marks::Group,
+}
+
+/// The value of parameter `index` in `frame`, which moves on every frame.
+fn param(index: usize, frame: u32) -> NormalParam {
+    let value = ((index as f32 * 0.37) + (frame as f32 * 0.013)).fract();
+
+    NormalParam {
+        value: Normal::from_clipped(value),
+        default: Normal::CENTER,
+    }
+}
+
+fn view(marks: &Marks, frame: u32) -> Element<'_, Message> {
+    let knobs = (0..KNOBS).map(|i| {
+        Knob::new(param(i, frame), move |normal| (i, normal))
+            .size(Length::Fixed(40.0))
+            .tick_marks(&marks.tick_marks)
+            .text_marks(&marks.text_marks)
+            .into()
+    });
+
+    let h_sliders = (0..H_SLIDERS).map(|i| {
+        let index = KNOBS + i;
+
+        HSlider::new(param(index, frame), move |normal| (index, normal))
+            .width(Length::Fixed(70.0))
+            .tick_marks(&marks.tick_marks)
+            .text_marks(&marks.text_marks)
+            .into()
+    });
+
+    let v_sliders = (0..V_SLIDERS).map(|i| {
+        let index = KNOBS + H_SLIDERS + i;
+
+        VSlider::new(param(index, frame), move |normal| (index, normal))
+            .height(Length::Fixed(120.0))
+            .tick_marks(&marks.tick_marks)
+            .text_marks(&marks.text_marks)
+            .into()
+    });
+
+    let mut widgets: Vec<Element<'_, Message>> = Vec::new();
+    widgets.extend(rows(knobs.collect()));
+    widgets.extend(rows(h_sliders.collect()));
+    widgets.extend(rows(v_sliders.collect()));
+
+    column(widgets).spacing(30).padding(20).into()
+}
+
+/// Lays out `widgets` in rows of `PER_ROW`.
+fn rows(widgets: Vec<Element<'_, Message>>) -> Vec<Element<'_, Message>> {
+    let mut rows = Vec::new();
+    let mut widgets = widgets.into_iter().peekable();
+
+    while widgets.peek().is_some() {
+        rows.push(
+            Row::with_children(widgets.by_ref().take(PER_ROW))
+                .spacing(30)
+                .into(),
+        );
+    }
+
+    rows
+}
+
+fn report(name: &str, total: Duration, frames: u32) {
+    let per_frame = total / frames;
+
+    println!(
+        "{name:<10} {:>9.3} ms per frame",
+        per_frame.as_secs_f64() * 1000.0
+    );
+}
+
+fn main() {
+    let marks = Marks {
+        tick_marks: tick_marks::Group::subdivided(1, 3, 4, Some(tick_marks::Tier::Two)),
+        text_marks: text_marks::Group::min_max_and_center("0", "10", "5"),
+    };
+    let theme = Theme::Dark;
+
+    let mut harness = Harness::new(view(&marks, 0), SIZE);
+
+    // Warm up the caches and the glyphs of the text.
+    harness.draw(&theme);
+
+    let mut view_time = Duration::ZERO;
+    let mut draw_time = Duration::ZERO;
+
+    for frame in 1..=FRAMES {
+        let start = Instant::now();
+        let element = view(&marks, frame);
+        view_time += start.elapsed();
+
+        harness.rebuild(element);
+
+        let start = Instant::now();
+        harness.draw(&theme);
+        draw_time += start.elapsed();
+    }
+
+    let mut render_time = Duration::ZERO;
+
+    for frame in 1..=RENDERED_FRAMES {
+        harness.rebuild(view(&marks, FRAMES + frame));
+
+        let start = Instant::now();
+        let _ = harness.snapshot(&theme);
+        render_time += start.elapsed();
+    }
+
+    println!("{KNOBS} knobs, {} sliders:", H_SLIDERS + V_SLIDERS);
+    report("view", view_time, FRAMES);
+    report("draw", draw_time, FRAMES);
+    report("render", render_time, RENDERED_FRAMES);
+}
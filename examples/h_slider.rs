@@ -7,8 +7,8 @@ use iced::{
     Element, Length, Rectangle, Result, Size,
 };
 use iced_audio::{
-    text_marks, tick_marks, widget::h_slider::HSlider, FloatRange, FreqRange, IntRange, LogDBRange,
-    Normal, NormalParam,
+    text_marks, tick_marks, FloatRange, FreqRange, HSlider, IntRange, LogDBRange, Normal,
+    NormalParam,
 };
 
 fn main() -> Result {
@@ -198,7 +198,7 @@ impl HSliderExample {
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         // create each of the HSlider widgets, passing in the value of
         // the corresponding parameter
 
@@ -220,18 +220,18 @@ impl HSliderExample {
 
         let h_slider_rect = HSlider::new(self.rect_param, Message::RectStyle)
             .height(Length::Fixed(24.0))
-            .style(style::h_slider::RectStyle);
+            .class(style::h_slider::RectStyle);
 
         let h_slider_rect_bp = HSlider::new(self.rect_bp_param, Message::BipolarRectStyle)
             .height(Length::Fixed(24.0))
-            .style(style::h_slider::RectBipolarStyle);
+            .class(style::h_slider::RectBipolarStyle);
 
         let h_slider_texture = HSlider::new(self.texture_param, Message::TextureStyle)
             .tick_marks(&self.float_tick_marks)
             .text_marks(&self.float_text_marks)
             // the height of the texture
             .height(Length::Fixed(20.0))
-            .style(style::h_slider::TextureStyle(
+            .class(style::h_slider::TextureStyle(
                 // clone the handle to the loaded texture
                 self.h_slider_texture_handle.clone(),
                 // bounds of the texture, where the origin is in the center
@@ -1,3 +1,8 @@
+//! Helpers for formatting the output text of the examples.
+#![allow(dead_code)]
+
+use iced_audio::{format_db, format_hz, format_percent};
+
 pub fn info_text_f32<ID: std::fmt::Debug>(id: ID, value: f32) -> String {
     format!("id: {id:?}  |  value: {value:.3}")
 }
@@ -7,13 +12,13 @@ pub fn info_text_i32<ID: std::fmt::Debug>(id: ID, value: i32) -> String {
 }
 
 pub fn info_text_db<ID: std::fmt::Debug>(id: ID, value: f32) -> String {
-    format!("id: {id:?}  |  value: {value:.3} dB")
+    format!("id: {id:?}  |  value: {}", format_db(value))
 }
 
 pub fn info_text_freq<ID: std::fmt::Debug>(id: ID, value: f32) -> String {
-    if value < 1000.0 {
-        format!("id: {id:?}  |  value: {value:.2} Hz")
-    } else {
-        format!("id: {id:?}  |  value: {:.2} kHz", value / 1000.0)
-    }
+    format!("id: {id:?}  |  value: {}", format_hz(value))
+}
+
+pub fn info_text_percent<ID: std::fmt::Debug>(id: ID, value: f32) -> String {
+    format!("id: {id:?}  |  value: {}", format_percent(value))
 }
@@ -4,29 +4,32 @@ mod style;
 use iced::{
     application,
     widget::{column, row, text},
-    Element, Length, Result, Size,
+    Element, Length, Result, Size, Theme,
 };
 use iced_audio::{
-    text_marks, tick_marks, FloatRange, FreqRange, IntRange, Knob, LogDBRange, Normal, NormalParam,
+    knob, text_marks, tick_marks, FloatRange, FreqRange, IntRange, Knob, LogDBRange, Normal,
+    NormalParam, RangedParam,
 };
 
 fn main() -> Result {
     application("Knob Example", KnobExample::update, KnobExample::view)
-        .window_size(Size::new(600.0, 400.0))
+        .window_size(Size::new(600.0, 480.0))
         .run()
 }
 
 #[derive(Debug, Clone)]
 enum Message {
-    Float(Normal),
+    Float(f32),
     Int(Normal),
     DB(Normal),
     Freq(Normal),
     Style1(Normal),
     Style2(Normal),
     Style3(Normal),
+    Style3Mod(Normal),
     Style4(Normal),
     Style5(Normal),
+    Endless(f32),
 }
 
 pub struct KnobExample {
@@ -35,15 +38,17 @@ pub struct KnobExample {
     db_range: LogDBRange,
     freq_range: FreqRange,
 
-    knob_float_param: NormalParam,
+    knob_float_param: RangedParam<FloatRange>,
     knob_int_param: NormalParam,
     knob_db_param: NormalParam,
     knob_freq_param: NormalParam,
     knob_style1_param: NormalParam,
     knob_style2_param: NormalParam,
     knob_style3_param: NormalParam,
+    knob_style3_mod: Normal,
     knob_style4_param: NormalParam,
     knob_style5_param: NormalParam,
+    endless_position: f32,
 
     float_tick_marks: tick_marks::Group,
     int_tick_marks: tick_marks::Group,
@@ -76,15 +81,17 @@ impl Default for KnobExample {
             freq_range,
 
             // initialize the state of the Knob widget
-            knob_float_param: float_range.default_normal_param(),
+            knob_float_param: RangedParam::new(float_range, 0.0, 0.0),
             knob_int_param: int_range.default_normal_param(),
             knob_db_param: db_range.default_normal_param(),
             knob_freq_param: freq_range.normal_param(1000.0, 1000.0),
             knob_style1_param: float_range.default_normal_param(),
             knob_style2_param: float_range.default_normal_param(),
             knob_style3_param: float_range.default_normal_param(),
+            knob_style3_mod: Normal::from_clipped(0.75),
             knob_style4_param: float_range.default_normal_param(),
             knob_style5_param: float_range.normal_param(-0.6, -0.6),
+            endless_position: 0.0,
 
             float_tick_marks: tick_marks::Group::subdivided(1, 1, 1, Some(tick_marks::Tier::Two)),
 
@@ -135,11 +142,11 @@ impl Default for KnobExample {
 impl KnobExample {
     fn update(&mut self, message: Message) {
         match message {
-            Message::Float(normal) => {
-                self.knob_float_param.update(normal);
+            Message::Float(value) => {
+                // A `Param` emits the mapped value directly.
+                self.knob_float_param.update(value);
 
-                self.output_text =
-                    info_text::info_text_f32("KnobFloat", self.float_range.unmap_to_value(normal));
+                self.output_text = info_text::info_text_f32("KnobFloat", value);
             }
             Message::Int(normal) => {
                 // Integer parameters must be snapped to make the widget "step" when moved.
@@ -178,6 +185,14 @@ impl KnobExample {
                 self.output_text =
                     info_text::info_text_f32("KnobStyle3", self.float_range.unmap_to_value(normal));
             }
+            Message::Style3Mod(normal) => {
+                self.knob_style3_mod = normal;
+
+                self.output_text = info_text::info_text_f32(
+                    "KnobStyle3Mod",
+                    self.float_range.unmap_to_value(normal),
+                );
+            }
             Message::Style4(normal) => {
                 self.knob_style4_param.update(normal);
 
@@ -190,14 +205,19 @@ impl KnobExample {
                 self.output_text =
                     info_text::info_text_f32("KnobStyle5", self.float_range.unmap_to_value(normal));
             }
+            Message::Endless(delta) => {
+                self.endless_position += delta;
+
+                self.output_text = info_text::info_text_f32("KnobEndless", delta);
+            }
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         // create each of the Knob widgets, passing in the value of
         // the corresponding parameter
 
-        let knob_float = Knob::new(self.knob_float_param, Message::Float)
+        let knob_float = Knob::from_param(&self.knob_float_param, Message::Float)
             .tick_marks(&self.float_tick_marks)
             .text_marks(&self.float_text_marks);
 
@@ -209,26 +229,45 @@ impl KnobExample {
             .tick_marks(&self.db_tick_marks)
             .text_marks(&self.db_text_marks);
 
+        // Style functions receive the theme and the status of the knob
         let knob_freq = Knob::new(self.knob_freq_param, Message::Freq)
             .tick_marks(&self.freq_tick_marks)
-            .text_marks(&self.freq_text_marks);
+            .text_marks(&self.freq_text_marks)
+            .style(|theme: &Theme, status| {
+                let mut appearance = iced_audio::style::knob::default(theme, status);
+
+                if let (knob::Appearance::Circle(circle), knob::Status::Dragged) =
+                    (&mut appearance, status)
+                {
+                    circle.border_color = theme.palette().primary;
+                }
+
+                appearance
+            });
 
         let knob_style1 = Knob::new(self.knob_style1_param, Message::Style1)
-            .style(style::knob::CustomStyleCircle)
+            .class(style::knob::CustomStyleCircle)
             .text_marks(&self.float_text_marks);
 
         let knob_style2 =
-            Knob::new(self.knob_style2_param, Message::Style2).style(style::knob::CustomStyleLine);
+            Knob::new(self.knob_style2_param, Message::Style2).class(style::knob::CustomStyleLine);
 
-        let knob_style3 =
-            Knob::new(self.knob_style3_param, Message::Style3).style(style::knob::CustomArc);
+        // Alt-drag this knob to edit its modulation amount
+        let knob_style3 = Knob::new(self.knob_style3_param, Message::Style3)
+            .on_mod_change(self.knob_style3_mod, Message::Style3Mod)
+            .class(style::knob::CustomArc);
 
         let knob_style4 =
-            Knob::new(self.knob_style4_param, Message::Style4).style(style::knob::CustomArcBipolar);
+            Knob::new(self.knob_style4_param, Message::Style4).class(style::knob::CustomArcBipolar);
 
         let knob_style5 = Knob::new(self.knob_style5_param, Message::Style5)
             .bipolar_center(Normal::from_clipped(0.2))
-            .style(style::knob::CustomArcBipolar);
+            .class(style::knob::CustomArcBipolar);
+
+        // An endless knob reports how far it was turned instead of a value
+        let knob_endless = Knob::endless(self.endless_position, Message::Endless)
+            .drag_mode(knob::DragMode::Circular)
+            .style(iced_audio::style::knob::arc);
 
         // push the widgets into rows
         let knob_row = row![
@@ -239,6 +278,8 @@ impl KnobExample {
                 knob_db,
                 text("Custom Style 1"),
                 knob_style1,
+                text("Endless (Circular Drag)"),
+                knob_endless,
             ]
             .width(Length::Fill)
             .spacing(20),
@@ -253,7 +294,7 @@ impl KnobExample {
             .width(Length::Fill)
             .spacing(20),
             column![
-                text("Custom Style 3"),
+                text("Custom Style 3 (Alt-Drag Mod)"),
                 knob_style3,
                 text("Custom Bipolar Style 4"),
                 knob_style4,
@@ -204,7 +204,7 @@ impl ModRangeInputExample {
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         // create each of the Knob widgets, passing in the value of
         // the corresponding parameter
 
@@ -214,32 +214,32 @@ impl ModRangeInputExample {
 
         let knob1 = Knob::new(self.knob1_param, Message::Knob1)
             .mod_range(&self.mod_range_1)
-            .style(style::knob::CustomArc);
+            .class(style::knob::CustomArc);
 
         let h_slider1 = HSlider::new(self.h_slider1_param, Message::HSlider1)
             .mod_range(&self.mod_range_1)
-            .style(style::h_slider::RectStyle);
+            .class(style::h_slider::RectStyle);
 
         let v_slider1 = VSlider::new(self.v_slider1_param, Message::VSlider1)
             .width(Length::Fixed(30.0))
             .mod_range(&self.mod_range_1)
-            .style(style::v_slider::RectStyle);
+            .class(style::v_slider::RectStyle);
 
         let auto_input1 = ModRangeInput::new(self.auto_input1_param, Message::ModRangeInput1)
             .size(Length::from(10))
-            .style(style::mod_range_input::CustomStyle);
+            .class(style::mod_range_input::CustomStyle);
 
         let knob_auto1 = Knob::new(self.knob_auto1_param, Message::ModKnob1)
             .mod_range(&self.knob_auto1_mod_range)
-            .style(style::knob::CustomStyleCircle);
+            .class(style::knob::CustomStyleCircle);
 
         let auto_input2 = ModRangeInput::new(self.auto_input2_param, Message::ModRangeInput2)
             .size(Length::from(15))
-            .style(mod_range_input::ModRangeInput::Invisible);
+            .class(mod_range_input::ModRangeInput::Invisible);
 
         let knob_auto2 = Knob::new(self.knob_auto2_param, Message::ModKnob2)
             .mod_range(&self.knob_auto2_mod_range)
-            .style(style::knob::CustomStyleCircle);
+            .class(style::knob::CustomStyleCircle);
 
         // push the widgets into rows
         let knob_row = row![
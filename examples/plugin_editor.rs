@@ -0,0 +1,167 @@
+//! The editor of an audio plugin, as it would be embedded into the plugin
+//! window with `iced_baseview`.
+//!
+//! The view is generic over `iced_audio::baseview::Renderer`, so the same
+//! code is returned from the `view` of the `iced_baseview` application of the
+//! plugin. Here it runs in a window of Iced to try it out without a host:
+//!
+//! ```text
+//! cargo run --example plugin_editor --features baseview
+//! ```
+
+mod info_text;
+
+use iced::{
+    application,
+    widget::{column, container, row, text},
+    Alignment, Element, Length, Result, Size, Theme,
+};
+use iced_audio::{
+    baseview::Renderer, FloatRange, FreqRange, HSlider, Knob, LogDBRange, Normal, NormalParam,
+    VSlider,
+};
+
+fn main() -> Result {
+    application(
+        "Plugin Editor Example",
+        Editor::update,
+        Editor::view::<iced::Renderer>,
+    )
+    .theme(|_| Theme::Dark)
+    .window_size(Size::new(420.0, 260.0))
+    .resizable(false)
+    .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Cutoff(Normal),
+    Resonance(Normal),
+    Mix(Normal),
+    Gain(Normal),
+}
+
+pub struct Editor {
+    cutoff_range: FreqRange,
+    resonance_range: FloatRange,
+    mix_range: FloatRange,
+    gain_range: LogDBRange,
+
+    cutoff_param: NormalParam,
+    resonance_param: NormalParam,
+    mix_param: NormalParam,
+    gain_param: NormalParam,
+
+    output_text: String,
+}
+
+impl Default for Editor {
+    fn default() -> Self {
+        // In a plugin, the ranges and values come from the parameters of the
+        // plugin instead.
+
+        let cutoff_range = FreqRange::default();
+        let resonance_range = FloatRange::default();
+        let mix_range = FloatRange::default();
+        let gain_range = LogDBRange::new(-12.0, 12.0, Normal::CENTER);
+
+        Self {
+            cutoff_range,
+            resonance_range,
+            mix_range,
+            gain_range,
+
+            cutoff_param: cutoff_range.normal_param(1000.0, 1000.0),
+            resonance_param: resonance_range.normal_param(0.0, 0.0),
+            mix_param: mix_range.normal_param(1.0, 1.0),
+            gain_param: gain_range.default_normal_param(),
+
+            output_text: String::new(),
+        }
+    }
+}
+
+impl Editor {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Cutoff(normal) => {
+                self.cutoff_param.update(normal);
+
+                self.output_text =
+                    info_text::info_text_freq("Cutoff", self.cutoff_range.unmap_to_value(normal));
+            }
+            Message::Resonance(normal) => {
+                self.resonance_param.update(normal);
+
+                self.output_text = info_text::info_text_f32(
+                    "Resonance",
+                    self.resonance_range.unmap_to_value(normal),
+                );
+            }
+            Message::Mix(normal) => {
+                self.mix_param.update(normal);
+
+                self.output_text =
+                    info_text::info_text_percent("Mix", self.mix_range.unmap_to_value(normal));
+            }
+            Message::Gain(normal) => {
+                self.gain_param.update(normal);
+
+                self.output_text =
+                    info_text::info_text_db("Gain", self.gain_range.unmap_to_value(normal));
+            }
+        }
+    }
+
+    // The same view works for the renderer of Iced and the one of
+    // `iced_baseview`, so the application of the plugin only has to call
+    // `self.view()`.
+    fn view<R: Renderer>(&self) -> Element<'_, Message, Theme, R> {
+        let labeled = |label, widget: Element<'static, Message, Theme, R>| {
+            column![widget, text(label)]
+                .spacing(8)
+                .align_x(Alignment::Center)
+        };
+
+        let filter = row![
+            labeled(
+                "Cutoff",
+                Knob::new(self.cutoff_param, Message::Cutoff).into()
+            ),
+            labeled(
+                "Resonance",
+                Knob::new(self.resonance_param, Message::Resonance).into()
+            ),
+        ]
+        .spacing(24);
+
+        let output = row![
+            labeled(
+                "Mix",
+                HSlider::new(self.mix_param, Message::Mix)
+                    .width(Length::Fixed(120.0))
+                    .into()
+            ),
+            labeled(
+                "Gain",
+                VSlider::new(self.gain_param, Message::Gain)
+                    .height(Length::Fixed(100.0))
+                    .into()
+            ),
+        ]
+        .spacing(24)
+        .align_y(Alignment::Center);
+
+        let content = column![
+            row![filter, output].spacing(40),
+            text(&self.output_text).size(14),
+        ]
+        .spacing(24);
+
+        container(content)
+            .padding(20)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
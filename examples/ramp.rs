@@ -6,7 +6,7 @@ use iced::{
     widget::{column, row, text},
     Element, Length, Result, Size,
 };
-use iced_audio::{widget::ramp::RampDirection, FloatRange, Normal, NormalParam, Ramp};
+use iced_audio::{ramp::RampDirection, FloatRange, Normal, NormalParam, Ramp};
 
 fn main() -> Result {
     application("Ramp Example", RampExample::update, RampExample::view)
@@ -87,7 +87,7 @@ impl RampExample {
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         // create each of the Ramp widgets, passing in the value of
         // the corresponding parameter
 
@@ -108,14 +108,14 @@ impl RampExample {
             Message::CustomUp,
             RampDirection::Up,
         )
-        .style(style::ramp::CustomStyle);
+        .class(style::ramp::CustomStyle);
 
         let ramp_custom_down = Ramp::new(
             self.ramp_custom_down_param,
             Message::CustomDown,
             RampDirection::Down,
         )
-        .style(style::ramp::CustomStyle);
+        .class(style::ramp::CustomStyle);
 
         // push the widgets into rows
         let ramp_row = row![
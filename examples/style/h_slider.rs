@@ -1,5 +1,6 @@
 use iced::widget::image;
 use iced::{Color, Rectangle};
+use iced_audio::style::Status;
 use iced_audio::{h_slider, text_marks, tick_marks, Offset};
 
 use super::colors;
@@ -23,20 +24,21 @@ impl RectStyle {
 impl h_slider::StyleSheet for RectStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> h_slider::Appearance {
-        h_slider::Appearance::Rect(Self::ACTIVE_RECT_STYLE)
-    }
-
-    fn hovered(&self, _style: &Self::Style) -> h_slider::Appearance {
-        h_slider::Appearance::Rect(h_slider::RectAppearance {
-            filled_color: colors::FILLED_HOVER,
-            handle_width: 5,
-            ..Self::ACTIVE_RECT_STYLE
-        })
-    }
-
-    fn dragging(&self, style: &Self::Style) -> h_slider::Appearance {
-        self.hovered(style)
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        status: Status,
+    ) -> h_slider::Appearance {
+        match status {
+            Status::Hovered | Status::Dragged => {
+                h_slider::Appearance::Rect(h_slider::RectAppearance {
+                    filled_color: colors::FILLED_HOVER,
+                    handle_width: 5,
+                    ..Self::ACTIVE_RECT_STYLE
+                })
+            }
+            _ => h_slider::Appearance::Rect(Self::ACTIVE_RECT_STYLE),
+        }
     }
 
     fn mod_range_appearance(
@@ -80,21 +82,24 @@ impl RectBipolarStyle {
 impl h_slider::StyleSheet for RectBipolarStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> h_slider::Appearance {
-        h_slider::Appearance::RectBipolar(Self::ACTIVE_RECT_STYLE)
-    }
-
-    fn hovered(&self, _style: &Self::Style) -> h_slider::Appearance {
-        h_slider::Appearance::RectBipolar(h_slider::RectBipolarAppearance {
-            left_filled_color: colors::FILLED_HOVER,
-            right_filled_color: Color::from_rgb(0.0, 0.64, 0.0),
-            handle_width: 5,
-            ..Self::ACTIVE_RECT_STYLE
-        })
-    }
-
-    fn dragging(&self, style: &Self::Style) -> h_slider::Appearance {
-        self.hovered(style)
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        status: Status,
+    ) -> h_slider::Appearance {
+        match status {
+            Status::Hovered | Status::Dragged => {
+                h_slider::Appearance::RectBipolar(
+                    h_slider::RectBipolarAppearance {
+                        left_filled_color: colors::FILLED_HOVER,
+                        right_filled_color: Color::from_rgb(0.0, 0.64, 0.0),
+                        handle_width: 5,
+                        ..Self::ACTIVE_RECT_STYLE
+                    },
+                )
+            }
+            _ => h_slider::Appearance::RectBipolar(Self::ACTIVE_RECT_STYLE),
+        }
     }
 }
 
@@ -104,7 +109,11 @@ pub struct TextureStyle(pub image::Handle, pub Rectangle);
 impl h_slider::StyleSheet for TextureStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> h_slider::Appearance {
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        _status: Status,
+    ) -> h_slider::Appearance {
         h_slider::Appearance::Texture(h_slider::TextureAppearance {
             rail: h_slider::ClassicRail {
                 rail_colors: (
@@ -113,21 +122,14 @@ impl h_slider::StyleSheet for TextureStyle {
                 ),
                 rail_widths: (1.0, 2.0),
                 rail_padding: 14.0,
+                rounded_ends: false,
             },
             handle_width: 38,
-            image_handle: self.0.clone(),
+            image_handle: self.0.clone().into(),
             image_bounds: self.1,
         })
     }
 
-    fn hovered(&self, style: &Self::Style) -> h_slider::Appearance {
-        self.active(style)
-    }
-
-    fn dragging(&self, style: &Self::Style) -> h_slider::Appearance {
-        self.active(style)
-    }
-
     fn tick_marks_appearance(
         &self,
         _style: &Self::Style,
@@ -149,6 +151,7 @@ impl h_slider::StyleSheet for TextureStyle {
                     width: 1.0,
                     color: [0.56, 0.56, 0.56, 0.75].into(),
                 },
+                density: Default::default(),
             },
             placement: tick_marks::Placement::CenterSplit {
                 offset: Offset::ZERO,
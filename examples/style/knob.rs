@@ -1,4 +1,5 @@
 use iced::Color;
+use iced_audio::style::Status;
 use iced_audio::{knob, text_marks};
 
 use super::colors;
@@ -25,23 +26,24 @@ impl CustomStyleCircle {
 impl knob::StyleSheet for CustomStyleCircle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> knob::Appearance {
-        knob::Appearance::Circle(Self::ACTIVE_CIRCLE_STYLE)
-    }
-
-    fn hovered(&self, _style: &Self::Style) -> knob::Appearance {
-        knob::Appearance::Circle(knob::CircleAppearance {
-            notch: knob::NotchShape::Circle(knob::CircleNotch {
-                color: colors::HANDLE_HOVER,
-                border_color: colors::FILLED_HOVER,
-                ..Self::ACTIVE_CIRCLE_NOTCH
-            }),
-            ..Self::ACTIVE_CIRCLE_STYLE
-        })
-    }
-
-    fn dragging(&self, style: &Self::Style) -> knob::Appearance {
-        self.hovered(style)
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        status: Status,
+    ) -> knob::Appearance {
+        match status {
+            Status::Hovered | Status::Dragged => {
+                knob::Appearance::Circle(knob::CircleAppearance {
+                    notch: knob::NotchShape::Circle(knob::CircleNotch {
+                        color: colors::HANDLE_HOVER,
+                        border_color: colors::FILLED_HOVER,
+                        ..Self::ACTIVE_CIRCLE_NOTCH
+                    }),
+                    ..Self::ACTIVE_CIRCLE_STYLE
+                })
+            }
+            _ => knob::Appearance::Circle(Self::ACTIVE_CIRCLE_STYLE),
+        }
     }
 
     fn value_arc_appearance(
@@ -55,6 +57,7 @@ impl knob::StyleSheet for CustomStyleCircle {
             left_filled_color: colors::KNOB_ARC,
             right_filled_color: None,
             cap: knob::LineCap::Butt,
+            ends: knob::ArcEnds::default(),
         })
     }
 
@@ -69,6 +72,7 @@ impl knob::StyleSheet for CustomStyleCircle {
             filled_color: colors::KNOB_ARC_RIGHT,
             filled_inverse_color: colors::KNOB_ARC_RIGHT,
             cap: knob::LineCap::Butt,
+            placement: knob::ModRangePlacement::Sweep,
         })
     }
 
@@ -113,18 +117,14 @@ impl CustomStyleLine {
 impl knob::StyleSheet for CustomStyleLine {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> knob::Appearance {
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        _status: Status,
+    ) -> knob::Appearance {
         knob::Appearance::Circle(Self::ACTIVE_CIRCLE_STYLE)
     }
 
-    fn hovered(&self, style: &Self::Style) -> knob::Appearance {
-        self.active(style)
-    }
-
-    fn dragging(&self, style: &Self::Style) -> knob::Appearance {
-        self.active(style)
-    }
-
     fn value_arc_appearance(
         &self,
         _style: &Self::Style,
@@ -136,6 +136,7 @@ impl knob::StyleSheet for CustomStyleLine {
             left_filled_color: colors::KNOB_ARC,
             right_filled_color: Some(colors::KNOB_ARC_RIGHT),
             cap: knob::LineCap::Round,
+            ends: knob::ArcEnds::default(),
         })
     }
 }
@@ -146,7 +147,11 @@ pub struct CustomArc;
 impl knob::StyleSheet for CustomArc {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> knob::Appearance {
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        _status: Status,
+    ) -> knob::Appearance {
         knob::Appearance::Arc(knob::ArcAppearance {
             width: knob::StyleLength::Fixed(3.15),
             empty_color: colors::KNOB_ARC_EMPTY,
@@ -159,17 +164,16 @@ impl knob::StyleSheet for CustomArc {
                 offset: knob::StyleLength::Fixed(2.5),
             }),
             cap: knob::LineCap::Round,
+            ends: knob::ArcEnds {
+                gap: 0.25,
+                dots: Some(knob::EndDots {
+                    diameter: 3.15,
+                    color: colors::KNOB_ARC_EMPTY,
+                }),
+            },
         })
     }
 
-    fn hovered(&self, style: &Self::Style) -> knob::Appearance {
-        self.active(style)
-    }
-
-    fn dragging(&self, style: &Self::Style) -> knob::Appearance {
-        self.active(style)
-    }
-
     fn angle_range(&self, _style: &Self::Style) -> iced_audio::KnobAngleRange {
         iced_audio::KnobAngleRange::from_deg(40.0, 320.0)
     }
@@ -185,6 +189,7 @@ impl knob::StyleSheet for CustomArc {
             filled_color: colors::KNOB_ARC,
             filled_inverse_color: colors::KNOB_ARC_RIGHT,
             cap: knob::LineCap::Round,
+            placement: knob::ModRangePlacement::Sweep,
         })
     }
 }
@@ -204,7 +209,11 @@ impl CustomArcBipolar {
 impl knob::StyleSheet for CustomArcBipolar {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> knob::Appearance {
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        _status: Status,
+    ) -> knob::Appearance {
         knob::Appearance::ArcBipolar(knob::ArcBipolarAppearance {
             width: knob::StyleLength::Fixed(3.15),
             empty_color: colors::KNOB_ARC_EMPTY,
@@ -222,17 +231,10 @@ impl knob::StyleSheet for CustomArcBipolar {
                 }),
             )),
             cap: knob::LineCap::Butt,
+            ends: knob::ArcEnds::default(),
         })
     }
 
-    fn hovered(&self, style: &Self::Style) -> knob::Appearance {
-        self.active(style)
-    }
-
-    fn dragging(&self, style: &Self::Style) -> knob::Appearance {
-        self.active(style)
-    }
-
     fn angle_range(&self, _style: &Self::Style) -> iced_audio::KnobAngleRange {
         iced_audio::KnobAngleRange::from_deg(40.0, 320.0)
     }
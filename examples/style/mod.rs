@@ -1,3 +1,6 @@
+//! Custom styles shared by the examples. Each example only uses a subset.
+#![allow(dead_code)]
+
 mod colors;
 
 pub mod h_slider;
@@ -1,4 +1,5 @@
 use iced::Color;
+use iced_audio::style::Status;
 use iced_audio::mod_range_input;
 
 use super::colors;
@@ -17,18 +18,21 @@ impl CustomStyle {
 impl mod_range_input::StyleSheet for CustomStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> mod_range_input::Appearance {
-        mod_range_input::Appearance::Circle(Self::ACTIVE_STYLE)
-    }
-
-    fn hovered(&self, _style: &Self::Style) -> mod_range_input::Appearance {
-        mod_range_input::Appearance::Circle(mod_range_input::CircleAppearance {
-            border_width: 1.0,
-            ..Self::ACTIVE_STYLE
-        })
-    }
-
-    fn dragging(&self, style: &Self::Style) -> mod_range_input::Appearance {
-        self.hovered(style)
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        status: Status,
+    ) -> mod_range_input::Appearance {
+        match status {
+            Status::Hovered | Status::Dragged => {
+                mod_range_input::Appearance::Circle(
+                    mod_range_input::CircleAppearance {
+                        border_width: 1.0,
+                        ..Self::ACTIVE_STYLE
+                    },
+                )
+            }
+            _ => mod_range_input::Appearance::Circle(Self::ACTIVE_STYLE),
+        }
     }
 }
@@ -1,4 +1,5 @@
 use iced::Color;
+use iced_audio::style::Status;
 use iced_audio::ramp;
 
 use super::colors;
@@ -15,29 +16,29 @@ impl CustomStyle {
         line_center_color: Color::from_rgb(0.7, 0.7, 0.7),
         line_up_color: Color::from_rgb(0.0, 0.9, 0.0),
         line_down_color: colors::HANDLE,
+        value_quantization: ramp::DEFAULT_VALUE_QUANTIZATION,
     };
 }
 impl ramp::StyleSheet for CustomStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> ramp::Appearance {
-        Self::ACTIVE_STYLE
-    }
-
-    fn hovered(&self, _style: &Self::Style) -> ramp::Appearance {
-        ramp::Appearance {
-            line_center_color: Color::from_rgb(0.8, 0.8, 0.8),
-            line_up_color: Color::from_rgb(0.0, 1.0, 0.0),
-            line_down_color: Color::from_rgb(
-                0x8A as f32 / 255.0,
-                0xD7 as f32 / 255.0,
-                0xFF as f32 / 255.0,
-            ),
-            ..Self::ACTIVE_STYLE
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        status: Status,
+    ) -> ramp::Appearance {
+        match status {
+            Status::Hovered | Status::Dragged => ramp::Appearance {
+                line_center_color: Color::from_rgb(0.8, 0.8, 0.8),
+                line_up_color: Color::from_rgb(0.0, 1.0, 0.0),
+                line_down_color: Color::from_rgb(
+                    0x8A as f32 / 255.0,
+                    0xD7 as f32 / 255.0,
+                    0xFF as f32 / 255.0,
+                ),
+                ..Self::ACTIVE_STYLE
+            },
+            _ => Self::ACTIVE_STYLE,
         }
     }
-
-    fn dragging(&self, style: &Self::Style) -> ramp::Appearance {
-        self.hovered(style)
-    }
 }
@@ -1,5 +1,6 @@
 use iced::widget::image;
 use iced::{Color, Rectangle};
+use iced_audio::style::Status;
 use iced_audio::{text_marks, tick_marks, v_slider, Offset};
 
 use super::colors;
@@ -23,20 +24,21 @@ impl RectStyle {
 impl v_slider::StyleSheet for RectStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> v_slider::Appearance {
-        v_slider::Appearance::Rect(Self::ACTIVE_RECT_STYLE)
-    }
-
-    fn hovered(&self, _style: &Self::Style) -> v_slider::Appearance {
-        v_slider::Appearance::Rect(v_slider::RectAppearance {
-            filled_color: colors::FILLED_HOVER,
-            handle_height: 5,
-            ..Self::ACTIVE_RECT_STYLE
-        })
-    }
-
-    fn dragging(&self, style: &Self::Style) -> v_slider::Appearance {
-        self.hovered(style)
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        status: Status,
+    ) -> v_slider::Appearance {
+        match status {
+            Status::Hovered | Status::Dragged => {
+                v_slider::Appearance::Rect(v_slider::RectAppearance {
+                    filled_color: colors::FILLED_HOVER,
+                    handle_height: 5,
+                    ..Self::ACTIVE_RECT_STYLE
+                })
+            }
+            _ => v_slider::Appearance::Rect(Self::ACTIVE_RECT_STYLE),
+        }
     }
 
     fn mod_range_appearance(
@@ -89,21 +91,24 @@ impl RectBipolarStyle {
 impl v_slider::StyleSheet for RectBipolarStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> v_slider::Appearance {
-        v_slider::Appearance::RectBipolar(Self::ACTIVE_RECT_STYLE)
-    }
-
-    fn hovered(&self, _style: &Self::Style) -> v_slider::Appearance {
-        v_slider::Appearance::RectBipolar(v_slider::RectBipolarAppearance {
-            top_filled_color: colors::FILLED_HOVER,
-            bottom_filled_color: Color::from_rgb(0.0, 0.64, 0.0),
-            handle_height: 5,
-            ..Self::ACTIVE_RECT_STYLE
-        })
-    }
-
-    fn dragging(&self, style: &Self::Style) -> v_slider::Appearance {
-        self.hovered(style)
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        status: Status,
+    ) -> v_slider::Appearance {
+        match status {
+            Status::Hovered | Status::Dragged => {
+                v_slider::Appearance::RectBipolar(
+                    v_slider::RectBipolarAppearance {
+                        top_filled_color: colors::FILLED_HOVER,
+                        bottom_filled_color: Color::from_rgb(0.0, 0.64, 0.0),
+                        handle_height: 5,
+                        ..Self::ACTIVE_RECT_STYLE
+                    },
+                )
+            }
+            _ => v_slider::Appearance::RectBipolar(Self::ACTIVE_RECT_STYLE),
+        }
     }
 }
 
@@ -113,7 +118,11 @@ pub struct TextureStyle(pub image::Handle, pub Rectangle);
 impl v_slider::StyleSheet for TextureStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> v_slider::Appearance {
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        _status: Status,
+    ) -> v_slider::Appearance {
         v_slider::Appearance::Texture(v_slider::TextureAppearance {
             rail: v_slider::ClassicRail {
                 rail_colors: (
@@ -122,21 +131,14 @@ impl v_slider::StyleSheet for TextureStyle {
                 ),
                 rail_widths: (1.0, 2.0),
                 rail_padding: 14.0,
+                rounded_ends: false,
             },
             handle_height: 38,
-            image_handle: self.0.clone(),
+            image_handle: self.0.clone().into(),
             image_bounds: self.1,
         })
     }
 
-    fn hovered(&self, style: &Self::Style) -> v_slider::Appearance {
-        self.active(style)
-    }
-
-    fn dragging(&self, style: &Self::Style) -> v_slider::Appearance {
-        self.active(style)
-    }
-
     fn tick_marks_appearance(
         &self,
         _style: &Self::Style,
@@ -158,6 +160,7 @@ impl v_slider::StyleSheet for TextureStyle {
                     width: 1.0,
                     color: [0.56, 0.56, 0.56, 0.75].into(),
                 },
+                density: Default::default(),
             },
             placement: tick_marks::Placement::CenterSplit {
                 offset: Offset::ZERO,
@@ -1,4 +1,5 @@
 use iced::Color;
+use iced_audio::style::Status;
 use iced_audio::xy_pad;
 
 use super::colors;
@@ -34,28 +35,28 @@ impl CustomStyle {
 impl xy_pad::StyleSheet for CustomStyle {
     type Style = iced::Theme;
 
-    fn active(&self, _style: &Self::Style) -> xy_pad::Appearance {
-        Self::ACTIVE_STYLE
-    }
-
-    fn hovered(&self, _style: &Self::Style) -> xy_pad::Appearance {
-        xy_pad::Appearance {
-            handle: xy_pad::HandleShape::Square(xy_pad::HandleSquare {
-                color: colors::FILLED_HOVER,
-                size: 12,
-                ..Self::ACTIVE_HANDLE
-            }),
-            ..Self::ACTIVE_STYLE
-        }
-    }
-
-    fn dragging(&self, _style: &Self::Style) -> xy_pad::Appearance {
-        xy_pad::Appearance {
-            handle: xy_pad::HandleShape::Square(xy_pad::HandleSquare {
-                color: colors::FILLED_HOVER,
-                ..Self::ACTIVE_HANDLE
-            }),
-            ..Self::ACTIVE_STYLE
+    fn appearance(
+        &self,
+        _style: &Self::Style,
+        status: Status,
+    ) -> xy_pad::Appearance {
+        match status {
+            Status::Hovered => xy_pad::Appearance {
+                handle: xy_pad::HandleShape::Square(xy_pad::HandleSquare {
+                    color: colors::FILLED_HOVER,
+                    size: 12,
+                    ..Self::ACTIVE_HANDLE
+                }),
+                ..Self::ACTIVE_STYLE
+            },
+            Status::Dragged => xy_pad::Appearance {
+                handle: xy_pad::HandleShape::Square(xy_pad::HandleSquare {
+                    color: colors::FILLED_HOVER,
+                    ..Self::ACTIVE_HANDLE
+                }),
+                ..Self::ACTIVE_STYLE
+            },
+            _ => Self::ACTIVE_STYLE,
         }
     }
 }
@@ -0,0 +1,167 @@
+use iced::{
+    application,
+    widget::{column, container, pick_list, row, scrollable, text, Column, Row},
+    Alignment, Element, Length, Result, Size, Theme,
+};
+use iced_audio::{
+    style::registry, HSlider, Knob, ModRangeInput, Normal, NormalParam, VSlider, XYPad,
+};
+
+fn main() -> Result {
+    application("Styles Example", StylesExample::update, StylesExample::view)
+        .theme(|example| example.theme.clone())
+        .window_size(Size::new(760.0, 720.0))
+        .run()
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Theme(Theme),
+    Knob(Normal),
+    HSlider(Normal),
+    VSlider(Normal),
+    ModRangeInput(Normal),
+    XYPad(Normal, Normal),
+}
+
+/// Shows every built-in style of each widget side by side. All the widgets
+/// of a kind share their value, so moving one moves the others as well.
+pub struct StylesExample {
+    theme: Theme,
+
+    knob_param: NormalParam,
+    h_slider_param: NormalParam,
+    v_slider_param: NormalParam,
+    mod_range_input_param: NormalParam,
+    xy_pad_x_param: NormalParam,
+    xy_pad_y_param: NormalParam,
+}
+
+impl Default for StylesExample {
+    fn default() -> Self {
+        let param = NormalParam {
+            value: Normal::from_clipped(0.3),
+            default: Normal::CENTER,
+        };
+
+        Self {
+            theme: Theme::Light,
+
+            knob_param: param,
+            h_slider_param: param,
+            v_slider_param: param,
+            mod_range_input_param: param,
+            xy_pad_x_param: param,
+            xy_pad_y_param: NormalParam::default(),
+        }
+    }
+}
+
+impl StylesExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Theme(theme) => self.theme = theme,
+            Message::Knob(normal) => self.knob_param.update(normal),
+            Message::HSlider(normal) => self.h_slider_param.update(normal),
+            Message::VSlider(normal) => self.v_slider_param.update(normal),
+            Message::ModRangeInput(normal) => self.mod_range_input_param.update(normal),
+            Message::XYPad(normal_x, normal_y) => {
+                self.xy_pad_x_param.update(normal_x);
+                self.xy_pad_y_param.update(normal_y);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let knobs = registry::all_knob_styles().iter().map(|(name, style)| {
+            labeled(
+                name,
+                Knob::new(self.knob_param, Message::Knob)
+                    .bipolar_center(Normal::CENTER)
+                    .style(*style),
+            )
+        });
+
+        let h_sliders = registry::all_h_slider_styles().iter().map(|(name, style)| {
+            labeled(
+                name,
+                HSlider::new(self.h_slider_param, Message::HSlider)
+                    .width(Length::Fixed(180.0))
+                    .bipolar_center(Normal::CENTER)
+                    .style(*style),
+            )
+        });
+
+        let v_sliders = registry::all_v_slider_styles().iter().map(|(name, style)| {
+            labeled(
+                name,
+                VSlider::new(self.v_slider_param, Message::VSlider)
+                    .height(Length::Fixed(160.0))
+                    .bipolar_center(Normal::CENTER)
+                    .style(*style),
+            )
+        });
+
+        let mod_range_inputs =
+            registry::all_mod_range_input_styles()
+                .iter()
+                .map(|(name, style)| {
+                    labeled(
+                        name,
+                        ModRangeInput::new(self.mod_range_input_param, Message::ModRangeInput)
+                            .style(*style),
+                    )
+                });
+
+        let xy_pads = registry::all_xy_pad_styles().iter().map(|(name, style)| {
+            labeled(
+                name,
+                XYPad::new(self.xy_pad_x_param, self.xy_pad_y_param, Message::XYPad)
+                    .size(Length::Fixed(140.0))
+                    .style(*style),
+            )
+        });
+
+        let content = column![
+            row![
+                text("Theme"),
+                pick_list(Theme::ALL, Some(&self.theme), Message::Theme),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            section("Knob", knobs),
+            section("HSlider", h_sliders),
+            section("VSlider", v_sliders),
+            section("ModRangeInput", mod_range_inputs),
+            section("XYPad", xy_pads),
+        ]
+        .spacing(24)
+        .padding(20);
+
+        scrollable(container(content).width(Length::Fill)).into()
+    }
+}
+
+/// A widget with the name of its style below it.
+fn labeled<'a>(name: &'a str, widget: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+    column![widget.into(), text(name).size(12)]
+        .spacing(8)
+        .align_x(Alignment::Center)
+        .into()
+}
+
+/// A row of the styles of one kind of widget, under its name.
+fn section<'a>(
+    title: &'a str,
+    widgets: impl Iterator<Item = Element<'a, Message>>,
+) -> Element<'a, Message> {
+    Column::new()
+        .push(text(title).size(18))
+        .push(
+            Row::with_children(widgets)
+                .spacing(40)
+                .align_y(Alignment::End),
+        )
+        .spacing(12)
+        .into()
+}
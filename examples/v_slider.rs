@@ -198,7 +198,7 @@ impl VSliderExample {
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         // create each of the VSlider widgets, passing in the value of
         // the corresponding parameter
 
@@ -220,18 +220,18 @@ impl VSliderExample {
 
         let v_slider_rect = VSlider::new(self.rect_param, Message::RectStyle)
             .width(Length::Fixed(24.0))
-            .style(style::v_slider::RectStyle);
+            .class(style::v_slider::RectStyle);
 
         let v_slider_rect_bp = VSlider::new(self.rect_bp_param, Message::RectBipolarStyle)
             .width(Length::Fixed(24.0))
-            .style(style::v_slider::RectBipolarStyle);
+            .class(style::v_slider::RectBipolarStyle);
 
         let v_slider_texture = VSlider::new(self.texture_param, Message::TextureStyle)
             .tick_marks(&self.float_tick_marks)
             .text_marks(&self.float_text_marks)
             // the width of the texture
             .width(Length::Fixed(20.0))
-            .style(style::v_slider::TextureStyle(
+            .class(style::v_slider::TextureStyle(
                 // clone the handle to the loaded texture
                 self.v_slider_texture_handle.clone(),
                 // bounds of the texture, where the origin is in the center
@@ -0,0 +1,173 @@
+//! A small synth panel that also runs in the browser.
+//!
+//! Build it for the web with:
+//!
+//! ```sh
+//! cargo build --example web --target wasm32-unknown-unknown --release
+//! wasm-bindgen --target web --out-dir examples/web/pkg \
+//!     target/wasm32-unknown-unknown/release/examples/web.wasm
+//! ```
+//!
+//! and serve the `examples/web` directory with any static file server.
+
+mod info_text;
+
+use iced::{
+    application,
+    widget::{column, row, text},
+    Alignment, Element, Length, Result, Size, Theme,
+};
+use iced_audio::{
+    tick_marks, FloatRange, FreqRange, HSlider, Knob, LogDBRange, Normal, NormalParam, VSlider,
+};
+
+fn main() -> Result {
+    application("Web Synth Example", WebExample::update, WebExample::view)
+        .theme(|_| Theme::Dark)
+        .window_size(Size::new(600.0, 400.0))
+        .run()
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Cutoff(Normal),
+    Resonance(Normal),
+    EnvAmount(Normal),
+    Mix(Normal),
+    Volume(Normal),
+}
+
+pub struct WebExample {
+    freq_range: FreqRange,
+    unipolar_range: FloatRange,
+    bipolar_range: FloatRange,
+    db_range: LogDBRange,
+
+    cutoff_param: NormalParam,
+    resonance_param: NormalParam,
+    env_amount_param: NormalParam,
+    mix_param: NormalParam,
+    volume_param: NormalParam,
+
+    center_tick_mark: tick_marks::Group,
+
+    output_text: String,
+}
+
+impl Default for WebExample {
+    fn default() -> Self {
+        // initalize parameters
+
+        let freq_range = FreqRange::default();
+        let unipolar_range = FloatRange::default();
+        let bipolar_range = FloatRange::default_bipolar();
+        let db_range = LogDBRange::default();
+
+        // create application
+
+        Self {
+            freq_range,
+            unipolar_range,
+            bipolar_range,
+            db_range,
+
+            cutoff_param: freq_range.normal_param(1000.0, 1000.0),
+            resonance_param: unipolar_range.normal_param(0.2, 0.2),
+            env_amount_param: bipolar_range.default_normal_param(),
+            mix_param: unipolar_range.normal_param(0.5, 0.5),
+            volume_param: db_range.default_normal_param(),
+
+            center_tick_mark: tick_marks::Group::center(tick_marks::Tier::Two),
+
+            output_text: "Drag or touch a control!".into(),
+        }
+    }
+}
+
+impl WebExample {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Cutoff(normal) => {
+                self.cutoff_param.update(normal);
+
+                self.output_text =
+                    info_text::info_text_freq("Cutoff", self.freq_range.unmap_to_value(normal));
+            }
+            Message::Resonance(normal) => {
+                self.resonance_param.update(normal);
+
+                self.output_text = info_text::info_text_f32(
+                    "Resonance",
+                    self.unipolar_range.unmap_to_value(normal),
+                );
+            }
+            Message::EnvAmount(normal) => {
+                self.env_amount_param.update(normal);
+
+                self.output_text = info_text::info_text_f32(
+                    "EnvAmount",
+                    self.bipolar_range.unmap_to_value(normal),
+                );
+            }
+            Message::Mix(normal) => {
+                self.mix_param.update(normal);
+
+                self.output_text =
+                    info_text::info_text_percent("Mix", self.unipolar_range.unmap_to_value(normal));
+            }
+            Message::Volume(normal) => {
+                self.volume_param.update(normal);
+
+                self.output_text =
+                    info_text::info_text_db("Volume", self.db_range.unmap_to_value(normal));
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        // larger controls are easier to hit with a finger
+        let knob_size = Length::Fixed(56.0);
+
+        let cutoff = Knob::new(self.cutoff_param, Message::Cutoff).size(knob_size);
+
+        let resonance = Knob::new(self.resonance_param, Message::Resonance).size(knob_size);
+
+        let env_amount = Knob::new(self.env_amount_param, Message::EnvAmount)
+            .size(knob_size)
+            .tick_marks(&self.center_tick_mark);
+
+        let mix = HSlider::new(self.mix_param, Message::Mix)
+            .height(Length::Fixed(24.0))
+            .hit_padding([10, 0]);
+
+        let volume = VSlider::new(self.volume_param, Message::Volume)
+            .width(Length::Fixed(24.0))
+            .height(Length::Fixed(200.0))
+            .tick_marks(&self.center_tick_mark);
+
+        let filter = column![
+            row![
+                column![text("Cutoff"), cutoff].spacing(10),
+                column![text("Resonance"), resonance].spacing(10),
+                column![text("Env Amount"), env_amount].spacing(10),
+            ]
+            .spacing(30),
+            text("Mix"),
+            mix,
+        ]
+        .width(Length::Fill)
+        .spacing(20);
+
+        let output = column![text("Volume"), volume]
+            .spacing(10)
+            .align_x(Alignment::Center);
+
+        column![
+            row![filter, output].spacing(40),
+            text(&self.output_text).size(16)
+        ]
+        .spacing(20)
+        .padding(20)
+        .into()
+    }
+}
@@ -90,7 +90,7 @@ impl XYPadExample {
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         // create each of the XYPad widgets, passing in the value of
         // the corresponding parameter
 
@@ -105,7 +105,7 @@ impl XYPadExample {
             self.xy_pad_custom_y_param,
             Message::Custom,
         )
-        .style(style::xy_pad::CustomStyle);
+        .class(style::xy_pad::CustomStyle);
 
         // push the widgets into rows
         let xy_pad_row = row![
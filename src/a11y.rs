@@ -0,0 +1,155 @@
+//! Describe the widgets to screen readers with [`accesskit`].
+//!
+//! iced does not talk to screen readers by itself yet. With the `a11y`
+//! feature, every widget of this crate describes itself as an
+//! [`accesskit::Node`] with the `Slider` role, its value, range, step and
+//! the name and description set with its `name()` and `description()`
+//! builders. The value is the [`Normal`] of the widget; set the text that
+//! screen readers announce for it, e.g. `"-6 dB"`, with the
+//! `announced_value()` builder. The [`nodes`] operation collects them, e.g.
+//! to build the tree updates of an `accesskit` adapter:
+//!
+//! ```no_run
+//! use iced::{advanced::widget::operate, Task};
+//! use iced_audio::a11y;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     AccessNodes(Vec<a11y::Entry>),
+//! }
+//!
+//! fn collect() -> Task<Message> {
+//!     operate(a11y::nodes()).map(Message::AccessNodes)
+//! }
+//! ```
+//!
+//! The actions of a screen reader (`Increment`, `Decrement`, `SetValue`,
+//! `Focus`) map to the operations in the [`operation`] module, e.g.
+//! [`operation::nudge`] with the id of the widget.
+//!
+//! [`accesskit`]: https://docs.rs/accesskit
+//! [`accesskit::Node`]: https://docs.rs/accesskit/0.17/accesskit/struct.Node.html
+//! [`Normal`]: ../struct.Normal.html
+//! [`nodes`]: fn.nodes.html
+//! [`operation`]: ../operation/index.html
+//! [`operation::nudge`]: ../operation/fn.nudge.html
+
+pub use accesskit;
+
+use crate::core::Normal;
+use accesskit::{Action, Node, Orientation, Rect, Role};
+use iced::{
+    advanced::widget::{operation::Outcome, Id, Operation},
+    Rectangle,
+};
+use std::any::Any;
+
+/// The [`accesskit::Node`] of a widget, passed to `Operation::custom()` by
+/// every widget of this crate.
+///
+/// [`accesskit::Node`]: https://docs.rs/accesskit/0.17/accesskit/struct.Node.html
+#[derive(Debug, Clone)]
+pub struct AccessNode(pub Node);
+
+/// A widget found by the [`nodes`] operation.
+///
+/// [`nodes`]: fn.nodes.html
+#[derive(Debug, Clone)]
+pub struct Entry {
+    /// The id of the widget, if it has one.
+    pub id: Option<Id>,
+    /// The accessibility node of the widget.
+    pub node: Node,
+}
+
+/// Produces an [`Operation`] that collects the accessibility nodes of all
+/// the widgets of this crate, in the order of the widget tree.
+///
+/// [`Operation`]: https://docs.rs/iced/0.13/iced/advanced/widget/trait.Operation.html
+pub fn nodes() -> impl Operation<Vec<Entry>> {
+    struct Nodes {
+        entries: Vec<Entry>,
+    }
+
+    impl Operation<Vec<Entry>> for Nodes {
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if let Some(AccessNode(node)) = state.downcast_ref::<AccessNode>() {
+                self.entries.push(Entry {
+                    id: id.cloned(),
+                    node: node.clone(),
+                });
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<Entry>>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn finish(&self) -> Outcome<Vec<Entry>> {
+            Outcome::Some(self.entries.clone())
+        }
+    }
+
+    Nodes {
+        entries: Vec::new(),
+    }
+}
+
+/// Describes a widget that controls a single [`Normal`] as a slider, with
+/// the `value_text` screen readers announce for it, if any.
+///
+/// [`Normal`]: ../struct.Normal.html
+pub(crate) fn slider_node(
+    name: Option<&str>,
+    description: Option<&str>,
+    normal: Normal,
+    value_text: Option<&str>,
+    step: Option<f32>,
+    orientation: Option<Orientation>,
+    bounds: Rectangle,
+) -> AccessNode {
+    let mut node = Node::new(Role::Slider);
+
+    if let Some(name) = name {
+        node.set_label(name);
+    }
+    if let Some(description) = description {
+        node.set_description(description);
+    }
+    if let Some(orientation) = orientation {
+        node.set_orientation(orientation);
+    }
+
+    node.set_numeric_value(f64::from(normal.as_f32()));
+    if let Some(value_text) = value_text {
+        node.set_value(value_text);
+    }
+    node.set_min_numeric_value(0.0);
+    node.set_max_numeric_value(1.0);
+    if let Some(step) = step {
+        node.set_numeric_value_step(f64::from(step));
+    }
+
+    node.set_bounds(Rect {
+        x0: f64::from(bounds.x),
+        y0: f64::from(bounds.y),
+        x1: f64::from(bounds.x + bounds.width),
+        y1: f64::from(bounds.y + bounds.height),
+    });
+
+    for action in [
+        Action::Focus,
+        Action::Increment,
+        Action::Decrement,
+        Action::SetValue,
+    ] {
+        node.add_action(action);
+    }
+
+    AccessNode(node)
+}
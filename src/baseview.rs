@@ -0,0 +1,77 @@
+//! Compatibility with [`iced_baseview`], which runs Iced inside of the
+//! editor windows of VST, CLAP, and LV2 plugins.
+//!
+//! The widgets are generic over the renderer, and each one asks for just the
+//! parts of it that it draws with. [`iced_baseview`] brings its own renderer
+//! type, so a view that mixes several widgets would have to repeat all of
+//! their bounds. The [`Renderer`] trait bundles them instead, so a view is
+//! written once for any renderer that can draw every widget of this crate:
+//!
+//! ```
+//! use iced::{widget::row, Element, Theme};
+//! use iced_audio::{baseview::Renderer, HSlider, Knob, Normal, NormalParam};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Gain(Normal),
+//!     Mix(Normal),
+//! }
+//!
+//! fn editor<'a, R: Renderer>(gain: NormalParam, mix: NormalParam) -> Element<'a, Message, Theme, R> {
+//!     row![Knob::new(gain, Message::Gain), HSlider::new(mix, Message::Mix)].into()
+//! }
+//! # let _ = editor::<iced::Renderer>(NormalParam::default(), NormalParam::default());
+//! ```
+//!
+//! This module does not depend on [`iced_baseview`], whose versions follow
+//! the ones of Iced. The editor of the plugin returns the view from its
+//! application, e.g.:
+//!
+//! ```ignore
+//! impl iced_baseview::Application for Editor {
+//!     type Message = Message;
+//!     type Theme = Theme;
+//!     // ...
+//!
+//!     fn view(&self) -> Element<'_, Message, Theme, iced_baseview::Renderer> {
+//!         editor(self.gain, self.mix)
+//!     }
+//! }
+//! ```
+//!
+//! See the `plugin_editor` example, which runs the same view in a window of
+//! Iced to try it out without a plugin host.
+//!
+//! [`iced_baseview`]: https://github.com/BillyDM/iced_baseview
+//! [`Renderer`]: trait.Renderer.html
+
+use iced::{
+    advanced::{graphics::geometry, image, renderer, text},
+    Font,
+};
+
+/// A renderer that can draw every widget of this crate: quads, text in the
+/// default [`Font`], images, and canvas geometry.
+///
+/// It is implemented for every renderer with these capabilities, such as
+/// the renderer of Iced and the one of [`iced_baseview`].
+///
+/// [`Font`]: https://docs.rs/iced/0.13/iced/struct.Font.html
+/// [`iced_baseview`]: https://github.com/BillyDM/iced_baseview
+pub trait Renderer:
+    'static
+    + renderer::Renderer
+    + text::Renderer<Font = Font>
+    + image::Renderer<Handle = image::Handle>
+    + geometry::Renderer
+{
+}
+
+impl<R> Renderer for R where
+    R: 'static
+        + renderer::Renderer
+        + text::Renderer<Font = Font>
+        + image::Renderer<Handle = image::Handle>
+        + geometry::Renderer
+{
+}
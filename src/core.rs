@@ -3,22 +3,67 @@
 //! This module holds basic types that can be reused and re-exported in
 //! different runtime implementations.
 
+pub mod change;
+pub mod double_click;
+pub mod drag;
+pub mod format;
+pub mod hit_shape;
 pub mod knob_angle_range;
 pub mod math;
 pub mod modulation_range;
 pub mod normal;
 pub mod normal_param;
 pub mod offset;
+pub mod param;
+// Plots are built from canvas paths, which only the features that draw on
+// a canvas enable.
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "baseview",
+    feature = "compressor_curve",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "labeled",
+    feature = "loudness_meter",
+    feature = "marks",
+    feature = "meter_bridge",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "reduction_meter",
+    feature = "spectrogram",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+pub mod plot;
+pub mod preset;
 pub mod range;
+pub mod record;
+pub mod selection_group;
+pub mod sensitivity;
+pub mod slider_direction;
 pub mod slider_status;
+pub mod view_window;
 
 pub mod text_marks;
 pub mod tick_marks;
 
+pub use change::{Change, ChangeSource};
+pub use double_click::DoubleClick;
+pub use drag::{
+    CircularDrag, DetentDrag, Detents, Drag, DragClamp, DragThreshold, CIRCULAR_DRAG_MIN_RADIUS,
+};
+pub use format::{format_db, format_hz, format_ms, format_percent};
+pub use hit_shape::HitShape;
 pub use knob_angle_range::*;
 pub use modulation_range::ModulationRange;
-pub use normal::Normal;
+pub use normal::{BipolarSide, Normal};
 pub use normal_param::NormalParam;
 pub use offset::Offset;
+pub use param::{Param, Range, RangedParam};
+pub use preset::{ParamBank, ParamSnapshot};
 pub use range::*;
+pub use selection_group::{GroupMode, SelectionGroup};
+pub use sensitivity::{ModifierMatch, ModifierScalar, SliderSensitivity};
+pub use slider_direction::SliderDirection;
 pub use slider_status::SliderStatus;
+pub use view_window::ViewWindow;
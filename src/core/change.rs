@@ -0,0 +1,48 @@
+//! Changes of a widget's value, with what caused them
+
+use crate::core::Normal;
+
+/// What changed the value of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChangeSource {
+    /// The user dragged the widget with the mouse or a finger.
+    Drag,
+    /// The user scrolled the mouse wheel over the widget.
+    Wheel,
+    /// The user reset the widget to its default value, e.g. with a double
+    /// click.
+    Reset,
+    /// The user changed the value with the keyboard.
+    Keyboard,
+    /// The user clicked a text mark, which moves the value to the position
+    /// of the mark. See the widget's `clickable_text_marks()`.
+    MarkClick,
+    /// The application changed the value with an operation, see the
+    /// [`operation`] module.
+    ///
+    /// [`operation`]: ../operation/index.html
+    Operation,
+    /// The widget sprang back to its default value on its own after it was
+    /// released. See the widget's `spring_back()`.
+    SpringBack,
+}
+
+/// A change of the value of a widget, passed to the widget's
+/// `on_change_full()` callback.
+///
+/// `previous` is the value right before this change, not the value when the
+/// widget was grabbed. A drag produces a [`Change`] for every movement; use
+/// the widget's `on_grab()` and `on_release()` messages to group them, e.g.
+/// into a single undo step.
+///
+/// [`Change`]: struct.Change.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    /// The new value.
+    pub normal: Normal,
+    /// The value before the change.
+    pub previous: Normal,
+    /// What changed the value.
+    pub via: ChangeSource,
+}
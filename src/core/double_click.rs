@@ -0,0 +1,96 @@
+//! When two presses count as a double click
+
+use iced::time::Duration;
+
+/// When two presses of a widget count as a double click, which resets most
+/// widgets to their default value.
+///
+/// Quick taps on a touchscreen easily land close together, so tablets may
+/// reset a widget by accident. Turn off `touch` to only reset with the mouse,
+/// either app-wide with the [`defaults`] or per widget with its
+/// `double_click()` builder:
+///
+/// ```
+/// use iced_audio::{
+///     defaults::{self, Defaults},
+///     DoubleClick,
+/// };
+///
+/// defaults::set(Defaults {
+///     double_click: DoubleClick {
+///         touch: false,
+///         ..DoubleClick::DEFAULT
+///     },
+///     ..Defaults::default()
+/// });
+/// ```
+///
+/// [`defaults`]: ../defaults/index.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoubleClick {
+    /// The longest time from the first press to the second. `Duration::ZERO`
+    /// disables double clicks.
+    pub timeout: Duration,
+    /// How far the second press may be from the first, in logical pixels.
+    /// `0.0` requires the exact same position.
+    pub max_distance: f32,
+    /// Whether two taps of a finger count as a double click as well.
+    pub touch: bool,
+}
+
+impl DoubleClick {
+    /// Double clicks and taps within 300 ms on the same position, like
+    /// `iced::mouse::Click`.
+    pub const DEFAULT: Self = Self {
+        timeout: Duration::from_millis(300),
+        max_distance: 0.0,
+        touch: true,
+    };
+
+    /// No double clicks at all.
+    pub const DISABLED: Self = Self {
+        timeout: Duration::ZERO,
+        ..Self::DEFAULT
+    };
+
+    /// Returns whether a press `distance` pixels away from the previous
+    /// press, `interval` after it, is a double click. `is_touch` is whether
+    /// both presses were taps of a finger.
+    pub fn is_double_click(&self, distance: f32, interval: Duration, is_touch: bool) -> bool {
+        (self.touch || !is_touch)
+            && !self.timeout.is_zero()
+            && interval <= self.timeout
+            && distance <= self.max_distance
+    }
+}
+
+impl Default for DoubleClick {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DoubleClick, Duration};
+
+    #[test]
+    fn double_clicks_within_timeout_and_distance() {
+        let fast = Duration::from_millis(200);
+        let slow = Duration::from_millis(400);
+
+        assert!(DoubleClick::DEFAULT.is_double_click(0.0, fast, false));
+        assert!(DoubleClick::DEFAULT.is_double_click(0.0, fast, true));
+        assert!(!DoubleClick::DEFAULT.is_double_click(0.0, slow, false));
+        assert!(!DoubleClick::DEFAULT.is_double_click(1.0, fast, false));
+        assert!(!DoubleClick::DISABLED.is_double_click(0.0, Duration::ZERO, false));
+
+        let mouse_only = DoubleClick {
+            max_distance: 4.0,
+            touch: false,
+            ..DoubleClick::DEFAULT
+        };
+        assert!(mouse_only.is_double_click(3.0, fast, false));
+        assert!(!mouse_only.is_double_click(3.0, fast, true));
+    }
+}
@@ -0,0 +1,590 @@
+//! Accumulate the movement of a dragged pointer along one axis, shared by
+//! the virtual sliders.
+
+use crate::core::math::TWO_PI;
+use iced::{time::Instant, Point};
+
+/// The distance from the center within which a [`CircularDrag`] ignores the
+/// pointer, as its angle is too unsteady there.
+///
+/// [`CircularDrag`]: struct.CircularDrag.html
+pub const CIRCULAR_DRAG_MIN_RADIUS: f32 = 4.0;
+
+/// The positions a [`Drag`] follows the pointer to.
+///
+/// [`Drag`]: struct.Drag.html
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum DragClamp {
+    /// Follow the pointer anywhere. Used by the widgets that are dragged
+    /// independently of their size, such as the `Knob` and `Ramp`.
+    #[default]
+    None,
+    /// Only follow the pointer between `min` and `max`, such as the bounds
+    /// of an `HSlider`.
+    ///
+    /// Moving the pointer past the range does not move the value any
+    /// further, and moving it back starts moving the value again as soon as
+    /// it is inside the range.
+    Range {
+        /// The smallest position to follow the pointer to
+        min: f32,
+        /// The largest position to follow the pointer to
+        max: f32,
+    },
+}
+
+impl DragClamp {
+    /// Returns `position` clamped to this [`DragClamp`]. The ends of a
+    /// `Range` may be given in either order.
+    ///
+    /// [`DragClamp`]: enum.DragClamp.html
+    pub fn apply(&self, position: f32) -> f32 {
+        match *self {
+            DragClamp::None => position,
+            DragClamp::Range { min, max } => {
+                let (lo, hi) = if min <= max { (min, max) } else { (max, min) };
+                position.max(lo).min(hi)
+            }
+        }
+    }
+}
+
+/// How far a pointer has to move from where it pressed a widget before the
+/// press turns into a drag, so that the small movements of a click or a tap
+/// don't change the value.
+///
+/// The `on_grab` message of a widget waits until then, or until the release
+/// of a press that never moved far enough. Fingers wobble more than the
+/// mouse, so they have a threshold of their own. Set it app-wide with the
+/// [`defaults`] or per widget with its `drag_threshold()` builder:
+///
+/// ```
+/// use iced_audio::{
+///     defaults::{self, Defaults},
+///     DragThreshold,
+/// };
+///
+/// defaults::set(Defaults {
+///     drag_threshold: DragThreshold {
+///         touch: 10.0,
+///         ..DragThreshold::DEFAULT
+///     },
+///     ..Defaults::default()
+/// });
+/// ```
+///
+/// [`defaults`]: ../defaults/index.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragThreshold {
+    /// The distance for the mouse, in logical pixels.
+    pub mouse: f32,
+    /// The distance for a finger on a touchscreen, in logical pixels.
+    pub touch: f32,
+}
+
+impl DragThreshold {
+    /// A threshold of 2 pixels for the mouse and 6 pixels for a finger.
+    pub const DEFAULT: Self = Self {
+        mouse: 2.0,
+        touch: 6.0,
+    };
+
+    /// Every press drags right away.
+    pub const DISABLED: Self = Self {
+        mouse: 0.0,
+        touch: 0.0,
+    };
+
+    /// Returns the distance for a finger if `is_touch`, or for the mouse
+    /// otherwise.
+    pub fn distance(&self, is_touch: bool) -> f32 {
+        if is_touch {
+            self.touch
+        } else {
+            self.mouse
+        }
+    }
+
+    /// Returns whether a pointer that moved `distance` pixels away from
+    /// where it pressed drags. `is_touch` is whether it is a finger.
+    pub fn is_crossed(&self, distance: f32, is_touch: bool) -> bool {
+        distance > self.distance(is_touch)
+    }
+}
+
+impl Default for DragThreshold {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The last position of a dragged pointer along one axis.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Drag, DragClamp};
+///
+/// let clamp = DragClamp::Range { min: 0.0, max: 100.0 };
+/// let mut drag = Drag::default();
+///
+/// drag.start(50.0, clamp);
+/// assert_eq!(drag.moved_to(60.0, clamp), 10.0);
+///
+/// // Leaving the range only counts up to its end...
+/// assert_eq!(drag.moved_to(150.0, clamp), 40.0);
+/// assert_eq!(drag.moved_to(200.0, clamp), 0.0);
+///
+/// // ...and coming back counts from it.
+/// assert_eq!(drag.moved_to(90.0, clamp), -10.0);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Drag {
+    prev: f32,
+}
+
+impl Drag {
+    /// Starts a drag at `position`.
+    pub fn start(&mut self, position: f32, clamp: DragClamp) {
+        self.prev = clamp.apply(position);
+    }
+
+    /// Moves the drag to `position`, returning how far it moved since the
+    /// last call to [`start`] or [`moved_to`].
+    ///
+    /// [`start`]: #method.start
+    /// [`moved_to`]: #method.moved_to
+    pub fn moved_to(&mut self, position: f32, clamp: DragClamp) -> f32 {
+        let position = clamp.apply(position);
+        let delta = position - self.prev;
+
+        self.prev = position;
+
+        delta
+    }
+}
+
+/// Makes the tick marks of a slider stick while it's dragged slowly, like
+/// the notches of a hardware fader. It doesn't snap the value: a drag still
+/// reaches every value in between, and fast drags pass the detents as if
+/// they weren't there.
+///
+/// Set it with the `detents()` builder of an `HSlider` or a `VSlider`,
+/// which uses its tick marks as the detents:
+///
+/// ```
+/// use iced_audio::Detents;
+///
+/// let detents = Detents {
+///     stickiness: 0.05,
+///     ..Detents::DEFAULT
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detents {
+    /// How far a drag moves while the value is held at a detent before it
+    /// leaves it, as a fraction of the visible range.
+    pub stickiness: f32,
+    /// The speed of a drag, in visible ranges per second, at or below which
+    /// the detents stick.
+    pub max_speed: f32,
+}
+
+impl Detents {
+    /// A stickiness of `0.03` for drags of up to `1.5` ranges per second.
+    pub const DEFAULT: Self = Self {
+        stickiness: 0.03,
+        max_speed: 1.5,
+    };
+
+    /// Returns the detents for a visible range of `span`.
+    #[cfg(any(
+        feature = "arc_slider",
+        feature = "h_slider",
+        feature = "knob",
+        feature = "mod_range_input",
+        feature = "ramp",
+        feature = "v_slider"
+    ))]
+    pub(crate) fn scaled(&self, span: f32) -> Self {
+        Self {
+            stickiness: self.stickiness * span,
+            max_speed: self.max_speed * span,
+        }
+    }
+}
+
+impl Default for Detents {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The detent a dragged value is held at, if any, and the speed of the
+/// drag.
+///
+/// # Example
+///
+/// ```
+/// use iced::time::{Duration, Instant};
+/// use iced_audio::{DetentDrag, Detents};
+///
+/// let detents = Detents {
+///     stickiness: 0.1,
+///     max_speed: 1.0,
+/// };
+/// let positions = [0.5_f32];
+/// let start = Instant::now();
+/// let mut drag = DetentDrag::default();
+///
+/// drag.start(start);
+///
+/// // A slow drag past the detent stops at it...
+/// let second = start + Duration::from_secs(1);
+/// assert_eq!(drag.moved(&detents, positions.into_iter(), 0.4, 0.2, second), 0.5);
+///
+/// // ...until it moves past the stickiness.
+/// let third = second + Duration::from_secs(1);
+/// assert!((drag.moved(&detents, positions.into_iter(), 0.5, 0.1, third) - 0.6).abs() < 1e-6);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct DetentDrag {
+    hold: Option<DetentHold>,
+    last_move: Option<Instant>,
+}
+
+/// A value held at a `detent`, while the drag moved `offset` away from it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct DetentHold {
+    detent: f32,
+    offset: f32,
+}
+
+impl DetentDrag {
+    /// Starts a drag at `now`, which holds no detent.
+    pub fn start(&mut self, now: Instant) {
+        self.hold = None;
+        self.last_move = Some(now);
+    }
+
+    /// Moves a dragged value `from` by `travel` at `now`, returning where it
+    /// ends up.
+    ///
+    /// While the drag is at most as fast as the `max_speed` of `detents`,
+    /// the value stops at each of the `positions` it crosses, and stays
+    /// there until the drag moves more than the `stickiness` away from it.
+    /// The rest of the travel continues from the detent. A faster drag
+    /// lets go of the detent and follows the pointer.
+    pub fn moved(
+        &mut self,
+        detents: &Detents,
+        positions: impl Iterator<Item = f32> + Clone,
+        from: f32,
+        travel: f32,
+        now: Instant,
+    ) -> f32 {
+        // A drag without a previous move counts as slow.
+        let speed = match self.last_move.replace(now) {
+            Some(last) => travel.abs() / now.saturating_duration_since(last).as_secs_f32(),
+            None => 0.0,
+        };
+
+        if speed > detents.max_speed {
+            return match self.hold.take() {
+                Some(hold) => hold.detent + hold.offset + travel,
+                None => from + travel,
+            };
+        }
+
+        let mut value = from;
+        let mut travel = travel;
+
+        loop {
+            if let Some(hold) = self.hold.as_mut() {
+                hold.offset += travel;
+
+                if hold.offset.abs() <= detents.stickiness {
+                    return hold.detent;
+                }
+
+                value = hold.detent;
+                travel = hold.offset - detents.stickiness.copysign(hold.offset);
+                self.hold = None;
+            }
+
+            // The closest detent ahead, leaving out the one the value is at.
+            let next = positions
+                .clone()
+                .filter(|detent| {
+                    let ahead = detent - value;
+                    ahead * travel > 0.0 && ahead.abs() <= travel.abs()
+                })
+                .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()));
+
+            match next {
+                Some(detent) => {
+                    travel -= detent - value;
+                    value = detent;
+                    self.hold = Some(DetentHold {
+                        detent,
+                        offset: 0.0,
+                    });
+                }
+                None => return value + travel,
+            }
+        }
+    }
+}
+
+/// The last angle of a pointer dragged around a center, such as the center
+/// of a `Knob`.
+///
+/// The angle is unwrapped, so dragging across the gap at the bottom of a
+/// knob keeps moving the value in the same direction instead of jumping to
+/// the other end. See [`limit`] for how the value is clamped at its ends.
+///
+/// # Example
+///
+/// ```
+/// use iced::Point;
+/// use iced_audio::CircularDrag;
+///
+/// let center = Point::ORIGIN;
+/// let span = std::f32::consts::PI;
+/// let mut drag = CircularDrag::default();
+///
+/// // From the left to the top is a quarter turn clockwise, i.e. half of
+/// // the span.
+/// drag.start(center, Point::new(-10.0, 0.0));
+/// assert!((drag.moved_to(center, Point::new(0.0, -10.0), span) - 0.5).abs() < 1e-6);
+/// ```
+///
+/// [`limit`]: #method.limit
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct CircularDrag {
+    prev: Option<f32>,
+    overshoot: f32,
+}
+
+impl CircularDrag {
+    /// Starts a drag around `center` at `position`.
+    pub fn start(&mut self, center: Point, position: Point) {
+        self.prev = angle(center, position);
+        self.overshoot = 0.0;
+    }
+
+    /// Moves the drag to `position`, returning how far the pointer turned
+    /// clockwise around `center` since the last call, where a turn of `span`
+    /// radians is `1.0`.
+    ///
+    /// Positions closer to `center` than [`CIRCULAR_DRAG_MIN_RADIUS`] are
+    /// ignored.
+    ///
+    /// [`CIRCULAR_DRAG_MIN_RADIUS`]: constant.CIRCULAR_DRAG_MIN_RADIUS.html
+    pub fn moved_to(&mut self, center: Point, position: Point, span: f32) -> f32 {
+        let Some(angle) = angle(center, position) else {
+            return 0.0;
+        };
+
+        let turned = self.prev.map_or(0.0, |prev| wrap(angle - prev));
+        self.prev = Some(angle);
+
+        if span > 0.0 {
+            turned / span
+        } else {
+            0.0
+        }
+    }
+
+    /// Limits the `change` of a `value` between `0.0` and `1.0` to its ends.
+    ///
+    /// The part of a change past an end is remembered as an overshoot of up
+    /// to `max_overshoot`, which has to be turned back before the value
+    /// leaves that end again. With the size of the gap of a knob as
+    /// `max_overshoot`, the value stays at an end while the pointer crosses
+    /// the gap, and the pointer lines up with the value again once it moves.
+    /// Only the size of `max_overshoot` counts, not its sign.
+    pub fn limit(&mut self, value: f32, change: f32, max_overshoot: f32) -> f32 {
+        let max_overshoot = max_overshoot.abs();
+        let mut change = change;
+
+        if self.overshoot != 0.0 {
+            let overshoot = self.overshoot + change;
+
+            if overshoot * self.overshoot > 0.0 {
+                self.overshoot = overshoot.clamp(-max_overshoot, max_overshoot);
+                return 0.0;
+            }
+
+            self.overshoot = 0.0;
+            change = overshoot;
+        }
+
+        let target = value + change;
+
+        if target > 1.0 {
+            self.overshoot = (target - 1.0).min(max_overshoot);
+            1.0 - value
+        } else if target < 0.0 {
+            self.overshoot = target.max(-max_overshoot);
+            -value
+        } else {
+            change
+        }
+    }
+}
+
+/// The clockwise angle of `position` around `center`, starting at the bottom
+/// like the angles of a `KnobAngleRange`, or `None` if it is too close to
+/// `center`.
+fn angle(center: Point, position: Point) -> Option<f32> {
+    let (x, y) = (position.x - center.x, position.y - center.y);
+
+    (x.hypot(y) >= CIRCULAR_DRAG_MIN_RADIUS).then(|| (-x).atan2(y))
+}
+
+/// Wraps an angle difference into `-PI..=PI`.
+fn wrap(angle: f32) -> f32 {
+    let angle = angle.rem_euclid(TWO_PI);
+
+    if angle > std::f32::consts::PI {
+        angle - TWO_PI
+    } else {
+        angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CircularDrag, DetentDrag, Detents, Drag, DragClamp, DragThreshold};
+    use iced::time::{Duration, Instant};
+
+    #[test]
+    fn unclamped_follows_the_pointer_anywhere() {
+        let mut drag = Drag::default();
+        drag.start(10.0, DragClamp::None);
+
+        assert_eq!(drag.moved_to(-20.0, DragClamp::None), -30.0);
+        assert_eq!(drag.moved_to(-25.0, DragClamp::None), -5.0);
+        assert_eq!(drag.moved_to(10.0, DragClamp::None), 35.0);
+    }
+
+    #[test]
+    fn clamped_adds_up_to_the_range() {
+        let clamp = DragClamp::Range {
+            min: 0.0,
+            max: 100.0,
+        };
+        let mut drag = Drag::default();
+        drag.start(-10.0, clamp);
+
+        // Wiggling outside of the range never moves.
+        assert_eq!(drag.moved_to(-50.0, clamp), 0.0);
+        assert_eq!(drag.moved_to(-5.0, clamp), 0.0);
+
+        // A drag across the whole range and beyond adds up to its length.
+        let total: f32 = [20.0, 80.0, 130.0, 170.0]
+            .into_iter()
+            .map(|position| drag.moved_to(position, clamp))
+            .sum();
+        assert_eq!(total, 100.0);
+    }
+
+    #[test]
+    fn clamped_takes_the_ends_in_either_order() {
+        let clamp = DragClamp::Range {
+            min: 100.0,
+            max: 0.0,
+        };
+
+        assert_eq!(clamp.apply(-10.0), 0.0);
+        assert_eq!(clamp.apply(50.0), 50.0);
+        assert_eq!(clamp.apply(150.0), 100.0);
+    }
+
+    #[test]
+    fn threshold_is_crossed_past_its_distance() {
+        assert!(!DragThreshold::DEFAULT.is_crossed(2.0, false));
+        assert!(DragThreshold::DEFAULT.is_crossed(2.5, false));
+        assert!(!DragThreshold::DEFAULT.is_crossed(5.0, true));
+        assert!(DragThreshold::DEFAULT.is_crossed(6.5, true));
+        assert!(DragThreshold::DISABLED.is_crossed(0.1, true));
+    }
+
+    #[test]
+    fn detents_only_hold_slow_drags() {
+        let detents = Detents {
+            stickiness: 0.1,
+            max_speed: 1.0,
+        };
+        let positions = [0.25_f32, 0.5, 0.75];
+        let moved = |drag: &mut DetentDrag, from, travel, at| {
+            drag.moved(&detents, positions.into_iter(), from, travel, at)
+        };
+        let start = Instant::now();
+        let second = |seconds: u64| start + Duration::from_secs(seconds);
+        let mut drag = DetentDrag::default();
+        drag.start(start);
+
+        // Down onto the closest detent, which moving back doesn't leave...
+        assert_eq!(moved(&mut drag, 0.6, -0.15, second(1)), 0.5);
+        assert_eq!(moved(&mut drag, 0.5, 0.15, second(2)), 0.5);
+        // ...until past the stickiness, which the value skips.
+        assert!((moved(&mut drag, 0.5, 0.1, second(3)) - 0.6).abs() < 1e-6);
+
+        // Leaving a detent continues onto the next one.
+        let mut drag = DetentDrag::default();
+        drag.start(start);
+        assert_eq!(moved(&mut drag, 0.4, 0.1, second(1)), 0.5);
+        assert_eq!(moved(&mut drag, 0.5, 0.4, second(2)), 0.75);
+
+        // A fast drag lets go of the detent and catches up with the
+        // pointer.
+        let fast = second(2) + Duration::from_millis(100);
+        assert!((moved(&mut drag, 0.75, 0.2, fast) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn circular_crosses_the_bottom_without_jumping() {
+        let center = iced::Point::ORIGIN;
+        let span = std::f32::consts::PI;
+        let mut drag = CircularDrag::default();
+        drag.start(center, iced::Point::new(10.0, 10.0));
+
+        // Right of the bottom to left of it is a small clockwise turn,
+        // although the raw angle wraps around.
+        let turned = drag.moved_to(center, iced::Point::new(-10.0, 10.0), span);
+        assert!((turned - 0.5).abs() < 1e-6);
+
+        // Close to the center, the pointer is ignored.
+        assert_eq!(drag.moved_to(center, iced::Point::new(1.0, 1.0), span), 0.0);
+    }
+
+    #[test]
+    fn circular_limit_holds_the_ends_with_hysteresis() {
+        let mut drag = CircularDrag::default();
+
+        // Going past the end clamps...
+        assert!((drag.limit(0.9, 0.3, 0.25) - 0.1).abs() < 1e-6);
+        // ...further overshoot is absorbed...
+        assert_eq!(drag.limit(1.0, 0.5, 0.25), 0.0);
+        // ...and has to be turned back first, up to `max_overshoot`.
+        assert_eq!(drag.limit(1.0, -0.2, 0.25), 0.0);
+        assert!((drag.limit(1.0, -0.1, 0.25) + 0.05).abs() < 1e-6);
+
+        // The same at the bottom end.
+        assert_eq!(drag.limit(0.1, -0.2, 0.25), -0.1);
+        assert!((drag.limit(0.0, 0.15, 0.25) - 0.05).abs() < 1e-6);
+    }
+
+    #[test]
+    fn circular_limit_takes_the_overshoot_as_a_size() {
+        let mut drag = CircularDrag::default();
+
+        // A negative `max_overshoot` holds the end as a positive one does.
+        assert!((drag.limit(0.9, 0.3, -0.25) - 0.1).abs() < 1e-6);
+        assert_eq!(drag.limit(1.0, 0.5, -0.25), 0.0);
+        assert_eq!(drag.limit(1.0, -0.2, -0.25), 0.0);
+        assert!((drag.limit(1.0, -0.1, -0.25) + 0.05).abs() < 1e-6);
+    }
+}
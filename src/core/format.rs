@@ -0,0 +1,171 @@
+//! Formatting of common audio units for display, e.g. in tooltips, text
+//! entry fields and text marks
+//!
+//! The precision of each unit follows what most DAWs show: the smaller the
+//! value, the more decimals it gets, so that the text stays about the same
+//! length across the range.
+
+/// Formats a value in decibels with one decimal, e.g. `"-6.0 dB"`.
+///
+/// Positive values get a `+` sign so that gains and cuts are easy to tell
+/// apart, and negative infinity (silence) is shown as `"-inf dB"`.
+///
+/// ```
+/// use iced_audio::format_db;
+///
+/// assert_eq!(format_db(-6.02), "-6.0 dB");
+/// assert_eq!(format_db(3.0), "+3.0 dB");
+/// assert_eq!(format_db(f32::NEG_INFINITY), "-inf dB");
+/// ```
+pub fn format_db(db: f32) -> String {
+    if db.is_infinite() {
+        return if db < 0.0 {
+            String::from("-inf dB")
+        } else {
+            String::from("+inf dB")
+        };
+    }
+
+    let text = fixed(db, 1);
+
+    if db > 0.0 && text != "0.0" {
+        format!("+{text} dB")
+    } else {
+        format!("{text} dB")
+    }
+}
+
+/// Formats a frequency in Hz, switching to kHz from 1 kHz on.
+///
+/// | Frequency        | Example      |
+/// |------------------|--------------|
+/// | below 100 Hz     | `"20.0 Hz"`  |
+/// | below 1 kHz      | `"440 Hz"`   |
+/// | below 10 kHz     | `"2.50 kHz"` |
+/// | 10 kHz and above | `"12.5 kHz"` |
+///
+/// ```
+/// use iced_audio::format_hz;
+///
+/// assert_eq!(format_hz(440.0), "440 Hz");
+/// assert_eq!(format_hz(12_500.0), "12.5 kHz");
+/// ```
+pub fn format_hz(hz: f32) -> String {
+    let magnitude = hz.abs();
+
+    if magnitude < 99.95 {
+        format!("{} Hz", fixed(hz, 1))
+    } else if magnitude < 999.5 {
+        format!("{} Hz", fixed(hz, 0))
+    } else if magnitude < 9_995.0 {
+        format!("{} kHz", fixed(hz / 1000.0, 2))
+    } else {
+        format!("{} kHz", fixed(hz / 1000.0, 1))
+    }
+}
+
+/// Formats a time in milliseconds, switching to seconds from 1 s on.
+///
+/// | Time             | Example      |
+/// |------------------|--------------|
+/// | below 10 ms      | `"2.50 ms"`  |
+/// | below 100 ms     | `"25.0 ms"`  |
+/// | below 1 s        | `"250 ms"`   |
+/// | 1 s and above    | `"2.50 s"`   |
+///
+/// ```
+/// use iced_audio::format_ms;
+///
+/// assert_eq!(format_ms(25.0), "25.0 ms");
+/// assert_eq!(format_ms(1500.0), "1.50 s");
+/// ```
+pub fn format_ms(ms: f32) -> String {
+    let magnitude = ms.abs();
+
+    if magnitude < 9.995 {
+        format!("{} ms", fixed(ms, 2))
+    } else if magnitude < 99.95 {
+        format!("{} ms", fixed(ms, 1))
+    } else if magnitude < 999.5 {
+        format!("{} ms", fixed(ms, 0))
+    } else {
+        format!("{} s", fixed(ms / 1000.0, 2))
+    }
+}
+
+/// Formats a fraction as a percentage, where `1.0` is `"100 %"`.
+///
+/// Percentages below 10 % get one decimal.
+///
+/// ```
+/// use iced_audio::format_percent;
+///
+/// assert_eq!(format_percent(0.5), "50 %");
+/// assert_eq!(format_percent(0.025), "2.5 %");
+/// ```
+pub fn format_percent(value: f32) -> String {
+    let percent = value * 100.0;
+
+    if percent.abs() < 9.95 {
+        format!("{} %", fixed(percent, 1))
+    } else {
+        format!("{} %", fixed(percent, 0))
+    }
+}
+
+/// Formats `value` with `precision` decimals, without the sign of values that
+/// round to zero.
+fn fixed(value: f32, precision: usize) -> String {
+    let text = format!("{value:.precision$}");
+
+    match text.strip_prefix('-') {
+        Some(unsigned) if unsigned.chars().all(|c| c == '0' || c == '.') => String::from(unsigned),
+        _ => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_db, format_hz, format_ms, format_percent};
+
+    #[test]
+    fn db() {
+        assert_eq!(format_db(0.0), "0.0 dB");
+        assert_eq!(format_db(-0.04), "0.0 dB");
+        assert_eq!(format_db(0.04), "0.0 dB");
+        assert_eq!(format_db(0.05), "+0.1 dB");
+        assert_eq!(format_db(-12.0), "-12.0 dB");
+        assert_eq!(format_db(6.0), "+6.0 dB");
+        assert_eq!(format_db(f32::INFINITY), "+inf dB");
+    }
+
+    #[test]
+    fn hz() {
+        assert_eq!(format_hz(20.0), "20.0 Hz");
+        assert_eq!(format_hz(99.96), "100 Hz");
+        assert_eq!(format_hz(999.4), "999 Hz");
+        assert_eq!(format_hz(999.6), "1.00 kHz");
+        assert_eq!(format_hz(2500.0), "2.50 kHz");
+        assert_eq!(format_hz(9996.0), "10.0 kHz");
+        assert_eq!(format_hz(20_000.0), "20.0 kHz");
+    }
+
+    #[test]
+    fn ms() {
+        assert_eq!(format_ms(0.0), "0.00 ms");
+        assert_eq!(format_ms(2.5), "2.50 ms");
+        assert_eq!(format_ms(9.996), "10.0 ms");
+        assert_eq!(format_ms(99.96), "100 ms");
+        assert_eq!(format_ms(999.6), "1.00 s");
+        assert_eq!(format_ms(12_000.0), "12.00 s");
+    }
+
+    #[test]
+    fn percent() {
+        assert_eq!(format_percent(0.0), "0.0 %");
+        assert_eq!(format_percent(-0.0001), "0.0 %");
+        assert_eq!(format_percent(0.0996), "10 %");
+        assert_eq!(format_percent(1.0), "100 %");
+        assert_eq!(format_percent(-0.5), "-50 %");
+    }
+}
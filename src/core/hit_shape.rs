@@ -0,0 +1,59 @@
+//! The shape of the area of a widget that reacts to the pointer
+
+use iced::{Point, Rectangle};
+
+/// The shape of the area of a widget that reacts to the mouse and to
+/// touches, within its bounds and hit padding.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HitShape {
+    /// The whole rectangle.
+    #[default]
+    Rectangle,
+    /// The circle inscribed in the rectangle (an ellipse if it is not
+    /// square), so that presses next to a round widget, e.g. in the corners
+    /// between it and a neighbouring knob, reach what is underneath.
+    Circle,
+}
+
+impl HitShape {
+    /// Returns whether `point` is inside the shape within `bounds`.
+    pub fn contains(self, bounds: Rectangle, point: Point) -> bool {
+        match self {
+            HitShape::Rectangle => bounds.contains(point),
+            HitShape::Circle => {
+                let radius_x = bounds.width / 2.0;
+                let radius_y = bounds.height / 2.0;
+
+                if radius_x <= 0.0 || radius_y <= 0.0 {
+                    return false;
+                }
+
+                let center = bounds.center();
+                let x = (point.x - center.x) / radius_x;
+                let y = (point.y - center.y) / radius_y;
+
+                x * x + y * y <= 1.0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HitShape;
+    use iced::{Point, Rectangle};
+
+    #[test]
+    fn circle_leaves_out_the_corners() {
+        let bounds = Rectangle::new(Point::new(10.0, 10.0), [20.0, 20.0].into());
+        let corner = Point::new(12.0, 12.0);
+        let edge = Point::new(20.0, 10.5);
+
+        assert!(HitShape::Rectangle.contains(bounds, corner));
+        assert!(!HitShape::Circle.contains(bounds, corner));
+        assert!(HitShape::Circle.contains(bounds, edge));
+        assert!(HitShape::Circle.contains(bounds, bounds.center()));
+        assert!(!HitShape::Circle.contains(Rectangle::default(), Point::ORIGIN));
+    }
+}
@@ -4,6 +4,7 @@ use super::normal::Normal;
 
 /// The state of a modulation range
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModulationRange {
     /// Where the modulation range starts.
     /// `0.0.into()` is all the way minimum, and `1.0.into()` is all the way maximum.
@@ -30,6 +31,98 @@ impl ModulationRange {
             filled_visible: true,
         }
     }
+
+    /// Creates a new `ModulationRange` that swings by a bipolar `amount`
+    /// around `center`, from `center - amount` to `center + amount`.
+    ///
+    /// A negative `amount` creates an inverse range (one that starts above
+    /// where it ends). The ends are clipped to the range of a [`Normal`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{ModulationRange, Normal};
+    ///
+    /// let mod_range = ModulationRange::from_center_and_amount(Normal::CENTER, -0.25);
+    ///
+    /// assert_eq!(mod_range.start.as_f32(), 0.75);
+    /// assert_eq!(mod_range.end.as_f32(), 0.25);
+    /// assert!(mod_range.is_inverse());
+    /// ```
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn from_center_and_amount(center: Normal, amount: f32) -> Self {
+        Self::new(
+            Normal::from_clipped(center.as_f32() - amount),
+            Normal::from_clipped(center.as_f32() + amount),
+        )
+    }
+
+    /// Returns a copy of the `ModulationRange` with both ends clamped
+    /// between `min` and `max`, keeping its direction. The bounds may be
+    /// given in either order.
+    pub fn clamp(&self, min: Normal, max: Normal) -> Self {
+        let (lo, hi) = if min <= max { (min, max) } else { (max, min) };
+        let clamp =
+            |normal: Normal| Normal::from_clipped(normal.as_f32().clamp(lo.as_f32(), hi.as_f32()));
+
+        Self {
+            start: clamp(self.start),
+            end: clamp(self.end),
+            filled_visible: self.filled_visible,
+        }
+    }
+
+    /// Returns a copy of the `ModulationRange` with its `start` and `end`
+    /// swapped.
+    pub fn invert(&self) -> Self {
+        Self {
+            start: self.end,
+            end: self.start,
+            filled_visible: self.filled_visible,
+        }
+    }
+
+    /// Returns whether `normal` is between the two ends of the
+    /// `ModulationRange` (inclusive), regardless of its direction.
+    pub fn contains(&self, normal: Normal) -> bool {
+        let (lower, upper) = self.bounds();
+        (lower..=upper).contains(&normal)
+    }
+
+    /// Returns whether the `ModulationRange` is inverse, which is when it
+    /// starts above where it ends.
+    ///
+    /// The filled portion of an inverse range is drawn with the
+    /// `filled_inverse_color` of a style.
+    pub fn is_inverse(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Returns the lower and upper ends of the `ModulationRange`, regardless
+    /// of its direction.
+    pub fn bounds(&self) -> (Normal, Normal) {
+        if self.is_inverse() {
+            (self.end, self.start)
+        } else {
+            (self.start, self.end)
+        }
+    }
+
+    /// Returns the lower and upper ends of the filled portion of the
+    /// `ModulationRange`, or `None` if no filled portion should be drawn,
+    /// which is when it is hidden or when the range is empty.
+    ///
+    /// Use [`is_inverse`] to pick the color of the filled portion.
+    ///
+    /// [`is_inverse`]: #method.is_inverse
+    pub fn filled(&self) -> Option<(Normal, Normal)> {
+        if self.filled_visible && self.start != self.end {
+            Some(self.bounds())
+        } else {
+            None
+        }
+    }
 }
 
 impl Default for ModulationRange {
@@ -41,3 +134,69 @@ impl Default for ModulationRange {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ModulationRange;
+    use crate::core::Normal;
+
+    fn normal(value: f32) -> Normal {
+        Normal::from_clipped(value)
+    }
+
+    #[test]
+    fn from_center_and_amount() {
+        let mod_range = ModulationRange::from_center_and_amount(normal(0.5), 0.25);
+        assert_eq!(
+            (mod_range.start, mod_range.end),
+            (normal(0.25), normal(0.75))
+        );
+        assert!(!mod_range.is_inverse());
+
+        let clipped = ModulationRange::from_center_and_amount(normal(0.9), 0.25);
+        assert_eq!((clipped.start, clipped.end), (normal(0.65), Normal::MAX));
+
+        let inverse = ModulationRange::from_center_and_amount(normal(0.5), -0.25);
+        assert_eq!(inverse.invert().start, mod_range.start);
+        assert_eq!(inverse.invert().end, mod_range.end);
+        assert!(inverse.is_inverse());
+    }
+
+    #[test]
+    fn clamp_keeps_direction() {
+        let inverse = ModulationRange::new(Normal::MAX, Normal::MIN);
+        let clamped = inverse.clamp(normal(0.25), normal(0.75));
+
+        assert_eq!((clamped.start, clamped.end), (normal(0.75), normal(0.25)));
+        assert!(clamped.is_inverse());
+    }
+
+    #[test]
+    fn clamp_takes_the_bounds_in_either_order() {
+        let mod_range = ModulationRange::new(Normal::MIN, Normal::MAX);
+        let clamped = mod_range.clamp(normal(0.75), normal(0.25));
+
+        assert_eq!((clamped.start, clamped.end), (normal(0.25), normal(0.75)));
+    }
+
+    #[test]
+    fn contains_and_filled() {
+        for mod_range in [
+            ModulationRange::new(normal(0.2), normal(0.6)),
+            ModulationRange::new(normal(0.6), normal(0.2)),
+        ] {
+            assert!(mod_range.contains(normal(0.2)));
+            assert!(mod_range.contains(normal(0.4)));
+            assert!(!mod_range.contains(normal(0.7)));
+            assert_eq!(mod_range.filled(), Some((normal(0.2), normal(0.6))));
+        }
+
+        let empty = ModulationRange::new(Normal::CENTER, Normal::CENTER);
+        assert_eq!(empty.filled(), None);
+        assert!(empty.contains(Normal::CENTER));
+
+        let mut hidden = ModulationRange::new(Normal::MIN, Normal::MAX);
+        hidden.filled_visible = false;
+        assert_eq!(hidden.filled(), None);
+    }
+}
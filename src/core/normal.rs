@@ -33,10 +33,32 @@ impl fmt::Display for NormalOutOfRange {
 /// assert_eq!(normal.as_f32(), 0.5);
 /// ```
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "f32", into = "f32")
+)]
 pub struct Normal {
     value: f32,
 }
 
+/// The side of a bipolar center a [`Normal`] is on, as returned by
+/// [`Normal::classify_bipolar`].
+///
+/// [`Normal`]: struct.Normal.html
+/// [`Normal::classify_bipolar`]: struct.Normal.html#method.classify_bipolar
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BipolarSide {
+    /// Less than the center
+    Left,
+    /// Within [`Normal::CENTER_TOLERANCE`] of the center
+    ///
+    /// [`Normal::CENTER_TOLERANCE`]: struct.Normal.html#associatedconstant.CENTER_TOLERANCE
+    Center,
+    /// Greater than the center
+    Right,
+}
+
 impl Default for Normal {
     fn default() -> Self {
         Self { value: 0.0 }
@@ -53,6 +75,13 @@ impl Normal {
     /// A `Normal` with the value `1.0`.
     pub const MAX: Self = Self { value: 1.0 };
 
+    /// How close a `Normal` has to be to a bipolar center to count as
+    /// centered in [`classify_bipolar`], so that the rounding errors of a
+    /// mapped or snapped value don't flip it to one side.
+    ///
+    /// [`classify_bipolar`]: #method.classify_bipolar
+    pub const CENTER_TOLERANCE: f32 = 0.001;
+
     /// Creates a new `Normal`, clipping the provided value.
     ///
     /// # Arguments
@@ -63,11 +92,42 @@ impl Normal {
     ///
     /// else if `value > 1.0`, then `normal.value` is set to `1.0`
     ///
+    /// else if `value` is `NaN`, then `normal.value` is set to `0.0`
+    ///
     /// else `normal.value` is set to `value`
     #[inline]
-    pub fn from_clipped(value: f32) -> Self {
+    pub const fn from_clipped(value: f32) -> Self {
+        Self {
+            value: if value.is_nan() {
+                0.0
+            } else {
+                value.clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    /// Creates a new `Normal` from an `f64`, clipping the provided value.
+    ///
+    /// Use this to store the result of math done in `f64`, so that
+    /// intermediate results don't lose precision. Like
+    /// [`from_clipped`](#method.from_clipped), `NaN` is clipped to `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// assert_eq!(Normal::from_f64(0.25), Normal::from_clipped(0.25));
+    /// assert_eq!(Normal::from_f64(2.0), Normal::MAX);
+    /// ```
+    #[inline]
+    pub const fn from_f64(value: f64) -> Self {
         Self {
-            value: value.clamp(0.0, 1.0),
+            value: if value.is_nan() {
+                0.0
+            } else {
+                value.clamp(0.0, 1.0) as f32
+            },
         }
     }
 
@@ -81,6 +141,8 @@ impl Normal {
     ///
     /// else if `value > 1.0`, then `normal.value` is set to `1.0`
     ///
+    /// else if `value` is `NaN`, then `normal.value` is set to `0.0`
+    ///
     /// else `normal.value` is set to `value`
     #[inline]
     pub fn set_clipped(&mut self, value: f32) {
@@ -98,28 +160,83 @@ impl Normal {
 
     /// Returns the value of the `Normal` as an `f32`
     #[inline]
-    pub fn as_f32(&self) -> f32 {
+    pub const fn as_f32(&self) -> f32 {
         self.value
     }
 
+    /// Returns the value of the `Normal` as an `f64`
+    #[inline]
+    pub const fn as_f64(&self) -> f64 {
+        self.value as f64
+    }
+
     /// Returns the inverse value (`1.0 - value`) of the `Normal` as an `f32`
     #[inline]
-    pub fn as_f32_inv(&self) -> f32 {
+    pub const fn as_f32_inv(&self) -> f32 {
         1.0 - self.value
     }
 
     /// Returns the value of the `Normal` times the `scalar`
     #[inline]
-    pub fn scale(&self, scalar: f32) -> f32 {
+    pub const fn scale(&self, scalar: f32) -> f32 {
         self.value * scalar
     }
 
     /// Returns the inverse value (`1.0 - value`) of the `Normal`
     /// times the `scalar`
     #[inline]
-    pub fn scale_inv(&self, scalar: f32) -> f32 {
+    pub const fn scale_inv(&self, scalar: f32) -> f32 {
         (1.0 - self.value) * scalar
     }
+
+    /// Returns `true` if the `Normal` is within `tolerance` of
+    /// [`Normal::CENTER`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// assert!(Normal::from_clipped(0.5005).is_center(Normal::CENTER_TOLERANCE));
+    /// assert!(!Normal::from_clipped(0.51).is_center(Normal::CENTER_TOLERANCE));
+    /// ```
+    ///
+    /// [`Normal::CENTER`]: #associatedconstant.CENTER
+    #[inline]
+    pub fn is_center(&self, tolerance: f32) -> bool {
+        (self.value - Self::CENTER.value).abs() <= tolerance
+    }
+
+    /// Returns the side of the bipolar `center` the `Normal` is on. Values
+    /// within [`CENTER_TOLERANCE`] of `center` are [`BipolarSide::Center`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{BipolarSide, Normal};
+    ///
+    /// // E.g. 0 dB of a -12 dB to +6 dB range.
+    /// let center = Normal::from_clipped(2.0 / 3.0);
+    ///
+    /// assert_eq!(Normal::CENTER.classify_bipolar(center), BipolarSide::Left);
+    /// assert_eq!(center.classify_bipolar(center), BipolarSide::Center);
+    /// assert_eq!(Normal::MAX.classify_bipolar(center), BipolarSide::Right);
+    /// ```
+    ///
+    /// [`CENTER_TOLERANCE`]: #associatedconstant.CENTER_TOLERANCE
+    /// [`BipolarSide::Center`]: enum.BipolarSide.html#variant.Center
+    #[inline]
+    pub fn classify_bipolar(&self, center: Normal) -> BipolarSide {
+        let delta = self.value - center.value;
+
+        if delta < -Self::CENTER_TOLERANCE {
+            BipolarSide::Left
+        } else if delta > Self::CENTER_TOLERANCE {
+            BipolarSide::Right
+        } else {
+            BipolarSide::Center
+        }
+    }
 }
 
 impl std::error::Error for NormalOutOfRange {}
@@ -144,7 +261,8 @@ impl From<Normal> for f32 {
 
 #[cfg(test)]
 mod tests {
-    use super::{Normal, NormalOutOfRange};
+    use super::{BipolarSide, Normal, NormalOutOfRange};
+    use proptest::prelude::*;
 
     #[test]
     fn from_clipped() {
@@ -163,6 +281,17 @@ mod tests {
         let more_than_max = Normal::from_clipped(1.1);
         assert_eq!(more_than_max, Normal::MAX);
         assert_eq!(more_than_max.as_f32(), 1.0);
+
+        assert_eq!(Normal::from_clipped(f32::NAN), Normal::MIN);
+    }
+
+    #[test]
+    fn from_f64() {
+        assert_eq!(Normal::from_f64(-0.1), Normal::MIN);
+        assert_eq!(Normal::from_f64(1.1), Normal::MAX);
+        assert_eq!(Normal::from_f64(f64::NAN), Normal::MIN);
+        assert_eq!(Normal::from_f64(0.1).as_f32(), 0.1);
+        assert_eq!(Normal::from_clipped(0.1).as_f64(), f64::from(0.1_f32));
     }
 
     #[test]
@@ -212,4 +341,100 @@ mod tests {
         assert_eq!(err, NormalOutOfRange(1.1));
         assert_eq!(normal.as_f32(), 1.0);
     }
+
+    #[test]
+    fn classify_bipolar() {
+        let center = Normal::from_clipped(0.25);
+
+        assert_eq!(
+            Normal::from_clipped(0.2).classify_bipolar(center),
+            BipolarSide::Left
+        );
+        assert_eq!(
+            Normal::from_clipped(0.2505).classify_bipolar(center),
+            BipolarSide::Center
+        );
+        assert_eq!(
+            Normal::from_clipped(0.3).classify_bipolar(center),
+            BipolarSide::Right
+        );
+
+        // The default center of the widgets.
+        assert_eq!(
+            Normal::from_clipped(0.45).classify_bipolar(Normal::CENTER),
+            BipolarSide::Left
+        );
+        assert!(Normal::from_clipped(0.4995).is_center(Normal::CENTER_TOLERANCE));
+        assert!(!Normal::from_clipped(0.45).is_center(Normal::CENTER_TOLERANCE));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let normal = Normal::from_clipped(0.25);
+        assert_eq!(serde_json::to_string(&normal).unwrap(), "0.25");
+        assert_eq!(serde_json::from_str::<Normal>("0.25").unwrap(), normal);
+        assert_eq!(serde_json::from_str::<Normal>("1.0").unwrap(), Normal::MAX);
+
+        // Values outside of the range are rejected instead of clipped.
+        assert!(serde_json::from_str::<Normal>("-0.1").is_err());
+        assert!(serde_json::from_str::<Normal>("1.5").is_err());
+        assert!(serde_json::from_str::<Normal>("\"0.5\"").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_from_clipped_is_in_range(value in proptest::num::f32::ANY) {
+            let normal = Normal::from_clipped(value);
+            prop_assert!((0.0..=1.0).contains(&normal.as_f32()));
+        }
+
+        #[test]
+        fn prop_from_clipped_keeps_valid_values(value in 0.0f32..=1.0) {
+            prop_assert_eq!(Normal::from_clipped(value).as_f32(), value);
+            prop_assert_eq!(Normal::try_from(value).unwrap().as_f32(), value);
+            prop_assert_eq!(Normal::from_f64(f64::from(value)).as_f32(), value);
+        }
+
+        #[test]
+        fn prop_try_from_rejects_invalid_values(
+            value in prop_oneof![-1000.0f32..-f32::EPSILON, 1.0 + f32::EPSILON..1000.0],
+        ) {
+            prop_assert_eq!(Normal::try_from(value).unwrap_err(), NormalOutOfRange(value));
+        }
+
+        #[test]
+        fn prop_from_clipped_is_monotonic(a in -2.0f32..2.0, b in -2.0f32..2.0) {
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+            prop_assert!(Normal::from_clipped(low) <= Normal::from_clipped(high));
+        }
+
+        #[test]
+        fn prop_inverse_and_scale(value in 0.0f32..=1.0, scalar in -1000.0f32..1000.0) {
+            let normal = Normal::from_clipped(value);
+
+            prop_assert_eq!(normal.as_f32() + normal.as_f32_inv(), 1.0);
+            prop_assert!(
+                (normal.scale(scalar) + normal.scale_inv(scalar) - scalar).abs()
+                    <= scalar.abs() * f32::EPSILON * 2.0
+            );
+        }
+
+        #[test]
+        fn prop_classify_bipolar(value in 0.0f32..=1.0, center in 0.0f32..=1.0) {
+            let normal = Normal::from_clipped(value);
+            let center = Normal::from_clipped(center);
+
+            let expected = match normal.classify_bipolar(center) {
+                BipolarSide::Left => normal < center,
+                BipolarSide::Center => {
+                    (normal.as_f32() - center.as_f32()).abs() <= Normal::CENTER_TOLERANCE
+                }
+                BipolarSide::Right => normal > center,
+            };
+            prop_assert!(expected);
+            prop_assert_eq!(normal.classify_bipolar(Normal::CENTER) == BipolarSide::Center,
+                normal.is_center(Normal::CENTER_TOLERANCE));
+        }
+    }
 }
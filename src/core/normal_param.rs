@@ -12,6 +12,7 @@ use std::fmt::Debug;
 ///
 /// [`Normal`]: ../struct.Normal.html
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NormalParam {
     /// The value of the parameter represented as a [`Normal`]
     ///
@@ -41,4 +42,12 @@ impl NormalParam {
     pub fn update(&mut self, normal: Normal) {
         self.value = normal;
     }
+    /// Updates the default [`Normal`] value of this `NormalParam`, which is
+    /// where resetting the widget (e.g. with a double click) moves it to
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    #[inline]
+    pub fn set_default(&mut self, normal: Normal) {
+        self.default = normal;
+    }
 }
@@ -0,0 +1,294 @@
+//! A generic parameter that maps a value to and from a [`Normal`]
+//!
+//! [`Normal`]: ../struct.Normal.html
+
+use crate::core::{
+    format_db, format_hz, FaderRange, FloatRange, FreqRange, IntRange, LogDBRange, Normal,
+    NormalParam, NoteRange, TempoDivision, TempoSyncRange,
+};
+
+/// A range that maps values of type `Value` to and from a [`Normal`].
+///
+/// This is implemented by all of the range types in this crate.
+///
+/// [`Normal`]: ../struct.Normal.html
+pub trait Range {
+    /// The type of the values in this range.
+    type Value: Clone;
+
+    /// Returns the corresponding [`Normal`] from the supplied value
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn map_to_normal(&self, value: Self::Value) -> Normal;
+
+    /// Returns the corresponding value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn unmap_to_value(&self, normal: Normal) -> Self::Value;
+
+    /// Returns a [`Normal`] that is snapped to the closest step in this
+    /// range. Continuous ranges return the [`Normal`] as is.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn snapped(&self, normal: Normal) -> Normal {
+        normal
+    }
+
+    /// Formats a value of this range for display.
+    fn format(&self, value: &Self::Value) -> String;
+}
+
+/// A parameter with a value that can be mapped to and from a [`Normal`].
+///
+/// Widgets can be constructed from a `Param` directly (e.g. with
+/// `Knob::from_param()`), in which case they emit values of type `Value`
+/// instead of [`Normal`]s.
+///
+/// [`Normal`]: ../struct.Normal.html
+pub trait Param {
+    /// The type of the value of this parameter.
+    type Value;
+
+    /// Returns the current and default value of this parameter as a
+    /// [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    fn normal_param(&self) -> NormalParam;
+
+    /// Returns the corresponding value from the supplied [`Normal`], snapped
+    /// to the closest step of the parameter.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn unmap_to_value(&self, normal: Normal) -> Self::Value;
+
+    /// Formats the current value of this parameter for display.
+    fn format(&self) -> String;
+}
+
+impl<P: Param + ?Sized> Param for &P {
+    type Value = P::Value;
+
+    fn normal_param(&self) -> NormalParam {
+        (**self).normal_param()
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> Self::Value {
+        (**self).unmap_to_value(normal)
+    }
+
+    fn format(&self) -> String {
+        (**self).format()
+    }
+}
+
+/// A [`Param`] made of a [`Range`] combined with a current and a default
+/// value.
+///
+/// [`Param`]: trait.Param.html
+/// [`Range`]: trait.Range.html
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangedParam<R: Range> {
+    /// The range of the parameter
+    pub range: R,
+    /// The current value of the parameter
+    pub value: R::Value,
+    /// The default value of the parameter
+    pub default: R::Value,
+}
+
+impl<R: Range> RangedParam<R> {
+    /// Creates a new `RangedParam`
+    ///
+    /// * `range` - the range of the parameter
+    /// * `value` - the initial value of the parameter
+    /// * `default` - the default value of the parameter
+    pub fn new(range: R, value: R::Value, default: R::Value) -> Self {
+        Self {
+            range,
+            value,
+            default,
+        }
+    }
+
+    /// Updates the value of this parameter.
+    pub fn update(&mut self, value: R::Value) {
+        self.value = value;
+    }
+
+    /// Updates the default value of this parameter, which is where resetting
+    /// the widget (e.g. with a double click) moves it to.
+    pub fn set_default(&mut self, default: R::Value) {
+        self.default = default;
+    }
+}
+
+impl<R: Range> Param for RangedParam<R> {
+    type Value = R::Value;
+
+    fn normal_param(&self) -> NormalParam {
+        NormalParam {
+            value: self.range.map_to_normal(self.value.clone()),
+            default: self.range.map_to_normal(self.default.clone()),
+        }
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> Self::Value {
+        self.range.unmap_to_value(self.range.snapped(normal))
+    }
+
+    fn format(&self) -> String {
+        self.range.format(&self.value)
+    }
+}
+
+impl Range for FloatRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        FloatRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        FloatRange::unmap_to_value(self, normal)
+    }
+
+    fn format(&self, value: &f32) -> String {
+        format!("{value:.3}")
+    }
+}
+
+impl Range for IntRange {
+    type Value = i32;
+
+    fn map_to_normal(&self, value: i32) -> Normal {
+        IntRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> i32 {
+        IntRange::unmap_to_value(self, normal)
+    }
+
+    fn snapped(&self, normal: Normal) -> Normal {
+        IntRange::snapped(self, normal)
+    }
+
+    fn format(&self, value: &i32) -> String {
+        format!("{value}")
+    }
+}
+
+impl Range for LogDBRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        LogDBRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        LogDBRange::unmap_to_value(self, normal)
+    }
+
+    fn format(&self, value: &f32) -> String {
+        format_db(*value)
+    }
+}
+
+impl Range for FaderRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        FaderRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        FaderRange::unmap_to_value(self, normal)
+    }
+
+    fn format(&self, value: &f32) -> String {
+        format_db(*value)
+    }
+}
+
+impl Range for FreqRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        FreqRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        FreqRange::unmap_to_value(self, normal)
+    }
+
+    fn format(&self, value: &f32) -> String {
+        format_hz(*value)
+    }
+}
+
+impl Range for NoteRange {
+    type Value = u8;
+
+    fn map_to_normal(&self, value: u8) -> Normal {
+        NoteRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> u8 {
+        NoteRange::unmap_to_value(self, normal)
+    }
+
+    fn snapped(&self, normal: Normal) -> Normal {
+        NoteRange::snapped(self, normal)
+    }
+
+    fn format(&self, value: &u8) -> String {
+        NoteRange::note_name(*value)
+    }
+}
+
+impl Range for TempoSyncRange {
+    type Value = TempoDivision;
+
+    fn map_to_normal(&self, value: TempoDivision) -> Normal {
+        TempoSyncRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> TempoDivision {
+        TempoSyncRange::unmap_to_value(self, normal)
+    }
+
+    fn snapped(&self, normal: Normal) -> Normal {
+        TempoSyncRange::snapped(self, normal)
+    }
+
+    fn format(&self, value: &TempoDivision) -> String {
+        value.label()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Param, RangedParam};
+    use crate::core::{FloatRange, IntRange, Normal};
+
+    #[test]
+    fn ranged_param() {
+        let param = RangedParam::new(FloatRange::default_bipolar(), 0.5, 0.0);
+        assert_eq!(param.normal_param().value.as_f32(), 0.75);
+        assert_eq!(param.normal_param().default, Normal::CENTER);
+        assert_eq!(param.unmap_to_value(Normal::MAX), 1.0);
+        assert_eq!(param.format(), "0.500");
+
+        let param = RangedParam::new(IntRange::new(0, 4), 1, 0);
+        assert_eq!(param.unmap_to_value(Normal::from_clipped(0.3)), 1);
+        assert_eq!(Param::format(&&param), "1");
+    }
+
+    #[test]
+    fn ranged_param_set_default() {
+        let mut param = RangedParam::new(FloatRange::default_bipolar(), 0.5, 0.0);
+        param.set_default(1.0);
+        assert_eq!(param.normal_param().default, Normal::MAX);
+        assert_eq!(param.normal_param().value.as_f32(), 0.75);
+    }
+}
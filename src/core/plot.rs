@@ -0,0 +1,204 @@
+//! Plot a response, such as the magnitude response of a filter, with the
+//! same scales as the widgets
+
+use crate::core::{FreqRange, LogDBRange};
+use iced::{widget::canvas::Path, Point, Rectangle};
+
+/// The width in pixels of the columns that the points of a response are
+/// decimated to.
+const COLUMN_WIDTH: f32 = 1.0;
+
+/// Maps `(frequency, magnitude_db)` points of a response to `bounds`, with
+/// the frequency from left to right through `freq_range` and the magnitude
+/// from bottom to top through `db_range`.
+///
+/// The points are expected to be sorted by frequency. Points that fall into
+/// the same pixel column are decimated to at most four: the first, the
+/// lowest, the highest, and the last one, so that a response with many more
+/// points than pixels keeps its peaks. Points that are not finite are left
+/// out, and points outside of a range are clamped to its ends.
+///
+/// # Example
+///
+/// ```
+/// use iced::{Point, Rectangle, Size};
+/// use iced_audio::{plot, FreqRange, LogDBRange, Normal};
+///
+/// // A flat response at 0 dB, which is at the center of the range.
+/// let response: Vec<(f32, f32)> = (0..1000)
+///     .map(|i| (20.0 + i as f32 * 20.0, 0.0))
+///     .collect();
+///
+/// let points = plot::response_points(
+///     &response,
+///     &FreqRange::default(),
+///     &LogDBRange::new(-24.0, 24.0, Normal::CENTER),
+///     Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0)),
+/// );
+///
+/// assert!(points.len() <= 4 * 101);
+/// assert!(points.iter().all(|point| point.y == 25.0));
+/// ```
+pub fn response_points(
+    points: &[(f32, f32)],
+    freq_range: &FreqRange,
+    db_range: &LogDBRange,
+    bounds: Rectangle,
+) -> Vec<Point> {
+    let mut plotted = Vec::new();
+    let mut column: Option<Column> = None;
+
+    for &(frequency, magnitude_db) in points {
+        if !frequency.is_finite() || !magnitude_db.is_finite() {
+            continue;
+        }
+
+        let point = Point::new(
+            bounds.x + freq_range.map_to_normal(frequency).scale(bounds.width),
+            bounds.y
+                + db_range
+                    .map_to_normal(magnitude_db)
+                    .scale_inv(bounds.height),
+        );
+        let index = ((point.x - bounds.x) / COLUMN_WIDTH).floor();
+
+        match column.as_mut() {
+            Some(column) if column.index == index => column.add(point),
+            _ => {
+                if let Some(column) = column.take() {
+                    column.flush(&mut plotted);
+                }
+
+                column = Some(Column::new(index, point));
+            }
+        }
+    }
+
+    if let Some(column) = column {
+        column.flush(&mut plotted);
+    }
+
+    plotted
+}
+
+/// Returns a [`Path`] through the `(frequency, magnitude_db)` points of a
+/// response, mapped to `bounds` as in [`response_points`], to stroke in a
+/// canvas `Frame`.
+///
+/// [`Path`]: https://docs.rs/iced/latest/iced/widget/canvas/struct.Path.html
+/// [`response_points`]: fn.response_points.html
+pub fn response_path(
+    points: &[(f32, f32)],
+    freq_range: &FreqRange,
+    db_range: &LogDBRange,
+    bounds: Rectangle,
+) -> Path {
+    let points = response_points(points, freq_range, db_range, bounds);
+
+    Path::new(|path| {
+        let mut points = points.iter();
+
+        if let Some(&first) = points.next() {
+            path.move_to(first);
+        }
+
+        for &point in points {
+            path.line_to(point);
+        }
+    })
+}
+
+/// The points of a response in one pixel column.
+struct Column {
+    index: f32,
+    first: Point,
+    lowest: (usize, Point),
+    highest: (usize, Point),
+    last: (usize, Point),
+}
+
+impl Column {
+    fn new(index: f32, point: Point) -> Self {
+        Self {
+            index,
+            first: point,
+            lowest: (0, point),
+            highest: (0, point),
+            last: (0, point),
+        }
+    }
+
+    fn add(&mut self, point: Point) {
+        let order = self.last.0 + 1;
+
+        // The y axis points down, so the lowest magnitude is the largest y.
+        if point.y > self.lowest.1.y {
+            self.lowest = (order, point);
+        }
+        if point.y < self.highest.1.y {
+            self.highest = (order, point);
+        }
+
+        self.last = (order, point);
+    }
+
+    /// Adds the points that are kept of this column to `plotted`, in the
+    /// order they came in.
+    fn flush(self, plotted: &mut Vec<Point>) {
+        let (low, high) = if self.lowest.0 <= self.highest.0 {
+            (self.lowest, self.highest)
+        } else {
+            (self.highest, self.lowest)
+        };
+
+        for point in [self.first, low.1, high.1, self.last.1] {
+            if plotted.last() != Some(&point) {
+                plotted.push(point);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::response_points;
+    use crate::core::{FreqRange, LogDBRange, Normal};
+    use iced::{Point, Rectangle, Size};
+
+    #[test]
+    fn decimates_to_columns_and_keeps_peaks() {
+        let freq_range = FreqRange::new(20.0, 20_000.0);
+        let db_range = LogDBRange::new(-12.0, 12.0, Normal::CENTER);
+        let bounds = Rectangle::new(Point::new(10.0, 20.0), Size::new(10.0, 100.0));
+
+        // A flat response with a narrow peak, with many points per column.
+        let response: Vec<(f32, f32)> = (0..=1000)
+            .map(|i| {
+                let frequency = freq_range.unmap_to_value(Normal::from_clipped(i as f32 / 1000.0));
+                let magnitude = if i == 503 { 12.0 } else { 0.0 };
+                (frequency, magnitude)
+            })
+            .collect();
+
+        let points = response_points(&response, &freq_range, &db_range, bounds);
+
+        assert!(points.len() <= 4 * 11);
+        assert_eq!(points.first().map(|point| point.y), Some(70.0));
+        assert!(points.iter().all(|point| (10.0..=20.0).contains(&point.x)));
+        assert!(points.iter().any(|point| point.y == 20.0));
+        assert!(points.windows(2).all(|pair| pair[0].x <= pair[1].x));
+    }
+
+    #[test]
+    fn skips_points_that_are_not_finite() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let points = response_points(
+            &[(100.0, f32::NAN), (1000.0, 0.0), (f32::INFINITY, 0.0)],
+            &FreqRange::default(),
+            &LogDBRange::default(),
+            bounds,
+        );
+
+        assert_eq!(points.len(), 1);
+    }
+}
@@ -0,0 +1,191 @@
+//! Utilities for capturing and morphing between presets of parameters
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::core::{Normal, NormalParam};
+
+/// A collection of parameters that can be addressed by an `ID`.
+///
+/// This is what a [`ParamSnapshot`] is applied to. It is implemented for
+/// a `HashMap<ID, NormalParam>`, but it can be implemented for any type
+/// that holds the parameters of an application.
+///
+/// [`ParamSnapshot`]: struct.ParamSnapshot.html
+pub trait ParamBank<ID> {
+    /// Sets the [`Normal`] value of the parameter with the given `id`.
+    ///
+    /// Parameters that don't exist in the bank should be ignored.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn set_normal(&mut self, id: &ID, normal: Normal);
+}
+
+impl<ID: Eq + Hash> ParamBank<ID> for HashMap<ID, NormalParam> {
+    fn set_normal(&mut self, id: &ID, normal: Normal) {
+        if let Some(param) = self.get_mut(id) {
+            param.update(normal);
+        }
+    }
+}
+
+/// A snapshot of the [`Normal`] values of a set of parameters, such as the
+/// state of a preset.
+///
+/// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParamSnapshot<ID: Eq + Hash> {
+    values: HashMap<ID, Normal>,
+}
+
+impl<ID: Eq + Hash> Default for ParamSnapshot<ID> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl<ID: Eq + Hash + Clone> ParamSnapshot<ID> {
+    /// Creates a new empty `ParamSnapshot`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`Normal`] value of the parameter with the given `id`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn insert(&mut self, id: ID, normal: Normal) {
+        self.values.insert(id, normal);
+    }
+
+    /// Returns the [`Normal`] value of the parameter with the given `id`,
+    /// if it is part of the snapshot.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn get(&self, id: &ID) -> Option<Normal> {
+        self.values.get(id).copied()
+    }
+
+    /// Returns the number of parameters in the snapshot.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the snapshot contains no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// An iterator over the parameters in the snapshot, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&ID, Normal)> {
+        self.values.iter().map(|(id, normal)| (id, *normal))
+    }
+
+    /// Linearly interpolates between the snapshots `a` and `b`.
+    ///
+    /// * `t` - the position of the morph. `Normal::MIN` returns the values
+    ///   of `a`, and `Normal::MAX` returns the values of `b`.
+    ///
+    /// Parameters that only exist in one of the snapshots keep their value.
+    pub fn lerp(a: &Self, b: &Self, t: Normal) -> Self {
+        let t = t.as_f32();
+
+        let mut values = a.values.clone();
+
+        for (id, b_normal) in b.values.iter() {
+            let normal = match a.values.get(id) {
+                Some(a_normal) => Normal::from_clipped(
+                    a_normal.as_f32() + ((b_normal.as_f32() - a_normal.as_f32()) * t),
+                ),
+                None => *b_normal,
+            };
+
+            values.insert(id.clone(), normal);
+        }
+
+        Self { values }
+    }
+
+    /// Sets the values of this snapshot to the parameters in the `bank`.
+    pub fn apply_to<B: ParamBank<ID>>(&self, bank: &mut B) {
+        for (id, normal) in self.values.iter() {
+            bank.set_normal(id, *normal);
+        }
+    }
+}
+
+impl<ID: Eq + Hash> FromIterator<(ID, Normal)> for ParamSnapshot<ID> {
+    fn from_iter<T: IntoIterator<Item = (ID, Normal)>>(iter: T) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a, ID: Eq + Hash + Clone + 'a> FromIterator<(&'a ID, &'a NormalParam)> for ParamSnapshot<ID> {
+    fn from_iter<T: IntoIterator<Item = (&'a ID, &'a NormalParam)>>(iter: T) -> Self {
+        Self {
+            values: iter
+                .into_iter()
+                .map(|(id, param)| (id.clone(), param.value))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::ParamSnapshot;
+    use crate::core::{Normal, NormalParam};
+
+    #[test]
+    fn lerp() {
+        let a: ParamSnapshot<u32> = [(0, Normal::MIN), (1, Normal::MAX), (2, Normal::CENTER)]
+            .into_iter()
+            .collect();
+        let b: ParamSnapshot<u32> = [(0, Normal::MAX), (1, Normal::MIN), (3, Normal::MAX)]
+            .into_iter()
+            .collect();
+
+        let start = ParamSnapshot::lerp(&a, &b, Normal::MIN);
+        assert_eq!(start.get(&0), Some(Normal::MIN));
+        assert_eq!(start.get(&1), Some(Normal::MAX));
+
+        let end = ParamSnapshot::lerp(&a, &b, Normal::MAX);
+        assert_eq!(end.get(&0), Some(Normal::MAX));
+        assert_eq!(end.get(&1), Some(Normal::MIN));
+
+        let mid = ParamSnapshot::lerp(&a, &b, Normal::CENTER);
+        assert_eq!(mid.get(&0), Some(Normal::CENTER));
+        assert_eq!(mid.get(&1), Some(Normal::CENTER));
+        assert_eq!(mid.get(&2), Some(Normal::CENTER));
+        assert_eq!(mid.get(&3), Some(Normal::MAX));
+        assert_eq!(mid.len(), 4);
+    }
+
+    #[test]
+    fn apply_to() {
+        let mut bank: HashMap<&str, NormalParam> = HashMap::new();
+        bank.insert("cutoff", NormalParam::default());
+        bank.insert("resonance", NormalParam::default());
+
+        let mut snapshot = ParamSnapshot::new();
+        snapshot.insert("cutoff", Normal::MAX);
+        snapshot.insert("unknown", Normal::MAX);
+
+        snapshot.apply_to(&mut bank);
+
+        assert_eq!(bank["cutoff"].value, Normal::MAX);
+        assert_eq!(bank["cutoff"].default, Normal::MIN);
+        assert_eq!(bank["resonance"].value, Normal::MIN);
+        assert!(!bank.contains_key("unknown"));
+
+        let captured: ParamSnapshot<&str> = bank.iter().collect();
+        assert_eq!(captured.get(&"cutoff"), Some(Normal::MAX));
+        assert_eq!(captured.get(&"resonance"), Some(Normal::MIN));
+    }
+}
@@ -3,6 +3,7 @@
 /// [`Normal`]: ../struct.Normal.html
 use crate::core::Normal;
 use crate::core::normal_param::NormalParam;
+use crate::core::{text_marks, tick_marks};
 
 use std::fmt::Debug;
 
@@ -11,11 +12,19 @@ use std::fmt::Debug;
 ///
 /// [`Normal`]: ../struct.Normal.html
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "serde_repr::FloatRangeRepr",
+        try_from = "serde_repr::FloatRangeRepr"
+    )
+)]
 pub struct FloatRange {
     min: f32,
     max: f32,
-    span: f32,
-    span_recip: f32,
+    span: f64,
+    span_recip: f64,
 }
 
 impl FloatRange {
@@ -29,10 +38,10 @@ impl FloatRange {
     /// # Panics
     ///
     /// This will panic if `max` <= `min`
-    pub fn new(min: f32, max: f32) -> Self {
+    pub const fn new(min: f32, max: f32) -> Self {
         assert!(max > min);
 
-        let span = max - min;
+        let span = max as f64 - min as f64;
         let span_recip = span.recip();
 
         Self {
@@ -47,11 +56,11 @@ impl FloatRange {
     ///
     /// * `min` = -1.0
     /// * `max` = 1.0
-    pub fn default_bipolar() -> Self {
+    pub const fn default_bipolar() -> Self {
         FloatRange::new(-1.0, 1.0)
     }
 
-    fn constrain(&self, value: f32) -> f32 {
+    const fn constrain(&self, value: f32) -> f32 {
         if value <= self.min {
             self.min
         } else if value >= self.max {
@@ -68,7 +77,7 @@ impl FloatRange {
     ///
     /// * `value` - The inital value of the parameter.
     /// * `default_value` - The default value of the parameter.
-    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+    pub const fn normal_param(&self, value: f32, default: f32) -> NormalParam {
         NormalParam {
             value: self.map_to_normal(value),
             default: self.map_to_normal(default),
@@ -79,7 +88,7 @@ impl FloatRange {
     /// from this range where `value` and `default_value` is `0.0`.
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
-    pub fn default_normal_param(&self) -> NormalParam {
+    pub const fn default_normal_param(&self) -> NormalParam {
         NormalParam {
             value: self.map_to_normal(0.0),
             default: self.map_to_normal(0.0),
@@ -89,16 +98,16 @@ impl FloatRange {
     /// Returns the corresponding [`Normal`] from the supplied value
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn map_to_normal(&self, value: f32) -> Normal {
+    pub const fn map_to_normal(&self, value: f32) -> Normal {
         let value = self.constrain(value);
-        Normal::from_clipped((value - self.min) * self.span_recip)
+        Normal::from_f64((value as f64 - self.min as f64) * self.span_recip)
     }
 
     /// Returns the corresponding value from the supplied [`Normal`]
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
-        (normal.as_f32() * self.span) + self.min
+    pub const fn unmap_to_value(&self, normal: Normal) -> f32 {
+        ((normal.as_f64() * self.span) + self.min as f64) as f32
     }
 }
 
@@ -110,11 +119,19 @@ impl Default for FloatRange {
 
 /// A range that defines a discrete linear range of i32 values
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "serde_repr::IntRangeRepr",
+        try_from = "serde_repr::IntRangeRepr"
+    )
+)]
 pub struct IntRange {
     min: i32,
     max: i32,
-    span: f32,
-    span_recip: f32,
+    span: f64,
+    span_recip: f64,
 }
 
 impl IntRange {
@@ -128,10 +145,10 @@ impl IntRange {
     /// # Panics
     ///
     /// This will panic if `max` <= `min`
-    pub fn new(min: i32, max: i32) -> Self {
+    pub const fn new(min: i32, max: i32) -> Self {
         assert!(max > min);
 
-        let span = (max - min) as f32;
+        let span = (max - min) as f64;
         let span_recip = span.recip();
 
         Self {
@@ -142,7 +159,7 @@ impl IntRange {
         }
     }
 
-    fn constrain(&self, value: i32) -> i32 {
+    const fn constrain(&self, value: i32) -> i32 {
         if value <= self.min {
             self.min
         } else if value >= self.max {
@@ -159,7 +176,7 @@ impl IntRange {
     ///
     /// * `value` - The inital value of the parameter.
     /// * `default_value` - The default value of the parameter.
-    pub fn normal_param(&self, value: i32, default: i32) -> NormalParam {
+    pub const fn normal_param(&self, value: i32, default: i32) -> NormalParam {
         NormalParam {
             value: self.map_to_normal(value),
             default: self.map_to_normal(default),
@@ -170,7 +187,7 @@ impl IntRange {
     /// from this range where `value` and `default_value` is `0`.
     ///
     /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
-    pub fn default_normal_param(&self) -> NormalParam {
+    pub const fn default_normal_param(&self) -> NormalParam {
         NormalParam {
             value: self.map_to_normal(0),
             default: self.map_to_normal(0),
@@ -181,7 +198,7 @@ impl IntRange {
     /// value in this range.
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn snapped(&self, normal: Normal) -> Normal {
+    pub const fn snapped(&self, normal: Normal) -> Normal {
         let value_int = self.unmap_to_value(normal);
         self.map_to_normal(value_int)
     }
@@ -189,16 +206,16 @@ impl IntRange {
     /// Returns the corresponding [`Normal`] from the supplied value
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn map_to_normal(&self, value: i32) -> Normal {
+    pub const fn map_to_normal(&self, value: i32) -> Normal {
         let value = self.constrain(value);
-        Normal::from_clipped((value - self.min) as f32 * self.span_recip)
+        Normal::from_f64((value - self.min) as f64 * self.span_recip)
     }
 
     /// Returns the corresponding value from the supplied [`Normal`]
     ///
     /// [`Normal`]: ../struct.Normal.html
-    pub fn unmap_to_value(&self, normal: Normal) -> i32 {
-        (normal.as_f32() * self.span).round() as i32 + self.min
+    pub const fn unmap_to_value(&self, normal: Normal) -> i32 {
+        (normal.as_f64() * self.span).round() as i32 + self.min
     }
 }
 
@@ -214,14 +231,22 @@ impl Default for IntRange {
 /// Values around 0 dB (positive and negative) will increment slower per
 /// slider movement than values farther away from 0 dB.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "serde_repr::LogDBRangeRepr",
+        try_from = "serde_repr::LogDBRangeRepr"
+    )
+)]
 pub struct LogDBRange {
     min: f32,
     max: f32,
     zero_position: Normal,
-    min_recip: f32,
-    max_recip: f32,
-    zero_pos_recip: f32,
-    one_min_zero_pos_recip: f32,
+    min_recip: f64,
+    max_recip: f64,
+    zero_pos_recip: f64,
+    one_min_zero_pos_recip: f64,
 }
 
 impl LogDBRange {
@@ -248,20 +273,28 @@ impl LogDBRange {
         assert!(max >= 0.0, "max must be 0.0 or positive");
         assert!(min <= 0.0, "min must be 0.0 or negative");
 
-        let min_recip = if min == 0.0 { 0.0 } else { 1.0 / min };
+        let min_recip = if min == 0.0 {
+            0.0
+        } else {
+            1.0 / f64::from(min)
+        };
 
-        let max_recip = if max == 0.0 { 0.0 } else { 1.0 / max };
+        let max_recip = if max == 0.0 {
+            0.0
+        } else {
+            1.0 / f64::from(max)
+        };
 
-        let zero_pos_recip = if zero_position.as_f32() == 0.0 {
+        let zero_pos_recip = if zero_position == Normal::MIN {
             0.0
         } else {
-            1.0 / zero_position.as_f32()
+            1.0 / zero_position.as_f64()
         };
 
-        let one_min_zero_pos_recip = if zero_position.as_f32() == 0.0 {
+        let one_min_zero_pos_recip = if zero_position == Normal::MAX {
             0.0
         } else {
-            1.0 / (1.0 - zero_position.as_f32())
+            1.0 / (1.0 - zero_position.as_f64())
         };
 
         Self {
@@ -321,21 +354,21 @@ impl LogDBRange {
             if self.min >= 0.0 {
                 return Normal::MIN;
             }
-            let neg_normal = value * self.min_recip;
+            let neg_normal = f64::from(value) * self.min_recip;
 
             let log_normal = 1.0 - neg_normal.sqrt();
 
-            Normal::from_clipped(log_normal * self.zero_position.as_f32())
+            Normal::from_f64(log_normal * self.zero_position.as_f64())
         } else {
             if self.max <= 0.0 {
                 return Normal::MAX;
             }
-            let pos_normal = value * self.max_recip;
+            let pos_normal = f64::from(value) * self.max_recip;
 
             let log_normal = pos_normal.sqrt();
 
-            Normal::from_clipped(
-                (log_normal * (1.0 - self.zero_position.as_f32())) + self.zero_position.as_f32(),
+            Normal::from_f64(
+                (log_normal * (1.0 - self.zero_position.as_f64())) + self.zero_position.as_f64(),
             )
         }
     }
@@ -350,21 +383,21 @@ impl LogDBRange {
             if self.min >= 0.0 {
                 return self.min;
             }
-            let neg_normal = 1.0 - (normal.as_f32() * self.zero_pos_recip);
+            let neg_normal = 1.0 - (normal.as_f64() * self.zero_pos_recip);
 
             let log_normal = 1.0 - (neg_normal * neg_normal);
 
-            (1.0 - log_normal) * self.min
+            ((1.0 - log_normal) * f64::from(self.min)) as f32
         } else {
-            if self.zero_position.as_f32() == 1.0 || self.max <= 0.0 {
+            if self.zero_position == Normal::MAX || self.max <= 0.0 {
                 return self.max;
             }
             let pos_normal =
-                (normal.as_f32() - self.zero_position.as_f32()) * self.one_min_zero_pos_recip;
+                (normal.as_f64() - self.zero_position.as_f64()) * self.one_min_zero_pos_recip;
 
             let log_normal = pos_normal * pos_normal;
 
-            log_normal * self.max
+            (log_normal * f64::from(self.max)) as f32
         }
     }
 }
@@ -375,18 +408,661 @@ impl Default for LogDBRange {
     }
 }
 
+/// The taper of a [`FaderRange`] below 0 dB.
+///
+/// [`FaderRange`]: struct.FaderRange.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FaderTaper {
+    /// The scale of IEC 60268-18. Each 10 dB segment below -20 dB takes up
+    /// less travel than the one above it, so the lower portion of the fader is
+    /// increasingly compressed.
+    #[default]
+    Iec60268,
+    /// A scale with a constant amount of travel per dB, as used by the
+    /// K-System meter scales.
+    LinearDB,
+}
+
+/// The breakpoints of the IEC 60268-18 scale as `(dB, deflection)`.
+static IEC_60268_TAPER: [(f64, f64); 7] = [
+    (-70.0, 0.0),
+    (-60.0, 0.025),
+    (-50.0, 0.075),
+    (-40.0, 0.15),
+    (-30.0, 0.3),
+    (-20.0, 0.5),
+    (0.0, 1.0),
+];
+
+impl FaderTaper {
+    /// Returns the deflection of the scale at the given (negative) dB value.
+    /// Values outside of the scale are extrapolated from the outer segments.
+    fn deflection(&self, db: f64) -> f64 {
+        match self {
+            FaderTaper::Iec60268 => {
+                let i = IEC_60268_TAPER[1..IEC_60268_TAPER.len() - 1]
+                    .iter()
+                    .take_while(|(point_db, _)| db >= *point_db)
+                    .count();
+                let (db_0, d_0) = IEC_60268_TAPER[i];
+                let (db_1, d_1) = IEC_60268_TAPER[i + 1];
+
+                d_0 + ((db - db_0) * (d_1 - d_0) / (db_1 - db_0))
+            }
+            FaderTaper::LinearDB => db,
+        }
+    }
+
+    /// Returns the (negative) dB value at the given deflection of the scale.
+    fn db(&self, deflection: f64) -> f64 {
+        match self {
+            FaderTaper::Iec60268 => {
+                let i = IEC_60268_TAPER[1..IEC_60268_TAPER.len() - 1]
+                    .iter()
+                    .take_while(|(_, point_d)| deflection >= *point_d)
+                    .count();
+                let (db_0, d_0) = IEC_60268_TAPER[i];
+                let (db_1, d_1) = IEC_60268_TAPER[i + 1];
+
+                db_0 + ((deflection - d_0) * (db_1 - db_0) / (d_1 - d_0))
+            }
+            FaderTaper::LinearDB => deflection,
+        }
+    }
+
+    /// Returns the dB value at the bottom of the scale, which fader legends
+    /// mark as `-inf`, or `None` if the scale has no bottom.
+    fn bottom(&self) -> Option<f64> {
+        match self {
+            FaderTaper::Iec60268 => Some(IEC_60268_TAPER[0].0),
+            FaderTaper::LinearDB => None,
+        }
+    }
+}
+
+/// A range of `dB` values with the taper of a mixing console fader.
+///
+/// 0 dB sits at a configurable position. Values above 0 dB are spaced
+/// linearly, and values below 0 dB follow the given [`FaderTaper`].
+///
+/// [`FaderTaper`]: enum.FaderTaper.html
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "serde_repr::FaderRangeRepr",
+        try_from = "serde_repr::FaderRangeRepr"
+    )
+)]
+pub struct FaderRange {
+    min: f32,
+    max: f32,
+    zero_position: Normal,
+    taper: FaderTaper,
+    min_deflection: f64,
+    zero_deflection: f64,
+}
+
+impl FaderRange {
+    /// Creates a new `FaderRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range in dB (inclusive), must be <= 0.0
+    /// * `max` - the maximum of the range in dB (inclusive), must be >= 0.0
+    /// * `zero_position` - a normal that defines where on the fader 0 decibels
+    ///   should be. A typical console fader has 0 dB at about `0.75`
+    /// * `taper` - the taper used below 0 dB
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `min` > `0.0`
+    /// * `max` < `0.0`
+    ///
+    pub fn new(min: f32, max: f32, zero_position: Normal, taper: FaderTaper) -> Self {
+        assert!(max > min, "max must be greater than min");
+        assert!(max >= 0.0, "max must be 0.0 or positive");
+        assert!(min <= 0.0, "min must be 0.0 or negative");
+
+        Self {
+            min,
+            max,
+            zero_position,
+            taper,
+            min_deflection: taper.deflection(f64::from(min)),
+            zero_deflection: taper.deflection(0.0),
+        }
+    }
+
+    fn constrain(&self, value: f32) -> f32 {
+        if value <= self.min {
+            self.min
+        } else if value >= self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `0.0`.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(0.0),
+            default: self.map_to_normal(0.0),
+        }
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied `value`
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        let value = self.constrain(value);
+        if value == 0.0 {
+            self.zero_position
+        } else if value < 0.0 {
+            let deflection = (self.taper.deflection(f64::from(value)) - self.min_deflection)
+                / (self.zero_deflection - self.min_deflection);
+
+            Normal::from_f64(deflection * self.zero_position.as_f64())
+        } else {
+            Normal::from_f64(
+                ((f64::from(value) / f64::from(self.max)) * (1.0 - self.zero_position.as_f64()))
+                    + self.zero_position.as_f64(),
+            )
+        }
+    }
+
+    /// Returns the corresponding dB value from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        if normal == self.zero_position {
+            0.0
+        } else if normal < self.zero_position {
+            let deflection = (normal.as_f64() / self.zero_position.as_f64())
+                * (self.zero_deflection - self.min_deflection)
+                + self.min_deflection;
+
+            self.constrain(self.taper.db(deflection) as f32)
+        } else {
+            if self.zero_position == Normal::MAX {
+                return self.max;
+            }
+
+            (((normal.as_f64() - self.zero_position.as_f64())
+                / (1.0 - self.zero_position.as_f64()))
+                * f64::from(self.max)) as f32
+        }
+    }
+
+    /// The dB values of the legend of a typical console fader that lie within
+    /// this range.
+    fn legend(&self) -> impl Iterator<Item = f32> + '_ {
+        [
+            10.0, 5.0, 0.0, -5.0, -10.0, -20.0, -30.0, -40.0, -50.0, -60.0,
+        ]
+        .into_iter()
+        .filter(|db| *db <= self.max && *db > self.min)
+    }
+
+    /// Returns a [`tick_marks::Group`] with tick marks at the minimum, the
+    /// maximum, and the positions of the legend of a typical console fader.
+    ///
+    /// [`tick_marks::Group`]: ../tick_marks/struct.Group.html
+    pub fn tick_marks(&self) -> tick_marks::Group {
+        let mut tick_marks = vec![(Normal::MIN, tick_marks::Tier::Two)];
+
+        if !self.legend().any(|db| db == self.max) {
+            tick_marks.push((Normal::MAX, tick_marks::Tier::Two));
+        }
+
+        tick_marks.extend(self.legend().map(|db| {
+            let tier = if db == 0.0 {
+                tick_marks::Tier::One
+            } else if db % 10.0 == 0.0 {
+                tick_marks::Tier::Two
+            } else {
+                tick_marks::Tier::Three
+            };
+
+            (self.map_to_normal(db), tier)
+        }));
+
+        tick_marks.into()
+    }
+
+    /// Returns a [`text_marks::Group`] labeling the positions of the
+    /// legend of a typical console fader and the minimum. The minimum is
+    /// labeled as `-inf` if it reaches the bottom of the [`FaderTaper`].
+    ///
+    /// [`text_marks::Group`]: ../text_marks/struct.Group.html
+    /// [`FaderTaper`]: enum.FaderTaper.html
+    pub fn text_marks(&self) -> text_marks::Group {
+        let label = |db: f32| {
+            if db > 0.0 {
+                format!("+{db}")
+            } else {
+                format!("{db}")
+            }
+        };
+
+        let min = match self.taper.bottom() {
+            Some(bottom) if f64::from(self.min) <= bottom => String::from("-inf"),
+            _ => label(self.min),
+        };
+        let mut text_marks = vec![(Normal::MIN, min)];
+
+        text_marks.extend(self.legend().map(|db| (self.map_to_normal(db), label(db))));
+
+        text_marks.into()
+    }
+}
+
+impl Default for FaderRange {
+    fn default() -> Self {
+        FaderRange::new(
+            -70.0,
+            10.0,
+            Normal::from_clipped(0.75),
+            FaderTaper::Iec60268,
+        )
+    }
+}
+
+/// The names of the 12 semitones of an octave, starting from C.
+static NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// A range that defines a discrete range of MIDI note numbers
+/// (`0` to `127`).
+///
+/// Notes are named with middle C (note number `60`) as "C3".
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "serde_repr::NoteRangeRepr",
+        try_from = "serde_repr::NoteRangeRepr"
+    )
+)]
+pub struct NoteRange {
+    range: IntRange,
+}
+
+impl NoteRange {
+    /// Creates a new `NoteRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum note number of the range (inclusive)
+    /// * `max` - the maximum note number of the range (inclusive)
+    ///
+    /// # Panics
+    ///
+    /// This will panic if
+    /// * `max` <= `min`
+    /// * `max` > `127`
+    pub fn new(min: u8, max: u8) -> Self {
+        assert!(max > min, "max must be greater than min");
+        assert!(max <= 127, "max must be a valid MIDI note number");
+
+        Self {
+            range: IntRange::new(i32::from(min), i32::from(max)),
+        }
+    }
+
+    /// Returns the name of the given MIDI note number, e.g. "C#3".
+    ///
+    /// Middle C (note number `60`) is named "C3".
+    pub fn note_name(note: u8) -> String {
+        let octave = i32::from(note / 12) - 2;
+        format!("{}{}", NOTE_NAMES[usize::from(note % 12)], octave)
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital note number of the parameter.
+    /// * `default_value` - The default note number of the parameter.
+    pub fn normal_param(&self, value: u8, default: u8) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is middle C (`60`).
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        self.normal_param(60, 60)
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest note
+    /// in this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snapped(&self, normal: Normal) -> Normal {
+        self.range.snapped(normal)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied note number
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, note: u8) -> Normal {
+        self.range.map_to_normal(i32::from(note))
+    }
+
+    /// Returns the corresponding note number from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> u8 {
+        self.range.unmap_to_value(normal) as u8
+    }
+
+    /// Returns the name of the note at the supplied [`Normal`], e.g. "C#3".
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_note_name(&self, normal: Normal) -> String {
+        Self::note_name(self.unmap_to_value(normal))
+    }
+
+    fn c_notes(&self) -> impl Iterator<Item = u8> {
+        (self.range.min..=self.range.max)
+            .filter(|note| note % 12 == 0)
+            .map(|note| note as u8)
+    }
+
+    /// Returns a [`tick_marks::Group`] with a tick mark at every C in this
+    /// range.
+    ///
+    /// [`tick_marks::Group`]: ../tick_marks/struct.Group.html
+    pub fn tick_marks(&self) -> tick_marks::Group {
+        self.c_notes()
+            .map(|note| (self.map_to_normal(note), tick_marks::Tier::Two))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Returns a [`text_marks::Group`] labeling every C in this range.
+    ///
+    /// [`text_marks::Group`]: ../text_marks/struct.Group.html
+    pub fn text_marks(&self) -> text_marks::Group {
+        self.c_notes()
+            .map(|note| (self.map_to_normal(note), Self::note_name(note)))
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+impl Default for NoteRange {
+    fn default() -> Self {
+        NoteRange::new(0, 127)
+    }
+}
+
+/// The feel of a [`TempoDivision`].
+///
+/// [`TempoDivision`]: struct.TempoDivision.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TempoFeel {
+    /// A straight note
+    #[default]
+    Straight,
+    /// A triplet note (2/3 of the length of a straight note)
+    Triplet,
+    /// A dotted note (3/2 of the length of a straight note)
+    Dotted,
+}
+
+/// A note length that is synced to the tempo, e.g. `1/4` or `1/8T`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TempoDivision {
+    /// The fraction of a whole note, e.g. `4` for a quarter note.
+    pub denominator: u32,
+    /// Whether the note is straight, triplet, or dotted.
+    pub feel: TempoFeel,
+}
+
+impl TempoDivision {
+    /// Creates a new `TempoDivision`
+    ///
+    /// * `denominator` - the fraction of a whole note, e.g. `4` for a quarter note
+    /// * `feel` - whether the note is straight, triplet, or dotted
+    pub const fn new(denominator: u32, feel: TempoFeel) -> Self {
+        Self { denominator, feel }
+    }
+
+    /// Returns the length of the division in quarter notes (beats).
+    pub fn beats(&self) -> f32 {
+        let straight = 4.0 / self.denominator as f32;
+
+        match self.feel {
+            TempoFeel::Straight => straight,
+            TempoFeel::Triplet => straight * (2.0 / 3.0),
+            TempoFeel::Dotted => straight * 1.5,
+        }
+    }
+
+    /// Returns the length of the division in seconds at the given tempo.
+    pub fn seconds(&self, bpm: f32) -> f32 {
+        self.beats() * 60.0 / bpm
+    }
+
+    /// Returns the label of the division, e.g. "1/4", "1/8T", or "1/16D".
+    pub fn label(&self) -> String {
+        let suffix = match self.feel {
+            TempoFeel::Straight => "",
+            TempoFeel::Triplet => "T",
+            TempoFeel::Dotted => "D",
+        };
+
+        format!("1/{}{}", self.denominator, suffix)
+    }
+}
+
+/// A range that defines a discrete range of [`TempoDivision`]s.
+///
+/// [`TempoDivision`]: struct.TempoDivision.html
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "serde_repr::TempoSyncRangeRepr",
+        try_from = "serde_repr::TempoSyncRangeRepr"
+    )
+)]
+pub struct TempoSyncRange {
+    divisions: Vec<TempoDivision>,
+    range: IntRange,
+}
+
+impl TempoSyncRange {
+    /// Creates a new `TempoSyncRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `divisions` - the divisions in the order they appear on the
+    ///   widget, from `Normal::MIN` to `Normal::MAX`
+    ///
+    /// # Panics
+    ///
+    /// This will panic if there are less than two `divisions`
+    pub fn new(divisions: Vec<TempoDivision>) -> Self {
+        assert!(divisions.len() >= 2, "at least two divisions are required");
+
+        let range = IntRange::new(0, divisions.len() as i32 - 1);
+
+        Self { divisions, range }
+    }
+
+    /// Returns the divisions of this range.
+    pub fn divisions(&self) -> &[TempoDivision] {
+        &self.divisions
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital division of the parameter.
+    /// * `default_value` - The default division of the parameter.
+    pub fn normal_param(&self, value: TempoDivision, default: TempoDivision) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is `1/4`
+    /// (or the closest division to it).
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        let quarter = TempoDivision::new(4, TempoFeel::Straight);
+        self.normal_param(quarter, quarter)
+    }
+
+    /// Returns a [`Normal`] that is snapped to the closest division
+    /// in this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snapped(&self, normal: Normal) -> Normal {
+        self.range.snapped(normal)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied division. If the
+    /// division is not part of this range, the division closest in length is
+    /// used.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, division: TempoDivision) -> Normal {
+        let index = self
+            .divisions
+            .iter()
+            .position(|d| *d == division)
+            .unwrap_or_else(|| {
+                let beats = division.beats();
+                let mut closest = 0;
+                for (i, d) in self.divisions.iter().enumerate() {
+                    if (d.beats() - beats).abs() < (self.divisions[closest].beats() - beats).abs() {
+                        closest = i;
+                    }
+                }
+                closest
+            });
+
+        self.range.map_to_normal(index as i32)
+    }
+
+    /// Returns the corresponding division from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> TempoDivision {
+        self.divisions[self.range.unmap_to_value(normal) as usize]
+    }
+
+    /// Returns a [`tick_marks::Group`] with a tick mark at every division in
+    /// this range, with larger tick marks at the straight divisions.
+    ///
+    /// [`tick_marks::Group`]: ../tick_marks/struct.Group.html
+    pub fn tick_marks(&self) -> tick_marks::Group {
+        self.divisions
+            .iter()
+            .enumerate()
+            .map(|(i, d)| {
+                let tier = if d.feel == TempoFeel::Straight {
+                    tick_marks::Tier::Two
+                } else {
+                    tick_marks::Tier::Three
+                };
+
+                (self.range.map_to_normal(i as i32), tier)
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Returns a [`text_marks::Group`] labeling every straight division in
+    /// this range.
+    ///
+    /// [`text_marks::Group`]: ../text_marks/struct.Group.html
+    pub fn text_marks(&self) -> text_marks::Group {
+        self.divisions
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.feel == TempoFeel::Straight)
+            .map(|(i, d)| (self.range.map_to_normal(i as i32), d.label()))
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+impl Default for TempoSyncRange {
+    /// The triplet, straight, and dotted divisions from `1/32T` to `1/1D`.
+    fn default() -> Self {
+        let mut divisions = Vec::with_capacity(18);
+        for denominator in [32, 16, 8, 4, 2, 1] {
+            for feel in [TempoFeel::Triplet, TempoFeel::Straight, TempoFeel::Dotted] {
+                divisions.push(TempoDivision::new(denominator, feel));
+            }
+        }
+
+        TempoSyncRange::new(divisions)
+    }
+}
+
 /// A [`NormalParam`] that defines a continuous logarithmic range of `f32` frequency
 /// values, with each octave in the 10 octave spectrum spaced evenly.
 ///
 /// Smaller frequencies will increment slower per slider movement than larger
 /// ones.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "serde_repr::FreqRangeRepr",
+        try_from = "serde_repr::FreqRangeRepr"
+    )
+)]
 pub struct FreqRange {
     min: f32,
     max: f32,
-    spectrum_normal_span: f32,
-    spectrum_normal_span_recip: f32,
-    min_spectrum_normal: Normal,
+    spectrum_normal_span: f64,
+    spectrum_normal_span_recip: f64,
+    min_spectrum_normal: f64,
 }
 
 impl FreqRange {
@@ -417,10 +1093,10 @@ impl FreqRange {
             max = 20480.0;
         }
 
-        let min_spectrum_normal = octave_spectrum_map_to_normal(min);
-        let max_spectrum_normal = octave_spectrum_map_to_normal(max);
+        let min_spectrum_normal = octave_spectrum_map_to_normal(f64::from(min));
+        let max_spectrum_normal = octave_spectrum_map_to_normal(f64::from(max));
 
-        let spectrum_normal_span = max_spectrum_normal.as_f32() - min_spectrum_normal.as_f32();
+        let spectrum_normal_span = max_spectrum_normal - min_spectrum_normal;
 
         let spectrum_normal_span_recip = 1.0 / spectrum_normal_span;
 
@@ -473,10 +1149,9 @@ impl FreqRange {
     /// [`Normal`]: ../struct.Normal.html
     pub fn map_to_normal(&self, value: f32) -> Normal {
         let value = self.constrain(value);
-        let spectrum_normal = octave_spectrum_map_to_normal(value);
-        Normal::from_clipped(
-            (spectrum_normal.as_f32() - self.min_spectrum_normal.as_f32())
-                * self.spectrum_normal_span_recip,
+        let spectrum_normal = octave_spectrum_map_to_normal(f64::from(value));
+        Normal::from_f64(
+            (spectrum_normal - self.min_spectrum_normal) * self.spectrum_normal_span_recip,
         )
     }
 
@@ -484,11 +1159,11 @@ impl FreqRange {
     ///
     /// [`Normal`]: ../struct.Normal.html
     pub fn unmap_to_value(&self, normal: Normal) -> f32 {
-        let spectrum_normal = Normal::from_clipped(
-            normal.as_f32() * self.spectrum_normal_span + self.min_spectrum_normal.as_f32(),
-        );
+        let spectrum_normal = (normal.as_f64() * self.spectrum_normal_span
+            + self.min_spectrum_normal)
+            .clamp(0.0, 1.0);
 
-        octave_normal_to_spectrum(spectrum_normal)
+        octave_normal_to_spectrum(spectrum_normal) as f32
     }
 }
 
@@ -499,17 +1174,673 @@ impl Default for FreqRange {
 }
 
 /// Returns the corresponding frequency for the whole 10 octave spectrum
-/// (between 20 Hz and 20480 Hz)
+/// (between 20 Hz and 20480 Hz) from a normal between `0.0` and `1.0`
 #[inline]
-fn octave_normal_to_spectrum(value: Normal) -> f32 {
-    40.0 * 2.0_f32.powf((10.0 * value.as_f32()) - 1.0)
+fn octave_normal_to_spectrum(value: f64) -> f64 {
+    40.0 * 2.0_f64.powf((10.0 * value) - 1.0)
 }
 
-/// Returns the corresponding [`Normal`] for a frequency in the whole
-/// 10 octave spectrum (between 20 Hz and 20480 Hz)
-///
-/// [`Normal`]: ../struct.Normal.html
+/// Returns the corresponding normal between `0.0` and `1.0` for a frequency
+/// in the whole 10 octave spectrum (between 20 Hz and 20480 Hz)
 #[inline]
-fn octave_spectrum_map_to_normal(freq: f32) -> Normal {
-    Normal::from_clipped(((freq / 40.0).log2() + 1.0) * 0.1)
+fn octave_spectrum_map_to_normal(freq: f64) -> f64 {
+    (((freq / 40.0).log2() + 1.0) * 0.1).clamp(0.0, 1.0)
+}
+
+/// Ranges are serialized by the arguments of their constructors only, the
+/// cached values are recomputed (and the arguments checked) on deserialization.
+#[cfg(feature = "serde")]
+mod serde_repr {
+    use super::{
+        FaderRange, FaderTaper, FloatRange, FreqRange, IntRange, LogDBRange, NoteRange,
+        TempoDivision, TempoSyncRange,
+    };
+    use crate::core::Normal;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct FloatRangeRepr {
+        min: f32,
+        max: f32,
+    }
+
+    impl From<FloatRange> for FloatRangeRepr {
+        fn from(range: FloatRange) -> Self {
+            Self {
+                min: range.min,
+                max: range.max,
+            }
+        }
+    }
+
+    impl TryFrom<FloatRangeRepr> for FloatRange {
+        type Error = &'static str;
+
+        fn try_from(repr: FloatRangeRepr) -> Result<Self, Self::Error> {
+            if repr.max > repr.min {
+                Ok(FloatRange::new(repr.min, repr.max))
+            } else {
+                Err("max must be greater than min")
+            }
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct IntRangeRepr {
+        min: i32,
+        max: i32,
+    }
+
+    impl From<IntRange> for IntRangeRepr {
+        fn from(range: IntRange) -> Self {
+            Self {
+                min: range.min,
+                max: range.max,
+            }
+        }
+    }
+
+    impl TryFrom<IntRangeRepr> for IntRange {
+        type Error = &'static str;
+
+        fn try_from(repr: IntRangeRepr) -> Result<Self, Self::Error> {
+            if repr.max > repr.min {
+                Ok(IntRange::new(repr.min, repr.max))
+            } else {
+                Err("max must be greater than min")
+            }
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct LogDBRangeRepr {
+        min: f32,
+        max: f32,
+        zero_position: Normal,
+    }
+
+    impl From<LogDBRange> for LogDBRangeRepr {
+        fn from(range: LogDBRange) -> Self {
+            Self {
+                min: range.min,
+                max: range.max,
+                zero_position: range.zero_position,
+            }
+        }
+    }
+
+    impl TryFrom<LogDBRangeRepr> for LogDBRange {
+        type Error = &'static str;
+
+        fn try_from(repr: LogDBRangeRepr) -> Result<Self, Self::Error> {
+            if repr.max <= repr.min {
+                Err("max must be greater than min")
+            } else if repr.max < 0.0 {
+                Err("max must be 0.0 or positive")
+            } else if repr.min > 0.0 {
+                Err("min must be 0.0 or negative")
+            } else {
+                Ok(LogDBRange::new(repr.min, repr.max, repr.zero_position))
+            }
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct FaderRangeRepr {
+        min: f32,
+        max: f32,
+        zero_position: Normal,
+        taper: FaderTaper,
+    }
+
+    impl From<FaderRange> for FaderRangeRepr {
+        fn from(range: FaderRange) -> Self {
+            Self {
+                min: range.min,
+                max: range.max,
+                zero_position: range.zero_position,
+                taper: range.taper,
+            }
+        }
+    }
+
+    impl TryFrom<FaderRangeRepr> for FaderRange {
+        type Error = &'static str;
+
+        fn try_from(repr: FaderRangeRepr) -> Result<Self, Self::Error> {
+            if repr.max <= repr.min {
+                Err("max must be greater than min")
+            } else if repr.max < 0.0 {
+                Err("max must be 0.0 or positive")
+            } else if repr.min > 0.0 {
+                Err("min must be 0.0 or negative")
+            } else {
+                Ok(FaderRange::new(
+                    repr.min,
+                    repr.max,
+                    repr.zero_position,
+                    repr.taper,
+                ))
+            }
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct NoteRangeRepr {
+        min: u8,
+        max: u8,
+    }
+
+    impl From<NoteRange> for NoteRangeRepr {
+        fn from(range: NoteRange) -> Self {
+            Self {
+                min: range.range.min as u8,
+                max: range.range.max as u8,
+            }
+        }
+    }
+
+    impl TryFrom<NoteRangeRepr> for NoteRange {
+        type Error = &'static str;
+
+        fn try_from(repr: NoteRangeRepr) -> Result<Self, Self::Error> {
+            if repr.max <= repr.min {
+                Err("max must be greater than min")
+            } else if repr.max > 127 {
+                Err("max must be a valid MIDI note number")
+            } else {
+                Ok(NoteRange::new(repr.min, repr.max))
+            }
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct TempoSyncRangeRepr {
+        divisions: Vec<TempoDivision>,
+    }
+
+    impl From<TempoSyncRange> for TempoSyncRangeRepr {
+        fn from(range: TempoSyncRange) -> Self {
+            Self {
+                divisions: range.divisions,
+            }
+        }
+    }
+
+    impl TryFrom<TempoSyncRangeRepr> for TempoSyncRange {
+        type Error = &'static str;
+
+        fn try_from(repr: TempoSyncRangeRepr) -> Result<Self, Self::Error> {
+            if repr.divisions.len() >= 2 {
+                Ok(TempoSyncRange::new(repr.divisions))
+            } else {
+                Err("at least two divisions are required")
+            }
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct FreqRangeRepr {
+        min: f32,
+        max: f32,
+    }
+
+    impl From<FreqRange> for FreqRangeRepr {
+        fn from(range: FreqRange) -> Self {
+            Self {
+                min: range.min,
+                max: range.max,
+            }
+        }
+    }
+
+    impl TryFrom<FreqRangeRepr> for FreqRange {
+        type Error = &'static str;
+
+        fn try_from(repr: FreqRangeRepr) -> Result<Self, Self::Error> {
+            if repr.max > repr.min {
+                Ok(FreqRange::new(repr.min, repr.max))
+            } else {
+                Err("max must be greater than min")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FaderRange, FaderTaper, FloatRange, FreqRange, IntRange, LogDBRange, NoteRange,
+        TempoDivision, TempoFeel, TempoSyncRange,
+    };
+    use crate::core::{Normal, NormalParam};
+    use proptest::prelude::*;
+
+    const SWEEP: usize = 1000;
+
+    /// Checks that mapping the normals of a sweep to values and back lands on
+    /// the same normals, and that repeating the round-trip does not drift.
+    fn assert_round_trips(map: impl Fn(f32) -> Normal, unmap: impl Fn(Normal) -> f32) {
+        for i in 0..=SWEEP {
+            let normal = Normal::from_clipped(i as f32 / SWEEP as f32);
+            let first = map(unmap(normal));
+            assert!(
+                (first.as_f32() - normal.as_f32()).abs() < 1e-5,
+                "{normal:?} -> {first:?}"
+            );
+
+            let mut repeated = first;
+            for _ in 0..SWEEP {
+                repeated = map(unmap(repeated));
+            }
+            assert!(
+                (repeated.as_f32() - first.as_f32()).abs() < 1e-5,
+                "{first:?} drifted to {repeated:?}"
+            );
+        }
+    }
+
+    /// Checks that unmapping the values of a sweep from `min` to `max` lands
+    /// on the same values, within `tolerance` of the span of the sweep.
+    fn assert_values_round_trip(
+        min: f32,
+        max: f32,
+        tolerance: f32,
+        map: impl Fn(f32) -> Normal,
+        unmap: impl Fn(Normal) -> f32,
+    ) {
+        for i in 0..=SWEEP {
+            let value = min + (max - min) * (i as f32 / SWEEP as f32);
+            let round_trip = unmap(map(value));
+            assert!(
+                (round_trip - value).abs() <= tolerance * (max - min),
+                "{value} -> {round_trip}"
+            );
+        }
+    }
+
+    #[test]
+    fn const_ranges() {
+        const GAIN: FloatRange = FloatRange::new(-12.0, 12.0);
+        const GAIN_PARAM: NormalParam = GAIN.normal_param(0.0, 6.0);
+        const STEPS: IntRange = IntRange::new(0, 8);
+        const STEP: Normal = STEPS.snapped(Normal::from_clipped(0.3));
+
+        assert_eq!(GAIN_PARAM.value, Normal::CENTER);
+        assert_eq!(GAIN_PARAM.default.as_f32(), 0.75);
+        assert_eq!(STEPS.unmap_to_value(STEP), 2);
+        assert_eq!(STEP.as_f32(), 0.25);
+    }
+
+    #[test]
+    fn float_range_round_trip() {
+        for range in [
+            FloatRange::default(),
+            FloatRange::default_bipolar(),
+            FloatRange::new(-12_345.0, 67_890.0),
+        ] {
+            assert_round_trips(|v| range.map_to_normal(v), |n| range.unmap_to_value(n));
+            assert_values_round_trip(
+                range.min,
+                range.max,
+                1e-6,
+                |v| range.map_to_normal(v),
+                |n| range.unmap_to_value(n),
+            );
+        }
+    }
+
+    #[test]
+    fn int_range_round_trip() {
+        let range = IntRange::new(-1000, 1000);
+
+        for value in -1000..=1000 {
+            assert_eq!(range.unmap_to_value(range.map_to_normal(value)), value);
+        }
+
+        for i in 0..=SWEEP {
+            let normal = range
+                .map_to_normal(range.unmap_to_value(Normal::from_clipped(i as f32 / SWEEP as f32)));
+            let mut repeated = normal;
+            for _ in 0..SWEEP {
+                repeated = range.map_to_normal(range.unmap_to_value(repeated));
+            }
+            assert_eq!(repeated, normal);
+        }
+    }
+
+    #[test]
+    fn log_db_range_round_trip() {
+        // Only the decibels on the side of the zero position that the range
+        // covers can be represented.
+        for (zero_position, min, max) in [
+            (Normal::MIN, 0.0, 12.0),
+            (Normal::from_clipped(0.625), -60.0, 12.0),
+            (Normal::MAX, -60.0, 0.0),
+        ] {
+            let range = LogDBRange::new(-60.0, 12.0, zero_position);
+
+            assert_round_trips(|v| range.map_to_normal(v), |n| range.unmap_to_value(n));
+            assert_values_round_trip(
+                min,
+                max,
+                1e-4,
+                |v| range.map_to_normal(v),
+                |n| range.unmap_to_value(n),
+            );
+        }
+    }
+
+    #[test]
+    fn fader_range_round_trip() {
+        for taper in [FaderTaper::Iec60268, FaderTaper::LinearDB] {
+            let range = FaderRange::new(-70.0, 10.0, Normal::from_clipped(0.75), taper);
+
+            assert_round_trips(|v| range.map_to_normal(v), |n| range.unmap_to_value(n));
+            assert_values_round_trip(
+                -70.0,
+                10.0,
+                1e-4,
+                |v| range.map_to_normal(v),
+                |n| range.unmap_to_value(n),
+            );
+        }
+    }
+
+    #[test]
+    fn freq_range_round_trip() {
+        let range = FreqRange::default();
+
+        assert_round_trips(|v| range.map_to_normal(v), |n| range.unmap_to_value(n));
+        assert_values_round_trip(
+            20.0,
+            20_000.0,
+            1e-6,
+            |v| range.map_to_normal(v),
+            |n| range.unmap_to_value(n),
+        );
+    }
+
+    #[test]
+    fn discrete_ranges_round_trip() {
+        let notes = NoteRange::default();
+        let tempo = TempoSyncRange::default();
+
+        for i in 0..=SWEEP {
+            let normal = Normal::from_clipped(i as f32 / SWEEP as f32);
+
+            let note = notes.unmap_to_value(normal);
+            assert_eq!(notes.unmap_to_value(notes.map_to_normal(note)), note);
+
+            let division = tempo.unmap_to_value(normal);
+            assert_eq!(
+                tempo.unmap_to_value(tempo.map_to_normal(division)),
+                division
+            );
+        }
+    }
+
+    #[test]
+    fn fader_range() {
+        for taper in [FaderTaper::Iec60268, FaderTaper::LinearDB] {
+            let range = FaderRange::new(-70.0, 10.0, Normal::from_clipped(0.75), taper);
+
+            assert_eq!(range.map_to_normal(-70.0), Normal::MIN);
+            assert_eq!(range.map_to_normal(0.0).as_f32(), 0.75);
+            assert_eq!(range.map_to_normal(10.0), Normal::MAX);
+            assert_eq!(range.map_to_normal(-100.0), Normal::MIN);
+
+            for db in [-65.0, -42.0, -20.0, -3.0, 4.0] {
+                let value = range.unmap_to_value(range.map_to_normal(db));
+                assert!((value - db).abs() < 0.001, "{db} -> {value}");
+            }
+        }
+    }
+
+    #[test]
+    fn fader_range_iec_taper() {
+        let range = FaderRange::new(-70.0, 10.0, Normal::MAX, FaderTaper::Iec60268);
+
+        assert!((range.map_to_normal(-20.0).as_f32() - 0.5).abs() < 0.001);
+        assert!((range.map_to_normal(-40.0).as_f32() - 0.15).abs() < 0.001);
+        assert!((range.map_to_normal(-60.0).as_f32() - 0.025).abs() < 0.001);
+    }
+
+    #[test]
+    fn fader_range_labels_the_minimum() {
+        let min_label = |min, taper| {
+            let text_marks = FaderRange::new(min, 10.0, Normal::CENTER, taper).text_marks();
+            let (normal, text) = &text_marks.group[0];
+
+            assert_eq!(*normal, Normal::MIN);
+            text.to_string()
+        };
+
+        assert_eq!(min_label(-70.0, FaderTaper::Iec60268), "-inf");
+        assert_eq!(min_label(-90.0, FaderTaper::Iec60268), "-inf");
+        assert_eq!(min_label(-24.0, FaderTaper::Iec60268), "-24");
+        assert_eq!(min_label(-70.0, FaderTaper::LinearDB), "-70");
+        assert_eq!(min_label(-24.5, FaderTaper::LinearDB), "-24.5");
+    }
+
+    #[test]
+    fn note_range() {
+        assert_eq!(NoteRange::note_name(60), "C3");
+        assert_eq!(NoteRange::note_name(61), "C#3");
+        assert_eq!(NoteRange::note_name(0), "C-2");
+        assert_eq!(NoteRange::note_name(127), "G8");
+
+        let range = NoteRange::new(36, 84);
+        assert_eq!(range.map_to_normal(36), Normal::MIN);
+        assert_eq!(range.map_to_normal(84), Normal::MAX);
+        assert_eq!(range.unmap_to_value(range.map_to_normal(61)), 61);
+        assert_eq!(range.unmap_to_note_name(range.map_to_normal(61)), "C#3");
+        assert_eq!(range.text_marks().group.len(), 5);
+    }
+
+    #[test]
+    fn tempo_sync_range() {
+        let quarter = TempoDivision::new(4, TempoFeel::Straight);
+        assert_eq!(quarter.beats(), 1.0);
+        assert_eq!(quarter.seconds(120.0), 0.5);
+        assert_eq!(TempoDivision::new(8, TempoFeel::Triplet).label(), "1/8T");
+        assert_eq!(TempoDivision::new(16, TempoFeel::Dotted).label(), "1/16D");
+
+        let range = TempoSyncRange::default();
+        assert_eq!(range.unmap_to_value(Normal::MIN).label(), "1/32T");
+        assert_eq!(range.unmap_to_value(Normal::MAX).label(), "1/1D");
+        assert_eq!(range.unmap_to_value(range.map_to_normal(quarter)), quarter);
+
+        // A division that is not part of the range maps to the closest one.
+        let sixty_fourth = TempoDivision::new(64, TempoFeel::Straight);
+        assert_eq!(range.map_to_normal(sixty_fourth), Normal::MIN);
+
+        assert_eq!(range.text_marks().group.len(), 6);
+    }
+
+    /// A `FloatRange` with its `min` and `max`.
+    fn float_range() -> impl Strategy<Value = (FloatRange, f32, f32)> {
+        (-10_000.0f32..10_000.0, 0.001f32..10_000.0)
+            .prop_map(|(min, span)| (FloatRange::new(min, min + span), min, min + span))
+    }
+
+    /// A `LogDBRange` with its `min` and `max`.
+    fn log_db_range() -> impl Strategy<Value = (LogDBRange, f32, f32)> {
+        (-120.0f32..=0.0, 0.0f32..=24.0, 0.0f32..=1.0)
+            .prop_filter("max must be greater than min", |(min, max, _)| max > min)
+            .prop_map(|(min, max, zero)| {
+                // Without any decibels on a side, the zero position is at
+                // that end.
+                let zero = if min == 0.0 {
+                    0.0
+                } else if max == 0.0 {
+                    1.0
+                } else {
+                    zero.clamp(0.01, 0.99)
+                };
+
+                (
+                    LogDBRange::new(min, max, Normal::from_clipped(zero)),
+                    min,
+                    max,
+                )
+            })
+    }
+
+    /// A `FreqRange` with its `min` and `max`.
+    fn freq_range() -> impl Strategy<Value = (FreqRange, f32, f32)> {
+        (20.0f32..20_000.0, 20.0f32..20_000.0)
+            .prop_filter("max must be greater than min", |(a, b)| (a - b).abs() > 1.0)
+            .prop_map(|(a, b)| {
+                let (min, max) = (a.min(b), a.max(b));
+                (FreqRange::new(min, max), min, max)
+            })
+    }
+
+    /// Checks that `range` serializes to `json`, and that it deserializes
+    /// back to a range that serializes the same.
+    #[cfg(feature = "serde")]
+    fn assert_serde_round_trips<T>(range: T, json: &str)
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        assert_eq!(serde_json::to_string(&range).unwrap(), json);
+
+        let round_trip: T = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&round_trip).unwrap(), json);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        assert_serde_round_trips(FloatRange::new(-1.0, 2.5), r#"{"min":-1.0,"max":2.5}"#);
+        assert_serde_round_trips(IntRange::new(-3, 7), r#"{"min":-3,"max":7}"#);
+        assert_serde_round_trips(
+            LogDBRange::new(-12.0, 6.0, Normal::CENTER),
+            r#"{"min":-12.0,"max":6.0,"zero_position":0.5}"#,
+        );
+        let zero_position = Normal::from_clipped(0.75);
+        assert_serde_round_trips(
+            FaderRange::new(-60.0, 12.0, zero_position, FaderTaper::LinearDB),
+            r#"{"min":-60.0,"max":12.0,"zero_position":0.75,"taper":"LinearDB"}"#,
+        );
+        assert_serde_round_trips(NoteRange::new(21, 108), r#"{"min":21,"max":108}"#);
+        assert_serde_round_trips(
+            TempoSyncRange::new(vec![
+                TempoDivision::new(4, TempoFeel::Straight),
+                TempoDivision::new(8, TempoFeel::Dotted),
+            ]),
+            r#"{"divisions":[{"denominator":4,"feel":"Straight"},{"denominator":8,"feel":"Dotted"}]}"#,
+        );
+        assert_serde_round_trips(FreqRange::new(40.0, 8000.0), r#"{"min":40.0,"max":8000.0}"#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_invalid_ranges() {
+        // Each of these would panic in `new`.
+        assert!(serde_json::from_str::<FloatRange>(r#"{"min":1.0,"max":0.0}"#).is_err());
+        assert!(serde_json::from_str::<FloatRange>(r#"{"min":1.0,"max":1.0}"#).is_err());
+        assert!(serde_json::from_str::<IntRange>(r#"{"min":5,"max":-5}"#).is_err());
+        for json in [
+            r#"{"min":6.0,"max":-12.0,"zero_position":0.5}"#,
+            r#"{"min":-12.0,"max":-6.0,"zero_position":0.5}"#,
+            r#"{"min":3.0,"max":6.0,"zero_position":0.5}"#,
+            r#"{"min":-12.0,"max":6.0,"zero_position":1.5}"#,
+        ] {
+            assert!(serde_json::from_str::<LogDBRange>(json).is_err(), "{json}");
+        }
+        for json in [
+            r#"{"min":12.0,"max":-60.0,"zero_position":0.75,"taper":"Iec60268"}"#,
+            r#"{"min":-60.0,"max":-6.0,"zero_position":0.75,"taper":"Iec60268"}"#,
+            r#"{"min":6.0,"max":12.0,"zero_position":0.75,"taper":"Iec60268"}"#,
+            r#"{"min":-60.0,"max":12.0,"zero_position":0.75,"taper":"Cubic"}"#,
+        ] {
+            assert!(serde_json::from_str::<FaderRange>(json).is_err(), "{json}");
+        }
+        assert!(serde_json::from_str::<NoteRange>(r#"{"min":60,"max":60}"#).is_err());
+        assert!(serde_json::from_str::<NoteRange>(r#"{"min":0,"max":200}"#).is_err());
+        assert!(serde_json::from_str::<TempoSyncRange>(
+            r#"{"divisions":[{"denominator":4,"feel":"Straight"}]}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<FreqRange>(r#"{"min":8000.0,"max":40.0}"#).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_float_range((range, min, max) in float_range(), a in -1.0f32..2.0, b in -1.0f32..2.0) {
+            let span = max - min;
+            let (a, b) = (min + a * span, min + b * span);
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+            prop_assert!(range.map_to_normal(low) <= range.map_to_normal(high));
+            prop_assert_eq!(range.map_to_normal(min - span), Normal::MIN);
+            prop_assert_eq!(range.map_to_normal(max + span), Normal::MAX);
+
+            let value = low.clamp(min, max);
+            let round_trip = range.unmap_to_value(range.map_to_normal(value));
+            prop_assert!((round_trip - value).abs() <= span * 1e-5 + min.abs() * 1e-6);
+        }
+
+        #[test]
+        fn prop_int_range(min in -10_000i32..10_000, span in 1i32..10_000, a in -2.0f32..3.0, b in -2.0f32..3.0) {
+            let max = min + span;
+            let range = IntRange::new(min, max);
+            let a = min + (a * span as f32) as i32;
+            let b = min + (b * span as f32) as i32;
+            let (low, high) = (a.min(b), a.max(b));
+
+            prop_assert!(range.map_to_normal(low) <= range.map_to_normal(high));
+            prop_assert_eq!(range.map_to_normal(min - span), Normal::MIN);
+            prop_assert_eq!(range.map_to_normal(max + span), Normal::MAX);
+
+            let value = low.clamp(min, max);
+            prop_assert_eq!(range.unmap_to_value(range.map_to_normal(value)), value);
+        }
+
+        #[test]
+        fn prop_log_db_range((range, min, max) in log_db_range(), a in 0.0f32..=1.0, b in 0.0f32..=1.0) {
+            let a = min + a * (max - min);
+            let b = min + b * (max - min);
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+            prop_assert!(range.map_to_normal(low) <= range.map_to_normal(high));
+            prop_assert_eq!(range.map_to_normal(min - 100.0), range.map_to_normal(min));
+            prop_assert_eq!(range.map_to_normal(max + 100.0), range.map_to_normal(max));
+
+            let round_trip = range.unmap_to_value(range.map_to_normal(low));
+            prop_assert!((round_trip - low).abs() <= (max - min) * 1e-4);
+        }
+
+        #[test]
+        fn prop_freq_range((range, min, max) in freq_range(), a in 0.0f32..=1.0, b in 0.0f32..=1.0) {
+            let a = min + a * (max - min);
+            let b = min + b * (max - min);
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+            prop_assert!(range.map_to_normal(low) <= range.map_to_normal(high));
+            prop_assert_eq!(range.map_to_normal(1.0), Normal::MIN);
+            prop_assert_eq!(range.map_to_normal(100_000.0), Normal::MAX);
+
+            let round_trip = range.unmap_to_value(range.map_to_normal(low));
+            prop_assert!((round_trip - low).abs() <= low * 1e-5);
+        }
+
+        #[test]
+        fn prop_unmap_is_monotonic(a in 0.0f32..=1.0, b in 0.0f32..=1.0) {
+            let (low, high) = (Normal::from_clipped(a.min(b)), Normal::from_clipped(a.max(b)));
+
+            let float = FloatRange::default_bipolar();
+            prop_assert!(float.unmap_to_value(low) <= float.unmap_to_value(high));
+
+            let int = IntRange::new(-50, 50);
+            prop_assert!(int.unmap_to_value(low) <= int.unmap_to_value(high));
+
+            let db = LogDBRange::default();
+            prop_assert!(db.unmap_to_value(low) <= db.unmap_to_value(high));
+
+            let freq = FreqRange::default();
+            prop_assert!(freq.unmap_to_value(low) <= freq.unmap_to_value(high));
+        }
+    }
 }
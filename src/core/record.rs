@@ -0,0 +1,303 @@
+//! Record the changes of a control into an automation lane
+
+use crate::core::Normal;
+use iced::time::{Duration, Instant};
+
+/// The default tolerance of a [`Recorder`], below which points that lie
+/// on the line between their neighbours are thinned out.
+///
+/// [`Recorder`]: struct.Recorder.html
+pub const DEFAULT_TOLERANCE: f32 = 0.001;
+
+/// A sequence of automation points of one parameter, sorted by time.
+///
+/// The value between two points is interpolated linearly, and the value
+/// before the first and after the last point is the value of that point.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AutomationLane {
+    /// The points of the lane, as the time since the origin of the
+    /// [`Recorder`] and the value at that time.
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    pub points: Vec<(Duration, Normal)>,
+}
+
+impl AutomationLane {
+    /// Returns the value of the lane at `time`, or `None` if it has no
+    /// points.
+    pub fn value_at(&self, time: Duration) -> Option<Normal> {
+        let after = self.points.partition_point(|&(point, _)| point <= time);
+
+        match (
+            self.points.get(after.wrapping_sub(1)),
+            self.points.get(after),
+        ) {
+            (Some(&(start, a)), Some(&(end, b))) => {
+                let t = (time - start).as_secs_f32() / (end - start).as_secs_f32();
+
+                Some(Normal::from_clipped(
+                    a.as_f32() + (b.as_f32() - a.as_f32()) * t,
+                ))
+            }
+            (Some(&(_, normal)), None) | (None, Some(&(_, normal))) => Some(normal),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Records the changes of a control between its grab and release messages
+/// into an [`AutomationLane`], like the touch mode of a host.
+///
+/// Each pass from a grab to a release replaces the points of the lane in
+/// its time span. Points that lie on the line between the points around
+/// them, within the tolerance, are left out when the pass ends.
+///
+/// # Example
+///
+/// ```
+/// use iced::time::{Duration, Instant};
+/// use iced_audio::{record::Recorder, Normal};
+///
+/// let origin = Instant::now();
+/// let at = |millis| origin + Duration::from_millis(millis);
+/// let mut recorder = Recorder::new(origin);
+///
+/// // The application calls these from its grab, change, and release
+/// // messages.
+/// recorder.grab(at(0), Normal::from_clipped(0.0));
+/// recorder.record(at(10), Normal::from_clipped(0.25));
+/// recorder.record(at(20), Normal::from_clipped(0.5));
+/// recorder.release(at(20));
+///
+/// // The point in the middle of the straight line is thinned out.
+/// assert_eq!(recorder.lane().points.len(), 2);
+/// ```
+///
+/// [`AutomationLane`]: struct.AutomationLane.html
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    origin: Instant,
+    tolerance: f32,
+    lane: AutomationLane,
+    pass: Option<Vec<(Duration, Normal)>>,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] with an empty lane, whose points are
+    /// timed from `origin`, e.g. the start of the transport.
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    pub fn new(origin: Instant) -> Self {
+        Self {
+            origin,
+            tolerance: DEFAULT_TOLERANCE,
+            lane: AutomationLane::default(),
+            pass: None,
+        }
+    }
+
+    /// Sets how far in normalized units a point may be from the line
+    /// between the points around it to be thinned out.
+    ///
+    /// The default is [`DEFAULT_TOLERANCE`]. A tolerance of `0.0` only
+    /// thins out points that are exactly on the line.
+    ///
+    /// [`DEFAULT_TOLERANCE`]: constant.DEFAULT_TOLERANCE.html
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance.max(0.0);
+        self
+    }
+
+    /// Starts a pass at `time` from the current `normal` value of the
+    /// control, when it is grabbed.
+    pub fn grab(&mut self, time: Instant, normal: Normal) {
+        self.pass = Some(vec![(self.since_origin(time), normal)]);
+    }
+
+    /// Records that the control changed to `normal` at `time`. Changes are
+    /// only recorded between a grab and a release.
+    ///
+    /// A change at the same time as the last one replaces it, and a change
+    /// before it is ignored.
+    pub fn record(&mut self, time: Instant, normal: Normal) {
+        let time = self.since_origin(time);
+
+        if let Some(pass) = self.pass.as_mut() {
+            match pass.last_mut() {
+                Some(last) if last.0 == time => last.1 = normal,
+                Some(last) if last.0 > time => {}
+                _ => pass.push((time, normal)),
+            }
+        }
+    }
+
+    /// Ends the pass at `time`, when the control is released, and merges it
+    /// into the lane.
+    pub fn release(&mut self, time: Instant) {
+        let Some(mut pass) = self.pass.take() else {
+            return;
+        };
+
+        let time = self.since_origin(time);
+
+        if let Some(&(last, normal)) = pass.last() {
+            if time > last {
+                pass.push((time, normal));
+            }
+        }
+
+        let pass = thin(&pass, self.tolerance);
+
+        if let (Some(&(start, _)), Some(&(end, _))) = (pass.first(), pass.last()) {
+            let points = &mut self.lane.points;
+            let from = points.partition_point(|&(point, _)| point < start);
+            let to = points.partition_point(|&(point, _)| point <= end);
+
+            let _ = points.splice(from..to, pass);
+        }
+    }
+
+    /// Whether a pass is being recorded, i.e. the control is grabbed.
+    pub fn is_recording(&self) -> bool {
+        self.pass.is_some()
+    }
+
+    /// Returns the recorded lane, without the pass that is being recorded.
+    pub fn lane(&self) -> &AutomationLane {
+        &self.lane
+    }
+
+    /// Returns the recorded lane, without the pass that is being recorded.
+    pub fn into_lane(self) -> AutomationLane {
+        self.lane
+    }
+
+    fn since_origin(&self, time: Instant) -> Duration {
+        time.saturating_duration_since(self.origin)
+    }
+}
+
+/// Returns `points` without the points that are within `tolerance` of the
+/// line between the points that are kept around them.
+///
+/// The first and last points are always kept.
+pub fn thin(points: &[(Duration, Normal)], tolerance: f32) -> Vec<(Duration, Normal)> {
+    let Some((&first, rest)) = points.split_first() else {
+        return Vec::new();
+    };
+
+    let mut thinned = vec![first];
+    let mut anchor = 0;
+
+    for end in 1..rest.len() {
+        // Extend the line from the anchor to the point after `end`, unless
+        // one of the points it skips is too far from it.
+        let (start, a) = points[anchor];
+        let (stop, b) = points[end + 1];
+        let span = (stop - start).as_secs_f32();
+
+        let is_redundant = points[anchor + 1..=end].iter().all(|&(time, normal)| {
+            let t = if span > 0.0 {
+                (time - start).as_secs_f32() / span
+            } else {
+                1.0
+            };
+            let expected = a.as_f32() + (b.as_f32() - a.as_f32()) * t;
+
+            (normal.as_f32() - expected).abs() <= tolerance
+        });
+
+        if !is_redundant {
+            thinned.push(points[end]);
+            anchor = end;
+        }
+    }
+
+    if let Some(&last) = rest.last() {
+        thinned.push(last);
+    }
+
+    thinned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{thin, AutomationLane, Recorder};
+    use crate::core::Normal;
+    use iced::time::{Duration, Instant};
+
+    fn point(millis: u64, value: f32) -> (Duration, Normal) {
+        (Duration::from_millis(millis), Normal::from_clipped(value))
+    }
+
+    #[test]
+    fn thin_keeps_corners() {
+        let points = [
+            point(0, 0.0),
+            point(10, 0.1),
+            point(20, 0.2),
+            point(30, 0.2),
+            point(40, 0.2),
+            point(50, 0.8),
+        ];
+
+        assert_eq!(
+            thin(&points, 0.001),
+            vec![
+                point(0, 0.0),
+                point(20, 0.2),
+                point(40, 0.2),
+                point(50, 0.8)
+            ]
+        );
+        assert_eq!(thin(&points[..1], 0.001), vec![point(0, 0.0)]);
+        assert!(thin(&[], 0.001).is_empty());
+    }
+
+    #[test]
+    fn passes_replace_their_span() {
+        let origin = Instant::now();
+        let at = |millis| origin + Duration::from_millis(millis);
+        let mut recorder = Recorder::new(origin).tolerance(0.0);
+
+        recorder.grab(at(0), Normal::from_clipped(0.0));
+        recorder.record(at(100), Normal::from_clipped(1.0));
+        recorder.record(at(200), Normal::from_clipped(0.0));
+        recorder.release(at(300));
+        assert_eq!(recorder.lane().points.len(), 4);
+
+        // Changes outside of a pass are not recorded.
+        recorder.record(at(350), Normal::MAX);
+        assert!(!recorder.is_recording());
+
+        recorder.grab(at(50), Normal::from_clipped(0.5));
+        recorder.record(at(150), Normal::from_clipped(0.5));
+        recorder.release(at(150));
+
+        assert_eq!(
+            recorder.lane().points,
+            vec![
+                point(0, 0.0),
+                point(50, 0.5),
+                point(150, 0.5),
+                point(200, 0.0),
+                point(300, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn value_at_interpolates() {
+        let lane = AutomationLane {
+            points: vec![point(100, 0.0), point(200, 1.0)],
+        };
+
+        assert_eq!(lane.value_at(Duration::ZERO), Some(Normal::MIN));
+        assert_eq!(
+            lane.value_at(Duration::from_millis(150)),
+            Some(Normal::CENTER)
+        );
+        assert_eq!(lane.value_at(Duration::from_secs(1)), Some(Normal::MAX));
+        assert_eq!(AutomationLane::default().value_at(Duration::ZERO), None);
+    }
+}
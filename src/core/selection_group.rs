@@ -0,0 +1,149 @@
+//! Move several selected controls together, like selected faders of a
+//! mixer
+
+use crate::core::{Normal, ParamSnapshot};
+
+/// How the other members of a [`SelectionGroup`] follow the member that is
+/// moved.
+///
+/// [`SelectionGroup`]: struct.SelectionGroup.html
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GroupMode {
+    /// Every member moves by the same amount, keeping the offsets between
+    /// them. A member that reaches an end of its range stays there, and
+    /// gets its offset back when the members move back.
+    #[default]
+    Relative,
+    /// Every member is set to the value of the moved member.
+    Absolute,
+}
+
+/// The controls that are selected to move together, and their current
+/// [`Normal`] values, identified by an id of the application.
+///
+/// The application builds the group from its selection and passes it to
+/// each selected widget. When one of them is moved, it produces the new
+/// values of all members at once, which are then applied like a
+/// [`ParamSnapshot`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{GroupMode, Normal, SelectionGroup};
+///
+/// let mut group = SelectionGroup::new(GroupMode::Relative);
+/// group.insert(0, Normal::from_clipped(0.25));
+/// group.insert(1, Normal::from_clipped(0.5));
+///
+/// let moved = group.moved(0, Normal::from_clipped(0.5));
+/// assert_eq!(moved.get(&1), Some(Normal::from_clipped(0.75)));
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`ParamSnapshot`]: ../struct.ParamSnapshot.html
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SelectionGroup {
+    mode: GroupMode,
+    members: ParamSnapshot<u64>,
+}
+
+impl SelectionGroup {
+    /// Creates a new empty [`SelectionGroup`].
+    ///
+    /// [`SelectionGroup`]: struct.SelectionGroup.html
+    pub fn new(mode: GroupMode) -> Self {
+        Self {
+            mode,
+            members: ParamSnapshot::new(),
+        }
+    }
+
+    /// Adds the control with the given `id` and its current [`Normal`]
+    /// value to the group.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn insert(&mut self, id: u64, normal: Normal) {
+        self.members.insert(id, normal);
+    }
+
+    /// Returns the [`GroupMode`] of the group.
+    ///
+    /// [`GroupMode`]: enum.GroupMode.html
+    pub fn mode(&self) -> GroupMode {
+        self.mode
+    }
+
+    /// Returns whether the control with the given `id` is a member of the
+    /// group.
+    pub fn contains(&self, id: u64) -> bool {
+        self.members.get(&id).is_some()
+    }
+
+    /// Returns the members of the group and their current values.
+    pub fn members(&self) -> &ParamSnapshot<u64> {
+        &self.members
+    }
+
+    /// Returns the values of all members when the member with the given
+    /// `id` is moved from its value in the group to `normal`.
+    ///
+    /// The members are returned unchanged if `id` is not a member.
+    pub fn moved(&self, id: u64, normal: Normal) -> ParamSnapshot<u64> {
+        let Some(start) = self.members.get(&id) else {
+            return self.members.clone();
+        };
+
+        let delta = normal.as_f32() - start.as_f32();
+
+        self.members
+            .iter()
+            .map(|(&member, value)| {
+                let value = if member == id {
+                    normal
+                } else {
+                    match self.mode {
+                        GroupMode::Relative => Normal::from_clipped(value.as_f32() + delta),
+                        GroupMode::Absolute => normal,
+                    }
+                };
+
+                (member, value)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GroupMode, SelectionGroup};
+    use crate::core::Normal;
+
+    #[test]
+    fn relative_keeps_offsets() {
+        let mut group = SelectionGroup::new(GroupMode::Relative);
+        group.insert(0, Normal::from_clipped(0.5));
+        group.insert(1, Normal::from_clipped(0.9));
+
+        // The member at 0.9 stops at the top, and comes back with its offset.
+        let up = group.moved(0, Normal::from_clipped(0.7));
+        assert_eq!(up.get(&0), Some(Normal::from_clipped(0.7)));
+        assert_eq!(up.get(&1), Some(Normal::MAX));
+
+        let back = group.moved(0, Normal::from_clipped(0.4));
+        assert!((back.get(&1).unwrap().as_f32() - 0.8).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn absolute_sets_all() {
+        let mut group = SelectionGroup::new(GroupMode::Absolute);
+        group.insert(0, Normal::from_clipped(0.5));
+        group.insert(1, Normal::from_clipped(0.9));
+
+        let moved = group.moved(1, Normal::from_clipped(0.25));
+        assert_eq!(moved.get(&0), Some(Normal::from_clipped(0.25)));
+        assert_eq!(moved.get(&1), Some(Normal::from_clipped(0.25)));
+
+        assert_eq!(group.moved(2, Normal::MAX), *group.members());
+    }
+}
@@ -0,0 +1,144 @@
+//! How fast the virtual sliders move
+
+use iced::keyboard;
+
+/// The default `pixels_per_step` of all widgets.
+///
+/// Browsers report the mouse wheel in pixels (about 100 per notch), while
+/// on the desktop only touchpads send pixel deltas.
+pub const DEFAULT_PIXELS_PER_STEP: f32 = if cfg!(target_arch = "wasm32") {
+    100.0
+} else {
+    20.0
+};
+
+/// How fast a virtual slider widget moves its [`Normal`] value.
+///
+/// Every widget that is dragged like a virtual slider (e.g. [`Knob`],
+/// [`HSlider`]) has its own default, exposed as `DEFAULT_SENSITIVITY` in its
+/// module. Keep an app-wide constant and pass it to the widgets'
+/// `sensitivity()` builder instead of repeating the builder calls for every
+/// widget.
+///
+/// ```
+/// use iced_audio::{knob, SliderSensitivity};
+///
+/// const FINE: SliderSensitivity = SliderSensitivity {
+///     scalar: knob::DEFAULT_SCALAR / 2.0,
+///     ..knob::DEFAULT_SENSITIVITY
+/// };
+/// # let _ = FINE;
+/// ```
+///
+/// [`Normal`]: struct.Normal.html
+/// [`Knob`]: knob/struct.Knob.html
+/// [`HSlider`]: h_slider/struct.HSlider.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliderSensitivity {
+    /// How much the [`Normal`] value changes per unit the mouse is dragged.
+    /// What a unit is depends on the widget.
+    ///
+    /// [`Normal`]: struct.Normal.html
+    pub scalar: f32,
+    /// How much the [`Normal`] value changes per line scrolled by the mouse
+    /// wheel. `0.0` disables the mouse wheel.
+    ///
+    /// [`Normal`]: struct.Normal.html
+    pub wheel_scalar: f32,
+    /// How many pixels a pixel based scroll delta (e.g. from a touchpad) has
+    /// to add up to before the value moves by one `wheel_scalar` step.
+    pub pixels_per_step: f32,
+    /// The scalar multiplied to `scalar` while the modifier keys are held
+    /// down.
+    pub modifier_scalar: f32,
+    /// The modifier keys that switch to `modifier_scalar`.
+    pub modifier_keys: keyboard::Modifiers,
+    /// How the held modifier keys have to match `modifier_keys` and the
+    /// keys of `extra_modifiers`.
+    pub modifier_match: ModifierMatch,
+    /// More combinations of modifier keys, each with its own scalar instead
+    /// of `modifier_scalar`, e.g. an even finer mode for `Ctrl + Shift`
+    /// next to `Shift`. When several combinations match, the one with the
+    /// most keys wins. On a stepped widget, the mouse wheel still moves by
+    /// at least one step while any of them is held.
+    pub extra_modifiers: &'static [ModifierScalar],
+}
+
+impl SliderSensitivity {
+    /// Returns the scalar multiplied to `scalar` while the `pressed`
+    /// modifier keys are held, or `None` if they match neither
+    /// `modifier_keys` nor any of `extra_modifiers`.
+    ///
+    /// ```
+    /// use iced::keyboard::Modifiers;
+    /// use iced_audio::{knob, ModifierMatch, ModifierScalar, SliderSensitivity};
+    ///
+    /// const SENSITIVITY: SliderSensitivity = SliderSensitivity {
+    ///     modifier_keys: Modifiers::SHIFT,
+    ///     modifier_scalar: 0.1,
+    ///     extra_modifiers: &[ModifierScalar {
+    ///         keys: Modifiers::CTRL.union(Modifiers::SHIFT),
+    ///         scalar: 0.01,
+    ///     }],
+    ///     modifier_match: ModifierMatch::Exact,
+    ///     ..knob::DEFAULT_SENSITIVITY
+    /// };
+    ///
+    /// let ctrl_shift = Modifiers::CTRL | Modifiers::SHIFT;
+    /// assert_eq!(SENSITIVITY.held_modifier_scalar(Modifiers::SHIFT), Some(0.1));
+    /// assert_eq!(SENSITIVITY.held_modifier_scalar(ctrl_shift), Some(0.01));
+    /// assert_eq!(SENSITIVITY.held_modifier_scalar(Modifiers::ALT | Modifiers::SHIFT), None);
+    /// ```
+    pub fn held_modifier_scalar(&self, pressed: keyboard::Modifiers) -> Option<f32> {
+        let main = ModifierScalar {
+            keys: self.modifier_keys,
+            scalar: self.modifier_scalar,
+        };
+
+        // `max_by_key` picks the last of equally specific combinations, so
+        // they are checked in reverse for `modifier_keys` to win a tie.
+        std::iter::once(main)
+            .chain(self.extra_modifiers.iter().copied())
+            .rev()
+            .filter(|modifier| self.modifier_match.matches(pressed, modifier.keys))
+            .max_by_key(|modifier| modifier.keys.bits().count_ones())
+            .map(|modifier| modifier.scalar)
+    }
+}
+
+/// A combination of modifier keys that scales the movement of a virtual
+/// slider while it is held, see [`SliderSensitivity::extra_modifiers`].
+///
+/// [`SliderSensitivity::extra_modifiers`]: struct.SliderSensitivity.html#structfield.extra_modifiers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModifierScalar {
+    /// The modifier keys.
+    pub keys: keyboard::Modifiers,
+    /// The scalar multiplied to the `scalar` of the sensitivity while the
+    /// keys are held.
+    pub scalar: f32,
+}
+
+/// How the held modifier keys have to match the modifier keys of a
+/// [`SliderSensitivity`].
+///
+/// [`SliderSensitivity`]: struct.SliderSensitivity.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModifierMatch {
+    /// The keys are held, along with any others, so e.g. a `Ctrl + Shift`
+    /// shortcut also holds `Shift`.
+    #[default]
+    Contains,
+    /// The keys are held and no others.
+    Exact,
+}
+
+impl ModifierMatch {
+    /// Returns whether the `pressed` modifier keys match `keys`.
+    pub fn matches(&self, pressed: keyboard::Modifiers, keys: keyboard::Modifiers) -> bool {
+        match self {
+            ModifierMatch::Contains => pressed.contains(keys),
+            ModifierMatch::Exact => pressed == keys,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! The direction in which a slider increases its value
+
+/// The direction in which a slider increases its value.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SliderDirection {
+    /// The value increases to the right of a horizontal slider, and up a
+    /// vertical slider.
+    #[default]
+    Normal,
+    /// The value increases to the left of a horizontal slider, and down a
+    /// vertical slider, e.g. for attenuation controls or right-to-left
+    /// layouts.
+    Inverted,
+}
+
+impl SliderDirection {
+    /// Whether the direction is [`Inverted`].
+    ///
+    /// [`Inverted`]: #variant.Inverted
+    pub fn is_inverted(self) -> bool {
+        self == SliderDirection::Inverted
+    }
+}
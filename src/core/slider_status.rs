@@ -4,19 +4,31 @@
 /// thus preventing some events from unnecessary being emitted.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub enum SliderStatus {
+    /// The value was moved since the slider was grabbed.
     Moved,
+    /// The value was not moved since the slider was grabbed.
     #[default]
     Unchanged,
 }
 
 impl SliderStatus {
     /// Sets the slider as moved.
+    #[cfg(any(
+        feature = "arc_slider",
+        feature = "compressor_curve",
+        feature = "h_slider",
+        feature = "knob",
+        feature = "mod_range_input",
+        feature = "ramp",
+        feature = "v_slider",
+        feature = "xy_pad"
+    ))]
     pub(crate) fn moved(&mut self) {
         *self = SliderStatus::Moved;
     }
 
     /// Whether the slider was moved.
-    pub(crate) fn was_moved(self) -> bool {
+    pub fn was_moved(self) -> bool {
         matches!(self, SliderStatus::Moved)
     }
 }
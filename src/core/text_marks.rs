@@ -1,9 +1,11 @@
+mod cache;
 mod group;
 mod horizontal;
 mod radial;
 mod vertical;
 
 pub use crate::style::text_marks::*;
+pub use cache::Cache;
 pub use group::Group;
 pub use horizontal::*;
 pub use radial::*;
@@ -0,0 +1,156 @@
+//! A cache of the laid-out text of a group of text marks.
+
+use std::any::Any;
+use std::cell::{Ref, RefCell};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::rc::Rc;
+
+use iced::{
+    advanced::{text::Paragraph, Text},
+    alignment::{Horizontal, Vertical},
+    widget::text::{LineHeight, Shaping, Wrapping},
+    Font, Pixels, Size,
+};
+
+use super::Group;
+use crate::style::text_marks::Appearance;
+
+/// Keeps the laid-out text of a group of text marks until the text marks or
+/// their style change, so that the text isn't copied and shaped again every
+/// time a widget is drawn.
+///
+/// Keep one in the state of the widget that draws the text marks. The
+/// paragraphs depend on the renderer, so they are stored without their type.
+#[derive(Debug, Clone, Default)]
+pub struct Cache {
+    entry: RefCell<Option<Entry>>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    key: u64,
+    /// A `Vec` of the paragraphs of the renderer that drew them.
+    paragraphs: Rc<dyn Any>,
+}
+
+impl Cache {
+    /// Creates an empty [`Cache`].
+    ///
+    /// [`Cache`]: struct.Cache.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a paragraph for each text mark in each of the `alignments`,
+    /// grouped by alignment, laying them out again if anything changed
+    /// since the last call.
+    pub(crate) fn paragraphs<P>(
+        &self,
+        text_marks: &Group,
+        style: &Appearance,
+        alignments: &[(Horizontal, Vertical)],
+    ) -> Ref<'_, [P]>
+    where
+        P: Paragraph<Font = Font> + 'static,
+    {
+        let key = key(text_marks, style, alignments);
+
+        let is_valid = self.entry.borrow().as_ref().is_some_and(|entry| {
+            entry.key == key && entry.paragraphs.downcast_ref::<Vec<P>>().is_some()
+        });
+
+        if !is_valid {
+            let paragraphs: Vec<P> = alignments
+                .iter()
+                .flat_map(|&(horizontal, vertical)| {
+                    text_marks.group.iter().map(move |(_, text)| {
+                        P::with_text(Text {
+                            content: text.as_ref(),
+                            size: Pixels(f32::from(style.text_size)),
+                            bounds: Size {
+                                width: f32::from(style.bounds_width),
+                                height: f32::from(style.bounds_height),
+                            },
+                            horizontal_alignment: horizontal,
+                            vertical_alignment: vertical,
+                            line_height: LineHeight::default(),
+                            wrapping: Wrapping::default(),
+                            shaping: Shaping::Basic,
+                            font: style.font,
+                        })
+                    })
+                })
+                .collect();
+
+            *self.entry.borrow_mut() = Some(Entry {
+                key,
+                paragraphs: Rc::new(paragraphs),
+            });
+        }
+
+        Ref::map(self.entry.borrow(), |entry| {
+            entry
+                .as_ref()
+                .and_then(|entry| entry.paragraphs.downcast_ref::<Vec<P>>())
+                .expect("Cached text mark paragraphs")
+                .as_slice()
+        })
+    }
+}
+
+/// Hashes everything the paragraphs of a [`Cache`] are laid out from.
+fn key(text_marks: &Group, style: &Appearance, alignments: &[(Horizontal, Vertical)]) -> u64 {
+    let mut hasher = DefaultHasher::default();
+
+    text_marks.group.len().hash(&mut hasher);
+    for (_, text) in text_marks.group.iter() {
+        text.hash(&mut hasher);
+    }
+
+    style.font.hash(&mut hasher);
+    style.text_size.hash(&mut hasher);
+    style.bounds_width.hash(&mut hasher);
+    style.bounds_height.hash(&mut hasher);
+    alignments.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cache;
+    use crate::{style::text_marks::Appearance, text_marks::Group};
+    use iced::{
+        advanced::text::{self, Paragraph as _},
+        alignment::{Horizontal, Vertical},
+    };
+
+    type Paragraph = <iced_tiny_skia::Renderer as text::Renderer>::Paragraph;
+
+    const ALIGNMENTS: &[(Horizontal, Vertical)] = &[
+        (Horizontal::Center, Vertical::Top),
+        (Horizontal::Center, Vertical::Bottom),
+    ];
+
+    #[test]
+    fn paragraphs_are_kept_until_the_text_changes() {
+        let cache = Cache::default();
+        let style = Appearance::default();
+        let text_marks = Group::min_max("0", "10");
+
+        let first = cache.paragraphs::<Paragraph>(&text_marks, &style, ALIGNMENTS);
+        assert_eq!(first.len(), 4);
+        let first_ptr = first.as_ptr();
+        let width = first[1].min_bounds().width;
+        drop(first);
+
+        let again = cache.paragraphs::<Paragraph>(&text_marks, &style, ALIGNMENTS);
+        assert_eq!(again.as_ptr(), first_ptr);
+        drop(again);
+
+        let text_marks = Group::min_max("0", "1000");
+        let changed = cache.paragraphs::<Paragraph>(&text_marks, &style, ALIGNMENTS);
+        assert!(changed[1].min_bounds().width > width);
+        assert!(changed[3].min_bounds().width > width);
+    }
+}
@@ -1,14 +1,16 @@
 //! Structs for constructing a group of text marks.
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use crate::core::Normal;
 
 /// A group of text marks.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Group {
     /// The group of text marks.
-    pub group: Vec<(Normal, String)>,
+    pub group: Cow<'static, [(Normal, Cow<'static, str>)]>,
     //hashed: u64,
 }
 
@@ -26,12 +28,34 @@ impl Group {
         Self::from_string(group)
     }
 
+    /// Constructs a new `Group` from text marks, without copying them.
+    ///
+    /// Because this is a `const fn`, a scale of text marks can be defined as
+    /// a `const` or `static`:
+    ///
+    /// ```
+    /// use iced_audio::{text_marks, Normal};
+    /// use std::borrow::Cow;
+    ///
+    /// static TEXT_MARKS: text_marks::Group = text_marks::Group::from_static(&[
+    ///     (Normal::MIN, Cow::Borrowed("-inf")),
+    ///     (Normal::MAX, Cow::Borrowed("0")),
+    /// ]);
+    ///
+    /// assert_eq!(TEXT_MARKS.group.len(), 2);
+    /// ```
+    pub const fn from_static(text_marks: &'static [(Normal, Cow<'static, str>)]) -> Self {
+        Self {
+            group: Cow::Borrowed(text_marks),
+        }
+    }
+
     /// Constructs a new `Group` from a vector of [`TextMark`]s.
     ///
     /// [`Group`]: struct.Group.html
     /// [`TextMark`]: struct.TextMark.html
     fn from_string(group: Vec<(Normal, String)>) -> Self {
-        use std::hash::{DefaultHasher, Hash, Hasher};
+        use std::hash::{DefaultHasher, Hash};
         let mut hasher = DefaultHasher::default();
         group.len().hash(&mut hasher);
 
@@ -42,7 +66,10 @@ impl Group {
         }
 
         Self {
-            group,
+            group: group
+                .into_iter()
+                .map(|(normal, text)| (normal, Cow::Owned(text)))
+                .collect(),
             //hashed: hasher.finish(),
         }
     }
@@ -143,6 +170,34 @@ impl Group {
         vec.into()
     }
 
+    /// Returns a new [`Group`] that zooms into the range between `start` and
+    /// `end`: the text marks in the range are stretched over the whole range,
+    /// and the others are dropped.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn zoomed(&self, start: Normal, end: Normal) -> Self {
+        let (start, end) = (start.as_f32(), end.as_f32());
+        let span = end - start;
+
+        if span <= 0.0 {
+            return Self::from_string(Vec::new());
+        }
+
+        let group: Vec<(Normal, String)> = self
+            .group
+            .iter()
+            .filter(|(normal, _)| (start..=end).contains(&normal.as_f32()))
+            .map(|(normal, text)| {
+                (
+                    Normal::from_clipped((normal.as_f32() - start) / span),
+                    text.to_string(),
+                )
+            })
+            .collect();
+
+        Self::from_string(group)
+    }
+
     // /// Returns the hashed value of the internal data.
     // pub(crate) fn hashed(&self) -> u64 {
     //     self.hashed
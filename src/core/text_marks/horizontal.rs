@@ -1,90 +1,14 @@
 use crate::{
+    core::Normal,
     style::text_marks::{Align, Appearance, Placement},
-    text_marks::Group,
+    text_marks::{Cache, Group},
 };
 use iced::{
-    advanced::{text::Renderer as _, Text},
+    advanced::text,
     alignment::{Horizontal, Vertical},
-    widget::text::{LineHeight, Shaping, Wrapping},
-    Pixels, Point, Rectangle, Renderer, Size,
+    Font, Point, Rectangle,
 };
 
-fn draw_aligned(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    y: f32,
-    text_marks: &Group,
-    style: &Appearance,
-    inverse: bool,
-    align: Vertical,
-) {
-    let color = style.color;
-    let font = style.font;
-    let text_size = f32::from(style.text_size);
-    let text_bounds_width = f32::from(style.bounds_width);
-    let text_bounds_height = f32::from(style.bounds_height);
-
-    if inverse {
-        for text_mark in &text_marks.group {
-            let x = (bounds.x + (text_mark.0.scale_inv(bounds.width))).round();
-
-            renderer.fill_text(
-                Text {
-                    content: text_mark.1.clone(),
-                    size: Pixels(text_size),
-                    bounds: Size {
-                        width: text_bounds_width,
-                        height: text_bounds_height,
-                    },
-                    horizontal_alignment: Horizontal::Center,
-                    vertical_alignment: align,
-                    line_height: LineHeight::default(),
-                    wrapping: Wrapping::default(),
-                    shaping: Shaping::Basic,
-                    font,
-                },
-                Point { x, y },
-                color,
-                Rectangle {
-                    x,
-                    y,
-                    width: text_bounds_width,
-                    height: text_bounds_height,
-                },
-            );
-        }
-    } else {
-        for text_mark in &text_marks.group {
-            let x = (bounds.x + (text_mark.0.scale(bounds.width))).round();
-            renderer.fill_text(
-                Text {
-                    content: text_mark.1.clone(),
-                    size: Pixels(text_size),
-                    bounds: Size {
-                        width: text_bounds_width,
-                        height: text_bounds_height,
-                    },
-                    horizontal_alignment: Horizontal::Center,
-                    vertical_alignment: align,
-                    line_height: LineHeight::default(),
-                    wrapping: Wrapping::default(),
-                    shaping: Shaping::Basic,
-                    font,
-                },
-                Point { x, y },
-                color,
-                // TODO: What is this?
-                Rectangle {
-                    x: 0.0,
-                    y: 0.0,
-                    width: 1000.0,
-                    height: 1000.0,
-                },
-            );
-        }
-    }
-}
-
 /// Draws text marks on a horizontal axis.
 ///
 /// * `bounds` - The bounds of the widget to place the text marks in/outside of.
@@ -93,147 +17,251 @@ fn draw_aligned(
 /// * `placement` - The placement of the text marks relative to the bounds.
 /// * `inverse` - Whether to inverse the positions of the text marks (true) or
 ///   not (false).
-pub fn draw_horizontal_text_marks(
+/// * `cache` - The cache of the laid-out text of the text marks.
+pub fn draw_horizontal_text_marks<Renderer>(
     renderer: &mut Renderer,
     bounds: &Rectangle,
     text_marks: &Group,
     style: &Appearance,
     placement: &Placement,
     inverse: bool,
-    //cache: &PrimitiveCache,
-) {
-    match placement {
+    cache: &Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let (bounds, rows) = rows(bounds, placement);
+
+    let alignments: Vec<_> = rows
+        .iter()
+        .map(|&(_, align)| (Horizontal::Center, align))
+        .collect();
+    let paragraphs = cache.paragraphs::<Renderer::Paragraph>(text_marks, style, &alignments);
+
+    // The paragraphs are grouped by row in the same order as the bounds.
+    let aligns = rows
+        .iter()
+        .flat_map(|&(_, align)| std::iter::repeat_n(align, text_marks.group.len()));
+
+    for (((_, text_bounds), align), paragraph) in
+        mark_bounds(&bounds, &rows, text_marks, style, inverse)
+            .zip(aligns)
+            .zip(paragraphs.iter())
+    {
+        let y = match align {
+            Vertical::Top => text_bounds.y,
+            Vertical::Center => text_bounds.center_y(),
+            Vertical::Bottom => text_bounds.y + text_bounds.height,
+        };
+
+        renderer.fill_paragraph(
+            paragraph,
+            Point::new(text_bounds.center_x(), y),
+            style.color,
+            text_bounds,
+        );
+    }
+}
+
+/// Returns the bounds to place the text marks along with the given
+/// `placement`, and the `y` and alignment of each row of text marks.
+fn rows(bounds: &Rectangle, placement: &Placement) -> (Rectangle, Vec<(f32, Vertical)>) {
+    let (bounds, rows) = match placement {
         Placement::BothSides { inside, offset } => {
             let bounds = offset.offset_rect(bounds);
 
-            if *inside {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.y,
-                    text_marks,
-                    style,
-                    inverse,
-                    Vertical::Top,
-                );
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.y + bounds.height,
-                    text_marks,
-                    style,
-                    inverse,
-                    Vertical::Bottom,
-                );
+            let rows = if *inside {
+                vec![
+                    (bounds.y, Vertical::Top),
+                    (bounds.y + bounds.height, Vertical::Bottom),
+                ]
             } else {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.y,
-                    text_marks,
-                    style,
-                    inverse,
-                    Vertical::Bottom,
-                );
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.y + bounds.height,
-                    text_marks,
-                    style,
-                    inverse,
-                    Vertical::Top,
-                );
-            }
+                vec![
+                    (bounds.y, Vertical::Bottom),
+                    (bounds.y + bounds.height, Vertical::Top),
+                ]
+            };
+
+            (bounds, rows)
         }
         Placement::LeftOrTop { inside, offset } => {
             let bounds = offset.offset_rect(bounds);
-
-            if *inside {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.y,
-                    text_marks,
-                    style,
-                    inverse,
-                    Vertical::Top,
-                );
+            let align = if *inside {
+                Vertical::Top
             } else {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.y,
-                    text_marks,
-                    style,
-                    inverse,
-                    Vertical::Bottom,
-                );
-            }
+                Vertical::Bottom
+            };
+
+            (bounds, vec![(bounds.y, align)])
         }
         Placement::RightOrBottom { inside, offset } => {
             let bounds = offset.offset_rect(bounds);
-
-            if *inside {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.y + bounds.height,
-                    text_marks,
-                    style,
-                    inverse,
-                    Vertical::Bottom,
-                );
+            let align = if *inside {
+                Vertical::Bottom
             } else {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.y + bounds.height,
-                    text_marks,
-                    style,
-                    inverse,
-                    Vertical::Top,
-                );
-            }
+                Vertical::Top
+            };
+
+            (bounds, vec![(bounds.y + bounds.height, align)])
         }
         Placement::Center { align, offset } => {
             let bounds = offset.offset_rect(bounds);
+            let align = match align {
+                Align::Start => Vertical::Top,
+                Align::End => Vertical::Bottom,
+                Align::Center => Vertical::Center,
+            };
 
-            match align {
-                Align::Start => {
-                    draw_aligned(
-                        renderer,
-                        &bounds,
-                        bounds.center_y(),
-                        text_marks,
-                        style,
-                        inverse,
-                        Vertical::Top,
-                    );
-                }
-                Align::End => {
-                    draw_aligned(
-                        renderer,
-                        &bounds,
-                        bounds.center_y(),
-                        text_marks,
-                        style,
-                        inverse,
-                        Vertical::Bottom,
-                    );
-                }
-                Align::Center => {
-                    draw_aligned(
-                        renderer,
-                        &bounds,
-                        bounds.center_y(),
-                        text_marks,
-                        style,
-                        inverse,
-                        Vertical::Center,
-                    );
-                }
-            }
+            (bounds, vec![(bounds.center_y(), align)])
         }
     };
+
+    (bounds, rows)
+}
+
+/// Returns the bounds of the text of each text mark that
+/// [`draw_horizontal_text_marks`] draws with the same arguments, together
+/// with the position of the text mark.
+///
+/// [`draw_horizontal_text_marks`]: fn.draw_horizontal_text_marks.html
+#[cfg(any(test, feature = "h_slider"))]
+pub(crate) fn horizontal_text_mark_bounds(
+    bounds: &Rectangle,
+    text_marks: &Group,
+    style: &Appearance,
+    placement: &Placement,
+    inverse: bool,
+) -> Vec<(Normal, Rectangle)> {
+    let (bounds, rows) = rows(bounds, placement);
+
+    mark_bounds(&bounds, &rows, text_marks, style, inverse).collect()
+}
+
+/// Returns the bounds of the text of each text mark in each of the `rows`,
+/// centered on the position of the text mark and aligned to the `y` of the
+/// row.
+fn mark_bounds<'a>(
+    bounds: &'a Rectangle,
+    rows: &'a [(f32, Vertical)],
+    text_marks: &'a Group,
+    style: &Appearance,
+    inverse: bool,
+) -> impl Iterator<Item = (Normal, Rectangle)> + 'a {
+    let width = f32::from(style.bounds_width);
+    let height = f32::from(style.bounds_height);
+
+    rows.iter().flat_map(move |&(y, align)| {
+        let y = match align {
+            Vertical::Top => y,
+            Vertical::Center => y - (height / 2.0),
+            Vertical::Bottom => y - height,
+        };
+
+        text_marks.group.iter().map(move |(normal, _)| {
+            let offset = if inverse {
+                normal.scale_inv(bounds.width)
+            } else {
+                normal.scale(bounds.width)
+            };
+            let x = (bounds.x + offset).round();
+
+            (
+                *normal,
+                Rectangle {
+                    x: x - (width / 2.0),
+                    y,
+                    width,
+                    height,
+                },
+            )
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::horizontal_text_mark_bounds;
+    use crate::{
+        core::text_marks::{Align, Appearance, Group, Placement},
+        Offset,
+    };
+    use iced::Rectangle;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 20.0,
+        width: 100.0,
+        height: 30.0,
+    };
+
+    /// The bounds of 30x14 text marks at 0.0 and 1.0.
+    fn marks(placement: Placement, inverse: bool) -> Vec<Rectangle> {
+        horizontal_text_mark_bounds(
+            &BOUNDS,
+            &Group::min_max("min", "max"),
+            &Appearance::default(),
+            &placement,
+            inverse,
+        )
+        .into_iter()
+        .map(|(_, bounds)| bounds)
+        .collect()
+    }
+
+    #[test]
+    fn text_is_centered_on_its_mark() {
+        let marks = marks(Placement::default(), false);
+
+        assert_eq!(marks[0].center_x(), 10.0);
+        assert_eq!(marks[1].center_x(), 110.0);
+        assert_eq!(marks[0].width, 30.0);
+
+        let inverse = self::marks(Placement::default(), true);
+        assert_eq!(inverse[0].center_x(), 110.0);
+    }
+
+    #[test]
+    fn text_is_aligned_to_the_edges() {
+        let offset = Offset::new(0.0, -2.0);
+        let ys = |placement| {
+            let mut ys: Vec<_> = marks(placement, false).iter().map(|mark| mark.y).collect();
+            ys.dedup();
+            ys
+        };
+
+        assert_eq!(
+            ys(Placement::BothSides {
+                inside: false,
+                offset
+            }),
+            [4.0, 48.0]
+        );
+        assert_eq!(
+            ys(Placement::BothSides {
+                inside: true,
+                offset
+            }),
+            [18.0, 34.0]
+        );
+        assert_eq!(
+            ys(Placement::RightOrBottom {
+                inside: false,
+                offset
+            }),
+            [48.0]
+        );
+        assert_eq!(
+            ys(Placement::Center {
+                align: Align::Center,
+                offset
+            }),
+            [26.0]
+        );
+        assert_eq!(
+            ys(Placement::Center {
+                align: Align::End,
+                offset
+            }),
+            [19.0]
+        );
+    }
 }
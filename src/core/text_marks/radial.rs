@@ -1,11 +1,10 @@
 use iced::{
-    advanced::{text::Renderer as _, Text},
+    advanced::text,
     alignment::{Horizontal, Vertical},
-    widget::text::{LineHeight, Shaping, Wrapping},
-    Pixels, Point, Rectangle, Renderer, Size,
+    Font, Point, Rectangle, Vector,
 };
 
-use super::Group;
+use super::{Cache, Group};
 use crate::style::text_marks::Appearance;
 /// Draws text marks around an arc.
 ///
@@ -20,8 +19,9 @@ use crate::style::text_marks::Appearance;
 ///   from being too close to the arc.
 /// * `inverse` - Whether to inverse the positions of the text marks (true) or
 ///   not (false).
+/// * `cache` - The cache of the laid-out text of the text marks.
 #[allow(clippy::too_many_arguments)]
-pub fn draw_radial_text_marks(
+pub fn draw_radial_text_marks<Renderer>(
     renderer: &mut Renderer,
     center: Point,
     radius: f32,
@@ -31,57 +31,63 @@ pub fn draw_radial_text_marks(
     style: &Appearance,
     h_char_offset: f32,
     inverse: bool,
-    //cache: &PrimitiveCache,
-) {
+    cache: &Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    draw_elliptical_text_marks(
+        renderer,
+        center,
+        Vector::new(radius, radius),
+        start_angle,
+        angle_span,
+        text_marks,
+        style,
+        h_char_offset,
+        inverse,
+        cache,
+    );
+}
+
+/// Draws text marks around an elliptical arc with the horizontal radius
+/// `radius.x` and the vertical radius `radius.y`.
+///
+/// See [`draw_radial_text_marks`] for the other arguments.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn draw_elliptical_text_marks<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    radius: Vector,
+    start_angle: f32,
+    angle_span: f32,
+    text_marks: &Group,
+    style: &Appearance,
+    h_char_offset: f32,
+    inverse: bool,
+    cache: &Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
     let color = style.color;
-    let font = style.font;
-    let text_size = f32::from(style.text_size);
-    let text_bounds_width = f32::from(style.bounds_width);
-    let text_bounds_height = f32::from(style.bounds_height);
 
     let start_angle = start_angle + std::f32::consts::FRAC_PI_2;
 
-    for (position, text) in text_marks.group.iter() {
+    let paragraphs = cache.paragraphs::<Renderer::Paragraph>(
+        text_marks,
+        style,
+        &[(Horizontal::Center, Vertical::Center)],
+    );
+
+    for ((position, text), paragraph) in text_marks.group.iter().zip(paragraphs.iter()) {
         let angle = if inverse {
             start_angle + position.scale_inv(angle_span)
         } else {
             start_angle + position.scale(angle_span)
         };
 
-        let (dx, dy) = {
-            if !(-0.001..=0.001).contains(&angle) {
-                angle.sin_cos()
-            } else {
-                (0.0, -1.0)
-            }
-        };
-
-        let mut offset_x = dx * radius;
-        if offset_x < -0.001 {
-            offset_x -= (text.len() as f32 - 1.0) * h_char_offset;
-        } else if offset_x > 0.001 {
-            offset_x += (text.len() as f32 - 1.0) * h_char_offset;
-        }
-
-        renderer.fill_text(
-            Text {
-                content: text.clone(),
-                size: Pixels(text_size),
-                bounds: Size {
-                    width: text_bounds_width,
-                    height: text_bounds_height,
-                },
-                horizontal_alignment: Horizontal::Center,
-                vertical_alignment: Vertical::Center,
-                line_height: LineHeight::default(),
-                wrapping: Wrapping::default(),
-                shaping: Shaping::Basic,
-                font,
-            },
-            Point {
-                x: (center.x + offset_x).round(),
-                y: (center.y - (dy * radius)).round(),
-            },
+        renderer.fill_paragraph(
+            paragraph,
+            text_mark_position(center, radius, angle, text, h_char_offset),
             color,
             // TODO: What is this?
             Rectangle {
@@ -93,3 +99,61 @@ pub fn draw_radial_text_marks(
         );
     }
 }
+
+/// Returns the center of a text mark at `angle` around an elliptical arc,
+/// where `0.0` points straight up and angles grow clockwise.
+///
+/// Labels on the sides are pushed out by `h_char_offset` for each character
+/// after the first, so longer ones don't overlap the arc.
+fn text_mark_position(
+    center: Point,
+    radius: Vector,
+    angle: f32,
+    text: &str,
+    h_char_offset: f32,
+) -> Point {
+    let (dx, dy) = angle.sin_cos();
+
+    let mut offset_x = dx * radius.x;
+    if offset_x < -0.001 {
+        offset_x -= (text.len() as f32 - 1.0) * h_char_offset;
+    } else if offset_x > 0.001 {
+        offset_x += (text.len() as f32 - 1.0) * h_char_offset;
+    }
+
+    Point {
+        x: (center.x + offset_x).round(),
+        y: (center.y - (dy * radius.y)).round(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::text_mark_position;
+    use iced::{Point, Vector};
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn text_marks_go_clockwise_from_the_top() {
+        let center = Point::new(50.0, 40.0);
+        let radius = Vector::new(20.0, 10.0);
+        let position = |angle| text_mark_position(center, radius, angle, "0", 3.0);
+
+        assert_eq!(position(0.0), Point::new(50.0, 30.0));
+        assert_eq!(position(FRAC_PI_2), Point::new(70.0, 40.0));
+        assert_eq!(position(PI), Point::new(50.0, 50.0));
+        assert_eq!(position(-FRAC_PI_2), Point::new(30.0, 40.0));
+    }
+
+    #[test]
+    fn long_labels_are_pushed_off_the_sides() {
+        let center = Point::new(50.0, 40.0);
+        let radius = Vector::new(20.0, 20.0);
+        let position = |angle, text| text_mark_position(center, radius, angle, text, 3.0);
+
+        assert_eq!(position(FRAC_PI_2, "-12"), Point::new(76.0, 40.0));
+        assert_eq!(position(-FRAC_PI_2, "-12"), Point::new(24.0, 40.0));
+        // Labels at the top and bottom stay centered.
+        assert_eq!(position(0.0, "-12"), Point::new(50.0, 20.0));
+    }
+}
@@ -1,92 +1,14 @@
 use crate::{
+    core::Normal,
     style::text_marks::{Align, Appearance, Placement},
-    text_marks::Group,
+    text_marks::{Cache, Group},
 };
 use iced::{
-    advanced::{text::Renderer as _, Text},
+    advanced::text,
     alignment::{Horizontal, Vertical},
-    widget::text::{LineHeight, Shaping, Wrapping},
-    Pixels, Point, Rectangle, Renderer, Size,
+    Font, Point, Rectangle,
 };
 
-fn draw_aligned(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    x: f32,
-    text_marks: &Group,
-    style: &Appearance,
-    inverse: bool,
-    align: Horizontal,
-) {
-    let color = style.color;
-    let font = style.font;
-    let text_size = f32::from(style.text_size);
-    let text_bounds_width = f32::from(style.bounds_width);
-    let text_bounds_height = f32::from(style.bounds_height);
-
-    if inverse {
-        for text_mark in &text_marks.group {
-            let y = (bounds.y + (text_mark.0.scale(bounds.height))).round();
-
-            renderer.fill_text(
-                Text {
-                    content: text_mark.1.clone(),
-                    size: Pixels(text_size),
-                    bounds: Size {
-                        width: text_bounds_width,
-                        height: text_bounds_height,
-                    },
-                    horizontal_alignment: align,
-                    vertical_alignment: Vertical::Center,
-                    line_height: LineHeight::default(),
-                    wrapping: Wrapping::default(),
-                    shaping: Shaping::Basic,
-                    font,
-                },
-                Point { x, y },
-                color,
-                // TODO: What is this?
-                Rectangle {
-                    x: 0.0,
-                    y: 0.0,
-                    width: 1000.0,
-                    height: 1000.0,
-                },
-            );
-        }
-    } else {
-        for text_mark in &text_marks.group {
-            let y = (bounds.y + (text_mark.0.scale_inv(bounds.height))).round();
-
-            renderer.fill_text(
-                Text {
-                    content: text_mark.1.clone(),
-                    size: Pixels(text_size),
-                    bounds: Size {
-                        width: text_bounds_width,
-                        height: text_bounds_height,
-                    },
-                    horizontal_alignment: align,
-                    vertical_alignment: Vertical::Center,
-                    line_height: LineHeight::default(),
-                    wrapping: Wrapping::default(),
-                    shaping: Shaping::Basic,
-                    font,
-                },
-                Point { x, y },
-                color,
-                // TODO: What is this?
-                Rectangle {
-                    x,
-                    y,
-                    width: text_bounds_width,
-                    height: text_bounds_height,
-                },
-            );
-        }
-    }
-}
-
 /// Draws text marks on a vertical axis.
 ///
 /// * bounds - The bounds of the widget to place the text marks in/outside of.
@@ -95,147 +17,245 @@ fn draw_aligned(
 /// * placement - The placement of the text marks relative to the bounds.
 /// * inverse - Whether to inverse the positions of the text marks (true) or
 ///   not (false).
-pub fn draw_vertical_text_marks(
+/// * cache - The cache of the laid-out text of the text marks.
+pub fn draw_vertical_text_marks<Renderer>(
     renderer: &mut Renderer,
     bounds: &Rectangle,
     text_marks: &Group,
     style: &Appearance,
     placement: &Placement,
     inverse: bool,
-    //cache: &PrimitiveCache,
-) {
-    match placement {
+    cache: &Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let (bounds, rows) = rows(bounds, placement);
+
+    let alignments: Vec<_> = rows
+        .iter()
+        .map(|&(_, align)| (align, Vertical::Center))
+        .collect();
+    let paragraphs = cache.paragraphs::<Renderer::Paragraph>(text_marks, style, &alignments);
+
+    // The paragraphs are grouped by column in the same order as the bounds.
+    let aligns = rows
+        .iter()
+        .flat_map(|&(_, align)| std::iter::repeat_n(align, text_marks.group.len()));
+
+    for (((_, text_bounds), align), paragraph) in
+        mark_bounds(&bounds, &rows, text_marks, style, inverse)
+            .zip(aligns)
+            .zip(paragraphs.iter())
+    {
+        let x = match align {
+            Horizontal::Left => text_bounds.x,
+            Horizontal::Center => text_bounds.center_x(),
+            Horizontal::Right => text_bounds.x + text_bounds.width,
+        };
+
+        renderer.fill_paragraph(
+            paragraph,
+            Point::new(x, text_bounds.center_y()),
+            style.color,
+            text_bounds,
+        );
+    }
+}
+
+/// Returns the bounds to place the text marks along with the given
+/// `placement`, and the `x` and alignment of each column of text marks.
+fn rows(bounds: &Rectangle, placement: &Placement) -> (Rectangle, Vec<(f32, Horizontal)>) {
+    let (bounds, rows) = match placement {
         Placement::BothSides { inside, offset } => {
             let bounds = offset.offset_rect(bounds);
 
-            if *inside {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x,
-                    text_marks,
-                    style,
-                    inverse,
-                    Horizontal::Left,
-                );
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    text_marks,
-                    style,
-                    inverse,
-                    Horizontal::Right,
-                );
+            let rows = if *inside {
+                vec![
+                    (bounds.x, Horizontal::Left),
+                    (bounds.x + bounds.width, Horizontal::Right),
+                ]
             } else {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x,
-                    text_marks,
-                    style,
-                    inverse,
-                    Horizontal::Right,
-                );
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    text_marks,
-                    style,
-                    inverse,
-                    Horizontal::Left,
-                );
-            }
+                vec![
+                    (bounds.x, Horizontal::Right),
+                    (bounds.x + bounds.width, Horizontal::Left),
+                ]
+            };
+
+            (bounds, rows)
         }
         Placement::LeftOrTop { inside, offset } => {
             let bounds = offset.offset_rect(bounds);
-
-            if *inside {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x,
-                    text_marks,
-                    style,
-                    inverse,
-                    Horizontal::Left,
-                );
+            let align = if *inside {
+                Horizontal::Left
             } else {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x,
-                    text_marks,
-                    style,
-                    inverse,
-                    Horizontal::Right,
-                );
-            }
+                Horizontal::Right
+            };
+
+            (bounds, vec![(bounds.x, align)])
         }
         Placement::RightOrBottom { inside, offset } => {
             let bounds = offset.offset_rect(bounds);
-
-            if *inside {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    text_marks,
-                    style,
-                    inverse,
-                    Horizontal::Right,
-                );
+            let align = if *inside {
+                Horizontal::Right
             } else {
-                draw_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    text_marks,
-                    style,
-                    inverse,
-                    Horizontal::Left,
-                );
-            }
+                Horizontal::Left
+            };
+
+            (bounds, vec![(bounds.x + bounds.width, align)])
         }
         Placement::Center { align, offset } => {
             let bounds = offset.offset_rect(bounds);
+            let align = match align {
+                Align::Start => Horizontal::Left,
+                Align::End => Horizontal::Right,
+                Align::Center => Horizontal::Center,
+            };
 
-            match align {
-                Align::Start => {
-                    draw_aligned(
-                        renderer,
-                        &bounds,
-                        bounds.center_x(),
-                        text_marks,
-                        style,
-                        inverse,
-                        Horizontal::Left,
-                    );
-                }
-                Align::End => {
-                    draw_aligned(
-                        renderer,
-                        &bounds,
-                        bounds.center_x(),
-                        text_marks,
-                        style,
-                        inverse,
-                        Horizontal::Right,
-                    );
-                }
-                Align::Center => {
-                    draw_aligned(
-                        renderer,
-                        &bounds,
-                        bounds.center_x(),
-                        text_marks,
-                        style,
-                        inverse,
-                        Horizontal::Center,
-                    );
-                }
-            }
+            (bounds, vec![(bounds.center_x(), align)])
         }
     };
+
+    (bounds, rows)
+}
+
+/// Returns the bounds of the text of each text mark that
+/// [`draw_vertical_text_marks`] draws with the same arguments, together with
+/// the position of the text mark.
+///
+/// [`draw_vertical_text_marks`]: fn.draw_vertical_text_marks.html
+#[cfg(any(test, feature = "v_slider"))]
+pub(crate) fn vertical_text_mark_bounds(
+    bounds: &Rectangle,
+    text_marks: &Group,
+    style: &Appearance,
+    placement: &Placement,
+    inverse: bool,
+) -> Vec<(Normal, Rectangle)> {
+    let (bounds, rows) = rows(bounds, placement);
+
+    mark_bounds(&bounds, &rows, text_marks, style, inverse).collect()
+}
+
+/// Returns the bounds of the text of each text mark in each of the
+/// columns, centered on the position of the text mark and aligned to the
+/// `x` of the column.
+fn mark_bounds<'a>(
+    bounds: &'a Rectangle,
+    rows: &'a [(f32, Horizontal)],
+    text_marks: &'a Group,
+    style: &Appearance,
+    inverse: bool,
+) -> impl Iterator<Item = (Normal, Rectangle)> + 'a {
+    let width = f32::from(style.bounds_width);
+    let height = f32::from(style.bounds_height);
+
+    rows.iter().flat_map(move |&(x, align)| {
+        let x = match align {
+            Horizontal::Left => x,
+            Horizontal::Center => x - (width / 2.0),
+            Horizontal::Right => x - width,
+        };
+
+        text_marks.group.iter().map(move |(normal, _)| {
+            let offset = if inverse {
+                normal.scale(bounds.height)
+            } else {
+                normal.scale_inv(bounds.height)
+            };
+            let y = (bounds.y + offset).round();
+
+            (
+                *normal,
+                Rectangle {
+                    x,
+                    y: y - (height / 2.0),
+                    width,
+                    height,
+                },
+            )
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::vertical_text_mark_bounds;
+    use crate::{
+        core::text_marks::{Align, Appearance, Group, Placement},
+        Offset,
+    };
+    use iced::Rectangle;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 20.0,
+        y: 10.0,
+        width: 30.0,
+        height: 100.0,
+    };
+
+    /// The bounds of 30x14 text marks at 0.0 and 1.0.
+    fn marks(placement: Placement, inverse: bool) -> Vec<Rectangle> {
+        vertical_text_mark_bounds(
+            &BOUNDS,
+            &Group::min_max("min", "max"),
+            &Appearance::default(),
+            &placement,
+            inverse,
+        )
+        .into_iter()
+        .map(|(_, bounds)| bounds)
+        .collect()
+    }
+
+    #[test]
+    fn text_is_centered_on_its_mark() {
+        let marks = marks(Placement::default(), false);
+
+        // The minimum is at the bottom.
+        assert_eq!(marks[0].center_y(), 110.0);
+        assert_eq!(marks[1].center_y(), 10.0);
+        assert_eq!(marks[0].height, 14.0);
+
+        let inverse = self::marks(Placement::default(), true);
+        assert_eq!(inverse[0].center_y(), 10.0);
+    }
+
+    #[test]
+    fn text_is_aligned_to_the_edges() {
+        let offset = Offset::new(2.0, 0.0);
+        let xs = |placement| {
+            let mut xs: Vec<_> = marks(placement, false).iter().map(|mark| mark.x).collect();
+            xs.dedup();
+            xs
+        };
+
+        assert_eq!(
+            xs(Placement::BothSides {
+                inside: false,
+                offset
+            }),
+            [-8.0, 52.0]
+        );
+        assert_eq!(
+            xs(Placement::LeftOrTop {
+                inside: true,
+                offset
+            }),
+            [22.0]
+        );
+        assert_eq!(
+            xs(Placement::Center {
+                align: Align::Center,
+                offset
+            }),
+            [22.0]
+        );
+        assert_eq!(
+            xs(Placement::Center {
+                align: Align::Start,
+                offset
+            }),
+            [37.0]
+        );
+    }
 }
@@ -11,3 +11,102 @@ pub use crate::style::tick_marks::*;
 pub use horizontal::*;
 pub use radial::*;
 pub use vertical::*;
+
+use crate::core::Normal;
+use iced::{
+    advanced::renderer::{self, Quad},
+    border::Radius,
+    Background, Border, Color, Rectangle, Shadow,
+};
+
+/// A tick mark placed along a bar, drawn as a filled rectangle with rounded
+/// corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Mark {
+    /// The bounds of the mark.
+    pub bounds: Rectangle,
+    /// The radius of the corners, which is half the size of a circle.
+    pub radius: f32,
+    /// The color of the mark.
+    pub color: Color,
+}
+
+impl Mark {
+    fn draw<Renderer: renderer::Renderer>(&self, renderer: &mut Renderer) {
+        renderer.fill_quad(
+            Quad {
+                bounds: self.bounds,
+                border: Border {
+                    width: 0.0,
+                    radius: Radius::new(self.radius),
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
+            },
+            Background::Color(self.color),
+        );
+    }
+}
+
+/// Returns the positions of each tier of `tick_marks` that has any, together
+/// with the shape of the tier.
+fn tiers<'a>(
+    tick_marks: &'a Group,
+    style: &Appearance,
+) -> impl Iterator<Item = (&'a [Normal], Shape)> {
+    [
+        (tick_marks.tier_1(), style.tier_1),
+        (tick_marks.tier_2(), style.tier_2),
+        (tick_marks.tier_3(), style.tier_3),
+    ]
+    .into_iter()
+    .filter_map(|(tier, shape)| Some((tier?, shape)))
+}
+
+/// Returns `style` with the tier 2 and tier 3 shapes faded or hidden
+/// following its [`Density`], when `tick_marks` are drawn along `length`
+/// pixels.
+///
+/// [`Density`]: struct.Density.html
+fn thinned(style: &Appearance, tick_marks: &Group, length: f32) -> Appearance {
+    let opacity = |tier, min_spacing| {
+        tick_marks.min_spacing(tier).map_or(1.0, |spacing| {
+            style.density.opacity(min_spacing, spacing * length)
+        })
+    };
+
+    Appearance {
+        tier_2: style
+            .tier_2
+            .faded(opacity(Tier::Two, style.density.tier_2_min_spacing)),
+        tier_3: style
+            .tier_3
+            .faded(opacity(Tier::Three, style.density.tier_3_min_spacing)),
+        ..*style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{thinned, Appearance, Group, Shape, Tier};
+
+    #[test]
+    fn thinned_fades_dense_tier_3() {
+        // Tier 3 marks are 1/8 of the length apart.
+        let group = Group::subdivided(1, 1, 1, Some(Tier::One));
+        let style = Appearance::default();
+
+        assert_eq!(thinned(&style, &group, 100.0), style);
+
+        let faded = thinned(&style, &group, 20.0);
+        assert_eq!(faded.tier_2, style.tier_2);
+        match (faded.tier_3, style.tier_3) {
+            (Shape::Line { color: faded, .. }, Shape::Line { color, .. }) => {
+                assert!((faded.a - color.a * (2.0 / 3.0)).abs() < 1.0e-6);
+            }
+            _ => panic!("tier 3 marks should be faded lines"),
+        }
+
+        assert_eq!(thinned(&style, &group, 10.0).tier_3, Shape::None);
+    }
+}
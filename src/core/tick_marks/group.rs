@@ -1,5 +1,6 @@
 //! Structs for constructing a group of tick marks.
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use super::Tier;
@@ -8,11 +9,16 @@ use crate::core::Normal;
 /// A group of tick marks.
 ///
 /// tick mark: struct.TickMark.html
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Vec<(Normal, Tier)>", into = "Vec<(Normal, Tier)>")
+)]
 pub struct Group {
-    tier_1_positions: Vec<Normal>,
-    tier_2_positions: Vec<Normal>,
-    tier_3_positions: Vec<Normal>,
+    tier_1_positions: Cow<'static, [Normal]>,
+    tier_2_positions: Cow<'static, [Normal]>,
+    tier_3_positions: Cow<'static, [Normal]>,
     len: usize,
     //hashed: u64,
 }
@@ -28,7 +34,7 @@ impl Group {
     ///
     /// [`Group`]: struct.Group.html
     pub fn from_normalized(tick_marks: &[(Normal, Tier)]) -> Self {
-        use std::hash::{DefaultHasher, Hash, Hasher};
+        use std::hash::{DefaultHasher, Hash};
         let mut hasher = DefaultHasher::default();
         tick_marks.len().hash(&mut hasher);
 
@@ -57,14 +63,46 @@ impl Group {
         }
 
         Self {
-            tier_1_positions,
-            tier_2_positions,
-            tier_3_positions,
+            tier_1_positions: Cow::Owned(tier_1_positions),
+            tier_2_positions: Cow::Owned(tier_2_positions),
+            tier_3_positions: Cow::Owned(tier_3_positions),
             len,
             //hashed: hasher.finish(),
         }
     }
 
+    /// Constructs a new `Group` from the positions of each [`Tier`] of tick
+    /// marks, without copying them.
+    ///
+    /// Because this is a `const fn`, a scale of tick marks can be defined as
+    /// a `const` or `static`:
+    ///
+    /// ```
+    /// use iced_audio::{tick_marks, Normal};
+    ///
+    /// static TICK_MARKS: tick_marks::Group = tick_marks::Group::from_tiers(
+    ///     &[Normal::MIN, Normal::MAX],
+    ///     &[Normal::CENTER],
+    ///     &[],
+    /// );
+    ///
+    /// assert_eq!(TICK_MARKS.len(), 3);
+    /// ```
+    ///
+    /// [`Tier`]: enum.Tier.html
+    pub const fn from_tiers(
+        tier_1: &'static [Normal],
+        tier_2: &'static [Normal],
+        tier_3: &'static [Normal],
+    ) -> Self {
+        Self {
+            tier_1_positions: Cow::Borrowed(tier_1),
+            tier_2_positions: Cow::Borrowed(tier_2),
+            tier_3_positions: Cow::Borrowed(tier_3),
+            len: tier_1.len() + tier_2.len() + tier_3.len(),
+        }
+    }
+
     /// Returns a new [`Group`] with a single tick mark in
     /// the center position.
     ///
@@ -192,7 +230,7 @@ impl Group {
 
     /// Returns the positions of the tier 1 tick marks.
     /// Returns `None` if there are no tier 1 tick marks.
-    pub fn tier_1(&self) -> Option<&Vec<Normal>> {
+    pub fn tier_1(&self) -> Option<&[Normal]> {
         if self.tier_1_positions.is_empty() {
             None
         } else {
@@ -202,7 +240,7 @@ impl Group {
 
     /// Returns the positions of the tier 2 tick marks.
     /// Returns `None` if there are no tier 2 tick marks.
-    pub fn tier_2(&self) -> Option<&Vec<Normal>> {
+    pub fn tier_2(&self) -> Option<&[Normal]> {
         if self.tier_2_positions.is_empty() {
             None
         } else {
@@ -212,7 +250,7 @@ impl Group {
 
     /// Returns the positions of the tier 3 tick marks.
     /// Returns `None` if there are no tier 3 tick marks.
-    pub fn tier_3(&self) -> Option<&Vec<Normal>> {
+    pub fn tier_3(&self) -> Option<&[Normal]> {
         if self.tier_3_positions.is_empty() {
             None
         } else {
@@ -220,6 +258,128 @@ impl Group {
         }
     }
 
+    /// Returns the smallest distance between two tick marks of the given
+    /// [`Tier`] or a larger one, e.g. between all tier 1 and tier 2 marks
+    /// for `Tier::Two`. Returns `None` if there are less than two of them.
+    ///
+    /// [`Tier`]: enum.Tier.html
+    pub fn min_spacing(&self, tier: Tier) -> Option<f32> {
+        let mut positions: Vec<f32> = self
+            .iter()
+            .filter(|(_, mark_tier)| *mark_tier as u8 <= tier as u8)
+            .map(|(normal, _)| normal.as_f32())
+            .collect();
+
+        positions.sort_by(f32::total_cmp);
+
+        positions
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .reduce(f32::min)
+    }
+
+    /// Returns an iterator over the position and [`Tier`] of every tick mark,
+    /// tier by tier.
+    ///
+    /// [`Tier`]: enum.Tier.html
+    pub fn iter(&self) -> impl Iterator<Item = (Normal, Tier)> + '_ {
+        let tier_1 = self.tier_1_positions.iter().map(|n| (*n, Tier::One));
+        let tier_2 = self.tier_2_positions.iter().map(|n| (*n, Tier::Two));
+        let tier_3 = self.tier_3_positions.iter().map(|n| (*n, Tier::Three));
+
+        tier_1.chain(tier_2).chain(tier_3)
+    }
+
+    /// Returns a new [`Group`] with the tick marks of both groups. Tick marks
+    /// with the same position and [`Tier`] in both groups are only kept once.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`Tier`]: enum.Tier.html
+    pub fn merge(&self, other: &Group) -> Self {
+        let mut tick_marks: Vec<(Normal, Tier)> = self.iter().collect();
+
+        for tick_mark in other.iter() {
+            if !self.contains(tick_mark) {
+                tick_marks.push(tick_mark);
+            }
+        }
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Returns a new [`Group`] with the tick marks of this group that are
+    /// not in `other` with the same position and [`Tier`].
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`Tier`]: enum.Tier.html
+    pub fn difference(&self, other: &Group) -> Self {
+        let tick_marks: Vec<(Normal, Tier)> = self
+            .iter()
+            .filter(|tick_mark| !other.contains(*tick_mark))
+            .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Returns a new [`Group`] with the tick marks moved from the whole range
+    /// into the range between `start` and `end`.
+    ///
+    /// For example, rescaling to `0.5` and `1.0` moves a tick mark at `0.5`
+    /// to `0.75`.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn rescaled(&self, start: Normal, end: Normal) -> Self {
+        let span = end.as_f32() - start.as_f32();
+
+        let tick_marks: Vec<(Normal, Tier)> = self
+            .iter()
+            .map(|(normal, tier)| {
+                (
+                    Normal::from_clipped(start.as_f32() + normal.scale(span)),
+                    tier,
+                )
+            })
+            .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    /// Returns a new [`Group`] that zooms into the range between `start` and
+    /// `end`. This is the inverse of [`rescaled`]: the tick marks in the
+    /// range are stretched over the whole range, and the others are dropped.
+    ///
+    /// This can be used to generate the tick marks of a zoomed scale from the
+    /// tick marks of the whole scale.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`rescaled`]: #method.rescaled
+    pub fn zoomed(&self, start: Normal, end: Normal) -> Self {
+        let (start, end) = (start.as_f32(), end.as_f32());
+        let span = end - start;
+
+        if span <= 0.0 {
+            return Self::from_normalized(&[]);
+        }
+
+        let tick_marks: Vec<(Normal, Tier)> = self
+            .iter()
+            .filter(|(normal, _)| (start..=end).contains(&normal.as_f32()))
+            .map(|(normal, tier)| (Normal::from_clipped((normal.as_f32() - start) / span), tier))
+            .collect();
+
+        Self::from_normalized(&tick_marks)
+    }
+
+    fn contains(&self, (normal, tier): (Normal, Tier)) -> bool {
+        let positions = match tier {
+            Tier::One => &self.tier_1_positions,
+            Tier::Two => &self.tier_2_positions,
+            Tier::Three => &self.tier_3_positions,
+        };
+
+        positions.contains(&normal)
+    }
+
     /// Returns the total number of tick marks.
     pub fn len(&self) -> usize {
         self.len
@@ -242,8 +402,79 @@ impl From<Vec<(Normal, Tier)>> for Group {
     }
 }
 
+impl From<Group> for Vec<(Normal, Tier)> {
+    fn from(group: Group) -> Self {
+        group.iter().collect()
+    }
+}
+
 impl From<&[(Normal, Tier)]> for Group {
     fn from(slice: &[(Normal, Tier)]) -> Self {
         Self::from_normalized(slice)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Group, Tier};
+    use crate::core::Normal;
+
+    fn normal(value: f32) -> Normal {
+        Normal::from_clipped(value)
+    }
+
+    #[test]
+    fn merge_and_difference() {
+        let min_max = Group::min_max(Tier::One);
+        let thirds = Group::min_max_and_center(Tier::One, Tier::Two);
+
+        let merged = min_max.merge(&thirds);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged, thirds);
+
+        let difference = thirds.difference(&min_max);
+        assert_eq!(difference, Group::center(Tier::Two));
+        assert!(min_max.difference(&thirds).is_empty());
+
+        // The same position with another tier is a different tick mark.
+        assert_eq!(Group::center(Tier::One).merge(&difference).len(), 2);
+    }
+
+    #[test]
+    fn min_spacing() {
+        let group = Group::subdivided(1, 1, 1, Some(Tier::One));
+
+        assert_eq!(group.min_spacing(Tier::One), Some(0.5));
+        assert_eq!(group.min_spacing(Tier::Two), Some(0.25));
+        assert_eq!(group.min_spacing(Tier::Three), Some(0.125));
+        assert_eq!(Group::center(Tier::One).min_spacing(Tier::Three), None);
+    }
+
+    #[test]
+    fn rescaled_and_zoomed() {
+        let group = Group::evenly_spaced(5, Tier::One);
+
+        let rescaled = group.rescaled(normal(0.5), Normal::MAX);
+        assert_eq!(
+            rescaled.tier_1(),
+            Some(
+                &[
+                    normal(0.5),
+                    normal(0.625),
+                    normal(0.75),
+                    normal(0.875),
+                    Normal::MAX
+                ][..]
+            )
+        );
+        assert_eq!(rescaled.zoomed(normal(0.5), Normal::MAX), group);
+
+        let zoomed = group.zoomed(normal(0.25), normal(0.75));
+        assert_eq!(
+            zoomed.tier_1(),
+            Some(&[Normal::MIN, Normal::CENTER, Normal::MAX][..])
+        );
+
+        assert!(group.zoomed(Normal::MAX, Normal::MIN).is_empty());
+    }
+}
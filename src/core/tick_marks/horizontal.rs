@@ -1,598 +1,109 @@
 //! `iced_graphics` renderer for tick marks
 
-use super::Group;
-use crate::{
-    core::Normal,
-    style::tick_marks::{Appearance, Placement, Shape},
-};
-use iced::{
-    Background, Border, Color, Rectangle, Renderer, Shadow,
-    advanced::renderer::{Quad, Renderer as _},
-    border::Radius,
-};
+use super::{tiers, Group, Mark};
+use crate::style::tick_marks::{Appearance, Placement, Shape};
+use iced::{advanced::renderer, Rectangle};
 
-#[allow(clippy::too_many_arguments)]
-fn draw_horizontal_lines(
-    renderer: &mut Renderer,
-    tick_marks: &[Normal],
-    bounds_x: f32,
-    bounds_width: f32,
-    y: f32,
-    width: f32,
-    length: f32,
-    color: Color,
-    inverse: bool,
-) {
-    let start_x = bounds_x - (width / 2.0);
-    let back_color = Background::Color(color);
-
-    if inverse {
-        for tick_mark in tick_marks {
-            renderer.fill_quad(
-                Quad {
-                    bounds: Rectangle {
-                        x: (start_x + tick_mark.scale_inv(bounds_width)),
-                        y,
-                        width,
-                        height: length,
-                    },
-                    border: Border {
-                        width: 0.0,
-                        radius: Radius::new(0.0),
-                        color: Color::TRANSPARENT,
-                    },
-                    shadow: Shadow::default(),
-                },
-                back_color,
-            );
-        }
-    } else {
-        for tick_mark in tick_marks {
-            renderer.fill_quad(
-                Quad {
-                    bounds: Rectangle {
-                        x: (start_x + tick_mark.scale(bounds_width)),
-                        y,
-                        width,
-                        height: length,
-                    },
-                    border: Border {
-                        width: 0.0,
-                        radius: Radius::new(0.0),
-                        color: Color::TRANSPARENT,
-                    },
-                    shadow: Shadow::default(),
-                },
-                back_color,
-            );
-        }
-    }
-}
-
-#[allow(clippy::too_many_arguments)]
-fn draw_horizontal_circles(
-    rendrerer: &mut Renderer,
-    tick_marks: &[Normal],
-    bounds_x: f32,
-    bounds_width: f32,
-    y: f32,
-    diameter: f32,
-    color: Color,
-    inverse: bool,
-) {
-    let radius = diameter / 2.0;
-    let start_x = bounds_x - radius;
-    let back_color = Background::Color(color);
-
-    if inverse {
-        for tick_mark in tick_marks {
-            rendrerer.fill_quad(
-                Quad {
-                    bounds: Rectangle {
-                        x: (start_x + tick_mark.scale_inv(bounds_width)),
-                        y,
-                        width: diameter,
-                        height: diameter,
-                    },
-                    border: Border {
-                        width: 0.0,
-                        radius: Radius::new(radius),
-                        color: Color::TRANSPARENT,
-                    },
-                    shadow: Shadow::default(),
-                },
-                back_color,
-            );
-        }
-    } else {
-        for tick_mark in tick_marks {
-            rendrerer.fill_quad(
-                Quad {
-                    bounds: Rectangle {
-                        x: (start_x + tick_mark.scale(bounds_width)),
-                        y,
-                        width: diameter,
-                        height: diameter,
-                    },
-                    border: Border {
-                        width: 0.0,
-                        radius: Radius::new(radius),
-                        color: Color::TRANSPARENT,
-                    },
-                    shadow: Shadow::default(),
-                },
-                back_color,
-            );
-        }
-    }
-}
-
-#[inline]
-fn draw_horizontal_top_aligned_tier(
-    rendrerer: &mut Renderer,
-    bounds: &Rectangle,
-    y: f32,
-    tick_marks: Option<&Vec<Normal>>,
-    shape: &Shape,
-    inverse: bool,
-) {
-    if let Some(tick_marks) = tick_marks {
-        match shape {
-            Shape::None => (),
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
-                draw_horizontal_lines(
-                    rendrerer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y,
-                    *width,
-                    *length,
-                    *color,
-                    inverse,
-                );
-            }
-            Shape::Circle { diameter, color } => {
-                draw_horizontal_circles(
-                    rendrerer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y,
-                    *diameter,
-                    *color,
-                    inverse,
-                );
-            }
-        }
-    }
-}
-
-fn draw_horizontal_top_aligned(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    y: f32,
-    tick_marks: &Group,
-    style: &Appearance,
-    inverse: bool,
-) {
-    draw_horizontal_top_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        inverse,
-    );
-    draw_horizontal_top_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        inverse,
-    );
-    draw_horizontal_top_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        inverse,
-    );
-}
-
-#[inline]
-fn draw_horizontal_bottom_aligned_tier(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    y: f32,
-    tick_marks: Option<&Vec<Normal>>,
-    shape: &Shape,
-    inverse: bool,
-) {
-    if let Some(tick_marks) = tick_marks {
-        match shape {
-            Shape::None => (),
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
-                draw_horizontal_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y - (*length),
-                    *width,
-                    *length,
-                    *color,
-                    inverse,
-                );
-            }
-            Shape::Circle { diameter, color } => {
-                draw_horizontal_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y - (*diameter),
-                    *diameter,
-                    *color,
-                    inverse,
-                );
-            }
-        }
-    }
-}
-
-fn draw_horizontal_bottom_aligned(
-    renderer: &mut Renderer,
+/// Places the tick marks of each tier along the width of `bounds`.
+///
+/// `across` returns the `y` of the top edge and the length of a mark from
+/// the length of its shape, e.g. to align it to the top or the bottom of a
+/// row.
+fn place_tiers(
+    marks: &mut Vec<Mark>,
     bounds: &Rectangle,
-    y: f32,
     tick_marks: &Group,
     style: &Appearance,
     inverse: bool,
+    across: impl Fn(f32) -> (f32, f32),
 ) {
-    draw_horizontal_bottom_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        inverse,
-    );
-    draw_horizontal_bottom_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        inverse,
-    );
-    draw_horizontal_bottom_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        inverse,
-    );
-}
-
-#[inline]
-fn draw_horizontal_center_aligned_tier(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    y: f32,
-    tick_marks: Option<&Vec<Normal>>,
-    shape: &Shape,
-    fill_length: bool,
-    inverse: bool,
-) {
-    if let Some(tick_marks) = tick_marks {
-        match shape {
-            Shape::None => (),
+    for (tier, shape) in tiers(tick_marks, style) {
+        let (width, radius, color, (y, length)) = match shape {
+            Shape::None => continue,
             Shape::Line {
                 length,
                 width,
                 color,
-            } => {
-                let (y, length) = if fill_length {
-                    (bounds.y + (*length), bounds.height - ((*length) * 2.0))
-                } else {
-                    (y - (*length / 2.0), *length)
-                };
-
-                draw_horizontal_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y,
-                    *width,
-                    length,
-                    *color,
-                    inverse,
-                );
-            }
+            } => (width, 0.0, color, across(length)),
             Shape::Circle { diameter, color } => {
-                let (y, diameter) = if fill_length {
-                    (bounds.y + (*diameter), bounds.height - ((*diameter) * 2.0))
-                } else {
-                    (y - (diameter / 2.0), *diameter)
-                };
+                let (y, diameter) = across(diameter);
 
-                draw_horizontal_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    y,
-                    diameter,
-                    *color,
-                    inverse,
-                );
+                (diameter, diameter / 2.0, color, (y, diameter))
             }
-        }
-    }
-}
+        };
 
-fn draw_horizontal_center_aligned(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    y: f32,
-    tick_marks: &Group,
-    style: &Appearance,
-    fill_length: bool,
-    inverse: bool,
-) {
-    draw_horizontal_center_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        fill_length,
-        inverse,
-    );
-    draw_horizontal_center_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        fill_length,
-        inverse,
-    );
-    draw_horizontal_center_aligned_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        fill_length,
-        inverse,
-    );
-}
+        marks.extend(tier.iter().map(|tick_mark| {
+            let x = if inverse {
+                tick_mark.scale_inv(bounds.width)
+            } else {
+                tick_mark.scale(bounds.width)
+            };
 
-#[inline]
-#[allow(clippy::too_many_arguments)]
-fn draw_horizontal_center_aligned_split_tier(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    y: f32,
-    tick_marks: Option<&Vec<Normal>>,
-    shape: &Shape,
-    fill_length: bool,
-    gap: f32,
-    inverse: bool,
-) {
-    if let Some(tick_marks) = tick_marks {
-        match shape {
-            Shape::None => (),
-            Shape::Line {
-                length,
-                width,
+            Mark {
+                bounds: Rectangle {
+                    x: bounds.x + x - (width / 2.0),
+                    y,
+                    width,
+                    height: length,
+                },
+                radius,
                 color,
-            } => {
-                let (left_y, length) = if fill_length {
-                    let length = (*length) + (bounds.height + gap) / 2.0;
-                    ((y - length - (gap / 2.0)), length)
-                } else {
-                    ((y - (*length) - (gap / 2.0)), *length)
-                };
-
-                let right_y = y + (gap / 2.0);
-
-                draw_horizontal_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    left_y,
-                    *width,
-                    length,
-                    *color,
-                    inverse,
-                );
-                draw_horizontal_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    right_y,
-                    *width,
-                    length,
-                    *color,
-                    inverse,
-                );
-            }
-            Shape::Circle { diameter, color } => {
-                let (left_y, diameter) = if fill_length {
-                    (
-                        bounds.y - (*diameter),
-                        (*diameter) + ((bounds.height + gap) / 2.0),
-                    )
-                } else {
-                    (y - (*diameter) - (gap / 2.0), *diameter)
-                };
-
-                let right_y = y + (gap / 2.0);
-
-                draw_horizontal_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    left_y,
-                    diameter,
-                    *color,
-                    inverse,
-                );
-                draw_horizontal_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.x,
-                    bounds.width,
-                    right_y,
-                    diameter,
-                    *color,
-                    inverse,
-                );
             }
-        }
+        }));
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn draw_horizontal_center_aligned_split(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    y: f32,
-    tick_marks: &Group,
-    style: &Appearance,
-    fill_length: bool,
-    gap: f32,
-    inverse: bool,
-) {
-    draw_horizontal_center_aligned_split_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        fill_length,
-        gap,
-        inverse,
-    );
-    draw_horizontal_center_aligned_split_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        fill_length,
-        gap,
-        inverse,
-    );
-    draw_horizontal_center_aligned_split_tier(
-        renderer,
-        bounds,
-        y,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        fill_length,
-        gap,
-        inverse,
-    );
-}
-
-/// Draws tick marks on a horizontal axis.
+/// Returns the tick marks that [`draw_horizontal_tick_marks`] draws with the
+/// same arguments, in the order they are drawn.
 ///
-/// * bounds - The bounds of the widget to place the tick marks in/outside of.
-/// * tick_marks - The group of tick marks.
-/// * style - The tick marks style.
-/// * placement - The placement of the tick marks relative to the bounds.
-/// * inverse - Whether to inverse the positions of the tick marks (true) or
-///   not (false).
-pub fn draw_horizontal_tick_marks(
-    renderer: &mut Renderer,
+/// [`draw_horizontal_tick_marks`]: fn.draw_horizontal_tick_marks.html
+pub(crate) fn horizontal_tick_marks(
     bounds: &Rectangle,
     tick_marks: &Group,
     style: &Appearance,
     placement: &Placement,
     inverse: bool,
-) {
+) -> Vec<Mark> {
+    let style = &super::thinned(style, tick_marks, bounds.width);
+    let mut marks = Vec::new();
+    let mut place = |bounds: &Rectangle, across: &dyn Fn(f32) -> (f32, f32)| {
+        place_tiers(&mut marks, bounds, tick_marks, style, inverse, across);
+    };
+
+    // Marks that start at `y` and grow down, or end at `y`.
+    let below = |y: f32| move |length: f32| (y, length);
+    let above = |y: f32| move |length: f32| (y - length, length);
+
     match placement {
         Placement::BothSides { offset, inside } => {
             let bounds = offset.offset_rect(bounds);
+            let bottom = bounds.y + bounds.height;
 
             if *inside {
-                draw_horizontal_top_aligned(
-                    renderer, &bounds, bounds.x, tick_marks, style, inverse,
-                );
-                draw_horizontal_bottom_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    tick_marks,
-                    style,
-                    inverse,
-                );
+                place(&bounds, &below(bounds.y));
+                place(&bounds, &above(bottom));
             } else {
-                draw_horizontal_bottom_aligned(
-                    renderer, &bounds, bounds.x, tick_marks, style, inverse,
-                );
-                draw_horizontal_top_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    tick_marks,
-                    style,
-                    inverse,
-                );
+                place(&bounds, &above(bounds.y));
+                place(&bounds, &below(bottom));
             }
         }
         Placement::LeftOrTop { offset, inside } => {
             let bounds = offset.offset_rect(bounds);
 
             if *inside {
-                draw_horizontal_top_aligned(
-                    renderer, &bounds, bounds.x, tick_marks, style, inverse,
-                );
+                place(&bounds, &below(bounds.y));
             } else {
-                draw_horizontal_bottom_aligned(
-                    renderer, &bounds, bounds.x, tick_marks, style, inverse,
-                );
+                place(&bounds, &above(bounds.y));
             }
         }
         Placement::RightOrBottom { offset, inside } => {
             let bounds = offset.offset_rect(bounds);
+            let bottom = bounds.y + bounds.height;
 
             if *inside {
-                draw_horizontal_bottom_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    tick_marks,
-                    style,
-                    inverse,
-                );
+                place(&bounds, &above(bottom));
             } else {
-                draw_horizontal_top_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    tick_marks,
-                    style,
-                    inverse,
-                );
+                place(&bounds, &below(bottom));
             }
         }
         Placement::Center {
@@ -600,16 +111,15 @@ pub fn draw_horizontal_tick_marks(
             fill_length,
         } => {
             let bounds = offset.offset_rect(bounds);
+            let center = bounds.center_y();
 
-            draw_horizontal_center_aligned(
-                renderer,
-                &bounds,
-                bounds.center_y(),
-                tick_marks,
-                style,
-                *fill_length,
-                inverse,
-            );
+            place(&bounds, &|length| {
+                if *fill_length {
+                    (bounds.y + length, bounds.height - (length * 2.0))
+                } else {
+                    (center - (length / 2.0), length)
+                }
+            });
         }
         Placement::CenterSplit {
             offset,
@@ -617,17 +127,166 @@ pub fn draw_horizontal_tick_marks(
             gap,
         } => {
             let bounds = offset.offset_rect(bounds);
+            let center = bounds.center_y();
+            let bottom = bounds.y + bounds.height;
 
-            draw_horizontal_center_aligned_split(
-                renderer,
-                &bounds,
-                bounds.center_y(),
-                tick_marks,
-                style,
-                *fill_length,
-                *gap,
-                inverse,
-            );
+            if *fill_length {
+                place(&bounds, &below(bounds.y));
+                place(&bounds, &above(bottom));
+            } else {
+                place(&bounds, &above(center - (gap / 2.0)));
+                place(&bounds, &below(center + (gap / 2.0)));
+            }
         }
     };
+
+    marks
+}
+
+/// Draws tick marks on a horizontal axis.
+///
+/// * bounds - The bounds of the widget to place the tick marks in/outside of.
+/// * tick_marks - The group of tick marks.
+/// * style - The tick marks style.
+/// * placement - The placement of the tick marks relative to the bounds.
+/// * inverse - Whether to inverse the positions of the tick marks (true) or
+///   not (false).
+pub fn draw_horizontal_tick_marks<Renderer>(
+    renderer: &mut Renderer,
+    bounds: &Rectangle,
+    tick_marks: &Group,
+    style: &Appearance,
+    placement: &Placement,
+    inverse: bool,
+) where
+    Renderer: renderer::Renderer,
+{
+    for mark in horizontal_tick_marks(bounds, tick_marks, style, placement, inverse) {
+        mark.draw(renderer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::horizontal_tick_marks;
+    use crate::{
+        core::tick_marks::{Appearance, Group, Placement, Shape, Tier},
+        Normal, Offset,
+    };
+    use iced::{Color, Rectangle};
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 10.0,
+        y: 20.0,
+        width: 100.0,
+        height: 30.0,
+    };
+
+    /// A line 4 pixels long and 2 pixels wide at 0.0, 0.5, and 1.0.
+    fn marks(placement: Placement, inverse: bool) -> Vec<Rectangle> {
+        let group = Group::from_normalized(&[
+            (Normal::MIN, Tier::One),
+            (Normal::CENTER, Tier::One),
+            (Normal::MAX, Tier::One),
+        ]);
+        let style = Appearance {
+            tier_1: Shape::Line {
+                length: 4.0,
+                width: 2.0,
+                color: Color::BLACK,
+            },
+            ..Appearance::default()
+        };
+
+        horizontal_tick_marks(&BOUNDS, &group, &style, &placement, inverse)
+            .into_iter()
+            .map(|mark| mark.bounds)
+            .collect()
+    }
+
+    fn ys(marks: &[Rectangle]) -> Vec<(f32, f32)> {
+        let mut ys: Vec<_> = marks.iter().map(|mark| (mark.y, mark.height)).collect();
+        ys.dedup();
+        ys
+    }
+
+    #[test]
+    fn marks_are_centered_on_their_position() {
+        let marks = marks(Placement::default(), false);
+        let xs: Vec<_> = marks.iter().map(|mark| mark.center_x()).collect();
+
+        assert_eq!(xs[..3], [10.0, 60.0, 110.0]);
+        assert!(marks.iter().all(|mark| mark.width == 2.0));
+
+        let inverse = self::marks(Placement::default(), true);
+        assert_eq!(inverse[0].center_x(), 110.0);
+        assert_eq!(inverse[2].center_x(), 10.0);
+    }
+
+    #[test]
+    fn marks_are_aligned_to_the_edges() {
+        let offset = Offset::new(0.0, 1.0);
+        let placed = |placement| ys(&marks(placement, false));
+
+        assert_eq!(
+            placed(Placement::BothSides {
+                offset,
+                inside: false
+            }),
+            [(17.0, 4.0), (51.0, 4.0)]
+        );
+        assert_eq!(
+            placed(Placement::BothSides {
+                offset,
+                inside: true
+            }),
+            [(21.0, 4.0), (47.0, 4.0)]
+        );
+        assert_eq!(
+            placed(Placement::LeftOrTop {
+                offset,
+                inside: false
+            }),
+            [(17.0, 4.0)]
+        );
+        assert_eq!(
+            placed(Placement::RightOrBottom {
+                offset,
+                inside: true
+            }),
+            [(47.0, 4.0)]
+        );
+        assert_eq!(
+            placed(Placement::Center {
+                offset,
+                fill_length: false
+            }),
+            [(34.0, 4.0)]
+        );
+        assert_eq!(
+            placed(Placement::Center {
+                offset,
+                fill_length: true
+            }),
+            [(25.0, 22.0)]
+        );
+    }
+
+    #[test]
+    fn split_marks_stay_inside_the_bounds() {
+        let split = |fill_length| {
+            ys(&marks(
+                Placement::CenterSplit {
+                    offset: Offset::ZERO,
+                    fill_length,
+                    gap: 6.0,
+                },
+                false,
+            ))
+        };
+
+        assert_eq!(split(false), [(28.0, 4.0), (38.0, 4.0)]);
+        // The gap has no effect, and the marks start at the edges.
+        assert_eq!(split(true), [(20.0, 4.0), (46.0, 4.0)]);
+    }
 }
@@ -4,6 +4,7 @@
 /// * Two - medium-sized tick mark
 /// * Small - small-sized tick mark
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq, std::hash::Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tier {
     /// large-sized tick mark
     #[default]
@@ -1,594 +1,110 @@
 //! `iced` renderer for tick marks
 
-use super::Group;
-use crate::{
-    core::Normal,
-    style::tick_marks::{Appearance, Placement, Shape},
-};
-use iced::{
-    Background, Border, Color, Rectangle, Renderer, Shadow,
-    advanced::{Renderer as _, renderer::Quad},
-    border::Radius,
-};
+use super::{tiers, Group, Mark};
+use crate::style::tick_marks::{Appearance, Placement, Shape};
+use iced::{advanced::renderer, Rectangle};
 
-#[allow(clippy::too_many_arguments)]
-fn draw_vertical_lines(
-    renderer: &mut Renderer,
-    tick_marks: &[Normal],
-    bounds_y: f32,
-    bounds_height: f32,
-    x: f32,
-    width: f32,
-    length: f32,
-    color: Color,
-    inverse: bool,
-) {
-    let start_y = bounds_y - (width / 2.0);
-    let back_color = Background::Color(color);
-
-    if inverse {
-        for tick_mark in tick_marks {
-            renderer.fill_quad(
-                Quad {
-                    bounds: Rectangle {
-                        x,
-                        y: (start_y + tick_mark.scale(bounds_height)),
-                        width: length,
-                        height: width,
-                    },
-                    border: Border {
-                        width: 0.0,
-                        radius: Radius::new(0.0),
-                        color: Color::TRANSPARENT,
-                    },
-                    shadow: Shadow::default(),
-                },
-                back_color,
-            );
-        }
-    } else {
-        for tick_mark in tick_marks {
-            renderer.fill_quad(
-                Quad {
-                    bounds: Rectangle {
-                        x,
-                        y: (start_y + tick_mark.scale_inv(bounds_height)),
-                        width: length,
-                        height: width,
-                    },
-                    border: Border {
-                        width: 0.0,
-                        radius: Radius::new(0.0),
-                        color: Color::TRANSPARENT,
-                    },
-                    shadow: Shadow::default(),
-                },
-                back_color,
-            );
-        }
-    }
-}
-
-#[allow(clippy::too_many_arguments)]
-fn draw_vertical_circles(
-    renderer: &mut Renderer,
-    tick_marks: &[Normal],
-    bounds_y: f32,
-    bounds_height: f32,
-    x: f32,
-    diameter: f32,
-    color: Color,
-    inverse: bool,
-) {
-    let radius = diameter / 2.0;
-    let start_y = bounds_y - radius;
-    let back_color = Background::Color(color);
-
-    if inverse {
-        for tick_mark in tick_marks {
-            renderer.fill_quad(
-                Quad {
-                    bounds: Rectangle {
-                        x,
-                        y: (start_y + tick_mark.scale(bounds_height)),
-                        width: diameter,
-                        height: diameter,
-                    },
-                    border: Border {
-                        width: 0.0,
-                        radius: Radius::new(radius),
-                        color: Color::TRANSPARENT,
-                    },
-                    shadow: Shadow::default(),
-                },
-                back_color,
-            );
-        }
-    } else {
-        for tick_mark in tick_marks {
-            renderer.fill_quad(
-                Quad {
-                    bounds: Rectangle {
-                        x,
-                        y: (start_y + tick_mark.scale_inv(bounds_height)),
-                        width: diameter,
-                        height: diameter,
-                    },
-                    border: Border {
-                        width: 0.0,
-                        radius: Radius::new(radius),
-                        color: Color::TRANSPARENT,
-                    },
-                    shadow: Shadow::default(),
-                },
-                back_color,
-            );
-        }
-    }
-}
-
-#[inline]
-fn draw_vertical_left_aligned_tier(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    x: f32,
-    tick_marks: Option<&Vec<Normal>>,
-    shape: &Shape,
-    inverse: bool,
-) {
-    if let Some(tick_marks) = tick_marks {
-        match shape {
-            Shape::None => (),
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
-                draw_vertical_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x,
-                    *width,
-                    *length,
-                    *color,
-                    inverse,
-                );
-            }
-            Shape::Circle { diameter, color } => {
-                draw_vertical_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x,
-                    *diameter,
-                    *color,
-                    inverse,
-                );
-            }
-        }
-    }
-}
-
-fn draw_vertical_left_aligned(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    x: f32,
-    tick_marks: &Group,
-    style: &Appearance,
-    inverse: bool,
-) {
-    draw_vertical_left_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        inverse,
-    );
-    draw_vertical_left_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        inverse,
-    );
-    draw_vertical_left_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        inverse,
-    );
-}
-
-#[inline]
-fn draw_vertical_right_aligned_tier(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    x: f32,
-    tick_marks: Option<&Vec<Normal>>,
-    shape: &Shape,
-    inverse: bool,
-) {
-    if let Some(tick_marks) = tick_marks {
-        match shape {
-            Shape::None => (),
-            Shape::Line {
-                length,
-                width,
-                color,
-            } => {
-                draw_vertical_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x - (*length),
-                    *width,
-                    *length,
-                    *color,
-                    inverse,
-                );
-            }
-            Shape::Circle { diameter, color } => {
-                draw_vertical_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x - (*diameter),
-                    *diameter,
-                    *color,
-                    inverse,
-                );
-            }
-        }
-    }
-}
-
-fn draw_vertical_right_aligned(
-    renderer: &mut Renderer,
+/// Places the tick marks of each tier along the height of `bounds`, where
+/// `0.0` is at the bottom unless `inverse`.
+///
+/// `across` returns the `x` of the left edge and the length of a mark from
+/// the length of its shape, e.g. to align it to the left or the right of a
+/// column.
+fn place_tiers(
+    marks: &mut Vec<Mark>,
     bounds: &Rectangle,
-    x: f32,
     tick_marks: &Group,
     style: &Appearance,
     inverse: bool,
+    across: impl Fn(f32) -> (f32, f32),
 ) {
-    draw_vertical_right_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        inverse,
-    );
-    draw_vertical_right_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        inverse,
-    );
-    draw_vertical_right_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        inverse,
-    );
-}
-
-#[inline]
-fn draw_vertical_center_aligned_tier(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    x: f32,
-    tick_marks: Option<&Vec<Normal>>,
-    shape: &Shape,
-    fill_length: bool,
-    inverse: bool,
-) {
-    if let Some(tick_marks) = tick_marks {
-        match shape {
-            Shape::None => (),
+    for (tier, shape) in tiers(tick_marks, style) {
+        let (width, radius, color, (x, length)) = match shape {
+            Shape::None => continue,
             Shape::Line {
                 length,
                 width,
                 color,
-            } => {
-                let (x, length) = if fill_length {
-                    (bounds.x + (*length), bounds.width - ((*length) * 2.0))
-                } else {
-                    (x - (*length / 2.0), *length)
-                };
-
-                draw_vertical_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x,
-                    *width,
-                    length,
-                    *color,
-                    inverse,
-                );
-            }
+            } => (width, 0.0, color, across(length)),
             Shape::Circle { diameter, color } => {
-                let (x, diameter) = if fill_length {
-                    (bounds.x + (*diameter), bounds.width - ((*diameter) * 2.0))
-                } else {
-                    (x - (*diameter / 2.0), *diameter)
-                };
+                let (x, diameter) = across(diameter);
 
-                draw_vertical_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    x,
-                    diameter,
-                    *color,
-                    inverse,
-                );
+                (diameter, diameter / 2.0, color, (x, diameter))
             }
-        }
-    }
-}
+        };
 
-fn draw_vertical_center_aligned(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    x: f32,
-    tick_marks: &Group,
-    style: &Appearance,
-    fill_length: bool,
-    inverse: bool,
-) {
-    draw_vertical_center_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        fill_length,
-        inverse,
-    );
-    draw_vertical_center_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        fill_length,
-        inverse,
-    );
-    draw_vertical_center_aligned_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        fill_length,
-        inverse,
-    );
-}
+        marks.extend(tier.iter().map(|tick_mark| {
+            let y = if inverse {
+                tick_mark.scale(bounds.height)
+            } else {
+                tick_mark.scale_inv(bounds.height)
+            };
 
-#[inline]
-#[allow(clippy::too_many_arguments)]
-fn draw_vertical_center_aligned_split_tier(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    x: f32,
-    tick_marks: Option<&Vec<Normal>>,
-    shape: &Shape,
-    fill_length: bool,
-    gap: f32,
-    inverse: bool,
-) {
-    if let Some(tick_marks) = tick_marks {
-        match shape {
-            Shape::None => (),
-            Shape::Line {
-                length,
-                width,
+            Mark {
+                bounds: Rectangle {
+                    x,
+                    y: bounds.y + y - (width / 2.0),
+                    width: length,
+                    height: width,
+                },
+                radius,
                 color,
-            } => {
-                let (left_x, length) = if fill_length {
-                    let length = *length + ((bounds.width + gap) / 2.0);
-                    (x - length - (gap / 2.0), length)
-                } else {
-                    (x - *length - (gap / 2.0), *length)
-                };
-
-                let right_x = x + (gap / 2.0);
-
-                draw_vertical_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    left_x,
-                    *width,
-                    length,
-                    *color,
-                    inverse,
-                );
-                draw_vertical_lines(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    right_x,
-                    *width,
-                    length,
-                    *color,
-                    inverse,
-                );
-            }
-            Shape::Circle { diameter, color } => {
-                let (left_x, diameter) = if fill_length {
-                    (
-                        bounds.x - *diameter,
-                        *diameter + ((bounds.width + gap) / 2.0),
-                    )
-                } else {
-                    (x - *diameter - (gap / 2.0), *diameter)
-                };
-
-                let right_x = x + (gap / 2.0);
-
-                draw_vertical_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    left_x,
-                    diameter,
-                    *color,
-                    inverse,
-                );
-                draw_vertical_circles(
-                    renderer,
-                    tick_marks,
-                    bounds.y,
-                    bounds.height,
-                    right_x,
-                    diameter,
-                    *color,
-                    inverse,
-                );
             }
-        }
+        }));
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn draw_vertical_center_aligned_split(
-    renderer: &mut Renderer,
-    bounds: &Rectangle,
-    x: f32,
-    tick_marks: &Group,
-    style: &Appearance,
-    fill_length: bool,
-    gap: f32,
-    inverse: bool,
-) {
-    draw_vertical_center_aligned_split_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_1(),
-        &style.tier_1,
-        fill_length,
-        gap,
-        inverse,
-    );
-    draw_vertical_center_aligned_split_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_2(),
-        &style.tier_2,
-        fill_length,
-        gap,
-        inverse,
-    );
-    draw_vertical_center_aligned_split_tier(
-        renderer,
-        bounds,
-        x,
-        tick_marks.tier_3(),
-        &style.tier_3,
-        fill_length,
-        gap,
-        inverse,
-    );
-}
-
-/// Draws tick marks on a vertical axis.
+/// Returns the tick marks that [`draw_vertical_tick_marks`] draws with the
+/// same arguments, in the order they are drawn.
 ///
-/// * bounds - The bounds of the widget to place the tick marks in/outside of.
-/// * tick_marks - The group of tick marks.
-/// * style - The tick marks style.
-/// * placement - The placement of the tick marks relative to the bounds.
-/// * inverse - Whether to inverse the positions of the tick marks (true) or
-///   not (false).
-pub fn draw_vertical_tick_marks(
-    renderer: &mut Renderer,
+/// [`draw_vertical_tick_marks`]: fn.draw_vertical_tick_marks.html
+pub(crate) fn vertical_tick_marks(
     bounds: &Rectangle,
     tick_marks: &Group,
     style: &Appearance,
     placement: &Placement,
     inverse: bool,
-) {
+) -> Vec<Mark> {
+    let style = &super::thinned(style, tick_marks, bounds.height);
+    let mut marks = Vec::new();
+    let mut place = |bounds: &Rectangle, across: &dyn Fn(f32) -> (f32, f32)| {
+        place_tiers(&mut marks, bounds, tick_marks, style, inverse, across);
+    };
+
+    // Marks that start at `x` and grow to the right, or end at `x`.
+    let right_of = |x: f32| move |length: f32| (x, length);
+    let left_of = |x: f32| move |length: f32| (x - length, length);
+
     match placement {
         Placement::BothSides { offset, inside } => {
             let bounds = offset.offset_rect(bounds);
+            let right = bounds.x + bounds.width;
 
             if *inside {
-                draw_vertical_left_aligned(renderer, &bounds, bounds.x, tick_marks, style, inverse);
-                draw_vertical_right_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    tick_marks,
-                    style,
-                    inverse,
-                );
+                place(&bounds, &right_of(bounds.x));
+                place(&bounds, &left_of(right));
             } else {
-                draw_vertical_right_aligned(
-                    renderer, &bounds, bounds.x, tick_marks, style, inverse,
-                );
-                draw_vertical_left_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    tick_marks,
-                    style,
-                    inverse,
-                );
+                place(&bounds, &left_of(bounds.x));
+                place(&bounds, &right_of(right));
             }
         }
         Placement::LeftOrTop { offset, inside } => {
             let bounds = offset.offset_rect(bounds);
 
             if *inside {
-                draw_vertical_left_aligned(renderer, &bounds, bounds.x, tick_marks, style, inverse);
+                place(&bounds, &right_of(bounds.x));
             } else {
-                draw_vertical_right_aligned(
-                    renderer, &bounds, bounds.x, tick_marks, style, inverse,
-                );
+                place(&bounds, &left_of(bounds.x));
             }
         }
         Placement::RightOrBottom { offset, inside } => {
             let bounds = offset.offset_rect(bounds);
+            let right = bounds.x + bounds.width;
 
             if *inside {
-                draw_vertical_right_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    tick_marks,
-                    style,
-                    inverse,
-                );
+                place(&bounds, &left_of(right));
             } else {
-                draw_vertical_left_aligned(
-                    renderer,
-                    &bounds,
-                    bounds.x + bounds.width,
-                    tick_marks,
-                    style,
-                    inverse,
-                );
+                place(&bounds, &right_of(right));
             }
         }
         Placement::Center {
@@ -596,16 +112,15 @@ pub fn draw_vertical_tick_marks(
             fill_length,
         } => {
             let bounds = offset.offset_rect(bounds);
+            let center = bounds.center_x();
 
-            draw_vertical_center_aligned(
-                renderer,
-                &bounds,
-                bounds.center_x(),
-                tick_marks,
-                style,
-                *fill_length,
-                inverse,
-            );
+            place(&bounds, &|length| {
+                if *fill_length {
+                    (bounds.x + length, bounds.width - (length * 2.0))
+                } else {
+                    (center - (length / 2.0), length)
+                }
+            });
         }
         Placement::CenterSplit {
             offset,
@@ -613,17 +128,150 @@ pub fn draw_vertical_tick_marks(
             gap,
         } => {
             let bounds = offset.offset_rect(bounds);
+            let center = bounds.center_x();
+            let right = bounds.x + bounds.width;
 
-            draw_vertical_center_aligned_split(
-                renderer,
-                &bounds,
-                bounds.center_x(),
-                tick_marks,
-                style,
-                *fill_length,
-                *gap,
-                inverse,
-            );
+            if *fill_length {
+                place(&bounds, &right_of(bounds.x));
+                place(&bounds, &left_of(right));
+            } else {
+                place(&bounds, &left_of(center - (gap / 2.0)));
+                place(&bounds, &right_of(center + (gap / 2.0)));
+            }
         }
     };
+
+    marks
+}
+
+/// Draws tick marks on a vertical axis.
+///
+/// * bounds - The bounds of the widget to place the tick marks in/outside of.
+/// * tick_marks - The group of tick marks.
+/// * style - The tick marks style.
+/// * placement - The placement of the tick marks relative to the bounds.
+/// * inverse - Whether to inverse the positions of the tick marks (true) or
+///   not (false).
+pub fn draw_vertical_tick_marks<Renderer>(
+    renderer: &mut Renderer,
+    bounds: &Rectangle,
+    tick_marks: &Group,
+    style: &Appearance,
+    placement: &Placement,
+    inverse: bool,
+) where
+    Renderer: renderer::Renderer,
+{
+    for mark in vertical_tick_marks(bounds, tick_marks, style, placement, inverse) {
+        mark.draw(renderer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::vertical_tick_marks;
+    use crate::{
+        core::tick_marks::{Appearance, Group, Placement, Shape, Tier},
+        Normal, Offset,
+    };
+    use iced::{Color, Rectangle};
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 20.0,
+        y: 10.0,
+        width: 30.0,
+        height: 100.0,
+    };
+
+    /// Circles 4 pixels wide at 0.0, 0.5, and 1.0.
+    fn marks(placement: Placement, inverse: bool) -> Vec<Rectangle> {
+        let group = Group::from_normalized(&[
+            (Normal::MIN, Tier::One),
+            (Normal::CENTER, Tier::One),
+            (Normal::MAX, Tier::One),
+        ]);
+        let style = Appearance {
+            tier_1: Shape::Circle {
+                diameter: 4.0,
+                color: Color::BLACK,
+            },
+            ..Appearance::default()
+        };
+
+        vertical_tick_marks(&BOUNDS, &group, &style, &placement, inverse)
+            .into_iter()
+            .map(|mark| mark.bounds)
+            .collect()
+    }
+
+    fn xs(marks: &[Rectangle]) -> Vec<(f32, f32)> {
+        let mut xs: Vec<_> = marks.iter().map(|mark| (mark.x, mark.width)).collect();
+        xs.dedup();
+        xs
+    }
+
+    #[test]
+    fn marks_are_centered_on_their_position() {
+        let marks = marks(Placement::default(), false);
+        let ys: Vec<_> = marks.iter().map(|mark| mark.center_y()).collect();
+
+        // The minimum is at the bottom.
+        assert_eq!(ys[..3], [110.0, 60.0, 10.0]);
+        assert!(marks.iter().all(|mark| mark.height == 4.0));
+
+        let inverse = self::marks(Placement::default(), true);
+        assert_eq!(inverse[0].center_y(), 10.0);
+    }
+
+    #[test]
+    fn marks_are_aligned_to_the_edges() {
+        let offset = Offset::new(-1.0, 0.0);
+        let placed = |placement| xs(&marks(placement, false));
+
+        assert_eq!(
+            placed(Placement::BothSides {
+                offset,
+                inside: false
+            }),
+            [(15.0, 4.0), (49.0, 4.0)]
+        );
+        assert_eq!(
+            placed(Placement::LeftOrTop {
+                offset,
+                inside: true
+            }),
+            [(19.0, 4.0)]
+        );
+        assert_eq!(
+            placed(Placement::RightOrBottom {
+                offset,
+                inside: false
+            }),
+            [(49.0, 4.0)]
+        );
+        assert_eq!(
+            placed(Placement::Center {
+                offset,
+                fill_length: false
+            }),
+            [(32.0, 4.0)]
+        );
+    }
+
+    #[test]
+    fn split_marks_stay_inside_the_bounds() {
+        let split = |fill_length| {
+            xs(&marks(
+                Placement::CenterSplit {
+                    offset: Offset::ZERO,
+                    fill_length,
+                    gap: 2.0,
+                },
+                false,
+            ))
+        };
+
+        assert_eq!(split(false), [(30.0, 4.0), (36.0, 4.0)]);
+        assert_eq!(split(true), [(20.0, 4.0), (46.0, 4.0)]);
+    }
 }
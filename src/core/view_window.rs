@@ -0,0 +1,162 @@
+//! A visible sub-range of a parameter
+
+use std::borrow::Cow;
+
+use super::normal::Normal;
+use super::{text_marks, tick_marks, ModulationRange};
+
+/// The sub-range of a parameter that a widget shows and edits, such as a
+/// slider that is zoomed in to edit 1 kHz to 2 kHz of a frequency.
+///
+/// Values in the window are shown stretched over the whole widget, and values
+/// outside of it are shown at its ends.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewWindow {
+    /// Where the window starts.
+    pub start: Normal,
+    /// Where the window ends.
+    pub end: Normal,
+}
+
+impl ViewWindow {
+    /// The window over the whole range of a parameter.
+    pub const FULL: Self = Self {
+        start: Normal::MIN,
+        end: Normal::MAX,
+    };
+
+    /// Creates a new `ViewWindow`
+    ///
+    /// * start - Where the window starts.
+    /// * end - Where the window ends.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `end` <= `start`
+    pub fn new(start: Normal, end: Normal) -> Self {
+        assert!(end > start, "end must be greater than start");
+
+        Self { start, end }
+    }
+
+    /// Returns the span of the window, where `1.0` is the whole range.
+    pub fn span(&self) -> f32 {
+        self.end.as_f32() - self.start.as_f32()
+    }
+
+    /// Returns whether the window covers the whole range.
+    pub fn is_full(&self) -> bool {
+        *self == Self::FULL
+    }
+
+    /// Returns whether `normal` is in the window (inclusive).
+    pub fn contains(&self, normal: Normal) -> bool {
+        (self.start..=self.end).contains(&normal)
+    }
+
+    /// Returns where `normal` is shown in the window, clipped to its ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{Normal, ViewWindow};
+    ///
+    /// let window = ViewWindow::new(Normal::CENTER, Normal::MAX);
+    ///
+    /// assert_eq!(window.to_view(Normal::from_clipped(0.75)), Normal::CENTER);
+    /// assert_eq!(window.to_view(Normal::MIN), Normal::MIN);
+    /// ```
+    pub fn to_view(&self, normal: Normal) -> Normal {
+        Normal::from_clipped((normal.as_f32() - self.start.as_f32()) / self.span())
+    }
+
+    /// Returns the value that is shown at `view` in the window. This is the
+    /// inverse of [`to_view`].
+    ///
+    /// [`to_view`]: #method.to_view
+    pub fn from_view(&self, view: Normal) -> Normal {
+        Normal::from_clipped(self.start.as_f32() + view.scale(self.span()))
+    }
+
+    /// Returns the tick marks that are in the window, stretched over the
+    /// whole range. They are only copied if the window is not full.
+    pub fn tick_marks<'a>(&self, tick_marks: &'a tick_marks::Group) -> Cow<'a, tick_marks::Group> {
+        if self.is_full() {
+            Cow::Borrowed(tick_marks)
+        } else {
+            Cow::Owned(tick_marks.zoomed(self.start, self.end))
+        }
+    }
+
+    /// Returns the text marks that are in the window, stretched over the
+    /// whole range. They are only copied if the window is not full.
+    pub fn text_marks<'a>(&self, text_marks: &'a text_marks::Group) -> Cow<'a, text_marks::Group> {
+        if self.is_full() {
+            Cow::Borrowed(text_marks)
+        } else {
+            Cow::Owned(text_marks.zoomed(self.start, self.end))
+        }
+    }
+
+    /// Returns the [`ModulationRange`] as shown in the window.
+    ///
+    /// [`ModulationRange`]: struct.ModulationRange.html
+    pub fn mod_range<'a>(&self, mod_range: &'a ModulationRange) -> Cow<'a, ModulationRange> {
+        if self.is_full() {
+            Cow::Borrowed(mod_range)
+        } else {
+            Cow::Owned(ModulationRange {
+                start: self.to_view(mod_range.start),
+                end: self.to_view(mod_range.end),
+                filled_visible: mod_range.filled_visible,
+            })
+        }
+    }
+}
+
+impl Default for ViewWindow {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ViewWindow;
+    use crate::core::{tick_marks, Normal};
+
+    fn normal(value: f32) -> Normal {
+        Normal::from_clipped(value)
+    }
+
+    #[test]
+    fn to_view_and_back() {
+        let window = ViewWindow::new(normal(0.25), normal(0.75));
+
+        assert_eq!(window.to_view(normal(0.5)), Normal::CENTER);
+        assert_eq!(window.to_view(normal(0.1)), Normal::MIN);
+        assert_eq!(window.to_view(normal(0.9)), Normal::MAX);
+        assert_eq!(window.from_view(Normal::MAX), normal(0.75));
+
+        for i in 0..=10 {
+            let view = normal(i as f32 / 10.0);
+            let round_trip = window.to_view(window.from_view(view));
+            assert!((round_trip.as_f32() - view.as_f32()).abs() < 1e-6);
+        }
+
+        assert!(ViewWindow::FULL.is_full());
+        assert_eq!(ViewWindow::FULL.to_view(normal(0.3)), normal(0.3));
+    }
+
+    #[test]
+    fn windowed_marks() {
+        let window = ViewWindow::new(normal(0.25), normal(0.75));
+        let tick_marks = tick_marks::Group::evenly_spaced(5, tick_marks::Tier::One);
+
+        assert_eq!(
+            window.tick_marks(&tick_marks).tier_1(),
+            Some(&[Normal::MIN, Normal::CENTER, Normal::MAX][..])
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! App-wide defaults for the sizes and sensitivities of the widgets.
+//!
+//! Every widget reads the current [`Defaults`] when it is created with
+//! `new()`, and its builders still override them. Call [`set`] once at
+//! startup, e.g. to make every knob of a plugin bigger without repeating the
+//! `size()` builder:
+//!
+//! ```
+//! use iced_audio::defaults::{self, Defaults};
+//!
+//! defaults::set(Defaults {
+//!     knob_size: 40.0,
+//!     ..Defaults::default()
+//! });
+//!
+//! assert_eq!(defaults::get().knob_size, 40.0);
+//! ```
+//!
+//! The fields of [`Defaults`] only exist for the widgets enabled by the
+//! crate features.
+//!
+//! [`Defaults`]: struct.Defaults.html
+//! [`set`]: fn.set.html
+
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+use crate::core::DoubleClick;
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider"
+))]
+use crate::core::{DragThreshold, SliderSensitivity};
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+use iced::time::Duration;
+use std::sync::{PoisonError, RwLock};
+
+static DEFAULTS: RwLock<Defaults> = RwLock::new(Defaults::BUILT_IN);
+
+/// The defaults of the widgets of this crate.
+///
+/// Start from [`Defaults::default()`] and change the fields you need, so new
+/// fields keep their built-in values.
+///
+/// [`Defaults::default()`]: #impl-Default-for-Defaults
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Defaults {
+    /// The width and height of a [`Knob`].
+    ///
+    /// [`Knob`]: ../knob/struct.Knob.html
+    #[cfg(feature = "knob")]
+    pub knob_size: f32,
+    /// The [`SliderSensitivity`] of a [`Knob`].
+    ///
+    /// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+    /// [`Knob`]: ../knob/struct.Knob.html
+    #[cfg(feature = "knob")]
+    pub knob_sensitivity: SliderSensitivity,
+    /// The height of an [`HSlider`].
+    ///
+    /// [`HSlider`]: ../h_slider/struct.HSlider.html
+    #[cfg(feature = "h_slider")]
+    pub h_slider_height: f32,
+    /// The [`SliderSensitivity`] of an [`HSlider`].
+    ///
+    /// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+    /// [`HSlider`]: ../h_slider/struct.HSlider.html
+    #[cfg(feature = "h_slider")]
+    pub h_slider_sensitivity: SliderSensitivity,
+    /// The width of a [`VSlider`].
+    ///
+    /// [`VSlider`]: ../v_slider/struct.VSlider.html
+    #[cfg(feature = "v_slider")]
+    pub v_slider_width: f32,
+    /// The [`SliderSensitivity`] of a [`VSlider`].
+    ///
+    /// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+    /// [`VSlider`]: ../v_slider/struct.VSlider.html
+    #[cfg(feature = "v_slider")]
+    pub v_slider_sensitivity: SliderSensitivity,
+    /// The width of a [`Ramp`].
+    ///
+    /// [`Ramp`]: ../ramp/struct.Ramp.html
+    #[cfg(feature = "ramp")]
+    pub ramp_width: f32,
+    /// The height of a [`Ramp`].
+    ///
+    /// [`Ramp`]: ../ramp/struct.Ramp.html
+    #[cfg(feature = "ramp")]
+    pub ramp_height: f32,
+    /// The [`SliderSensitivity`] of a [`Ramp`].
+    ///
+    /// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+    /// [`Ramp`]: ../ramp/struct.Ramp.html
+    #[cfg(feature = "ramp")]
+    pub ramp_sensitivity: SliderSensitivity,
+    /// The `modifier_scalar` of an [`XYPad`].
+    ///
+    /// [`XYPad`]: ../xy_pad/struct.XYPad.html
+    #[cfg(feature = "xy_pad")]
+    pub xy_pad_modifier_scalar: f32,
+    /// The width and height of a [`ModRangeInput`].
+    ///
+    /// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+    #[cfg(feature = "mod_range_input")]
+    pub mod_range_input_size: f32,
+    /// The [`SliderSensitivity`] of a [`ModRangeInput`].
+    ///
+    /// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+    /// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+    #[cfg(feature = "mod_range_input")]
+    pub mod_range_input_sensitivity: SliderSensitivity,
+    /// The [`SliderSensitivity`] of an [`ArcSlider`].
+    ///
+    /// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+    /// [`ArcSlider`]: ../arc_slider/struct.ArcSlider.html
+    #[cfg(feature = "arc_slider")]
+    pub arc_slider_sensitivity: SliderSensitivity,
+    /// When two presses count as a double click, which resets the widgets
+    /// to their default value.
+    #[cfg(any(
+        feature = "arc_slider",
+        feature = "h_slider",
+        feature = "knob",
+        feature = "mod_range_input",
+        feature = "ramp",
+        feature = "v_slider",
+        feature = "xy_pad"
+    ))]
+    pub double_click: DoubleClick,
+    /// How far the pointer has to move from a press of a widget before it
+    /// drags the value, so that a click or a tap doesn't move it.
+    #[cfg(any(
+        feature = "arc_slider",
+        feature = "h_slider",
+        feature = "knob",
+        feature = "mod_range_input",
+        feature = "ramp",
+        feature = "v_slider"
+    ))]
+    pub drag_threshold: DragThreshold,
+    /// How long the wheel has to be idle to end a gesture of wheel steps,
+    /// which fires `on_grab` at its first step and `on_release` at its end.
+    /// With `None`, every wheel step is a gesture of its own.
+    #[cfg(any(
+        feature = "arc_slider",
+        feature = "h_slider",
+        feature = "knob",
+        feature = "mod_range_input",
+        feature = "ramp",
+        feature = "v_slider",
+        feature = "xy_pad"
+    ))]
+    pub wheel_gesture: Option<Duration>,
+    /// Whether the widgets skip their animations, for users who prefer
+    /// reduced motion or to save GPU time. Values that spring back are back
+    /// at once, and meters follow their level without ballistics.
+    #[cfg(any(
+        feature = "h_slider",
+        feature = "knob",
+        feature = "reduction_meter",
+        feature = "v_slider"
+    ))]
+    pub reduced_motion: bool,
+}
+
+impl Defaults {
+    /// The built-in defaults, made of the `DEFAULT_*` constants of the
+    /// widget modules.
+    pub const BUILT_IN: Self = Self {
+        #[cfg(feature = "knob")]
+        knob_size: crate::knob::DEFAULT_SIZE,
+        #[cfg(feature = "knob")]
+        knob_sensitivity: crate::knob::DEFAULT_SENSITIVITY,
+        #[cfg(feature = "h_slider")]
+        h_slider_height: crate::h_slider::DEFAULT_HEIGHT,
+        #[cfg(feature = "h_slider")]
+        h_slider_sensitivity: crate::h_slider::DEFAULT_SENSITIVITY,
+        #[cfg(feature = "v_slider")]
+        v_slider_width: crate::v_slider::DEFAULT_WIDTH,
+        #[cfg(feature = "v_slider")]
+        v_slider_sensitivity: crate::v_slider::DEFAULT_SENSITIVITY,
+        #[cfg(feature = "ramp")]
+        ramp_width: crate::ramp::DEFAULT_WIDTH,
+        #[cfg(feature = "ramp")]
+        ramp_height: crate::ramp::DEFAULT_HEIGHT,
+        #[cfg(feature = "ramp")]
+        ramp_sensitivity: crate::ramp::DEFAULT_SENSITIVITY,
+        #[cfg(feature = "xy_pad")]
+        xy_pad_modifier_scalar: crate::xy_pad::DEFAULT_MODIFIER_SCALAR,
+        #[cfg(feature = "mod_range_input")]
+        mod_range_input_size: crate::mod_range_input::DEFAULT_SIZE,
+        #[cfg(feature = "mod_range_input")]
+        mod_range_input_sensitivity: crate::mod_range_input::DEFAULT_SENSITIVITY,
+        #[cfg(feature = "arc_slider")]
+        arc_slider_sensitivity: crate::arc_slider::DEFAULT_SENSITIVITY,
+        #[cfg(any(
+            feature = "arc_slider",
+            feature = "h_slider",
+            feature = "knob",
+            feature = "mod_range_input",
+            feature = "ramp",
+            feature = "v_slider",
+            feature = "xy_pad"
+        ))]
+        double_click: DoubleClick::DEFAULT,
+        #[cfg(any(
+            feature = "arc_slider",
+            feature = "h_slider",
+            feature = "knob",
+            feature = "mod_range_input",
+            feature = "ramp",
+            feature = "v_slider"
+        ))]
+        drag_threshold: DragThreshold::DEFAULT,
+        #[cfg(any(
+            feature = "arc_slider",
+            feature = "h_slider",
+            feature = "knob",
+            feature = "mod_range_input",
+            feature = "ramp",
+            feature = "v_slider",
+            feature = "xy_pad"
+        ))]
+        wheel_gesture: None,
+        #[cfg(any(
+            feature = "h_slider",
+            feature = "knob",
+            feature = "reduction_meter",
+            feature = "v_slider"
+        ))]
+        reduced_motion: false,
+    };
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self::BUILT_IN
+    }
+}
+
+/// Replaces the [`Defaults`] of the widgets created from now on.
+///
+/// Widgets that already exist keep their values, so call it before building
+/// the first view.
+///
+/// [`Defaults`]: struct.Defaults.html
+pub fn set(defaults: Defaults) {
+    *DEFAULTS.write().unwrap_or_else(PoisonError::into_inner) = defaults;
+}
+
+/// Returns the current [`Defaults`] of the widgets.
+///
+/// [`Defaults`]: struct.Defaults.html
+pub fn get() -> Defaults {
+    *DEFAULTS.read().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,240 @@
+//! Glue for binding widgets to the parameters of a plugin host.
+//!
+//! Plugin frameworks such as [nih-plug] expose parameters that have to be
+//! changed inside of a "gesture": the host must be told when the user grabs
+//! a control, every normalized value the control produces, and when the user
+//! releases it again. The functions in this module create widgets that emit
+//! a [`HostMessage`] for each of these steps, and [`HostMessage::apply()`]
+//! forwards them to anything implementing [`HostParamSetter`].
+//!
+//! This module does not depend on any plugin framework, the setter is
+//! implemented by the application. For example, with nih-plug's
+//! `ParamSetter` and parameters identified by their `ParamPtr`:
+//!
+//! ```
+//! # mod nih_plug {
+//! #     pub mod context {
+//! #         pub mod gui {
+//! #             // Stand-ins for nih-plug's `GuiContext` and `ParamSetter`.
+//! #             pub trait GuiContext {
+//! #                 unsafe fn raw_begin_set_parameter(&self, param: crate::ParamPtr);
+//! #                 unsafe fn raw_set_parameter_normalized(&self, param: crate::ParamPtr, normalized: f32);
+//! #                 unsafe fn raw_end_set_parameter(&self, param: crate::ParamPtr);
+//! #             }
+//! #
+//! #             pub struct ParamSetter<'a> {
+//! #                 pub raw_context: &'a dyn GuiContext,
+//! #             }
+//! #         }
+//! #     }
+//! # }
+//! # #[derive(Clone, Copy)]
+//! # pub struct ParamPtr;
+//! use iced_audio::{host::HostParamSetter, Normal};
+//!
+//! struct Setter<'a>(&'a nih_plug::context::gui::ParamSetter<'a>);
+//!
+//! impl HostParamSetter<ParamPtr> for Setter<'_> {
+//!     fn begin_set(&mut self, param: &ParamPtr) {
+//!         unsafe { self.0.raw_context.raw_begin_set_parameter(*param) };
+//!     }
+//!
+//!     fn set_normalized(&mut self, param: &ParamPtr, normal: Normal) {
+//!         unsafe { self.0.raw_context.raw_set_parameter_normalized(*param, normal.as_f32()) };
+//!     }
+//!
+//!     fn end_set(&mut self, param: &ParamPtr) {
+//!         unsafe { self.0.raw_context.raw_end_set_parameter(*param) };
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! [nih-plug]: https://github.com/robbert-vdh/nih-plug
+//! [`HostMessage`]: enum.HostMessage.html
+//! [`HostMessage::apply()`]: enum.HostMessage.html#method.apply
+//! [`HostParamSetter`]: trait.HostParamSetter.html
+
+use crate::core::{Normal, NormalParam};
+
+/// Something that can change the parameters of a plugin host.
+pub trait HostParamSetter<ID> {
+    /// Tells the host that the user started changing the parameter.
+    fn begin_set(&mut self, param: &ID);
+
+    /// Sets the normalized value of the parameter.
+    fn set_normalized(&mut self, param: &ID, normal: Normal);
+
+    /// Tells the host that the user finished changing the parameter.
+    fn end_set(&mut self, param: &ID);
+}
+
+/// A message produced by a widget that is bound to a host parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HostMessage<ID> {
+    /// The user grabbed the widget.
+    BeginSet(ID),
+    /// The widget produced a new normalized value.
+    SetNormalized(ID, Normal),
+    /// The user released the widget.
+    EndSet(ID),
+}
+
+impl<ID> HostMessage<ID> {
+    /// Returns the parameter this message is about.
+    pub fn param(&self) -> &ID {
+        match self {
+            HostMessage::BeginSet(param)
+            | HostMessage::SetNormalized(param, _)
+            | HostMessage::EndSet(param) => param,
+        }
+    }
+
+    /// Forwards this message to the `setter`.
+    pub fn apply<S: HostParamSetter<ID>>(&self, setter: &mut S) {
+        match self {
+            HostMessage::BeginSet(param) => setter.begin_set(param),
+            HostMessage::SetNormalized(param, normal) => setter.set_normalized(param, *normal),
+            HostMessage::EndSet(param) => setter.end_set(param),
+        }
+    }
+}
+
+/// Creates a new [`Knob`] bound to the host parameter `param`.
+///
+/// * `normal_param` - the current and default normalized value of the
+///   parameter, as reported by the host
+///
+/// [`Knob`]: ../knob/struct.Knob.html
+#[cfg(feature = "knob")]
+pub fn knob<'a, ID, Theme>(
+    param: ID,
+    normal_param: NormalParam,
+) -> crate::Knob<'a, HostMessage<ID>, Theme>
+where
+    ID: 'a + Clone,
+    Theme: crate::style::knob::Catalog,
+{
+    let grab_param = param.clone();
+    let release_param = param.clone();
+
+    crate::Knob::new(normal_param, move |normal| {
+        HostMessage::SetNormalized(param.clone(), normal)
+    })
+    .on_grab(move || Some(HostMessage::BeginSet(grab_param.clone())))
+    .on_release(move || Some(HostMessage::EndSet(release_param.clone())))
+}
+
+/// Creates a new [`HSlider`] bound to the host parameter `param`.
+///
+/// * `normal_param` - the current and default normalized value of the
+///   parameter, as reported by the host
+///
+/// [`HSlider`]: ../h_slider/struct.HSlider.html
+#[cfg(feature = "h_slider")]
+pub fn h_slider<'a, ID, Theme>(
+    param: ID,
+    normal_param: NormalParam,
+) -> crate::HSlider<'a, HostMessage<ID>, Theme>
+where
+    ID: 'a + Clone,
+    Theme: crate::style::h_slider::Catalog,
+{
+    let grab_param = param.clone();
+    let release_param = param.clone();
+
+    crate::HSlider::new(normal_param, move |normal| {
+        HostMessage::SetNormalized(param.clone(), normal)
+    })
+    .on_grab(move || Some(HostMessage::BeginSet(grab_param.clone())))
+    .on_release(move || Some(HostMessage::EndSet(release_param.clone())))
+}
+
+/// Creates a new [`VSlider`] bound to the host parameter `param`.
+///
+/// * `normal_param` - the current and default normalized value of the
+///   parameter, as reported by the host
+///
+/// [`VSlider`]: ../v_slider/struct.VSlider.html
+#[cfg(feature = "v_slider")]
+pub fn v_slider<'a, ID, Theme>(
+    param: ID,
+    normal_param: NormalParam,
+) -> crate::VSlider<'a, HostMessage<ID>, Theme>
+where
+    ID: 'a + Clone,
+    Theme: crate::style::v_slider::Catalog,
+{
+    let grab_param = param.clone();
+    let release_param = param.clone();
+
+    crate::VSlider::new(normal_param, move |normal| {
+        HostMessage::SetNormalized(param.clone(), normal)
+    })
+    .on_grab(move || Some(HostMessage::BeginSet(grab_param.clone())))
+    .on_release(move || Some(HostMessage::EndSet(release_param.clone())))
+}
+
+/// Creates a new [`ModRangeInput`] bound to the host parameter `param`.
+///
+/// * `normal_param` - the current and default normalized value of the
+///   parameter, as reported by the host
+///
+/// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+#[cfg(feature = "mod_range_input")]
+pub fn mod_range_input<'a, ID, Theme>(
+    param: ID,
+    normal_param: NormalParam,
+) -> crate::ModRangeInput<'a, HostMessage<ID>, Theme>
+where
+    ID: 'a + Clone,
+    Theme: crate::style::mod_range_input::Catalog,
+{
+    let grab_param = param.clone();
+    let release_param = param.clone();
+
+    crate::ModRangeInput::new(normal_param, move |normal| {
+        HostMessage::SetNormalized(param.clone(), normal)
+    })
+    .on_grab(move || Some(HostMessage::BeginSet(grab_param.clone())))
+    .on_release(move || Some(HostMessage::EndSet(release_param.clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HostMessage, HostParamSetter};
+    use crate::core::Normal;
+
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl HostParamSetter<u32> for Recorder {
+        fn begin_set(&mut self, param: &u32) {
+            self.0.push(format!("begin {param}"));
+        }
+
+        fn set_normalized(&mut self, param: &u32, normal: Normal) {
+            self.0.push(format!("set {param} {}", normal.as_f32()));
+        }
+
+        fn end_set(&mut self, param: &u32) {
+            self.0.push(format!("end {param}"));
+        }
+    }
+
+    #[test]
+    fn apply() {
+        let mut recorder = Recorder::default();
+
+        for message in [
+            HostMessage::BeginSet(3),
+            HostMessage::SetNormalized(3, Normal::CENTER),
+            HostMessage::EndSet(3),
+        ] {
+            assert_eq!(*message.param(), 3);
+            message.apply(&mut recorder);
+        }
+
+        assert_eq!(recorder.0, ["begin 3", "set 3 0.5", "end 3"]);
+    }
+}
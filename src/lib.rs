@@ -1,8 +1,22 @@
 mod core;
 mod widget;
 
+#[cfg(feature = "a11y")]
+pub mod a11y;
+pub mod defaults;
+pub mod operation;
+pub mod prelude;
 pub mod style;
 
+#[cfg(feature = "host")]
+pub mod host;
+
+#[cfg(feature = "baseview")]
+pub mod baseview;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
 pub use crate::core::*;
 
 pub use core::text_marks;
@@ -13,6 +27,11 @@ pub use widget::knob;
 #[cfg(feature = "knob")]
 pub use widget::knob::Knob;
 
+#[cfg(feature = "arc_slider")]
+pub use widget::arc_slider;
+#[cfg(feature = "arc_slider")]
+pub use widget::arc_slider::ArcSlider;
+
 #[cfg(feature = "h_slider")]
 pub use widget::h_slider;
 #[cfg(feature = "h_slider")]
@@ -23,6 +42,11 @@ pub use widget::v_slider;
 #[cfg(feature = "v_slider")]
 pub use widget::v_slider::VSlider;
 
+#[cfg(feature = "stereo_slider")]
+pub use widget::stereo_slider;
+#[cfg(feature = "stereo_slider")]
+pub use widget::stereo_slider::StereoSlider;
+
 #[cfg(feature = "ramp")]
 pub use widget::ramp;
 #[cfg(feature = "ramp")]
@@ -33,7 +57,57 @@ pub use widget::xy_pad;
 #[cfg(feature = "xy_pad")]
 pub use widget::xy_pad::XYPad;
 
+#[cfg(feature = "marks")]
+pub use widget::marks;
+#[cfg(feature = "marks")]
+pub use widget::marks::{TextMarks, TickMarks};
+#[cfg(feature = "marks")]
+pub use widget::ruler;
+#[cfg(feature = "marks")]
+pub use widget::ruler::Ruler;
+
 #[cfg(feature = "mod_range_input")]
 pub use widget::mod_range_input;
 #[cfg(feature = "mod_range_input")]
 pub use widget::mod_range_input::ModRangeInput;
+
+#[cfg(feature = "mod_range_knob")]
+pub use widget::mod_range_knob;
+#[cfg(feature = "mod_range_knob")]
+pub use widget::mod_range_knob::ModRangeKnob;
+
+#[cfg(feature = "labeled")]
+pub use widget::labeled;
+#[cfg(feature = "labeled")]
+pub use widget::labeled::Labeled;
+#[cfg(all(feature = "labeled", feature = "h_slider"))]
+pub use widget::labeled::LabeledHSlider;
+#[cfg(all(feature = "labeled", feature = "knob"))]
+pub use widget::labeled::LabeledKnob;
+#[cfg(all(feature = "labeled", feature = "v_slider"))]
+pub use widget::labeled::LabeledSlider;
+
+#[cfg(feature = "reduction_meter")]
+pub use widget::reduction_meter;
+#[cfg(feature = "reduction_meter")]
+pub use widget::reduction_meter::ReductionMeter;
+
+#[cfg(feature = "loudness_meter")]
+pub use widget::loudness_meter;
+#[cfg(feature = "loudness_meter")]
+pub use widget::loudness_meter::LoudnessMeter;
+
+#[cfg(feature = "meter_bridge")]
+pub use widget::meter_bridge;
+#[cfg(feature = "meter_bridge")]
+pub use widget::meter_bridge::MeterBridge;
+
+#[cfg(feature = "spectrogram")]
+pub use widget::spectrogram;
+#[cfg(feature = "spectrogram")]
+pub use widget::spectrogram::Spectrogram;
+
+#[cfg(feature = "compressor_curve")]
+pub use widget::compressor_curve;
+#[cfg(feature = "compressor_curve")]
+pub use widget::compressor_curve::CompressorCurve;
@@ -0,0 +1,330 @@
+//! Query and control the widgets from the application.
+//!
+//! Give a widget an id with its `id()` builder, then run one of these
+//! operations with [`iced::advanced::widget::operate`], e.g. to pause meter
+//! animations or to block preset switches while the user drags a knob:
+//!
+//! ```no_run
+//! use iced::{advanced::widget::{operate, Id}, Task};
+//! use iced_audio::operation;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     CutoffDragging(bool),
+//! }
+//!
+//! fn check_cutoff() -> Task<Message> {
+//!     operate(operation::is_dragging(Id::new("cutoff"))).map(Message::CutoffDragging)
+//! }
+//! ```
+//!
+//! The widgets are focusable, so the [`focus`], [`focus_next`] and
+//! [`focus_previous`] operations of iced work with them, e.g. for keyboard
+//! navigation. A widget focused by them draws a [focus ring] until it is
+//! clicked. [`nudge`] and [`reset`] change the value of a widget like
+//! the mouse wheel and a double click do. Operations cannot produce the
+//! widget's messages, so they return the [`Change`] instead, which the
+//! application maps to its own message:
+//!
+//! ```no_run
+//! use iced::{advanced::widget::{operate, Id}, Task};
+//! use iced_audio::{operation, Change};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Cutoff(Option<Change>),
+//! }
+//!
+//! fn nudge_cutoff_up() -> Task<Message> {
+//!     operate(operation::nudge(Id::new("cutoff"), 0.01)).map(Message::Cutoff)
+//! }
+//! ```
+//!
+//! [`handle_position`] returns where the handle of a widget is in the
+//! window, e.g. to draw a modulation cable from a mod source to a knob.
+//!
+//! [`iced::advanced::widget::operate`]: https://docs.rs/iced/0.13/iced/advanced/widget/fn.operate.html
+//! [`focus`]: fn.focus.html
+//! [`focus_next`]: fn.focus_next.html
+//! [`focus_previous`]: fn.focus_previous.html
+//! [focus ring]: ../style/focus_ring/index.html
+//! [`nudge`]: fn.nudge.html
+//! [`reset`]: fn.reset.html
+//! [`handle_position`]: fn.handle_position.html
+//! [`Change`]: ../struct.Change.html
+
+use crate::core::{Change, ChangeSource, Normal, SliderStatus};
+use iced::{
+    advanced::widget::{
+        operation::{Outcome, Scrollable},
+        Id, Operation,
+    },
+    Point, Rectangle, Vector,
+};
+use std::any::Any;
+
+pub use iced::advanced::widget::operation::focusable::{focus, focus_next, focus_previous};
+
+/// The drag state of a widget of this crate.
+///
+/// Every widget of this crate passes its [`DragState`] to
+/// `Operation::custom()`, so custom operations can downcast to it.
+///
+/// [`DragState`]: struct.DragState.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DragState {
+    /// The [`SliderStatus`] of the current drag, or `None` if the widget is
+    /// not being dragged. It becomes `Moved` once the drag changed the
+    /// value of the widget.
+    ///
+    /// [`SliderStatus`]: ../enum.SliderStatus.html
+    pub status: Option<SliderStatus>,
+}
+
+impl DragState {
+    /// Whether the widget is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.status.is_some()
+    }
+}
+
+/// Produces an [`Operation`] that returns whether the widget with the given
+/// `id` is being dragged. It returns `false` if there is no such widget.
+///
+/// [`Operation`]: https://docs.rs/iced/0.13/iced/advanced/widget/trait.Operation.html
+pub fn is_dragging(id: Id) -> impl Operation<bool> {
+    struct IsDragging {
+        id: Id,
+        dragging: bool,
+    }
+
+    impl Operation<bool> for IsDragging {
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if id == Some(&self.id) {
+                if let Some(drag_state) = state.downcast_ref::<DragState>() {
+                    self.dragging = drag_state.is_dragging();
+                }
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<bool>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn finish(&self) -> Outcome<bool> {
+            Outcome::Some(self.dragging)
+        }
+    }
+
+    IsDragging {
+        id,
+        dragging: false,
+    }
+}
+
+/// Produces an [`Operation`] that returns whether any widget of this crate
+/// is being dragged.
+///
+/// [`Operation`]: https://docs.rs/iced/0.13/iced/advanced/widget/trait.Operation.html
+pub fn any_dragging() -> impl Operation<bool> {
+    struct AnyDragging {
+        dragging: bool,
+    }
+
+    impl Operation<bool> for AnyDragging {
+        fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+            if let Some(drag_state) = state.downcast_ref::<DragState>() {
+                self.dragging |= drag_state.is_dragging();
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<bool>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn finish(&self) -> Outcome<bool> {
+            Outcome::Some(self.dragging)
+        }
+    }
+
+    AnyDragging { dragging: false }
+}
+
+/// The value of a widget of this crate.
+///
+/// Every widget of this crate that controls a single [`NormalParam`] passes
+/// its [`ValueState`] to `Operation::custom()`, so custom operations can
+/// downcast to it.
+///
+/// [`NormalParam`]: ../struct.NormalParam.html
+/// [`ValueState`]: struct.ValueState.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValueState {
+    /// The current value of the widget.
+    pub normal: Normal,
+    /// The default value of the widget.
+    pub default: Normal,
+}
+
+/// Produces an [`Operation`] that moves the value of the widget with the
+/// given `id` by `delta`, and returns the resulting [`Change`].
+///
+/// It returns `None` if there is no such widget or the value did not
+/// change, e.g. because it is already at its maximum.
+///
+/// [`Operation`]: https://docs.rs/iced/0.13/iced/advanced/widget/trait.Operation.html
+/// [`Change`]: ../struct.Change.html
+pub fn nudge(id: Id, delta: f32) -> impl Operation<Option<Change>> {
+    ChangeValue {
+        id,
+        new_value: move |value: ValueState| Normal::from_clipped(value.normal.as_f32() + delta),
+        change: None,
+    }
+}
+
+/// Produces an [`Operation`] that resets the widget with the given `id` to
+/// its default value, and returns the resulting [`Change`].
+///
+/// It returns `None` if there is no such widget or it already is at its
+/// default value.
+///
+/// [`Operation`]: https://docs.rs/iced/0.13/iced/advanced/widget/trait.Operation.html
+/// [`Change`]: ../struct.Change.html
+pub fn reset(id: Id) -> impl Operation<Option<Change>> {
+    ChangeValue {
+        id,
+        new_value: |value: ValueState| value.default,
+        change: None,
+    }
+}
+
+struct ChangeValue<F> {
+    id: Id,
+    new_value: F,
+    change: Option<Change>,
+}
+
+impl<F> Operation<Option<Change>> for ChangeValue<F>
+where
+    F: Fn(ValueState) -> Normal + Send,
+{
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id != Some(&self.id) {
+            return;
+        }
+
+        if let Some(value) = state.downcast_ref::<ValueState>() {
+            let normal = (self.new_value)(*value);
+
+            self.change = (normal != value.normal).then_some(Change {
+                normal,
+                previous: value.normal,
+                via: ChangeSource::Operation,
+            });
+        }
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Option<Change>>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn finish(&self) -> Outcome<Option<Change>> {
+        Outcome::Some(self.change)
+    }
+}
+
+/// The position of the handle of a widget of this crate.
+///
+/// Every widget of this crate with a handle passes its [`HandleState`] to
+/// `Operation::custom()`, so custom operations can downcast to it.
+///
+/// [`HandleState`]: struct.HandleState.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HandleState {
+    /// The center of the handle in the coordinates of the layout, which are
+    /// the coordinates of the window outside of scrollables.
+    ///
+    /// The center of a knob is its handle, and a slider reports the point
+    /// on the center line of its body at its value.
+    pub center: Point,
+}
+
+/// Produces an [`Operation`] that returns the center of the handle of the
+/// widget with the given `id` in window coordinates, taking the scroll
+/// offsets of the scrollables around it into account.
+///
+/// It returns `None` if there is no such widget or it has no handle.
+///
+/// [`Operation`]: https://docs.rs/iced/0.13/iced/advanced/widget/trait.Operation.html
+pub fn handle_position(id: Id) -> impl Operation<Option<Point>> {
+    struct HandlePosition {
+        id: Id,
+        /// The scroll offset of the scrollables around the current widget.
+        translation: Vector,
+        /// The scroll offset of the scrollable whose content is operated on
+        /// next.
+        scrolled: Vector,
+        position: Option<Point>,
+    }
+
+    impl Operation<Option<Point>> for HandlePosition {
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if id == Some(&self.id) {
+                if let Some(handle) = state.downcast_ref::<HandleState>() {
+                    self.position = Some(handle.center - self.translation);
+                }
+            }
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.scrolled = translation;
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Option<Point>>),
+        ) {
+            let outer = self.translation;
+            self.translation = outer + std::mem::take(&mut self.scrolled);
+
+            operate_on_children(self);
+
+            self.translation = outer;
+        }
+
+        fn finish(&self) -> Outcome<Option<Point>> {
+            Outcome::Some(self.position)
+        }
+    }
+
+    HandlePosition {
+        id,
+        translation: Vector::ZERO,
+        scrolled: Vector::ZERO,
+        position: None,
+    }
+}
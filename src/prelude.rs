@@ -0,0 +1,74 @@
+//! The types most apps need, for a single import
+//!
+//! ```
+//! use iced_audio::prelude::*;
+//!
+//! let range = FloatRange::default_bipolar();
+//! let param: NormalParam = range.default_normal_param();
+//! # let _ = param;
+//! ```
+//!
+//! This brings in the widgets, [`Normal`] and [`NormalParam`], the ranges,
+//! the groups of tick marks and text marks, and the style types that most
+//! styles are written with. The [`Appearance`] of each widget is named after
+//! it, e.g. [`KnobAppearance`]. Everything else a widget has stays in its
+//! module, e.g. `iced_audio::knob::NotchShape`.
+//!
+//! [`Normal`]: ../struct.Normal.html
+//! [`NormalParam`]: ../struct.NormalParam.html
+//! [`Appearance`]: ../knob/struct.Appearance.html
+//! [`KnobAppearance`]: ../knob/struct.Appearance.html
+
+pub use crate::core::{
+    text_marks::Group as TextMarkGroup, tick_marks::Group as TickMarkGroup, Change, ChangeSource,
+    FaderRange, FaderTaper, FloatRange, FreqRange, IntRange, LogDBRange, ModulationRange, Normal,
+    NormalParam, NoteRange, Offset, Param, Range, RangedParam, SliderDirection, SliderSensitivity,
+    TempoDivision, TempoSyncRange,
+};
+pub use crate::style::{palette::Palette, presets::Preset, Status};
+
+#[cfg(feature = "arc_slider")]
+pub use crate::widget::arc_slider::{Appearance as ArcSliderAppearance, ArcSlider};
+#[cfg(feature = "compressor_curve")]
+pub use crate::widget::compressor_curve::{
+    Appearance as CompressorCurveAppearance, CompressorCurve,
+};
+#[cfg(feature = "h_slider")]
+pub use crate::widget::h_slider::{Appearance as HSliderAppearance, HSlider};
+#[cfg(feature = "knob")]
+pub use crate::widget::knob::{Appearance as KnobAppearance, Knob};
+#[cfg(feature = "labeled")]
+pub use crate::widget::labeled::{Appearance as LabeledAppearance, Labeled};
+#[cfg(all(feature = "labeled", feature = "h_slider"))]
+pub use crate::widget::labeled::LabeledHSlider;
+#[cfg(all(feature = "labeled", feature = "knob"))]
+pub use crate::widget::labeled::LabeledKnob;
+#[cfg(all(feature = "labeled", feature = "v_slider"))]
+pub use crate::widget::labeled::LabeledSlider;
+#[cfg(feature = "loudness_meter")]
+pub use crate::widget::loudness_meter::{Appearance as LoudnessMeterAppearance, LoudnessMeter};
+#[cfg(feature = "meter_bridge")]
+pub use crate::widget::meter_bridge::{Appearance as MeterBridgeAppearance, MeterBridge};
+#[cfg(feature = "mod_range_input")]
+pub use crate::widget::mod_range_input::{Appearance as ModRangeInputAppearance, ModRangeInput};
+#[cfg(feature = "mod_range_knob")]
+pub use crate::widget::mod_range_knob::ModRangeKnob;
+#[cfg(feature = "ramp")]
+pub use crate::widget::ramp::{Appearance as RampAppearance, Ramp};
+#[cfg(feature = "reduction_meter")]
+pub use crate::widget::reduction_meter::{Appearance as ReductionMeterAppearance, ReductionMeter};
+#[cfg(feature = "spectrogram")]
+pub use crate::widget::spectrogram::{
+    Appearance as SpectrogramAppearance, Spectrogram, SpectrogramData,
+};
+#[cfg(feature = "stereo_slider")]
+pub use crate::widget::stereo_slider::{Appearance as StereoSliderAppearance, StereoSlider};
+#[cfg(feature = "v_slider")]
+pub use crate::widget::v_slider::{Appearance as VSliderAppearance, VSlider};
+#[cfg(feature = "xy_pad")]
+pub use crate::widget::xy_pad::{Appearance as XYPadAppearance, XYPad};
+#[cfg(feature = "marks")]
+pub use crate::widget::{
+    marks::{TextMarks, TickMarks},
+    ruler::Ruler,
+};
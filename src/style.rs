@@ -2,11 +2,85 @@
 
 mod default_colors;
 
+pub mod arc_slider;
+pub mod compressor_curve;
+pub mod focus_ring;
 pub mod h_slider;
 pub mod knob;
+pub mod labeled;
+pub mod loudness_meter;
+pub mod meter_bridge;
 pub mod mod_range_input;
+pub mod palette;
+pub mod presets;
 pub mod ramp;
+pub mod reduction_meter;
+pub mod registry;
+#[cfg(feature = "serde")]
+mod serde_color;
+pub mod skin;
+pub mod spectrogram;
+pub mod stereo_slider;
 pub mod text_marks;
+pub mod texture;
 pub mod tick_marks;
 pub mod v_slider;
+pub mod value_text;
+#[cfg(feature = "hot_reload")]
+pub mod watcher;
 pub mod xy_pad;
+
+use iced::Color;
+
+/// The possible statuses of a widget, passed to its style function.
+///
+/// New statuses may be added in the future, so styles should fall back to
+/// one of the existing appearances for statuses they don't handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Status {
+    /// The widget is idle.
+    Active,
+    /// The cursor is over the widget.
+    Hovered,
+    /// The widget is being dragged.
+    Dragged,
+    /// The widget has keyboard focus.
+    Focused,
+    /// The widget can't be interacted with.
+    Disabled,
+    /// A drag and drop of the application is over the widget, which
+    /// accepts it.
+    DropTarget,
+}
+
+/// Multiplies the alpha of all the colors of an appearance by an opacity.
+///
+/// The widgets dim their appearances with it when their `opacity` is below
+/// `1.0`, e.g. for the controls of a bypassed section. Textures are dimmed
+/// by the widgets when they are drawn.
+pub trait Dim {
+    /// Returns `self` with the alpha of its colors multiplied by `opacity`.
+    fn dim(self, opacity: f32) -> Self;
+}
+
+impl Dim for Color {
+    fn dim(self, opacity: f32) -> Self {
+        Color {
+            a: self.a * opacity,
+            ..self
+        }
+    }
+}
+
+impl<T: Dim> Dim for Option<T> {
+    fn dim(self, opacity: f32) -> Self {
+        self.map(|value| value.dim(opacity))
+    }
+}
+
+impl<A: Dim, B: Dim> Dim for (A, B) {
+    fn dim(self, opacity: f32) -> Self {
+        (self.0.dim(opacity), self.1.dim(opacity))
+    }
+}
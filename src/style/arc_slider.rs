@@ -0,0 +1,218 @@
+//! Various styles for the [`ArcSlider`] widget
+//!
+//! [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+
+use crate::style::{
+    default_colors, focus_ring,
+    palette::Palette,
+    presets::{self, Preset},
+    Dim,
+};
+use iced::{Color, Theme};
+
+pub use crate::style::Status;
+
+pub use iced::widget::canvas::LineCap;
+
+/// The appearance of an [`ArcSlider`]
+///
+/// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+#[derive(Debug, Clone)]
+pub struct Appearance {
+    /// The width of the rail along the arc
+    pub rail_width: f32,
+    /// The color of the rail
+    pub rail_color: Color,
+    /// The color of the filled portion of the rail, from the start of the
+    /// arc to the handle
+    pub filled_color: Color,
+    /// The cap of the ends of the rail and of its filled portion
+    pub cap: LineCap,
+    /// The diameter of the handle
+    pub handle_diameter: f32,
+    /// The color of the handle
+    pub handle_color: Color,
+    /// The width of the border of the handle
+    pub handle_border_width: f32,
+    /// The color of the border of the handle
+    pub handle_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            rail_width: 4.0,
+            rail_color: default_colors::SLIDER_RAIL.1,
+            filled_color: default_colors::SLIDER_RAIL.0,
+            cap: LineCap::Round,
+            handle_diameter: 14.0,
+            handle_color: default_colors::LIGHT_BACK,
+            handle_border_width: 1.0,
+            handle_border_color: default_colors::BORDER,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of an [`ArcSlider`].
+///
+/// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of an [`ArcSlider`] with the given [`Status`].
+    ///
+    /// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+    /// [`Status`]: ../enum.Status.html
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
+
+    /// The style of the ring drawn around an [`ArcSlider`] that was focused
+    /// with the keyboard.
+    ///
+    /// The default is [`focus_ring::Appearance::default()`]. For no focus
+    /// ring, override this to return `None`.
+    ///
+    /// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+    /// [`focus_ring::Appearance::default()`]: ../focus_ring/struct.Appearance.html
+    fn focus_ring_appearance(&self, _style: &Self::Style) -> Option<focus_ring::Appearance> {
+        Some(focus_ring::Appearance::default())
+    }
+}
+
+/// A styling function for an [`ArcSlider`].
+///
+/// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The theme catalog of an [`ArcSlider`].
+///
+/// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class with the given status.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+
+    /// The style of the focus ring of a class.
+    fn focus_ring_appearance(&self, _class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        None
+    }
+}
+
+/// The class of an [`ArcSlider`] for the built-in `Theme`.
+///
+/// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+#[derive(Default)]
+pub enum ArcSlider<'a> {
+    /// The default style.
+    #[default]
+    Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl<'a, S> From<S> for ArcSlider<'a>
+where
+    S: 'static + StyleSheet<Style = Theme>,
+{
+    fn from(val: S) -> Self {
+        ArcSlider::Custom(Box::new(val))
+    }
+}
+
+impl<'a> From<StyleFn<'a, Theme>> for ArcSlider<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        ArcSlider::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = ArcSlider<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        ArcSlider::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            ArcSlider::Default => default(self, status),
+            ArcSlider::Preset(preset) => with_palette(&preset.palette(self), status),
+            ArcSlider::Function(style) => style(self, status),
+            ArcSlider::Custom(custom) => custom.appearance(self, status),
+        }
+    }
+
+    fn focus_ring_appearance(&self, class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        match class {
+            ArcSlider::Custom(custom) => custom.focus_ring_appearance(self),
+            _ => Some(focus_ring::with_palette(&palette(self, class))),
+        }
+    }
+}
+
+/// The default style of an [`ArcSlider`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    with_palette(&presets::global().palette(theme), status)
+}
+
+/// The default style of an [`ArcSlider`] with the colors of the `palette`.
+///
+/// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+pub fn with_palette(palette: &Palette, status: Status) -> Appearance {
+    let handle_color = match status {
+        Status::Active | Status::Disabled => palette.back,
+        Status::Hovered | Status::Focused | Status::DropTarget => palette.back_hover,
+        Status::Dragged => palette.back_drag,
+    };
+
+    Appearance {
+        rail_color: palette.slider_rail.1,
+        filled_color: palette.slider_rail.0,
+        handle_color,
+        handle_border_color: palette.border,
+        ..Default::default()
+    }
+}
+
+/// The palette of a class: the one of its [`Preset`], or the global one.
+///
+/// [`Preset`]: ../presets/enum.Preset.html
+fn palette(theme: &Theme, class: &ArcSlider<'_>) -> Palette {
+    match class {
+        ArcSlider::Preset(preset) => preset.palette(theme),
+        _ => presets::global().palette(theme),
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            rail_color: self.rail_color.dim(opacity),
+            filled_color: self.filled_color.dim(opacity),
+            handle_color: self.handle_color.dim(opacity),
+            handle_border_color: self.handle_border_color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! Various styles for the [`CompressorCurve`] widget
+//!
+//! [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+
+use crate::style::{
+    palette::Palette,
+    presets::{self, Preset},
+    Dim,
+};
+use iced::{Color, Theme};
+
+pub use crate::style::Status;
+
+/// The appearance of a [`CompressorCurve`]
+///
+/// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+#[derive(Debug, Clone)]
+pub struct Appearance {
+    /// The color of the background
+    pub back_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The radius of the border
+    pub border_radius: f32,
+    /// The color of the border
+    pub border_color: Color,
+    /// The color of the grid lines at tier 1 tick marks
+    pub grid_color: Color,
+    /// The color of the grid lines at tier 2 and tier 3 tick marks
+    pub grid_minor_color: Color,
+    /// The width of the grid lines
+    pub grid_width: f32,
+    /// The color of the diagonal line where the output equals the input
+    pub unity_color: Color,
+    /// The color of the transfer curve
+    pub curve_color: Color,
+    /// The width of the transfer curve
+    pub curve_width: f32,
+    /// The color of the handles
+    pub handle_color: Color,
+    /// The color of the hovered or dragged handle
+    pub active_handle_color: Color,
+    /// The radius of the handles
+    pub handle_radius: f32,
+    /// The width of the border of the handles
+    pub handle_border_width: f32,
+    /// The color of the border of the handles
+    pub handle_border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`CompressorCurve`].
+///
+/// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of a [`CompressorCurve`] with the given [`Status`].
+    ///
+    /// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+    /// [`Status`]: ../enum.Status.html
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
+}
+
+/// A styling function for a [`CompressorCurve`].
+///
+/// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The theme catalog of a [`CompressorCurve`].
+///
+/// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class with the given status.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+}
+
+/// The class of a [`CompressorCurve`] for the built-in `Theme`.
+///
+/// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+#[derive(Default)]
+pub enum CompressorCurve<'a> {
+    /// The default style.
+    #[default]
+    Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl<'a, S> From<S> for CompressorCurve<'a>
+where
+    S: 'static + StyleSheet<Style = Theme>,
+{
+    fn from(val: S) -> Self {
+        CompressorCurve::Custom(Box::new(val))
+    }
+}
+
+impl<'a> From<StyleFn<'a, Theme>> for CompressorCurve<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        CompressorCurve::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = CompressorCurve<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        CompressorCurve::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            CompressorCurve::Default => default(self, status),
+            CompressorCurve::Preset(preset) => with_palette(&preset.palette(self), status),
+            CompressorCurve::Function(style) => style(self, status),
+            CompressorCurve::Custom(custom) => custom.appearance(self, status),
+        }
+    }
+}
+
+/// The default style of a [`CompressorCurve`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    with_palette(&presets::global().palette(theme), status)
+}
+
+/// The default style of a [`CompressorCurve`] with the colors of the `palette`.
+///
+/// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+pub fn with_palette(palette: &Palette, status: Status) -> Appearance {
+    let handle_color = match status {
+        Status::Hovered | Status::Focused | Status::Dragged | Status::DropTarget => {
+            palette.back_hover
+        }
+        Status::Active | Status::Disabled => palette.back,
+    };
+
+    Appearance {
+        back_color: palette.back,
+        border_width: 1.0,
+        border_radius: 2.0,
+        border_color: palette.border,
+        grid_color: palette.tick_tier_1,
+        grid_minor_color: palette.tick_tier_3,
+        grid_width: 1.0,
+        unity_color: palette.tick_tier_2,
+        curve_color: palette.accent,
+        curve_width: 2.0,
+        handle_color,
+        active_handle_color: palette.accent,
+        handle_radius: 4.0,
+        handle_border_width: 1.0,
+        handle_border_color: palette.border,
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            grid_color: self.grid_color.dim(opacity),
+            grid_minor_color: self.grid_minor_color.dim(opacity),
+            unity_color: self.unity_color.dim(opacity),
+            curve_color: self.curve_color.dim(opacity),
+            handle_color: self.handle_color.dim(opacity),
+            active_handle_color: self.active_handle_color.dim(opacity),
+            handle_border_color: self.handle_border_color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -4,8 +4,6 @@ use iced::Color;
 
 pub const BORDER: Color = Color::from_rgb(0.315, 0.315, 0.315);
 pub const LIGHT_BACK: Color = Color::from_rgb(0.97, 0.97, 0.97);
-pub const LIGHT_BACK_HOVER: Color = Color::from_rgb(0.93, 0.93, 0.93);
-pub const LIGHT_BACK_DRAG: Color = Color::from_rgb(0.92, 0.92, 0.92);
 
 pub const SLIDER_RAIL: (Color, Color) = (
     Color {
@@ -48,10 +46,6 @@ pub const TEXT_MARK: Color = Color {
     a: 0.65,
 };
 
-pub const KNOB_BACK_HOVER: Color = Color::from_rgb(0.96, 0.96, 0.96);
-
-pub const RAMP_BACK_HOVER: Color = Color::from_rgb(0.95, 0.95, 0.95);
-
 pub const XY_PAD_RAIL: Color = Color {
     r: 0.56,
     g: 0.56,
@@ -0,0 +1,53 @@
+//! The style of the ring drawn around a widget that was focused with the
+//! keyboard
+//!
+//! The widgets draw their focus ring only when they were focused by an
+//! operation, e.g. when tabbing to them with [`operation::focus_next`],
+//! like the `:focus-visible` state of the web. Clicking a widget focuses it
+//! without a ring.
+//!
+//! [`operation::focus_next`]: ../../operation/fn.focus_next.html
+
+use iced::Color;
+
+use crate::style::{palette::Palette, Dim};
+
+/// The appearance of the focus ring of a widget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The color of the ring
+    pub color: Color,
+    /// The width of the ring
+    pub width: f32,
+    /// The gap between the widget and the ring
+    pub offset: f32,
+    /// The radius of the outer corners of the ring around rectangular
+    /// widgets. The ring around a round widget, such as a `Knob`, is always
+    /// round.
+    pub border_radius: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        with_palette(&Palette::default())
+    }
+}
+
+/// The default focus ring in the accent color of the `palette`.
+pub fn with_palette(palette: &Palette) -> Appearance {
+    Appearance {
+        color: palette.accent,
+        width: 2.0,
+        offset: 2.0,
+        border_radius: 4.0,
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -3,10 +3,19 @@
 //! [`HSlider`]: ../native/h_slider/struct.HSlider.html
 
 use crate::{
-    style::{default_colors, text_marks, tick_marks},
+    style::{
+        default_colors, focus_ring,
+        palette::Palette,
+        presets::{self, Preset},
+        text_marks,
+        texture::TextureHandle,
+        tick_marks, value_text, Dim,
+    },
     Offset,
 };
-use iced::{advanced::image, Color, Rectangle};
+use iced::{Color, Rectangle, Theme};
+
+pub use crate::style::Status;
 
 /// The appearance of an [`HSlider`].
 ///
@@ -22,6 +31,9 @@ pub enum Appearance {
     /// same as `Rect` but can have different colors for left,
     /// right, and center positions
     RectBipolar(RectBipolarAppearance),
+    /// same as `RectBipolar` but with a visible origin line at the
+    /// center, and a filled portion rounded only at its outer end
+    RectSplit(RectSplitAppearance),
 }
 
 /// A classic line rail style
@@ -33,21 +45,21 @@ pub struct ClassicRail {
     pub rail_widths: (f32, f32),
     /// The padding from the rail to the left and right edges of the widget
     pub rail_padding: f32,
+    /// Whether the ends of the rail are rounded instead of square
+    pub rounded_ends: bool,
 }
 
 /// An [`Appearance`] for an [`HSlider`] that uses an image texture for the handle
 ///
 /// [`Appearance`]: enum.Appearance.html
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
-/// [`Handle`]: https://docs.rs/iced/latest/iced/pure/widget/image/struct.Handle.html
 #[derive(Debug, Clone)]
 pub struct TextureAppearance {
     /// The rail style
     pub rail: ClassicRail,
-    /// The [`Handle`] to the image texture
-    ///
-    /// [`Handle`]: https://docs.rs/iced/latest/iced/pure/widget/image/struct.Handle.html
-    pub image_handle: image::Handle,
+    /// The image texture, with a version for each scale factor of the
+    /// display it was drawn for
+    pub image_handle: TextureHandle,
     /// The effective width of the handle (not including any padding on the texture)
     pub handle_width: u16,
     /// The bounds of the image texture, where the origin is in the
@@ -75,6 +87,7 @@ impl Default for ClassicAppearance {
                 rail_colors: default_colors::SLIDER_RAIL,
                 rail_widths: (1.0, 1.0),
                 rail_padding: 12.0,
+                rounded_ends: false,
             },
             handle: ClassicHandle::default(),
         }
@@ -95,6 +108,11 @@ pub struct ClassicHandle {
     pub notch_width: f32,
     /// color of the middle notch
     pub notch_color: Color,
+    /// radius of the corners of the middle notch, independent of the
+    /// `border_radius` of the handle
+    pub notch_radius: f32,
+    /// an image texture drawn as the middle notch instead of a rectangle
+    pub notch_texture: Option<TextureNotch>,
     /// radius of the background rectangle
     pub border_radius: f32,
     /// width of the background rectangle
@@ -110,6 +128,8 @@ impl Default for ClassicHandle {
             width: 34,
             notch_width: 4.0,
             notch_color: default_colors::BORDER,
+            notch_radius: 0.0,
+            notch_texture: None,
             border_radius: 2.0,
             border_color: default_colors::BORDER,
             border_width: 1.0,
@@ -117,6 +137,19 @@ impl Default for ClassicHandle {
     }
 }
 
+/// An image texture drawn as the middle notch of a [`ClassicHandle`]
+///
+/// [`ClassicHandle`]: struct.ClassicHandle.html
+#[derive(Debug, Clone)]
+pub struct TextureNotch {
+    /// The image texture, with a version for each scale factor of the
+    /// display it was drawn for
+    pub image_handle: TextureHandle,
+    /// The bounds of the image texture, where the origin is the center of
+    /// the notch
+    pub image_bounds: Rectangle,
+}
+
 /// A modern [`Appearance`] for an [`HSlider`]. It is composed of a background
 /// rectangle and a rectangular handle.
 ///
@@ -180,6 +213,49 @@ pub struct RectBipolarAppearance {
     pub handle_filled_gap: f32,
 }
 
+/// A bipolar [`Appearance`] for an [`HSlider`] with a split track. A line
+/// marks the origin at the center, and the track is filled from the origin
+/// to the handle with the color of the side the value is on.
+///
+/// [`Appearance`]: enum.Appearance.html
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone, Copy)]
+pub struct RectSplitAppearance {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: f32,
+    /// radius of the background rectangle
+    pub back_border_radius: f32,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// color of the filled portion when the value
+    /// is below the center
+    pub negative_filled_color: Color,
+    /// color of the filled portion when the value
+    /// is above the center
+    pub positive_filled_color: Color,
+    /// color of the handle rectangle when the value
+    /// is below the center
+    pub handle_negative_color: Color,
+    /// color of the handle rectangle when the value
+    /// is above the center
+    pub handle_positive_color: Color,
+    /// color of the origin line, and of the handle
+    /// rectangle when it is in the center
+    pub origin_color: Color,
+    /// width of the origin line
+    pub origin_width: f32,
+    /// radius of the outer end of the filled portion,
+    /// the end at the origin is always square
+    pub filled_radius: f32,
+    /// width of the handle rectangle
+    pub handle_width: u16,
+    /// width of the gap between the handle and the filled
+    /// portion of the background rectangle
+    pub handle_filled_gap: f32,
+}
+
 /// The position of a [`ModRangeStyle`] ring for an [`HSlider`]
 ///
 /// [`ModRangeStyle`]: struct.ModRangeStyle.html
@@ -239,6 +315,22 @@ pub struct ModRangeAppearance {
     pub filled_inverse_color: Color,
 }
 
+/// A style for the marker of the default value of an [`HSlider`], which shows
+/// where resetting it (e.g. with a double click) moves it to
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+#[derive(Debug, Clone)]
+pub struct DefaultMarkerAppearance {
+    /// The placement of the marker relative to the widget
+    pub placement: ModRangePlacement,
+    /// The width of the marker along the slider
+    pub width: f32,
+    /// The radius of the corners of the marker
+    pub border_radius: f32,
+    /// The color of the marker
+    pub color: Color,
+}
+
 /// Style of tick marks for an [`HSlider`].
 ///
 /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
@@ -268,20 +360,23 @@ pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
     type Style: Default;
 
-    /// Produces the style of an active [`HSlider`].
+    /// Produces the style of an [`HSlider`] with the given [`Status`].
     ///
     /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
-    fn active(&self, style: &Self::Style) -> Appearance;
+    /// [`Status`]: ../enum.Status.html
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
 
-    /// Produces the style of a hovered [`HSlider`].
+    /// The style of the ring drawn around an [`HSlider`] that was focused
+    /// with the keyboard.
     ///
-    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
-    fn hovered(&self, style: &Self::Style) -> Appearance;
-
-    /// Produces the style of an [`HSlider`] that is being dragged.
+    /// The default is [`focus_ring::Appearance::default()`]. For no focus
+    /// ring, override this to return `None`.
     ///
     /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
-    fn dragging(&self, style: &Self::Style) -> Appearance;
+    /// [`focus_ring::Appearance::default()`]: ../focus_ring/struct.Appearance.html
+    fn focus_ring_appearance(&self, _style: &Self::Style) -> Option<focus_ring::Appearance> {
+        Some(focus_ring::Appearance::default())
+    }
 
     /// The style of tick marks for an [`HSlider`]
     ///
@@ -320,116 +415,506 @@ pub trait StyleSheet {
     fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
         None
     }
+
+    /// The style of the value text of an [`HSlider`]
+    ///
+    /// For no value text, don't override this or set this to return `None`.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn value_text_appearance(&self, _style: &Self::Style) -> Option<value_text::Appearance> {
+        None
+    }
+    /// The style of the marker of the default value of an [`HSlider`]
+    ///
+    /// For no marker, don't override this or set this to return `None`.
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    fn default_marker_appearance(&self, _style: &Self::Style) -> Option<DefaultMarkerAppearance> {
+        None
+    }
 }
 
-/// The style of a HSlider.
+/// A styling function for an [`HSlider`].
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The theme catalog of an [`HSlider`].
+///
+/// Only the [`Appearance`] has to be provided, everything else defaults to
+/// not being displayed.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`Appearance`]: enum.Appearance.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class with the given status.
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+
+    /// The style of the focus ring of a class.
+    fn focus_ring_appearance(&self, _class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        None
+    }
+
+    /// The style of the tick marks of a class.
+    fn tick_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of the first modulation range line of a class.
+    fn mod_range_appearance(&self, _class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        None
+    }
+
+    /// The style of the second modulation range line of a class.
+    fn mod_range_appearance_2(&self, _class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        None
+    }
+
+    /// The style of the text marks of a class.
+    fn text_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        None
+    }
+
+    /// The style of the value text of a class.
+    fn value_text_appearance(&self, _class: &Self::Class<'_>) -> Option<value_text::Appearance> {
+        None
+    }
+    /// The style of the marker of the default value of a class.
+    fn default_marker_appearance(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<DefaultMarkerAppearance> {
+        None
+    }
+}
+
+/// The class of an [`HSlider`] for the built-in `Theme`.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
 #[derive(Default)]
-pub enum HSlider {
+pub enum HSlider<'a> {
     /// The default style.
     #[default]
     Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function. The tick marks, text marks, and
+    /// other extras use the default style.
+    Function(StyleFn<'a, Theme>),
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = iced::Theme>>),
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
 }
 
-impl<S> From<S> for HSlider
+impl<'a, S> From<S> for HSlider<'a>
 where
-    S: 'static + StyleSheet<Style = iced::Theme>,
+    S: 'static + StyleSheet<Style = Theme>,
 {
     fn from(val: S) -> Self {
         HSlider::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for iced::Theme {
-    type Style = HSlider;
+impl<'a> From<StyleFn<'a, Theme>> for HSlider<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        HSlider::Function(style)
+    }
+}
 
-    fn active(&self, style: &Self::Style) -> Appearance {
-        match style {
-            HSlider::Default => Appearance::Classic(Default::default()),
-            HSlider::Custom(custom) => custom.active(self),
-        }
+impl Catalog for Theme {
+    type Class<'a> = HSlider<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        HSlider::Default
     }
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            HSlider::Default => Appearance::Classic(ClassicAppearance {
-                handle: ClassicHandle {
-                    color: default_colors::LIGHT_BACK_HOVER,
-                    ..Default::default()
-                },
-                ..Default::default()
-            }),
-            HSlider::Custom(custom) => custom.hovered(self),
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            HSlider::Default => default(self, status),
+            HSlider::Preset(preset) => with_palette(&preset.palette(self), status),
+            HSlider::Function(style) => style(self, status),
+            HSlider::Custom(custom) => custom.appearance(self, status),
         }
     }
 
-    fn dragging(&self, style: &Self::Style) -> Appearance {
-        match style {
-            HSlider::Default => Appearance::Classic(ClassicAppearance {
-                handle: ClassicHandle {
-                    color: default_colors::LIGHT_BACK_DRAG,
-                    ..Default::default()
-                },
-                ..Default::default()
-            }),
-            HSlider::Custom(custom) => custom.dragging(self),
+    fn focus_ring_appearance(&self, class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        match class {
+            HSlider::Custom(custom) => custom.focus_ring_appearance(self),
+            _ => Some(focus_ring::with_palette(&palette(self, class))),
         }
     }
 
-    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
-        match style {
-            HSlider::Default => Some(TickMarksAppearance {
-                style: tick_marks::Appearance {
-                    tier_1: tick_marks::Shape::Line {
-                        length: 24.0,
-                        width: 2.0,
-                        color: default_colors::TICK_TIER_1,
-                    },
-                    tier_2: tick_marks::Shape::Line {
-                        length: 22.0,
-                        width: 1.0,
-                        color: default_colors::TICK_TIER_2,
+    fn tick_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        match class {
+            HSlider::Custom(custom) => custom.tick_marks_appearance(self),
+            _ => {
+                let palette = palette(self, class);
+
+                Some(TickMarksAppearance {
+                    style: tick_marks::Appearance {
+                        tier_1: tick_marks::Shape::Line {
+                            length: 24.0,
+                            width: 2.0,
+                            color: palette.tick_tier_1,
+                        },
+                        tier_2: tick_marks::Shape::Line {
+                            length: 22.0,
+                            width: 1.0,
+                            color: palette.tick_tier_2,
+                        },
+                        tier_3: tick_marks::Shape::Line {
+                            length: 18.0,
+                            width: 1.0,
+                            color: palette.tick_tier_3,
+                        },
+                        density: Default::default(),
                     },
-                    tier_3: tick_marks::Shape::Line {
-                        length: 18.0,
-                        width: 1.0,
-                        color: default_colors::TICK_TIER_3,
+                    placement: tick_marks::Placement::Center {
+                        offset: Offset::ZERO,
+                        fill_length: false,
                     },
-                },
-                placement: tick_marks::Placement::Center {
-                    offset: Offset::ZERO,
-                    fill_length: false,
-                },
-            }),
-            HSlider::Custom(custom) => custom.tick_marks_appearance(self),
+                })
+            }
         }
     }
 
-    fn mod_range_appearance(&self, style: &Self::Style) -> Option<ModRangeAppearance> {
-        match style {
-            HSlider::Default => None,
+    fn mod_range_appearance(&self, class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        match class {
             HSlider::Custom(custom) => custom.mod_range_appearance(self),
+            _ => None,
         }
     }
 
-    fn mod_range_appearance_2(&self, style: &Self::Style) -> Option<ModRangeAppearance> {
-        match style {
-            HSlider::Default => None,
+    fn mod_range_appearance_2(&self, class: &Self::Class<'_>) -> Option<ModRangeAppearance> {
+        match class {
             HSlider::Custom(custom) => custom.mod_range_appearance_2(self),
+            _ => None,
         }
     }
 
-    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
-        match style {
-            HSlider::Default => Some(TextMarksAppearance {
-                style: Default::default(),
+    fn text_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        match class {
+            HSlider::Custom(custom) => custom.text_marks_appearance(self),
+            _ => Some(TextMarksAppearance {
+                style: text_marks::Appearance {
+                    color: palette(self, class).text_mark,
+                    ..Default::default()
+                },
                 placement: text_marks::Placement::RightOrBottom {
                     inside: false,
                     offset: Offset { x: 0.0, y: 7.0 },
                 },
             }),
-            HSlider::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+
+    fn value_text_appearance(&self, class: &Self::Class<'_>) -> Option<value_text::Appearance> {
+        match class {
+            HSlider::Custom(custom) => custom.value_text_appearance(self),
+            _ => Some(value_text::Appearance {
+                color: palette(self, class).text_mark,
+                offset: Offset { x: 0.0, y: -2.0 },
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn default_marker_appearance(
+        &self,
+        class: &Self::Class<'_>,
+    ) -> Option<DefaultMarkerAppearance> {
+        match class {
+            HSlider::Custom(custom) => custom.default_marker_appearance(self),
+            _ => None,
+        }
+    }
+}
+
+/// The default style of an [`HSlider`], with colors derived from the `theme`
+/// and the global [`Preset`].
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    with_palette(&presets::global().palette(theme), status)
+}
+
+/// The default style of an [`HSlider`] with the colors of the `palette`.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+pub fn with_palette(palette: &Palette, status: Status) -> Appearance {
+    let handle_color = match status {
+        Status::Active | Status::Disabled => palette.back,
+        Status::Hovered | Status::Focused | Status::DropTarget => palette.back_hover,
+        Status::Dragged => palette.back_drag,
+    };
+
+    let mut classic = default_classic(palette, handle_color);
+
+    // A drop target is highlighted with the accent color.
+    if status == Status::DropTarget {
+        classic.handle.border_color = palette.accent;
+    }
+
+    Appearance::Classic(classic)
+}
+
+/// The default classic appearance with the colors of the `palette`.
+fn default_classic(palette: &Palette, handle_color: Color) -> ClassicAppearance {
+    ClassicAppearance {
+        rail: ClassicRail {
+            rail_colors: palette.slider_rail,
+            ..ClassicAppearance::default().rail
+        },
+        handle: ClassicHandle {
+            color: handle_color,
+            notch_color: palette.border,
+            border_color: palette.border,
+            ..Default::default()
+        },
+    }
+}
+
+/// A rectangular style of an [`HSlider`] that fills up to its value, with
+/// colors derived from the `theme` and the global [`Preset`].
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn rect(theme: &Theme, status: Status) -> Appearance {
+    let palette = presets::global().palette(theme);
+
+    Appearance::Rect(RectAppearance {
+        back_color: palette.back,
+        back_border_width: 1.0,
+        back_border_radius: 2.0,
+        back_border_color: palette.border,
+        filled_color: filled_color(palette.accent, status),
+        handle_color: palette.accent,
+        handle_width: rect_handle_width(status),
+        handle_filled_gap: 1.0,
+    })
+}
+
+/// A bipolar rectangular style of an [`HSlider`] that fills from its center
+/// to its value, with colors derived from the `theme` and the global
+/// [`Preset`]. It is filled with the negative color to the left of the
+/// center and with the positive color to the right of it.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn rect_bipolar(theme: &Theme, status: Status) -> Appearance {
+    let palette = presets::global().palette(theme);
+
+    Appearance::RectBipolar(RectBipolarAppearance {
+        back_color: palette.back,
+        back_border_width: 1.0,
+        back_border_radius: 2.0,
+        back_border_color: palette.border,
+        left_filled_color: filled_color(palette.negative, status),
+        right_filled_color: filled_color(palette.positive, status),
+        handle_left_color: palette.negative,
+        handle_right_color: palette.positive,
+        handle_center_color: palette.border,
+        handle_width: rect_handle_width(status),
+        handle_filled_gap: 1.0,
+    })
+}
+
+/// A split-track bipolar rectangular style of an [`HSlider`], with colors
+/// derived from the `theme` and the global [`Preset`]. A line marks the
+/// center, and the track is filled from there to the value with the
+/// negative or the positive color.
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn rect_split(theme: &Theme, status: Status) -> Appearance {
+    let palette = presets::global().palette(theme);
+
+    Appearance::RectSplit(RectSplitAppearance {
+        back_color: palette.back,
+        back_border_width: 1.0,
+        back_border_radius: 2.0,
+        back_border_color: palette.border,
+        negative_filled_color: filled_color(palette.negative, status),
+        positive_filled_color: filled_color(palette.positive, status),
+        handle_negative_color: palette.negative,
+        handle_positive_color: palette.positive,
+        origin_color: palette.border,
+        origin_width: 2.0,
+        filled_radius: 2.0,
+        handle_width: rect_handle_width(status),
+        handle_filled_gap: 1.0,
+    })
+}
+
+/// The filled color of the rectangular styles, which is brighter while the
+/// slider is hovered or dragged.
+fn filled_color(color: Color, status: Status) -> Color {
+    match status {
+        Status::Active | Status::Disabled | Status::Focused => Color { a: 0.75, ..color },
+        Status::Hovered | Status::Dragged | Status::DropTarget => Color { a: 0.9, ..color },
+    }
+}
+
+/// The width of the handle of the rectangular styles.
+fn rect_handle_width(status: Status) -> u16 {
+    match status {
+        Status::Hovered | Status::Dragged => 5,
+        _ => 4,
+    }
+}
+
+/// The palette of the default extras of a class.
+fn palette(theme: &Theme, class: &HSlider<'_>) -> Palette {
+    match class {
+        HSlider::Preset(preset) => preset.palette(theme),
+        _ => presets::global().palette(theme),
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        match self {
+            Appearance::Texture(texture) => Appearance::Texture(texture.dim(opacity)),
+            Appearance::Classic(classic) => Appearance::Classic(classic.dim(opacity)),
+            Appearance::Rect(rect) => Appearance::Rect(rect.dim(opacity)),
+            Appearance::RectBipolar(rect_bipolar) => {
+                Appearance::RectBipolar(rect_bipolar.dim(opacity))
+            }
+            Appearance::RectSplit(rect_split) => Appearance::RectSplit(rect_split.dim(opacity)),
+        }
+    }
+}
+
+impl Dim for ClassicRail {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            rail_colors: self.rail_colors.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for TextureAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            rail: self.rail.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ClassicAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            rail: self.rail.dim(opacity),
+            handle: self.handle.dim(opacity),
+        }
+    }
+}
+
+impl Dim for ClassicHandle {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            notch_color: self.notch_color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for RectAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            back_border_color: self.back_border_color.dim(opacity),
+            filled_color: self.filled_color.dim(opacity),
+            handle_color: self.handle_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for RectBipolarAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            back_border_color: self.back_border_color.dim(opacity),
+            left_filled_color: self.left_filled_color.dim(opacity),
+            right_filled_color: self.right_filled_color.dim(opacity),
+            handle_left_color: self.handle_left_color.dim(opacity),
+            handle_right_color: self.handle_right_color.dim(opacity),
+            handle_center_color: self.handle_center_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for RectSplitAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            back_border_color: self.back_border_color.dim(opacity),
+            negative_filled_color: self.negative_filled_color.dim(opacity),
+            positive_filled_color: self.positive_filled_color.dim(opacity),
+            handle_negative_color: self.handle_negative_color.dim(opacity),
+            handle_positive_color: self.handle_positive_color.dim(opacity),
+            origin_color: self.origin_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ModRangeAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_border_color: self.back_border_color.dim(opacity),
+            back_color: self.back_color.dim(opacity),
+            filled_color: self.filled_color.dim(opacity),
+            filled_inverse_color: self.filled_inverse_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for DefaultMarkerAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for TickMarksAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            style: self.style.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for TextMarksAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            style: self.style.dim(opacity),
+            ..self
         }
     }
 }
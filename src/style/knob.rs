@@ -3,10 +3,20 @@
 //! [`Knob`]: ../native/knob/struct.Knob.html
 
 use crate::{
-    style::{default_colors, text_marks, tick_marks},
-    KnobAngleRange,
+    style::{
+        default_colors, focus_ring,
+        palette::Palette,
+        presets::{self, Preset},
+        text_marks,
+        texture::TextureHandle,
+        tick_marks, Dim,
+    },
+    KnobAngleRange, Normal,
 };
-use iced::Color;
+use iced::{widget::canvas::Path, Color, Rectangle, Theme};
+use std::{fmt, sync::Arc};
+
+pub use crate::style::Status;
 
 pub use iced::widget::canvas::{Canvas, LineCap};
 
@@ -23,6 +33,8 @@ pub enum Appearance {
     /// A modern arc style with. It can display different colors
     /// for left, right, and center positions.
     ArcBipolar(ArcBipolarAppearance),
+    /// An arc of LED-like segments, lit up to the value
+    ArcSegmented(ArcSegmentedAppearance),
 }
 
 /*
@@ -98,6 +110,97 @@ pub struct LineNotch {
     pub offset: StyleLength,
 }
 
+/// Image texture notch
+#[derive(Debug, Clone)]
+pub struct TextureNotch {
+    /// The image texture, with a version for each scale factor of the
+    /// display it was drawn for
+    pub image_handle: TextureHandle,
+    /// The bounds of the image texture, where the origin is the center of
+    /// the notch while the knob points straight up.
+    pub image_bounds: Rectangle,
+    /// The offset from the edge of the knob to the center of the notch. Use
+    /// the knob's radius with `rotate` to spin a texture of the whole knob.
+    pub offset: StyleLength,
+    /// Whether the texture turns with the value of the knob. Draw the
+    /// texture pointing straight up.
+    pub rotate: bool,
+}
+
+/// Triangle notch, pointing out from the center of the knob
+#[derive(Debug, Clone)]
+pub struct TriangleNotch {
+    /// The color of the triangle
+    pub color: Color,
+    /// The width of the base of the triangle
+    pub width: StyleLength,
+    /// The length from the base to the tip of the triangle
+    pub length: StyleLength,
+    /// The offset from the edge of the knob to the tip of the triangle.
+    pub offset: StyleLength,
+}
+
+/// What a [`CustomNotch`] knows about the [`Knob`] it is drawn on
+///
+/// [`CustomNotch`]: struct.CustomNotch.html
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NotchInfo {
+    /// The radius of the knob
+    pub radius: f32,
+    /// The value of the knob
+    pub value: Normal,
+    /// The angle of the value in radians, where `0.0` points straight up
+    /// and the angle grows clockwise
+    pub angle: f32,
+}
+
+/// A notch with a path drawn by the skin
+///
+/// The path is drawn around the center of the knob while the knob points
+/// straight up, and is turned with the value of the knob. It is only built
+/// when the knob is drawn, so it can depend on the [`NotchInfo`], e.g. to
+/// grow with the value.
+///
+/// ```
+/// use iced::{widget::canvas::Path, Color, Point};
+/// use iced_audio::style::knob::{CustomNotch, NotchShape};
+///
+/// let notch = NotchShape::Custom(CustomNotch::new(Color::WHITE, |info| {
+///     Path::rectangle(Point::new(-1.0, -info.radius), [2.0, info.radius].into())
+/// }));
+/// ```
+///
+/// [`NotchInfo`]: struct.NotchInfo.html
+#[derive(Clone)]
+pub struct CustomNotch {
+    /// The color the path is filled with
+    pub color: Color,
+    /// Builds the path of the notch
+    pub path: Arc<dyn Fn(&NotchInfo) -> Path + Send + Sync>,
+}
+
+impl CustomNotch {
+    /// Creates a [`CustomNotch`] that fills the path built by `path` with
+    /// `color`.
+    ///
+    /// [`CustomNotch`]: struct.CustomNotch.html
+    pub fn new(color: Color, path: impl Fn(&NotchInfo) -> Path + Send + Sync + 'static) -> Self {
+        Self {
+            color,
+            path: Arc::new(path),
+        }
+    }
+}
+
+impl fmt::Debug for CustomNotch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomNotch")
+            .field("color", &self.color)
+            .finish_non_exhaustive()
+    }
+}
+
 /// The shape of the notch
 #[derive(Debug, Clone)]
 pub enum NotchShape {
@@ -107,6 +210,12 @@ pub enum NotchShape {
     Circle(CircleNotch),
     /// Line notch
     Line(LineNotch),
+    /// Image texture notch
+    Texture(TextureNotch),
+    /// Triangle notch
+    Triangle(TriangleNotch),
+    /// Notch with a path drawn by the skin
+    Custom(CustomNotch),
 }
 
 /// A classic circular [`Appearance`] of a [`Knob`]
@@ -142,6 +251,31 @@ impl Default for CircleAppearance {
     }
 }
 
+/// The dots that mark the minimum and maximum of an arc
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EndDots {
+    /// The diameter of the dots in pixels
+    pub diameter: f32,
+    /// The color of the dots
+    pub color: Color,
+}
+
+/// How the ends of the arc of a [`Knob`] are drawn, like on the artwork of
+/// a hardware knob
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct ArcEnds {
+    /// The angle in radians that the arc starts and ends inset from the
+    /// angle range of the knob, leaving a gap around the notch at the
+    /// minimum and maximum. The filled portion of the arc stops at the
+    /// gap.
+    pub gap: f32,
+    /// The dots drawn on the arc at the minimum and maximum of the angle
+    /// range, e.g. in the gap. Set this to `None` for no dots.
+    pub dots: Option<EndDots>,
+}
+
 /// A modern arc [`Appearance`] of a [`Knob`]
 ///
 /// [`Appearance`]: enum.Appearance.html
@@ -158,6 +292,8 @@ pub struct ArcAppearance {
     pub notch: NotchShape,
     /// The cap at the ends of the arc
     pub cap: LineCap,
+    /// The gap and the dots at the ends of the arc
+    pub ends: ArcEnds,
 }
 
 /// A modern arc [`Appearance`] of a [`Knob`].
@@ -183,6 +319,45 @@ pub struct ArcBipolarAppearance {
     pub notch_left_right: Option<(NotchShape, NotchShape)>,
     /// The cap at the ends of the arc
     pub cap: LineCap,
+    /// The gap and the dots at the ends of the arc
+    pub ends: ArcEnds,
+}
+
+/// The shape of the segments of an [`ArcSegmentedAppearance`]
+///
+/// [`ArcSegmentedAppearance`]: struct.ArcSegmentedAppearance.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SegmentShape {
+    /// Round dots, centered in their segment
+    #[default]
+    Dot,
+    /// Dashes along the arc, separated by the gap
+    Dash,
+}
+
+/// An [`Appearance`] of a [`Knob`] with an arc of LED-like segments, which
+/// are lit up to the value like the LED ring of a hardware encoder.
+///
+/// [`Appearance`]: enum.Appearance.html
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Clone)]
+pub struct ArcSegmentedAppearance {
+    /// The width (thickness) of the segments, which is the diameter of the
+    /// dots
+    pub width: StyleLength,
+    /// The number of segments along the arc
+    pub segments: u16,
+    /// The gap between two segments in pixels along the arc. Dots shrink to
+    /// fit between the gaps.
+    pub gap: f32,
+    /// The shape of the segments
+    pub shape: SegmentShape,
+    /// The color of the segments up to the value
+    pub lit_color: Color,
+    /// The color of the segments past the value
+    pub unlit_color: Color,
+    /// The shape of the notch
+    pub notch: NotchShape,
 }
 
 /// A style for a value arc around a [`Knob`]
@@ -205,6 +380,8 @@ pub struct ValueArcAppearance {
     pub right_filled_color: Option<Color>,
     /// The cap at the ends of the arc
     pub cap: LineCap,
+    /// The gap and the dots at the ends of the arc
+    pub ends: ArcEnds,
 }
 
 /// A style for a [`ModulationRange`] arc around a [`Knob`]
@@ -227,12 +404,75 @@ pub struct ModRangeArcAppearance {
     pub filled_inverse_color: Color,
     /// The cap at the ends of the arc
     pub cap: LineCap,
+    /// Where the arc is drawn around the [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub placement: ModRangePlacement,
+}
+
+/// The placement of a [`ModRangeArcAppearance`] arc around a [`Knob`]
+///
+/// [`ModRangeArcAppearance`]: struct.ModRangeArcAppearance.html
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ModRangePlacement {
+    /// Along the sweep of the value of the [`Knob`], from its minimum to its
+    /// maximum angle
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    #[default]
+    Sweep,
+    /// A full ring behind the body of the [`Knob`], which starts at the top
+    /// and goes around clockwise, e.g. to show the amount of a macro
+    /// regardless of the angle range of the [`Knob`]
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    Ring,
+}
+
+/// A style for the marker of the default value of a [`Knob`], which shows
+/// where resetting it (e.g. with a double click) moves it to. It is a short
+/// line pointing at the center of the [`Knob`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone)]
+pub struct DefaultMarkerAppearance {
+    /// The width (thickness) of the line
+    pub width: f32,
+    /// The length of the line
+    pub length: f32,
+    /// The offset of the inner end of the line from the edge of the `Knob`
+    /// in pixels
+    pub offset: f32,
+    /// The color of the line
+    pub color: Color,
+    /// The cap at the ends of the line
+    pub cap: LineCap,
+}
+
+/// A style for the rings of the modulation sources of a [`Knob`], stacked
+/// at increasing radii, each filled with the color of its source.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone)]
+pub struct ModSourcesArcAppearance {
+    /// The width (thickness) of each ring
+    pub width: f32,
+    /// The offset of the innermost ring from the edge of the `Knob` in pixels
+    pub offset: f32,
+    /// The gap between two rings in pixels
+    pub spacing: f32,
+    /// The color of an empty background portion in each ring. Set this to
+    /// `None` for no background rings.
+    pub empty_color: Option<Color>,
+    /// The cap at the ends of the arcs
+    pub cap: LineCap,
 }
 
 /// Style of tick marks for a [`Knob`].
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TickMarksAppearance {
     /// The style of the tick marks
     pub style: tick_marks::Appearance,
@@ -279,20 +519,23 @@ pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
     type Style: Default;
 
-    /// Produces the style of an active [`Knob`].
+    /// Produces the style of a [`Knob`] with the given [`Status`].
     ///
     /// [`Knob`]: ../../native/knob/struct.Knob.html
-    fn active(&self, style: &Self::Style) -> Appearance;
+    /// [`Status`]: ../enum.Status.html
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
 
-    /// Produces the style of a hovered [`Knob`].
+    /// The style of the ring drawn around a [`Knob`] that was focused
+    /// with the keyboard.
     ///
-    /// [`Knob`]: ../../native/knob/struct.Knob.html
-    fn hovered(&self, style: &Self::Style) -> Appearance;
-
-    /// Produces the style of a [`Knob`] that is being dragged.
+    /// The default is [`focus_ring::Appearance::default()`]. For no focus
+    /// ring, override this to return `None`.
     ///
     /// [`Knob`]: ../../native/knob/struct.Knob.html
-    fn dragging(&self, style: &Self::Style) -> Appearance;
+    /// [`focus_ring::Appearance::default()`]: ../focus_ring/struct.Appearance.html
+    fn focus_ring_appearance(&self, _style: &Self::Style) -> Option<focus_ring::Appearance> {
+        Some(focus_ring::Appearance::default())
+    }
 
     /// a [`KnobAngleRange`] that defines the minimum and maximum angle that the
     /// knob rotates
@@ -341,6 +584,15 @@ pub trait StyleSheet {
         None
     }
 
+    /// The style of the modulation source rings around a [`Knob`]
+    ///
+    /// For no modulation source rings, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn mod_sources_arc_appearance(&self, _style: &Self::Style) -> Option<ModSourcesArcAppearance> {
+        None
+    }
+
     /// The style of text marks around a [`Knob`]
     ///
     /// For no text marks, don't override this or set this to return `None`.
@@ -350,114 +602,567 @@ pub trait StyleSheet {
     fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
         None
     }
+    /// The style of the marker of the default value of a [`Knob`]
+    ///
+    /// For no marker, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn default_marker_appearance(&self, _style: &Self::Style) -> Option<DefaultMarkerAppearance> {
+        None
+    }
+}
+
+/// A styling function for a [`Knob`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The theme catalog of a [`Knob`].
+///
+/// Only the [`Appearance`] has to be provided, everything else defaults to
+/// not being displayed.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`Appearance`]: enum.Appearance.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class with the given status.
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+
+    /// The style of the focus ring of a class.
+    fn focus_ring_appearance(&self, _class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        None
+    }
+
+    /// The [`KnobAngleRange`] of a class.
+    ///
+    /// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+    fn angle_range(&self, _class: &Self::Class<'_>) -> KnobAngleRange {
+        KnobAngleRange::default()
+    }
+
+    /// The style of the tick marks of a class.
+    fn tick_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of the value arc of a class.
+    fn value_arc_appearance(&self, _class: &Self::Class<'_>) -> Option<ValueArcAppearance> {
+        None
+    }
+
+    /// The style of the first modulation range arc of a class.
+    fn mod_range_arc_appearance(&self, _class: &Self::Class<'_>) -> Option<ModRangeArcAppearance> {
+        None
+    }
+
+    /// The style of the second modulation range arc of a class.
+    fn mod_range_arc_appearance_2(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<ModRangeArcAppearance> {
+        None
+    }
+
+    /// The style of the modulation source rings of a class.
+    fn mod_sources_arc_appearance(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<ModSourcesArcAppearance> {
+        None
+    }
+
+    /// The style of the text marks of a class.
+    fn text_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        None
+    }
+    /// The style of the marker of the default value of a class.
+    fn default_marker_appearance(
+        &self,
+        _class: &Self::Class<'_>,
+    ) -> Option<DefaultMarkerAppearance> {
+        None
+    }
 }
 
-/// The style of a Knob.
+/// The class of a [`Knob`] for the built-in `Theme`.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
 #[derive(Default)]
-pub enum Knob {
+pub enum Knob<'a> {
     /// The default style.
     #[default]
     Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function. The tick marks, text marks, and
+    /// other extras use the default style.
+    Function(StyleFn<'a, Theme>),
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = iced::Theme>>),
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
 }
 
-impl<S> From<S> for Knob
+impl<'a, S> From<S> for Knob<'a>
 where
-    S: 'static + StyleSheet<Style = iced::Theme>,
+    S: 'static + StyleSheet<Style = Theme>,
 {
     fn from(val: S) -> Self {
         Knob::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for iced::Theme {
-    type Style = Knob;
+impl<'a> From<StyleFn<'a, Theme>> for Knob<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        Knob::Function(style)
+    }
+}
 
-    fn active(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Knob::Default => Appearance::Circle(Default::default()),
-            Knob::Custom(custom) => custom.active(self),
-        }
+impl Catalog for Theme {
+    type Class<'a> = Knob<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Knob::Default
     }
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Knob::Default => Appearance::Circle(CircleAppearance {
-                color: default_colors::KNOB_BACK_HOVER,
-                ..Default::default()
-            }),
-            Knob::Custom(custom) => custom.hovered(self),
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            Knob::Default => default(self, status),
+            Knob::Preset(preset) => with_palette(&preset.palette(self), status),
+            Knob::Function(style) => style(self, status),
+            Knob::Custom(custom) => custom.appearance(self, status),
         }
     }
 
-    fn dragging(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Knob::Default => self.hovered(style),
-            Knob::Custom(custom) => custom.dragging(self),
+    fn focus_ring_appearance(&self, class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        match class {
+            Knob::Custom(custom) => custom.focus_ring_appearance(self),
+            _ => Some(focus_ring::with_palette(&palette(self, class))),
         }
     }
 
-    fn angle_range(&self, style: &Self::Style) -> KnobAngleRange {
-        match style {
-            Knob::Default => KnobAngleRange::default(),
+    fn angle_range(&self, class: &Self::Class<'_>) -> KnobAngleRange {
+        match class {
             Knob::Custom(custom) => custom.angle_range(self),
+            _ => KnobAngleRange::default(),
         }
     }
 
-    fn tick_marks_appearance(&self, style: &Self::Style) -> Option<TickMarksAppearance> {
-        match style {
-            Knob::Default => Some(TickMarksAppearance {
-                style: tick_marks::Appearance {
-                    tier_1: tick_marks::Shape::Circle {
-                        diameter: 4.0,
-                        color: default_colors::TICK_TIER_1,
-                    },
-                    tier_2: tick_marks::Shape::Circle {
-                        diameter: 2.0,
-                        color: default_colors::TICK_TIER_2,
-                    },
-                    tier_3: tick_marks::Shape::Circle {
-                        diameter: 2.0,
-                        color: default_colors::TICK_TIER_3,
-                    },
-                },
-                offset: 3.5,
-            }),
+    fn tick_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        match class {
             Knob::Custom(custom) => custom.tick_marks_appearance(self),
+            _ => {
+                let palette = palette(self, class);
+
+                Some(TickMarksAppearance {
+                    style: tick_marks::Appearance {
+                        tier_1: tick_marks::Shape::Circle {
+                            diameter: 4.0,
+                            color: palette.tick_tier_1,
+                        },
+                        tier_2: tick_marks::Shape::Circle {
+                            diameter: 2.0,
+                            color: palette.tick_tier_2,
+                        },
+                        tier_3: tick_marks::Shape::Circle {
+                            diameter: 2.0,
+                            color: palette.tick_tier_3,
+                        },
+                        density: Default::default(),
+                    },
+                    offset: 3.5,
+                })
+            }
         }
     }
 
-    fn value_arc_appearance(&self, style: &Self::Style) -> Option<ValueArcAppearance> {
-        match style {
-            Knob::Default => None,
+    fn value_arc_appearance(&self, class: &Self::Class<'_>) -> Option<ValueArcAppearance> {
+        match class {
             Knob::Custom(custom) => custom.value_arc_appearance(self),
+            _ => None,
         }
     }
 
-    fn mod_range_arc_appearance(&self, style: &Self::Style) -> Option<ModRangeArcAppearance> {
-        match style {
-            Knob::Default => None,
+    fn mod_range_arc_appearance(&self, class: &Self::Class<'_>) -> Option<ModRangeArcAppearance> {
+        match class {
             Knob::Custom(custom) => custom.mod_range_arc_appearance(self),
+            _ => None,
         }
     }
 
-    fn mod_range_arc_appearance_2(&self, style: &Self::Style) -> Option<ModRangeArcAppearance> {
-        match style {
-            Knob::Default => None,
+    fn mod_range_arc_appearance_2(&self, class: &Self::Class<'_>) -> Option<ModRangeArcAppearance> {
+        match class {
             Knob::Custom(custom) => custom.mod_range_arc_appearance_2(self),
+            _ => None,
         }
     }
 
-    fn text_marks_appearance(&self, style: &Self::Style) -> Option<TextMarksAppearance> {
-        match style {
-            Knob::Default => Some(TextMarksAppearance {
-                style: Default::default(),
+    fn mod_sources_arc_appearance(
+        &self,
+        class: &Self::Class<'_>,
+    ) -> Option<ModSourcesArcAppearance> {
+        match class {
+            Knob::Custom(custom) => custom.mod_sources_arc_appearance(self),
+            _ => None,
+        }
+    }
+
+    fn text_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        match class {
+            Knob::Custom(custom) => custom.text_marks_appearance(self),
+            _ => Some(TextMarksAppearance {
+                style: text_marks::Appearance {
+                    color: palette(self, class).text_mark,
+                    ..Default::default()
+                },
                 offset: 14.0,
                 h_char_offset: 3.0,
                 v_offset: -0.75,
             }),
-            Knob::Custom(custom) => custom.text_marks_appearance(self),
+        }
+    }
+    fn default_marker_appearance(
+        &self,
+        class: &Self::Class<'_>,
+    ) -> Option<DefaultMarkerAppearance> {
+        match class {
+            Knob::Custom(custom) => custom.default_marker_appearance(self),
+            _ => None,
+        }
+    }
+}
+
+/// The default style of a [`Knob`], with colors derived from the `theme` and
+/// the global [`Preset`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    with_palette(&presets::global().palette(theme), status)
+}
+
+/// The default style of a [`Knob`] with the colors of the `palette`.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+pub fn with_palette(palette: &Palette, status: Status) -> Appearance {
+    let color = match status {
+        Status::Active | Status::Disabled => palette.back,
+        Status::Hovered | Status::Dragged | Status::Focused | Status::DropTarget => {
+            palette.knob_back_hover
+        }
+    };
+
+    let mut circle = default_circle(palette, color);
+
+    // A drop target is highlighted with the accent color.
+    if status == Status::DropTarget {
+        circle.border_color = palette.accent;
+    }
+
+    Appearance::Circle(circle)
+}
+
+/// The default circle appearance with the colors of the `palette`.
+fn default_circle(palette: &Palette, color: Color) -> CircleAppearance {
+    let default = CircleAppearance::default();
+
+    CircleAppearance {
+        color,
+        border_color: palette.border,
+        notch: match default.notch {
+            NotchShape::Circle(notch) => NotchShape::Circle(CircleNotch {
+                color: palette.border,
+                ..notch
+            }),
+            notch => notch,
+        },
+        ..default
+    }
+}
+
+/// An arc style of a [`Knob`], with colors derived from the `theme` and the
+/// global [`Preset`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn arc(theme: &Theme, status: Status) -> Appearance {
+    let palette = presets::global().palette(theme);
+
+    Appearance::Arc(ArcAppearance {
+        width: StyleLength::Fixed(3.15),
+        empty_color: empty_arc_color(&palette, status),
+        filled_color: palette.accent,
+        notch: arc_notch(palette.accent),
+        cap: LineCap::Round,
+        ends: ArcEnds::default(),
+    })
+}
+
+/// A bipolar arc style of a [`Knob`], with colors derived from the `theme`
+/// and the global [`Preset`]. The arc is filled with the negative color
+/// below the center and with the positive color above it.
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn arc_bipolar(theme: &Theme, status: Status) -> Appearance {
+    let palette = presets::global().palette(theme);
+
+    Appearance::ArcBipolar(ArcBipolarAppearance {
+        width: StyleLength::Fixed(3.15),
+        empty_color: empty_arc_color(&palette, status),
+        left_filled_color: palette.negative,
+        right_filled_color: palette.positive,
+        notch_center: arc_notch(palette.border),
+        notch_left_right: Some((arc_notch(palette.negative), arc_notch(palette.positive))),
+        cap: LineCap::Round,
+        ends: ArcEnds::default(),
+    })
+}
+
+/// A style of a [`Knob`] with an arc of dots that light up to the value,
+/// with colors derived from the `theme` and the global [`Preset`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn arc_segmented(theme: &Theme, status: Status) -> Appearance {
+    let palette = presets::global().palette(theme);
+
+    Appearance::ArcSegmented(ArcSegmentedAppearance {
+        width: StyleLength::Fixed(3.5),
+        segments: 15,
+        gap: 2.0,
+        shape: SegmentShape::Dot,
+        lit_color: palette.accent,
+        unlit_color: empty_arc_color(&palette, status),
+        notch: arc_notch(palette.accent),
+    })
+}
+
+/// The color of the empty portion of the arc of the arc styles.
+fn empty_arc_color(palette: &Palette, status: Status) -> Color {
+    match status {
+        Status::Active | Status::Disabled => palette.tick_tier_3,
+        Status::Hovered | Status::Dragged | Status::Focused | Status::DropTarget => {
+            palette.tick_tier_1
+        }
+    }
+}
+
+/// The notch of the arc styles.
+fn arc_notch(color: Color) -> NotchShape {
+    NotchShape::Line(LineNotch {
+        color,
+        width: StyleLength::Fixed(3.15),
+        length: StyleLength::Scaled(0.25),
+        cap: LineCap::Round,
+        offset: StyleLength::Fixed(2.5),
+    })
+}
+
+/// The palette of the default extras of a class.
+fn palette(theme: &Theme, class: &Knob<'_>) -> Palette {
+    match class {
+        Knob::Preset(preset) => preset.palette(theme),
+        _ => presets::global().palette(theme),
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        match self {
+            Appearance::Circle(circle) => Appearance::Circle(circle.dim(opacity)),
+            Appearance::Arc(arc) => Appearance::Arc(arc.dim(opacity)),
+            Appearance::ArcBipolar(arc_bipolar) => Appearance::ArcBipolar(arc_bipolar.dim(opacity)),
+            Appearance::ArcSegmented(arc_segmented) => {
+                Appearance::ArcSegmented(arc_segmented.dim(opacity))
+            }
+        }
+    }
+}
+
+impl Dim for DefaultMarkerAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for TickMarksAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            style: self.style.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for TextMarksAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            style: self.style.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for CircleNotch {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for LineNotch {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for TriangleNotch {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for CustomNotch {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for NotchShape {
+    fn dim(self, opacity: f32) -> Self {
+        match self {
+            NotchShape::Circle(circle) => NotchShape::Circle(circle.dim(opacity)),
+            NotchShape::Line(line) => NotchShape::Line(line.dim(opacity)),
+            NotchShape::Triangle(triangle) => NotchShape::Triangle(triangle.dim(opacity)),
+            NotchShape::Custom(custom) => NotchShape::Custom(custom.dim(opacity)),
+            other => other,
+        }
+    }
+}
+
+impl Dim for CircleAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            notch: self.notch.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for EndDots {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ArcEnds {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            dots: self.dots.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ArcAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            empty_color: self.empty_color.dim(opacity),
+            filled_color: self.filled_color.dim(opacity),
+            notch: self.notch.dim(opacity),
+            ends: self.ends.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ArcBipolarAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            empty_color: self.empty_color.dim(opacity),
+            left_filled_color: self.left_filled_color.dim(opacity),
+            right_filled_color: self.right_filled_color.dim(opacity),
+            notch_center: self.notch_center.dim(opacity),
+            notch_left_right: self.notch_left_right.dim(opacity),
+            ends: self.ends.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ArcSegmentedAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            lit_color: self.lit_color.dim(opacity),
+            unlit_color: self.unlit_color.dim(opacity),
+            notch: self.notch.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ValueArcAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            empty_color: self.empty_color.dim(opacity),
+            left_filled_color: self.left_filled_color.dim(opacity),
+            right_filled_color: self.right_filled_color.dim(opacity),
+            ends: self.ends.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ModRangeArcAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            empty_color: self.empty_color.dim(opacity),
+            filled_color: self.filled_color.dim(opacity),
+            filled_inverse_color: self.filled_inverse_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for ModSourcesArcAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            empty_color: self.empty_color.dim(opacity),
+            ..self
         }
     }
 }
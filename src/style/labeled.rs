@@ -0,0 +1,146 @@
+//! Various styles for the title and the value readout of the labeled
+//! widgets, e.g. [`LabeledKnob`]
+//!
+//! The control itself is drawn with its own style.
+//!
+//! [`LabeledKnob`]: ../../native/labeled/type.LabeledKnob.html
+
+use crate::style::{
+    palette::Palette,
+    presets::{self, Preset},
+    Dim,
+};
+use iced::{Color, Theme};
+
+/// The appearance of the title and the value readout of a [`Labeled`]
+/// widget
+///
+/// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Appearance {
+    /// The color of the title
+    pub title_color: Color,
+    /// The color of the value readout
+    pub value_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`Labeled`] widget.
+///
+/// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of a [`Labeled`] widget.
+    ///
+    /// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// A styling function for a [`Labeled`] widget.
+///
+/// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Appearance + 'a>;
+
+/// The theme catalog of a [`Labeled`] widget.
+///
+/// Like the meters, its style does not depend on a [`Status`].
+///
+/// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+/// [`Status`]: ../enum.Status.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>) -> Appearance;
+}
+
+/// The class of a [`Labeled`] widget for the built-in `Theme`.
+///
+/// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+#[derive(Default)]
+pub enum Labeled<'a> {
+    /// The default style.
+    #[default]
+    Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl<'a, S> From<S> for Labeled<'a>
+where
+    S: 'static + StyleSheet<Style = Theme>,
+{
+    fn from(val: S) -> Self {
+        Labeled::Custom(Box::new(val))
+    }
+}
+
+impl<'a> From<StyleFn<'a, Theme>> for Labeled<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        Labeled::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = Labeled<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Labeled::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Appearance {
+        match class {
+            Labeled::Default => default(self),
+            Labeled::Preset(preset) => with_palette(&preset.palette(self)),
+            Labeled::Function(style) => style(self),
+            Labeled::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The default style of a [`Labeled`] widget, with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme) -> Appearance {
+    with_palette(&presets::global().palette(theme))
+}
+
+/// The default style of a [`Labeled`] widget with the colors of the
+/// `palette`, where the value readout has the color of the text marks.
+///
+/// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+pub fn with_palette(palette: &Palette) -> Appearance {
+    Appearance {
+        title_color: palette.border,
+        value_color: palette.text_mark,
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            title_color: self.title_color.dim(opacity),
+            value_color: self.value_color.dim(opacity),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Various styles for the [`LoudnessMeter`] widget
+//!
+//! [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+
+use crate::style::{
+    palette::Palette,
+    presets::{self, Preset},
+    Dim,
+};
+use iced::{Color, Theme};
+
+/// The appearance of a [`LoudnessMeter`]
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+#[derive(Debug, Clone)]
+pub struct Appearance {
+    /// The color of the background
+    pub back_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The radius of the border
+    pub border_radius: f32,
+    /// The color of the border
+    pub border_color: Color,
+    /// The color of the bars below the top of the target zone
+    pub bar_color: Color,
+    /// The color of the parts of the bars above the top of the target zone
+    pub over_color: Color,
+    /// The gap between the bars in pixels
+    pub bar_spacing: f32,
+    /// The color of the target zone behind the bars
+    pub target_zone_color: Color,
+    /// The color of the line at the integrated loudness when it is shown as
+    /// a marker
+    pub marker_color: Color,
+    /// The height of the line at the integrated loudness in pixels
+    pub marker_height: f32,
+}
+
+/// A set of rules that dictate the style of a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of a [`LoudnessMeter`].
+    ///
+    /// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// A styling function for a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Appearance + 'a>;
+
+/// The theme catalog of a [`LoudnessMeter`].
+///
+/// A meter is not interactive, so unlike the other widgets its style does
+/// not depend on a [`Status`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+/// [`Status`]: ../enum.Status.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>) -> Appearance;
+}
+
+/// The class of a [`LoudnessMeter`] for the built-in `Theme`.
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+#[derive(Default)]
+pub enum LoudnessMeter<'a> {
+    /// The default style.
+    #[default]
+    Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl<'a, S> From<S> for LoudnessMeter<'a>
+where
+    S: 'static + StyleSheet<Style = Theme>,
+{
+    fn from(val: S) -> Self {
+        LoudnessMeter::Custom(Box::new(val))
+    }
+}
+
+impl<'a> From<StyleFn<'a, Theme>> for LoudnessMeter<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        LoudnessMeter::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = LoudnessMeter<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        LoudnessMeter::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Appearance {
+        match class {
+            LoudnessMeter::Default => default(self),
+            LoudnessMeter::Preset(preset) => with_palette(&preset.palette(self)),
+            LoudnessMeter::Function(style) => style(self),
+            LoudnessMeter::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The default style of a [`LoudnessMeter`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme) -> Appearance {
+    with_palette(&presets::global().palette(theme))
+}
+
+/// The default style of a [`LoudnessMeter`] with the colors of the `palette`.
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+pub fn with_palette(palette: &Palette) -> Appearance {
+    Appearance {
+        back_color: palette.back,
+        border_width: 1.0,
+        border_radius: 2.0,
+        border_color: palette.border,
+        bar_color: palette.accent,
+        over_color: palette.negative,
+        bar_spacing: 2.0,
+        target_zone_color: Color {
+            a: 0.25,
+            ..palette.positive
+        },
+        marker_color: palette.text_mark,
+        marker_height: 2.0,
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            bar_color: self.bar_color.dim(opacity),
+            over_color: self.over_color.dim(opacity),
+            target_zone_color: self.target_zone_color.dim(opacity),
+            marker_color: self.marker_color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Various styles for the [`MeterBridge`] widget
+//!
+//! [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+
+use crate::style::{
+    palette::Palette,
+    presets::{self, Preset},
+    Dim,
+};
+use iced::{Color, Theme};
+
+/// The appearance of a [`MeterBridge`]
+///
+/// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+#[derive(Debug, Clone)]
+pub struct Appearance {
+    /// The color of the background
+    pub back_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The radius of the border
+    pub border_radius: f32,
+    /// The color of the border
+    pub border_color: Color,
+    /// The color of the bars
+    pub bar_color: Color,
+    /// The gap between the bars in pixels
+    pub bar_spacing: f32,
+    /// The color of the peak hold lines
+    pub peak_color: Color,
+    /// The height of the peak hold lines in pixels
+    pub peak_height: f32,
+    /// The color behind the bar of the channel under the cursor
+    pub highlight_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`MeterBridge`].
+///
+/// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of a [`MeterBridge`].
+    ///
+    /// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// A styling function for a [`MeterBridge`].
+///
+/// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Appearance + 'a>;
+
+/// The theme catalog of a [`MeterBridge`].
+///
+/// Like the other meters, its style does not depend on a [`Status`]. The
+/// channel under the cursor is shown with the highlight color instead.
+///
+/// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+/// [`Status`]: ../enum.Status.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>) -> Appearance;
+}
+
+/// The class of a [`MeterBridge`] for the built-in `Theme`.
+///
+/// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+#[derive(Default)]
+pub enum MeterBridge<'a> {
+    /// The default style.
+    #[default]
+    Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl<'a, S> From<S> for MeterBridge<'a>
+where
+    S: 'static + StyleSheet<Style = Theme>,
+{
+    fn from(val: S) -> Self {
+        MeterBridge::Custom(Box::new(val))
+    }
+}
+
+impl<'a> From<StyleFn<'a, Theme>> for MeterBridge<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        MeterBridge::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = MeterBridge<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        MeterBridge::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Appearance {
+        match class {
+            MeterBridge::Default => default(self),
+            MeterBridge::Preset(preset) => with_palette(&preset.palette(self)),
+            MeterBridge::Function(style) => style(self),
+            MeterBridge::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The default style of a [`MeterBridge`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme) -> Appearance {
+    with_palette(&presets::global().palette(theme))
+}
+
+/// The default style of a [`MeterBridge`] with the colors of the `palette`.
+///
+/// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+pub fn with_palette(palette: &Palette) -> Appearance {
+    Appearance {
+        back_color: palette.back,
+        border_width: 1.0,
+        border_radius: 2.0,
+        border_color: palette.border,
+        bar_color: palette.accent,
+        bar_spacing: 1.0,
+        peak_color: palette.text_mark,
+        peak_height: 1.0,
+        highlight_color: palette.back_hover,
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            bar_color: self.bar_color.dim(opacity),
+            peak_color: self.peak_color.dim(opacity),
+            highlight_color: self.highlight_color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -2,9 +2,16 @@
 //!
 //! [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 
-use crate::style::default_colors;
+use crate::style::{
+    default_colors, focus_ring,
+    palette::Palette,
+    presets::{self, Preset},
+    Dim,
+};
 use iced::{Color, Theme};
 
+pub use crate::style::Status;
+
 /// The appearance of an [`ModRangeInput`]
 ///
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
@@ -69,35 +76,77 @@ pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
     type Style: Default;
 
-    /// Produces the style of an active [`ModRangeInput`].
+    /// Produces the style of a [`ModRangeInput`] with the given [`Status`].
     ///
     /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
-    fn active(&self, style: &Self::Style) -> Appearance;
+    /// [`Status`]: ../enum.Status.html
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
 
-    /// Produces the style of a hovered [`ModRangeInput`].
+    /// The style of the ring drawn around a [`ModRangeInput`] that was focused
+    /// with the keyboard.
+    ///
+    /// The default is [`focus_ring::Appearance::default()`]. For no focus
+    /// ring, override this to return `None`.
     ///
     /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
-    fn hovered(&self, style: &Self::Style) -> Appearance;
+    /// [`focus_ring::Appearance::default()`]: ../focus_ring/struct.Appearance.html
+    fn focus_ring_appearance(&self, _style: &Self::Style) -> Option<focus_ring::Appearance> {
+        Some(focus_ring::Appearance::default())
+    }
+}
 
-    /// Produces the style of a [`ModRangeInput`] that is being dragged.
+/// A styling function for a [`ModRangeInput`].
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The theme catalog of a [`ModRangeInput`].
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
     ///
-    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
-    fn dragging(&self, style: &Self::Style) -> Appearance;
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class with the given status.
+    ///
+    /// [`Appearance`]: enum.Appearance.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+
+    /// The style of the focus ring of a class.
+    fn focus_ring_appearance(&self, _class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        None
+    }
 }
 
-/// The style of a [`ModRangeInput`].
+/// The class of a [`ModRangeInput`] for the built-in `Theme`.
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
 #[derive(Default)]
-pub enum ModRangeInput {
+pub enum ModRangeInput<'a> {
     /// The default style.
     #[default]
     Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
     /// The invisible style.
     Invisible,
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
     /// A custom style.
     Custom(Box<dyn StyleSheet<Style = Theme>>),
 }
 
-impl<S> From<S> for ModRangeInput
+impl<'a, S> From<S> for ModRangeInput<'a>
 where
     S: 'static + StyleSheet<Style = Theme>,
 {
@@ -106,33 +155,125 @@ where
     }
 }
 
-impl StyleSheet for Theme {
-    type Style = ModRangeInput;
+impl<'a> From<StyleFn<'a, Theme>> for ModRangeInput<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        ModRangeInput::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = ModRangeInput<'a>;
 
-    fn active(&self, style: &Self::Style) -> Appearance {
-        match style {
-            ModRangeInput::Default => Appearance::Circle(Default::default()),
+    fn default<'a>() -> Self::Class<'a> {
+        ModRangeInput::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            ModRangeInput::Default => default(self, status),
+            ModRangeInput::Preset(preset) => with_palette(&preset.palette(self), status),
             ModRangeInput::Invisible => Appearance::Invisible,
-            ModRangeInput::Custom(custom) => custom.active(self),
+            ModRangeInput::Function(style) => style(self, status),
+            ModRangeInput::Custom(custom) => custom.appearance(self, status),
         }
     }
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            ModRangeInput::Default => Appearance::Circle(CircleAppearance {
-                color: default_colors::KNOB_BACK_HOVER,
-                ..Default::default()
-            }),
-            ModRangeInput::Invisible => self.active(style),
-            ModRangeInput::Custom(custom) => custom.active(self),
+    fn focus_ring_appearance(&self, class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        match class {
+            ModRangeInput::Custom(custom) => custom.focus_ring_appearance(self),
+            _ => Some(focus_ring::with_palette(&palette(self, class))),
         }
     }
+}
+
+/// The default style of a [`ModRangeInput`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    with_palette(&presets::global().palette(theme), status)
+}
+
+/// The default style of a [`ModRangeInput`] with the colors of the `palette`.
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+pub fn with_palette(palette: &Palette, status: Status) -> Appearance {
+    Appearance::Circle(default_circle(palette, back_color(palette, status)))
+}
+
+/// A square style of a [`ModRangeInput`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn square(theme: &Theme, status: Status) -> Appearance {
+    let palette = presets::global().palette(theme);
+    let circle = default_circle(&palette, back_color(&palette, status));
+
+    Appearance::Square(SquareAppearance {
+        color: circle.color,
+        border_width: circle.border_width,
+        border_radius: 2.0,
+        border_color: circle.border_color,
+    })
+}
+
+/// The background color of the default styles with the given `status`.
+fn back_color(palette: &Palette, status: Status) -> Color {
+    match status {
+        Status::Active | Status::Disabled => palette.back,
+        Status::Hovered | Status::Dragged | Status::Focused | Status::DropTarget => {
+            palette.knob_back_hover
+        }
+    }
+}
+
+/// The default circle appearance with the colors of the `palette`.
+fn default_circle(palette: &Palette, color: Color) -> CircleAppearance {
+    CircleAppearance {
+        color,
+        border_color: palette.border,
+        ..Default::default()
+    }
+}
+
+/// The palette of a class: the one of its [`Preset`], or the global one.
+///
+/// [`Preset`]: ../presets/enum.Preset.html
+fn palette(theme: &Theme, class: &ModRangeInput<'_>) -> Palette {
+    match class {
+        ModRangeInput::Preset(preset) => preset.palette(theme),
+        _ => presets::global().palette(theme),
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        match self {
+            Appearance::Circle(circle) => Appearance::Circle(circle.dim(opacity)),
+            Appearance::Square(square) => Appearance::Square(square.dim(opacity)),
+            other => other,
+        }
+    }
+}
+
+impl Dim for CircleAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            ..self
+        }
+    }
+}
 
-    fn dragging(&self, style: &Self::Style) -> Appearance {
-        match style {
-            ModRangeInput::Default => self.hovered(style),
-            ModRangeInput::Invisible => self.active(style),
-            ModRangeInput::Custom(custom) => custom.active(self),
+impl Dim for SquareAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            ..self
         }
     }
 }
@@ -0,0 +1,164 @@
+//! Colors of the default styles, derived from an [`iced::Theme`]
+//!
+//! [`iced::Theme`]: https://docs.rs/iced/latest/iced/enum.Theme.html
+
+use iced::{Color, Theme};
+
+/// The colors used by the default styles of the widgets.
+///
+/// A `Palette` is derived from the [`palette`] of the active theme, so that
+/// the default styles look right on both light and dark themes. Custom
+/// styles can use it as well to match the default ones.
+///
+/// With the `serde` feature, its colors are hex strings, e.g. `"#1e1e1e"`,
+/// and missing colors are those of the light theme.
+///
+/// [`palette`]: https://docs.rs/iced/latest/iced/enum.Theme.html#method.palette
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Palette {
+    /// The background of handles and knobs
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub back: Color,
+    /// The background of hovered slider and pad handles
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub back_hover: Color,
+    /// The background of dragged slider and pad handles
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub back_drag: Color,
+    /// The background of hovered knobs
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub knob_back_hover: Color,
+    /// The background of a hovered ramp
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub ramp_back_hover: Color,
+    /// The color of borders, notches, and lines
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub border: Color,
+    /// The colors of the top and bottom of a slider rail
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color::pair"))]
+    pub slider_rail: (Color, Color),
+    /// The color of tier 1 tick marks
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub tick_tier_1: Color,
+    /// The color of tier 2 tick marks
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub tick_tier_2: Color,
+    /// The color of tier 3 tick marks
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub tick_tier_3: Color,
+    /// The color of text marks
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub text_mark: Color,
+    /// The color of the rails of an XY pad
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub xy_pad_rail: Color,
+    /// The color of the center lines of an XY pad
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub xy_pad_center_line: Color,
+    /// The primary color of the theme, for filled portions and highlights
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub accent: Color,
+    /// The success color of the theme, e.g. for positive modulation
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub positive: Color,
+    /// The danger color of the theme, e.g. for negative modulation
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::serde_color"))]
+    pub negative: Color,
+}
+
+impl Palette {
+    /// Derives a `Palette` from the palette of the given `theme`.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let palette = theme.palette();
+        let shade = |amount: f32, alpha: f32| mix(palette.background, palette.text, amount, alpha);
+
+        Self {
+            back: shade(0.03, 1.0),
+            back_hover: shade(0.07, 1.0),
+            back_drag: shade(0.08, 1.0),
+            knob_back_hover: shade(0.04, 1.0),
+            ramp_back_hover: shade(0.05, 1.0),
+            border: shade(0.685, 1.0),
+            slider_rail: (shade(0.74, 0.75), shade(0.44, 0.75)),
+            tick_tier_1: shade(0.44, 0.93),
+            tick_tier_2: shade(0.44, 0.83),
+            tick_tier_3: shade(0.44, 0.65),
+            text_mark: shade(0.44, 0.65),
+            xy_pad_rail: shade(0.44, 0.9),
+            xy_pad_center_line: shade(0.44, 0.5),
+            accent: palette.primary,
+            positive: palette.success,
+            negative: palette.danger,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::from_theme(&Theme::Light)
+    }
+}
+
+/// Moves `amount` of the way from `from` to `to`, with the given `alpha`.
+fn mix(from: Color, to: Color, amount: f32, alpha: f32) -> Color {
+    Color {
+        r: from.r + (to.r - from.r) * amount,
+        g: from.g + (to.g - from.g) * amount,
+        b: from.b + (to.b - from.b) * amount,
+        a: alpha,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Palette;
+    use crate::style::default_colors;
+    use iced::{Color, Theme};
+
+    fn assert_close(a: Color, b: Color) {
+        let a = a.into_rgba8();
+        let b = b.into_rgba8();
+
+        assert!(
+            a.iter().zip(b.iter()).all(|(a, b)| a.abs_diff(*b) <= 1),
+            "{a:?} is not {b:?}"
+        );
+    }
+
+    #[test]
+    fn light_theme_matches_default_colors() {
+        let palette = Palette::from_theme(&Theme::Light);
+
+        assert_close(palette.back, default_colors::LIGHT_BACK);
+        assert_close(palette.back_hover, Color::from_rgb(0.93, 0.93, 0.93));
+        assert_close(palette.back_drag, Color::from_rgb(0.92, 0.92, 0.92));
+        assert_close(palette.knob_back_hover, Color::from_rgb(0.96, 0.96, 0.96));
+        assert_close(palette.ramp_back_hover, Color::from_rgb(0.95, 0.95, 0.95));
+        assert_close(palette.border, default_colors::BORDER);
+        assert_close(palette.slider_rail.0, default_colors::SLIDER_RAIL.0);
+        assert_close(palette.slider_rail.1, default_colors::SLIDER_RAIL.1);
+        assert_close(palette.tick_tier_1, default_colors::TICK_TIER_1);
+        assert_close(palette.tick_tier_2, default_colors::TICK_TIER_2);
+        assert_close(palette.tick_tier_3, default_colors::TICK_TIER_3);
+        assert_close(palette.text_mark, default_colors::TEXT_MARK);
+        assert_close(palette.xy_pad_rail, default_colors::XY_PAD_RAIL);
+        assert_close(
+            palette.xy_pad_center_line,
+            default_colors::XY_PAD_CENTER_LINE,
+        );
+    }
+
+    #[test]
+    fn dark_theme_has_dark_back() {
+        let palette = Palette::from_theme(&Theme::Dark);
+
+        let luminance = |color: Color| color.r + color.g + color.b;
+        assert!(luminance(palette.back) < luminance(palette.border));
+        assert_eq!(palette.accent, Theme::Dark.palette().primary);
+    }
+}
@@ -0,0 +1,192 @@
+//! Alternative color presets of the default styles, e.g. for high contrast
+//! or colorblind users
+//!
+//! A [`Preset`] is selected for all widgets with [`set_global`], or for a
+//! single widget with the `Preset` variant of its class, e.g.
+//! `Knob::Preset(Preset::HighContrast)`.
+//!
+//! [`Preset`]: enum.Preset.html
+//! [`set_global`]: fn.set_global.html
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::style::palette::Palette;
+use iced::{Color, Theme};
+
+/// The colors of the default styles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Preset {
+    /// The colors derived from the theme, see [`Palette::from_theme`].
+    ///
+    /// [`Palette::from_theme`]: ../palette/struct.Palette.html#method.from_theme
+    #[default]
+    Standard,
+    /// Opaque marks and borders in the text color of the theme on a black or
+    /// white background, see [`high_contrast`].
+    ///
+    /// [`high_contrast`]: fn.high_contrast.html
+    HighContrast,
+    /// The colors derived from the theme, with blue and orange instead of
+    /// green and red, see [`deuteranopia_safe`].
+    ///
+    /// [`deuteranopia_safe`]: fn.deuteranopia_safe.html
+    DeuteranopiaSafe,
+}
+
+impl Preset {
+    /// Returns the [`Palette`] of this preset for the given `theme`.
+    ///
+    /// [`Palette`]: ../palette/struct.Palette.html
+    pub fn palette(self, theme: &Theme) -> Palette {
+        match self {
+            Preset::Standard => Palette::from_theme(theme),
+            Preset::HighContrast => high_contrast(theme),
+            Preset::DeuteranopiaSafe => deuteranopia_safe(theme),
+        }
+    }
+}
+
+static GLOBAL: AtomicU8 = AtomicU8::new(Preset::Standard as u8);
+
+/// Sets the [`Preset`] of the default styles of all widgets whose class
+/// doesn't select one.
+///
+/// [`Preset`]: enum.Preset.html
+pub fn set_global(preset: Preset) {
+    GLOBAL.store(preset as u8, Ordering::Relaxed);
+}
+
+/// Returns the [`Preset`] set with [`set_global`], [`Preset::Standard`] by
+/// default.
+///
+/// [`Preset`]: enum.Preset.html
+/// [`set_global`]: fn.set_global.html
+/// [`Preset::Standard`]: enum.Preset.html#variant.Standard
+pub fn global() -> Preset {
+    match GLOBAL.load(Ordering::Relaxed) {
+        x if x == Preset::HighContrast as u8 => Preset::HighContrast,
+        x if x == Preset::DeuteranopiaSafe as u8 => Preset::DeuteranopiaSafe,
+        _ => Preset::Standard,
+    }
+}
+
+/// A high contrast [`Palette`] for the given `theme`.
+///
+/// The background is black on dark themes and white on light themes, and
+/// borders, rails, tick marks, and text marks are opaque in the text color
+/// of the theme. The accent and the bipolar (positive and negative) colors
+/// are saturated and differ in lightness as well as hue.
+///
+/// [`Palette`]: ../palette/struct.Palette.html
+pub fn high_contrast(theme: &Theme) -> Palette {
+    let is_dark = theme.extended_palette().is_dark;
+
+    let (back, text) = if is_dark {
+        (Color::BLACK, Color::WHITE)
+    } else {
+        (Color::WHITE, Color::BLACK)
+    };
+    let shade = |amount: f32| Color {
+        r: back.r + (text.r - back.r) * amount,
+        g: back.g + (text.g - back.g) * amount,
+        b: back.b + (text.b - back.b) * amount,
+        a: 1.0,
+    };
+
+    let (accent, positive, negative) = if is_dark {
+        (
+            Color::from_rgb(1.0, 0.85, 0.0),
+            Color::from_rgb(0.35, 0.8, 1.0),
+            Color::from_rgb(1.0, 0.45, 0.0),
+        )
+    } else {
+        (
+            Color::from_rgb(0.0, 0.25, 0.8),
+            Color::from_rgb(0.0, 0.4, 0.75),
+            Color::from_rgb(0.85, 0.4, 0.0),
+        )
+    };
+
+    Palette {
+        back,
+        back_hover: shade(0.15),
+        back_drag: shade(0.2),
+        knob_back_hover: shade(0.15),
+        ramp_back_hover: shade(0.15),
+        border: text,
+        slider_rail: (text, shade(0.6)),
+        tick_tier_1: text,
+        tick_tier_2: text,
+        tick_tier_3: shade(0.75),
+        text_mark: text,
+        xy_pad_rail: text,
+        xy_pad_center_line: shade(0.6),
+        accent,
+        positive,
+        negative,
+    }
+}
+
+/// A [`Palette`] for the given `theme` that stays distinguishable with
+/// red-green color blindness (deuteranopia and protanopia).
+///
+/// The neutral colors are derived from the theme like
+/// [`Palette::from_theme`], and the accent and the bipolar (positive and
+/// negative) colors are taken from the Okabe-Ito palette: blue, sky blue,
+/// and orange.
+///
+/// [`Palette`]: ../palette/struct.Palette.html
+/// [`Palette::from_theme`]: ../palette/struct.Palette.html#method.from_theme
+pub fn deuteranopia_safe(theme: &Theme) -> Palette {
+    let accent = if theme.extended_palette().is_dark {
+        Color::from_rgb(0.34, 0.71, 0.91)
+    } else {
+        Color::from_rgb(0.0, 0.45, 0.7)
+    };
+
+    Palette {
+        accent,
+        positive: Color::from_rgb(0.34, 0.71, 0.91),
+        negative: Color::from_rgb(0.9, 0.62, 0.0),
+        ..Palette::from_theme(theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deuteranopia_safe, high_contrast, Preset};
+    use crate::style::palette::Palette;
+    use iced::{Color, Theme};
+
+    fn luminance(color: Color) -> f32 {
+        0.2126 * color.r + 0.7152 * color.g + 0.0722 * color.b
+    }
+
+    #[test]
+    fn high_contrast_is_opaque_and_far_from_back() {
+        for theme in [Theme::Light, Theme::Dark] {
+            let palette = high_contrast(&theme);
+
+            for color in [palette.border, palette.tick_tier_1, palette.text_mark] {
+                assert_eq!(color.a, 1.0);
+                assert!((luminance(color) - luminance(palette.back)).abs() > 0.9);
+            }
+
+            // The bipolar colors differ in lightness, not only in hue.
+            assert!((luminance(palette.positive) - luminance(palette.negative)).abs() > 0.05);
+        }
+    }
+
+    #[test]
+    fn deuteranopia_safe_avoids_red_and_green() {
+        let palette = deuteranopia_safe(&Theme::Light);
+
+        assert_eq!(palette.back, Palette::from_theme(&Theme::Light).back);
+        assert!(palette.positive.b > palette.positive.g);
+        assert!(palette.negative.r > palette.negative.g && palette.negative.b == 0.0);
+        assert_eq!(
+            Preset::Standard.palette(&Theme::Dark),
+            Palette::from_theme(&Theme::Dark)
+        );
+    }
+}
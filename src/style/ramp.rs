@@ -2,8 +2,21 @@
 //!
 //! [`Ramp`]: ../native/ramp/struct.Ramp.html
 
-use crate::style::default_colors;
-use iced::Color;
+use crate::core::Offset;
+use crate::style::{
+    default_colors, focus_ring,
+    palette::Palette,
+    presets::{self, Preset},
+    text_marks, tick_marks, Dim,
+};
+use iced::{Color, Theme};
+
+pub use crate::style::Status;
+
+/// The default `value_quantization` of a [`Ramp`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+pub const DEFAULT_VALUE_QUANTIZATION: f32 = 0.001;
 
 /// The appearance of a [`Ramp`],
 ///
@@ -24,6 +37,11 @@ pub struct Appearance {
     pub line_up_color: Color,
     /// The color of the ramp line when it is in the down position
     pub line_down_color: Color,
+    /// The step that the value is rounded to before the ramp line is drawn.
+    /// The line is only drawn again when the rounded value, the size, or
+    /// the line style changes, so smaller changes of the value are not
+    /// shown. `0.0` draws the line again for every change.
+    pub value_quantization: f32,
 }
 
 impl Default for Appearance {
@@ -36,10 +54,33 @@ impl Default for Appearance {
             line_center_color: default_colors::BORDER,
             line_up_color: default_colors::BORDER,
             line_down_color: default_colors::BORDER,
+            value_quantization: DEFAULT_VALUE_QUANTIZATION,
         }
     }
 }
 
+/// Style of tick marks for a [`Ramp`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+#[derive(Debug, Clone)]
+pub struct TickMarksAppearance {
+    /// The style of the tick marks
+    pub style: tick_marks::Appearance,
+    /// The placement of the tick marks
+    pub placement: tick_marks::Placement,
+}
+
+/// Style of text marks for a [`Ramp`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+#[derive(Debug, Clone)]
+pub struct TextMarksAppearance {
+    /// The style of the text marks
+    pub style: text_marks::Appearance,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
 /// A set of rules that dictate the style of a [`Ramp`].
 ///
 /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
@@ -47,62 +88,262 @@ pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
     type Style: Default;
 
-    /// Produces the style of an active [`Ramp`].
+    /// Produces the style of a [`Ramp`] with the given [`Status`].
     ///
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
-    fn active(&self, style: &Self::Style) -> Appearance;
+    /// [`Status`]: ../enum.Status.html
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
 
-    /// Produces the style of a hovered [`Ramp`].
+    /// The style of the ring drawn around a [`Ramp`] that was focused
+    /// with the keyboard.
+    ///
+    /// The default is [`focus_ring::Appearance::default()`]. For no focus
+    /// ring, override this to return `None`.
     ///
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
-    fn hovered(&self, style: &Self::Style) -> Appearance;
+    /// [`focus_ring::Appearance::default()`]: ../focus_ring/struct.Appearance.html
+    fn focus_ring_appearance(&self, _style: &Self::Style) -> Option<focus_ring::Appearance> {
+        Some(focus_ring::Appearance::default())
+    }
 
-    /// Produces the style of a [`Ramp`] that is being dragged.
+    /// The style of tick marks along the bottom edge of a [`Ramp`]
+    ///
+    /// For no tick marks, don't override this or set this to return `None`.
     ///
     /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
-    fn dragging(&self, style: &Self::Style) -> Appearance;
+    fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of text marks along the bottom edge of a [`Ramp`]
+    ///
+    /// For no text marks, don't override this or set this to return `None`.
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    fn text_marks_appearance(&self, _style: &Self::Style) -> Option<TextMarksAppearance> {
+        None
+    }
+}
+
+/// A styling function for a [`Ramp`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The theme catalog of a [`Ramp`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class with the given status.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+
+    /// The style of the focus ring of a class.
+    fn focus_ring_appearance(&self, _class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        None
+    }
+
+    /// The style of the tick marks of a class.
+    fn tick_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        None
+    }
+
+    /// The style of the text marks of a class.
+    fn text_marks_appearance(&self, _class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        None
+    }
 }
 
-/// The style of a Ramp.
+/// The class of a [`Ramp`] for the built-in `Theme`.
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
 #[derive(Default)]
-pub enum Ramp {
+pub enum Ramp<'a> {
     /// The default style.
     #[default]
     Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function. The tick marks and text marks use
+    /// the default style.
+    Function(StyleFn<'a, Theme>),
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = iced::Theme>>),
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
 }
 
-impl<S> From<S> for Ramp
+impl<'a, S> From<S> for Ramp<'a>
 where
-    S: 'static + StyleSheet<Style = iced::Theme>,
+    S: 'static + StyleSheet<Style = Theme>,
 {
     fn from(val: S) -> Self {
         Ramp::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for iced::Theme {
-    type Style = Ramp;
+impl<'a> From<StyleFn<'a, Theme>> for Ramp<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        Ramp::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = Ramp<'a>;
 
-    fn active(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Ramp::Default => Default::default(),
-            Ramp::Custom(custom) => custom.active(self),
+    fn default<'a>() -> Self::Class<'a> {
+        Ramp::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            Ramp::Default => default(self, status),
+            Ramp::Preset(preset) => with_palette(&preset.palette(self), status),
+            Ramp::Function(style) => style(self, status),
+            Ramp::Custom(custom) => custom.appearance(self, status),
         }
     }
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            Ramp::Default => Appearance {
-                back_color: default_colors::RAMP_BACK_HOVER,
-                ..Default::default()
-            },
-            Ramp::Custom(custom) => custom.active(self),
+    fn focus_ring_appearance(&self, class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        match class {
+            Ramp::Custom(custom) => custom.focus_ring_appearance(self),
+            _ => Some(focus_ring::with_palette(&palette(self, class))),
         }
     }
 
-    fn dragging(&self, style: &Self::Style) -> Appearance {
-        self.hovered(style)
+    fn tick_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TickMarksAppearance> {
+        match class {
+            Ramp::Custom(custom) => custom.tick_marks_appearance(self),
+            _ => {
+                let palette = palette(self, class);
+
+                Some(TickMarksAppearance {
+                    style: tick_marks::Appearance {
+                        tier_1: tick_marks::Shape::Line {
+                            length: 4.0,
+                            width: 1.0,
+                            color: palette.tick_tier_1,
+                        },
+                        tier_2: tick_marks::Shape::Line {
+                            length: 3.0,
+                            width: 1.0,
+                            color: palette.tick_tier_2,
+                        },
+                        tier_3: tick_marks::Shape::Line {
+                            length: 2.0,
+                            width: 1.0,
+                            color: palette.tick_tier_3,
+                        },
+                        density: Default::default(),
+                    },
+                    placement: tick_marks::Placement::RightOrBottom {
+                        offset: Offset::ZERO,
+                        inside: false,
+                    },
+                })
+            }
+        }
+    }
+
+    fn text_marks_appearance(&self, class: &Self::Class<'_>) -> Option<TextMarksAppearance> {
+        match class {
+            Ramp::Custom(custom) => custom.text_marks_appearance(self),
+            _ => Some(TextMarksAppearance {
+                style: text_marks::Appearance {
+                    color: palette(self, class).text_mark,
+                    ..Default::default()
+                },
+                placement: text_marks::Placement::RightOrBottom {
+                    inside: false,
+                    offset: Offset { x: 0.0, y: 5.0 },
+                },
+            }),
+        }
+    }
+}
+
+/// The default style of a [`Ramp`], with colors derived from the `theme` and
+/// the global [`Preset`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    with_palette(&presets::global().palette(theme), status)
+}
+
+/// The default style of a [`Ramp`] with the colors of the `palette`.
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+pub fn with_palette(palette: &Palette, status: Status) -> Appearance {
+    let back_color = match status {
+        Status::Active | Status::Disabled => palette.back,
+        Status::Hovered | Status::Dragged | Status::Focused | Status::DropTarget => {
+            palette.ramp_back_hover
+        }
+    };
+
+    default_appearance(palette, back_color)
+}
+
+/// The default appearance with the colors of the `palette`.
+fn default_appearance(palette: &Palette, back_color: Color) -> Appearance {
+    Appearance {
+        back_color,
+        back_border_color: palette.border,
+        line_center_color: palette.border,
+        line_up_color: palette.border,
+        line_down_color: palette.border,
+        ..Default::default()
+    }
+}
+
+/// The palette of the default extras of a class.
+fn palette(theme: &Theme, class: &Ramp<'_>) -> Palette {
+    match class {
+        Ramp::Preset(preset) => preset.palette(theme),
+        _ => presets::global().palette(theme),
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            back_border_color: self.back_border_color.dim(opacity),
+            line_center_color: self.line_center_color.dim(opacity),
+            line_up_color: self.line_up_color.dim(opacity),
+            line_down_color: self.line_down_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for TickMarksAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            style: self.style.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for TextMarksAppearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            style: self.style.dim(opacity),
+            ..self
+        }
     }
 }
@@ -0,0 +1,167 @@
+//! Various styles for the [`ReductionMeter`] widget
+//!
+//! [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+
+use crate::style::{
+    palette::Palette,
+    presets::{self, Preset},
+    Dim,
+};
+use iced::{Color, Theme};
+
+/// The appearance of a [`ReductionMeter`]
+///
+/// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+#[derive(Debug, Clone)]
+pub struct Appearance {
+    /// The color of the background
+    pub back_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The radius of the border
+    pub border_radius: f32,
+    /// The color of the border
+    pub border_color: Color,
+    /// The color of the bar that grows down from the top
+    pub bar_color: Color,
+    /// The width of the bar when the history is shown to the left of it.
+    /// Without a history, the bar fills the whole width.
+    pub bar_width: f32,
+    /// The color of the filled history graph
+    pub history_color: Color,
+    /// The gap between the history and the bar in pixels
+    pub history_spacing: f32,
+}
+
+/// A set of rules that dictate the style of a [`ReductionMeter`].
+///
+/// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of a [`ReductionMeter`].
+    ///
+    /// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// A styling function for a [`ReductionMeter`].
+///
+/// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Appearance + 'a>;
+
+/// The theme catalog of a [`ReductionMeter`].
+///
+/// A meter is not interactive, so unlike the other widgets its style does
+/// not depend on a [`Status`].
+///
+/// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+/// [`Status`]: ../enum.Status.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>) -> Appearance;
+}
+
+/// The class of a [`ReductionMeter`] for the built-in `Theme`.
+///
+/// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+#[derive(Default)]
+pub enum ReductionMeter<'a> {
+    /// The default style.
+    #[default]
+    Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl<'a, S> From<S> for ReductionMeter<'a>
+where
+    S: 'static + StyleSheet<Style = Theme>,
+{
+    fn from(val: S) -> Self {
+        ReductionMeter::Custom(Box::new(val))
+    }
+}
+
+impl<'a> From<StyleFn<'a, Theme>> for ReductionMeter<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        ReductionMeter::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = ReductionMeter<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        ReductionMeter::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Appearance {
+        match class {
+            ReductionMeter::Default => default(self),
+            ReductionMeter::Preset(preset) => with_palette(&preset.palette(self)),
+            ReductionMeter::Function(style) => style(self),
+            ReductionMeter::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The default style of a [`ReductionMeter`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme) -> Appearance {
+    with_palette(&presets::global().palette(theme))
+}
+
+/// The default style of a [`ReductionMeter`] with the colors of the `palette`.
+///
+/// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+pub fn with_palette(palette: &Palette) -> Appearance {
+    Appearance {
+        back_color: palette.back,
+        border_width: 1.0,
+        border_radius: 2.0,
+        border_color: palette.border,
+        bar_color: palette.accent,
+        bar_width: 8.0,
+        history_color: Color {
+            a: 0.5,
+            ..palette.accent
+        },
+        history_spacing: 2.0,
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            bar_color: self.bar_color.dim(opacity),
+            history_color: self.history_color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Lists of the built-in styles of every widget that has a style, e.g. to let
+//! users choose a style or to show them all side by side
+//!
+//! Each style is listed with the name of the variant of the appearance it
+//! produces, or `"Default"` for widgets with a single kind of appearance, and
+//! can be passed to the `style` builder of its widget:
+//!
+//! ```
+//! use iced_audio::style::registry;
+//!
+//! let names: Vec<_> = registry::all_knob_styles()
+//!     .iter()
+//!     .map(|(name, _)| *name)
+//!     .collect();
+//!
+//! assert_eq!(names, ["Circle", "Arc", "ArcBipolar", "ArcSegmented"]);
+//! ```
+//!
+//! Styles that need an image, like the texture styles, aren't listed.
+
+use iced::Theme;
+
+use crate::style::{
+    arc_slider, compressor_curve, h_slider, knob, labeled, loudness_meter, meter_bridge,
+    mod_range_input, ramp, reduction_meter, spectrogram, stereo_slider, v_slider, xy_pad, Status,
+};
+
+/// A built-in style function together with its name.
+pub type Entry<Appearance> = (&'static str, fn(&Theme, Status) -> Appearance);
+
+/// A built-in style function of a widget without a [`Status`], like a meter,
+/// together with its name.
+///
+/// [`Status`]: ../enum.Status.html
+pub type ThemeEntry<Appearance> = (&'static str, fn(&Theme) -> Appearance);
+
+/// Returns the built-in styles of a [`Knob`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+pub fn all_knob_styles() -> &'static [Entry<knob::Appearance>] {
+    &[
+        ("Circle", knob::default),
+        ("Arc", knob::arc),
+        ("ArcBipolar", knob::arc_bipolar),
+        ("ArcSegmented", knob::arc_segmented),
+    ]
+}
+
+/// Returns the built-in styles of an [`ArcSlider`].
+///
+/// [`ArcSlider`]: ../../native/arc_slider/struct.ArcSlider.html
+pub fn all_arc_slider_styles() -> &'static [Entry<arc_slider::Appearance>] {
+    &[("Default", arc_slider::default)]
+}
+
+/// Returns the built-in styles of an [`HSlider`].
+///
+/// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+pub fn all_h_slider_styles() -> &'static [Entry<h_slider::Appearance>] {
+    &[
+        ("Classic", h_slider::default),
+        ("Rect", h_slider::rect),
+        ("RectBipolar", h_slider::rect_bipolar),
+        ("RectSplit", h_slider::rect_split),
+    ]
+}
+
+/// Returns the built-in styles of a [`VSlider`].
+///
+/// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+pub fn all_v_slider_styles() -> &'static [Entry<v_slider::Appearance>] {
+    &[
+        ("Classic", v_slider::default),
+        ("Rect", v_slider::rect),
+        ("RectBipolar", v_slider::rect_bipolar),
+        ("RectSplit", v_slider::rect_split),
+    ]
+}
+
+/// Returns the built-in styles of a [`StereoSlider`].
+///
+/// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+pub fn all_stereo_slider_styles() -> &'static [Entry<stereo_slider::Appearance>] {
+    &[("Default", stereo_slider::default)]
+}
+
+/// Returns the built-in styles of a [`Ramp`].
+///
+/// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+pub fn all_ramp_styles() -> &'static [Entry<ramp::Appearance>] {
+    &[("Default", ramp::default)]
+}
+
+/// Returns the built-in styles of a [`ModRangeInput`].
+///
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+pub fn all_mod_range_input_styles() -> &'static [Entry<mod_range_input::Appearance>] {
+    &[
+        ("Circle", mod_range_input::default),
+        ("Square", mod_range_input::square),
+    ]
+}
+
+/// Returns the built-in styles of an [`XYPad`], named after the shape of
+/// their handle.
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+pub fn all_xy_pad_styles() -> &'static [Entry<xy_pad::Appearance>] {
+    &[
+        ("Circle", xy_pad::default),
+        ("Square", xy_pad::square_handle),
+    ]
+}
+
+/// Returns the built-in styles of a [`CompressorCurve`].
+///
+/// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+pub fn all_compressor_curve_styles() -> &'static [Entry<compressor_curve::Appearance>] {
+    &[("Default", compressor_curve::default)]
+}
+
+/// Returns the built-in styles of a [`Labeled`] widget.
+///
+/// [`Labeled`]: ../../native/labeled/struct.Labeled.html
+pub fn all_labeled_styles() -> &'static [ThemeEntry<labeled::Appearance>] {
+    &[("Default", labeled::default)]
+}
+
+/// Returns the built-in styles of a [`ReductionMeter`].
+///
+/// [`ReductionMeter`]: ../../native/reduction_meter/struct.ReductionMeter.html
+pub fn all_reduction_meter_styles() -> &'static [ThemeEntry<reduction_meter::Appearance>] {
+    &[("Default", reduction_meter::default)]
+}
+
+/// Returns the built-in styles of a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: ../../native/loudness_meter/struct.LoudnessMeter.html
+pub fn all_loudness_meter_styles() -> &'static [ThemeEntry<loudness_meter::Appearance>] {
+    &[("Default", loudness_meter::default)]
+}
+
+/// Returns the built-in styles of a [`MeterBridge`].
+///
+/// [`MeterBridge`]: ../../native/meter_bridge/struct.MeterBridge.html
+pub fn all_meter_bridge_styles() -> &'static [ThemeEntry<meter_bridge::Appearance>] {
+    &[("Default", meter_bridge::default)]
+}
+
+/// Returns the built-in styles of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+pub fn all_spectrogram_styles() -> &'static [ThemeEntry<spectrogram::Appearance>] {
+    &[("Default", spectrogram::default)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        all_arc_slider_styles, all_compressor_curve_styles, all_h_slider_styles, all_knob_styles,
+        all_labeled_styles, all_loudness_meter_styles, all_meter_bridge_styles,
+        all_mod_range_input_styles, all_ramp_styles, all_reduction_meter_styles,
+        all_spectrogram_styles, all_stereo_slider_styles, all_v_slider_styles, all_xy_pad_styles,
+    };
+    use crate::style::{h_slider, knob, ramp, v_slider, Status};
+    use iced::Theme;
+
+    #[test]
+    fn styles_produce_the_variants_they_are_named_after() {
+        let theme = Theme::Dark;
+
+        for (name, style) in all_knob_styles() {
+            let variant = match style(&theme, Status::Active) {
+                knob::Appearance::Circle(_) => "Circle",
+                knob::Appearance::Arc(_) => "Arc",
+                knob::Appearance::ArcBipolar(_) => "ArcBipolar",
+                knob::Appearance::ArcSegmented(_) => "ArcSegmented",
+            };
+            assert_eq!(*name, variant);
+        }
+
+        for (name, style) in all_h_slider_styles() {
+            let variant = match style(&theme, Status::Active) {
+                h_slider::Appearance::Texture(_) => "Texture",
+                h_slider::Appearance::Classic(_) => "Classic",
+                h_slider::Appearance::Rect(_) => "Rect",
+                h_slider::Appearance::RectBipolar(_) => "RectBipolar",
+                h_slider::Appearance::RectSplit(_) => "RectSplit",
+            };
+            assert_eq!(*name, variant);
+        }
+
+        for (name, style) in all_v_slider_styles() {
+            let variant = match style(&theme, Status::Active) {
+                v_slider::Appearance::Texture(_) => "Texture",
+                v_slider::Appearance::Classic(_) => "Classic",
+                v_slider::Appearance::Rect(_) => "Rect",
+                v_slider::Appearance::RectBipolar(_) => "RectBipolar",
+                v_slider::Appearance::RectSplit(_) => "RectSplit",
+            };
+            assert_eq!(*name, variant);
+        }
+    }
+
+    #[test]
+    fn every_widget_with_a_style_is_listed() {
+        let theme = Theme::Dark;
+        let counts = [
+            all_knob_styles().len(),
+            all_arc_slider_styles().len(),
+            all_h_slider_styles().len(),
+            all_v_slider_styles().len(),
+            all_stereo_slider_styles().len(),
+            all_ramp_styles().len(),
+            all_mod_range_input_styles().len(),
+            all_xy_pad_styles().len(),
+            all_compressor_curve_styles().len(),
+            all_labeled_styles().len(),
+            all_reduction_meter_styles().len(),
+            all_loudness_meter_styles().len(),
+            all_meter_bridge_styles().len(),
+            all_spectrogram_styles().len(),
+        ];
+        assert!(counts.iter().all(|count| *count > 0));
+
+        // The single styles are the defaults the widgets use.
+        let (name, style) = all_ramp_styles()[0];
+        assert_eq!(name, "Default");
+        assert_eq!(
+            style(&theme, Status::Active).back_color,
+            ramp::default(&theme, Status::Active).back_color
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Serializes colors as hex strings, e.g. `"#1e1e1e"` or `"#ffffff80"`, so
+//! style files stay readable
+
+use iced::Color;
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+pub(crate) fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&to_hex(*color))
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    from_hex(&String::deserialize(deserializer)?)
+}
+
+/// A pair of colors, e.g. the top and bottom colors of a slider rail.
+pub(crate) mod pair {
+    use super::{from_hex, to_hex};
+    use iced::Color;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        colors: &(Color, Color),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        (to_hex(colors.0), to_hex(colors.1)).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<(Color, Color), D::Error> {
+        let (first, second) = <(String, String)>::deserialize(deserializer)?;
+
+        Ok((from_hex(&first)?, from_hex(&second)?))
+    }
+}
+
+fn to_hex(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+fn from_hex<E: Error>(hex: &str) -> Result<Color, E> {
+    Color::parse(hex).ok_or_else(|| {
+        E::custom(format!(
+            "invalid color `{hex}`, expected `#rrggbb` or `#rrggbbaa`"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use iced::Color;
+    use serde::de::value::{Error, StrDeserializer};
+
+    #[test]
+    fn reads_hex_colors() {
+        let color = |hex| super::deserialize(StrDeserializer::<Error>::new(hex));
+
+        assert_eq!(
+            color("#ff8800").unwrap(),
+            Color::from_rgb8(0xff, 0x88, 0x00)
+        );
+        assert_eq!(color("#ff880080").unwrap().into_rgba8()[3], 0x80);
+        assert!(color("orange").is_err());
+        assert_eq!(super::to_hex(Color::from_rgb8(0x1e, 0x1e, 0x1e)), "#1e1e1e");
+    }
+}
@@ -0,0 +1,367 @@
+//! A skin that styles every widget from one set of design tokens
+//!
+//! A [`Skin`] implements the `StyleSheet` of every widget, so a custom look
+//! is defined once and passed to the `class` builder of each widget:
+//!
+//! ```
+//! use iced_audio::{style::skin::Skin, Knob, Normal, NormalParam};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Changed(Normal),
+//! }
+//!
+//! let skin = Skin {
+//!     border_radius: 4.0,
+//!     ..Skin::dark()
+//! };
+//!
+//! let knob: Knob<'_, Message, iced::Theme> =
+//!     Knob::new(NormalParam::default(), Message::Changed).class(skin);
+//! ```
+//!
+//! [`Skin`]: struct.Skin.html
+
+use crate::style::{
+    arc_slider, compressor_curve, h_slider, knob, labeled, loudness_meter, meter_bridge,
+    mod_range_input, palette::Palette, ramp, reduction_meter, spectrogram, stereo_slider, v_slider,
+    xy_pad, Status,
+};
+use iced::Theme;
+
+/// The design tokens of a custom look for all widgets.
+///
+/// The colors come from the [`palette`], and the widths and radii replace
+/// those of the default styles. Everything else, e.g. the size of handles,
+/// is the same as in the default styles.
+///
+/// With the `serde` feature, a [`Skin`] can be loaded from a style file, and
+/// missing tokens are those of [`Skin::light`]. Only the tokens are loaded;
+/// the `Appearance` of each widget is always derived from them.
+///
+/// [`Skin`]: struct.Skin.html
+/// [`Skin::light`]: #method.light
+///
+/// [`palette`]: #structfield.palette
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Skin {
+    /// The colors of the widgets
+    pub palette: Palette,
+    /// The width of the borders around handles, knobs, and backgrounds
+    pub border_width: f32,
+    /// The radius of the corners of handles and backgrounds
+    pub border_radius: f32,
+    /// The width of the lines that show a value, e.g. the rails of an
+    /// [`XYPad`], the line of a [`Ramp`], and the curve of a
+    /// [`CompressorCurve`]
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    /// [`CompressorCurve`]: ../../native/compressor_curve/struct.CompressorCurve.html
+    pub line_width: f32,
+}
+
+impl Skin {
+    /// Creates a [`Skin`] with the colors of the `palette` and the widths and
+    /// radii of the default styles.
+    ///
+    /// [`Skin`]: struct.Skin.html
+    pub fn new(palette: Palette) -> Self {
+        Self {
+            palette,
+            border_width: 1.0,
+            border_radius: 2.0,
+            line_width: 2.0,
+        }
+    }
+
+    /// The built-in dark [`Skin`], with the colors of the default styles on
+    /// `Theme::Dark`.
+    ///
+    /// [`Skin`]: struct.Skin.html
+    pub fn dark() -> Self {
+        Self::new(Palette::from_theme(&Theme::Dark))
+    }
+
+    /// The built-in light [`Skin`], with the colors of the default styles on
+    /// `Theme::Light`.
+    ///
+    /// [`Skin`]: struct.Skin.html
+    pub fn light() -> Self {
+        Self::new(Palette::from_theme(&Theme::Light))
+    }
+}
+
+impl Default for Skin {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl arc_slider::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> arc_slider::Appearance {
+        arc_slider::Appearance {
+            handle_border_width: self.border_width,
+            ..arc_slider::with_palette(&self.palette, status)
+        }
+    }
+}
+
+impl compressor_curve::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> compressor_curve::Appearance {
+        compressor_curve::Appearance {
+            border_width: self.border_width,
+            border_radius: self.border_radius,
+            curve_width: self.line_width,
+            handle_border_width: self.border_width,
+            ..compressor_curve::with_palette(&self.palette, status)
+        }
+    }
+}
+
+impl h_slider::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> h_slider::Appearance {
+        let mut appearance = h_slider::with_palette(&self.palette, status);
+
+        if let h_slider::Appearance::Classic(classic) = &mut appearance {
+            classic.handle.border_width = self.border_width;
+            classic.handle.border_radius = self.border_radius;
+        }
+
+        appearance
+    }
+}
+
+impl knob::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> knob::Appearance {
+        let mut appearance = knob::with_palette(&self.palette, status);
+
+        if let knob::Appearance::Circle(circle) = &mut appearance {
+            circle.border_width = self.border_width;
+        }
+
+        appearance
+    }
+}
+
+impl labeled::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> labeled::Appearance {
+        labeled::with_palette(&self.palette)
+    }
+}
+
+impl loudness_meter::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> loudness_meter::Appearance {
+        loudness_meter::Appearance {
+            border_width: self.border_width,
+            border_radius: self.border_radius,
+            ..loudness_meter::with_palette(&self.palette)
+        }
+    }
+}
+
+impl meter_bridge::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> meter_bridge::Appearance {
+        meter_bridge::Appearance {
+            border_width: self.border_width,
+            border_radius: self.border_radius,
+            ..meter_bridge::with_palette(&self.palette)
+        }
+    }
+}
+
+impl mod_range_input::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> mod_range_input::Appearance {
+        let mut appearance = mod_range_input::with_palette(&self.palette, status);
+
+        if let mod_range_input::Appearance::Circle(circle) = &mut appearance {
+            circle.border_width = self.border_width;
+        }
+
+        appearance
+    }
+}
+
+impl ramp::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> ramp::Appearance {
+        ramp::Appearance {
+            back_border_width: self.border_width,
+            line_width: self.line_width,
+            ..ramp::with_palette(&self.palette, status)
+        }
+    }
+}
+
+impl reduction_meter::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> reduction_meter::Appearance {
+        reduction_meter::Appearance {
+            border_width: self.border_width,
+            border_radius: self.border_radius,
+            ..reduction_meter::with_palette(&self.palette)
+        }
+    }
+}
+
+impl spectrogram::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> spectrogram::Appearance {
+        spectrogram::Appearance {
+            border_width: self.border_width,
+            ..spectrogram::with_palette(&self.palette)
+        }
+    }
+}
+
+impl stereo_slider::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> stereo_slider::Appearance {
+        stereo_slider::Appearance {
+            link_border_width: self.border_width,
+            link_border_radius: self.border_radius,
+            ..stereo_slider::with_palette(&self.palette, status)
+        }
+    }
+}
+
+impl v_slider::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> v_slider::Appearance {
+        let mut appearance = v_slider::with_palette(&self.palette, status);
+
+        if let v_slider::Appearance::Classic(classic) = &mut appearance {
+            classic.handle.border_width = self.border_width;
+            classic.handle.border_radius = self.border_radius;
+        }
+
+        appearance
+    }
+}
+
+impl xy_pad::StyleSheet for Skin {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, status: Status) -> xy_pad::Appearance {
+        let mut appearance = xy_pad::Appearance {
+            rail_width: self.line_width,
+            border_width: self.border_width,
+            ..xy_pad::with_palette(&self.palette, status)
+        };
+
+        if let xy_pad::HandleShape::Circle(circle) = &mut appearance.handle {
+            circle.border_width = self.border_width;
+        }
+
+        appearance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Skin;
+    use crate::style::{knob, reduction_meter, v_slider, Status};
+    use iced::Theme;
+
+    #[test]
+    fn built_in_skins_match_the_default_styles() {
+        for (skin, theme) in [(Skin::light(), Theme::Light), (Skin::dark(), Theme::Dark)] {
+            let (knob::Appearance::Circle(skinned), knob::Appearance::Circle(default)) = (
+                knob::StyleSheet::appearance(&skin, &theme, Status::Hovered),
+                knob::default(&theme, Status::Hovered),
+            ) else {
+                panic!("the default knob style is a circle");
+            };
+
+            assert_eq!(skinned.color, default.color);
+            assert_eq!(skinned.border_color, default.border_color);
+            assert_eq!(skinned.border_width, default.border_width);
+        }
+    }
+
+    #[test]
+    fn tokens_reach_every_widget() {
+        let skin = Skin {
+            border_width: 3.0,
+            border_radius: 5.0,
+            ..Skin::dark()
+        };
+        let theme = Theme::Light;
+
+        let v_slider::Appearance::Classic(classic) =
+            v_slider::StyleSheet::appearance(&skin, &theme, Status::Active)
+        else {
+            panic!("the default slider style is classic");
+        };
+        assert_eq!(classic.handle.border_width, 3.0);
+        assert_eq!(classic.handle.border_radius, 5.0);
+        assert_eq!(classic.handle.color, skin.palette.back);
+
+        let meter = reduction_meter::StyleSheet::appearance(&skin, &theme);
+        assert_eq!(meter.border_radius, 5.0);
+        assert_eq!(meter.back_color, skin.palette.back);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn loads_a_theme_file() {
+        use iced::Color;
+
+        let skin: Skin = serde_json::from_str(
+            r##"{
+                "border_width": 1.5,
+                "palette": {
+                    "back": "#1e1e1e",
+                    "accent": "#ff880080",
+                    "slider_rail": ["#000000", "#ffffff"]
+                }
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(skin.border_width, 1.5);
+        assert_eq!(skin.border_radius, Skin::light().border_radius);
+        assert_eq!(skin.palette.back, Color::from_rgb8(0x1e, 0x1e, 0x1e));
+        assert_eq!(
+            skin.palette.accent,
+            Color::from_rgba8(0xff, 0x88, 0x00, 0x80 as f32 / 255.0)
+        );
+        assert_eq!(skin.palette.slider_rail, (Color::BLACK, Color::WHITE));
+        assert_eq!(skin.palette.border, Skin::light().palette.border);
+
+        let knob::Appearance::Circle(circle) =
+            knob::StyleSheet::appearance(&skin, &Theme::Dark, Status::Active)
+        else {
+            panic!("the default knob style is a circle");
+        };
+        assert_eq!(circle.border_width, 1.5);
+
+        assert!(serde_json::from_str::<Skin>(r#"{ "palette": { "back": "dark" } }"#).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! Various styles for the [`Spectrogram`] widget
+//!
+//! [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+
+use crate::{
+    core::Normal,
+    style::{
+        palette::Palette,
+        presets::{self, Preset},
+        Dim,
+    },
+};
+use iced::{Color, Theme};
+
+/// The colors of the magnitudes in a [`Spectrogram`]
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Colormap {
+    /// From black, through purple, red and orange, to pale yellow, like the
+    /// "inferno" colormap of matplotlib
+    #[default]
+    Heat,
+    /// From black to white
+    Grayscale,
+    /// The colors evenly spaced from the lowest magnitude (the first color)
+    /// to the highest (the last color), blended in between
+    Gradient(Vec<Color>),
+}
+
+/// The stops of [`Colormap::Heat`].
+///
+/// [`Colormap::Heat`]: enum.Colormap.html#variant.Heat
+const HEAT: [Color; 5] = [
+    Color::from_rgb(0.0, 0.0, 0.016),
+    Color::from_rgb(0.341, 0.063, 0.431),
+    Color::from_rgb(0.737, 0.216, 0.329),
+    Color::from_rgb(0.976, 0.557, 0.035),
+    Color::from_rgb(0.988, 1.0, 0.643),
+];
+
+/// The stops of [`Colormap::Grayscale`].
+///
+/// [`Colormap::Grayscale`]: enum.Colormap.html#variant.Grayscale
+const GRAYSCALE: [Color; 2] = [Color::BLACK, Color::WHITE];
+
+impl Colormap {
+    /// Returns the color of a magnitude.
+    pub fn color(&self, magnitude: Normal) -> Color {
+        let stops = match self {
+            Colormap::Heat => &HEAT[..],
+            Colormap::Grayscale => &GRAYSCALE[..],
+            Colormap::Gradient(colors) => &colors[..],
+        };
+
+        match stops {
+            [] => Color::TRANSPARENT,
+            [color] => *color,
+            _ => {
+                let position = magnitude.as_f32() * (stops.len() - 1) as f32;
+                let index = (position.floor() as usize).min(stops.len() - 2);
+                let t = position - index as f32;
+
+                let (from, to) = (stops[index], stops[index + 1]);
+
+                Color {
+                    r: from.r + (to.r - from.r) * t,
+                    g: from.g + (to.g - from.g) * t,
+                    b: from.b + (to.b - from.b) * t,
+                    a: from.a + (to.a - from.a) * t,
+                }
+            }
+        }
+    }
+}
+
+/// The appearance of a [`Spectrogram`]
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+#[derive(Debug, Clone)]
+pub struct Appearance {
+    /// The colors of the magnitudes
+    pub colormap: Colormap,
+    /// The color of the background, shown until the columns fill the width
+    pub back_color: Color,
+    /// The width of the border
+    pub border_width: f32,
+    /// The color of the border
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of a [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
+
+/// A styling function for a [`Spectrogram`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Appearance + 'a>;
+
+/// The theme catalog of a [`Spectrogram`].
+///
+/// Like the meters, its style does not depend on a [`Status`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+/// [`Status`]: ../enum.Status.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>) -> Appearance;
+}
+
+/// The class of a [`Spectrogram`] for the built-in `Theme`.
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+#[derive(Default)]
+pub enum Spectrogram<'a> {
+    /// The default style.
+    #[default]
+    Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl<'a, S> From<S> for Spectrogram<'a>
+where
+    S: 'static + StyleSheet<Style = Theme>,
+{
+    fn from(val: S) -> Self {
+        Spectrogram::Custom(Box::new(val))
+    }
+}
+
+impl<'a> From<StyleFn<'a, Theme>> for Spectrogram<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        Spectrogram::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = Spectrogram<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Spectrogram::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Appearance {
+        match class {
+            Spectrogram::Default => default(self),
+            Spectrogram::Preset(preset) => with_palette(&preset.palette(self)),
+            Spectrogram::Function(style) => style(self),
+            Spectrogram::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+/// The default style of a [`Spectrogram`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme) -> Appearance {
+    with_palette(&presets::global().palette(theme))
+}
+
+/// The default style of a [`Spectrogram`] with the colors of the `palette`
+/// around the [`Heat`] colormap.
+///
+/// [`Spectrogram`]: ../../native/spectrogram/struct.Spectrogram.html
+/// [`Heat`]: enum.Colormap.html#variant.Heat
+pub fn with_palette(palette: &Palette) -> Appearance {
+    Appearance {
+        colormap: Colormap::Heat,
+        back_color: palette.back,
+        border_width: 1.0,
+        border_color: palette.border,
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            back_color: self.back_color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Various styles for the [`StereoSlider`] widget
+//!
+//! The two sliders of a [`StereoSlider`] are drawn with the styles of a
+//! [`VSlider`], so this only styles the channel labels and the link toggle
+//! below them.
+//!
+//! [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+//! [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+
+use crate::style::{
+    palette::Palette,
+    presets::{self, Preset},
+    Dim,
+};
+use iced::{Color, Theme};
+
+pub use crate::style::Status;
+
+/// The appearance of the labels and the link toggle of a [`StereoSlider`]
+///
+/// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+#[derive(Debug, Clone)]
+pub struct Appearance {
+    /// The color of the channel labels
+    pub label_color: Color,
+    /// The size of the channel labels
+    pub label_size: f32,
+    /// The color of the link toggle while the channels are not linked
+    pub link_color: Color,
+    /// The color of the link toggle while the channels are linked
+    pub linked_color: Color,
+    /// The width of the border of the link toggle
+    pub link_border_width: f32,
+    /// The radius of the border of the link toggle
+    pub link_border_radius: f32,
+    /// The color of the border of the link toggle
+    pub link_border_color: Color,
+}
+
+/// A set of rules that dictate the style of a [`StereoSlider`].
+///
+/// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of a [`StereoSlider`] with the given [`Status`] of
+    /// its link toggle.
+    ///
+    /// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+    /// [`Status`]: ../enum.Status.html
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
+}
+
+/// A styling function for a [`StereoSlider`].
+///
+/// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
+
+/// The theme catalog of a [`StereoSlider`].
+///
+/// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class with the given status of the link
+    /// toggle.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+}
+
+/// The class of a [`StereoSlider`] for the built-in `Theme`.
+///
+/// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+#[derive(Default)]
+pub enum StereoSlider<'a> {
+    /// The default style.
+    #[default]
+    Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
+    /// A custom style.
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl<'a, S> From<S> for StereoSlider<'a>
+where
+    S: 'static + StyleSheet<Style = Theme>,
+{
+    fn from(val: S) -> Self {
+        StereoSlider::Custom(Box::new(val))
+    }
+}
+
+impl<'a> From<StyleFn<'a, Theme>> for StereoSlider<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        StereoSlider::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = StereoSlider<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        StereoSlider::Default
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            StereoSlider::Default => default(self, status),
+            StereoSlider::Preset(preset) => with_palette(&preset.palette(self), status),
+            StereoSlider::Function(style) => style(self, status),
+            StereoSlider::Custom(custom) => custom.appearance(self, status),
+        }
+    }
+}
+
+/// The default style of a [`StereoSlider`], with colors derived from the
+/// `theme` and the global [`Preset`].
+///
+/// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    with_palette(&presets::global().palette(theme), status)
+}
+
+/// The default style of a [`StereoSlider`] with the colors of the `palette`.
+///
+/// [`StereoSlider`]: ../../native/stereo_slider/struct.StereoSlider.html
+pub fn with_palette(palette: &Palette, status: Status) -> Appearance {
+    let link_color = match status {
+        Status::Hovered | Status::Focused | Status::Dragged | Status::DropTarget => {
+            palette.back_hover
+        }
+        Status::Active | Status::Disabled => palette.back,
+    };
+
+    Appearance {
+        label_color: palette.text_mark,
+        label_size: 11.0,
+        link_color,
+        linked_color: palette.accent,
+        link_border_width: 1.0,
+        link_border_radius: 2.0,
+        link_border_color: palette.border,
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            label_color: self.label_color.dim(opacity),
+            link_color: self.link_color.dim(opacity),
+            linked_color: self.linked_color.dim(opacity),
+            link_border_color: self.link_border_color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -4,7 +4,7 @@
 use iced::{Color, Font};
 
 use crate::core::Offset;
-use crate::style::default_colors;
+use crate::style::{default_colors, Dim};
 
 /// The alignment of text in text marks.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -62,6 +62,69 @@ impl std::default::Default for Placement {
     }
 }
 
+#[cfg(any(feature = "h_slider", feature = "v_slider"))]
+impl Placement {
+    /// Returns where the text marks with this placement and `appearance` are
+    /// drawn across a bar widget that is `thickness` pixels thick, as the
+    /// distances from its top (or left) edge to where they start and end.
+    ///
+    /// `vertical` is whether the widget is vertical, so that the marks are
+    /// placed across its width.
+    pub(crate) fn span(
+        &self,
+        appearance: &Appearance,
+        thickness: f32,
+        vertical: bool,
+    ) -> (f32, f32) {
+        let length = f32::from(if vertical {
+            appearance.bounds_width
+        } else {
+            appearance.bounds_height
+        });
+        let across = |offset: &Offset| if vertical { offset.x } else { offset.y };
+        let center = thickness / 2.0;
+
+        match self {
+            Placement::BothSides { inside, offset } => {
+                let offset = across(offset);
+
+                if *inside {
+                    (offset, thickness + offset)
+                } else {
+                    (offset - length, thickness + offset + length)
+                }
+            }
+            Placement::LeftOrTop { inside, offset } => {
+                let offset = across(offset);
+
+                if *inside {
+                    (offset, offset + length)
+                } else {
+                    (offset - length, offset)
+                }
+            }
+            Placement::RightOrBottom { inside, offset } => {
+                let offset = across(offset);
+
+                if *inside {
+                    (thickness + offset - length, thickness + offset)
+                } else {
+                    (thickness + offset, thickness + offset + length)
+                }
+            }
+            Placement::Center { align, offset } => {
+                let start = center + across(offset);
+
+                match align {
+                    Align::Start => (start, start + length),
+                    Align::End => (start - length, start),
+                    Align::Center => (start - length / 2.0, start + length / 2.0),
+                }
+            }
+        }
+    }
+}
+
 /// The style of a [`TextMarkGroup`] for a bar meter widget
 ///
 /// [`TextMarkGroup`]: ../../core/text_marks/struct.TextMarkGroup.html
@@ -100,3 +163,12 @@ impl Default for Appearance {
         }
     }
 }
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Image textures with versions for HiDPI displays
+//!
+//! The texture styles, e.g. of an [`HSlider`] handle, take a
+//! [`TextureHandle`], which holds one image for each scale factor it was
+//! drawn for. Widgets draw the version that best matches the scale factor
+//! set with their `scale_factor()` builder, so handles stay sharp on HiDPI
+//! displays:
+//!
+//! ```
+//! use iced::advanced::image::Handle;
+//! use iced_audio::{style::texture::TextureHandle, HSlider, Normal, NormalParam};
+//!
+//! let handle = TextureHandle::new(Handle::from_path("handle.png"))
+//!     .with_scale(2.0, Handle::from_path("handle@2x.png"));
+//!
+//! // e.g. with the result of `iced::window::get_scale_factor`
+//! let h_slider: HSlider<'_, Normal, iced::Theme> =
+//!     HSlider::new(NormalParam::default(), |normal| normal).scale_factor(2.0);
+//! ```
+//!
+//! A widget can't read the scale factor of the window while it draws, so
+//! the app passes it to the widgets when it builds them, and keeps it in its
+//! state to update it whenever the window moves to another display.
+//!
+//! [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+//! [`TextureHandle`]: struct.TextureHandle.html
+
+use iced::advanced::image;
+
+/// An image texture with a version for each scale factor it was drawn
+/// for.
+///
+/// A [`TextureHandle`] can be created from a single [`Handle`], which is
+/// drawn at every scale factor.
+///
+/// [`TextureHandle`]: struct.TextureHandle.html
+/// [`Handle`]: https://docs.rs/iced/0.13/iced/advanced/image/struct.Handle.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureHandle {
+    /// The versions, sorted by scale factor.
+    versions: Vec<(f32, image::Handle)>,
+}
+
+impl TextureHandle {
+    /// Creates a [`TextureHandle`] of an image drawn at a scale factor of
+    /// `1.0`.
+    ///
+    /// [`TextureHandle`]: struct.TextureHandle.html
+    pub fn new(handle: image::Handle) -> Self {
+        Self {
+            versions: vec![(1.0, handle)],
+        }
+    }
+
+    /// Adds a version of the image drawn at `scale`, e.g. `2.0` for an
+    /// image twice as large in each dimension. It replaces any version with
+    /// the same scale.
+    pub fn with_scale(mut self, scale: f32, handle: image::Handle) -> Self {
+        match self
+            .versions
+            .binary_search_by(|(version, _)| version.total_cmp(&scale))
+        {
+            Ok(index) => self.versions[index].1 = handle,
+            Err(index) => self.versions.insert(index, (scale, handle)),
+        }
+        self
+    }
+
+    /// Returns the version for a `scale_factor`: the smallest one that is at
+    /// least as large, so it's never scaled up, or else the largest one.
+    pub fn select(&self, scale_factor: f32) -> &image::Handle {
+        self.versions
+            .iter()
+            .find(|(scale, _)| *scale >= scale_factor)
+            .or(self.versions.last())
+            .map(|(_, handle)| handle)
+            .expect("a texture has at least one version")
+    }
+}
+
+impl From<image::Handle> for TextureHandle {
+    fn from(handle: image::Handle) -> Self {
+        Self::new(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextureHandle;
+    use iced::advanced::image::Handle;
+
+    #[test]
+    fn picks_the_sharpest_version_that_fits() {
+        let [one, two, three] = [1, 2, 3]
+            .map(|size| Handle::from_rgba(size, size, vec![0; (size * size * 4) as usize]));
+        let texture = TextureHandle::new(one.clone())
+            .with_scale(3.0, three.clone())
+            .with_scale(2.0, two.clone());
+
+        assert_eq!(texture.select(1.0), &one);
+        assert_eq!(texture.select(1.5), &two);
+        assert_eq!(texture.select(2.0), &two);
+        assert_eq!(texture.select(4.0), &three);
+        assert_eq!(TextureHandle::from(one.clone()).select(2.0), &one);
+    }
+}
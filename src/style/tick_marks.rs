@@ -4,7 +4,7 @@
 use iced::Color;
 
 use crate::core::Offset;
-use crate::style::default_colors;
+use crate::style::{default_colors, Dim};
 
 /// The placement of tick marks relative to the widget
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -65,6 +65,87 @@ impl std::default::Default for Placement {
     }
 }
 
+#[cfg(any(feature = "h_slider", feature = "v_slider"))]
+impl Placement {
+    /// Returns where the tick marks with this placement and `appearance` are
+    /// drawn across a bar widget that is `thickness` pixels thick, as the
+    /// distances from its top (or left) edge to where they start and end.
+    ///
+    /// `vertical` is whether the widget is vertical, so that the marks are
+    /// placed across its width.
+    pub(crate) fn span(
+        &self,
+        appearance: &Appearance,
+        thickness: f32,
+        vertical: bool,
+    ) -> (f32, f32) {
+        let length = appearance.max_length();
+        let across = |offset: &Offset| if vertical { offset.x } else { offset.y };
+        let center = thickness / 2.0;
+
+        match self {
+            Placement::BothSides { offset, inside } => {
+                let offset = across(offset);
+
+                if *inside {
+                    (offset, thickness + offset)
+                } else {
+                    (offset - length, thickness + offset + length)
+                }
+            }
+            Placement::LeftOrTop { offset, inside } => {
+                let offset = across(offset);
+
+                if *inside {
+                    (offset, offset + length)
+                } else {
+                    (offset - length, offset)
+                }
+            }
+            Placement::RightOrBottom { offset, inside } => {
+                let offset = across(offset);
+
+                if *inside {
+                    (thickness + offset - length, thickness + offset)
+                } else {
+                    (thickness + offset, thickness + offset + length)
+                }
+            }
+            Placement::Center {
+                offset,
+                fill_length,
+            } => {
+                let offset = across(offset);
+
+                if *fill_length {
+                    (offset, thickness + offset)
+                } else {
+                    (
+                        center + offset - length / 2.0,
+                        center + offset + length / 2.0,
+                    )
+                }
+            }
+            Placement::CenterSplit {
+                offset,
+                fill_length,
+                gap,
+            } => {
+                let offset = across(offset);
+
+                if *fill_length {
+                    (offset, thickness + offset)
+                } else {
+                    (
+                        center + offset - gap / 2.0 - length,
+                        center + offset + gap / 2.0 + length,
+                    )
+                }
+            }
+        }
+    }
+}
+
 /// The appearance of a tick mark
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Appearance {
@@ -74,6 +155,52 @@ pub struct Appearance {
     pub tier_2: Shape,
     /// The style of a tier 3 tick mark.
     pub tier_3: Shape,
+    /// How tick marks are thinned out when they are too close together.
+    pub density: Density,
+}
+
+/// How the tier 2 and tier 3 tick marks are thinned out when they are too
+/// close together to tell apart, e.g. on small widgets, so that they do not
+/// smear into a solid line.
+///
+/// The spacing of a tier is the smallest distance in pixels between its
+/// tick marks and the tick marks of the larger tiers.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Density {
+    /// The spacing in pixels below which tier 2 tick marks are thinned
+    /// out. `0.0` never thins them out.
+    pub tier_2_min_spacing: f32,
+    /// The spacing in pixels below which tier 3 tick marks are thinned
+    /// out. `0.0` never thins them out.
+    pub tier_3_min_spacing: f32,
+    /// Whether tick marks fade out while their spacing shrinks from the
+    /// minimum to half of it (true), or are hidden below the minimum
+    /// (false).
+    pub fade: bool,
+}
+
+impl Default for Density {
+    fn default() -> Self {
+        Self {
+            tier_2_min_spacing: 0.0,
+            tier_3_min_spacing: 3.0,
+            fade: true,
+        }
+    }
+}
+
+impl Density {
+    /// Returns how opaque tick marks with a `spacing` in pixels are drawn,
+    /// from `0.0` for hidden to `1.0` for fully opaque.
+    pub fn opacity(&self, min_spacing: f32, spacing: f32) -> f32 {
+        if spacing >= min_spacing {
+            1.0
+        } else if self.fade {
+            ((spacing * 2.0 - min_spacing) / min_spacing).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
 }
 
 /// The shape of a tick mark
@@ -102,6 +229,53 @@ pub enum Shape {
     },
 }
 
+impl Shape {
+    /// Returns this shape with its color multiplied by `opacity`, or no
+    /// shape if `opacity` is zero.
+    pub fn faded(self, opacity: f32) -> Shape {
+        if opacity <= 0.0 {
+            return Shape::None;
+        }
+
+        match self {
+            Shape::None => Shape::None,
+            Shape::Line {
+                length,
+                width,
+                color,
+            } => Shape::Line {
+                length,
+                width,
+                color: Color {
+                    a: color.a * opacity,
+                    ..color
+                },
+            },
+            Shape::Circle { diameter, color } => Shape::Circle {
+                diameter,
+                color: Color {
+                    a: color.a * opacity,
+                    ..color
+                },
+            },
+        }
+    }
+}
+
+impl Appearance {
+    /// Returns the length of the longest tick mark across the widget.
+    pub(crate) fn max_length(&self) -> f32 {
+        [self.tier_1, self.tier_2, self.tier_3]
+            .iter()
+            .map(|shape| match shape {
+                Shape::None => 0.0,
+                Shape::Line { length, .. } => *length,
+                Shape::Circle { diameter, .. } => *diameter,
+            })
+            .fold(0.0, f32::max)
+    }
+}
+
 impl Default for Appearance {
     fn default() -> Self {
         Self {
@@ -120,6 +294,24 @@ impl Default for Appearance {
                 width: 1.0,
                 color: default_colors::TICK_TIER_3,
             },
+            density: Density::default(),
+        }
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            tier_1: self.tier_1.dim(opacity),
+            tier_2: self.tier_2.dim(opacity),
+            tier_3: self.tier_3.dim(opacity),
+            ..self
         }
     }
 }
+
+impl Dim for Shape {
+    fn dim(self, opacity: f32) -> Self {
+        self.faded(opacity)
+    }
+}
@@ -0,0 +1,61 @@
+//! Various styles for the value text that the slider widgets draw at their
+//! handle
+
+use iced::{Color, Font};
+
+use crate::core::Offset;
+use crate::style::{default_colors, Dim};
+
+/// The placement of the value text relative to the handle of a slider
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Placement {
+    /// Centered on the handle.
+    Handle,
+    /// Above the handle of a horizontal slider, or left of the handle of a
+    /// vertical slider.
+    #[default]
+    LeftOrTop,
+    /// Below the handle of a horizontal slider, or right of the handle of a
+    /// vertical slider.
+    RightOrBottom,
+}
+
+/// The style of the value text of a slider
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Appearance {
+    /// The color of the text.
+    pub color: Color,
+    /// The size of the text.
+    pub text_size: f32,
+    /// The font of the text.
+    pub font: Font,
+    /// The placement of the text relative to the handle.
+    pub placement: Placement,
+    /// The offset of the text in pixels.
+    pub offset: Offset,
+    /// Whether the text is only shown while the slider is hovered or
+    /// dragged.
+    pub on_hover_only: bool,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            color: default_colors::TEXT_MARK,
+            text_size: 12.0,
+            font: Default::default(),
+            placement: Placement::default(),
+            offset: Offset::ZERO,
+            on_hover_only: true,
+        }
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Reload a [`Skin`] from a style file while the app runs
+//!
+//! A [`StyleWatcher`] implements the `StyleSheet` of every widget with the
+//! [`Skin`] it last loaded, so designers can tweak a skin file and see the
+//! changes without recompiling. The file is parsed with any serde format,
+//! e.g. JSON, RON, or TOML:
+//!
+//! ```no_run
+//! use iced_audio::style::{skin::Skin, watcher::StyleWatcher};
+//!
+//! // skin.json:
+//! //   { "border_radius": 4.0, "palette": { "accent": "#ff8800" } }
+//! let watcher = StyleWatcher::new("skin.json", Skin::dark(), |text| {
+//!     serde_json::from_str::<Skin>(text)
+//! });
+//!
+//! // On start-up and then on a timer, e.g. from a `time::every`
+//! // subscription:
+//! if let Err(error) = watcher.poll() {
+//!     eprintln!("{error}");
+//! }
+//!
+//! // In the view, e.g. `Knob::new(param, Message::Changed).class(watcher.clone())`
+//! ```
+//!
+//! The file only holds the design tokens of a [`Skin`], i.e. its [`Palette`]
+//! and its widths and radii. The `Appearance` of each widget is derived from
+//! them and is not deserialized itself, since some of them hold textures,
+//! canvas paths, or closures that a style file can't describe.
+//!
+//! [`Skin`]: ../skin/struct.Skin.html
+//! [`Palette`]: ../palette/struct.Palette.html
+//! [`StyleWatcher`]: struct.StyleWatcher.html
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, PoisonError, RwLock},
+    time::SystemTime,
+};
+
+use crate::style::{
+    arc_slider, compressor_curve, h_slider, knob, labeled, loudness_meter, meter_bridge,
+    mod_range_input, ramp, reduction_meter, skin::Skin, spectrogram, stereo_slider, v_slider,
+    xy_pad, Status,
+};
+use iced::Theme;
+
+type Parse = dyn Fn(&str) -> Result<Skin, String> + Send + Sync;
+
+/// A [`Skin`] that is loaded again from its style file when the file
+/// changes.
+///
+/// Clones share the same [`Skin`], so a clone can be passed to the `class`
+/// builder of every widget and all of them change with the file.
+///
+/// [`Skin`]: ../skin/struct.Skin.html
+#[derive(Clone)]
+pub struct StyleWatcher {
+    path: Arc<Path>,
+    parse: Arc<Parse>,
+    state: Arc<RwLock<State>>,
+}
+
+struct State {
+    skin: Skin,
+    modified: Option<SystemTime>,
+}
+
+impl StyleWatcher {
+    /// Creates a [`StyleWatcher`] of the style file at `path`, which is read
+    /// with `parse` on the next [`poll`]. Until a version of the file has
+    /// been loaded, the widgets have the `fallback` skin.
+    ///
+    /// [`StyleWatcher`]: struct.StyleWatcher.html
+    /// [`poll`]: #method.poll
+    pub fn new<E: fmt::Display>(
+        path: impl Into<PathBuf>,
+        fallback: Skin,
+        parse: impl Fn(&str) -> Result<Skin, E> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            path: path.into().into(),
+            parse: Arc::new(move |text| parse(text).map_err(|error| error.to_string())),
+            state: Arc::new(RwLock::new(State {
+                skin: fallback,
+                modified: None,
+            })),
+        }
+    }
+
+    /// Loads the style file again if it was modified since the last call,
+    /// and returns whether the [`Skin`] changed.
+    ///
+    /// When the file can't be read or parsed, the widgets keep the last
+    /// [`Skin`] and the error is returned once for each version of the file.
+    ///
+    /// [`Skin`]: ../skin/struct.Skin.html
+    pub fn poll(&self) -> Result<bool, ReloadError> {
+        let modified = fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(ReloadError::Io)?;
+
+        {
+            let mut state = self.state.write().unwrap_or_else(PoisonError::into_inner);
+
+            if state.modified == Some(modified) {
+                return Ok(false);
+            }
+            state.modified = Some(modified);
+        }
+
+        let text = fs::read_to_string(&self.path).map_err(ReloadError::Io)?;
+        let skin = (self.parse)(&text).map_err(ReloadError::Parse)?;
+
+        self.state
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .skin = skin;
+
+        Ok(true)
+    }
+
+    /// Returns the [`Skin`] that was loaded last.
+    ///
+    /// [`Skin`]: ../skin/struct.Skin.html
+    pub fn skin(&self) -> Skin {
+        self.state
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .skin
+    }
+
+    /// Returns the path of the style file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl fmt::Debug for StyleWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StyleWatcher")
+            .field("path", &self.path)
+            .field("skin", &self.skin())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error while loading a style file with a [`StyleWatcher`].
+///
+/// [`StyleWatcher`]: struct.StyleWatcher.html
+#[derive(Debug)]
+pub enum ReloadError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The file isn't a valid skin, with the message of the parser.
+    Parse(String),
+}
+
+impl fmt::Display for ReloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReloadError::Io(error) => write!(f, "couldn't read the style file: {error}"),
+            ReloadError::Parse(error) => write!(f, "invalid style file: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ReloadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReloadError::Io(error) => Some(error),
+            ReloadError::Parse(_) => None,
+        }
+    }
+}
+
+/// Implements the `StyleSheet` of widgets with the current [`Skin`].
+macro_rules! delegate_to_skin {
+    ($($module:ident),* $(,)?; status: $($status_module:ident),* $(,)?) => {
+        $(
+            impl $module::StyleSheet for StyleWatcher {
+                type Style = Theme;
+
+                fn appearance(&self, style: &Self::Style) -> $module::Appearance {
+                    $module::StyleSheet::appearance(&self.skin(), style)
+                }
+            }
+        )*
+        $(
+            impl $status_module::StyleSheet for StyleWatcher {
+                type Style = Theme;
+
+                fn appearance(
+                    &self,
+                    style: &Self::Style,
+                    status: Status,
+                ) -> $status_module::Appearance {
+                    $status_module::StyleSheet::appearance(&self.skin(), style, status)
+                }
+            }
+        )*
+    };
+}
+
+delegate_to_skin!(
+    labeled, loudness_meter, meter_bridge, reduction_meter, spectrogram;
+    status: arc_slider, compressor_curve, h_slider, knob, mod_range_input, ramp,
+    stereo_slider, v_slider, xy_pad,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{ReloadError, StyleWatcher};
+    use crate::style::{reduction_meter, skin::Skin};
+    use iced::Theme;
+    use std::{
+        fs::{self, File},
+        time::{Duration, SystemTime},
+    };
+
+    #[test]
+    fn swaps_the_skin_when_the_file_changes() {
+        let path = std::env::temp_dir().join(format!("iced_audio_skin_{}", std::process::id()));
+        let write = |text: &str, age: u64| {
+            fs::write(&path, text).unwrap();
+            File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(SystemTime::now() - Duration::from_secs(age))
+                .unwrap();
+        };
+        let watcher = StyleWatcher::new(&path, Skin::dark(), |text| {
+            serde_json::from_str::<Skin>(text)
+        });
+        let border_radius =
+            || reduction_meter::StyleSheet::appearance(&watcher, &Theme::Dark).border_radius;
+
+        assert!(matches!(watcher.poll(), Err(ReloadError::Io(_))));
+        assert_eq!(border_radius(), 2.0);
+
+        write(
+            r##"{ "border_radius": 4.0, "palette": { "accent": "#ff8800" } }"##,
+            20,
+        );
+        let clone = watcher.clone();
+        assert!(watcher.poll().unwrap());
+        assert!(!watcher.poll().unwrap());
+        assert_eq!(border_radius(), 4.0);
+        assert_eq!(clone.skin().border_radius, 4.0);
+        assert_eq!(
+            clone.skin().palette.accent,
+            iced::Color::from_rgb8(0xff, 0x88, 0x00)
+        );
+        // Missing tokens are those of the light skin.
+        assert_eq!(clone.skin().line_width, Skin::light().line_width);
+
+        // A broken file is reported once and keeps the last skin.
+        write(r#"{ "border_radius": "round" }"#, 10);
+        assert!(matches!(watcher.poll(), Err(ReloadError::Parse(_))));
+        assert!(!watcher.poll().unwrap());
+        assert_eq!(border_radius(), 4.0);
+
+        write(r#"{ "border_radius": 6.0 }"#, 0);
+        assert!(watcher.poll().unwrap());
+        assert_eq!(border_radius(), 6.0);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
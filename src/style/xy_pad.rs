@@ -2,8 +2,16 @@
 //!
 //! [`XYPad`]: ../native/xy_pad/struct.XYPad.html
 
-use crate::style::default_colors;
-use iced::Color;
+use crate::style::{
+    default_colors, focus_ring,
+    palette::Palette,
+    presets::{self, Preset},
+    texture::TextureHandle,
+    Dim,
+};
+use iced::{Color, Rectangle, Theme};
+
+pub use crate::style::Status;
 
 /// The appearance of an [`XYPad`].
 ///
@@ -59,6 +67,8 @@ pub enum HandleShape {
     Circle(HandleCircle),
     /// a square handle
     Square(HandleSquare),
+    /// a handle that uses an image texture
+    Texture(HandleTexture),
 }
 
 /// a circular handle style for the [`Style`] of an [`XYPad`]
@@ -106,6 +116,20 @@ pub struct HandleSquare {
     pub border_color: Color,
 }
 
+/// an image texture handle style for the [`Style`] of an [`XYPad`]
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+/// [`Style`]: struct.Style.html
+#[derive(Debug, Clone)]
+pub struct HandleTexture {
+    /// The image texture, with a version for each scale factor of the
+    /// display it was drawn for
+    pub image_handle: TextureHandle,
+    /// the bounds of the image texture, where the origin is in the center
+    /// of the handle
+    pub image_bounds: Rectangle,
+}
+
 /// A set of rules that dictate the style of an [`XYPad`].
 ///
 /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
@@ -113,75 +137,227 @@ pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
     type Style: Default;
 
-    /// Produces the style of an active [`XYPad`].
+    /// Produces the style of an [`XYPad`] with the given [`Status`].
     ///
     /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
-    fn active(&self, style: &Self::Style) -> Appearance;
+    /// [`Status`]: ../enum.Status.html
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
 
-    /// Produces the style of a hovered [`XYPad`].
+    /// The style of the ring drawn around an [`XYPad`] that was focused
+    /// with the keyboard.
+    ///
+    /// The default is [`focus_ring::Appearance::default()`]. For no focus
+    /// ring, override this to return `None`.
     ///
     /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
-    fn hovered(&self, style: &Self::Style) -> Appearance;
+    /// [`focus_ring::Appearance::default()`]: ../focus_ring/struct.Appearance.html
+    fn focus_ring_appearance(&self, _style: &Self::Style) -> Option<focus_ring::Appearance> {
+        Some(focus_ring::Appearance::default())
+    }
+}
+
+/// A styling function for an [`XYPad`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Appearance + 'a>;
 
-    /// Produces the style of an [`XYPad`] that is being dragged.
+/// The theme catalog of an [`XYPad`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
     ///
-    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
-    fn dragging(&self, style: &Self::Style) -> Appearance;
+    /// [`Catalog`]: trait.Catalog.html
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    ///
+    /// [`Catalog`]: trait.Catalog.html
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Appearance`] of a class with the given status.
+    ///
+    /// [`Appearance`]: struct.Appearance.html
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance;
+
+    /// The style of the focus ring of a class.
+    fn focus_ring_appearance(&self, _class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        None
+    }
 }
 
-/// The style of a XYPad.
+/// The class of an [`XYPad`] for the built-in `Theme`.
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
 #[derive(Default)]
-pub enum XYPad {
+pub enum XYPad<'a> {
     /// The default style.
     #[default]
     Default,
+    /// The default style with the colors of a [`Preset`] instead of the
+    /// global one.
+    ///
+    /// [`Preset`]: ../presets/enum.Preset.html
+    Preset(Preset),
+    /// A style produced by a function.
+    Function(StyleFn<'a, Theme>),
     /// A custom style.
-    Custom(Box<dyn StyleSheet<Style = iced::Theme>>),
+    Custom(Box<dyn StyleSheet<Style = Theme>>),
 }
 
-impl<S> From<S> for XYPad
+impl<'a, S> From<S> for XYPad<'a>
 where
-    S: 'static + StyleSheet<Style = iced::Theme>,
+    S: 'static + StyleSheet<Style = Theme>,
 {
     fn from(val: S) -> Self {
         XYPad::Custom(Box::new(val))
     }
 }
 
-impl StyleSheet for iced::Theme {
-    type Style = XYPad;
+impl<'a> From<StyleFn<'a, Theme>> for XYPad<'a> {
+    fn from(style: StyleFn<'a, Theme>) -> Self {
+        XYPad::Function(style)
+    }
+}
+
+impl Catalog for Theme {
+    type Class<'a> = XYPad<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        XYPad::Default
+    }
 
-    fn active(&self, style: &Self::Style) -> Appearance {
-        match style {
-            XYPad::Default => Default::default(),
-            XYPad::Custom(custom) => custom.active(self),
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Appearance {
+        match class {
+            XYPad::Default => default(self, status),
+            XYPad::Preset(preset) => with_palette(&preset.palette(self), status),
+            XYPad::Function(style) => style(self, status),
+            XYPad::Custom(custom) => custom.appearance(self, status),
         }
     }
 
-    fn hovered(&self, style: &Self::Style) -> Appearance {
-        match style {
-            XYPad::Default => Appearance {
-                handle: HandleShape::Circle(HandleCircle {
-                    color: default_colors::LIGHT_BACK_HOVER,
-                    ..Default::default()
-                }),
-                ..Default::default()
-            },
-            XYPad::Custom(custom) => custom.hovered(self),
+    fn focus_ring_appearance(&self, class: &Self::Class<'_>) -> Option<focus_ring::Appearance> {
+        match class {
+            XYPad::Custom(custom) => custom.focus_ring_appearance(self),
+            _ => Some(focus_ring::with_palette(&palette(self, class))),
         }
     }
+}
+
+/// The default style of an [`XYPad`], with colors derived from the `theme` and
+/// the global [`Preset`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    with_palette(&presets::global().palette(theme), status)
+}
+
+/// The default style of an [`XYPad`] with the colors of the `palette`.
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+pub fn with_palette(palette: &Palette, status: Status) -> Appearance {
+    match status {
+        Status::Active | Status::Disabled => default_appearance(palette, palette.back, 11.0),
+        Status::Hovered | Status::Focused | Status::DropTarget => {
+            default_appearance(palette, palette.back_hover, 11.0)
+        }
+        Status::Dragged => default_appearance(palette, palette.back_drag, 9.0),
+    }
+}
+
+/// A style of an [`XYPad`] with a square handle, with colors derived from
+/// the `theme` and the global [`Preset`].
+///
+/// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+/// [`Preset`]: ../presets/enum.Preset.html
+pub fn square_handle(theme: &Theme, status: Status) -> Appearance {
+    let appearance = default(theme, status);
+
+    let HandleShape::Circle(circle) = appearance.handle else {
+        return appearance;
+    };
+
+    Appearance {
+        handle: HandleShape::Square(HandleSquare {
+            color: circle.color,
+            size: circle.diameter as u16,
+            border_width: circle.border_width,
+            border_radius: 2.0,
+            border_color: circle.border_color,
+        }),
+        ..appearance
+    }
+}
+
+/// The default appearance with the colors of the `palette`.
+fn default_appearance(palette: &Palette, handle_color: Color, handle_diameter: f32) -> Appearance {
+    Appearance {
+        h_rail_color: palette.xy_pad_rail,
+        v_rail_color: palette.xy_pad_rail,
+        handle: HandleShape::Circle(HandleCircle {
+            color: handle_color,
+            diameter: handle_diameter,
+            border_color: palette.border,
+            ..Default::default()
+        }),
+        back_color: palette.back,
+        border_color: palette.border,
+        center_line_color: palette.xy_pad_center_line,
+        ..Default::default()
+    }
+}
+
+/// The palette of a class: the one of its [`Preset`], or the global one.
+///
+/// [`Preset`]: ../presets/enum.Preset.html
+fn palette(theme: &Theme, class: &XYPad<'_>) -> Palette {
+    match class {
+        XYPad::Preset(preset) => preset.palette(theme),
+        _ => presets::global().palette(theme),
+    }
+}
+
+impl Dim for Appearance {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            h_rail_color: self.h_rail_color.dim(opacity),
+            v_rail_color: self.v_rail_color.dim(opacity),
+            handle: self.handle.dim(opacity),
+            back_color: self.back_color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            center_line_color: self.center_line_color.dim(opacity),
+            ..self
+        }
+    }
+}
+
+impl Dim for HandleShape {
+    fn dim(self, opacity: f32) -> Self {
+        match self {
+            HandleShape::Circle(circle) => HandleShape::Circle(circle.dim(opacity)),
+            HandleShape::Square(square) => HandleShape::Square(square.dim(opacity)),
+            other => other,
+        }
+    }
+}
+
+impl Dim for HandleCircle {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            ..self
+        }
+    }
+}
 
-    fn dragging(&self, style: &Self::Style) -> Appearance {
-        match style {
-            XYPad::Default => Appearance {
-                handle: HandleShape::Circle(HandleCircle {
-                    color: default_colors::LIGHT_BACK_DRAG,
-                    diameter: 9.0,
-                    ..Default::default()
-                }),
-                ..Default::default()
-            },
-            XYPad::Custom(custom) => custom.dragging(self),
+impl Dim for HandleSquare {
+    fn dim(self, opacity: f32) -> Self {
+        Self {
+            color: self.color.dim(opacity),
+            border_color: self.border_color.dim(opacity),
+            ..self
         }
     }
 }
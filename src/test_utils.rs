@@ -0,0 +1,660 @@
+//! Utilities for testing widgets without a window.
+//!
+//! A [`Harness`] holds a single widget together with its state, lays it out
+//! at a given size, and lets tests feed it mouse, touch, and keyboard events
+//! while collecting the messages it produces.
+//!
+//! A widget can also be drawn offscreen into a [`Snapshot`], which can be
+//! compared against a golden PNG file to catch visual regressions, or saved
+//! to build a gallery of styles.
+//!
+//! [`Harness`]: struct.Harness.html
+//! [`Snapshot`]: struct.Snapshot.html
+
+use std::{io, path::Path};
+
+use iced::{
+    advanced::{
+        clipboard, layout, renderer,
+        widget::{
+            operation::{self, Outcome},
+            Operation, Tree,
+        },
+        Layout, Shell,
+    },
+    event, keyboard, mouse,
+    time::Instant,
+    window, Color, Element, Event, Font, Pixels, Point, Rectangle, Size,
+};
+use iced_tiny_skia::graphics::Viewport;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+/// The environment variable that makes [`Snapshot::assert_golden()`]
+/// overwrite the golden files instead of comparing against them.
+///
+/// [`Snapshot::assert_golden()`]: struct.Snapshot.html#method.assert_golden
+pub const UPDATE_SNAPSHOTS_VAR: &str = "ICED_AUDIO_UPDATE_SNAPSHOTS";
+
+/// A renderer that can be created without a window.
+trait Headless: renderer::Renderer {
+    fn headless() -> Self;
+
+    fn tiny_skia(&mut self) -> &mut iced_tiny_skia::Renderer;
+}
+
+impl Headless for iced_tiny_skia::Renderer {
+    fn headless() -> Self {
+        iced_tiny_skia::Renderer::new(Font::DEFAULT, Pixels(16.0))
+    }
+
+    fn tiny_skia(&mut self) -> &mut iced_tiny_skia::Renderer {
+        self
+    }
+}
+
+impl<A> Headless for iced_renderer::fallback::Renderer<A, iced_tiny_skia::Renderer>
+where
+    A: renderer::Renderer,
+{
+    fn headless() -> Self {
+        Self::Secondary(iced_tiny_skia::Renderer::headless())
+    }
+
+    fn tiny_skia(&mut self) -> &mut iced_tiny_skia::Renderer {
+        match self {
+            Self::Primary(_) => unreachable!("headless renderers always use tiny-skia"),
+            Self::Secondary(renderer) => renderer,
+        }
+    }
+}
+
+/// Creates a [`Renderer`] that can be used without a window.
+///
+/// [`Renderer`]: https://docs.rs/iced/latest/iced/type.Renderer.html
+#[allow(private_bounds)]
+pub fn headless_renderer() -> iced::Renderer
+where
+    iced::Renderer: Headless,
+{
+    iced::Renderer::headless()
+}
+
+/// Holds a widget and its state, and feeds it events.
+///
+/// The widget is drawn with the default [`Renderer`] of iced unless another
+/// one is given, e.g. `iced_tiny_skia::Renderer`.
+///
+/// [`Renderer`]: https://docs.rs/iced/latest/iced/type.Renderer.html
+#[allow(missing_debug_implementations)]
+pub struct Harness<'a, Message, Theme, Renderer = iced::Renderer> {
+    element: Element<'a, Message, Theme, Renderer>,
+    tree: Tree,
+    node: layout::Node,
+    size: Size,
+    renderer: Renderer,
+    cursor: mouse::Cursor,
+    messages: Vec<Message>,
+}
+
+#[allow(private_bounds)]
+impl<'a, Message, Theme> Harness<'a, Message, Theme>
+where
+    iced::Renderer: Headless,
+{
+    /// Creates a new [`Harness`] and lays out the widget with the given
+    /// maximum `size` at the origin.
+    ///
+    /// [`Harness`]: struct.Harness.html
+    pub fn new(widget: impl Into<Element<'a, Message, Theme>>, size: Size) -> Self {
+        Self::with_renderer(widget, size, headless_renderer())
+    }
+}
+
+#[allow(private_bounds)]
+impl<'a, Message, Theme, Renderer> Harness<'a, Message, Theme, Renderer>
+where
+    Renderer: Headless,
+{
+    /// Creates a new [`Harness`] that draws the widget with the given
+    /// headless `renderer`, and lays out the widget with the given maximum
+    /// `size` at the origin.
+    ///
+    /// [`Harness`]: struct.Harness.html
+    pub fn with_renderer(
+        widget: impl Into<Element<'a, Message, Theme, Renderer>>,
+        size: Size,
+        renderer: Renderer,
+    ) -> Self {
+        let element = widget.into();
+        let mut tree = Tree::new(element.as_widget());
+
+        let node = element.as_widget().layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, size),
+        );
+
+        Self {
+            element,
+            tree,
+            node,
+            size,
+            renderer,
+            cursor: mouse::Cursor::Unavailable,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Replaces the widget while keeping its state, like a new `view`
+    /// would.
+    pub fn rebuild(&mut self, widget: impl Into<Element<'a, Message, Theme, Renderer>>) {
+        self.element = widget.into();
+        self.tree.diff(self.element.as_widget());
+    }
+
+    /// The bounds of the widget.
+    pub fn bounds(&self) -> Rectangle {
+        self.node.bounds()
+    }
+
+    /// The layout of the widget, e.g. to find the bounds of its children.
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.node)
+    }
+
+    /// The center of the widget.
+    pub fn center(&self) -> Point {
+        self.bounds().center()
+    }
+
+    /// Sends an event to the widget, returning whether it was captured.
+    ///
+    /// Like on the web, touch events do not move the mouse cursor.
+    pub fn event(&mut self, event: Event) -> event::Status {
+        if let Event::Mouse(mouse::Event::CursorMoved { position }) = event {
+            self.cursor = mouse::Cursor::Available(position);
+        }
+
+        self.dispatch(event).0
+    }
+
+    /// Sends the `RedrawRequested` event of the frame drawn at `now`,
+    /// returning the redraw the widget requested in turn, if any.
+    pub fn redraw(&mut self, now: Instant) -> Option<window::RedrawRequest> {
+        self.dispatch(Event::Window(window::Event::RedrawRequested(now)))
+            .1
+    }
+
+    fn dispatch(&mut self, event: Event) -> (event::Status, Option<window::RedrawRequest>) {
+        let mut shell = Shell::new(&mut self.messages);
+
+        let status = self.element.as_widget_mut().on_event(
+            &mut self.tree,
+            event,
+            Layout::new(&self.node),
+            self.cursor,
+            &self.renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &self.node.bounds(),
+        );
+
+        // Lay the widget out again when it asks for it, like the runtime.
+        let is_layout_invalid = shell.is_layout_invalid();
+        let redraw_request = shell.redraw_request();
+
+        if is_layout_invalid {
+            self.node = self.element.as_widget().layout(
+                &mut self.tree,
+                &self.renderer,
+                &layout::Limits::new(Size::ZERO, self.size),
+            );
+        }
+
+        (status, redraw_request)
+    }
+
+    /// Moves the mouse cursor to `position`.
+    pub fn move_cursor(&mut self, position: Point) -> event::Status {
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Presses the left mouse button.
+    pub fn press(&mut self) -> event::Status {
+        self.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )))
+    }
+
+    /// Releases the left mouse button.
+    pub fn release(&mut self) -> event::Status {
+        self.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )))
+    }
+
+    /// Moves the cursor to `from`, presses the left mouse button, moves the
+    /// cursor to `to`, and releases the button again.
+    pub fn drag(&mut self, from: Point, to: Point) {
+        let _ = self.move_cursor(from);
+        let _ = self.press();
+        let _ = self.move_cursor(to);
+        let _ = self.release();
+    }
+
+    /// Scrolls the mouse wheel by `lines`.
+    pub fn scroll(&mut self, lines: f32) -> event::Status {
+        self.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y: lines },
+        }))
+    }
+
+    /// Scrolls by `pixels`, like a touchpad does.
+    pub fn scroll_pixels(&mut self, pixels: f32) -> event::Status {
+        self.event(Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x: 0.0, y: pixels },
+        }))
+    }
+
+    /// Changes the pressed modifier keys.
+    pub fn modifiers(&mut self, modifiers: keyboard::Modifiers) -> event::Status {
+        self.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            modifiers,
+        )))
+    }
+
+    /// Runs an [`Operation`] on the widget, returning its result.
+    ///
+    /// Operations that chain into another one, like `focus_next`, are run
+    /// until the last one finishes, like the iced runtime does.
+    ///
+    /// [`Operation`]: https://docs.rs/iced/0.13/iced/advanced/widget/trait.Operation.html
+    pub fn operate<T>(&mut self, mut operation: impl Operation<T>) -> Option<T> {
+        let mut outcome = self.run_operation(&mut operation);
+
+        while let Outcome::Chain(mut next) = outcome {
+            outcome = self.run_operation(&mut next);
+        }
+
+        match outcome {
+            Outcome::Some(result) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Runs a single pass of an [`Operation`] on the widget.
+    ///
+    /// [`Operation`]: https://docs.rs/iced/0.13/iced/advanced/widget/trait.Operation.html
+    fn run_operation<T>(&mut self, operation: &mut impl Operation<T>) -> Outcome<T> {
+        self.element.as_widget().operate(
+            &mut self.tree,
+            Layout::new(&self.node),
+            &self.renderer,
+            &mut operation::black_box(operation),
+        );
+
+        operation.finish()
+    }
+
+    /// Returns the messages produced so far, and clears them.
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Draws the widget in its current state into the renderer, without
+    /// turning it into pixels, e.g. to measure how long drawing takes.
+    pub fn draw(&mut self, theme: &Theme) {
+        let bounds = self.bounds();
+
+        self.renderer.clear();
+
+        self.element.as_widget().draw(
+            &self.tree,
+            &mut self.renderer,
+            theme,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Layout::new(&self.node),
+            self.cursor,
+            &bounds,
+        );
+    }
+
+    /// Draws the widget in its current state into a [`Snapshot`].
+    ///
+    /// The widget is drawn on a transparent background at a scale factor of
+    /// `1.0`, with the cursor at its last known position.
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn snapshot(&mut self, theme: &Theme) -> Snapshot {
+        let bounds = self.bounds();
+        let width = (bounds.x + bounds.width).ceil().max(1.0) as u32;
+        let height = (bounds.y + bounds.height).ceil().max(1.0) as u32;
+
+        self.draw(theme);
+
+        let mut pixmap = tiny_skia::Pixmap::new(width, height).expect("Create snapshot pixmap");
+        let mut clip_mask = tiny_skia::Mask::new(width, height).expect("Create clip mask");
+
+        self.renderer.tiny_skia().draw(
+            &mut pixmap.as_mut(),
+            &mut clip_mask,
+            &Viewport::with_physical_size(Size::new(width, height), 1.0),
+            &[Rectangle::with_size(Size::new(width as f32, height as f32))],
+            Color::TRANSPARENT,
+            &[] as &[&str],
+        );
+
+        // The renderer produces BGRA pixels for the window surface
+        for pixel in pixmap.pixels_mut() {
+            *pixel = tiny_skia::PremultipliedColorU8::from_rgba(
+                pixel.blue(),
+                pixel.green(),
+                pixel.red(),
+                pixel.alpha(),
+            )
+            .expect("Swap color channels");
+        }
+
+        Snapshot { pixmap }
+    }
+}
+
+/// An image of a widget drawn by a [`Harness`].
+///
+/// [`Harness`]: struct.Harness.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pixmap: tiny_skia::Pixmap,
+}
+
+impl Snapshot {
+    /// The width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        self.pixmap.width()
+    }
+
+    /// The height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        self.pixmap.height()
+    }
+
+    /// Returns the color of the pixel at `x`, `y`, or `None` if it is out of
+    /// bounds.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+
+        let color = self.pixmap.pixel(x, y)?.demultiply();
+
+        Some(Color::from_rgba8(
+            color.red(),
+            color.green(),
+            color.blue(),
+            f32::from(color.alpha()) / 255.0,
+        ))
+    }
+
+    /// Decodes a PNG image.
+    pub fn from_png(data: &[u8]) -> io::Result<Self> {
+        let pixmap = tiny_skia::Pixmap::decode_png(data).map_err(io::Error::other)?;
+
+        Ok(Self { pixmap })
+    }
+
+    /// Encodes the image as a PNG.
+    pub fn to_png(&self) -> io::Result<Vec<u8>> {
+        self.pixmap.encode_png().map_err(io::Error::other)
+    }
+
+    /// Loads a PNG image from `path`.
+    pub fn load_png(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_png(&std::fs::read(path)?)
+    }
+
+    /// Saves the image as a PNG to `path`, creating any missing parent
+    /// directories.
+    pub fn save_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, self.to_png()?)
+    }
+
+    /// Returns the number of pixels that differ from `other` by more than
+    /// `tolerance` in any channel, or `None` if the images have different
+    /// sizes.
+    pub fn count_differences(&self, other: &Snapshot, tolerance: u8) -> Option<usize> {
+        if self.width() != other.width() || self.height() != other.height() {
+            return None;
+        }
+
+        Some(
+            self.pixmap
+                .pixels()
+                .iter()
+                .zip(other.pixmap.pixels())
+                .filter(|(a, b)| {
+                    a.red().abs_diff(b.red()) > tolerance
+                        || a.green().abs_diff(b.green()) > tolerance
+                        || a.blue().abs_diff(b.blue()) > tolerance
+                        || a.alpha().abs_diff(b.alpha()) > tolerance
+                })
+                .count(),
+        )
+    }
+
+    /// Compares the image against the golden PNG file at `path`.
+    ///
+    /// If the file does not exist yet, or the [`UPDATE_SNAPSHOTS_VAR`]
+    /// environment variable is set, the image is saved to `path` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the golden file can't be read or written, or if it differs
+    /// from this image. Small differences of up to two levels per channel
+    /// are tolerated, so that anti-aliasing does not cause spurious failures.
+    ///
+    /// [`UPDATE_SNAPSHOTS_VAR`]: constant.UPDATE_SNAPSHOTS_VAR.html
+    #[track_caller]
+    pub fn assert_golden(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+
+        if std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() || !path.exists() {
+            if let Err(error) = self.save_png(path) {
+                panic!("failed to save snapshot {}: {error}", path.display());
+            }
+
+            return;
+        }
+
+        let golden = match Snapshot::load_png(path) {
+            Ok(golden) => golden,
+            Err(error) => panic!("failed to load snapshot {}: {error}", path.display()),
+        };
+
+        match self.count_differences(&golden, 2) {
+            Some(0) => {}
+            Some(count) => panic!(
+                "{count} pixels differ from snapshot {} (set {UPDATE_SNAPSHOTS_VAR} to update it)",
+                path.display()
+            ),
+            None => panic!(
+                "snapshot {} is {}x{}, but the widget was drawn at {}x{}",
+                path.display(),
+                golden.width(),
+                golden.height(),
+                self.width(),
+                self.height()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::{
+        assert_close, changed, h_slider_snapshot, knob, knob_snapshot, param, reduction_meter,
+        snapshot_path, Message, BLUE, SIZE,
+    };
+    use super::{Harness, Snapshot};
+    use crate::{operation, HSlider, Knob, Normal, SliderDirection};
+    use iced::{
+        advanced::widget::Id,
+        time::{Duration, Instant},
+        widget::{column, scrollable, Space},
+        Color, Font, Length, Pixels, Point, Theme, Vector,
+    };
+
+    #[test]
+    fn handle_position_operation() {
+        let content = column![
+            knob()
+                .id(Id::new("knob"))
+                .width(Length::Fixed(50.0))
+                .height(Length::Fixed(50.0)),
+            Space::with_height(100.0),
+            HSlider::new(param(0.3, 0.5), Message::Changed)
+                .id(Id::new("h_slider"))
+                .direction(SliderDirection::Inverted)
+                .width(Length::Fixed(100.0))
+                .height(Length::Fixed(20.0)),
+            Space::with_height(300.0),
+        ];
+        let mut harness = Harness::new(scrollable(content), SIZE);
+        let position = |harness: &mut Harness<'_, Message, Theme>, id| {
+            harness.operate(operation::handle_position(Id::new(id)))
+        };
+
+        assert_eq!(
+            position(&mut harness, "knob"),
+            Some(Some(Point::new(25.0, 25.0)))
+        );
+        let slider = position(&mut harness, "h_slider").flatten().unwrap();
+        assert_close(slider.x, 70.0);
+        assert_close(slider.y, 160.0);
+        assert_eq!(position(&mut harness, "other"), Some(None));
+
+        // The positions are in window coordinates, so they follow the
+        // scrollable.
+        let _ = harness.move_cursor(Point::new(80.0, 1.0));
+        let _ = harness.scroll_pixels(-40.0);
+
+        let slider = position(&mut harness, "h_slider").flatten().unwrap();
+        assert_close(slider.x, 70.0);
+        assert_close(slider.y, 120.0);
+    }
+
+    #[test]
+    fn reduced_motion_skips_animations() {
+        let start = Instant::now();
+        let slider = HSlider::new(param(0.5, 0.5), Message::Changed)
+            .spring_back(Duration::from_millis(100))
+            .reduced_motion(true)
+            .on_release(|| Some(Message::Released));
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(slider, SIZE);
+
+        // The value is back at the default on the first frame.
+        let center = harness.center();
+        harness.drag(center, center + Vector::new(50.0, 0.0));
+        let _ = harness.take_messages();
+        assert_eq!(harness.redraw(start), None);
+        assert_eq!(
+            harness.take_messages(),
+            vec![
+                Message::Changed(Normal::from_clipped(0.5)),
+                Message::Released
+            ]
+        );
+
+        // The meter shows the whole reduction without asking for more frames.
+        let mut harness: Harness<'_, Message, Theme> =
+            Harness::new(reduction_meter(0.0).reduced_motion(true), SIZE);
+        let _ = harness.redraw(start);
+        harness.rebuild(reduction_meter(1.0).reduced_motion(true));
+        assert_eq!(harness.redraw(start + Duration::from_millis(10)), None);
+        let snapshot = harness.snapshot(&Theme::Light);
+        assert_eq!(
+            snapshot.pixel(5, 99).map(Color::into_rgba8),
+            Some(BLUE.into_rgba8())
+        );
+    }
+
+    #[test]
+    fn dark_theme_snapshots() {
+        let knob: Knob<'_, _, Theme> =
+            Knob::new(param(0.3, 0.5), Message::Changed).size(Length::Fixed(40.0));
+        Harness::new(knob, SIZE)
+            .snapshot(&Theme::Dark)
+            .assert_golden(snapshot_path("knob_circle_dark"));
+
+        let h_slider: HSlider<'_, _, Theme> = HSlider::new(param(0.3, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(14.0));
+        Harness::new(h_slider, SIZE)
+            .snapshot(&Theme::Dark)
+            .assert_golden(snapshot_path("h_slider_classic_dark"));
+    }
+
+    #[test]
+    fn snapshot_size_and_png() {
+        let snapshot = knob_snapshot(None);
+        assert_eq!((snapshot.width(), snapshot.height()), (40, 40));
+
+        // The corners are outside of the knob, its center is not.
+        assert_eq!(snapshot.pixel(0, 0).map(|color| color.a), Some(0.0));
+        assert_eq!(snapshot.pixel(20, 20).map(|color| color.a), Some(1.0));
+        assert_eq!(snapshot.pixel(40, 0), None);
+
+        let decoded = Snapshot::from_png(&snapshot.to_png().unwrap()).unwrap();
+        assert_eq!(snapshot.count_differences(&decoded, 0), Some(0));
+        assert_eq!(
+            snapshot.count_differences(&h_slider_snapshot(None), 0),
+            None
+        );
+    }
+
+    #[test]
+    fn snapshot_follows_state() {
+        let mut harness = Harness::new(
+            Knob::<_, Theme>::new(param(0.3, 0.5), Message::Changed).size(Length::Fixed(40.0)),
+            SIZE,
+        );
+
+        let active = harness.snapshot(&Theme::Light);
+        let _ = harness.move_cursor(harness.center());
+        let hovered = harness.snapshot(&Theme::Light);
+
+        assert_ne!(hovered.count_differences(&active, 0), Some(0));
+        assert_eq!(
+            hovered.count_differences(&harness.snapshot(&Theme::Light), 0),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn other_renderer() {
+        let renderer = || iced_tiny_skia::Renderer::new(Font::DEFAULT, Pixels(16.0));
+
+        let knob = Knob::new(param(0.3, 0.5), Message::Changed).size(Length::Fixed(40.0));
+        Harness::with_renderer(knob, SIZE, renderer())
+            .snapshot(&Theme::Light)
+            .assert_golden(snapshot_path("knob_circle"));
+
+        let h_slider: HSlider<'_, _, Theme> =
+            HSlider::new(param(0.5, 0.5), Message::Changed).width(Length::Fixed(100.0));
+        let mut harness = Harness::with_renderer(h_slider, SIZE, renderer());
+        let center = harness.center();
+
+        harness.drag(center, center + Vector::new(10.0, 0.0));
+
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 0.1 * 0.9575);
+    }
+}
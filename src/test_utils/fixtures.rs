@@ -0,0 +1,159 @@
+//! Messages, params, and styles shared by the tests of the widgets
+
+use super::{Harness, Snapshot};
+use crate::{
+    compressor_curve::{Handle, Transfer},
+    reduction_meter,
+    style::{h_slider, knob, Status},
+    Change, FloatRange, HSlider, Knob, Normal, NormalParam, ParamSnapshot, ReductionMeter,
+};
+use iced::{keyboard, mouse, Color, Element, Event, Length, Size, Theme};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Message {
+    Changed(Normal),
+    ChangedFull(Change),
+    ChangedPair(Normal, Normal),
+    ChangedCurve(Handle, Transfer),
+    Linked(bool),
+    TypedIn(String),
+    Hovered(Option<usize>),
+    Dropped(u64),
+    Grouped(ParamSnapshot<u64>),
+    Turned(f32),
+    ModChanged(Normal),
+    Grabbed,
+    Released,
+}
+
+pub(crate) const SIZE: Size = Size::new(200.0, 200.0);
+
+pub(crate) fn param(value: f32, default: f32) -> NormalParam {
+    FloatRange::default().normal_param(value, default)
+}
+
+pub(crate) fn changed(messages: &[Message]) -> Vec<f32> {
+    messages
+        .iter()
+        .filter_map(|message| match message {
+            Message::Changed(normal) => Some(normal.as_f32()),
+            _ => None,
+        })
+        .collect()
+}
+
+pub(crate) fn assert_close(value: f32, expected: f32) {
+    assert!(
+        (value - expected).abs() < 0.0001,
+        "{value} is not {expected}"
+    );
+}
+
+pub(crate) fn knob<'a>() -> Knob<'a, Message, Theme> {
+    Knob::new(param(0.5, 0.25), Message::Changed)
+}
+
+pub(crate) fn child_sizes<'a>(widget: impl Into<Element<'a, Message, Theme>>) -> Vec<Size> {
+    Harness::new(widget, SIZE)
+        .layout()
+        .children()
+        .map(|child| child.bounds().size())
+        .collect()
+}
+
+pub(crate) fn wheel(x: f32, y: f32) -> Event {
+    Event::Mouse(mouse::Event::WheelScrolled {
+        delta: mouse::ScrollDelta::Pixels { x, y },
+    })
+}
+
+pub(crate) fn key_events(modifiers: keyboard::Modifiers) -> [Event; 2] {
+    let key = keyboard::Key::Character("a".into());
+    let physical_key = keyboard::key::Physical::Code(keyboard::key::Code::KeyA);
+
+    [
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            modified_key: key.clone(),
+            physical_key,
+            location: keyboard::Location::Standard,
+            modifiers,
+            text: Some("a".into()),
+        }),
+        Event::Keyboard(keyboard::Event::KeyReleased {
+            key,
+            location: keyboard::Location::Standard,
+            modifiers,
+        }),
+    ]
+}
+
+pub(crate) fn reduction_meter<'a>(reduction: f32) -> ReductionMeter<'a, Theme> {
+    ReductionMeter::new(Normal::from_clipped(reduction))
+        .height(Length::Fixed(100.0))
+        .style(|_| reduction_meter::Appearance {
+            back_color: LIGHT,
+            border_width: 0.0,
+            border_radius: 0.0,
+            border_color: GRAY,
+            bar_color: BLUE,
+            bar_width: 10.0,
+            history_color: ORANGE,
+            history_spacing: 0.0,
+        })
+}
+
+pub(crate) const GRAY: Color = Color::from_rgb(0.4, 0.4, 0.4);
+
+pub(crate) const LIGHT: Color = Color::from_rgb(0.85, 0.85, 0.85);
+
+pub(crate) const BLUE: Color = Color::from_rgb(0.2, 0.5, 0.9);
+
+pub(crate) const ORANGE: Color = Color::from_rgb(0.9, 0.5, 0.1);
+
+pub(crate) fn snapshot_path(name: &str) -> String {
+    format!(
+        "{}/src/test_utils/snapshots/{name}.png",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+pub(crate) struct KnobStyle(pub(crate) knob::Appearance);
+
+impl knob::StyleSheet for KnobStyle {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, _status: Status) -> knob::Appearance {
+        self.0.clone()
+    }
+}
+
+pub(crate) struct HSliderStyle(pub(crate) h_slider::Appearance);
+
+impl h_slider::StyleSheet for HSliderStyle {
+    type Style = Theme;
+
+    fn appearance(&self, _style: &Self::Style, _status: Status) -> h_slider::Appearance {
+        self.0.clone()
+    }
+}
+
+pub(crate) fn knob_snapshot(style: Option<knob::Appearance>) -> Snapshot {
+    let mut knob = Knob::new(param(0.3, 0.5), Message::Changed).size(Length::Fixed(40.0));
+    if let Some(appearance) = style {
+        knob = knob.class(KnobStyle(appearance));
+    }
+
+    Harness::new(knob, SIZE).snapshot(&Theme::Light)
+}
+
+pub(crate) fn h_slider_snapshot(style: Option<h_slider::Appearance>) -> Snapshot {
+    let mut h_slider = HSlider::new(param(0.3, 0.5), Message::Changed)
+        .width(Length::Fixed(100.0))
+        .height(Length::Fixed(14.0));
+    if let Some(appearance) = style {
+        h_slider = h_slider.class(HSliderStyle(appearance));
+    }
+
+    Harness::new(h_slider, SIZE).snapshot(&Theme::Light)
+}
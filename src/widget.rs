@@ -1,12 +1,77 @@
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider"
+))]
+mod animation;
+#[cfg(feature = "arc_slider")]
+pub mod arc_slider;
+#[cfg(feature = "compressor_curve")]
+pub mod compressor_curve;
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+mod focus_ring;
 #[cfg(feature = "h_slider")]
 pub mod h_slider;
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "compressor_curve",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+mod input;
 #[cfg(feature = "knob")]
 pub mod knob;
+#[cfg(feature = "labeled")]
+pub mod labeled;
+#[cfg(feature = "loudness_meter")]
+pub mod loudness_meter;
+#[cfg(feature = "marks")]
+pub mod marks;
+#[cfg(any(feature = "h_slider", feature = "v_slider"))]
+mod marks_layout;
+#[cfg(feature = "meter_bridge")]
+pub mod meter_bridge;
 #[cfg(feature = "mod_range_input")]
 pub mod mod_range_input;
+#[cfg(feature = "mod_range_knob")]
+pub mod mod_range_knob;
 #[cfg(feature = "ramp")]
 pub mod ramp;
+#[cfg(feature = "marks")]
+pub mod ruler;
+#[cfg(feature = "reduction_meter")]
+pub mod reduction_meter;
+#[cfg(feature = "spectrogram")]
+pub mod spectrogram;
+#[cfg(feature = "stereo_slider")]
+pub mod stereo_slider;
 #[cfg(feature = "v_slider")]
 pub mod v_slider;
+#[cfg(any(feature = "h_slider", feature = "v_slider"))]
+mod value_text;
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider"
+))]
+mod virtual_slider;
 #[cfg(feature = "xy_pad")]
 pub mod xy_pad;
@@ -0,0 +1,106 @@
+//! Animations that advance on the frames the widgets request from the
+//! shell, so that they move without the application sending messages.
+//!
+//! A widget starts an animation from `on_event`, which requests the next
+//! frame, and advances it when the `RedrawRequested` event of that frame
+//! arrives, requesting frames until the animation is complete. The meters
+//! follow the same pattern for their ballistics and peak holds.
+//!
+//! Widgets with reduced motion, e.g. from the `reduced_motion` of the
+//! `Defaults`, skip their animations and show where they end right away.
+
+use iced::{
+    advanced::Shell,
+    time::{Duration, Instant},
+    window,
+};
+
+/// Returns the duration of an animation over `duration`, which is none at
+/// all with `reduced_motion`, so that it completes at its first frame.
+#[cfg(any(feature = "h_slider", feature = "knob", feature = "v_slider"))]
+pub(crate) fn motion_duration(duration: Duration, reduced_motion: bool) -> Duration {
+    if reduced_motion {
+        Duration::ZERO
+    } else {
+        duration
+    }
+}
+
+/// A transition from `0.0` to `1.0` over a duration, eased out so that it
+/// slows down toward the end. It starts at the first frame drawn after it is
+/// created.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Transition {
+    duration: Duration,
+    start: Option<Instant>,
+}
+
+impl Transition {
+    /// Starts a transition over `duration`, requesting the frame it starts
+    /// at from the `shell`.
+    pub fn start<Message>(duration: Duration, shell: &mut Shell<'_, Message>) -> Self {
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+
+        Self {
+            duration,
+            start: None,
+        }
+    }
+
+    /// Returns the eased progress of the transition at the frame drawn at
+    /// `now`, and requests the next frame from the `shell` until it is
+    /// complete.
+    pub fn progress<Message>(&mut self, now: Instant, shell: &mut Shell<'_, Message>) -> f32 {
+        let start = *self.start.get_or_insert(now);
+
+        let linear = if self.duration.is_zero() {
+            1.0
+        } else {
+            (now.saturating_duration_since(start).as_secs_f32() / self.duration.as_secs_f32())
+                .min(1.0)
+        };
+
+        if linear < 1.0 {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        1.0 - (1.0 - linear).powi(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Duration, Instant, Transition};
+    use iced::{advanced::Shell, window};
+
+    #[test]
+    fn transition_eases_out_until_complete() {
+        let start = Instant::now();
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let mut transition = Transition::start(Duration::from_millis(100), &mut shell);
+        assert_eq!(
+            shell.redraw_request(),
+            Some(window::RedrawRequest::NextFrame)
+        );
+
+        let mut shell = Shell::new(&mut messages);
+        assert_eq!(transition.progress(start, &mut shell), 0.0);
+        assert_eq!(
+            transition.progress(start + Duration::from_millis(50), &mut shell),
+            0.875
+        );
+        assert_eq!(
+            shell.redraw_request(),
+            Some(window::RedrawRequest::NextFrame)
+        );
+
+        let mut shell = Shell::new(&mut messages);
+        assert_eq!(
+            transition.progress(start + Duration::from_millis(150), &mut shell),
+            1.0
+        );
+        assert_eq!(shell.redraw_request(), None);
+    }
+}
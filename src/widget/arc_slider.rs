@@ -0,0 +1,869 @@
+//! Display an interactive slider along an arc that controls a [`NormalParam`]
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+
+#[cfg(feature = "a11y")]
+use crate::a11y;
+use crate::{
+    core::{
+        math::{THREE_HALVES_PI, TWO_PI},
+        sensitivity::DEFAULT_PIXELS_PER_STEP,
+        Change, DoubleClick, DragThreshold, HitShape, KnobAngleRange, ModifierMatch, Normal,
+        NormalParam, Param, SliderSensitivity, ViewWindow,
+    },
+    defaults,
+    operation::{DragState, HandleState, ValueState},
+    style::Dim,
+    widget::{
+        focus_ring, input,
+        virtual_slider::{Axis, State, VirtualSlider},
+    },
+};
+use iced::{
+    advanced::{
+        graphics::{
+            core::{event, keyboard},
+            geometry,
+        },
+        layout, mouse,
+        renderer::Style,
+        widget::{tree, Id, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    time::Duration,
+    widget::canvas::{path::Arc, Frame, Path, Stroke},
+    Element, Event, Length, Point, Radians, Rectangle, Size, Vector,
+};
+
+pub use crate::style::arc_slider::{Appearance, Catalog, LineCap, Status, StyleFn, StyleSheet};
+
+/// The default width of the [`ArcSlider`].
+///
+/// [`ArcSlider`]: struct.ArcSlider.html
+pub const DEFAULT_WIDTH: f32 = 120.0;
+
+/// The default height of the [`ArcSlider`].
+///
+/// [`ArcSlider`]: struct.ArcSlider.html
+pub const DEFAULT_HEIGHT: f32 = 60.0;
+
+/// The default distance between the arc of the [`ArcSlider`] and the edges
+/// of its bounds, which leaves room for the handle.
+///
+/// [`ArcSlider`]: struct.ArcSlider.html
+pub const DEFAULT_INSET: f32 = 8.0;
+
+/// The default width across the arc of the [`ArcSlider`] that reacts to the
+/// mouse and to touches.
+///
+/// [`ArcSlider`]: struct.ArcSlider.html
+pub const DEFAULT_HIT_WIDTH: f32 = 16.0;
+
+/// The default `wheel_scalar` of the [`ArcSlider`].
+///
+/// [`ArcSlider`]: struct.ArcSlider.html
+pub const DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+
+/// The default `modifier_scalar` of the [`ArcSlider`].
+///
+/// [`ArcSlider`]: struct.ArcSlider.html
+pub const DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// The default [`SliderSensitivity`] of the [`ArcSlider`].
+///
+/// The handle follows the pointer along the arc, so the `scalar` is not
+/// used.
+///
+/// [`ArcSlider`]: struct.ArcSlider.html
+/// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+pub const DEFAULT_SENSITIVITY: SliderSensitivity = SliderSensitivity {
+    scalar: 0.0,
+    wheel_scalar: DEFAULT_WHEEL_SCALAR,
+    pixels_per_step: DEFAULT_PIXELS_PER_STEP,
+    modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+    modifier_keys: keyboard::Modifiers::CTRL,
+    modifier_match: ModifierMatch::Contains,
+    extra_modifiers: &[],
+};
+
+/// A slider GUI widget whose handle travels along an arc, e.g. a curved
+/// fader of a channel strip, that controls a [`NormalParam`]
+///
+/// The arc is a segment of a circle with a configurable center, radius, and
+/// [`KnobAngleRange`], where the value grows clockwise like on a [`Knob`].
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+/// [`KnobAngleRange`]: ../../core/struct.KnobAngleRange.html
+/// [`Knob`]: ../knob/struct.Knob.html
+#[allow(missing_debug_implementations)]
+pub struct ArcSlider<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    normal_param: NormalParam,
+    width: Length,
+    height: Length,
+    center: Option<Point>,
+    radius: Option<f32>,
+    angle_range: KnobAngleRange,
+    hit_width: f32,
+    on_change: Box<dyn 'a + Fn(Change) -> Message>,
+    on_grab: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
+    on_release: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
+    sensitivity: SliderSensitivity,
+    capture_wheel: bool,
+    double_click: DoubleClick,
+    drag_threshold: DragThreshold,
+    wheel_gesture: Option<Duration>,
+    id: Option<Id>,
+    #[cfg(feature = "a11y")]
+    name: Option<String>,
+    #[cfg(feature = "a11y")]
+    description: Option<String>,
+    #[cfg(feature = "a11y")]
+    announced_value: Option<Box<dyn 'a + Fn(Normal) -> String>>,
+    class: Theme::Class<'a>,
+    opacity: f32,
+}
+
+impl<'a, Message, Theme> ArcSlider<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`ArcSlider`].
+    ///
+    /// It expects:
+    ///   * the [`NormalParam`] of the [`ArcSlider`]
+    ///   * a function that will be called when the [`ArcSlider`] is moved.
+    ///
+    /// [`NormalParam`]: struct.NormalParam.html
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    pub fn new<F>(normal_param: NormalParam, on_change: F) -> Self
+    where
+        F: 'a + Fn(Normal) -> Message,
+    {
+        ArcSlider {
+            normal_param,
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            center: None,
+            radius: None,
+            angle_range: KnobAngleRange::from_deg(120.0, 240.0),
+            hit_width: DEFAULT_HIT_WIDTH,
+            on_change: Box::new(move |change: Change| on_change(change.normal)),
+            on_grab: None,
+            on_release: None,
+            sensitivity: defaults::get().arc_slider_sensitivity,
+            capture_wheel: true,
+            double_click: defaults::get().double_click,
+            drag_threshold: defaults::get().drag_threshold,
+            wheel_gesture: defaults::get().wheel_gesture,
+            id: None,
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            announced_value: None,
+            class: Theme::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Creates a new [`ArcSlider`] from a [`Param`].
+    ///
+    /// Unlike `ArcSlider::new()`, `on_change` is called with the (snapped)
+    /// value of the parameter instead of a [`Normal`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Param`]: ../../core/param/trait.Param.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn from_param<P, F>(param: P, on_change: F) -> Self
+    where
+        P: 'a + Param,
+        F: 'a + Fn(P::Value) -> Message,
+    {
+        let normal_param = param.normal_param();
+
+        Self::new(normal_param, move |normal| {
+            on_change(param.unmap_to_value(normal))
+        })
+    }
+
+    /// Sets the message to produce when the [`ArcSlider`] is moved, replacing
+    /// the `on_change` function given to `ArcSlider::new()`.
+    ///
+    /// Unlike `on_change`, `on_change_full` receives a [`Change`] with the
+    /// previous value and whether the value was dragged, scrolled or reset.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Change`]: ../../core/struct.Change.html
+    pub fn on_change_full(mut self, on_change_full: impl 'a + Fn(Change) -> Message) -> Self {
+        self.on_change = Box::new(on_change_full);
+        self
+    }
+
+    /// Sets the grab message of the [`ArcSlider`].
+    /// This is called when the mouse grabs the handle.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    pub fn on_grab(mut self, on_grab: impl 'a + FnMut() -> Option<Message>) -> Self {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the release message of the [`ArcSlider`].
+    /// This is called when the mouse is released from the handle.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    pub fn on_release(mut self, on_release: impl 'a + FnMut() -> Option<Message>) -> Self {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the width of the [`ArcSlider`]. The default width is
+    /// [`DEFAULT_WIDTH`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`DEFAULT_WIDTH`]: constant.DEFAULT_WIDTH.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ArcSlider`]. The default height is
+    /// [`DEFAULT_HEIGHT`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`DEFAULT_HEIGHT`]: constant.DEFAULT_HEIGHT.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the center of the circle of the arc, relative to the top left
+    /// corner of the [`ArcSlider`]. It may lie outside of its bounds.
+    ///
+    /// The default center is the middle of the bottom edge.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    pub fn center(mut self, center: Point) -> Self {
+        self.center = Some(center);
+        self
+    }
+
+    /// Sets the radius of the arc.
+    ///
+    /// By default, the arc reaches up to [`DEFAULT_INSET`] from the top, or
+    /// from the sides if the [`ArcSlider`] is less than twice as wide as
+    /// high.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`DEFAULT_INSET`]: constant.DEFAULT_INSET.html
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// Sets the start and end angle of the arc, where `0` points straight
+    /// down and the angles grow clockwise, like on a [`Knob`].
+    ///
+    /// The default range is from `120` to `240` degrees, i.e. the top third
+    /// of the circle.
+    ///
+    /// [`Knob`]: ../knob/struct.Knob.html
+    pub fn angle_range(mut self, angle_range: KnobAngleRange) -> Self {
+        self.angle_range = angle_range;
+        self
+    }
+
+    /// Sets the width across the arc that reacts to the mouse and to
+    /// touches. The default is [`DEFAULT_HIT_WIDTH`].
+    ///
+    /// [`DEFAULT_HIT_WIDTH`]: constant.DEFAULT_HIT_WIDTH.html
+    pub fn hit_width(mut self, hit_width: f32) -> Self {
+        self.hit_width = hit_width;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`ArcSlider`], e.g. to check whether it is
+    /// being dragged with [`operation::is_dragging()`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Id`]: https://docs.rs/iced/0.13/iced/advanced/widget/struct.Id.html
+    /// [`operation::is_dragging()`]: ../../operation/fn.is_dragging.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the name of the [`ArcSlider`] that screen readers announce, e.g.
+    /// the name of the parameter it controls.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    #[cfg(feature = "a11y")]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the [`ArcSlider`] that screen readers
+    /// announce after its name.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    #[cfg(feature = "a11y")]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets how screen readers announce the value of the [`ArcSlider`], e.g. as
+    /// `"-6 dB"` or `"440 Hz"` for the mapped value of its parameter, instead
+    /// of the raw [`Normal`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    #[cfg(feature = "a11y")]
+    pub fn announced_value(mut self, format: impl 'a + Fn(Normal) -> String) -> Self {
+        self.announced_value = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the [`ArcSlider`] with a function of the theme and
+    /// the [`Status`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Status`]: ../../style/enum.Status.html
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ArcSlider`], e.g. a custom
+    /// [`StyleSheet`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`StyleSheet`]: ../../style/arc_slider/trait.StyleSheet.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`ArcSlider`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the faders of a bypassed section. The default is `1.0`.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the [`SliderSensitivity`] of the [`ArcSlider`], i.e. its
+    /// `wheel_scalar`, `pixels_per_step`, `modifier_scalar` and
+    /// `modifier_keys` at once. Its `scalar` is not used.
+    ///
+    /// The default is [`DEFAULT_SENSITIVITY`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`SliderSensitivity`]: ../../core/struct.SliderSensitivity.html
+    /// [`DEFAULT_SENSITIVITY`]: constant.DEFAULT_SENSITIVITY.html
+    pub fn sensitivity(mut self, sensitivity: SliderSensitivity) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the [`ArcSlider`]
+    /// per line scrolled by the mouse wheel.
+    ///
+    /// This can be set to `0.0` to disable the scroll wheel from moving the
+    /// parameter.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.sensitivity.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how many pixels a pixel based scroll delta (e.g. from a
+    /// touchpad) has to add up to before the [`ArcSlider`] moves by one
+    /// `wheel_scalar` step.
+    ///
+    /// The default value is [`DEFAULT_PIXELS_PER_STEP`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`DEFAULT_PIXELS_PER_STEP`]: ../../core/sensitivity/constant.DEFAULT_PIXELS_PER_STEP.html
+    pub fn pixels_per_step(mut self, pixels_per_step: f32) -> Self {
+        self.sensitivity.pixels_per_step = pixels_per_step;
+        self
+    }
+
+    /// Sets whether the [`ArcSlider`] captures the wheel events that move it,
+    /// so a scrollable parent doesn't scroll at the same time. With `false`,
+    /// the wheel scrolls the parent instead of moving the [`ArcSlider`].
+    ///
+    /// Wheel events are always captured while the [`ArcSlider`] is dragged. The
+    /// default value is `true`.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    pub fn capture_wheel(mut self, capture_wheel: bool) -> Self {
+        self.capture_wheel = capture_wheel;
+        self
+    }
+
+    /// Sets when two presses of the [`ArcSlider`] count as a double click, which
+    /// resets it to its default value.
+    ///
+    /// The default is the `double_click` of the [`Defaults`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn double_click(mut self, double_click: DoubleClick) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
+    /// Sets how far the pointer has to move from a press of the [`ArcSlider`]
+    /// before it drags the value, so that a click or a tap doesn't move it.
+    ///
+    /// The default is the `drag_threshold` of the [`Defaults`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn drag_threshold(mut self, drag_threshold: DragThreshold) -> Self {
+        self.drag_threshold = drag_threshold;
+        self
+    }
+
+    /// Groups consecutive wheel steps of the [`ArcSlider`] into one gesture, which
+    /// fires `on_grab` at the first step and `on_release` once the wheel is
+    /// idle for `idle_timeout`, e.g. for hosts that create an undo entry per
+    /// gesture. With `None`, every wheel step fires both.
+    ///
+    /// The default is the `wheel_gesture` of the [`Defaults`].
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn wheel_gesture(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.wheel_gesture = idle_timeout;
+        self
+    }
+
+    /// Sets the modifier keys of the [`ArcSlider`].
+    ///
+    /// The default modifier key is `Ctrl`.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
+        self.sensitivity.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags the [`ArcSlider`] while
+    /// holding down the modifier key, which scales down how far the handle
+    /// moves along the arc.
+    ///
+    /// The default `modifier_scalar` is `0.02`, and the default modifier key
+    /// is `Ctrl`.
+    ///
+    /// [`ArcSlider`]: struct.ArcSlider.html
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.sensitivity.modifier_scalar = scalar;
+        self
+    }
+
+    /// Returns where the arc lies within `bounds`.
+    fn arc_info(&self, bounds: Rectangle) -> ArcInfo {
+        let center = match self.center {
+            Some(center) => Point::new(bounds.x + center.x, bounds.y + center.y),
+            None => Point::new(bounds.center_x(), bounds.y + bounds.height),
+        };
+
+        let radius = self
+            .radius
+            .unwrap_or_else(|| (bounds.height.min(bounds.width / 2.0) - DEFAULT_INSET).max(0.0));
+
+        ArcInfo::new(center, radius, &self.angle_range)
+    }
+
+    fn virtual_slider(&mut self) -> VirtualSlider<'_, 'a, Message> {
+        VirtualSlider {
+            normal_param: &mut self.normal_param,
+            sensitivity: self.sensitivity,
+            on_change: &*self.on_change,
+            on_grab: self.on_grab.as_deref_mut(),
+            on_release: self.on_release.as_deref_mut(),
+            steps: None,
+            free_modifier_keys: keyboard::Modifiers::empty(),
+            fine_drag_button: None,
+            double_click: self.double_click,
+            drag_threshold: self.drag_threshold,
+            detents: None,
+            on_type_in: None,
+            view_window: ViewWindow::FULL,
+            inverse: false,
+            on_drop: None,
+            group: None,
+            on_group_change: None,
+            capture_wheel: self.capture_wheel,
+            horizontal_wheel_scalar: 0.0,
+            spring_back: None,
+            wheel_gesture: self.wheel_gesture,
+            hit_shape: HitShape::Rectangle,
+        }
+    }
+}
+
+/// Where the arc of an [`ArcSlider`] lies, with its angles in the clockwise
+/// radians of the canvas, where `0` points right.
+///
+/// [`ArcSlider`]: struct.ArcSlider.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ArcInfo {
+    center: Point,
+    radius: f32,
+    start_angle: f32,
+    angle_span: f32,
+}
+
+impl ArcInfo {
+    fn new(center: Point, radius: f32, angle_range: &KnobAngleRange) -> Self {
+        // A `KnobAngleRange` starts at the bottom, a quarter turn from the
+        // start of the canvas.
+        let start_angle = if angle_range.min() >= THREE_HALVES_PI {
+            angle_range.min() - THREE_HALVES_PI
+        } else {
+            angle_range.min() + std::f32::consts::FRAC_PI_2
+        };
+
+        Self {
+            center,
+            radius,
+            start_angle,
+            angle_span: angle_range.max() - angle_range.min(),
+        }
+    }
+
+    /// The angle of the handle at `normal`.
+    fn angle_at(&self, normal: Normal) -> f32 {
+        self.start_angle + normal.scale(self.angle_span)
+    }
+
+    /// The position of the handle at `normal`.
+    fn point_at(&self, normal: Normal) -> Point {
+        let angle = self.angle_at(normal);
+
+        Point::new(
+            self.center.x + self.radius * angle.cos(),
+            self.center.y + self.radius * angle.sin(),
+        )
+    }
+
+    /// Whether `point` is on the arc, i.e. less than half of `width` away
+    /// from it.
+    fn contains(&self, point: Point, width: f32) -> bool {
+        let (x, y) = (point.x - self.center.x, point.y - self.center.y);
+        let half_width = width / 2.0;
+
+        if (x.hypot(y) - self.radius).abs() > half_width {
+            return false;
+        }
+
+        if self.radius <= 0.0 {
+            return true;
+        }
+
+        // The ends reach out by half of the width as well.
+        let slack = half_width / self.radius;
+        let angle = (y.atan2(x) - self.start_angle).rem_euclid(TWO_PI);
+
+        angle <= self.angle_span + slack || angle >= TWO_PI - slack
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ArcSlider<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.normal_param.value))
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = state.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref());
+
+        operation.custom(
+            &mut DragState {
+                status: state.dragging_status,
+            },
+            self.id.as_ref(),
+        );
+
+        operation.custom(
+            &mut ValueState {
+                normal: self.normal_param.value,
+                default: self.normal_param.default,
+            },
+            self.id.as_ref(),
+        );
+
+        operation.custom(
+            &mut HandleState {
+                center: self
+                    .arc_info(layout.bounds())
+                    .point_at(self.normal_param.value),
+            },
+            self.id.as_ref(),
+        );
+
+        #[cfg(feature = "a11y")]
+        operation.custom(
+            &mut a11y::slider_node(
+                self.name.as_deref(),
+                self.description.as_deref(),
+                self.normal_param.value,
+                self.announced_value
+                    .as_ref()
+                    .map(|format| format(self.normal_param.value))
+                    .as_deref(),
+                (self.sensitivity.wheel_scalar > 0.0).then_some(self.sensitivity.wheel_scalar),
+                None,
+                layout.bounds(),
+            ),
+            self.id.as_ref(),
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = state.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let arc_info = self.arc_info(bounds);
+
+        // Only the arc reacts to the pointer, not the rest of the bounds.
+        let is_on_arc = input::event_cursor(&event, cursor)
+            .position()
+            .is_some_and(|position| arc_info.contains(position, self.hit_width));
+        let hit_bounds = if is_on_arc {
+            bounds
+        } else {
+            Rectangle::new(bounds.position(), Size::ZERO)
+        };
+
+        let axis = Some(Axis::Circular {
+            center: arc_info.center,
+            span: arc_info.angle_span,
+        });
+
+        self.virtual_slider()
+            .on_event(state, &event, cursor, hit_bounds, axis, shell)
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let arc_info = self.arc_info(bounds);
+
+        let is_over = cursor
+            .position()
+            .is_some_and(|position| arc_info.contains(position, self.hit_width));
+
+        let status = if state.dragging_status.is_some() {
+            Status::Dragged
+        } else if is_over {
+            Status::Hovered
+        } else if state.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        };
+
+        let appearance = theme.style(&self.class, status).dim(self.opacity);
+
+        // The frame covers the bounds, so the arc is drawn relative to them.
+        let mut frame = Frame::new(renderer, bounds.size());
+        let center = arc_info.center - Vector::new(bounds.x, bounds.y);
+        let value_angle = arc_info.angle_at(self.normal_param.value);
+
+        let rail = |end_angle: f32| {
+            Path::new(|path| {
+                path.arc(Arc {
+                    center,
+                    radius: arc_info.radius,
+                    start_angle: Radians(arc_info.start_angle),
+                    end_angle: Radians(end_angle),
+                })
+            })
+        };
+
+        frame.stroke(
+            &rail(arc_info.start_angle + arc_info.angle_span),
+            Stroke::default()
+                .with_width(appearance.rail_width)
+                .with_color(appearance.rail_color)
+                .with_line_cap(appearance.cap),
+        );
+
+        if self.normal_param.value > Normal::MIN {
+            frame.stroke(
+                &rail(value_angle),
+                Stroke::default()
+                    .with_width(appearance.rail_width)
+                    .with_color(appearance.filled_color)
+                    .with_line_cap(appearance.cap),
+            );
+        }
+
+        let handle = Path::circle(
+            arc_info.point_at(self.normal_param.value) - Vector::new(bounds.x, bounds.y),
+            appearance.handle_diameter / 2.0,
+        );
+
+        frame.fill(&handle, appearance.handle_color);
+
+        if appearance.handle_border_width > 0.0 {
+            frame.stroke(
+                &handle,
+                Stroke::default()
+                    .with_width(appearance.handle_border_width)
+                    .with_color(appearance.handle_border_color),
+            );
+        }
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+
+        if state.is_focused && state.focus_visible {
+            if let Some(focus_ring) = theme.focus_ring_appearance(&self.class) {
+                focus_ring::draw(renderer, bounds, &focus_ring.dim(self.opacity), false);
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ArcSlider<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + Catalog,
+    Renderer: 'a + geometry::Renderer,
+{
+    fn from(arc_slider: ArcSlider<'a, Message, Theme>) -> Self {
+        Self::new(arc_slider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArcInfo;
+    use crate::core::{KnobAngleRange, Normal};
+    use crate::test_utils::fixtures::{assert_close, changed, param, Message, SIZE};
+    use crate::test_utils::Harness;
+    use crate::{operation, ArcSlider};
+    use iced::Point;
+    use iced::{advanced::widget::Id, event, Theme, Vector};
+
+    #[test]
+    fn arc_contains_only_the_segment() {
+        // The top half of a circle around the origin.
+        let arc = ArcInfo::new(Point::ORIGIN, 50.0, &KnobAngleRange::from_deg(90.0, 270.0));
+
+        let top = arc.point_at(Normal::CENTER);
+        assert!(top.x.abs() < 1.0e-4 && (top.y + 50.0).abs() < 1.0e-4);
+
+        assert!(arc.contains(Point::new(0.0, -45.0), 16.0));
+        assert!(arc.contains(Point::new(-50.0, 2.0), 16.0));
+        assert!(!arc.contains(Point::new(0.0, -30.0), 16.0));
+        assert!(!arc.contains(Point::new(0.0, 50.0), 16.0));
+    }
+
+    #[test]
+    fn arc_slider_follows_the_arc() {
+        let arc_slider =
+            ArcSlider::<_, Theme>::new(param(0.5, 0.5), Message::Changed).id(Id::new("arc"));
+        let mut harness = Harness::new(arc_slider, SIZE);
+
+        // The arc is the top third of a circle around the middle of the
+        // bottom edge, with a radius of 52 pixels.
+        let center = Point::new(60.0, 60.0);
+        let on_arc = |degrees: f32| {
+            let angle = degrees.to_radians();
+            center + Vector::new(52.0 * angle.cos(), 52.0 * angle.sin())
+        };
+
+        // Inside the bounds but off the arc, the slider isn't grabbed.
+        let _ = harness.move_cursor(Point::new(60.0, 30.0));
+        assert_eq!(harness.press(), event::Status::Ignored);
+        let _ = harness.release();
+
+        // Grab the handle at the top and move it a sixth of a turn along
+        // the arc, which is half of its 120 degrees.
+        let _ = harness.move_cursor(on_arc(270.0));
+        assert_eq!(harness.press(), event::Status::Captured);
+        let _ = harness.move_cursor(on_arc(300.0));
+        let _ = harness.move_cursor(on_arc(330.0));
+        assert_close(*changed(&harness.take_messages()).last().unwrap(), 1.0);
+
+        // The handle is at the end of the arc.
+        let handle = harness
+            .operate(operation::handle_position(Id::new("arc")))
+            .flatten()
+            .unwrap();
+        assert!(handle.distance(on_arc(330.0)) < 1.0e-3);
+    }
+}
@@ -0,0 +1,813 @@
+//! Display an interactive transfer curve of a compressor, with handles to
+//! drag its threshold, ratio, and knee.
+//!
+//! Both axes show the level in decibels with the same [`LogDBRange`], the
+//! input level from left to right and the output level from bottom to top.
+//!
+//! [`LogDBRange`]: ../../core/range/struct.LogDBRange.html
+
+use crate::{
+    core::{tick_marks, LogDBRange, Normal, SliderStatus},
+    style::Dim,
+    widget::input,
+};
+use iced::{
+    advanced::{
+        graphics::{
+            core::{event, touch},
+            geometry,
+        },
+        layout, mouse,
+        renderer::{self, Quad, Style},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    border::Radius,
+    widget::canvas::{self, Fill, Frame, Path, Stroke},
+    Border, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+
+pub use crate::style::compressor_curve::{Appearance, Catalog, Status, StyleFn, StyleSheet};
+
+/// The default largest ratio of a [`CompressorCurve`].
+///
+/// [`CompressorCurve`]: struct.CompressorCurve.html
+pub const DEFAULT_MAX_RATIO: f32 = 20.0;
+
+/// The default largest knee width of a [`CompressorCurve`] in decibels.
+///
+/// [`CompressorCurve`]: struct.CompressorCurve.html
+pub const DEFAULT_MAX_KNEE: f32 = 24.0;
+
+/// How close to a handle in pixels a press has to be to grab it.
+const HANDLE_HIT_RADIUS: f32 = 8.0;
+
+/// How far in pixels the knee handle sits to the right of the end of the
+/// knee, so that it does not cover the threshold handle when the knee is hard.
+const KNEE_HANDLE_GAP: f32 = 16.0;
+
+/// The number of line segments the curve is drawn with.
+const CURVE_SEGMENTS: usize = 64;
+
+/// The static characteristic of a compressor: how loud the output is for a
+/// given input level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transfer {
+    /// The input level in decibels above which the compressor reduces the
+    /// gain.
+    pub threshold: f32,
+    /// How many decibels the input has to rise above the threshold for the
+    /// output to rise by one decibel. `1.0` does not compress at all.
+    pub ratio: f32,
+    /// The width in decibels of the range around the threshold in which the
+    /// ratio eases in. `0.0` is a hard knee.
+    pub knee: f32,
+}
+
+impl Transfer {
+    /// Creates a new [`Transfer`].
+    ///
+    /// [`Transfer`]: struct.Transfer.html
+    pub fn new(threshold: f32, ratio: f32, knee: f32) -> Self {
+        Self {
+            threshold,
+            ratio,
+            knee,
+        }
+    }
+
+    /// Returns the output level in decibels for the `input` level in
+    /// decibels.
+    pub fn output(&self, input: f32) -> f32 {
+        let over = input - self.threshold;
+        let slope = 1.0 / self.ratio.max(1.0);
+
+        if 2.0 * over < -self.knee {
+            input
+        } else if self.knee > 0.0 && 2.0 * over.abs() <= self.knee {
+            let eased = over + self.knee / 2.0;
+            input + (slope - 1.0) * eased * eased / (2.0 * self.knee)
+        } else {
+            self.threshold + over * slope
+        }
+    }
+}
+
+impl Default for Transfer {
+    fn default() -> Self {
+        Self::new(-20.0, 4.0, 6.0)
+    }
+}
+
+/// A handle of a [`CompressorCurve`].
+///
+/// [`CompressorCurve`]: struct.CompressorCurve.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Handle {
+    /// The handle on the curve at the threshold, dragged left and right.
+    Threshold,
+    /// The handle at the right end of the curve, dragged up and down.
+    Ratio,
+    /// The handle on the curve right of the end of the knee, dragged left
+    /// and right.
+    Knee,
+}
+
+/// An interactive transfer curve of a compressor.
+///
+/// It draws the output level over the input level, and emits a message with
+/// the changed [`Transfer`] when one of its [`Handle`]s is dragged.
+///
+/// [`Transfer`]: struct.Transfer.html
+/// [`Handle`]: enum.Handle.html
+#[allow(missing_debug_implementations)]
+pub struct CompressorCurve<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    transfer: Transfer,
+    on_change: Box<dyn 'a + Fn(Handle, Transfer) -> Message>,
+    on_grab: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
+    on_release: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
+    axis: LogDBRange,
+    grid: Option<&'a tick_marks::Group>,
+    max_ratio: f32,
+    max_knee: f32,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    opacity: f32,
+}
+
+impl<'a, Message, Theme> CompressorCurve<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`CompressorCurve`].
+    ///
+    /// It expects:
+    ///   * the [`Transfer`] to show
+    ///   * a function that will be called with the dragged [`Handle`] and the
+    ///     changed [`Transfer`] when a handle is dragged.
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    /// [`Transfer`]: struct.Transfer.html
+    /// [`Handle`]: enum.Handle.html
+    pub fn new<F>(transfer: Transfer, on_change: F) -> Self
+    where
+        F: 'a + Fn(Handle, Transfer) -> Message,
+    {
+        CompressorCurve {
+            transfer,
+            on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            axis: LogDBRange::new(-60.0, 0.0, Normal::MAX),
+            grid: None,
+            max_ratio: DEFAULT_MAX_RATIO,
+            max_knee: DEFAULT_MAX_KNEE,
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets the grab message of the [`CompressorCurve`].
+    /// This is called when a handle is grabbed.
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn on_grab(mut self, on_grab: impl 'a + FnMut() -> Option<Message>) -> Self {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the release message of the [`CompressorCurve`].
+    /// This is called when a handle is released.
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn on_release(mut self, on_release: impl 'a + FnMut() -> Option<Message>) -> Self {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the range in decibels of both axes of the [`CompressorCurve`].
+    ///
+    /// The default is -60 dB to 0 dB.
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn axis(mut self, axis: LogDBRange) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Draws grid lines at the positions of the tick marks on both axes,
+    /// e.g. the same tick marks as a ruler next to the curve. Tier 1 tick
+    /// marks are drawn with the major grid color, the others with the minor
+    /// one.
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn grid(mut self, grid: &'a tick_marks::Group) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    /// Sets the largest ratio the ratio handle can be dragged to.
+    ///
+    /// The default is [`DEFAULT_MAX_RATIO`].
+    ///
+    /// [`DEFAULT_MAX_RATIO`]: constant.DEFAULT_MAX_RATIO.html
+    pub fn max_ratio(mut self, max_ratio: f32) -> Self {
+        self.max_ratio = max_ratio.max(1.0);
+        self
+    }
+
+    /// Sets the largest knee width in decibels the knee handle can be
+    /// dragged to.
+    ///
+    /// The default is [`DEFAULT_MAX_KNEE`].
+    ///
+    /// [`DEFAULT_MAX_KNEE`]: constant.DEFAULT_MAX_KNEE.html
+    pub fn max_knee(mut self, max_knee: f32) -> Self {
+        self.max_knee = max_knee.max(0.0);
+        self
+    }
+
+    /// Sets the width of the [`CompressorCurve`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`CompressorCurve`].
+    ///
+    /// The default height is `Length::Fill`.
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`CompressorCurve`].
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`CompressorCurve`].
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`CompressorCurve`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the dynamics section of a bypassed plugin. The default is `1.0`.
+    ///
+    /// [`CompressorCurve`]: struct.CompressorCurve.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    fn maybe_fire_on_grab(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
+            shell.publish(message);
+        }
+    }
+
+    fn maybe_fire_on_release(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(message) = self.on_release.as_mut().and_then(|on_release| on_release()) {
+            shell.publish(message);
+        }
+    }
+
+    /// Returns the [`Transfer`] with `handle` dragged to `position`.
+    ///
+    /// [`Transfer`]: struct.Transfer.html
+    fn dragged(&self, plot: &Plot, handle: Handle, position: Point) -> Transfer {
+        let mut transfer = self.transfer;
+
+        match handle {
+            Handle::Threshold => {
+                transfer.threshold = plot.level_at_x(position.x);
+            }
+            Handle::Ratio => {
+                let output = plot.level_at_y(position.y);
+
+                transfer.ratio = if output <= transfer.threshold {
+                    self.max_ratio
+                } else {
+                    ((plot.max - transfer.threshold) / (output - transfer.threshold))
+                        .clamp(1.0, self.max_ratio)
+                };
+            }
+            Handle::Knee => {
+                let knee_end = plot.level_at_x(position.x - KNEE_HANDLE_GAP);
+
+                transfer.knee = (2.0 * (knee_end - transfer.threshold)).clamp(0.0, self.max_knee);
+            }
+        }
+
+        transfer
+    }
+}
+
+/// Maps between levels in decibels and positions in the bounds of a
+/// [`CompressorCurve`].
+///
+/// [`CompressorCurve`]: struct.CompressorCurve.html
+struct Plot<'b> {
+    bounds: Rectangle,
+    axis: &'b LogDBRange,
+    max: f32,
+}
+
+impl<'b> Plot<'b> {
+    fn new(bounds: Rectangle, axis: &'b LogDBRange) -> Self {
+        Self {
+            bounds,
+            axis,
+            max: axis.unmap_to_value(Normal::MAX),
+        }
+    }
+
+    fn x(&self, level: f32) -> f32 {
+        self.bounds.x + self.axis.map_to_normal(level).scale(self.bounds.width)
+    }
+
+    fn y(&self, level: f32) -> f32 {
+        self.bounds.y + self.axis.map_to_normal(level).scale_inv(self.bounds.height)
+    }
+
+    fn point(&self, transfer: &Transfer, input: f32) -> Point {
+        Point::new(self.x(input), self.y(transfer.output(input)))
+    }
+
+    fn level_at_x(&self, x: f32) -> f32 {
+        if self.bounds.width <= 0.0 {
+            return self.max;
+        }
+
+        let normal = Normal::from_clipped((x - self.bounds.x) / self.bounds.width);
+        self.axis.unmap_to_value(normal)
+    }
+
+    fn level_at_y(&self, y: f32) -> f32 {
+        if self.bounds.height <= 0.0 {
+            return self.max;
+        }
+
+        let normal = Normal::from_clipped(1.0 - (y - self.bounds.y) / self.bounds.height);
+        self.axis.unmap_to_value(normal)
+    }
+
+    /// Returns where the handles of `transfer` are drawn.
+    fn handles(&self, transfer: &Transfer) -> [(Handle, Point); 3] {
+        let knee_x = self.x(transfer.threshold + transfer.knee / 2.0) + KNEE_HANDLE_GAP;
+
+        [
+            (Handle::Threshold, self.point(transfer, transfer.threshold)),
+            (Handle::Ratio, self.point(transfer, self.max)),
+            (Handle::Knee, self.point(transfer, self.level_at_x(knee_x))),
+        ]
+    }
+
+    /// Returns the handle of `transfer` that is closest to `position`, if it
+    /// is close enough to grab.
+    fn handle_at(&self, transfer: &Transfer, position: Point) -> Option<Handle> {
+        self.handles(transfer)
+            .into_iter()
+            .map(|(handle, point)| (handle, point.distance(position)))
+            .filter(|&(_, distance)| distance <= HANDLE_HIT_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(handle, _)| handle)
+    }
+}
+
+/// The local state of a [`CompressorCurve`].
+///
+/// [`CompressorCurve`]: struct.CompressorCurve.html
+#[derive(Debug, Clone, Default)]
+struct State {
+    dragging: Option<(Handle, SliderStatus)>,
+    drag_pointer: Option<input::Pointer>,
+    press_claim: input::PressClaim,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CompressorCurve<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer + geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = state.state.downcast_mut::<State>();
+        let cursor = input::event_cursor(&event, cursor);
+        let pointer = input::Pointer::of(&event);
+        let plot = Plot::new(layout.bounds(), &self.axis);
+        let is_claimed = input::is_press_claimed(&event, &state.press_claim);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.drag_pointer == pointer =>
+            {
+                if let Some((handle, status)) = state.dragging.as_mut() {
+                    let transfer = self.dragged(&plot, *handle, position);
+
+                    if transfer != self.transfer {
+                        self.transfer = transfer;
+                        shell.publish((self.on_change)(*handle, transfer));
+                        status.moved();
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if state.drag_pointer.is_none() && !is_claimed =>
+            {
+                let handle = cursor
+                    .position()
+                    .and_then(|position| plot.handle_at(&self.transfer, position));
+
+                if let Some(handle) = handle {
+                    input::claim_press(&event, &state.press_claim);
+                    self.maybe_fire_on_grab(shell);
+
+                    state.dragging = Some((handle, SliderStatus::default()));
+                    state.drag_pointer = pointer;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if state.drag_pointer.is_some() && cursor.is_over(layout.bounds()) =>
+            {
+                // Another pointer drags a handle, so the press must not
+                // reach what is underneath the curve either.
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { .. }) if state.dragging.is_some() => {
+                // A scrollable parent must not move the curve away from
+                // under the pointer that drags it.
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag_pointer == pointer =>
+            {
+                state.drag_pointer = None;
+
+                if let Some((_, status)) = state.dragging.take() {
+                    if self.on_grab.is_some() || status.was_moved() {
+                        self.maybe_fire_on_release(shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = state.state.downcast_ref::<State>();
+        let plot = Plot::new(layout.bounds(), &self.axis);
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor
+            .position()
+            .and_then(|position| plot.handle_at(&self.transfer, position))
+            .is_some()
+        {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let hovered = cursor
+            .position()
+            .and_then(|position| Plot::new(bounds, &self.axis).handle_at(&self.transfer, position));
+
+        let (status, active_handle) = match state.dragging {
+            Some((handle, _)) => (Status::Dragged, Some(handle)),
+            None if cursor.is_over(bounds) => (Status::Hovered, hovered),
+            None => (Status::Active, None),
+        };
+
+        let appearance = theme.style(&self.class, status).dim(self.opacity);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.border_color,
+                    width: appearance.border_width,
+                    radius: Radius::new(appearance.border_radius),
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.back_color,
+        );
+
+        let inner = bounds.shrink(appearance.border_width);
+
+        if inner.width <= 0.0 || inner.height <= 0.0 {
+            return;
+        }
+
+        // Draw in a frame at the origin, and translate it into place.
+        let plot = Plot::new(
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                ..inner
+            },
+            &self.axis,
+        );
+
+        let mut frame = Frame::new(renderer, inner.size());
+
+        if let Some(grid) = self.grid {
+            grid_lines(
+                &mut frame,
+                grid.tier_1(),
+                appearance.grid_color,
+                &appearance,
+            );
+            grid_lines(
+                &mut frame,
+                grid.tier_2(),
+                appearance.grid_minor_color,
+                &appearance,
+            );
+            grid_lines(
+                &mut frame,
+                grid.tier_3(),
+                appearance.grid_minor_color,
+                &appearance,
+            );
+        }
+
+        frame.stroke(
+            &Path::line(Point::new(0.0, inner.height), Point::new(inner.width, 0.0)),
+            Stroke::default()
+                .with_color(appearance.unity_color)
+                .with_width(appearance.grid_width),
+        );
+
+        let curve = Path::new(|path| {
+            for i in 0..=CURVE_SEGMENTS {
+                let normal = Normal::from_clipped(i as f32 / CURVE_SEGMENTS as f32);
+                let point = plot.point(&self.transfer, self.axis.unmap_to_value(normal));
+
+                if i == 0 {
+                    path.move_to(point);
+                } else {
+                    path.line_to(point);
+                }
+            }
+        });
+
+        frame.stroke(
+            &curve,
+            Stroke::default()
+                .with_color(appearance.curve_color)
+                .with_width(appearance.curve_width),
+        );
+
+        for (handle, point) in plot.handles(&self.transfer) {
+            let circle = Path::circle(point, appearance.handle_radius);
+
+            let color = if active_handle == Some(handle) {
+                appearance.active_handle_color
+            } else {
+                appearance.handle_color
+            };
+
+            frame.fill(
+                &circle,
+                Fill {
+                    style: canvas::Style::Solid(color),
+                    ..Fill::default()
+                },
+            );
+
+            if appearance.handle_border_width > 0.0 {
+                frame.stroke(
+                    &circle,
+                    Stroke::default()
+                        .with_color(appearance.handle_border_color)
+                        .with_width(appearance.handle_border_width),
+                );
+            }
+        }
+
+        renderer.with_translation(Vector::new(inner.x, inner.y), |renderer| {
+            renderer.draw_geometry(frame.into_geometry());
+        });
+    }
+}
+
+/// Draws a vertical and a horizontal grid line at each of the `positions`.
+fn grid_lines<Renderer>(
+    frame: &mut Frame<Renderer>,
+    positions: Option<&[Normal]>,
+    color: iced::Color,
+    appearance: &Appearance,
+) where
+    Renderer: geometry::Renderer,
+{
+    let Some(positions) = positions else {
+        return;
+    };
+
+    let size = frame.size();
+    let stroke = Stroke::default()
+        .with_color(color)
+        .with_width(appearance.grid_width);
+
+    for position in positions {
+        let x = position.scale(size.width);
+        let y = position.scale_inv(size.height);
+
+        frame.stroke(
+            &Path::line(Point::new(x, 0.0), Point::new(x, size.height)),
+            stroke,
+        );
+        frame.stroke(
+            &Path::line(Point::new(0.0, y), Point::new(size.width, y)),
+            stroke,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CompressorCurve<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + renderer::Renderer + geometry::Renderer,
+{
+    fn from(compressor_curve: CompressorCurve<'a, Message, Theme>) -> Self {
+        Self::new(compressor_curve)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transfer;
+    use crate::test_utils::fixtures::{assert_close, Message, SIZE};
+    use crate::test_utils::Harness;
+    use crate::{
+        compressor_curve::{self, CompressorCurve, Handle},
+        LogDBRange, Normal,
+    };
+    use iced::{Point, Theme};
+
+    #[test]
+    fn transfer_output() {
+        let hard = Transfer::new(-20.0, 4.0, 0.0);
+
+        assert_eq!(hard.output(-40.0), -40.0);
+        assert_eq!(hard.output(-20.0), -20.0);
+        assert_eq!(hard.output(0.0), -15.0);
+
+        // The soft knee meets the straight lines at both of its ends, and
+        // passes below the threshold at its center.
+        let soft = Transfer::new(-20.0, 4.0, 10.0);
+
+        assert_eq!(soft.output(-25.0), -25.0);
+        assert!((soft.output(-15.0) - -18.75).abs() < 1e-5);
+        assert!(soft.output(-20.0) < -20.0);
+        assert_eq!(soft.output(0.0), -15.0);
+    }
+
+    #[test]
+    fn compressor_curve_handles() {
+        let axis = LogDBRange::new(-60.0, 0.0, Normal::MAX);
+        let transfer = Transfer::new(-20.0, 4.0, 0.0);
+        let x = |level| axis.map_to_normal(level).scale(SIZE.width);
+        let y = |level| axis.map_to_normal(level).scale_inv(SIZE.height);
+
+        let drag = |from: Point, to: Point| {
+            let curve = CompressorCurve::new(transfer, Message::ChangedCurve)
+                .axis(axis)
+                .style(|theme, status| compressor_curve::Appearance {
+                    border_width: 0.0,
+                    ..crate::style::compressor_curve::default(theme, status)
+                });
+            let mut harness: Harness<'_, Message, Theme> = Harness::new(curve, SIZE);
+            harness.drag(from, to);
+            harness.take_messages()
+        };
+        let changed = |messages: Vec<Message>| match messages.as_slice() {
+            [Message::ChangedCurve(handle, transfer)] => (*handle, *transfer),
+            _ => panic!("expected one change, got {messages:?}"),
+        };
+
+        // The threshold handle follows the input level under the cursor.
+        let (handle, dragged) = changed(drag(
+            Point::new(x(-20.0), y(-20.0)),
+            Point::new(x(-30.0), y(-20.0)),
+        ));
+        assert_eq!(handle, Handle::Threshold);
+        assert_close(dragged.threshold, -30.0);
+        assert_eq!(dragged.ratio, 4.0);
+
+        // The ratio handle sets the output level at the end of the axis.
+        let (handle, dragged) = changed(drag(
+            Point::new(x(0.0), y(-15.0)),
+            Point::new(x(0.0), y(-10.0)),
+        ));
+        assert_eq!(handle, Handle::Ratio);
+        assert_close(dragged.ratio, 2.0);
+
+        // The knee handle sits a little right of where the knee ends.
+        let (handle, dragged) = changed(drag(
+            Point::new(x(-20.0) + 16.0, y(-20.0)),
+            Point::new(x(-15.0) + 16.0, y(-20.0)),
+        ));
+        assert_eq!(handle, Handle::Knee);
+        assert_close(dragged.knee, 10.0);
+        assert_eq!(dragged.threshold, -20.0);
+
+        // Presses away from the handles are ignored.
+        assert!(drag(Point::new(10.0, 10.0), Point::new(50.0, 50.0)).is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+//! Drawing of the focus ring shared by the focusable widgets
+
+use iced::{
+    advanced::renderer::{self, Quad},
+    Background, Border, Color, Rectangle,
+};
+
+use crate::style::focus_ring::Appearance;
+
+/// Draws the focus ring around a widget drawn in `bounds`, which is a circle
+/// or an ellipse if `is_round`.
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    appearance: &Appearance,
+    is_round: bool,
+) where
+    Renderer: renderer::Renderer,
+{
+    if appearance.width <= 0.0 || appearance.color.a == 0.0 {
+        return;
+    }
+
+    let bounds = bounds.expand(appearance.offset + appearance.width);
+
+    let radius = if is_round {
+        bounds.width.min(bounds.height) / 2.0
+    } else {
+        appearance.border_radius
+    };
+
+    renderer.fill_quad(
+        Quad {
+            bounds,
+            border: Border {
+                color: appearance.color,
+                width: appearance.width,
+                radius: radius.into(),
+            },
+            ..Quad::default()
+        },
+        Background::Color(Color::TRANSPARENT),
+    );
+}
@@ -3,36 +3,85 @@
 //! [`NormalParam`]: ../core/normal_param/struct.Param.html
 
 mod draw;
-mod state;
 mod value_markers;
 
+#[cfg(feature = "a11y")]
+use crate::a11y::{self, accesskit};
 use crate::{
-    core::{ModulationRange, Normal, NormalParam, SliderStatus},
+    core::{
+        sensitivity::DEFAULT_PIXELS_PER_STEP, Change, Detents, DoubleClick, DragThreshold,
+        HitShape, ModifierMatch, ModulationRange, Normal, NormalParam, Param, ParamSnapshot,
+        SelectionGroup, SliderDirection, SliderSensitivity, ViewWindow,
+    },
+    defaults,
+    operation::{DragState, HandleState, ValueState},
+    style::Dim,
     text_marks, tick_marks,
+    widget::{
+        animation, focus_ring, marks_layout, value_text,
+        virtual_slider::{Axis, State, VirtualSlider},
+    },
 };
 use iced::{
     advanced::{
-        graphics::core::{event, keyboard, touch},
-        layout, mouse,
+        graphics::core::{event, keyboard},
+        image, layout, mouse,
         renderer::Style,
-        widget::{tree, Tree},
+        text,
+        widget::{tree, Id, Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
-    Element, Event, Length, Rectangle, Renderer, Size,
+    time::Duration,
+    Element, Event, Font, Length, Padding, Point, Rectangle, Size,
 };
-use state::State;
 use value_markers::ValueMarkers;
 
 pub use crate::style::h_slider::{
-    Appearance, ClassicAppearance, ClassicHandle, ClassicRail, ModRangeAppearance,
-    ModRangePlacement, RectAppearance, RectBipolarAppearance, StyleSheet, TextMarksAppearance,
-    TextureAppearance, TickMarksAppearance,
+    Appearance, Catalog, ClassicAppearance, ClassicHandle, ClassicRail, DefaultMarkerAppearance,
+    ModRangeAppearance, ModRangePlacement, RectAppearance, RectBipolarAppearance,
+    RectSplitAppearance, Status, StyleFn, StyleSheet, TextMarksAppearance, TextureAppearance,
+    TextureNotch, TickMarksAppearance,
 };
 
-static DEFAULT_HEIGHT: f32 = 14.0;
-static DEFAULT_SCALAR: f32 = 0.9575;
-static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
-static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+/// The default height of the [`HSlider`].
+///
+/// [`HSlider`]: struct.HSlider.html
+pub const DEFAULT_HEIGHT: f32 = 14.0;
+
+/// The width of the [`HSlider`] when its width is `Length::Shrink`.
+///
+/// [`HSlider`]: struct.HSlider.html
+pub const DEFAULT_SHRINK_LENGTH: f32 = 100.0;
+
+/// The default `scalar` of the [`HSlider`], i.e. how much the value changes
+/// when the mouse is dragged across the width of the slider.
+///
+/// [`HSlider`]: struct.HSlider.html
+pub const DEFAULT_SCALAR: f32 = 0.9575;
+
+/// The default `wheel_scalar` of the [`HSlider`].
+///
+/// [`HSlider`]: struct.HSlider.html
+pub const DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+
+/// The default `modifier_scalar` of the [`HSlider`].
+///
+/// [`HSlider`]: struct.HSlider.html
+pub const DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// The default [`SliderSensitivity`] of the [`HSlider`].
+///
+/// [`HSlider`]: struct.HSlider.html
+/// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+pub const DEFAULT_SENSITIVITY: SliderSensitivity = SliderSensitivity {
+    scalar: DEFAULT_SCALAR,
+    wheel_scalar: DEFAULT_WHEEL_SCALAR,
+    pixels_per_step: DEFAULT_PIXELS_PER_STEP,
+    modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+    modifier_keys: keyboard::Modifiers::CTRL,
+    modifier_match: ModifierMatch::Contains,
+    extra_modifiers: &[],
+};
 
 /// A horizontal slider GUI widget that controls a [`NormalParam`]
 ///
@@ -43,28 +92,57 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 #[allow(missing_debug_implementations)]
 pub struct HSlider<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     normal_param: NormalParam,
-    on_change: Box<dyn 'a + Fn(Normal) -> Message>,
+    on_change: Box<dyn 'a + Fn(Change) -> Message>,
     on_grab: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
     on_release: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
-    scalar: f32,
-    wheel_scalar: f32,
-    modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
+    spring_back: Option<Duration>,
+    reduced_motion: bool,
+    sensitivity: SliderSensitivity,
+    capture_wheel: bool,
+    double_click: DoubleClick,
+    drag_threshold: DragThreshold,
+    wheel_gesture: Option<Duration>,
+    horizontal_wheel_scalar: f32,
+    bipolar_center: Option<Normal>,
+    steps: Option<u32>,
+    free_modifier_keys: keyboard::Modifiers,
+    fine_drag_button: Option<mouse::Button>,
+    on_type_in: Option<Box<dyn 'a + Fn(String) -> Message>>,
+    drop_payload: Option<u64>,
+    on_drop: Option<Box<dyn 'a + Fn(u64) -> Message>>,
+    selection_group: Option<(&'a SelectionGroup, u64)>,
+    on_group_change: Option<Box<dyn 'a + Fn(ParamSnapshot<u64>) -> Message>>,
+    value_text: Option<Box<dyn 'a + Fn(Normal) -> String>>,
+    view_window: ViewWindow,
+    direction: SliderDirection,
     width: Length,
     height: Length,
-    style: <Theme as StyleSheet>::Style,
+    hit_padding: Padding,
+    id: Option<Id>,
+    #[cfg(feature = "a11y")]
+    name: Option<String>,
+    #[cfg(feature = "a11y")]
+    description: Option<String>,
+    #[cfg(feature = "a11y")]
+    announced_value: Option<Box<dyn 'a + Fn(Normal) -> String>>,
+    class: Theme::Class<'a>,
+    opacity: f32,
+    scale_factor: f32,
     tick_marks: Option<&'a tick_marks::Group>,
+    detents: Option<Detents>,
     text_marks: Option<&'a text_marks::Group>,
+    include_marks_in_layout: bool,
+    clickable_text_marks: bool,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
 }
 
 impl<'a, Message, Theme> HSlider<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     /// Creates a new [`HSlider`].
     ///
@@ -78,25 +156,91 @@ where
     where
         F: 'a + Fn(Normal) -> Message,
     {
+        let defaults = defaults::get();
+
         HSlider {
             normal_param,
-            on_change: Box::new(on_change),
+            on_change: Box::new(move |change: Change| on_change(change.normal)),
             on_grab: None,
             on_release: None,
-            scalar: DEFAULT_SCALAR,
-            wheel_scalar: DEFAULT_WHEEL_SCALAR,
-            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers::CTRL,
+            spring_back: None,
+            reduced_motion: defaults.reduced_motion,
+            sensitivity: defaults.h_slider_sensitivity,
+            capture_wheel: true,
+            double_click: defaults.double_click,
+            drag_threshold: defaults.drag_threshold,
+            wheel_gesture: defaults.wheel_gesture,
+            horizontal_wheel_scalar: DEFAULT_WHEEL_SCALAR,
+            bipolar_center: None,
+            steps: None,
+            free_modifier_keys: keyboard::Modifiers::SHIFT,
+            fine_drag_button: None,
+            on_type_in: None,
+            drop_payload: None,
+            on_drop: None,
+            selection_group: None,
+            on_group_change: None,
+            value_text: None,
+            view_window: ViewWindow::FULL,
+            direction: SliderDirection::Normal,
             width: Length::Fill,
-            height: Length::Fixed(DEFAULT_HEIGHT),
-            style: Default::default(),
+            height: Length::Fixed(defaults.h_slider_height),
+            hit_padding: Padding::ZERO,
+            id: None,
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            announced_value: None,
+            class: Theme::default(),
+            opacity: 1.0,
+            scale_factor: 1.0,
             tick_marks: None,
+            detents: None,
             text_marks: None,
+            include_marks_in_layout: false,
+            clickable_text_marks: false,
             mod_range_1: None,
             mod_range_2: None,
         }
     }
 
+    /// Creates a new [`HSlider`] from a [`Param`].
+    ///
+    /// Unlike `HSlider::new()`, `on_change` is called with the (snapped) value of
+    /// the parameter instead of a [`Normal`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Param`]: ../../core/param/trait.Param.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn from_param<P, F>(param: P, on_change: F) -> Self
+    where
+        P: 'a + Param,
+        F: 'a + Fn(P::Value) -> Message,
+    {
+        let normal_param = param.normal_param();
+
+        Self::new(normal_param, move |normal| {
+            on_change(param.unmap_to_value(normal))
+        })
+    }
+
+    /// Sets the message to produce when the [`HSlider`] is moved, replacing
+    /// the `on_change` function given to `HSlider::new()`.
+    ///
+    /// Unlike `on_change`, `on_change_full` receives a [`Change`] with the
+    /// previous value and whether the value was dragged, scrolled or reset.
+    /// This is useful to implement undo, or to treat wheel nudges
+    /// differently from drags.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Change`]: ../../core/struct.Change.html
+    pub fn on_change_full(mut self, on_change_full: impl 'a + Fn(Change) -> Message) -> Self {
+        self.on_change = Box::new(on_change_full);
+        self
+    }
+
     /// Sets the grab message of the [`HSlider`].
     /// This is called when the mouse grabs from the slider.
     ///
@@ -119,9 +263,89 @@ where
         self
     }
 
+    /// Makes the [`HSlider`] spring back to its default value over `duration`
+    /// when it is released, like the pitch wheel of a keyboard.
+    ///
+    /// The value moves back on its own, producing a change for each frame,
+    /// and the release message is produced once it is back at the default.
+    /// Grabbing the [`HSlider`] again stops it where it is.
+    ///
+    /// It doesn't spring back by default.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn spring_back(mut self, duration: Duration) -> Self {
+        self.spring_back = Some(duration);
+        self
+    }
+
+    /// Skips the animations of the [`HSlider`], so that a value that springs
+    /// back is back at the default on the next frame.
+    ///
+    /// The default is the `reduced_motion` of the [`Defaults`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    /// Sets the message to produce when a digit, a minus sign, or a decimal
+    /// point is typed while the [`HSlider`] has keyboard focus, like a host
+    /// starts entering a value when typing into a selected parameter.
+    ///
+    /// The message gets the typed text, e.g. to open a `text_input` that is
+    /// pre-filled with it. Other keys are left to other widgets.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_type_in(mut self, on_type_in: impl 'a + Fn(String) -> Message) -> Self {
+        self.on_type_in = Some(Box::new(on_type_in));
+        self
+    }
+
+    /// Makes the [`HSlider`] a drop target of a drag and drop of the
+    /// application, e.g. of a modulation source onto the parameter it
+    /// modulates.
+    ///
+    /// `payload` identifies what the application is dragging, or is `None`
+    /// while nothing is dragged. While a payload is over the [`HSlider`], it
+    /// is drawn with `Status::DropTarget`, and releasing the pointer over it
+    /// produces the message of `on_drop` with the payload.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_drop(mut self, payload: Option<u64>, on_drop: impl 'a + Fn(u64) -> Message) -> Self {
+        self.drop_payload = payload;
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Makes the [`HSlider`] the member with the given `id` of a
+    /// [`SelectionGroup`], e.g. to move several selected faders together.
+    ///
+    /// When the [`HSlider`] is a member of the `group`, moving it produces the
+    /// message of `on_group_change` with the new values of all members,
+    /// instead of its `on_change` message. A drag moves the members from
+    /// their values when it started, following the [`GroupMode`] of the
+    /// group.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`SelectionGroup`]: ../../core/struct.SelectionGroup.html
+    /// [`GroupMode`]: ../../core/enum.GroupMode.html
+    pub fn selection_group(
+        mut self,
+        group: &'a SelectionGroup,
+        id: u64,
+        on_group_change: impl 'a + Fn(ParamSnapshot<u64>) -> Message,
+    ) -> Self {
+        self.selection_group = Some((group, id));
+        self.on_group_change = Some(Box::new(on_group_change));
+        self
+    }
+
     /// Sets the width of the [`HSlider`].
     ///
-    /// The default height is `Length::Fill`.
+    /// The default width is `Length::Fill`. With `Length::Shrink`, the
+    /// [`HSlider`] is `100` pixels wide.
     ///
     /// [`HSlider`]: struct.HSlider.html
     pub fn width(mut self, width: Length) -> Self {
@@ -131,7 +355,8 @@ where
 
     /// Sets the height of the [`HSlider`].
     ///
-    /// The default height is `Length::Fixed(14)`.
+    /// The default height is `Length::Fixed(14)`, which is also used for
+    /// `Length::Shrink`.
     ///
     /// [`HSlider`]: struct.HSlider.html
     pub fn height(mut self, height: Length) -> Self {
@@ -139,11 +364,106 @@ where
         self
     }
 
-    /// Sets the style of the [`HSlider`].
+    /// Sets extra space around the [`HSlider`] that also reacts to the mouse
+    /// and to touches, without changing its size in the layout or how it is
+    /// drawn. There is no hit padding by default.
+    ///
+    /// This makes small controls easier to hit on touch screens. The padded
+    /// area may overlap neighbouring widgets.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn hit_padding(mut self, hit_padding: impl Into<Padding>) -> Self {
+        self.hit_padding = hit_padding.into();
+        self
+    }
+
+    /// Sets the [`Id`] of the [`HSlider`], e.g. to check whether it is being
+    /// dragged with [`operation::is_dragging()`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Id`]: https://docs.rs/iced/0.13/iced/advanced/widget/struct.Id.html
+    /// [`operation::is_dragging()`]: ../../operation/fn.is_dragging.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the name of the [`HSlider`] that screen readers announce, e.g. the
+    /// name of the parameter it controls.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    #[cfg(feature = "a11y")]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the [`HSlider`] that screen readers announce
+    /// after its name.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    #[cfg(feature = "a11y")]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets how screen readers announce the value of the [`HSlider`], e.g. as
+    /// `"-6 dB"` or `"440 Hz"` for the mapped value of its parameter, instead
+    /// of the raw [`Normal`]. Defaults to the `value_text()` format, if any.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/normal/struct.Normal.html
+    #[cfg(feature = "a11y")]
+    pub fn announced_value(mut self, format: impl 'a + Fn(Normal) -> String) -> Self {
+        self.announced_value = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the [`HSlider`] with a function of the theme and the
+    /// [`Status`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Status`]: ../../style/enum.Status.html
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`HSlider`], e.g. a custom [`StyleSheet`].
     ///
     /// [`HSlider`]: struct.HSlider.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`HSlider`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the sliders of a bypassed section. The default is `1.0`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the scale factor of the window the [`HSlider`] is drawn in, which
+    /// picks the version of the [`TextureHandle`]s of its style to draw. The
+    /// default is `1.0`.
+    ///
+    /// Values that aren't positive are ignored.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`TextureHandle`]: ../../style/texture/struct.TextureHandle.html
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        if scale_factor > 0.0 {
+            self.scale_factor = scale_factor;
+        }
         self
     }
 
@@ -153,7 +473,21 @@ where
     ///
     /// [`HSlider`]: struct.HSlider.html
     pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
+        self.sensitivity.modifier_keys = modifier_keys;
+        self
+    }
+
+    /// Sets the [`SliderSensitivity`] of the [`HSlider`], i.e. its `scalar`,
+    /// `wheel_scalar`, `pixels_per_step`, `modifier_scalar` and `modifier_keys`
+    /// at once.
+    ///
+    /// The default is [`DEFAULT_SENSITIVITY`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`SliderSensitivity`]: ../../core/struct.SliderSensitivity.html
+    /// [`DEFAULT_SENSITIVITY`]: constant.DEFAULT_SENSITIVITY.html
+    pub fn sensitivity(mut self, sensitivity: SliderSensitivity) -> Self {
+        self.sensitivity = sensitivity;
         self
     }
 
@@ -166,7 +500,7 @@ where
     ///
     /// [`HSlider`]: struct.HSlider.html
     pub fn scalar(mut self, scalar: f32) -> Self {
-        self.scalar = scalar;
+        self.sensitivity.scalar = scalar;
         self
     }
 
@@ -180,7 +514,86 @@ where
     /// [`HSlider`]: struct.HSlider.html
     /// [`Normal`]: ../../core/struct.Normal.html
     pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
-        self.wheel_scalar = wheel_scalar;
+        self.sensitivity.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value will change for the [`HSlider`] per line scrolled
+    /// to the right, e.g. with a horizontal swipe on a touchpad. Scrolling to the left
+    /// moves the value down.
+    ///
+    /// This can be set to `0.0` to leave horizontal scrolling to a scrollable parent.
+    ///
+    /// The default value is `0.01`
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn horizontal_wheel_scalar(mut self, wheel_scalar: f32) -> Self {
+        self.horizontal_wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how many pixels a pixel based scroll delta (e.g. from a
+    /// touchpad) has to add up to before the [`HSlider`] moves by one
+    /// `wheel_scalar` step.
+    ///
+    /// The default value is [`DEFAULT_PIXELS_PER_STEP`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`DEFAULT_PIXELS_PER_STEP`]: ../../core/sensitivity/constant.DEFAULT_PIXELS_PER_STEP.html
+    pub fn pixels_per_step(mut self, pixels_per_step: f32) -> Self {
+        self.sensitivity.pixels_per_step = pixels_per_step;
+        self
+    }
+
+    /// Sets whether the [`HSlider`] captures the wheel events that move it, so
+    /// a scrollable parent doesn't scroll at the same time. With `false`, the
+    /// wheel scrolls the parent instead of moving the [`HSlider`].
+    ///
+    /// Wheel events are always captured while the [`HSlider`] is dragged. The
+    /// default value is `true`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn capture_wheel(mut self, capture_wheel: bool) -> Self {
+        self.capture_wheel = capture_wheel;
+        self
+    }
+
+    /// Sets when two presses of the [`HSlider`] count as a double click, which
+    /// resets it to its default value.
+    ///
+    /// The default is the `double_click` of the [`Defaults`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn double_click(mut self, double_click: DoubleClick) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
+    /// Sets how far the pointer has to move from a press of the [`HSlider`]
+    /// before it drags the value, so that a click or a tap doesn't move it.
+    ///
+    /// The default is the `drag_threshold` of the [`Defaults`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn drag_threshold(mut self, drag_threshold: DragThreshold) -> Self {
+        self.drag_threshold = drag_threshold;
+        self
+    }
+
+    /// Groups consecutive wheel steps of the [`HSlider`] into one gesture, which
+    /// fires `on_grab` at the first step and `on_release` once the wheel is
+    /// idle for `idle_timeout`, e.g. for hosts that create an undo entry per
+    /// gesture. With `None`, every wheel step fires both.
+    ///
+    /// The default is the `wheel_gesture` of the [`Defaults`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn wheel_gesture(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.wheel_gesture = idle_timeout;
         self
     }
 
@@ -194,7 +607,7 @@ where
     ///
     /// [`HSlider`]: struct.HSlider.html
     pub fn modifier_scalar(mut self, scalar: f32) -> Self {
-        self.modifier_scalar = scalar;
+        self.sensitivity.modifier_scalar = scalar;
         self
     }
 
@@ -208,6 +621,20 @@ where
         self
     }
 
+    /// Makes the tick marks of the [`HSlider`] stick while it is dragged
+    /// slowly, so that their values are easy to hit without snapping to
+    /// them. It has no effect without [`tick_marks`].
+    ///
+    /// The detents are independent of the `steps`, which still snap the
+    /// value where it stops.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`tick_marks`]: #method.tick_marks
+    pub fn detents(mut self, detents: Detents) -> Self {
+        self.detents = Some(detents);
+        self
+    }
+
     /// Sets the text marks to display. Note your [`StyleSheet`] must
     /// also implement `text_marks_style(&self) -> Option<text_marks::Style>` for
     /// them to display (which the default style does).
@@ -218,6 +645,50 @@ where
         self
     }
 
+    /// Shows the value of the [`HSlider`] as text at its handle, formatted by
+    /// `format`, e.g. after mapping the [`Normal`] value back to the range
+    /// of its parameter.
+    ///
+    /// The text is placed by the `value_text_appearance()` of the style,
+    /// which shows it above the handle while the [`HSlider`] is hovered or
+    /// dragged by default. It is not included in the layout.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`Normal`]: ../../core/normal/struct.Normal.html
+    pub fn value_text(mut self, format: impl 'a + Fn(Normal) -> String) -> Self {
+        self.value_text = Some(Box::new(format));
+        self
+    }
+
+    /// Sets whether the layout of the [`HSlider`] makes room for the tick and
+    /// text marks that its style places outside of it, so that they are not
+    /// clipped by its container or drawn over its neighbors.
+    ///
+    /// The room is added around the given width and height. The marks are
+    /// styled by the theme, so the room is measured when the [`HSlider`] is
+    /// drawn, and it is laid out again on the next event. The default is
+    /// `false`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn include_marks_in_layout(mut self, include: bool) -> Self {
+        self.include_marks_in_layout = include;
+        self
+    }
+
+    /// Sets whether clicking the label of a text mark moves the [`HSlider`] to
+    /// the position of that mark, turning the labels into buttons that set
+    /// the value quickly.
+    ///
+    /// The change is reported like any other, with a [`ChangeSource`] of
+    /// `MarkClick`. The default is `false`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`ChangeSource`]: ../../core/enum.ChangeSource.html
+    pub fn clickable_text_marks(mut self, clickable: bool) -> Self {
+        self.clickable_text_marks = clickable;
+        self
+    }
+
     /// Sets a [`ModulationRange`] to display. Note your [`StyleSheet`] must
     /// also implement `mod_range_style(&self) -> Option<ModRangeStyle>` for
     /// them to display.
@@ -240,43 +711,123 @@ where
         self
     }
 
-    fn move_virtual_slider(&mut self, state: &mut State, mut normal_delta: f32) -> SliderStatus {
-        if normal_delta.abs() < f32::EPSILON {
-            return SliderStatus::Unchanged;
-        }
+    /// Sets the value to be considered the center of the [`HSlider`]. It is
+    /// `Normal::CENTER` by default.
+    ///
+    /// The filled portion of [`RectBipolarAppearance`] starts there, and the
+    /// handle takes its center color there, so that asymmetric ranges
+    /// (e.g. -12 dB to +6 dB) are drawn from their zero. The origin line of
+    /// [`RectSplitAppearance`] is drawn there as well.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`RectBipolarAppearance`]: ../../style/h_slider/struct.RectBipolarAppearance.html
+    /// [`RectSplitAppearance`]: ../../style/h_slider/struct.RectSplitAppearance.html
+    pub fn bipolar_center(mut self, bipolar_center: Normal) -> Self {
+        self.bipolar_center = Some(bipolar_center);
+        self
+    }
 
-        if state.pressed_modifiers.contains(self.modifier_keys) {
-            normal_delta *= self.modifier_scalar;
-        }
+    /// Snaps the [`HSlider`] to `steps` equal steps between the minimum and
+    /// the maximum value while it is dragged or scrolled, with every wheel
+    /// step moving it by one step.
+    ///
+    /// Holding the [`free_modifier_keys`] bypasses the steps for free
+    /// continuous adjustment.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`free_modifier_keys`]: #method.free_modifier_keys
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = Some(steps);
+        self
+    }
 
-        self.normal_param
-            .value
-            .set_clipped(state.continuous_normal - normal_delta);
-        state.continuous_normal = self.normal_param.value.as_f32();
+    /// Sets the modifier keys that bypass the [`steps`] of the [`HSlider`]
+    /// while held.
+    ///
+    /// The default modifier key is `Shift`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`steps`]: #method.steps
+    pub fn free_modifier_keys(mut self, free_modifier_keys: keyboard::Modifiers) -> Self {
+        self.free_modifier_keys = free_modifier_keys;
+        self
+    }
 
-        SliderStatus::Moved
+    /// Sets a mouse button that always drags the [`HSlider`] finely, by the
+    /// `modifier_scalar`, without holding the modifier keys. The left button
+    /// keeps dragging normally, unless it is the one set here.
+    ///
+    /// There is no fine drag button by default.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn fine_drag_button(mut self, button: mouse::Button) -> Self {
+        self.fine_drag_button = Some(button);
+        self
     }
 
-    fn maybe_fire_on_grab(&mut self, shell: &mut Shell<'_, Message>) {
-        if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
-            shell.publish(message);
-        }
+    /// Zooms the [`HSlider`] into a sub-range of its parameter, which is then
+    /// shown over the whole [`HSlider`]. Dragging and scrolling move the value
+    /// across the window, which makes it easier to edit precisely, and keep
+    /// it inside the window.
+    ///
+    /// The tick marks, text marks and modulation ranges are zoomed with the
+    /// value. The window covers the whole range by default.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn view_window(mut self, view_window: ViewWindow) -> Self {
+        self.view_window = view_window;
+        self
     }
 
-    fn fire_on_change(&self, shell: &mut Shell<'_, Message>) {
-        shell.publish((self.on_change)(self.normal_param.value));
+    /// Sets the direction in which the value of the [`HSlider`] increases.
+    ///
+    /// With [`SliderDirection::Inverted`], the value increases to the left, e.g.
+    /// for attenuation controls or right-to-left layouts. Dragging,
+    /// scrolling, the filled portion, the marks and the modulation ranges
+    /// all follow the direction. The default is [`SliderDirection::Normal`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`SliderDirection::Inverted`]: ../../core/enum.SliderDirection.html#variant.Inverted
+    /// [`SliderDirection::Normal`]: ../../core/enum.SliderDirection.html#variant.Normal
+    pub fn direction(mut self, direction: SliderDirection) -> Self {
+        self.direction = direction;
+        self
     }
 
-    fn maybe_fire_on_release(&mut self, shell: &mut Shell<'_, Message>) {
-        if let Some(message) = self.on_release.as_mut().and_then(|on_release| on_release()) {
-            shell.publish(message);
+    fn virtual_slider(&mut self) -> VirtualSlider<'_, 'a, Message> {
+        VirtualSlider {
+            normal_param: &mut self.normal_param,
+            sensitivity: self.sensitivity,
+            on_change: &*self.on_change,
+            on_grab: self.on_grab.as_deref_mut(),
+            on_release: self.on_release.as_deref_mut(),
+            steps: self.steps,
+            free_modifier_keys: self.free_modifier_keys,
+            fine_drag_button: self.fine_drag_button,
+            double_click: self.double_click,
+            drag_threshold: self.drag_threshold,
+            detents: self.tick_marks.zip(self.detents),
+            on_type_in: self.on_type_in.as_deref(),
+            on_drop: self.drop_payload.zip(self.on_drop.as_deref()),
+            group: self.selection_group,
+            on_group_change: self.on_group_change.as_deref(),
+            view_window: self.view_window,
+            inverse: self.direction.is_inverted(),
+            capture_wheel: self.capture_wheel,
+            horizontal_wheel_scalar: self.horizontal_wheel_scalar,
+            spring_back: self
+                .spring_back
+                .map(|duration| animation::motion_duration(duration, self.reduced_motion)),
+            wheel_gesture: self.wheel_gesture,
+            hit_shape: HitShape::Rectangle,
         }
     }
 }
 
-impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for HSlider<'a, Message, Theme>
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for HSlider<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font>,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -288,18 +839,89 @@ where
 
     fn size(&self) -> Size<Length> {
         Size {
-            width: Length::Shrink,
+            width: self.width,
             height: self.height,
         }
     }
 
     fn layout(
         &self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         _renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+        let intrinsic_size = Size::new(DEFAULT_SHRINK_LENGTH, DEFAULT_HEIGHT);
+
+        if self.include_marks_in_layout {
+            let state = tree.state.downcast_mut::<State>();
+
+            return state
+                .marks_layout
+                .layout(limits, self.width, self.height, intrinsic_size);
+        }
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic_size))
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = state.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref());
+
+        operation.custom(
+            &mut DragState {
+                status: state.dragging_status,
+            },
+            self.id.as_ref(),
+        );
+
+        operation.custom(
+            &mut ValueState {
+                normal: self.normal_param.value,
+                default: self.normal_param.default,
+            },
+            self.id.as_ref(),
+        );
+
+        let body = marks_layout::body_bounds(layout);
+        // The handle is drawn where the value is shown in the view window.
+        let normal = self.view_window.to_view(self.normal_param.value).as_f32();
+        let normal = if self.direction.is_inverted() {
+            1.0 - normal
+        } else {
+            normal
+        };
+
+        operation.custom(
+            &mut HandleState {
+                center: Point::new(body.x + normal * body.width, body.center_y()),
+            },
+            self.id.as_ref(),
+        );
+
+        #[cfg(feature = "a11y")]
+        operation.custom(
+            &mut a11y::slider_node(
+                self.name.as_deref(),
+                self.description.as_deref(),
+                self.normal_param.value,
+                self.announced_value
+                    .as_ref()
+                    .or(self.value_text.as_ref())
+                    .map(|format| format(self.normal_param.value))
+                    .as_deref(),
+                (self.sensitivity.wheel_scalar > 0.0).then_some(self.sensitivity.wheel_scalar),
+                Some(accesskit::Orientation::Horizontal),
+                marks_layout::body_bounds(layout),
+            ),
+            self.id.as_ref(),
+        );
     }
 
     fn on_event(
@@ -314,161 +936,29 @@ where
         _viewport: &Rectangle,
     ) -> event::Status {
         let state = state.state.downcast_mut::<State>();
-
-        let is_over = cursor.is_over(layout.bounds());
-
-        // Update state after a discontinuity
-        if state.dragging_status.is_none() && state.prev_normal != self.normal_param.value {
-            state.prev_normal = self.normal_param.value;
-            state.continuous_normal = self.normal_param.value.as_f32();
+        let bounds = marks_layout::body_bounds(layout);
+        let hit_bounds = bounds.expand(self.hit_padding);
+        let axis = Axis::across_width(
+            &bounds,
+            self.sensitivity.scalar,
+            self.direction.is_inverted(),
+        );
+
+        if self.include_marks_in_layout {
+            state.marks_layout.on_event(&event, shell);
         }
 
-        match event {
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if state.dragging_status.is_some() {
-                    let bounds = layout.bounds();
-                    if bounds.width > 0.0 {
-                        let normal_delta =
-                            (position.x - state.prev_drag_x) / bounds.width * -self.scalar;
-
-                        state.prev_drag_x = if position.x <= bounds.x {
-                            bounds.x
-                        } else {
-                            position.x.min(bounds.x + bounds.width)
-                        };
-
-                        if self.move_virtual_slider(state, normal_delta).was_moved() {
-                            self.fire_on_change(shell);
-
-                            state
-                                .dragging_status
-                                .as_mut()
-                                .expect("dragging_status taken")
-                                .moved();
-                        }
-
-                        return event::Status::Captured;
-                    }
-                }
-            }
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                if self.wheel_scalar == 0.0 {
-                    return event::Status::Ignored;
-                }
-
-                if is_over {
-                    let lines = match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => y,
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            if y > 0.0 {
-                                1.0
-                            } else if y < 0.0 {
-                                -1.0
-                            } else {
-                                0.0
-                            }
-                        }
-                    };
-
-                    if lines != 0.0 {
-                        let normal_delta = -lines * self.wheel_scalar;
-
-                        if self.move_virtual_slider(state, normal_delta).was_moved() {
-                            if state.dragging_status.is_none() {
-                                self.maybe_fire_on_grab(shell);
-                            }
-
-                            self.fire_on_change(shell);
-
-                            if let Some(slider_status) = state.dragging_status.as_mut() {
-                                // Widget was grabbed => keep it grabbed
-                                slider_status.moved();
-                            } else {
-                                self.maybe_fire_on_release(shell);
-                            }
-                        }
-
-                        return event::Status::Captured;
-                    }
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if is_over {
-                    let click = mouse::Click::new(
-                        cursor.position().unwrap(),
-                        mouse::Button::Left,
-                        state.last_click,
-                    );
-
-                    match click.kind() {
-                        mouse::click::Kind::Single => {
-                            self.maybe_fire_on_grab(shell);
-
-                            state.dragging_status = Some(Default::default());
-                            state.prev_drag_x = cursor.position().unwrap().x;
-                        }
-                        _ => {
-                            // Reset to default
-
-                            let prev_dragging_status = state.dragging_status.take();
-
-                            if self.normal_param.value != self.normal_param.default {
-                                if prev_dragging_status.is_none() {
-                                    self.maybe_fire_on_grab(shell);
-                                }
-
-                                self.normal_param.value = self.normal_param.default;
-
-                                self.fire_on_change(shell);
-
-                                self.maybe_fire_on_release(shell);
-                            } else if prev_dragging_status.is_some() {
-                                self.maybe_fire_on_release(shell);
-                            }
-                        }
-                    }
-
-                    state.last_click = Some(click);
-
-                    return event::Status::Captured;
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                if let Some(slider_status) = state.dragging_status.take() {
-                    if self.on_grab.is_some() || slider_status.was_moved() {
-                        // maybe fire on release if `on_grab` is defined
-                        // so as to terminate the action, regardless of the actual user movement.
-                        self.maybe_fire_on_release(shell);
-                    }
-
-                    return event::Status::Captured;
-                }
-            }
-            Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::KeyReleased { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::ModifiersChanged(modifiers) => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-            },
-            _ => {}
+        if self.clickable_text_marks
+            && self
+                .virtual_slider()
+                .on_mark_click(state, &event, cursor, shell)
+                == event::Status::Captured
+        {
+            return event::Status::Captured;
         }
 
-        event::Status::Ignored
+        self.virtual_slider()
+            .on_event(state, &event, cursor, hit_bounds, axis, shell)
     }
 
     fn draw(
@@ -479,20 +969,26 @@ where
         _style: &Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let state = state.state.downcast_ref::<State>();
-        let bounds = layout.bounds();
-        let is_over = cursor.is_over(bounds);
+        let bounds = marks_layout::body_bounds(layout);
+        let is_over = cursor.is_over(bounds.expand(self.hit_padding));
 
-        let appearance = if state.dragging_status.is_some() {
-            theme.dragging(&self.style)
+        let status = if is_over && self.drop_payload.is_some() {
+            Status::DropTarget
+        } else if state.dragging_status.is_some() {
+            Status::Dragged
         } else if is_over {
-            theme.hovered(&self.style)
+            Status::Hovered
+        } else if state.is_focused {
+            Status::Focused
         } else {
-            theme.active(&self.style)
+            Status::Active
         };
 
+        let appearance = theme.style(&self.class, status).dim(self.opacity);
+
         let bounds = Rectangle {
             x: bounds.x.round(),
             y: bounds.y.round(),
@@ -500,66 +996,818 @@ where
             height: bounds.height.round(),
         };
 
-        let value_markers = ValueMarkers {
-            tick_marks: self.tick_marks,
-            text_marks: self.text_marks,
-            mod_range_1: self.mod_range_1,
-            mod_range_2: self.mod_range_2,
-            tick_marks_style: theme.tick_marks_appearance(&self.style),
-            text_marks_style: theme.text_marks_appearance(&self.style),
-            mod_range_style_1: theme.mod_range_appearance(&self.style),
-            mod_range_style_2: theme.mod_range_appearance_2(&self.style),
+        let window = self.view_window;
+        let tick_marks = self.tick_marks.map(|marks| window.tick_marks(marks));
+        let text_marks = self.text_marks.map(|marks| window.text_marks(marks));
+        let mod_range_1 = self
+            .mod_range_1
+            .map(|mod_range| window.mod_range(mod_range));
+        let mod_range_2 = self
+            .mod_range_2
+            .map(|mod_range| window.mod_range(mod_range));
+
+        let mut value_markers = ValueMarkers {
+            tick_marks: tick_marks.as_deref(),
+            text_marks: text_marks.as_deref(),
+            mod_range_1: mod_range_1.as_deref(),
+            mod_range_2: mod_range_2.as_deref(),
+            default: window
+                .contains(self.normal_param.default)
+                .then(|| window.to_view(self.normal_param.default)),
+            tick_marks_style: theme.tick_marks_appearance(&self.class),
+            text_marks_style: theme.text_marks_appearance(&self.class),
+            mod_range_style_1: theme.mod_range_appearance(&self.class),
+            mod_range_style_2: theme.mod_range_appearance_2(&self.class),
+            default_marker_style: theme.default_marker_appearance(&self.class),
+            text_mark_bounds: self.clickable_text_marks.then_some(&state.text_mark_bounds),
+            opacity: 1.0,
+            scale_factor: self.scale_factor,
         };
+        value_markers.dim(self.opacity);
+
+        if self.include_marks_in_layout {
+            state.marks_layout.drawn(
+                bounds.size(),
+                false,
+                value_markers
+                    .tick_marks
+                    .and(value_markers.tick_marks_style.as_ref())
+                    .map(|style| (&style.style, &style.placement)),
+                value_markers
+                    .text_marks
+                    .and(value_markers.text_marks_style.as_ref())
+                    .map(|style| (&style.style, &style.placement)),
+            );
+        }
+
+        value_markers.cull(&bounds, viewport);
 
-        let normal = self.normal_param.value;
+        let normal = window.to_view(self.normal_param.value);
+        let inverse = self.direction.is_inverted();
 
         match appearance {
             Appearance::Texture(style) => draw::texture_style(
                 renderer,
                 normal,
+                inverse,
                 &bounds,
                 style,
                 &value_markers,
                 //tick_marks_cache,
-                //text_marks_cache,
+                &state.text_marks_cache,
             ),
             Appearance::Classic(style) => draw::classic_style(
                 renderer,
                 normal,
+                inverse,
                 &bounds,
                 &style,
                 &value_markers,
                 //tick_marks_cache,
-                //text_marks_cache,
+                &state.text_marks_cache,
             ),
             Appearance::Rect(style) => draw::rect_style(
                 renderer,
                 normal,
+                inverse,
                 &bounds,
                 &style,
                 &value_markers,
                 //tick_marks_cache,
-                //text_marks_cache,
+                &state.text_marks_cache,
             ),
             Appearance::RectBipolar(style) => draw::rect_bipolar_style(
                 renderer,
                 normal,
+                window.to_view(self.bipolar_center.unwrap_or(Normal::CENTER)),
+                inverse,
                 &bounds,
                 &style,
                 &value_markers,
                 //tick_marks_cache,
-                //text_marks_cache,
+                &state.text_marks_cache,
+            ),
+            Appearance::RectSplit(style) => draw::rect_split_style(
+                renderer,
+                normal,
+                window.to_view(self.bipolar_center.unwrap_or(Normal::CENTER)),
+                inverse,
+                &bounds,
+                &style,
+                &value_markers,
+                //tick_marks_cache,
+                &state.text_marks_cache,
             ),
         };
+
+        if state.is_focused && state.focus_visible {
+            if let Some(focus_ring) = theme.focus_ring_appearance(&self.class) {
+                focus_ring::draw(renderer, bounds, &focus_ring.dim(self.opacity), false);
+            }
+        }
+
+        if let (Some(format), Some(text_style)) =
+            (&self.value_text, theme.value_text_appearance(&self.class))
+        {
+            let shown = if inverse {
+                Normal::from_clipped(normal.as_f32_inv())
+            } else {
+                normal
+            };
+
+            value_text::draw(
+                renderer,
+                &bounds,
+                bounds.x + shown.scale(bounds.width),
+                false,
+                format(self.normal_param.value),
+                &text_style.dim(self.opacity),
+                status,
+                viewport,
+            );
+        }
     }
 }
 
-impl<'a, Message, Theme> From<HSlider<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer> From<HSlider<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
+    Renderer: 'a + image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font>,
 {
     fn from(h_slider: HSlider<'a, Message, Theme>) -> Self {
         Self::new(h_slider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::fixtures::{
+        assert_close, changed, child_sizes, h_slider_snapshot, param, snapshot_path, wheel,
+        HSliderStyle, Message, BLUE, GRAY, LIGHT, ORANGE, SIZE,
+    };
+    use crate::test_utils::{Harness, Snapshot};
+    use crate::{
+        operation,
+        style::{h_slider, Status},
+        text_marks, tick_marks, ChangeSource, Detents, DragThreshold, GroupMode, HSlider, Normal,
+        Offset, SelectionGroup, SliderDirection, ViewWindow,
+    };
+    use iced::{
+        advanced::widget::Id,
+        event,
+        time::{Duration, Instant},
+        widget::{column, container, row, Space},
+        window, Color, Length, Padding, Point, Rectangle, Size, Theme, Vector,
+    };
+
+    #[test]
+    fn focus_ring() {
+        let slider = HSlider::new(param(0.5, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(20.0));
+        let mut harness = Harness::new(container(slider).padding(20), SIZE);
+
+        // The ring is drawn 2 pixels out from the slider, and is 2 pixels wide.
+        let has_ring = |harness: &mut Harness<'_, Message, Theme>| {
+            harness
+                .snapshot(&Theme::Light)
+                .pixel(17, 30)
+                .is_some_and(|color| color.a > 0.0)
+        };
+
+        assert!(!has_ring(&mut harness));
+
+        let _ = harness.operate(operation::focus_next::<()>());
+        assert!(has_ring(&mut harness));
+
+        // Clicking the slider keeps it focused, but hides the ring.
+        let _ = harness.move_cursor(Point::new(70.0, 30.0));
+        let _ = harness.press();
+        let _ = harness.release();
+        assert!(!has_ring(&mut harness));
+    }
+
+    #[test]
+    fn h_slider_drag() {
+        let h_slider: HSlider<'_, _, Theme> =
+            HSlider::new(param(0.5, 0.5), Message::Changed).width(Length::Fixed(100.0));
+        let mut harness = Harness::new(h_slider, SIZE);
+        let center = harness.center();
+
+        harness.drag(center, center + Vector::new(10.0, 0.0));
+
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 0.1 * 0.9575);
+    }
+
+    #[test]
+    fn h_slider_detents_hold_the_tick_marks() {
+        let detent = Normal::from_clipped(0.6);
+        let tick_marks = tick_marks::Group::from_normalized(&[(detent, tick_marks::Tier::One)]);
+        let h_slider: HSlider<'_, _, Theme> = HSlider::new(param(0.5, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .drag_threshold(DragThreshold::DISABLED)
+            .tick_marks(&tick_marks)
+            .detents(Detents {
+                stickiness: 0.08,
+                max_speed: f32::INFINITY,
+            });
+        let mut harness = Harness::new(h_slider, SIZE);
+        let center = harness.center();
+        let pixel = 0.01 * 0.9575;
+
+        let _ = harness.move_cursor(center);
+        let _ = harness.press();
+        let _ = harness.move_cursor(center + Vector::new(5.0, 0.0));
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 5.0 * pixel);
+
+        // Crossing the tick mark stops at it...
+        let _ = harness.move_cursor(center + Vector::new(15.0, 0.0));
+        assert_close(changed(&harness.take_messages())[0], 0.6);
+
+        // ...and the value doesn't move within the stickiness...
+        let _ = harness.move_cursor(center + Vector::new(16.0, 0.0));
+        assert!(harness.take_messages().is_empty());
+
+        // ...after which the drag continues from the tick mark.
+        let _ = harness.move_cursor(center + Vector::new(20.0, 0.0));
+        assert_close(
+            changed(&harness.take_messages())[0],
+            0.5 + 20.0 * pixel - 0.08,
+        );
+    }
+
+    #[test]
+    fn h_slider_hit_padding() {
+        let h_slider: HSlider<'_, _, Theme> = HSlider::new(param(0.5, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .hit_padding([10, 0]);
+        let mut harness = Harness::new(h_slider, SIZE);
+        assert_eq!(harness.bounds().height, 14.0);
+
+        // Below the slider, but inside of its hit padding.
+        let below = Point::new(50.0, 20.0);
+        harness.drag(below, below + Vector::new(10.0, 0.0));
+
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 0.1 * 0.9575);
+    }
+
+    #[test]
+    fn h_slider_clickable_text_marks() {
+        let text_marks = text_marks::Group::min_max("min", "max");
+        let h_slider = |clickable| {
+            HSlider::<_, Theme>::new(param(0.5, 0.5), Message::Changed)
+                .on_change_full(Message::ChangedFull)
+                .width(Length::Fixed(100.0))
+                .text_marks(&text_marks)
+                .clickable_text_marks(clickable)
+        };
+
+        // The labels are drawn below the slider, outside of its hit area.
+        let max_label = Point::new(90.0, 28.0);
+
+        let mut harness = Harness::new(h_slider(false), SIZE);
+        let _ = harness.snapshot(&Theme::Dark);
+        let _ = harness.move_cursor(max_label);
+        assert_eq!(harness.press(), event::Status::Ignored);
+        assert!(harness.take_messages().is_empty());
+
+        let mut harness = Harness::new(h_slider(true), SIZE);
+        let _ = harness.snapshot(&Theme::Dark);
+        let _ = harness.move_cursor(max_label);
+        assert_eq!(harness.press(), event::Status::Captured);
+
+        let messages = harness.take_messages();
+        assert_eq!(messages.len(), 1);
+        match &messages[0] {
+            Message::ChangedFull(change) => {
+                assert_eq!(change.normal, Normal::MAX);
+                assert_eq!(change.via, ChangeSource::MarkClick);
+            }
+            message => panic!("unexpected message {message:?}"),
+        }
+
+        // Between the labels nothing happens.
+        let _ = harness.release();
+        let _ = harness.move_cursor(Point::new(50.0, 28.0));
+        assert_eq!(harness.press(), event::Status::Ignored);
+    }
+
+    #[test]
+    fn h_slider_layout() {
+        let h_slider = || HSlider::<_, Theme>::new(param(0.5, 0.5), Message::Changed);
+
+        assert_eq!(
+            child_sizes(row![h_slider(), Space::with_width(50)]),
+            [Size::new(150.0, 14.0), Size::new(50.0, 0.0)]
+        );
+        assert_eq!(
+            child_sizes(column![
+                h_slider().height(Length::Fill),
+                Space::with_height(50)
+            ]),
+            [Size::new(200.0, 150.0), Size::new(0.0, 50.0)]
+        );
+        assert_eq!(
+            child_sizes(row![h_slider()
+                .width(Length::Shrink)
+                .height(Length::Shrink)]),
+            [Size::new(100.0, 14.0)]
+        );
+    }
+
+    #[test]
+    fn h_slider_horizontal_wheel() {
+        let h_slider: HSlider<'_, _, Theme> = HSlider::new(param(0.5, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .horizontal_wheel_scalar(0.05);
+        let mut harness = Harness::new(h_slider, SIZE);
+        let _ = harness.move_cursor(harness.center());
+
+        let _ = harness.event(wheel(-20.0, 0.0));
+        let _ = harness.event(wheel(0.0, 20.0));
+        let _ = harness.event(wheel(40.0, 0.0));
+
+        let changes = changed(&harness.take_messages());
+        assert_close(changes[0], 0.55);
+        assert_close(changes[1], 0.56);
+        assert_close(changes[2], 0.46);
+
+        // Without a scalar, horizontal scrolling is left to the parent.
+        let h_slider: HSlider<'_, _, Theme> =
+            HSlider::new(param(0.5, 0.5), Message::Changed).horizontal_wheel_scalar(0.0);
+        let mut harness = Harness::new(h_slider, SIZE);
+        let _ = harness.move_cursor(harness.center());
+
+        assert_eq!(harness.event(wheel(-20.0, 0.0)), event::Status::Ignored);
+        assert!(harness.take_messages().is_empty());
+    }
+
+    #[test]
+    fn h_slider_springs_back_after_release() {
+        let start = Instant::now();
+        let slider = HSlider::new(param(0.5, 0.5), Message::Changed)
+            .spring_back(Duration::from_millis(100))
+            .on_grab(|| Some(Message::Grabbed))
+            .on_release(|| Some(Message::Released));
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(slider, SIZE);
+
+        let center = harness.center();
+        harness.drag(center, center + Vector::new(50.0, 0.0));
+        let messages = harness.take_messages();
+        assert_eq!(messages.first(), Some(&Message::Grabbed));
+        assert_ne!(messages.last(), Some(&Message::Released));
+        let released_at = changed(&messages).last().copied().unwrap();
+        assert!(released_at > 0.5);
+
+        // The value moves back on the frames the slider requests, without
+        // any message from the application.
+        assert_eq!(
+            harness.redraw(start),
+            Some(window::RedrawRequest::NextFrame)
+        );
+        assert_eq!(
+            harness.redraw(start + Duration::from_millis(50)),
+            Some(window::RedrawRequest::NextFrame)
+        );
+        let halfway = changed(&harness.take_messages());
+        assert!(halfway.len() == 1 && halfway[0] > 0.5 && halfway[0] < released_at);
+
+        assert_eq!(harness.redraw(start + Duration::from_millis(100)), None);
+        assert_eq!(
+            harness.take_messages(),
+            vec![
+                Message::Changed(Normal::from_clipped(0.5)),
+                Message::Released
+            ]
+        );
+
+        // Grabbing it while it springs back stops it, within the same
+        // gesture.
+        harness.drag(
+            center + Vector::new(10.0, 0.0),
+            center + Vector::new(50.0, 0.0),
+        );
+        let _ = harness.take_messages();
+        let later = start + Duration::from_secs(1);
+        let _ = harness.redraw(later);
+        let _ = harness.redraw(later + Duration::from_millis(50));
+        let _ = harness.take_messages();
+        let _ = harness.press();
+        assert_eq!(harness.redraw(later + Duration::from_millis(100)), None);
+        assert!(!harness
+            .take_messages()
+            .iter()
+            .any(|message| matches!(message, Message::Grabbed | Message::Released)));
+    }
+
+    #[test]
+    fn h_slider_snapshots() {
+        h_slider_snapshot(None).assert_golden(snapshot_path("h_slider_classic"));
+
+        h_slider_snapshot(Some(h_slider::Appearance::Rect(h_slider::RectAppearance {
+            back_color: LIGHT,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: GRAY,
+            filled_color: BLUE,
+            handle_color: GRAY,
+            handle_width: 4,
+            handle_filled_gap: 1.0,
+        })))
+        .assert_golden(snapshot_path("h_slider_rect"));
+
+        h_slider_snapshot(Some(rect_bipolar()))
+            .assert_golden(snapshot_path("h_slider_rect_bipolar"));
+    }
+
+    fn rect_bipolar() -> h_slider::Appearance {
+        h_slider::Appearance::RectBipolar(h_slider::RectBipolarAppearance {
+            back_color: LIGHT,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: GRAY,
+            left_filled_color: ORANGE,
+            right_filled_color: BLUE,
+            handle_left_color: ORANGE,
+            handle_right_color: BLUE,
+            handle_center_color: GRAY,
+            handle_width: 4,
+            handle_filled_gap: 1.0,
+        })
+    }
+
+    #[test]
+    fn h_slider_bipolar_center() {
+        let h_slider = HSlider::new(param(0.3, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(14.0))
+            .bipolar_center(Normal::from_clipped(0.2))
+            .class(HSliderStyle(rect_bipolar()));
+        let snapshot = Harness::new(h_slider, SIZE).snapshot(&Theme::Light);
+
+        // The value is right of the center, so it is filled from the center
+        // up to the handle, and nothing is filled up to the middle.
+        let rgba = |x| snapshot.pixel(x, 7).map(Color::into_rgba8);
+        assert_eq!(rgba(25), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(29), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(40), Some(LIGHT.into_rgba8()));
+    }
+
+    #[test]
+    fn h_slider_rect_split() {
+        let style = h_slider::Appearance::RectSplit(h_slider::RectSplitAppearance {
+            back_color: LIGHT,
+            back_border_width: 1.0,
+            back_border_radius: 2.0,
+            back_border_color: GRAY,
+            negative_filled_color: ORANGE,
+            positive_filled_color: BLUE,
+            handle_negative_color: ORANGE,
+            handle_positive_color: BLUE,
+            origin_color: GRAY,
+            origin_width: 2.0,
+            filled_radius: 2.0,
+            handle_width: 4,
+            handle_filled_gap: 1.0,
+        });
+        let h_slider = HSlider::new(param(0.3, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(14.0))
+            .class(HSliderStyle(style));
+        let snapshot = Harness::new(h_slider, SIZE).snapshot(&Theme::Light);
+
+        // The value is left of the origin line in the middle, so it is filled
+        // with the negative color from the handle up to the line.
+        let rgba = |x| snapshot.pixel(x, 7).map(Color::into_rgba8);
+        assert_eq!(rgba(30), Some(ORANGE.into_rgba8()));
+        assert_eq!(rgba(40), Some(ORANGE.into_rgba8()));
+        assert_eq!(rgba(49), Some(GRAY.into_rgba8()));
+        assert_eq!(rgba(55), Some(LIGHT.into_rgba8()));
+    }
+
+    #[test]
+    fn h_slider_value_text() {
+        let h_slider = HSlider::<_, Theme>::new(param(0.5, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .value_text(|normal| format!("{:.0}", normal.as_f32() * 100.0));
+        let h_slider = container(h_slider).padding(Padding {
+            top: 20.0,
+            ..Padding::ZERO
+        });
+        let mut harness = Harness::<'_, Message, Theme>::new(h_slider, SIZE);
+
+        // The value is shown above the handle while the slider is hovered.
+        let has_text = |snapshot: &Snapshot| {
+            (40..60).any(|x| (4..18).any(|y| snapshot.pixel(x, y).is_some_and(|c| c.a > 0.0)))
+        };
+        assert!(!has_text(&harness.snapshot(&Theme::Light)));
+
+        let _ = harness.move_cursor(Point::new(50.0, 27.0));
+        assert!(has_text(&harness.snapshot(&Theme::Light)));
+    }
+
+    #[cfg(feature = "a11y")]
+    #[test]
+    fn h_slider_access_node_announces_the_value_text() {
+        use crate::a11y;
+
+        let value_text = |normal: Normal| format!("{:.0} %", normal.as_f32() * 100.0);
+        let h_slider = HSlider::<_, Theme>::new(param(0.5, 0.5), Message::Changed);
+        let mut harness = Harness::new(h_slider.value_text(value_text), SIZE);
+
+        // The announced value falls back to the value text...
+        let entries = harness.operate(a11y::nodes()).unwrap();
+        assert_eq!(entries[0].node.value(), Some("50 %"));
+
+        // ...unless it is set on its own.
+        let h_slider = HSlider::<_, Theme>::new(param(0.5, 0.5), Message::Changed)
+            .value_text(value_text)
+            .announced_value(|_| "0 dB".to_string());
+        harness.rebuild(h_slider);
+        let entries = harness.operate(a11y::nodes()).unwrap();
+        assert_eq!(entries[0].node.value(), Some("0 dB"));
+    }
+
+    #[test]
+    fn h_slider_drop_target() {
+        let statuses = std::cell::RefCell::new(Vec::new());
+        let h_slider = |payload| {
+            HSlider::<_, Theme>::new(param(0.5, 0.5), Message::Changed)
+                .width(Length::Fixed(100.0))
+                .on_drop(payload, Message::Dropped)
+                .style(|theme, status| {
+                    statuses.borrow_mut().push(status);
+                    h_slider::default(theme, status)
+                })
+        };
+
+        // Releasing a payload over the slider drops it, without moving the
+        // value.
+        let mut harness = Harness::new(h_slider(Some(7)), SIZE);
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.snapshot(&Theme::Light);
+        assert_eq!(harness.release(), event::Status::Captured);
+        assert_eq!(harness.take_messages(), vec![Message::Dropped(7)]);
+        assert_eq!(statuses.take(), vec![Status::DropTarget]);
+
+        let _ = harness.move_cursor(Point::new(150.0, 7.0));
+        let _ = harness.snapshot(&Theme::Light);
+        assert_eq!(harness.release(), event::Status::Ignored);
+        assert!(harness.take_messages().is_empty());
+        assert_eq!(statuses.take(), vec![Status::Active]);
+
+        // Without a payload, the slider is only hovered.
+        let mut harness = Harness::new(h_slider(None), SIZE);
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.snapshot(&Theme::Light);
+        let _ = harness.release();
+        assert!(harness.take_messages().is_empty());
+        assert_eq!(statuses.take(), vec![Status::Hovered]);
+    }
+
+    #[test]
+    fn h_slider_selection_group() {
+        let mut group = SelectionGroup::new(GroupMode::Relative);
+        group.insert(0, Normal::from_clipped(0.5));
+        group.insert(1, Normal::from_clipped(0.9));
+        let h_slider = |id| {
+            HSlider::<_, Theme>::new(param(0.5, 0.5), Message::Changed)
+                .width(Length::Fixed(100.0))
+                .selection_group(&group, id, Message::Grouped)
+        };
+
+        // The other member stops at the top, and gets its offset back when
+        // the drag moves back.
+        let mut harness = Harness::new(h_slider(0), SIZE);
+        let center = harness.center();
+        let _ = harness.move_cursor(center);
+        let _ = harness.press();
+        let _ = harness.move_cursor(center + Vector::new(30.0, 0.0));
+        let _ = harness.move_cursor(center - Vector::new(10.0, 0.0));
+        let _ = harness.release();
+
+        let messages = harness.take_messages();
+        assert_eq!(messages.len(), 2);
+        let Message::Grouped(up) = &messages[0] else {
+            panic!("expected a group change, got {:?}", messages[0]);
+        };
+        assert_eq!(up.get(&1), Some(Normal::MAX));
+        let Message::Grouped(back) = &messages[1] else {
+            panic!("expected a group change, got {:?}", messages[1]);
+        };
+        assert_close(back.get(&0).unwrap().as_f32(), 0.5 - 0.1 * 0.9575);
+        assert_close(back.get(&1).unwrap().as_f32(), 0.9 - 0.1 * 0.9575);
+
+        // A widget that is not a member moves on its own.
+        let mut harness = Harness::new(h_slider(2), SIZE);
+        harness.drag(center, center + Vector::new(10.0, 0.0));
+        assert_eq!(changed(&harness.take_messages()).len(), 1);
+    }
+
+    #[test]
+    fn h_slider_inverted() {
+        let h_slider = |value| {
+            HSlider::<_, Theme>::new(param(value, 0.5), Message::Changed)
+                .width(Length::Fixed(100.0))
+                .direction(SliderDirection::Inverted)
+        };
+
+        // Dragging and scrolling to the right move the value down.
+        let mut harness = Harness::new(h_slider(0.5), SIZE);
+        let center = harness.center();
+        harness.drag(center, center + Vector::new(10.0, 0.0));
+        assert_close(changed(&harness.take_messages())[0], 0.5 - 0.1 * 0.9575);
+
+        let mut harness = Harness::new(h_slider(0.5), SIZE);
+        let _ = harness.move_cursor(center);
+        let _ = harness.scroll(1.0);
+        assert_close(changed(&harness.take_messages())[0], 0.49);
+
+        // The handle is shown right of the middle, and the filled portion is
+        // mirrored to its left.
+        let h_slider = h_slider(0.3).class(HSliderStyle(h_slider::Appearance::Rect(
+            h_slider::RectAppearance {
+                back_color: LIGHT,
+                back_border_width: 1.0,
+                back_border_radius: 2.0,
+                back_border_color: GRAY,
+                filled_color: BLUE,
+                handle_color: GRAY,
+                handle_width: 4,
+                handle_filled_gap: 1.0,
+            },
+        )));
+        let snapshot = Harness::new(h_slider, SIZE).snapshot(&Theme::Light);
+
+        let rgba = |x| snapshot.pixel(x, 7).map(Color::into_rgba8);
+        assert_eq!(rgba(30), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(67), Some(GRAY.into_rgba8()));
+        assert_eq!(rgba(85), Some(LIGHT.into_rgba8()));
+    }
+
+    #[test]
+    fn h_slider_view_window() {
+        let window = ViewWindow::new(Normal::from_clipped(0.25), Normal::from_clipped(0.75));
+        let h_slider = |value| {
+            HSlider::<_, Theme>::new(param(value, 0.5), Message::Changed)
+                .width(Length::Fixed(100.0))
+                .view_window(window)
+        };
+
+        // Dragging moves across the window, which is half of the range.
+        let mut harness = Harness::new(h_slider(0.5), SIZE);
+        let center = harness.center();
+        harness.drag(center, center + Vector::new(10.0, 0.0));
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 0.05 * 0.9575);
+
+        // The value stays in the window.
+        let mut harness = Harness::new(h_slider(0.3), SIZE);
+        harness.drag(center, center - Vector::new(40.0, 0.0));
+        assert_close(changed(&harness.take_messages())[0], 0.25);
+    }
+
+    #[test]
+    fn h_slider_view_window_draw() {
+        let h_slider = HSlider::new(param(0.3, 0.5), Message::Changed)
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(14.0))
+            .bipolar_center(Normal::from_clipped(0.2))
+            .view_window(ViewWindow::new(
+                Normal::from_clipped(0.1),
+                Normal::from_clipped(0.5),
+            ))
+            .class(HSliderStyle(rect_bipolar()));
+        let snapshot = Harness::new(h_slider, SIZE).snapshot(&Theme::Light);
+
+        // The center is shown at a quarter and the value in the middle.
+        let rgba = |x| snapshot.pixel(x, 7).map(Color::into_rgba8);
+        assert_eq!(rgba(20), Some(LIGHT.into_rgba8()));
+        assert_eq!(rgba(30), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(45), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(60), Some(LIGHT.into_rgba8()));
+    }
+
+    #[test]
+    fn h_slider_handle_position_follows_the_view_window() {
+        let h_slider = HSlider::new(param(0.3, 0.5), Message::Changed)
+            .id(Id::new("h_slider"))
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(14.0))
+            .bipolar_center(Normal::from_clipped(0.2))
+            .view_window(ViewWindow::new(
+                Normal::from_clipped(0.1),
+                Normal::from_clipped(0.5),
+            ))
+            .class(HSliderStyle(rect_bipolar()));
+        let mut harness = Harness::new(h_slider, SIZE);
+
+        let center = harness
+            .operate(operation::handle_position(Id::new("h_slider")))
+            .flatten()
+            .unwrap();
+        assert_close(center.x, 50.0);
+
+        // The value is drawn up to the reported center.
+        let snapshot = harness.snapshot(&Theme::Light);
+        let rgba = |x: f32| snapshot.pixel(x as u32, 7).map(Color::into_rgba8);
+        assert_eq!(rgba(center.x - 5.0), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(center.x + 5.0), Some(LIGHT.into_rgba8()));
+    }
+
+    struct MarksOutsideStyle;
+
+    impl h_slider::StyleSheet for MarksOutsideStyle {
+        type Style = Theme;
+
+        fn appearance(&self, _style: &Self::Style, _status: Status) -> h_slider::Appearance {
+            rect_bipolar()
+        }
+
+        fn tick_marks_appearance(
+            &self,
+            _style: &Self::Style,
+        ) -> Option<h_slider::TickMarksAppearance> {
+            Some(h_slider::TickMarksAppearance {
+                style: tick_marks::Appearance {
+                    tier_1: tick_marks::Shape::Line {
+                        length: 6.0,
+                        width: 1.0,
+                        color: GRAY,
+                    },
+                    ..Default::default()
+                },
+                placement: tick_marks::Placement::LeftOrTop {
+                    offset: Offset::ZERO,
+                    inside: false,
+                },
+            })
+        }
+
+        fn text_marks_appearance(
+            &self,
+            _style: &Self::Style,
+        ) -> Option<h_slider::TextMarksAppearance> {
+            Some(h_slider::TextMarksAppearance {
+                style: text_marks::Appearance {
+                    bounds_width: 30,
+                    bounds_height: 14,
+                    ..Default::default()
+                },
+                placement: text_marks::Placement::RightOrBottom {
+                    inside: false,
+                    offset: Offset::new(0.0, 2.0),
+                },
+            })
+        }
+    }
+
+    #[test]
+    fn h_slider_marks_in_layout() {
+        let tick_marks = tick_marks::Group::evenly_spaced(3, tick_marks::Tier::One);
+        let text_marks = text_marks::Group::min_max_and_center("0", "10", "5");
+        let h_slider = |include| {
+            HSlider::new(param(0.5, 0.5), Message::Changed)
+                .width(Length::Fixed(100.0))
+                .height(Length::Fixed(14.0))
+                .tick_marks(&tick_marks)
+                .text_marks(&text_marks)
+                .include_marks_in_layout(include)
+                .class(MarksOutsideStyle)
+        };
+
+        let mut harness = Harness::new(h_slider(true), SIZE);
+
+        // The marks are only known once the slider is drawn...
+        assert_eq!(harness.bounds().size(), Size::new(100.0, 14.0));
+        assert_eq!(
+            harness.redraw(Instant::now()),
+            Some(window::RedrawRequest::NextFrame)
+        );
+
+        // ...and make room for themselves on the next event: the tick marks
+        // above, the text marks below, and half of the labels at the ends.
+        let _ = harness.snapshot(&Theme::Light);
+        let _ = harness.redraw(Instant::now());
+
+        assert_eq!(harness.bounds().size(), Size::new(130.0, 36.0));
+        let body = harness.layout().children().next().map(|body| body.bounds());
+        assert_eq!(
+            body,
+            Some(Rectangle::new(
+                Point::new(15.0, 6.0),
+                Size::new(100.0, 14.0)
+            ))
+        );
+
+        // Dragging still moves the value across the body.
+        harness.drag(Point::new(65.0, 13.0), Point::new(75.0, 13.0));
+        assert_eq!(changed(&harness.take_messages()).len(), 1);
+
+        let mut harness = Harness::new(h_slider(false), SIZE);
+        let _ = harness.snapshot(&Theme::Light);
+        let _ = harness.redraw(Instant::now());
+        assert_eq!(harness.bounds().size(), Size::new(100.0, 14.0));
+    }
+}
@@ -1,33 +1,54 @@
+use std::cell::RefCell;
+
 use iced::{
-    advanced::{image::Renderer as _, renderer::Quad, Renderer as _},
+    advanced::{
+        image,
+        renderer::{self, Quad},
+        text,
+    },
     border::Radius,
     widget::canvas::Image,
-    Border, Color, Rectangle, Renderer, Shadow,
+    Border, Color, Font, Rectangle, Shadow,
 };
 
 use crate::{
     core::{text_marks, tick_marks},
     style::h_slider::{
-        ClassicAppearance, ClassicRail, ModRangeAppearance, ModRangePlacement, RectAppearance,
-        RectBipolarAppearance, TextMarksAppearance, TextureAppearance, TickMarksAppearance,
+        ClassicAppearance, ClassicRail, DefaultMarkerAppearance, ModRangeAppearance,
+        ModRangePlacement, RectAppearance, RectBipolarAppearance, RectSplitAppearance,
+        TextMarksAppearance, TextureAppearance, TickMarksAppearance,
     },
-    widget::h_slider::ValueMarkers,
-    ModulationRange, Normal,
+    widget::{h_slider::ValueMarkers, marks_layout},
+    BipolarSide, ModulationRange, Normal,
 };
 
-fn markers(
+/// Returns where `normal` is shown along the slider, which is mirrored if
+/// the slider is `inverse`.
+fn shown(normal: Normal, inverse: bool) -> Normal {
+    if inverse {
+        Normal::from_clipped(normal.as_f32_inv())
+    } else {
+        normal
+    }
+}
+
+fn markers<Renderer>(
     renderer: &mut Renderer,
     mark_bounds: &Rectangle,
     mod_bounds: &Rectangle,
     value_markers: &ValueMarkers<'_>,
+    inverse: bool,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
     tick_marks(
         renderer,
         mark_bounds,
         value_markers.tick_marks,
         &value_markers.tick_marks_style,
+        inverse,
         //tick_marks_cache,
     );
     text_marks(
@@ -35,7 +56,9 @@ fn markers(
         mark_bounds,
         value_markers.text_marks,
         &value_markers.text_marks_style,
-        //text_marks_cache,
+        value_markers.text_mark_bounds,
+        inverse,
+        text_marks_cache,
     );
 
     modulation(
@@ -43,22 +66,34 @@ fn markers(
         mod_bounds,
         value_markers.mod_range_1,
         &value_markers.mod_range_style_1,
+        inverse,
     );
     modulation(
         renderer,
         mod_bounds,
         value_markers.mod_range_2,
         &value_markers.mod_range_style_2,
+        inverse,
+    );
+    default_marker(
+        renderer,
+        mod_bounds,
+        value_markers.default,
+        &value_markers.default_marker_style,
+        inverse,
     );
 }
 
-fn tick_marks(
+fn tick_marks<Renderer>(
     renderer: &mut Renderer,
     bounds: &Rectangle,
     tick_marks: Option<&tick_marks::Group>,
     tick_marks_style: &Option<TickMarksAppearance>,
+    inverse: bool,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-) {
+) where
+    Renderer: renderer::Renderer,
+{
     if let Some(tick_marks) = tick_marks {
         if let Some(style) = tick_marks_style {
             tick_marks::draw_horizontal_tick_marks(
@@ -67,56 +102,127 @@ fn tick_marks(
                 tick_marks,
                 &style.style,
                 &style.placement,
-                false,
+                inverse,
                 //tick_marks_cache,
             )
         }
     }
 }
 
-fn text_marks(
+fn text_marks<Renderer>(
     renderer: &mut Renderer,
     bounds: &Rectangle,
     text_marks: Option<&text_marks::Group>,
     text_marks_style: &Option<TextMarksAppearance>,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
-    if let Some(text_marks) = text_marks {
-        if let Some(style) = text_marks_style {
-            text_marks::draw_horizontal_text_marks(
-                renderer,
+    text_mark_bounds: Option<&RefCell<Vec<(Normal, Rectangle)>>>,
+    inverse: bool,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let marks = text_marks.zip(text_marks_style.as_ref());
+
+    if let Some(text_mark_bounds) = text_mark_bounds {
+        *text_mark_bounds.borrow_mut() = marks.map_or_else(Vec::new, |(text_marks, style)| {
+            text_marks::horizontal_text_mark_bounds(
                 bounds,
                 text_marks,
                 &style.style,
                 &style.placement,
-                false,
-                //text_marks_cache,
+                inverse,
             )
+        });
+    }
+
+    if let Some((text_marks, style)) = marks {
+        text_marks::draw_horizontal_text_marks(
+            renderer,
+            bounds,
+            text_marks,
+            &style.style,
+            &style.placement,
+            inverse,
+            text_marks_cache,
+        )
+    }
+}
+
+/// Returns the bounds that the marks, mod ranges and default marker in
+/// `value_markers` are drawn in around a slider with the given `bounds`.
+pub fn value_markers_bounds(bounds: &Rectangle, value_markers: &ValueMarkers<'_>) -> Rectangle {
+    let marks_bounds = marks_layout::marks_bounds(
+        bounds,
+        false,
+        value_markers
+            .tick_marks
+            .and(value_markers.tick_marks_style.as_ref())
+            .map(|style| (&style.style, &style.placement)),
+        value_markers
+            .text_marks
+            .and(value_markers.text_marks_style.as_ref())
+            .map(|style| (&style.style, &style.placement)),
+    );
+
+    // The default marker is centered on its position, so it reaches half of
+    // its width past the ends of the slider.
+    let spans = [
+        value_markers
+            .mod_range_1
+            .and(value_markers.mod_range_style_1.as_ref())
+            .map(|style| (&style.placement, 0.0)),
+        value_markers
+            .mod_range_2
+            .and(value_markers.mod_range_style_2.as_ref())
+            .map(|style| (&style.placement, 0.0)),
+        value_markers
+            .default
+            .and(value_markers.default_marker_style.as_ref())
+            .map(|style| (&style.placement, style.width / 2.0)),
+    ];
+
+    spans
+        .into_iter()
+        .flatten()
+        .fold(marks_bounds, |markers_bounds, (placement, overhang)| {
+            let (y, height) = placement_span(bounds, placement);
+
+            markers_bounds.union(&Rectangle {
+                x: bounds.x - overhang,
+                y,
+                width: bounds.width + (overhang * 2.0),
+                height,
+            })
+        })
+}
+
+/// Returns the `y` and the height of a mod range or default marker with
+/// the given `placement`.
+fn placement_span(bounds: &Rectangle, placement: &ModRangePlacement) -> (f32, f32) {
+    match *placement {
+        ModRangePlacement::Center { height, offset } => {
+            (bounds.y + offset + ((bounds.height - height) / 2.0), height)
         }
+        ModRangePlacement::CenterFilled { edge_padding } => (
+            bounds.y + edge_padding,
+            bounds.height - (edge_padding * 2.0),
+        ),
+        ModRangePlacement::Top { height, offset } => (bounds.y + offset - height, height),
+        ModRangePlacement::Bottom { height, offset } => (bounds.y + bounds.height + offset, height),
     }
 }
 
-fn modulation(
+fn modulation<Renderer>(
     renderer: &mut Renderer,
     bounds: &Rectangle,
     mod_range: Option<&ModulationRange>,
     style: &Option<ModRangeAppearance>,
-) {
+    inverse: bool,
+) where
+    Renderer: renderer::Renderer,
+{
     if let Some(mod_range) = mod_range {
         if let Some(style) = style {
-            let (y, height) = match style.placement {
-                ModRangePlacement::Center { height, offset } => {
-                    (bounds.y + offset + ((bounds.height - height) / 2.0), height)
-                }
-                ModRangePlacement::CenterFilled { edge_padding } => (
-                    bounds.y + edge_padding,
-                    bounds.height - (edge_padding * 2.0),
-                ),
-                ModRangePlacement::Top { height, offset } => (bounds.y + offset - height, height),
-                ModRangePlacement::Bottom { height, offset } => {
-                    (bounds.y + bounds.height + offset, height)
-                }
-            };
+            let (y, height) = placement_span(bounds, &style.placement);
 
             if let Some(back_color) = style.back_color {
                 renderer.fill_quad(
@@ -138,23 +244,21 @@ fn modulation(
                 );
             };
 
-            if mod_range.filled_visible && (mod_range.start.as_f32() != mod_range.end.as_f32()) {
-                let (start, end, color) = if mod_range.start.as_f32() < mod_range.end.as_f32() {
-                    (
-                        mod_range.start.as_f32(),
-                        mod_range.end.as_f32(),
-                        style.filled_color,
-                    )
+            if let Some((start, end)) = mod_range.filled() {
+                let color = if mod_range.is_inverse() {
+                    style.filled_inverse_color
                 } else {
-                    (
-                        mod_range.end.as_f32(),
-                        mod_range.start.as_f32(),
-                        style.filled_inverse_color,
-                    )
+                    style.filled_color
                 };
 
-                let start_offset = bounds.width * start;
-                let filled_width = (bounds.width * end) - start_offset;
+                let (start, end) = if inverse {
+                    (shown(end, inverse), shown(start, inverse))
+                } else {
+                    (start, end)
+                };
+
+                let start_offset = start.scale(bounds.width);
+                let filled_width = end.scale(bounds.width) - start_offset;
 
                 renderer.fill_quad(
                     Quad {
@@ -178,15 +282,55 @@ fn modulation(
     }
 }
 
-pub fn texture_style(
+fn default_marker<Renderer>(
+    renderer: &mut Renderer,
+    bounds: &Rectangle,
+    default: Option<Normal>,
+    style: &Option<DefaultMarkerAppearance>,
+    inverse: bool,
+) where
+    Renderer: renderer::Renderer,
+{
+    if let Some(default) = default {
+        if let Some(style) = style {
+            let (y, height) = placement_span(bounds, &style.placement);
+
+            let x = bounds.x + shown(default, inverse).scale(bounds.width) - (style.width / 2.0);
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x: x.round(),
+                        y,
+                        width: style.width,
+                        height,
+                    },
+                    border: Border {
+                        color: Color::TRANSPARENT,
+                        width: 0.0,
+                        radius: Radius::new(style.border_radius),
+                    },
+                    shadow: Shadow::default(),
+                },
+                style.color,
+            );
+        }
+    }
+}
+
+pub fn texture_style<Renderer>(
     renderer: &mut Renderer,
     normal: Normal,
+    inverse: bool,
     bounds: &Rectangle,
     style: TextureAppearance,
     value_markers: &ValueMarkers<'_>,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font>,
+{
+    let normal = shown(normal, inverse);
     let value_bounds = Rectangle {
         x: (bounds.x + (f32::from(style.handle_width) / 2.0)).round(),
         y: bounds.y,
@@ -199,14 +343,16 @@ pub fn texture_style(
         &value_bounds,
         &value_bounds,
         value_markers,
+        inverse,
         //tick_marks_cache,
-        //text_marks_cache,
+        text_marks_cache,
     );
 
     classic_rail(renderer, bounds, &style.rail);
 
     renderer.draw_image(
-        Image::from(&style.image_handle),
+        Image::from(style.image_handle.select(value_markers.scale_factor))
+            .opacity(value_markers.opacity),
         Rectangle {
             x: (value_bounds.x + style.image_bounds.x + normal.scale(value_bounds.width)).round(),
             y: (bounds.center_y() + style.image_bounds.y).round(),
@@ -216,15 +362,19 @@ pub fn texture_style(
     );
 }
 
-pub fn classic_style(
+pub fn classic_style<Renderer>(
     renderer: &mut Renderer,
     normal: Normal,
+    inverse: bool,
     bounds: &Rectangle,
     style: &ClassicAppearance,
     value_markers: &ValueMarkers<'_>,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font>,
+{
+    let normal = shown(normal, inverse);
     let handle_width = f32::from(style.handle.width);
 
     let value_bounds = Rectangle {
@@ -239,8 +389,9 @@ pub fn classic_style(
         &value_bounds,
         &value_bounds,
         value_markers,
+        inverse,
         //tick_marks_cache,
-        //text_marks_cache,
+        text_marks_cache,
     );
 
     classic_rail(renderer, bounds, &style.rail);
@@ -266,12 +417,24 @@ pub fn classic_style(
         style.handle.color,
     );
 
-    if style.handle.notch_width != 0.0 {
+    let notch_center_x = bounds.x + handle_offset + (handle_width / 2.0);
+
+    if let Some(notch) = &style.handle.notch_texture {
+        renderer.draw_image(
+            Image::from(notch.image_handle.select(value_markers.scale_factor))
+                .opacity(value_markers.opacity),
+            Rectangle {
+                x: (notch_center_x + notch.image_bounds.x).round(),
+                y: (bounds.center_y() + notch.image_bounds.y).round(),
+                width: notch.image_bounds.width,
+                height: notch.image_bounds.height,
+            },
+        );
+    } else if style.handle.notch_width != 0.0 {
         renderer.fill_quad(
             Quad {
                 bounds: Rectangle {
-                    x: (bounds.x + handle_offset + (handle_width / 2.0) - (notch_width / 2.0))
-                        .round(),
+                    x: (notch_center_x - (notch_width / 2.0)).round(),
                     y: bounds.y,
                     width: notch_width,
                     height: bounds.height,
@@ -279,7 +442,7 @@ pub fn classic_style(
                 border: Border {
                     color: Color::TRANSPARENT,
                     width: 0.0,
-                    radius: Radius::new(0.0),
+                    radius: Radius::new(style.handle.notch_radius),
                 },
                 shadow: Shadow::default(),
             },
@@ -288,15 +451,19 @@ pub fn classic_style(
     }
 }
 
-pub fn rect_style(
+pub fn rect_style<Renderer>(
     renderer: &mut Renderer,
     normal: Normal,
+    inverse: bool,
     bounds: &Rectangle,
     style: &RectAppearance,
     value_markers: &ValueMarkers<'_>,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let normal = shown(normal, inverse);
     let handle_width = f32::from(style.handle_width);
     let border_width = style.back_border_width;
     let twice_border_width = border_width * 2.0;
@@ -330,14 +497,22 @@ pub fn rect_style(
         .scale(value_bounds.width - twice_border_width)
         .round();
 
-    let filled_offset = handle_offset + handle_width + style.handle_filled_gap;
+    // The filled portion is on the other side of the handle when the slider
+    // is inverse.
+    let (filled_x, filled_width) = if inverse {
+        (bounds.x, handle_offset - style.handle_filled_gap)
+    } else {
+        let filled_offset = handle_offset + handle_width + style.handle_filled_gap;
+
+        (bounds.x + filled_offset, bounds.width - filled_offset)
+    };
 
     renderer.fill_quad(
         Quad {
             bounds: Rectangle {
-                x: bounds.x + filled_offset,
+                x: filled_x,
                 y: bounds.y,
-                width: bounds.width - filled_offset,
+                width: filled_width,
                 height: bounds.height,
             },
             border: Border {
@@ -373,20 +548,26 @@ pub fn rect_style(
         &value_bounds,
         bounds,
         value_markers,
+        inverse,
         //tick_marks_cache,
-        //text_marks_cache,
+        text_marks_cache,
     );
 }
 
-pub fn rect_bipolar_style(
+#[allow(clippy::too_many_arguments)]
+pub fn rect_bipolar_style<Renderer>(
     renderer: &mut Renderer,
     normal: Normal,
+    bipolar_center: Normal,
+    inverse: bool,
     bounds: &Rectangle,
     style: &RectBipolarAppearance,
     value_markers: &ValueMarkers<'_>,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
     let handle_width = f32::from(style.handle_width);
     let border_width = style.back_border_width;
     let twice_border_width = border_width * 2.0;
@@ -403,8 +584,9 @@ pub fn rect_bipolar_style(
         &value_bounds,
         bounds,
         value_markers,
+        inverse,
         //tick_marks_cache,
-        //text_marks_cache,
+        text_marks_cache,
     );
 
     renderer.fill_quad(
@@ -425,18 +607,35 @@ pub fn rect_bipolar_style(
         style.back_color,
     );
 
+    // The handle keeps the color of the side of the center that the value
+    // is on, and so do the filled portions when the slider is inverse.
+    let bipolar_side = normal.classify_bipolar(bipolar_center);
+    let (left_filled_color, right_filled_color) = if inverse {
+        (style.right_filled_color, style.left_filled_color)
+    } else {
+        (style.left_filled_color, style.right_filled_color)
+    };
+
+    let normal = shown(normal, inverse);
+    let bipolar_center = shown(bipolar_center, inverse);
+
     let handle_offset = normal
         .scale(value_bounds.width - twice_border_width)
         .round();
+    let center_offset = bipolar_center
+        .scale(value_bounds.width - twice_border_width)
+        .round()
+        + (handle_width / 2.0)
+        + border_width;
 
-    if normal.as_f32() < 0.5 {
+    if normal < bipolar_center {
         let filled_rect_offset = handle_offset + handle_width + style.handle_filled_gap;
         renderer.fill_quad(
             Quad {
                 bounds: Rectangle {
                     x: bounds.x + filled_rect_offset,
                     y: bounds.y,
-                    width: ((bounds.width / 2.0) - filled_rect_offset + twice_border_width).round(),
+                    width: (center_offset - filled_rect_offset + twice_border_width).round(),
                     height: bounds.height,
                 },
                 border: Border {
@@ -446,10 +645,10 @@ pub fn rect_bipolar_style(
                 },
                 shadow: Shadow::default(),
             },
-            style.left_filled_color,
+            left_filled_color,
         );
     } else {
-        let filled_rect_offset = (bounds.width / 2.0).round() - border_width;
+        let filled_rect_offset = center_offset.round() - border_width;
         renderer.fill_quad(
             Quad {
                 bounds: Rectangle {
@@ -466,18 +665,163 @@ pub fn rect_bipolar_style(
                 },
                 shadow: Shadow::default(),
             },
-            style.right_filled_color,
+            right_filled_color,
         );
     };
 
-    let handle_color = if normal.as_f32() > 0.499 && normal.as_f32() < 0.501 {
-        style.handle_center_color
-    } else if normal.as_f32() < 0.5 {
-        style.handle_left_color
+    let handle_color = match bipolar_side {
+        BipolarSide::Left => style.handle_left_color,
+        BipolarSide::Right => style.handle_right_color,
+        BipolarSide::Center => style.handle_center_color,
+    };
+
+    renderer.fill_quad(
+        Quad {
+            bounds: Rectangle {
+                x: bounds.x + handle_offset,
+                y: bounds.y,
+                width: handle_width + twice_border_width,
+                height: bounds.height,
+            },
+            border: Border {
+                color: Color::TRANSPARENT,
+                width: style.back_border_width,
+                radius: Radius::new(style.back_border_radius),
+            },
+            shadow: Shadow::default(),
+        },
+        handle_color,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn rect_split_style<Renderer>(
+    renderer: &mut Renderer,
+    normal: Normal,
+    bipolar_center: Normal,
+    inverse: bool,
+    bounds: &Rectangle,
+    style: &RectSplitAppearance,
+    value_markers: &ValueMarkers<'_>,
+    //tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let handle_width = f32::from(style.handle_width);
+    let border_width = style.back_border_width;
+    let twice_border_width = border_width * 2.0;
+
+    let value_bounds = Rectangle {
+        x: (bounds.x + (handle_width / 2.0)).round(),
+        y: bounds.y,
+        width: bounds.width - handle_width,
+        height: bounds.height,
+    };
+
+    markers(
+        renderer,
+        &value_bounds,
+        bounds,
+        value_markers,
+        inverse,
+        //tick_marks_cache,
+        text_marks_cache,
+    );
+
+    renderer.fill_quad(
+        Quad {
+            bounds: *bounds,
+            border: Border {
+                color: style.back_border_color,
+                width: style.back_border_width,
+                radius: Radius::new(style.back_border_radius),
+            },
+            shadow: Shadow::default(),
+        },
+        style.back_color,
+    );
+
+    // The colors follow the side of the center that the value is on, on
+    // whichever side an inverse slider shows it.
+    let (filled_color, handle_color) = match normal.classify_bipolar(bipolar_center) {
+        BipolarSide::Left => (style.negative_filled_color, style.handle_negative_color),
+        BipolarSide::Right => (style.positive_filled_color, style.handle_positive_color),
+        BipolarSide::Center => (Color::TRANSPARENT, style.origin_color),
+    };
+
+    let normal = shown(normal, inverse);
+    let bipolar_center = shown(bipolar_center, inverse);
+
+    let handle_offset = normal
+        .scale(value_bounds.width - twice_border_width)
+        .round();
+    let center_offset = bipolar_center
+        .scale(value_bounds.width - twice_border_width)
+        .round()
+        + (handle_width / 2.0)
+        + border_width;
+
+    // The filled portion runs from the origin to the handle, and only its
+    // end at the handle is rounded.
+    let radius = style.filled_radius;
+    let (filled_start, filled_end, filled_radius) = if normal < bipolar_center {
+        (
+            handle_offset + handle_width + twice_border_width + style.handle_filled_gap,
+            center_offset,
+            Radius {
+                top_left: radius,
+                bottom_left: radius,
+                ..Radius::default()
+            },
+        )
     } else {
-        style.handle_right_color
+        (
+            center_offset,
+            handle_offset - style.handle_filled_gap,
+            Radius {
+                top_right: radius,
+                bottom_right: radius,
+                ..Radius::default()
+            },
+        )
     };
 
+    if filled_end > filled_start {
+        renderer.fill_quad(
+            Quad {
+                bounds: Rectangle {
+                    x: bounds.x + filled_start,
+                    y: bounds.y + border_width,
+                    width: filled_end - filled_start,
+                    height: bounds.height - twice_border_width,
+                },
+                border: Border {
+                    radius: filled_radius,
+                    ..Border::default()
+                },
+                shadow: Shadow::default(),
+            },
+            filled_color,
+        );
+    }
+
+    if style.origin_width > 0.0 {
+        renderer.fill_quad(
+            Quad {
+                bounds: Rectangle {
+                    x: (bounds.x + center_offset - (style.origin_width / 2.0)).round(),
+                    y: bounds.y + border_width,
+                    width: style.origin_width,
+                    height: bounds.height - twice_border_width,
+                },
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            style.origin_color,
+        );
+    }
+
     renderer.fill_quad(
         Quad {
             bounds: Rectangle {
@@ -497,7 +841,10 @@ pub fn rect_bipolar_style(
     );
 }
 
-fn classic_rail(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail) {
+fn classic_rail<Renderer>(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail)
+where
+    Renderer: renderer::Renderer,
+{
     let (top_width, bottom_width) = style.rail_widths;
     let (top_color, bottom_color) = style.rail_colors;
 
@@ -508,6 +855,18 @@ fn classic_rail(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail
 
     let start_y = (bounds.y + ((bounds.height - full_width) / 2.0)).round();
 
+    // The ends are rounded by the outer corners of both halves of the rail.
+    let end_radius = |half_width: f32| {
+        if style.rounded_ends {
+            half_width.min(full_width / 2.0)
+        } else {
+            0.0
+        }
+    };
+
+    let top_radius = end_radius(top_width);
+    let bottom_radius = end_radius(bottom_width);
+
     renderer.fill_quad(
         Quad {
             bounds: Rectangle {
@@ -519,7 +878,11 @@ fn classic_rail(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail
             border: Border {
                 color: Color::TRANSPARENT,
                 width: 0.0,
-                radius: Radius::new(0.0),
+                radius: Radius {
+                    top_left: top_radius,
+                    top_right: top_radius,
+                    ..Radius::default()
+                },
             },
             shadow: Shadow::default(),
         },
@@ -537,7 +900,11 @@ fn classic_rail(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail
             border: Border {
                 color: Color::TRANSPARENT,
                 width: 0.0,
-                radius: Radius::new(0.0),
+                radius: Radius {
+                    bottom_right: bottom_radius,
+                    bottom_left: bottom_radius,
+                    ..Radius::default()
+                },
             },
             shadow: Shadow::default(),
         },
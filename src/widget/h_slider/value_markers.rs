@@ -1,15 +1,117 @@
+use std::cell::RefCell;
+
 use crate::{
-    style::h_slider::{ModRangeAppearance, TextMarksAppearance, TickMarksAppearance},
-    text_marks, tick_marks, ModulationRange,
+    style::{
+        h_slider::{
+            DefaultMarkerAppearance, ModRangeAppearance, TextMarksAppearance, TickMarksAppearance,
+        },
+        Dim,
+    },
+    text_marks, tick_marks, ModulationRange, Normal,
 };
+use iced::Rectangle;
 
 pub struct ValueMarkers<'a> {
     pub tick_marks: Option<&'a tick_marks::Group>,
     pub text_marks: Option<&'a text_marks::Group>,
     pub mod_range_1: Option<&'a ModulationRange>,
     pub mod_range_2: Option<&'a ModulationRange>,
+    pub default: Option<Normal>,
     pub tick_marks_style: Option<TickMarksAppearance>,
     pub text_marks_style: Option<TextMarksAppearance>,
     pub mod_range_style_1: Option<ModRangeAppearance>,
     pub mod_range_style_2: Option<ModRangeAppearance>,
+    pub default_marker_style: Option<DefaultMarkerAppearance>,
+    /// Where the bounds of the text marks are kept when they are drawn, if
+    /// they are clickable.
+    pub text_mark_bounds: Option<&'a RefCell<Vec<(Normal, Rectangle)>>>,
+    /// The opacity the textures of the slider are drawn with.
+    pub opacity: f32,
+    /// The scale factor the textures of the slider are picked for.
+    pub scale_factor: f32,
+}
+
+impl ValueMarkers<'_> {
+    /// Leaves out the markers when none of them can be seen in `viewport`,
+    /// e.g. when the slider is scrolled out of view.
+    pub fn cull(&mut self, bounds: &Rectangle, viewport: &Rectangle) {
+        if !super::draw::value_markers_bounds(bounds, self).intersects(viewport) {
+            self.tick_marks = None;
+            self.text_marks = None;
+            self.mod_range_1 = None;
+            self.mod_range_2 = None;
+            self.default = None;
+        }
+    }
+
+    /// Dims the styles of the markers and the textures of the slider to
+    /// `opacity`.
+    pub fn dim(&mut self, opacity: f32) {
+        self.tick_marks_style = self.tick_marks_style.take().dim(opacity);
+        self.text_marks_style = self.text_marks_style.take().dim(opacity);
+        self.mod_range_style_1 = self.mod_range_style_1.take().dim(opacity);
+        self.mod_range_style_2 = self.mod_range_style_2.take().dim(opacity);
+        self.default_marker_style = self.default_marker_style.take().dim(opacity);
+        self.opacity *= opacity;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueMarkers;
+    use crate::{
+        style::h_slider::{ModRangeAppearance, ModRangePlacement},
+        ModulationRange,
+    };
+    use iced::{Color, Rectangle};
+
+    fn value_markers(mod_range: &ModulationRange) -> ValueMarkers<'_> {
+        ValueMarkers {
+            tick_marks: None,
+            text_marks: None,
+            mod_range_1: Some(mod_range),
+            mod_range_2: None,
+            default: None,
+            tick_marks_style: None,
+            text_marks_style: None,
+            mod_range_style_1: Some(ModRangeAppearance {
+                placement: ModRangePlacement::Top {
+                    height: 4.0,
+                    offset: -2.0,
+                },
+                back_border_width: 0.0,
+                back_border_radius: 0.0,
+                back_border_color: Color::TRANSPARENT,
+                back_color: None,
+                filled_color: Color::WHITE,
+                filled_inverse_color: Color::WHITE,
+            }),
+            mod_range_style_2: None,
+            default_marker_style: None,
+            text_mark_bounds: None,
+            opacity: 1.0,
+            scale_factor: 1.0,
+        }
+    }
+
+    #[test]
+    fn markers_are_culled_only_when_none_of_them_can_be_seen() {
+        let mod_range = ModulationRange::default();
+        let bounds = Rectangle::new([0.0, 100.0].into(), [200.0, 20.0].into());
+
+        // Only the mod range above the slider is in view.
+        let mut markers = value_markers(&mod_range);
+        markers.cull(
+            &bounds,
+            &Rectangle::new([0.0, 0.0].into(), [200.0, 97.0].into()),
+        );
+        assert!(markers.mod_range_1.is_some());
+
+        let mut markers = value_markers(&mod_range);
+        markers.cull(
+            &bounds,
+            &Rectangle::new([0.0, 0.0].into(), [200.0, 90.0].into()),
+        );
+        assert!(markers.mod_range_1.is_none());
+    }
 }
@@ -0,0 +1,574 @@
+//! Input handling shared by the widgets
+
+use std::cell::Cell;
+
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+use iced::{
+    advanced::{mouse::click, Shell},
+    time::{Duration, Instant},
+    window, Point,
+};
+use iced::{mouse, touch, Event};
+
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+use crate::core::DoubleClick;
+
+/// What drags a widget: the mouse or one finger on a touchscreen.
+///
+/// The widgets remember the [`Pointer`] that started a drag and ignore the
+/// other pointers until it ends, so several widgets can be dragged with
+/// different fingers at the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Pointer {
+    Mouse,
+    Finger(touch::Finger),
+}
+
+impl Pointer {
+    /// Returns the [`Pointer`] that produced `event`, if any.
+    pub(crate) fn of(event: &Event) -> Option<Self> {
+        match event {
+            Event::Mouse(_) => Some(Self::Mouse),
+            Event::Touch(
+                touch::Event::FingerPressed { id, .. }
+                | touch::Event::FingerMoved { id, .. }
+                | touch::Event::FingerLifted { id, .. }
+                | touch::Event::FingerLost { id, .. },
+            ) => Some(Self::Finger(*id)),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the position of the finger for touch events, and `cursor`
+/// otherwise.
+///
+/// Touches on the web do not move the mouse cursor, so the widgets have to
+/// follow the finger themselves.
+pub(crate) fn event_cursor(event: &Event, cursor: mouse::Cursor) -> mouse::Cursor {
+    match event {
+        Event::Touch(touch::Event::FingerPressed { position, .. })
+        | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+            mouse::Cursor::Available(*position)
+        }
+        _ => cursor,
+    }
+}
+
+thread_local! {
+    /// The pointer of the press that is being handled and the address of the
+    /// [`PressClaim`] of the widget that started a drag with it, if any.
+    static PRESS_OWNER: Cell<Option<(Pointer, usize)>> = const { Cell::new(None) };
+}
+
+/// The claim of a widget on the press that started its drag, which is kept
+/// in the state of the widget.
+///
+/// All widgets in a row or column see every press, and widgets with hit
+/// padding may overlap, so the first widget that claims a press with
+/// [`claim_press`] keeps the others from being dragged by it as well.
+///
+/// The claim only lasts while the press is handled: any other event ends
+/// it, e.g. the release of the press, even if no widget handles that event.
+/// Dropping the state of the widget ends it as well, so it can't outlive a
+/// widget that leaves the tree mid-press, or be inherited by a widget whose
+/// state reuses the same address.
+#[derive(Debug, Default)]
+pub(crate) struct PressClaim {
+    /// Whether this widget claimed a press since it was created, so that
+    /// only those look at the claim when they are dropped.
+    has_claimed: Cell<bool>,
+}
+
+impl PressClaim {
+    fn address(&self) -> usize {
+        self as *const Self as usize
+    }
+}
+
+impl Clone for PressClaim {
+    /// A clone is another widget, which hasn't claimed anything yet.
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl Drop for PressClaim {
+    fn drop(&mut self) {
+        if self.has_claimed.get()
+            && PRESS_OWNER
+                .get()
+                .is_some_and(|(_, owner)| owner == self.address())
+        {
+            PRESS_OWNER.set(None);
+        }
+    }
+}
+
+/// Returns whether the press of `event` already started a drag on another
+/// widget of this crate than the one with the given `claim`.
+///
+/// Any event that is not a press ends the claim, see [`PressClaim`].
+pub(crate) fn is_press_claimed(event: &Event, claim: &PressClaim) -> bool {
+    let is_press = matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+    );
+
+    if !is_press {
+        PRESS_OWNER.set(None);
+        return false;
+    }
+
+    PRESS_OWNER.get().is_some_and(|(pointer, owner)| {
+        Pointer::of(event) == Some(pointer) && owner != claim.address()
+    })
+}
+
+/// Claims the press of `event` for the widget with the given `claim`, when
+/// it starts a drag.
+pub(crate) fn claim_press(event: &Event, claim: &PressClaim) {
+    if let Some(pointer) = Pointer::of(event) {
+        claim.has_claimed.set(true);
+        PRESS_OWNER.set(Some((pointer, claim.address())));
+    }
+}
+
+/// A press of a widget, to tell whether the next press is a double click.
+///
+/// Unlike `mouse::Click`, it follows the [`DoubleClick`] settings of the
+/// widget, which can leave out touches.
+///
+/// [`DoubleClick`]: ../../core/double_click/struct.DoubleClick.html
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Click {
+    position: Point,
+    button: mouse::Button,
+    is_touch: bool,
+    time: Instant,
+    is_double: bool,
+}
+
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+impl Click {
+    /// Tracks the press of `event` with `button` at `position`, which is a
+    /// double click if it follows the `previous` press closely enough.
+    pub(crate) fn new(
+        event: &Event,
+        position: Point,
+        button: mouse::Button,
+        previous: Option<Click>,
+        double_click: &DoubleClick,
+    ) -> Self {
+        let time = Instant::now();
+        let is_touch = matches!(event, Event::Touch(_));
+
+        let is_double = previous.is_some_and(|previous| {
+            previous.button == button
+                && previous.is_touch == is_touch
+                && double_click.is_double_click(
+                    previous.position.distance(position),
+                    time.saturating_duration_since(previous.time),
+                    is_touch,
+                )
+        });
+
+        Self {
+            position,
+            button,
+            is_touch,
+            time,
+            is_double,
+        }
+    }
+
+    /// Returns the kind of the press, where every press after the first of a
+    /// double click is a double click as well.
+    pub(crate) fn kind(&self) -> click::Kind {
+        if self.is_double {
+            click::Kind::Double
+        } else {
+            click::Kind::Single
+        }
+    }
+}
+
+/// Adds up the scroll deltas of a widget into whole steps.
+///
+/// A line based delta (e.g. from a mouse wheel) is one step per line. Pixel
+/// based deltas (e.g. from a touchpad) are much smaller and come in many
+/// events, so they are added up and make one step every `pixels_per_step`
+/// pixels, keeping the rest for the next event.
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct ScrollSteps {
+    pixels: f32,
+    pixels_x: f32,
+}
+
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+impl ScrollSteps {
+    /// Adds `delta`, returning the number of whole steps to move by, or
+    /// `None` if it doesn't scroll vertically.
+    pub(crate) fn add(&mut self, delta: mouse::ScrollDelta, pixels_per_step: f32) -> Option<f32> {
+        match delta {
+            mouse::ScrollDelta::Lines { y, .. } => add_lines(&mut self.pixels, y),
+            mouse::ScrollDelta::Pixels { y, .. } => {
+                add_pixels(&mut self.pixels, y, pixels_per_step)
+            }
+        }
+    }
+
+    /// Adds the horizontal part of `delta`, returning the number of whole
+    /// steps to move by, or `None` if it doesn't scroll horizontally.
+    ///
+    /// Scrolling to the right counts like scrolling up, i.e. the steps are
+    /// positive.
+    pub(crate) fn add_horizontal(
+        &mut self,
+        delta: mouse::ScrollDelta,
+        pixels_per_step: f32,
+    ) -> Option<f32> {
+        // A positive `x` reveals more content to the left.
+        match delta {
+            mouse::ScrollDelta::Lines { x, .. } => add_lines(&mut self.pixels_x, -x),
+            mouse::ScrollDelta::Pixels { x, .. } => {
+                add_pixels(&mut self.pixels_x, -x, pixels_per_step)
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+fn add_lines(pixels: &mut f32, lines: f32) -> Option<f32> {
+    *pixels = 0.0;
+
+    (lines != 0.0).then_some(lines)
+}
+
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+fn add_pixels(pixels: &mut f32, delta: f32, pixels_per_step: f32) -> Option<f32> {
+    if delta == 0.0 {
+        return None;
+    }
+
+    if pixels_per_step <= 0.0 {
+        return Some(delta.signum());
+    }
+
+    // Turning around starts over instead of first using up the pixels left
+    // over from the other direction.
+    if *pixels * delta < 0.0 {
+        *pixels = 0.0;
+    }
+
+    *pixels += delta;
+
+    let steps = (*pixels / pixels_per_step).trunc();
+    *pixels -= steps * pixels_per_step;
+
+    Some(steps)
+}
+
+/// Groups the wheel steps of a widget into one gesture, which ends once the
+/// wheel is idle for a while.
+///
+/// Hosts that create an undo entry per gesture then get one entry for a
+/// whole turn of the wheel instead of one per step.
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct WheelGesture {
+    /// When the gesture ends, if there is one.
+    end: Option<Instant>,
+}
+
+#[cfg(any(
+    feature = "arc_slider",
+    feature = "h_slider",
+    feature = "knob",
+    feature = "mod_range_input",
+    feature = "ramp",
+    feature = "v_slider",
+    feature = "xy_pad"
+))]
+impl WheelGesture {
+    /// Returns whether a gesture is going on.
+    #[cfg(any(
+        feature = "arc_slider",
+        feature = "h_slider",
+        feature = "knob",
+        feature = "mod_range_input",
+        feature = "ramp",
+        feature = "v_slider"
+    ))]
+    pub(crate) fn is_active(&self) -> bool {
+        self.end.is_some()
+    }
+
+    /// Continues the gesture with a wheel step, until the wheel is idle for
+    /// `timeout`, and returns whether the step starts a new gesture.
+    ///
+    /// The frame at the end is requested from the `shell`.
+    pub(crate) fn step<Message>(
+        &mut self,
+        timeout: Duration,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let is_new = self.end.is_none();
+        let end = Instant::now() + timeout;
+
+        self.end = Some(end);
+        shell.request_redraw(window::RedrawRequest::At(end));
+
+        is_new
+    }
+
+    /// Returns whether the gesture ended at the frame drawn at `now`, or
+    /// requests the frame at its end again from the `shell`, since an
+    /// earlier frame may have taken the place of that request.
+    pub(crate) fn redraw<Message>(&mut self, now: Instant, shell: &mut Shell<'_, Message>) -> bool {
+        match self.end {
+            Some(end) if now >= end => {
+                self.end = None;
+                true
+            }
+            Some(end) => {
+                shell.request_redraw(window::RedrawRequest::At(end));
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Ends the gesture early, e.g. when the widget is grabbed, and returns
+    /// whether there was one.
+    pub(crate) fn end(&mut self) -> bool {
+        self.end.take().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{claim_press, event_cursor, is_press_claimed, Pointer, PressClaim, ScrollSteps};
+    use iced::{mouse, touch, Event, Point};
+
+    #[test]
+    fn touch_moves_cursor() {
+        let position = Point::new(3.0, 4.0);
+        let finger = touch::Finger(0);
+
+        assert_eq!(
+            event_cursor(
+                &Event::Touch(touch::Event::FingerPressed {
+                    id: finger,
+                    position
+                }),
+                mouse::Cursor::Unavailable
+            ),
+            mouse::Cursor::Available(position)
+        );
+        assert_eq!(
+            event_cursor(
+                &Event::Touch(touch::Event::FingerLifted {
+                    id: finger,
+                    position
+                }),
+                mouse::Cursor::Unavailable
+            ),
+            mouse::Cursor::Unavailable
+        );
+    }
+
+    #[test]
+    fn pointer_tells_fingers_apart() {
+        let position = Point::new(3.0, 4.0);
+
+        assert_eq!(
+            Pointer::of(&Event::Touch(touch::Event::FingerMoved {
+                id: touch::Finger(1),
+                position
+            })),
+            Some(Pointer::Finger(touch::Finger(1)))
+        );
+        assert_ne!(
+            Pointer::of(&Event::Touch(touch::Event::FingerLifted {
+                id: touch::Finger(2),
+                position
+            })),
+            Pointer::of(&Event::Touch(touch::Event::FingerPressed {
+                id: touch::Finger(1),
+                position
+            }))
+        );
+        assert_eq!(
+            Pointer::of(&Event::Mouse(mouse::Event::CursorMoved { position })),
+            Some(Pointer::Mouse)
+        );
+    }
+
+    #[test]
+    fn scroll_steps_add_up_pixels() {
+        let mut scroll = ScrollSteps::default();
+        let pixels = |y| mouse::ScrollDelta::Pixels { x: 0.0, y };
+
+        assert_eq!(scroll.add(pixels(8.0), 20.0), Some(0.0));
+        assert_eq!(scroll.add(pixels(8.0), 20.0), Some(0.0));
+        assert_eq!(scroll.add(pixels(8.0), 20.0), Some(1.0));
+        assert_eq!(scroll.add(pixels(50.0), 20.0), Some(2.0));
+
+        // The 14 pixels left over are dropped when turning around.
+        assert_eq!(scroll.add(pixels(-10.0), 20.0), Some(0.0));
+        assert_eq!(scroll.add(pixels(-10.0), 20.0), Some(-1.0));
+
+        assert_eq!(scroll.add(pixels(0.0), 20.0), None);
+        assert_eq!(scroll.add(pixels(3.0), 0.0), Some(1.0));
+    }
+
+    #[test]
+    fn scroll_steps_count_lines() {
+        let mut scroll = ScrollSteps::default();
+
+        assert_eq!(
+            scroll.add(mouse::ScrollDelta::Lines { x: 0.0, y: 2.0 }, 20.0),
+            Some(2.0)
+        );
+        assert_eq!(
+            scroll.add(mouse::ScrollDelta::Lines { x: 1.0, y: 0.0 }, 20.0),
+            None
+        );
+    }
+
+    #[test]
+    fn scroll_steps_add_horizontal() {
+        let mut scroll = ScrollSteps::default();
+        let pixels = |x, y| mouse::ScrollDelta::Pixels { x, y };
+
+        // Scrolling to the right is negative in `x`, and counts as up.
+        assert_eq!(scroll.add_horizontal(pixels(-30.0, 0.0), 20.0), Some(1.0));
+        assert_eq!(scroll.add_horizontal(pixels(0.0, 30.0), 20.0), None);
+
+        // The axes add up their pixels separately.
+        assert_eq!(scroll.add(pixels(-15.0, 15.0), 20.0), Some(0.0));
+        assert_eq!(scroll.add_horizontal(pixels(-5.0, 0.0), 20.0), Some(0.0));
+        assert_eq!(scroll.add_horizontal(pixels(-5.0, 0.0), 20.0), Some(1.0));
+
+        assert_eq!(
+            scroll.add_horizontal(mouse::ScrollDelta::Lines { x: 2.0, y: 0.0 }, 20.0),
+            Some(-2.0)
+        );
+    }
+
+    #[test]
+    fn press_claims_end_with_the_press_or_the_widget() {
+        let press = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let release = Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+        let other = PressClaim::default();
+
+        // The states of widgets are boxed in the tree, so that the claims
+        // don't move when they are dropped.
+        let owner = Box::new(PressClaim::default());
+        claim_press(&press, &owner);
+        assert!(!is_press_claimed(&press, &owner));
+        assert!(is_press_claimed(&press, &other));
+
+        // A release ends the claim, even if only a widget that doesn't
+        // handle it sees it.
+        assert!(!is_press_claimed(&release, &other));
+        assert!(!is_press_claimed(&press, &other));
+
+        // So does a widget that leaves the tree mid-press.
+        claim_press(&press, &owner);
+        drop(owner);
+        assert!(!is_press_claimed(&press, &other));
+
+        // Another finger isn't claimed by the press of the mouse.
+        let owner = Box::new(PressClaim::default());
+        claim_press(&press, &owner);
+        let finger = Event::Touch(touch::Event::FingerPressed {
+            id: touch::Finger(1),
+            position: Point::ORIGIN,
+        });
+        assert!(!is_press_claimed(&finger, &other));
+        assert!(is_press_claimed(&press, &other));
+
+        // Dropping a widget without the claim keeps it.
+        drop(PressClaim::default());
+        let clone = owner.clone();
+        drop(clone);
+        assert!(is_press_claimed(&press, &other));
+    }
+}
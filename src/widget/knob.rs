@@ -2,40 +2,98 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
-mod bipolar_state;
 mod draw;
+mod endless;
 mod knob_info;
 mod state;
 mod value_markers;
 
+#[cfg(feature = "a11y")]
+use crate::a11y;
 use crate::{
-    core::{ModulationRange, Normal, NormalParam, SliderStatus},
+    core::{
+        sensitivity::DEFAULT_PIXELS_PER_STEP, Change, DoubleClick, DragClamp, DragThreshold,
+        HitShape, ModifierMatch, ModulationRange, Normal, NormalParam, Param, ParamSnapshot,
+        SelectionGroup, SliderSensitivity, SliderStatus, ViewWindow,
+    },
+    defaults,
+    operation::{DragState, HandleState, ValueState},
+    style::Dim,
     text_marks, tick_marks,
+    widget::{
+        animation, focus_ring, input,
+        virtual_slider::{Axis, PendingDrag, VirtualSlider},
+    },
 };
 use iced::{
     advanced::{
-        graphics::core::{event, keyboard, touch},
-        layout, mouse,
+        graphics::{
+            core::{event, keyboard, touch},
+            geometry,
+        },
+        image, layout, mouse,
         renderer::Style,
-        widget::{tree, Tree},
+        text,
+        widget::{tree, Id, Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
-    Element, Event, Length, Rectangle, Renderer, Size,
+    alignment,
+    time::Duration,
+    Color, Element, Event, Font, Length, Padding, Rectangle, Size, Vector,
 };
 use knob_info::KnobInfo;
 use state::State;
 use value_markers::ValueMarkers;
 
 pub use crate::style::knob::{
-    Appearance, ArcAppearance, ArcBipolarAppearance, CircleAppearance, CircleNotch, LineCap,
-    LineNotch, ModRangeArcAppearance, NotchShape, StyleLength, StyleSheet, TextMarksAppearance,
-    TickMarksAppearance, ValueArcAppearance,
+    Appearance, ArcAppearance, ArcBipolarAppearance, ArcEnds, ArcSegmentedAppearance, Catalog,
+    CircleAppearance, CircleNotch, CustomNotch, DefaultMarkerAppearance, EndDots, LineCap,
+    LineNotch, ModRangeArcAppearance, ModRangePlacement, ModSourcesArcAppearance, NotchInfo,
+    NotchShape, SegmentShape, Status, StyleFn, StyleLength, StyleSheet, TextMarksAppearance,
+    TextureNotch, TickMarksAppearance, TriangleNotch, ValueArcAppearance,
+};
+
+/// The default width and height of the [`Knob`].
+///
+/// [`Knob`]: struct.Knob.html
+pub const DEFAULT_SIZE: f32 = 30.0;
+
+/// The default `scalar` of the [`Knob`], i.e. how much the value changes per
+/// pixel the mouse is dragged vertically.
+///
+/// Touch screens are smaller and fingers less precise than a mouse, so the
+/// web (`wasm32`) needs less travel to sweep the whole range.
+///
+/// [`Knob`]: struct.Knob.html
+pub const DEFAULT_SCALAR: f32 = if cfg!(target_arch = "wasm32") {
+    0.0077
+} else {
+    0.00385
 };
 
-static DEFAULT_SIZE: f32 = 30.0;
-static DEFAULT_SCALAR: f32 = 0.00385;
-static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
-static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+/// The default `wheel_scalar` of the [`Knob`].
+///
+/// [`Knob`]: struct.Knob.html
+pub const DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+
+/// The default `modifier_scalar` of the [`Knob`].
+///
+/// [`Knob`]: struct.Knob.html
+pub const DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// The default [`SliderSensitivity`] of the [`Knob`].
+///
+/// [`Knob`]: struct.Knob.html
+/// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+pub const DEFAULT_SENSITIVITY: SliderSensitivity = SliderSensitivity {
+    scalar: DEFAULT_SCALAR,
+    wheel_scalar: DEFAULT_WHEEL_SCALAR,
+    pixels_per_step: DEFAULT_PIXELS_PER_STEP,
+    modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+    modifier_keys: keyboard::Modifiers::CTRL,
+    modifier_match: ModifierMatch::Contains,
+    extra_modifiers: &[],
+};
 
 /// A rotating knob GUI widget that controls a [`NormalParam`]
 ///
@@ -43,28 +101,92 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 #[allow(missing_debug_implementations)]
 pub struct Knob<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     normal_param: NormalParam,
-    size: Length,
-    on_change: Box<dyn 'a + Fn(Normal) -> Message>,
+    width: Length,
+    height: Length,
+    align_x: alignment::Horizontal,
+    align_y: alignment::Vertical,
+    content_fit: ContentFit,
+    hit_padding: Padding,
+    on_change: Box<dyn 'a + Fn(Change) -> Message>,
     on_grab: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
     on_release: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
-    scalar: f32,
-    wheel_scalar: f32,
-    modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
+    spring_back: Option<Duration>,
+    reduced_motion: bool,
+    on_mod_change: Option<Box<dyn 'a + Fn(Normal) -> Message>>,
+    mod_amount: Normal,
+    mod_modifier_keys: keyboard::Modifiers,
+    sensitivity: SliderSensitivity,
+    capture_wheel: bool,
+    double_click: DoubleClick,
+    drag_threshold: DragThreshold,
+    wheel_gesture: Option<Duration>,
     bipolar_center: Option<Normal>,
-    style: <Theme as StyleSheet>::Style,
+    steps: Option<u32>,
+    free_modifier_keys: keyboard::Modifiers,
+    fine_drag_button: Option<mouse::Button>,
+    on_type_in: Option<Box<dyn 'a + Fn(String) -> Message>>,
+    drop_payload: Option<u64>,
+    on_drop: Option<Box<dyn 'a + Fn(u64) -> Message>>,
+    selection_group: Option<(&'a SelectionGroup, u64)>,
+    on_group_change: Option<Box<dyn 'a + Fn(ParamSnapshot<u64>) -> Message>>,
+    id: Option<Id>,
+    #[cfg(feature = "a11y")]
+    name: Option<String>,
+    #[cfg(feature = "a11y")]
+    description: Option<String>,
+    #[cfg(feature = "a11y")]
+    announced_value: Option<Box<dyn 'a + Fn(Normal) -> String>>,
+    class: Theme::Class<'a>,
+    opacity: f32,
+    scale_factor: f32,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
     mod_range_2: Option<&'a ModulationRange>,
+    mod_sources: &'a [(ModulationRange, Color)],
+    display: bool,
+    drag_mode: DragMode,
+    on_delta: Option<Box<dyn 'a + Fn(f32) -> Message>>,
+    endless_position: f32,
+}
+
+/// How a [`Knob`] is drawn inside bounds that are not square.
+///
+/// [`Knob`]: struct.Knob.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ContentFit {
+    /// Draws a round knob as large as the shorter side of the bounds, placed
+    /// with `Knob::align_x()` and `Knob::align_y()`.
+    #[default]
+    Contain,
+    /// Stretches the knob into an ellipse that fills the bounds.
+    Stretch,
+}
+
+/// How dragging a [`Knob`] turns it.
+///
+/// [`Knob`]: struct.Knob.html
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DragMode {
+    /// Dragging up turns the knob up, by the `scalar` of its sensitivity for
+    /// every pixel.
+    #[default]
+    Vertical,
+    /// Dragging around the center of the knob turns it along, relative to
+    /// where the drag started.
+    ///
+    /// Crossing the gap at the bottom of the knob does not make it jump to
+    /// the other end. Instead, it stays at the end it reached until the
+    /// pointer comes back to it.
+    Circular,
 }
 
 impl<'a, Message, Theme> Knob<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     /// Creates a new [`Knob`].
     ///
@@ -78,25 +200,147 @@ where
     where
         F: 'a + Fn(Normal) -> Message,
     {
+        let defaults = defaults::get();
+
         Knob {
             normal_param,
-            size: Length::Fixed(DEFAULT_SIZE),
-            on_change: Box::new(on_change),
+            width: Length::Fixed(defaults.knob_size),
+            height: Length::Fixed(defaults.knob_size),
+            align_x: alignment::Horizontal::Center,
+            align_y: alignment::Vertical::Center,
+            content_fit: ContentFit::default(),
+            hit_padding: Padding::ZERO,
+            on_change: Box::new(move |change: Change| on_change(change.normal)),
             on_grab: None,
             on_release: None,
-            scalar: DEFAULT_SCALAR,
-            wheel_scalar: DEFAULT_WHEEL_SCALAR,
-            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers::CTRL,
+            spring_back: None,
+            reduced_motion: defaults.reduced_motion,
+            on_mod_change: None,
+            mod_amount: Normal::MIN,
+            mod_modifier_keys: keyboard::Modifiers::ALT,
+            sensitivity: defaults.knob_sensitivity,
+            capture_wheel: true,
+            double_click: defaults.double_click,
+            drag_threshold: defaults.drag_threshold,
+            wheel_gesture: defaults.wheel_gesture,
             bipolar_center: None,
-            style: Default::default(),
+            steps: None,
+            free_modifier_keys: keyboard::Modifiers::SHIFT,
+            fine_drag_button: None,
+            on_type_in: None,
+            drop_payload: None,
+            on_drop: None,
+            selection_group: None,
+            on_group_change: None,
+            id: None,
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            announced_value: None,
+            class: Theme::default(),
+            opacity: 1.0,
+            scale_factor: 1.0,
             tick_marks: None,
             text_marks: None,
             mod_range_1: None,
             mod_range_2: None,
+            mod_sources: &[],
+            display: false,
+            drag_mode: DragMode::default(),
+            on_delta: None,
+            endless_position: 0.0,
         }
     }
 
+    /// Creates a read-only [`Knob`] that displays `normal`, such as a gain
+    /// reduction meter drawn as a knob arc.
+    ///
+    /// It ignores all events and is never focused, so it is cheap to
+    /// rebuild with a new value every frame. Its tick marks are only drawn
+    /// again when they or the size of the [`Knob`] change.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn display(normal: Normal) -> Self {
+        let mut knob = Self::new(
+            NormalParam {
+                value: normal,
+                default: normal,
+            },
+            |_| unreachable!("a display knob never changes"),
+        );
+        knob.display = true;
+        knob
+    }
+
+    /// Creates an endless [`Knob`], like a relative encoder, that has no
+    /// minimum or maximum and can be turned around and around, e.g. to scrub
+    /// through audio or to send relative MIDI CC messages.
+    ///
+    /// Instead of a value, turning it produces the message of `on_delta`
+    /// with how far it was turned, which is positive when turned up (or
+    /// clockwise) and negative when turned down. Dragging by the `scalar` of
+    /// the sensitivity, a circular drag and the mouse wheel all report their
+    /// deltas in the same unit as they would move a [`Normal`] value, so
+    /// that one full circular turn is a delta of `1.0`.
+    ///
+    /// The notch is drawn at `position` turns from the top, so adding the
+    /// deltas to the position makes the notch follow a circular drag.
+    /// Arc styles draw a full track with a short spinner that ends at the
+    /// notch, and no marks, mod ranges or default marker are drawn. The
+    /// steps and the mod drag of the [`Knob`] are ignored, and double
+    /// clicking does not reset it.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn endless<F>(position: f32, on_delta: F) -> Self
+    where
+        F: 'a + Fn(f32) -> Message,
+    {
+        let mut knob = Self::new(NormalParam::default(), |_| {
+            unreachable!("an endless knob reports deltas instead")
+        });
+        knob.on_delta = Some(Box::new(on_delta));
+        knob.endless_position = position;
+        knob
+    }
+
+    /// Creates a new [`Knob`] from a [`Param`].
+    ///
+    /// Unlike `Knob::new()`, `on_change` is called with the (snapped) value of
+    /// the parameter instead of a [`Normal`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Param`]: ../../core/param/trait.Param.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn from_param<P, F>(param: P, on_change: F) -> Self
+    where
+        P: 'a + Param,
+        F: 'a + Fn(P::Value) -> Message,
+    {
+        let normal_param = param.normal_param();
+
+        Self::new(normal_param, move |normal| {
+            on_change(param.unmap_to_value(normal))
+        })
+    }
+
+    /// Sets the message to produce when the [`Knob`] is moved, replacing
+    /// the `on_change` function given to `Knob::new()`.
+    ///
+    /// Unlike `on_change`, `on_change_full` receives a [`Change`] with the
+    /// previous value and whether the value was dragged, scrolled or reset.
+    /// This is useful to implement undo, or to treat wheel nudges
+    /// differently from drags.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Change`]: ../../core/struct.Change.html
+    pub fn on_change_full(mut self, on_change_full: impl 'a + Fn(Change) -> Message) -> Self {
+        self.on_change = Box::new(on_change_full);
+        self
+    }
+
     /// Sets the grab message of the [`Knob`].
     /// This is called when the mouse grabs from the knob.
     ///
@@ -119,32 +363,314 @@ where
         self
     }
 
-    /// Sets the diameter of the [`Knob`]. The default size is
-    /// `Length::from(Length::Fixed(31))`.
+    /// Makes the [`Knob`] spring back to its default value over `duration`
+    /// when it is released, like the pitch wheel of a keyboard.
+    ///
+    /// The value moves back on its own, producing a change for each frame,
+    /// and the release message is produced once it is back at the default.
+    /// Grabbing the [`Knob`] again stops it where it is.
+    ///
+    /// It doesn't spring back by default.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn spring_back(mut self, duration: Duration) -> Self {
+        self.spring_back = Some(duration);
+        self
+    }
+
+    /// Skips the animations of the [`Knob`], so that a value that springs
+    /// back is back at the default on the next frame.
+    ///
+    /// The default is the `reduced_motion` of the [`Defaults`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    /// Sets the message to produce when a digit, a minus sign, or a decimal
+    /// point is typed while the [`Knob`] has keyboard focus, like a host
+    /// starts entering a value when typing into a selected parameter.
+    ///
+    /// The message gets the typed text, e.g. to open a `text_input` that is
+    /// pre-filled with it. Other keys are left to other widgets.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_type_in(mut self, on_type_in: impl 'a + Fn(String) -> Message) -> Self {
+        self.on_type_in = Some(Box::new(on_type_in));
+        self
+    }
+
+    /// Makes the [`Knob`] a drop target of a drag and drop of the
+    /// application, e.g. of a modulation source onto the parameter it
+    /// modulates.
+    ///
+    /// `payload` identifies what the application is dragging, or is `None`
+    /// while nothing is dragged. While a payload is over the [`Knob`], it
+    /// is drawn with `Status::DropTarget`, and releasing the pointer over it
+    /// produces the message of `on_drop` with the payload.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_drop(mut self, payload: Option<u64>, on_drop: impl 'a + Fn(u64) -> Message) -> Self {
+        self.drop_payload = payload;
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Makes the [`Knob`] the member with the given `id` of a
+    /// [`SelectionGroup`], e.g. to move several selected faders together.
+    ///
+    /// When the [`Knob`] is a member of the `group`, moving it produces the
+    /// message of `on_group_change` with the new values of all members,
+    /// instead of its `on_change` message. A drag moves the members from
+    /// their values when it started, following the [`GroupMode`] of the
+    /// group.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`SelectionGroup`]: ../../core/struct.SelectionGroup.html
+    /// [`GroupMode`]: ../../core/enum.GroupMode.html
+    pub fn selection_group(
+        mut self,
+        group: &'a SelectionGroup,
+        id: u64,
+        on_group_change: impl 'a + Fn(ParamSnapshot<u64>) -> Message,
+    ) -> Self {
+        self.selection_group = Some((group, id));
+        self.on_group_change = Some(Box::new(on_group_change));
+        self
+    }
+
+    /// Sets the modulation amount of the [`Knob`] and the message to produce
+    /// when the user edits it.
+    ///
+    /// While the mod modifier key is held down, dragging the knob moves the
+    /// modulation amount instead of the value. The modulation amount is the
+    /// [`Normal`] position the modulation reaches, and it is displayed as a
+    /// mod range arc from the current value to it (unless a
+    /// [`ModulationRange`] is set explicitly with `Knob::mod_range()`).
+    /// Double-clicking while holding the mod modifier key resets the
+    /// modulation amount to the current value.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    pub fn on_mod_change<F>(mut self, mod_amount: Normal, on_mod_change: F) -> Self
+    where
+        F: 'a + Fn(Normal) -> Message,
+    {
+        self.mod_amount = mod_amount;
+        self.on_mod_change = Some(Box::new(on_mod_change));
+        self
+    }
+
+    /// Sets the modifier keys that switch dragging the [`Knob`] to editing
+    /// the modulation amount. Only has an effect when `Knob::on_mod_change()`
+    /// is set.
+    ///
+    /// The default mod modifier key is `Alt`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn mod_modifier_keys(mut self, mod_modifier_keys: keyboard::Modifiers) -> Self {
+        self.mod_modifier_keys = mod_modifier_keys;
+        self
+    }
+
+    /// Sets the diameter of the [`Knob`], i.e. both its width and its height.
+    /// The default size is `Length::Fixed(30.0)`.
     ///
     /// [`Knob`]: struct.Knob.html
     pub fn size(mut self, size: Length) -> Self {
-        self.size = size;
+        self.width = size;
+        self.height = size;
+        self
+    }
+
+    /// Sets the width of the [`Knob`].
+    ///
+    /// The whole layout bounds react to the mouse, so a width larger than
+    /// the height gives the knob a wider hit area. How the knob is drawn
+    /// inside non-square bounds is set with `Knob::content_fit()`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Knob`].
+    ///
+    /// The whole layout bounds react to the mouse, so a height larger than
+    /// the width gives the knob a taller hit area. How the knob is drawn
+    /// inside non-square bounds is set with `Knob::content_fit()`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`Knob`] inside bounds that are
+    /// wider than they are tall. The default is `Center`.
+    ///
+    /// Only has an effect with [`ContentFit::Contain`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`ContentFit::Contain`]: enum.ContentFit.html#variant.Contain
+    pub fn align_x(mut self, align_x: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = align_x.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the [`Knob`] inside bounds that are
+    /// taller than they are wide. The default is `Center`.
+    ///
+    /// Only has an effect with [`ContentFit::Contain`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`ContentFit::Contain`]: enum.ContentFit.html#variant.Contain
+    pub fn align_y(mut self, align_y: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = align_y.into();
+        self
+    }
+
+    /// Sets how the [`Knob`] is drawn inside non-square bounds. The default
+    /// is [`ContentFit::Contain`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`ContentFit::Contain`]: enum.ContentFit.html#variant.Contain
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets extra space around the [`Knob`] that also reacts to the mouse
+    /// and to touches, without changing its size in the layout or how it is
+    /// drawn. There is no hit padding by default.
+    ///
+    /// This makes small controls easier to hit on touch screens. The padded
+    /// area may overlap neighbouring widgets.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn hit_padding(mut self, hit_padding: impl Into<Padding>) -> Self {
+        self.hit_padding = hit_padding.into();
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Knob`], e.g. to check whether it is being
+    /// dragged with [`operation::is_dragging()`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Id`]: https://docs.rs/iced/0.13/iced/advanced/widget/struct.Id.html
+    /// [`operation::is_dragging()`]: ../../operation/fn.is_dragging.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the name of the [`Knob`] that screen readers announce, e.g. the
+    /// name of the parameter it controls.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    #[cfg(feature = "a11y")]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the [`Knob`] that screen readers announce
+    /// after its name.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    #[cfg(feature = "a11y")]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets how screen readers announce the value of the [`Knob`], e.g. as
+    /// `"-6 dB"` or `"440 Hz"` for the mapped value of its parameter, instead
+    /// of the raw [`Normal`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    #[cfg(feature = "a11y")]
+    pub fn announced_value(mut self, format: impl 'a + Fn(Normal) -> String) -> Self {
+        self.announced_value = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the [`Knob`] with a function of the theme and the
+    /// [`Status`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Status`]: ../../style/enum.Status.html
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Knob`], e.g. a custom [`StyleSheet`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Knob`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the knobs of a bypassed section. The default is `1.0`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the scale factor of the window the [`Knob`] is drawn in, which
+    /// picks the version of the [`TextureHandle`]s of its style to draw. The
+    /// default is `1.0`.
+    ///
+    /// Values that aren't positive are ignored.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`TextureHandle`]: ../../style/texture/struct.TextureHandle.html
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        if scale_factor > 0.0 {
+            self.scale_factor = scale_factor;
+        }
         self
     }
 
-    /// Sets the style of the [`Knob`].
+    /// Sets the [`SliderSensitivity`] of the [`Knob`], i.e. its `scalar`,
+    /// `wheel_scalar`, `pixels_per_step`, `modifier_scalar` and `modifier_keys`
+    /// at once.
+    ///
+    /// The default is [`DEFAULT_SENSITIVITY`].
     ///
     /// [`Knob`]: struct.Knob.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    /// [`SliderSensitivity`]: ../../core/struct.SliderSensitivity.html
+    /// [`DEFAULT_SENSITIVITY`]: constant.DEFAULT_SENSITIVITY.html
+    pub fn sensitivity(mut self, sensitivity: SliderSensitivity) -> Self {
+        self.sensitivity = sensitivity;
         self
     }
 
     /// Sets how much the [`Normal`] value will change for the [`Knob`] per `y`
     /// pixel movement of the mouse.
     ///
-    /// The default value is `0.00385`
+    /// The default value is `0.00385`, or `0.0077` on the web (`wasm32`)
     ///
     /// [`Knob`]: struct.Knob.html
     /// [`Normal`]: ../../core/struct.Normal.html
     pub fn scalar(mut self, scalar: f32) -> Self {
-        self.scalar = scalar;
+        self.sensitivity.scalar = scalar;
         self
     }
 
@@ -158,7 +684,71 @@ where
     /// [`Knob`]: struct.Knob.html
     /// [`Normal`]: ../../core/struct.Normal.html
     pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
-        self.wheel_scalar = wheel_scalar;
+        self.sensitivity.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how many pixels a pixel based scroll delta (e.g. from a
+    /// touchpad) has to add up to before the [`Knob`] moves by one
+    /// `wheel_scalar` step.
+    ///
+    /// The default value is [`DEFAULT_PIXELS_PER_STEP`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`DEFAULT_PIXELS_PER_STEP`]: ../../core/sensitivity/constant.DEFAULT_PIXELS_PER_STEP.html
+    pub fn pixels_per_step(mut self, pixels_per_step: f32) -> Self {
+        self.sensitivity.pixels_per_step = pixels_per_step;
+        self
+    }
+
+    /// Sets whether the [`Knob`] captures the wheel events that move it, so a
+    /// scrollable parent doesn't scroll at the same time. With `false`, the
+    /// wheel scrolls the parent instead of moving the [`Knob`].
+    ///
+    /// Wheel events are always captured while the [`Knob`] is dragged. The
+    /// default value is `true`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn capture_wheel(mut self, capture_wheel: bool) -> Self {
+        self.capture_wheel = capture_wheel;
+        self
+    }
+
+    /// Sets when two presses of the [`Knob`] count as a double click, which
+    /// resets it to its default value.
+    ///
+    /// The default is the `double_click` of the [`Defaults`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn double_click(mut self, double_click: DoubleClick) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
+    /// Sets how far the pointer has to move from a press of the [`Knob`]
+    /// before it drags the value, so that a click or a tap doesn't move it.
+    ///
+    /// The default is the `drag_threshold` of the [`Defaults`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn drag_threshold(mut self, drag_threshold: DragThreshold) -> Self {
+        self.drag_threshold = drag_threshold;
+        self
+    }
+
+    /// Groups consecutive wheel steps of the [`Knob`] into one gesture, which
+    /// fires `on_grab` at the first step and `on_release` once the wheel is
+    /// idle for `idle_timeout`, e.g. for hosts that create an undo entry per
+    /// gesture. With `None`, every wheel step fires both.
+    ///
+    /// The default is the `wheel_gesture` of the [`Defaults`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn wheel_gesture(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.wheel_gesture = idle_timeout;
         self
     }
 
@@ -168,7 +758,7 @@ where
     ///
     /// [`Knob`]: struct.Knob.html
     pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
+        self.sensitivity.modifier_keys = modifier_keys;
         self
     }
 
@@ -184,7 +774,7 @@ where
     ///
     /// [`Knob`]: struct.Knob.html
     pub fn modifier_scalar(mut self, scalar: f32) -> Self {
-        self.modifier_scalar = scalar;
+        self.sensitivity.modifier_scalar = scalar;
         self
     }
 
@@ -230,67 +820,170 @@ where
         self
     }
 
-    /// Sets the value to be considered the center of the [`Knob`]. Only has
-    /// an effect when using [`ArcBipolarStyle`].
+    /// Sets the modulation sources to display, each as its own ring with the
+    /// [`ModulationRange`] and color of the source. The rings are stacked
+    /// outwards in order, starting with the first source. Note your
+    /// [`StyleSheet`] must also implement `mod_sources_arc_appearance()` for
+    /// them to display.
+    ///
+    /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn mod_sources(mut self, mod_sources: &'a [(ModulationRange, Color)]) -> Self {
+        self.mod_sources = mod_sources;
+        self
+    }
+
+    /// Sets the value to be considered the center of the [`Knob`]. It is
+    /// `Normal::CENTER` by default.
+    ///
+    /// It is where the filled arc of [`ArcBipolarStyle`] and of a
+    /// [`ValueArcAppearance`] with a `right_filled_color` starts, so that
+    /// asymmetric ranges (e.g. -12 dB to +6 dB) are drawn from their zero.
     ///
     /// [`Knob`]: struct.Knob.html
     /// [`ArcBipolarStyle`]: ../../style/knob/struct.ArcBipolarStyle.html
+    /// [`ValueArcAppearance`]: ../../style/knob/struct.ValueArcAppearance.html
     pub fn bipolar_center(mut self, bipolar_center: Normal) -> Self {
         self.bipolar_center = Some(bipolar_center);
         self
     }
 
-    fn move_virtual_slider(&mut self, state: &mut State, mut normal_delta: f32) -> SliderStatus {
+    /// Snaps the [`Knob`] to `steps` equal steps between the minimum and
+    /// the maximum value while it is dragged or scrolled, with every wheel
+    /// step moving it by one step.
+    ///
+    /// Holding the [`free_modifier_keys`] bypasses the steps for free
+    /// continuous adjustment.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`free_modifier_keys`]: #method.free_modifier_keys
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    /// Sets the modifier keys that bypass the [`steps`] of the [`Knob`]
+    /// while held.
+    ///
+    /// The default modifier key is `Shift`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`steps`]: #method.steps
+    pub fn free_modifier_keys(mut self, free_modifier_keys: keyboard::Modifiers) -> Self {
+        self.free_modifier_keys = free_modifier_keys;
+        self
+    }
+
+    /// Sets a mouse button that always drags the [`Knob`] finely, by the
+    /// `modifier_scalar`, without holding the modifier keys. The left button
+    /// keeps dragging normally, unless it is the one set here.
+    ///
+    /// There is no fine drag button by default.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn fine_drag_button(mut self, button: mouse::Button) -> Self {
+        self.fine_drag_button = Some(button);
+        self
+    }
+
+    /// Sets how dragging turns the [`Knob`]. The default is
+    /// [`DragMode::Vertical`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`DragMode::Vertical`]: enum.DragMode.html#variant.Vertical
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
+    fn move_mod_amount<Renderer: geometry::Renderer>(
+        &mut self,
+        state: &mut State<Renderer>,
+        mut normal_delta: f32,
+    ) -> SliderStatus {
         if normal_delta.abs() < f32::EPSILON {
             return SliderStatus::Unchanged;
         }
 
-        if state.pressed_modifiers.contains(self.modifier_keys) {
-            normal_delta *= self.modifier_scalar;
+        if let Some(scalar) = self
+            .sensitivity
+            .held_modifier_scalar(state.slider.pressed_modifiers)
+        {
+            normal_delta *= scalar;
         }
 
-        self.normal_param
-            .value
-            .set_clipped(state.continuous_normal - normal_delta);
-        state.continuous_normal = self.normal_param.value.as_f32();
+        self.mod_amount
+            .set_clipped(state.continuous_mod - normal_delta);
+        state.continuous_mod = self.mod_amount.as_f32();
 
         SliderStatus::Moved
     }
 
-    fn maybe_fire_on_grab(&mut self, shell: &mut Shell<'_, Message>) {
-        if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
-            shell.publish(message);
-        }
+    fn is_mod_drag<Renderer: geometry::Renderer>(&self, state: &State<Renderer>) -> bool {
+        self.on_mod_change.is_some()
+            && state
+                .slider
+                .pressed_modifiers
+                .contains(self.mod_modifier_keys)
     }
 
-    fn fire_on_change(&self, shell: &mut Shell<'_, Message>) {
-        shell.publish((self.on_change)(self.normal_param.value));
+    fn fire_on_mod_change(&self, shell: &mut Shell<'_, Message>) {
+        if let Some(on_mod_change) = &self.on_mod_change {
+            shell.publish(on_mod_change(self.mod_amount));
+        }
     }
 
-    fn maybe_fire_on_release(&mut self, shell: &mut Shell<'_, Message>) {
-        if let Some(message) = self.on_release.as_mut().and_then(|on_release| on_release()) {
-            shell.publish(message);
+    fn virtual_slider(&mut self) -> VirtualSlider<'_, 'a, Message> {
+        VirtualSlider {
+            normal_param: &mut self.normal_param,
+            sensitivity: self.sensitivity,
+            on_change: &*self.on_change,
+            on_grab: self.on_grab.as_deref_mut(),
+            on_release: self.on_release.as_deref_mut(),
+            steps: self.steps,
+            free_modifier_keys: self.free_modifier_keys,
+            fine_drag_button: self.fine_drag_button,
+            double_click: self.double_click,
+            drag_threshold: self.drag_threshold,
+            detents: None,
+            on_type_in: self.on_type_in.as_deref(),
+            on_drop: self.drop_payload.zip(self.on_drop.as_deref()),
+            group: self.selection_group,
+            on_group_change: self.on_group_change.as_deref(),
+            view_window: ViewWindow::FULL,
+            inverse: false,
+            capture_wheel: self.capture_wheel,
+            horizontal_wheel_scalar: 0.0,
+            spring_back: self
+                .spring_back
+                .map(|duration| animation::motion_duration(duration, self.reduced_motion)),
+            wheel_gesture: self.wheel_gesture,
+            hit_shape: HitShape::Rectangle,
         }
     }
 }
 
-impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for Knob<'a, Message, Theme>
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Knob<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: StyleSheet,
+    Theme: Catalog,
+    Renderer: 'static
+        + image::Renderer<Handle = image::Handle>
+        + text::Renderer<Font = Font>
+        + geometry::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State>()
+        tree::Tag::of::<State<Renderer>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new(self.normal_param.value))
+        tree::State::new(State::<Renderer>::new(self.normal_param.value))
     }
 
     fn size(&self) -> Size<Length> {
         Size {
-            width: self.size,
-            height: self.size,
+            width: self.width,
+            height: self.height,
         }
     }
 
@@ -300,168 +993,222 @@ where
         _renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout::Node::new(limits.resolve(self.size, self.size, Size::ZERO))
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
     }
 
-    fn on_event(
-        &mut self,
+    fn operate(
+        &self,
         state: &mut Tree,
-        event: Event,
         layout: Layout<'_>,
-        cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
-        shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
-    ) -> event::Status {
-        let state = state.state.downcast_mut::<State>();
+        operation: &mut dyn Operation,
+    ) {
+        let state = state.state.downcast_mut::<State<Renderer>>();
 
-        let is_over = cursor.is_over(layout.bounds());
-
-        // Update state after a discontinuity
-        if state.dragging_status.is_none() && state.prev_normal != self.normal_param.value {
-            state.prev_normal = self.normal_param.value;
-            state.continuous_normal = self.normal_param.value.as_f32();
+        if !self.display {
+            operation.focusable(state, self.id.as_ref());
         }
 
-        match event {
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if state.dragging_status.is_some() {
-                    let normal_delta = (position.y - state.prev_drag_y) * self.scalar;
-
-                    state.prev_drag_y = position.y;
-
-                    if self.move_virtual_slider(state, normal_delta).was_moved() {
-                        self.fire_on_change(shell);
+        operation.custom(
+            &mut DragState {
+                status: state
+                    .slider
+                    .dragging_status
+                    .or(state.mod_dragging.then_some(SliderStatus::Unchanged)),
+            },
+            self.id.as_ref(),
+        );
 
-                        state
-                            .dragging_status
-                            .as_mut()
-                            .expect("dragging_status taken")
-                            .moved();
-                    }
+        operation.custom(
+            &mut ValueState {
+                normal: self.normal_param.value,
+                default: self.normal_param.default,
+            },
+            self.id.as_ref(),
+        );
+
+        operation.custom(
+            &mut HandleState {
+                center: fit_bounds(
+                    layout.bounds(),
+                    self.align_x,
+                    self.align_y,
+                    self.content_fit,
+                )
+                .0
+                .center(),
+            },
+            self.id.as_ref(),
+        );
+
+        #[cfg(feature = "a11y")]
+        operation.custom(
+            &mut a11y::slider_node(
+                self.name.as_deref(),
+                self.description.as_deref(),
+                self.normal_param.value,
+                self.announced_value
+                    .as_ref()
+                    .map(|format| format(self.normal_param.value))
+                    .as_deref(),
+                (self.sensitivity.wheel_scalar > 0.0).then_some(self.sensitivity.wheel_scalar),
+                None,
+                layout.bounds(),
+            ),
+            self.id.as_ref(),
+        );
+    }
 
-                    return event::Status::Captured;
-                }
-            }
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                if self.wheel_scalar == 0.0 {
-                    return event::Status::Ignored;
-                }
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if self.display {
+            return event::Status::Ignored;
+        }
 
-                if is_over {
-                    let lines = match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => y,
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            if y > 0.0 {
-                                1.0
-                            } else if y < 0.0 {
-                                -1.0
-                            } else {
-                                0.0
-                            }
-                        }
-                    };
+        let state = state.state.downcast_mut::<State<Renderer>>();
+        let cursor = input::event_cursor(&event, cursor);
+        let pointer = input::Pointer::of(&event);
 
-                    if lines != 0.0 {
-                        let normal_delta = -lines * self.wheel_scalar;
+        let hit_bounds = layout.bounds().expand(self.hit_padding);
 
-                        if self.move_virtual_slider(state, normal_delta).was_moved() {
-                            if state.dragging_status.is_none() {
-                                self.maybe_fire_on_grab(shell);
-                            }
+        if self.on_delta.is_some() {
+            let center = fit_bounds(
+                layout.bounds(),
+                self.align_x,
+                self.align_y,
+                self.content_fit,
+            )
+            .0
+            .center();
 
-                            self.fire_on_change(shell);
+            return self.on_endless_event(state, &event, cursor, center, hit_bounds, shell);
+        }
 
-                            if let Some(slider_status) = state.dragging_status.as_mut() {
-                                // Widget was grabbed => keep it grabbed
-                                slider_status.moved();
-                            } else {
-                                self.maybe_fire_on_release(shell);
-                            }
-                        }
+        if !state.mod_dragging {
+            state.continuous_mod = self.mod_amount.as_f32();
+        }
 
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.mod_dragging && state.slider.drag_pointer == pointer =>
+            {
+                if let Some(pending) = state.slider.pending_drag {
+                    if !pending.is_crossed(position, &self.drag_threshold) {
                         return event::Status::Captured;
                     }
+
+                    state.slider.pending_drag = None;
                 }
-            }
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if is_over {
-                    let click = mouse::Click::new(
-                        cursor.position().unwrap(),
-                        mouse::Button::Left,
-                        state.last_click,
-                    );
-
-                    match click.kind() {
-                        mouse::click::Kind::Single => {
-                            self.maybe_fire_on_grab(shell);
-
-                            state.dragging_status = Some(Default::default());
-                            state.prev_drag_y = cursor.position().unwrap().y;
-                        }
-                        _ => {
-                            // Reset to default
 
-                            let prev_dragging_status = state.dragging_status.take();
+                let normal_delta = state.slider.drag.moved_to(position.y, DragClamp::None)
+                    * self.sensitivity.scalar;
 
-                            if self.normal_param.value != self.normal_param.default {
-                                if prev_dragging_status.is_none() {
-                                    self.maybe_fire_on_grab(shell);
-                                }
+                if self.move_mod_amount(state, normal_delta).was_moved() {
+                    self.fire_on_mod_change(shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if self.is_mod_drag(state)
+                    && cursor.is_over(hit_bounds)
+                    && (state.slider.drag_pointer.is_none()
+                        || state.slider.drag_pointer == pointer)
+                    && !input::is_press_claimed(&event, &state.slider.press_claim) =>
+            {
+                input::claim_press(&event, &state.slider.press_claim);
+
+                let cursor_position = cursor.position().unwrap();
+
+                let click = input::Click::new(
+                    &event,
+                    cursor_position,
+                    mouse::Button::Left,
+                    state.slider.last_click,
+                    &self.double_click,
+                );
+
+                state.slider.is_focused = true;
+                state.slider.focus_visible = false;
+
+                match click.kind() {
+                    mouse::click::Kind::Single => {
+                        state.mod_dragging = true;
+                        state.slider.drag_pointer = pointer;
+                        state.slider.pending_drag =
+                            PendingDrag::new(&event, cursor_position, &self.drag_threshold, false);
+                        state.slider.drag.start(cursor_position.y, DragClamp::None);
+                    }
+                    _ => {
+                        // Reset the modulation amount
 
-                                self.normal_param.value = self.normal_param.default;
+                        state.mod_dragging = false;
+                        state.slider.drag_pointer = None;
 
-                                self.fire_on_change(shell);
+                        if self.mod_amount != self.normal_param.value {
+                            self.mod_amount = self.normal_param.value;
+                            state.continuous_mod = self.mod_amount.as_f32();
 
-                                self.maybe_fire_on_release(shell);
-                            } else if prev_dragging_status.is_some() {
-                                self.maybe_fire_on_release(shell);
-                            }
+                            self.fire_on_mod_change(shell);
                         }
                     }
+                }
 
-                    state.last_click = Some(click);
+                state.slider.last_click = Some(click);
 
-                    return event::Status::Captured;
-                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { .. }) if state.mod_dragging => {
+                return event::Status::Captured;
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                if let Some(slider_status) = state.dragging_status.take() {
-                    if self.on_grab.is_some() || slider_status.was_moved() {
-                        // maybe fire on release if `on_grab` is defined
-                        // so as to terminate the action, regardless of the actual user movement.
-                        self.maybe_fire_on_release(shell);
-                    }
-
-                    return event::Status::Captured;
-                }
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.mod_dragging && state.slider.drag_pointer == pointer =>
+            {
+                state.slider.drag_pointer = None;
+                state.slider.pending_drag = None;
+                state.mod_dragging = false;
+
+                return event::Status::Captured;
             }
-            Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::KeyReleased { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::ModifiersChanged(modifiers) => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-            },
             _ => {}
         }
 
-        event::Status::Ignored
+        let axis = match self.drag_mode {
+            DragMode::Vertical => Axis::vertical(self.sensitivity.scalar),
+            DragMode::Circular => Axis::Circular {
+                center: fit_bounds(
+                    layout.bounds(),
+                    self.align_x,
+                    self.align_y,
+                    self.content_fit,
+                )
+                .0
+                .center(),
+                span: state.angle_span.get(),
+            },
+        };
+
+        self.virtual_slider().on_event(
+            &mut state.slider,
+            &event,
+            cursor,
+            hit_bounds,
+            Some(axis),
+            shell,
+        )
     }
 
     fn draw(
@@ -472,62 +1219,54 @@ where
         _style: &Style,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
-        let state = state.state.downcast_ref::<State>();
+        let state = state.state.downcast_ref::<State<Renderer>>();
 
         let bounds = layout.bounds();
 
-        let is_over = cursor.is_over(bounds);
+        let is_over = cursor.is_over(bounds.expand(self.hit_padding));
 
-        let angle_range = theme.angle_range(&self.style);
+        let angle_range = theme.angle_range(&self.class);
 
-        let appearance = if state.dragging_status.is_some() {
-            theme.dragging(&self.style)
+        let status = if self.display {
+            Status::Active
+        } else if is_over && self.drop_payload.is_some() {
+            Status::DropTarget
+        } else if state.slider.dragging_status.is_some() || state.mod_dragging {
+            Status::Dragged
         } else if is_over {
-            theme.hovered(&self.style)
+            Status::Hovered
+        } else if state.slider.is_focused {
+            Status::Focused
         } else {
-            theme.active(&self.style)
+            Status::Active
         };
 
-        let value_markers = ValueMarkers {
+        let appearance = theme.style(&self.class, status).dim(self.opacity);
+
+        let mod_amount_range = self
+            .on_mod_change
+            .as_ref()
+            .map(|_| ModulationRange::new(self.normal_param.value, self.mod_amount));
+
+        let mut value_markers = ValueMarkers {
             tick_marks: self.tick_marks,
             text_marks: self.text_marks,
-            mod_range_1: self.mod_range_1,
+            mod_range_1: self.mod_range_1.or(mod_amount_range.as_ref()),
             mod_range_2: self.mod_range_2,
-            tick_marks_style: theme.tick_marks_appearance(&self.style),
-            text_marks_style: theme.text_marks_appearance(&self.style),
-            value_arc_style: theme.value_arc_appearance(&self.style),
-            mod_range_style_1: theme.mod_range_arc_appearance(&self.style),
-            mod_range_style_2: theme.mod_range_arc_appearance_2(&self.style),
+            mod_sources: self.mod_sources,
+            default: self.normal_param.default,
+            tick_marks_style: theme.tick_marks_appearance(&self.class),
+            text_marks_style: theme.text_marks_appearance(&self.class),
+            value_arc_style: theme.value_arc_appearance(&self.class),
+            mod_range_style_1: theme.mod_range_arc_appearance(&self.class),
+            mod_range_style_2: theme.mod_range_arc_appearance_2(&self.class),
+            mod_sources_style: theme.mod_sources_arc_appearance(&self.class),
+            default_marker_style: theme.default_marker_appearance(&self.class),
         };
 
-        let bounds = {
-            let bounds = Rectangle {
-                x: bounds.x.round(),
-                y: bounds.y.round(),
-                width: bounds.width.round(),
-                height: bounds.height.round(),
-            };
-
-            if bounds.width == bounds.height {
-                bounds
-            } else if bounds.width > bounds.height {
-                Rectangle {
-                    x: (bounds.x + (bounds.width - bounds.height) / 2.0).round(),
-                    y: bounds.y,
-                    width: bounds.height,
-                    height: bounds.height,
-                }
-            } else {
-                Rectangle {
-                    x: bounds.x,
-                    y: (bounds.y + (bounds.height - bounds.width) / 2.0).round(),
-                    width: bounds.width,
-                    height: bounds.width,
-                }
-            }
-        };
+        let (bounds, scale) = fit_bounds(bounds, self.align_x, self.align_y, self.content_fit);
 
         let radius = bounds.width / 2.0;
 
@@ -536,35 +1275,52 @@ where
         } else {
             angle_range.min() + std::f32::consts::FRAC_PI_2
         };
-        let angle_span = angle_range.max() - angle_range.min();
-        let value_angle = start_angle + (self.normal_param.value.scale(angle_span));
+        let angle_span = state::angle_span(&angle_range);
+        state.angle_span.set(angle_span);
+        let value_angle = if self.on_delta.is_some() {
+            endless::position_angle(self.endless_position)
+        } else {
+            start_angle + (self.normal_param.value.scale(angle_span))
+        };
 
         let knob_info = KnobInfo {
             bounds,
+            scale,
             start_angle,
             angle_span,
             radius,
             value: self.normal_param.value,
-            bipolar_center: self.bipolar_center,
+            bipolar_center: self.bipolar_center.unwrap_or(Normal::CENTER),
             value_angle,
+            endless: self.on_delta.is_some(),
+            opacity: self.opacity,
+            scale_factor: self.scale_factor,
         };
 
+        value_markers.dim(self.opacity);
+
+        if knob_info.endless {
+            value_markers.leave_out_all();
+        } else {
+            value_markers.cull(&knob_info, viewport);
+        }
+
         match appearance {
             Appearance::Circle(style) => draw::circle_style(
                 renderer,
                 &knob_info,
                 style,
                 &value_markers,
-                //tick_marks_cache,
-                //text_marks_cache,
+                &state.tick_marks_cache,
+                &state.text_marks_cache,
             ),
             Appearance::Arc(style) => draw::arc_style(
                 renderer,
                 &knob_info,
                 style,
                 &value_markers,
-                //tick_marks_cache,
-                //text_marks_cache,
+                &state.tick_marks_cache,
+                &state.text_marks_cache,
             ),
 
             Appearance::ArcBipolar(style) => draw::arc_bipolar_style(
@@ -572,19 +1328,1464 @@ where
                 &knob_info,
                 style,
                 &value_markers,
-                //tick_marks_cache,
-                //text_marks_cache,
+                &state.tick_marks_cache,
+                &state.text_marks_cache,
+            ),
+            Appearance::ArcSegmented(style) => draw::arc_segmented_style(
+                renderer,
+                &knob_info,
+                style,
+                &value_markers,
+                &state.tick_marks_cache,
+                &state.text_marks_cache,
             ),
         }
+
+        if state.slider.is_focused && state.slider.focus_visible && !self.display {
+            if let Some(focus_ring) = theme.focus_ring_appearance(&self.class) {
+                focus_ring::draw(renderer, bounds, &focus_ring.dim(self.opacity), true);
+            }
+        }
     }
 }
 
-impl<'a, Message, Theme> From<Knob<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer> From<Knob<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
+    Renderer: 'static
+        + image::Renderer<Handle = image::Handle>
+        + text::Renderer<Font = Font>
+        + geometry::Renderer,
 {
     fn from(knob: Knob<'a, Message, Theme>) -> Self {
         Self::new(knob)
     }
 }
+
+/// Returns the square the knob is drawn in and how much it is stretched on
+/// each axis to fill `bounds`.
+fn fit_bounds(
+    bounds: Rectangle,
+    align_x: alignment::Horizontal,
+    align_y: alignment::Vertical,
+    content_fit: ContentFit,
+) -> (Rectangle, Vector) {
+    let bounds = Rectangle {
+        x: bounds.x.round(),
+        y: bounds.y.round(),
+        width: bounds.width.round(),
+        height: bounds.height.round(),
+    };
+
+    let side = bounds.width.min(bounds.height);
+
+    match content_fit {
+        ContentFit::Contain => {
+            let factor_x = match align_x {
+                alignment::Horizontal::Left => 0.0,
+                alignment::Horizontal::Center => 0.5,
+                alignment::Horizontal::Right => 1.0,
+            };
+            let factor_y = match align_y {
+                alignment::Vertical::Top => 0.0,
+                alignment::Vertical::Center => 0.5,
+                alignment::Vertical::Bottom => 1.0,
+            };
+
+            (
+                Rectangle {
+                    x: (bounds.x + (bounds.width - side) * factor_x).round(),
+                    y: (bounds.y + (bounds.height - side) * factor_y).round(),
+                    width: side,
+                    height: side,
+                },
+                Vector::new(1.0, 1.0),
+            )
+        }
+        ContentFit::Stretch => {
+            let scale = if side > 0.0 {
+                Vector::new(bounds.width / side, bounds.height / side)
+            } else {
+                Vector::new(1.0, 1.0)
+            };
+
+            (
+                Rectangle {
+                    x: bounds.x + (bounds.width - side) / 2.0,
+                    y: bounds.y + (bounds.height - side) / 2.0,
+                    width: side,
+                    height: side,
+                },
+                scale,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::fixtures::{
+        assert_close, changed, key_events, knob, knob_snapshot, param, snapshot_path, KnobStyle,
+        Message, BLUE, GRAY, LIGHT, ORANGE, SIZE,
+    };
+    use crate::test_utils::{Harness, Snapshot};
+    use crate::{
+        operation,
+        style::{knob, Status},
+        tick_marks,
+        widget::knob::{ContentFit, DragMode},
+        Change, ChangeSource, DoubleClick, DragThreshold, Knob, ModifierMatch, ModifierScalar,
+        ModulationRange, Normal, SliderSensitivity,
+    };
+    use iced::{
+        advanced::widget::Id,
+        alignment::Horizontal,
+        event, keyboard, mouse,
+        time::{Duration, Instant},
+        touch,
+        widget::{canvas::Path, column, container, row, scrollable, Space},
+        window, Color, Event, Length, Point, Size, Theme, Vector,
+    };
+
+    fn grabbable_knob<'a>() -> Knob<'a, Message, Theme> {
+        knob()
+            .on_grab(|| Some(Message::Grabbed))
+            .on_release(|| Some(Message::Released))
+    }
+
+    #[test]
+    fn knob_drag() {
+        let mut harness = Harness::new(knob(), SIZE);
+        let center = harness.center();
+
+        harness.drag(center, center - Vector::new(0.0, 100.0));
+
+        let messages = harness.take_messages();
+        assert_eq!(messages.len(), 1);
+        assert_close(changed(&messages)[0], 0.5 + 100.0 * 0.00385);
+    }
+
+    #[test]
+    fn knob_drag_with_modifier() {
+        let mut harness = Harness::new(knob(), SIZE);
+        let center = harness.center();
+
+        let _ = harness.modifiers(keyboard::Modifiers::CTRL);
+        harness.drag(center, center - Vector::new(0.0, 100.0));
+
+        assert_close(
+            changed(&harness.take_messages())[0],
+            0.5 + 100.0 * 0.00385 * 0.02,
+        );
+    }
+
+    fn mod_knob<'a>(mod_amount: f32) -> Knob<'a, Message, Theme> {
+        knob().on_mod_change(Normal::from_clipped(mod_amount), Message::ModChanged)
+    }
+
+    fn mod_changed(messages: &[Message]) -> Vec<f32> {
+        messages
+            .iter()
+            .filter_map(|message| match message {
+                Message::ModChanged(normal) => Some(normal.as_f32()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn knob_mod_drag_moves_the_mod_amount() {
+        let mut harness = Harness::new(mod_knob(0.5), SIZE);
+        let center = harness.center();
+
+        let _ = harness.modifiers(keyboard::Modifiers::ALT);
+        harness.drag(center, center - Vector::new(0.0, 100.0));
+
+        let messages = harness.take_messages();
+        assert!(changed(&messages).is_empty());
+        let mod_amounts = mod_changed(&messages);
+        assert_eq!(mod_amounts.len(), 1);
+        assert_close(mod_amounts[0], 0.5 + 100.0 * 0.00385);
+    }
+
+    #[test]
+    fn knob_mod_double_click_resets_the_mod_amount() {
+        let mut harness = Harness::new(mod_knob(0.8), SIZE);
+
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.modifiers(keyboard::Modifiers::ALT);
+        let _ = harness.press();
+        let _ = harness.release();
+        let _ = harness.press();
+        let _ = harness.release();
+
+        // The mod amount goes back to the value, which stays where it is.
+        let messages = harness.take_messages();
+        assert!(changed(&messages).is_empty());
+        assert_eq!(mod_changed(&messages), [0.5]);
+    }
+
+    #[test]
+    fn knob_release_ends_the_mod_drag() {
+        let mut harness = Harness::new(mod_knob(0.5), SIZE);
+        let center = harness.center();
+
+        let _ = harness.modifiers(keyboard::Modifiers::ALT);
+        harness.drag(center, center - Vector::new(0.0, 10.0));
+        assert_eq!(mod_changed(&harness.take_messages()).len(), 1);
+
+        // Start away from the last press so it isn't taken as a double-click.
+        let start = center + Vector::new(0.0, 10.0);
+        let _ = harness.modifiers(keyboard::Modifiers::empty());
+        harness.drag(start, start - Vector::new(0.0, 10.0));
+
+        let messages = harness.take_messages();
+        assert!(mod_changed(&messages).is_empty());
+        assert_close(changed(&messages)[0], 0.5 + 10.0 * 0.00385);
+    }
+
+    #[test]
+    fn knob_sensitivity() {
+        let sensitivity = SliderSensitivity {
+            scalar: 0.001,
+            modifier_keys: keyboard::Modifiers::SHIFT,
+            ..crate::knob::DEFAULT_SENSITIVITY
+        };
+        let mut harness = Harness::new(knob().sensitivity(sensitivity), SIZE);
+        let center = harness.center();
+
+        harness.drag(center, center - Vector::new(0.0, 100.0));
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 100.0 * 0.001);
+
+        let mut harness = Harness::new(knob().sensitivity(sensitivity), SIZE);
+        let _ = harness.modifiers(keyboard::Modifiers::SHIFT);
+        harness.drag(center, center - Vector::new(0.0, 100.0));
+        assert_close(
+            changed(&harness.take_messages())[0],
+            0.5 + 100.0 * 0.001 * 0.02,
+        );
+    }
+
+    #[test]
+    fn knob_exact_modifiers_pick_their_own_scalar() {
+        const SENSITIVITY: SliderSensitivity = SliderSensitivity {
+            scalar: 0.001,
+            modifier_keys: keyboard::Modifiers::SHIFT,
+            modifier_scalar: 0.1,
+            modifier_match: ModifierMatch::Exact,
+            extra_modifiers: &[ModifierScalar {
+                keys: keyboard::Modifiers::CTRL.union(keyboard::Modifiers::SHIFT),
+                scalar: 0.01,
+            }],
+            ..crate::knob::DEFAULT_SENSITIVITY
+        };
+        let drag = |modifiers| {
+            let mut harness = Harness::new(knob().sensitivity(SENSITIVITY), SIZE);
+            let center = harness.center();
+            let _ = harness.modifiers(modifiers);
+            harness.drag(center, center - Vector::new(0.0, 100.0));
+            changed(&harness.take_messages())[0]
+        };
+
+        assert_close(drag(keyboard::Modifiers::SHIFT), 0.5 + 100.0 * 0.001 * 0.1);
+        assert_close(
+            drag(keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT),
+            0.5 + 100.0 * 0.001 * 0.01,
+        );
+        // Alt is not part of any combination, so nothing is scaled.
+        assert_close(
+            drag(keyboard::Modifiers::ALT | keyboard::Modifiers::SHIFT),
+            0.5 + 100.0 * 0.001,
+        );
+    }
+
+    #[test]
+    fn knob_extra_modifiers_keep_whole_wheel_steps() {
+        const SENSITIVITY: SliderSensitivity = SliderSensitivity {
+            modifier_match: ModifierMatch::Exact,
+            extra_modifiers: &[ModifierScalar {
+                keys: keyboard::Modifiers::CTRL.union(keyboard::Modifiers::SHIFT),
+                scalar: 0.01,
+            }],
+            ..crate::knob::DEFAULT_SENSITIVITY
+        };
+        let knob = Knob::new(param(0.5, 0.5), Message::Changed)
+            .sensitivity(SENSITIVITY)
+            .steps(10)
+            .free_modifier_keys(keyboard::Modifiers::ALT)
+            .wheel_gesture(Some(Duration::from_millis(200)));
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(knob, SIZE);
+        let _ = harness.move_cursor(harness.center());
+
+        let _ = harness.modifiers(keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT);
+        for _ in 0..3 {
+            let _ = harness.scroll(1.0);
+        }
+        let values = changed(&harness.take_messages());
+        assert_eq!(values.len(), 3);
+        for (value, expected) in values.into_iter().zip([0.6, 0.7, 0.8]) {
+            assert_close(value, expected);
+        }
+    }
+
+    #[test]
+    fn knob_touchpad_scroll() {
+        let mut harness = Harness::new(knob().pixels_per_step(10.0), SIZE);
+        let _ = harness.move_cursor(harness.center());
+
+        // Nothing moves until a whole step is scrolled, but the widget still
+        // keeps the events from scrolling its parent.
+        assert_eq!(harness.scroll_pixels(4.0), event::Status::Captured);
+        assert_eq!(harness.scroll_pixels(4.0), event::Status::Captured);
+        assert!(changed(&harness.take_messages()).is_empty());
+
+        let _ = harness.scroll_pixels(24.0);
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 3.0 * 0.01);
+    }
+
+    #[test]
+    fn knob_type_in() {
+        let knob = knob().on_type_in(Message::TypedIn);
+        let mut harness = Harness::new(knob, SIZE);
+        let type_in = |text: &str, modifiers| {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(text.into()),
+                modified_key: keyboard::Key::Character(text.into()),
+                physical_key: keyboard::key::Physical::Code(keyboard::key::Code::Digit5),
+                location: keyboard::Location::Standard,
+                modifiers,
+                text: Some(text.into()),
+            })
+        };
+
+        // Typing is left alone until the knob is focused.
+        let _ = harness.move_cursor(harness.center());
+        let no_modifiers = keyboard::Modifiers::empty();
+        assert_eq!(
+            harness.event(type_in("5", no_modifiers)),
+            event::Status::Ignored
+        );
+
+        let _ = harness.press();
+        let _ = harness.release();
+        let _ = harness.take_messages();
+
+        assert_eq!(
+            harness.event(type_in("5", no_modifiers)),
+            event::Status::Captured
+        );
+        assert_eq!(
+            harness.event(type_in("-", no_modifiers)),
+            event::Status::Captured
+        );
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::TypedIn("5".into()), Message::TypedIn("-".into())]
+        );
+
+        // Other keys and shortcuts still reach other widgets.
+        assert_eq!(
+            harness.event(type_in("a", no_modifiers)),
+            event::Status::Ignored
+        );
+        assert_eq!(
+            harness.event(type_in("5", keyboard::Modifiers::CTRL)),
+            event::Status::Ignored
+        );
+        assert!(harness.take_messages().is_empty());
+    }
+
+    #[test]
+    fn knob_fine_drag_button() {
+        let knob = knob()
+            .on_release(|| Some(Message::Released))
+            .fine_drag_button(mouse::Button::Right);
+        let mut harness = Harness::new(knob, SIZE);
+        let center = harness.center();
+        let button = |button| Event::Mouse(mouse::Event::ButtonPressed(button));
+
+        let _ = harness.move_cursor(center);
+        assert_eq!(
+            harness.event(button(mouse::Button::Right)),
+            event::Status::Captured
+        );
+        let _ = harness.move_cursor(center - Vector::new(0.0, 100.0));
+
+        let fine = 0.5 + 100.0 * 0.00385 * 0.02;
+        assert_close(changed(&harness.take_messages())[0], fine);
+
+        // Only releasing the button that started the drag ends it.
+        let _ = harness.release();
+        assert!(harness.take_messages().is_empty());
+
+        let _ = harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Right,
+        )));
+        assert_eq!(harness.take_messages(), vec![Message::Released]);
+
+        // Dragging with the left button is not fine.
+        harness.drag(center, center - Vector::new(0.0, 100.0));
+        assert_close(changed(&harness.take_messages())[0], fine + 100.0 * 0.00385);
+    }
+
+    #[test]
+    fn knob_circular_drag() {
+        let knob = knob()
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .drag_mode(DragMode::Circular);
+        let mut harness = Harness::new(knob, SIZE);
+        let turn_to = |harness: &mut Harness<'_, Message, Theme>, x, y| {
+            let _ = harness.move_cursor(Point::new(x, y));
+            changed(&harness.take_messages())
+        };
+
+        // Grab the knob at its left and turn it a quarter clockwise, which
+        // is 90 of its 300 degrees.
+        let _ = turn_to(&mut harness, 50.0, 100.0);
+        let _ = harness.press();
+        assert_close(*turn_to(&mut harness, 100.0, 50.0).last().unwrap(), 0.8);
+
+        // Past the end, the knob stays at it, also while crossing the gap at
+        // the bottom instead of jumping to the start.
+        assert_eq!(turn_to(&mut harness, 150.0, 100.0), vec![1.0]);
+        assert!(turn_to(&mut harness, 100.0, 150.0).is_empty());
+
+        // Turning back, it leaves the end once the overshoot, up to the size
+        // of the gap, is turned back.
+        assert_close(*turn_to(&mut harness, 150.0, 100.0).last().unwrap(), 0.9);
+    }
+
+    #[test]
+    fn knob_steps() {
+        let mut harness = Harness::new(knob().steps(4), SIZE);
+        let center = harness.center();
+
+        // Every wheel step moves by a whole step.
+        let _ = harness.move_cursor(center);
+        let _ = harness.scroll(1.0);
+        assert_close(changed(&harness.take_messages())[0], 0.75);
+
+        // A short drag stays within the step.
+        harness.drag(center, center - Vector::new(0.0, 2.0));
+        assert!(changed(&harness.take_messages()).is_empty());
+
+        // Holding the free modifier keys bypasses the steps.
+        let _ = harness.modifiers(keyboard::Modifiers::SHIFT);
+        let _ = harness.scroll(1.0);
+        assert_close(changed(&harness.take_messages())[0], 0.75 + 0.01);
+
+        // The fine modifier keys still move by at least one whole step.
+        let _ = harness.modifiers(keyboard::Modifiers::CTRL);
+        let _ = harness.scroll(-1.0);
+        assert_close(changed(&harness.take_messages())[0], 0.5);
+    }
+
+    #[test]
+    fn knob_on_change_full() {
+        let mut harness = Harness::new(knob().on_change_full(Message::ChangedFull), SIZE);
+        let center = harness.center();
+
+        let changes = |harness: &mut Harness<'_, Message, Theme>| -> Vec<Change> {
+            harness
+                .take_messages()
+                .into_iter()
+                .filter_map(|message| match message {
+                    Message::ChangedFull(change) => Some(change),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        harness.drag(center, center - Vector::new(0.0, 10.0));
+        let drag = changes(&mut harness);
+        assert_eq!(drag.len(), 1);
+        assert_eq!(drag[0].via, ChangeSource::Drag);
+        assert_close(drag[0].previous.as_f32(), 0.5);
+        assert_close(drag[0].normal.as_f32(), 0.5 + 10.0 * 0.00385);
+
+        let _ = harness.scroll(1.0);
+        let wheel = changes(&mut harness);
+        assert_eq!(wheel[0].via, ChangeSource::Wheel);
+        assert_eq!(wheel[0].previous, drag[0].normal);
+
+        let _ = harness.press();
+        let _ = harness.release();
+        let _ = harness.press();
+        let _ = harness.release();
+        let reset = changes(&mut harness);
+        assert_eq!(reset[0].via, ChangeSource::Reset);
+        assert_eq!(reset[0].previous, wheel[0].normal);
+        assert_close(reset[0].normal.as_f32(), 0.25);
+    }
+
+    #[test]
+    fn knob_dragging_operation() {
+        let mut harness = Harness::new(knob().id(Id::new("knob")), SIZE);
+        let is_dragging = |harness: &mut Harness<'_, Message, Theme>, id| {
+            harness.operate(operation::is_dragging(Id::new(id)))
+        };
+
+        assert_eq!(is_dragging(&mut harness, "knob"), Some(false));
+
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.press();
+        assert_eq!(is_dragging(&mut harness, "knob"), Some(true));
+        assert_eq!(is_dragging(&mut harness, "other"), Some(false));
+        assert_eq!(harness.operate(operation::any_dragging()), Some(true));
+
+        let _ = harness.release();
+        assert_eq!(is_dragging(&mut harness, "knob"), Some(false));
+        assert_eq!(harness.operate(operation::any_dragging()), Some(false));
+    }
+
+    #[test]
+    fn knob_value_operations() {
+        let mut harness = Harness::new(knob().id(Id::new("knob")), SIZE);
+
+        let nudged = harness.operate(operation::nudge(Id::new("knob"), 0.1));
+        assert_eq!(
+            nudged.flatten().map(|change| (change.previous, change.via)),
+            Some((Normal::from_clipped(0.5), ChangeSource::Operation))
+        );
+        assert_close(nudged.flatten().unwrap().normal.as_f32(), 0.6);
+
+        let reset = harness.operate(operation::reset(Id::new("knob")));
+        assert_eq!(
+            reset.flatten().map(|change| change.normal),
+            Some(Normal::from_clipped(0.25))
+        );
+
+        // Operations do not produce the widget's own messages.
+        assert!(harness.take_messages().is_empty());
+
+        let missing = harness.operate(operation::nudge(Id::new("other"), 0.1));
+        assert_eq!(missing, Some(None));
+
+        let at_default =
+            Knob::<_, Theme>::new(param(0.25, 0.25), Message::Changed).id(Id::new("knob"));
+        let mut harness = Harness::new(at_default, SIZE);
+        assert_eq!(
+            harness.operate(operation::reset(Id::new("knob"))),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn knob_focus() {
+        let statuses = std::cell::RefCell::new(Vec::new());
+        let knob = knob().id(Id::new("knob")).style(|theme, status| {
+            statuses.borrow_mut().push(status);
+            knob::default(theme, status)
+        });
+        let mut harness = Harness::new(knob, SIZE);
+
+        let _ = harness.operate(operation::focus::<()>(Id::new("knob")));
+        let _ = harness.snapshot(&Theme::Light);
+
+        // Pressing outside of the knob unfocuses it, pressing on it focuses it.
+        let _ = harness.move_cursor(Point::new(SIZE.width - 1.0, SIZE.height - 1.0));
+        let _ = harness.press();
+        let _ = harness.release();
+        let _ = harness.snapshot(&Theme::Light);
+
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.press();
+        let _ = harness.release();
+        let _ = harness.move_cursor(Point::new(SIZE.width - 1.0, SIZE.height - 1.0));
+        let _ = harness.snapshot(&Theme::Light);
+
+        drop(harness);
+        assert_eq!(
+            statuses.into_inner(),
+            [Status::Focused, Status::Active, Status::Focused]
+        );
+    }
+
+    #[test]
+    fn opacity_dims_the_whole_widget() {
+        let alpha = |opacity| {
+            let snapshot = Harness::new(knob().opacity(opacity), SIZE).snapshot(&Theme::Light);
+
+            snapshot
+                .pixel(snapshot.width() / 2, snapshot.height() / 2)
+                .unwrap()
+                .a
+        };
+
+        assert_close(alpha(1.0), 1.0);
+        assert!((alpha(0.5) - 0.5).abs() < 0.01);
+        assert_eq!(alpha(-1.0), 0.0);
+    }
+
+    #[cfg(feature = "a11y")]
+    #[test]
+    fn knob_access_node() {
+        use crate::a11y::{self, accesskit};
+
+        let knob = knob()
+            .id(Id::new("knob"))
+            .name("Cutoff")
+            .description("Filter cutoff frequency");
+        let mut harness = Harness::new(knob, SIZE);
+
+        let entries = harness.operate(a11y::nodes()).unwrap();
+        assert_eq!(entries.len(), 1);
+
+        let entry = &entries[0];
+        assert_eq!(entry.id, Some(Id::new("knob")));
+        assert_eq!(entry.node.role(), accesskit::Role::Slider);
+        assert_eq!(entry.node.label(), Some("Cutoff"));
+        assert_eq!(entry.node.description(), Some("Filter cutoff frequency"));
+        assert_eq!(entry.node.numeric_value(), Some(0.5));
+        assert_eq!(entry.node.min_numeric_value(), Some(0.0));
+        assert_eq!(entry.node.max_numeric_value(), Some(1.0));
+        assert!(entry.node.supports_action(accesskit::Action::Increment));
+    }
+
+    #[cfg(feature = "a11y")]
+    #[test]
+    fn knob_access_node_announces_the_mapped_value() {
+        use crate::{a11y, FreqRange};
+
+        let range = FreqRange::new(20.0, 20_000.0);
+        let knob =
+            knob().announced_value(|normal| format!("{:.0} Hz", range.unmap_to_value(normal)));
+        let mut harness = Harness::new(knob, SIZE);
+
+        let entries = harness.operate(a11y::nodes()).unwrap();
+        assert_eq!(entries[0].node.value(), Some("632 Hz"));
+        assert_eq!(entries[0].node.numeric_value(), Some(0.5));
+    }
+
+    #[test]
+    fn knob_multi_touch() {
+        let mut harness = Harness::new(knob(), SIZE);
+        let center = harness.center();
+
+        let _ = harness.event(Event::Touch(touch::Event::FingerPressed {
+            id: touch::Finger(0),
+            position: center,
+        }));
+
+        // Another finger dragging another widget does not move the knob.
+        let _ = harness.event(Event::Touch(touch::Event::FingerMoved {
+            id: touch::Finger(1),
+            position: center + Vector::new(0.0, 30.0),
+        }));
+        let _ = harness.event(Event::Touch(touch::Event::FingerLifted {
+            id: touch::Finger(1),
+            position: center + Vector::new(0.0, 30.0),
+        }));
+        let _ = harness.release();
+        assert!(changed(&harness.take_messages()).is_empty());
+        assert_eq!(harness.operate(operation::any_dragging()), Some(true));
+
+        let _ = harness.event(Event::Touch(touch::Event::FingerMoved {
+            id: touch::Finger(0),
+            position: center + Vector::new(0.0, 10.0),
+        }));
+        assert_eq!(changed(&harness.take_messages()).len(), 1);
+
+        let _ = harness.event(Event::Touch(touch::Event::FingerLifted {
+            id: touch::Finger(0),
+            position: center + Vector::new(0.0, 10.0),
+        }));
+        assert_eq!(harness.operate(operation::any_dragging()), Some(false));
+    }
+
+    #[test]
+    fn knob_grab_and_release() {
+        let mut harness = Harness::new(grabbable_knob(), SIZE);
+        let center = harness.center();
+
+        harness.drag(center, center + Vector::new(0.0, 10.0));
+
+        let messages = harness.take_messages();
+        assert_eq!(messages.first(), Some(&Message::Grabbed));
+        assert_eq!(messages.last(), Some(&Message::Released));
+        assert_eq!(changed(&messages).len(), 1);
+
+        // A click without movement still ends the gesture when `on_grab` is set.
+        let mut harness = Harness::new(grabbable_knob(), SIZE);
+        let _ = harness.move_cursor(center);
+        let _ = harness.press();
+        let _ = harness.release();
+        assert_eq!(
+            harness.take_messages(),
+            [Message::Grabbed, Message::Released]
+        );
+    }
+
+    #[test]
+    fn knob_release_without_move() {
+        let knob = knob().on_release(|| Some(Message::Released));
+        let mut harness = Harness::new(knob, SIZE);
+
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.press();
+        let _ = harness.release();
+
+        assert!(harness.take_messages().is_empty());
+    }
+
+    #[test]
+    fn knob_wheel() {
+        let mut harness = Harness::new(grabbable_knob(), SIZE);
+
+        // Scrolling outside of the widget is ignored.
+        let _ = harness.move_cursor(Point::new(SIZE.width - 1.0, SIZE.height - 1.0));
+        let _ = harness.scroll(1.0);
+        assert!(harness.take_messages().is_empty());
+
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.scroll(2.0);
+
+        let messages = harness.take_messages();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0], Message::Grabbed);
+        assert_close(changed(&messages)[0], 0.52);
+        assert_eq!(messages[2], Message::Released);
+    }
+
+    #[test]
+    fn knob_double_click_resets_to_default() {
+        let mut harness = Harness::new(knob(), SIZE);
+
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.press();
+        let _ = harness.release();
+        let _ = harness.press();
+        let _ = harness.release();
+
+        assert_eq!(changed(&harness.take_messages()), [0.25]);
+    }
+
+    #[test]
+    fn knob_double_tap_can_be_disabled() {
+        let tap_twice = |double_click| {
+            let mut harness = Harness::new(knob().double_click(double_click), SIZE);
+            let center = harness.center();
+
+            for _ in 0..2 {
+                let _ = harness.event(Event::Touch(touch::Event::FingerPressed {
+                    id: touch::Finger(0),
+                    position: center,
+                }));
+                let _ = harness.event(Event::Touch(touch::Event::FingerLifted {
+                    id: touch::Finger(0),
+                    position: center,
+                }));
+            }
+
+            changed(&harness.take_messages())
+        };
+
+        assert_eq!(tap_twice(DoubleClick::DEFAULT), [0.25]);
+
+        let mouse_only = DoubleClick {
+            touch: false,
+            ..DoubleClick::DEFAULT
+        };
+        assert!(tap_twice(mouse_only).is_empty());
+        assert!(tap_twice(DoubleClick::DISABLED).is_empty());
+
+        // The mouse still resets it.
+        let mut harness = Harness::new(knob().double_click(mouse_only), SIZE);
+        let _ = harness.move_cursor(harness.center());
+        for _ in 0..2 {
+            let _ = harness.press();
+            let _ = harness.release();
+        }
+        assert_eq!(changed(&harness.take_messages()), [0.25]);
+    }
+
+    #[test]
+    fn knob_press_outside() {
+        let mut harness = Harness::new(knob(), SIZE);
+
+        harness.drag(
+            Point::new(SIZE.width - 1.0, SIZE.height - 1.0),
+            Point::new(0.0, 0.0),
+        );
+
+        assert!(harness.take_messages().is_empty());
+    }
+
+    #[test]
+    fn knob_wide_hit_area() {
+        let knob = knob()
+            .width(Length::Fixed(100.0))
+            .height(Length::Fixed(30.0))
+            .align_x(Horizontal::Left);
+        let mut harness = Harness::new(knob, SIZE);
+
+        // The far corner is away from the drawn knob, but still in its bounds.
+        harness.drag(Point::new(98.0, 2.0), Point::new(98.0, 12.0));
+
+        assert_close(changed(&harness.take_messages())[0], 0.5 - 10.0 * 0.00385);
+    }
+
+    #[test]
+    fn knob_hit_padding() {
+        // The knob is 30 pixels wide, so this is just outside of it.
+        let outside = Point::new(36.0, 15.0);
+
+        let mut harness = Harness::new(knob(), SIZE);
+        harness.drag(outside, outside + Vector::new(0.0, 10.0));
+        assert!(harness.take_messages().is_empty());
+
+        let mut harness = Harness::new(knob().hit_padding(7), SIZE);
+        harness.drag(outside, outside + Vector::new(0.0, 10.0));
+        assert_close(changed(&harness.take_messages())[0], 0.5 - 10.0 * 0.00385);
+
+        // The padding does not take up space in the layout.
+        assert_eq!(harness.bounds().size(), Size::new(30.0, 30.0));
+    }
+
+    #[test]
+    fn overlapping_hit_padding_grabs_one_widget() {
+        let content = column![
+            knob().hit_padding(10),
+            Knob::new(param(0.5, 0.25), |normal| Message::ChangedPair(
+                normal, normal
+            ))
+            .hit_padding(10),
+        ];
+        let mut harness = Harness::new(content, SIZE);
+
+        // At the bottom of the first knob, inside of the padding of both.
+        let press = Point::new(15.0, 28.0);
+        harness.drag(press, press + Vector::new(0.0, 10.0));
+
+        let messages = harness.take_messages();
+        assert_eq!(messages.len(), 1);
+        assert_close(changed(&messages)[0], 0.5 - 10.0 * 0.00385);
+
+        // The claim ends with the press, so the second knob can still be
+        // grabbed away from the padding of the first one.
+        let press = Point::new(15.0, 45.0);
+        harness.drag(press, press + Vector::new(0.0, 10.0));
+
+        assert!(matches!(
+            harness.take_messages()[..],
+            [Message::ChangedPair(..)]
+        ));
+    }
+
+    #[test]
+    fn knob_capture_wheel() {
+        let content = |knob: Knob<'static, Message, Theme>| {
+            scrollable(column![knob.id(Id::new("knob")), Space::with_height(400.0)])
+        };
+        let position = |harness: &mut Harness<'_, Message, Theme>| {
+            harness
+                .operate(operation::handle_position(Id::new("knob")))
+                .flatten()
+                .unwrap()
+        };
+
+        // Captured wheel events move the knob, but not the scrollable.
+        let mut harness = Harness::new(content(knob()), SIZE);
+        let _ = harness.move_cursor(Point::new(15.0, 15.0));
+        assert_eq!(harness.scroll(-1.0), event::Status::Captured);
+        assert_eq!(changed(&harness.take_messages()), [0.49]);
+        assert_eq!(position(&mut harness), Point::new(15.0, 15.0));
+
+        // Without capturing, the scrollable wins.
+        let mut harness = Harness::new(content(knob().capture_wheel(false)), SIZE);
+        let _ = harness.move_cursor(Point::new(15.0, 15.0));
+        let _ = harness.scroll(-1.0);
+        assert!(harness.take_messages().is_empty());
+        assert!(position(&mut harness).y < 15.0);
+
+        // A dragged knob holds on to the wheel, even away from it.
+        let mut harness = Harness::new(content(knob().capture_wheel(false)), SIZE);
+        let _ = harness.move_cursor(Point::new(15.0, 15.0));
+        let _ = harness.press();
+        let _ = harness.move_cursor(Point::new(100.0, 100.0));
+        assert_eq!(harness.scroll(-1.0), event::Status::Captured);
+        assert_eq!(position(&mut harness), Point::new(15.0, 15.0));
+    }
+
+    #[test]
+    fn knob_touch_drag() {
+        let mut harness = Harness::new(knob(), SIZE);
+        let center = harness.center();
+        let finger = touch::Finger(0);
+
+        for event in [
+            touch::Event::FingerPressed {
+                id: finger,
+                position: center,
+            },
+            touch::Event::FingerMoved {
+                id: finger,
+                position: center - Vector::new(0.0, 10.0),
+            },
+            touch::Event::FingerLifted {
+                id: finger,
+                position: center - Vector::new(0.0, 10.0),
+            },
+        ] {
+            let _ = harness.event(Event::Touch(event));
+        }
+
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 10.0 * 0.00385);
+    }
+
+    #[test]
+    fn knob_leaves_key_events() {
+        let mut harness = Harness::new(knob().steps(4), SIZE);
+        let _ = harness.move_cursor(harness.center());
+
+        // Key events are left for text inputs, even while hovered.
+        for event in key_events(keyboard::Modifiers::empty()) {
+            assert_eq!(harness.event(event), event::Status::Ignored);
+        }
+
+        // The modifiers are still observed without capturing them.
+        assert_eq!(
+            harness.modifiers(keyboard::Modifiers::SHIFT),
+            event::Status::Ignored
+        );
+        let _ = harness.scroll(1.0);
+        assert_close(changed(&harness.take_messages())[0], 0.5 + 0.01);
+    }
+
+    #[test]
+    fn knob_groups_wheel_steps_until_idle() {
+        let start = Instant::now();
+        let knob = Knob::new(param(0.5, 0.5), Message::Changed)
+            .wheel_gesture(Some(Duration::from_millis(200)))
+            .on_grab(|| Some(Message::Grabbed))
+            .on_release(|| Some(Message::Released));
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(knob, SIZE);
+        let _ = harness.move_cursor(harness.center());
+
+        for _ in 0..3 {
+            let _ = harness.scroll(1.0);
+        }
+        let messages = harness.take_messages();
+        assert_eq!(messages.first(), Some(&Message::Grabbed));
+        assert_eq!(changed(&messages).len(), 3);
+        assert!(!messages.contains(&Message::Released));
+
+        // The release waits for a frame after the wheel is idle.
+        assert!(matches!(
+            harness.redraw(start),
+            Some(window::RedrawRequest::At(_))
+        ));
+        assert!(harness.take_messages().is_empty());
+        assert_eq!(harness.redraw(start + Duration::from_secs(1)), None);
+        assert_eq!(harness.take_messages(), vec![Message::Released]);
+
+        // A press ends the gesture of the wheel before starting its own,
+        // which grabs once the press drags or is released.
+        let _ = harness.scroll(1.0);
+        let _ = harness.take_messages();
+        let _ = harness.press();
+        assert_eq!(harness.take_messages(), vec![Message::Released]);
+        let _ = harness.release();
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Grabbed, Message::Released]
+        );
+    }
+
+    #[test]
+    fn knob_drags_past_the_threshold() {
+        let knob = |threshold| {
+            Knob::new(param(0.5, 0.5), Message::Changed)
+                .drag_threshold(threshold)
+                .on_grab(|| Some(Message::Grabbed))
+                .on_release(|| Some(Message::Released))
+        };
+        let mut harness: Harness<'_, Message, Theme> =
+            Harness::new(knob(DragThreshold::DEFAULT), SIZE);
+        let center = harness.center();
+
+        // Wobbling within the threshold doesn't grab or move the knob...
+        let _ = harness.move_cursor(center);
+        let _ = harness.press();
+        let _ = harness.move_cursor(center + Vector::new(1.0, -1.0));
+        assert!(harness.take_messages().is_empty());
+
+        // ...and the value follows the whole drag once it is past it.
+        let _ = harness.move_cursor(center + Vector::new(0.0, -10.0));
+        let messages = harness.take_messages();
+        assert_eq!(messages.first(), Some(&Message::Grabbed));
+        assert!(changed(&messages)[0] > 0.5);
+        let _ = harness.release();
+        let _ = harness.take_messages();
+
+        // A click grabs and releases without a change.
+        let mut harness: Harness<'_, Message, Theme> =
+            Harness::new(knob(DragThreshold::DEFAULT), SIZE);
+        harness.drag(center, center + Vector::new(-1.0, 1.0));
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::Grabbed, Message::Released]
+        );
+
+        // Without a threshold, the press grabs right away.
+        let mut harness: Harness<'_, Message, Theme> =
+            Harness::new(knob(DragThreshold::DISABLED), SIZE);
+        let _ = harness.move_cursor(center);
+        let _ = harness.press();
+        assert_eq!(harness.take_messages(), vec![Message::Grabbed]);
+    }
+
+    fn line_notch() -> knob::NotchShape {
+        knob::NotchShape::Line(knob::LineNotch {
+            color: GRAY,
+            width: knob::StyleLength::Fixed(2.0),
+            length: knob::StyleLength::Scaled(0.2),
+            cap: knob::LineCap::Round,
+            offset: knob::StyleLength::Scaled(0.2),
+        })
+    }
+
+    #[test]
+    fn style_function_receives_status() {
+        let statuses = std::cell::RefCell::new(Vec::new());
+        let knob = knob().style(|theme, status| {
+            statuses.borrow_mut().push(status);
+            knob::default(theme, status)
+        });
+        let mut harness = Harness::new(knob, SIZE);
+
+        let _ = harness.snapshot(&Theme::Light);
+        let _ = harness.move_cursor(harness.center());
+        let _ = harness.snapshot(&Theme::Light);
+        let _ = harness.press();
+        let _ = harness.snapshot(&Theme::Light);
+
+        drop(harness);
+        assert_eq!(
+            statuses.into_inner(),
+            [Status::Active, Status::Hovered, Status::Dragged]
+        );
+    }
+
+    #[test]
+    fn knob_snapshots() {
+        knob_snapshot(None).assert_golden(snapshot_path("knob_circle"));
+
+        knob_snapshot(Some(knob::Appearance::Arc(knob::ArcAppearance {
+            width: knob::StyleLength::Fixed(3.0),
+            empty_color: LIGHT,
+            filled_color: BLUE,
+            notch: line_notch(),
+            cap: knob::LineCap::Square,
+            ends: knob::ArcEnds::default(),
+        })))
+        .assert_golden(snapshot_path("knob_arc"));
+
+        knob_snapshot(Some(knob::Appearance::ArcBipolar(
+            knob::ArcBipolarAppearance {
+                width: knob::StyleLength::Fixed(3.0),
+                empty_color: LIGHT,
+                left_filled_color: ORANGE,
+                right_filled_color: BLUE,
+                notch_center: line_notch(),
+                notch_left_right: None,
+                cap: knob::LineCap::Square,
+                ends: knob::ArcEnds::default(),
+            },
+        )))
+        .assert_golden(snapshot_path("knob_arc_bipolar"));
+    }
+
+    #[test]
+    fn knob_arc_segmented() {
+        let snapshot = knob_snapshot(Some(knob::Appearance::ArcSegmented(
+            knob::ArcSegmentedAppearance {
+                width: knob::StyleLength::Fixed(4.0),
+                segments: 4,
+                gap: 2.0,
+                shape: knob::SegmentShape::Dash,
+                lit_color: BLUE,
+                unlit_color: ORANGE,
+                notch: knob::NotchShape::None,
+            },
+        )));
+
+        // At 0.3, the first of the four segments, which starts at the bottom
+        // left, is lit, and the last one at the bottom right is not.
+        let rgba = |x, y| snapshot.pixel(x, y).map(Color::into_rgba8);
+        assert_eq!(rgba(3, 26), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(36, 26), Some(ORANGE.into_rgba8()));
+
+        // There is a gap between the segments, e.g. straight up between the
+        // second and the third one.
+        assert_eq!(rgba(20, 2), Some(Color::TRANSPARENT.into_rgba8()));
+    }
+
+    #[test]
+    fn knob_arc_ends() {
+        let knob = Knob::new(param(1.0, 0.5), Message::Changed)
+            .size(Length::Fixed(40.0))
+            .class(KnobStyle(knob::Appearance::Arc(knob::ArcAppearance {
+                width: knob::StyleLength::Fixed(3.0),
+                empty_color: LIGHT,
+                filled_color: BLUE,
+                notch: knob::NotchShape::None,
+                cap: knob::LineCap::Butt,
+                ends: knob::ArcEnds {
+                    gap: 0.5,
+                    dots: Some(knob::EndDots {
+                        diameter: 4.0,
+                        color: ORANGE,
+                    }),
+                },
+            })));
+        let snapshot = Harness::new(knob, SIZE).snapshot(&Theme::Light);
+        let rgba = |x, y| snapshot.pixel(x, y).map(Color::into_rgba8);
+
+        // The dots are at the minimum and maximum, at the bottom left and
+        // the bottom right.
+        assert_eq!(rgba(10, 36), Some(ORANGE.into_rgba8()));
+        assert_eq!(rgba(29, 36), Some(ORANGE.into_rgba8()));
+
+        // The arc is filled up to the gap, which is empty between the dots
+        // and the arc.
+        assert_eq!(rgba(20, 1), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(37, 26), Some(BLUE.into_rgba8()));
+        assert_eq!(rgba(6, 33), Some(Color::TRANSPARENT.into_rgba8()));
+        assert_eq!(rgba(33, 33), Some(Color::TRANSPARENT.into_rgba8()));
+    }
+
+    struct ModSourcesStyle;
+
+    impl knob::StyleSheet for ModSourcesStyle {
+        type Style = Theme;
+
+        fn appearance(&self, style: &Self::Style, status: Status) -> knob::Appearance {
+            knob::default(style, status)
+        }
+
+        fn mod_sources_arc_appearance(
+            &self,
+            _style: &Self::Style,
+        ) -> Option<knob::ModSourcesArcAppearance> {
+            Some(knob::ModSourcesArcAppearance {
+                width: 2.0,
+                offset: 1.0,
+                spacing: 1.0,
+                empty_color: Some(LIGHT),
+                cap: knob::LineCap::Butt,
+            })
+        }
+    }
+
+    #[test]
+    fn knob_mod_sources() {
+        let mod_sources = [
+            (ModulationRange::new(Normal::MIN, Normal::MAX), ORANGE),
+            (ModulationRange::new(Normal::MAX, Normal::MIN), BLUE),
+            (ModulationRange::new(Normal::MIN, Normal::CENTER), GRAY),
+        ];
+        let knob = Knob::new(param(0.3, 0.5), Message::Changed)
+            .size(Length::Fixed(20.0))
+            .mod_sources(&mod_sources)
+            .class(ModSourcesStyle);
+        let snapshot =
+            Harness::new(row![knob, Space::with_width(20.0)], SIZE).snapshot(&Theme::Light);
+        let pixel = |x| snapshot.pixel(x, 10).map(Color::into_rgba8);
+
+        // The rings are stacked outwards from the edge of the knob, each in
+        // the color of its source and filled as far as its range goes.
+        assert_eq!(pixel(22), Some(ORANGE.into_rgba8()));
+        assert_eq!(pixel(25), Some(BLUE.into_rgba8()));
+        assert_eq!(pixel(28), Some(LIGHT.into_rgba8()));
+    }
+
+    #[test]
+    fn knob_display_ignores_events() {
+        let mut harness = Harness::new(
+            Knob::<Message, Theme>::display(Normal::from_clipped(0.3)),
+            SIZE,
+        );
+        let center = harness.center();
+
+        let _ = harness.move_cursor(center);
+        assert_eq!(harness.press(), event::Status::Ignored);
+        assert_eq!(
+            harness.move_cursor(center - Vector::new(0.0, 20.0)),
+            event::Status::Ignored
+        );
+        assert_eq!(harness.release(), event::Status::Ignored);
+        assert_eq!(harness.scroll(1.0), event::Status::Ignored);
+        assert!(harness.take_messages().is_empty());
+    }
+
+    #[test]
+    fn knob_endless() {
+        let turned = |messages: Vec<Message>| -> f32 {
+            messages
+                .iter()
+                .map(|message| match message {
+                    Message::Turned(delta) => *delta,
+                    _ => 0.0,
+                })
+                .sum()
+        };
+
+        let endless = Knob::<Message, Theme>::endless(0.25, Message::Turned)
+            .on_grab(|| Some(Message::Grabbed))
+            .on_release(|| Some(Message::Released));
+        let mut harness = Harness::new(endless, SIZE);
+        let center = harness.center();
+
+        // Dragging never stops at an end, and a double click doesn't reset.
+        for _ in 0..2 {
+            harness.drag(center, center - Vector::new(0.0, 300.0));
+
+            let messages = harness.take_messages();
+            assert_eq!(messages.first(), Some(&Message::Grabbed));
+            assert_eq!(messages.last(), Some(&Message::Released));
+            assert_close(turned(messages), 300.0 * 0.00385);
+        }
+
+        harness.drag(center, center + Vector::new(0.0, 100.0));
+        assert_close(turned(harness.take_messages()), -100.0 * 0.00385);
+
+        let _ = harness.move_cursor(center);
+        assert_eq!(harness.scroll(-2.0), event::Status::Captured);
+        assert_close(turned(harness.take_messages()), -2.0 * 0.01);
+
+        // A circular drag reports whole turns as `1.0`, around and around.
+        let endless = Knob::<Message, Theme>::endless(0.0, Message::Turned)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .drag_mode(DragMode::Circular);
+        let mut harness = Harness::new(endless, SIZE);
+
+        let _ = harness.move_cursor(Point::new(50.0, 100.0));
+        let _ = harness.press();
+        for _ in 0..2 {
+            for (x, y) in [(100.0, 50.0), (150.0, 100.0), (100.0, 150.0), (50.0, 100.0)] {
+                let _ = harness.move_cursor(Point::new(x, y));
+            }
+        }
+        assert_close(turned(harness.take_messages()), 2.0);
+    }
+
+    #[test]
+    fn knob_endless_notch() {
+        let snapshot = |position| {
+            Harness::new(
+                Knob::<Message, Theme>::endless(position, Message::Turned).style(knob::arc),
+                Size::new(30.0, 30.0),
+            )
+            .snapshot(&Theme::Dark)
+        };
+
+        // The notch is at the position in turns from the top, and whole
+        // turns look the same.
+        assert_eq!(snapshot(0.25), snapshot(1.25));
+        assert_eq!(snapshot(-0.75), snapshot(0.25));
+        assert_ne!(snapshot(0.25), snapshot(0.5));
+    }
+
+    /// Draws the tick marks of a [`Knob`] as lines instead of circles.
+    struct LineTickMarks;
+
+    impl knob::StyleSheet for LineTickMarks {
+        type Style = Theme;
+
+        fn appearance(&self, _style: &Self::Style, _status: Status) -> knob::Appearance {
+            knob::Appearance::Circle(Default::default())
+        }
+
+        fn tick_marks_appearance(&self, _style: &Self::Style) -> Option<knob::TickMarksAppearance> {
+            let line = |length, width, color| tick_marks::Shape::Line {
+                length,
+                width,
+                color,
+            };
+
+            Some(knob::TickMarksAppearance {
+                style: tick_marks::Appearance {
+                    tier_1: line(4.0, 2.0, BLUE),
+                    tier_2: line(3.0, 1.0, ORANGE),
+                    tier_3: line(2.0, 1.0, GRAY),
+                    density: Default::default(),
+                },
+                offset: 3.5,
+            })
+        }
+    }
+
+    #[test]
+    fn knob_tick_marks() {
+        let tick_marks = tick_marks::Group::subdivided(1, 3, 4, Some(tick_marks::Tier::Two));
+        let knob = || {
+            Knob::new(param(0.3, 0.5), Message::Changed)
+                .size(Length::Fixed(40.0))
+                .tick_marks(&tick_marks)
+        };
+        let snapshot =
+            |knob| Harness::new(container(knob).padding(10), SIZE).snapshot(&Theme::Light);
+
+        snapshot(knob()).assert_golden(snapshot_path("knob_tick_marks_circle"));
+        snapshot(knob().class(LineTickMarks)).assert_golden(snapshot_path("knob_tick_marks_line"));
+    }
+
+    #[test]
+    fn knob_display_updates() {
+        let center = tick_marks::Group::center(tick_marks::Tier::One);
+        let min_max = tick_marks::Group::min_max(tick_marks::Tier::One);
+        let display = |value, tick_marks| {
+            container(
+                Knob::<Message, Theme>::display(Normal::from_clipped(value))
+                    .size(Length::Fixed(40.0))
+                    .tick_marks(tick_marks),
+            )
+            .padding(10)
+        };
+        let fresh = |value, tick_marks| {
+            Harness::new(display(value, tick_marks), SIZE).snapshot(&Theme::Light)
+        };
+
+        // The cached tick marks are drawn along with the new value...
+        let mut harness = Harness::new(display(0.3, &center), SIZE);
+        let _ = harness.snapshot(&Theme::Light);
+        harness.rebuild(display(0.8, &center));
+        let snapshot = harness.snapshot(&Theme::Light);
+        assert_eq!(snapshot.count_differences(&fresh(0.8, &center), 0), Some(0));
+        assert_ne!(snapshot.count_differences(&fresh(0.3, &center), 0), Some(0));
+
+        // ...until the tick marks change.
+        harness.rebuild(display(0.8, &min_max));
+        let snapshot = harness.snapshot(&Theme::Light);
+        assert_eq!(
+            snapshot.count_differences(&fresh(0.8, &min_max), 0),
+            Some(0)
+        );
+        assert_ne!(snapshot.count_differences(&fresh(0.8, &center), 0), Some(0));
+    }
+
+    fn wide_knob_snapshot(align_x: Horizontal, content_fit: ContentFit) -> Snapshot {
+        let knob = Knob::new(param(0.3, 0.5), Message::Changed)
+            .width(Length::Fixed(80.0))
+            .height(Length::Fixed(40.0))
+            .align_x(align_x)
+            .content_fit(content_fit);
+
+        Harness::new(knob, SIZE).snapshot(&Theme::Light)
+    }
+
+    #[test]
+    fn knob_content_fit() {
+        let opaque =
+            |snapshot: &Snapshot, x| snapshot.pixel(x, 20).map(|color| color.a) == Some(1.0);
+
+        let left = wide_knob_snapshot(Horizontal::Left, ContentFit::Contain);
+        assert!(opaque(&left, 20) && !opaque(&left, 60));
+
+        let right = wide_knob_snapshot(Horizontal::Right, ContentFit::Contain);
+        assert!(!opaque(&right, 20) && opaque(&right, 60));
+
+        // Centered by default, like the knob in square bounds.
+        let center = wide_knob_snapshot(Horizontal::Center, ContentFit::Contain);
+        assert!(!opaque(&center, 10) && opaque(&center, 40) && !opaque(&center, 70));
+
+        let stretched = wide_knob_snapshot(Horizontal::Left, ContentFit::Stretch);
+        assert!(opaque(&stretched, 5) && opaque(&stretched, 75));
+        stretched.assert_golden(snapshot_path("knob_circle_stretched"));
+    }
+
+    fn texture_notch_snapshot(value: f32, scale_factor: f32) -> Snapshot {
+        use crate::style::texture::TextureHandle;
+        use iced::advanced::image::Handle;
+
+        // A red image, with a green version twice as large for HiDPI.
+        let red = Handle::from_rgba(4, 4, [255, 0, 0, 255].repeat(16));
+        let green = Handle::from_rgba(8, 8, [0, 255, 0, 255].repeat(64));
+        let appearance = knob::Appearance::Circle(knob::CircleAppearance {
+            notch: knob::NotchShape::Texture(knob::TextureNotch {
+                image_handle: TextureHandle::new(red).with_scale(2.0, green),
+                image_bounds: iced::Rectangle::new(Point::new(-2.0, -2.0), Size::new(4.0, 4.0)),
+                offset: knob::StyleLength::Scaled(0.15),
+                rotate: true,
+            }),
+            ..Default::default()
+        });
+        let knob = Knob::new(param(value, 0.5), Message::Changed)
+            .size(Length::Fixed(40.0))
+            .scale_factor(scale_factor)
+            .class(KnobStyle(appearance));
+
+        Harness::new(knob, SIZE).snapshot(&Theme::Light)
+    }
+
+    #[test]
+    fn knob_texture_notch() {
+        let is_red =
+            |snapshot: &Snapshot| snapshot.pixel(20, 6) == Some(Color::from_rgb8(255, 0, 0));
+
+        // The notch points straight up in the center, 6 pixels from the edge.
+        assert!(is_red(&texture_notch_snapshot(0.5, 1.0)));
+        assert!(!is_red(&texture_notch_snapshot(0.0, 1.0)));
+    }
+
+    #[test]
+    fn knob_texture_notch_follows_the_scale_factor() {
+        let green = Color::from_rgb8(0, 255, 0);
+
+        assert_eq!(texture_notch_snapshot(0.5, 2.0).pixel(20, 6), Some(green));
+        assert_eq!(texture_notch_snapshot(0.5, 1.5).pixel(20, 6), Some(green));
+    }
+
+    fn notch_snapshot(notch: knob::NotchShape, value: f32) -> Snapshot {
+        let appearance = knob::Appearance::Circle(knob::CircleAppearance {
+            notch,
+            ..Default::default()
+        });
+        let knob = Knob::new(param(value, 0.5), Message::Changed)
+            .size(Length::Fixed(40.0))
+            .class(KnobStyle(appearance));
+
+        Harness::new(knob, SIZE).snapshot(&Theme::Light)
+    }
+
+    #[test]
+    fn knob_triangle_and_custom_notch() {
+        let red = Color::from_rgb8(255, 0, 0);
+        let is_red = |snapshot: &Snapshot, y| snapshot.pixel(20, y) == Some(red);
+
+        // The tip is 2 pixels from the edge, and the base 8 pixels below.
+        let triangle = |value| {
+            notch_snapshot(
+                knob::NotchShape::Triangle(knob::TriangleNotch {
+                    color: red,
+                    width: knob::StyleLength::Fixed(6.0),
+                    length: knob::StyleLength::Fixed(8.0),
+                    offset: knob::StyleLength::Fixed(2.0),
+                }),
+                value,
+            )
+        };
+        assert!(is_red(&triangle(0.5), 7));
+        assert!(!is_red(&triangle(0.5), 1) && !is_red(&triangle(0.5), 12));
+        assert!(!is_red(&triangle(0.0), 7));
+
+        // A bar from the edge towards the center, as long as the value.
+        let custom = |value| {
+            notch_snapshot(
+                knob::NotchShape::Custom(knob::CustomNotch::new(red, |info| {
+                    let length = info.radius * info.value.as_f32();
+
+                    Path::rectangle(Point::new(-2.0, -info.radius), Size::new(4.0, length))
+                })),
+                value,
+            )
+        };
+        assert!(is_red(&custom(0.5), 6) && !is_red(&custom(0.5), 14));
+        assert!(!is_red(&custom(0.0), 6));
+    }
+}
@@ -1,40 +1,281 @@
+use std::{
+    cell::{Ref, RefCell},
+    f32::consts::{FRAC_PI_2, TAU},
+};
+
 use crate::{
-    style::knob::{
-        ArcAppearance, ArcBipolarAppearance, CircleAppearance, CircleNotch, LineNotch,
-        ModRangeArcAppearance, NotchShape, TextMarksAppearance, TickMarksAppearance,
-        ValueArcAppearance,
+    core::math::THREE_HALVES_PI,
+    style::{
+        knob::{
+            ArcAppearance, ArcBipolarAppearance, ArcEnds, ArcSegmentedAppearance, CircleAppearance,
+            CircleNotch, CustomNotch, DefaultMarkerAppearance, LineNotch, ModRangeArcAppearance,
+            ModRangePlacement, ModSourcesArcAppearance, NotchInfo, NotchShape, SegmentShape,
+            TextMarksAppearance, TextureNotch, TickMarksAppearance, TriangleNotch,
+            ValueArcAppearance,
+        },
+        Dim,
     },
     text_marks, tick_marks,
-    widget::knob::{bipolar_state::BipolarState, KnobInfo, ValueMarkers},
-    ModulationRange, Normal,
+    widget::knob::{KnobInfo, ValueMarkers},
+    BipolarSide, ModulationRange, Normal,
 };
 use iced::{
-    advanced::{graphics::geometry::Renderer as _, renderer::Quad, Renderer as _},
+    advanced::{graphics::geometry, image, renderer::Quad, text},
     border::Radius,
-    widget::canvas::{self, path::Arc, Frame, Path, Stroke},
-    Border, Point, Radians, Rectangle, Renderer, Shadow, Size, Vector,
+    widget::canvas::{
+        self,
+        path::{self, Arc},
+        Fill, Frame, Path, Stroke,
+    },
+    Border, Color, Font, Point, Radians, Rectangle, Shadow, Size, Vector,
 };
 
-pub fn markers(
+/// Returns the size of a frame spanning `half_size` around the center of the
+/// knob, stretched by the scale of the knob.
+fn frame_size(knob_info: &KnobInfo, half_size: f32) -> Size {
+    let size = half_size * 2.0;
+
+    Size::new(size * knob_info.scale.x, size * knob_info.scale.y)
+}
+
+/// Stretches the contents of `frame` by the scale of the knob.
+fn scale_frame<Renderer>(frame: &mut Frame<Renderer>, knob_info: &KnobInfo)
+where
+    Renderer: geometry::Renderer,
+{
+    if knob_info.is_stretched() {
+        frame.scale_nonuniform(knob_info.scale);
+    }
+}
+
+/// Creates a frame spanning `half_size` around the center of the knob,
+/// stretched by the scale of the knob.
+fn centered_frame<Renderer>(
+    renderer: &Renderer,
+    knob_info: &KnobInfo,
+    half_size: f32,
+) -> Frame<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    let mut frame = Frame::new(renderer, frame_size(knob_info, half_size));
+
+    scale_frame(&mut frame, knob_info);
+
+    frame
+}
+
+/// Draws a frame created with `centered_frame()`.
+fn draw_centered_frame<Renderer>(
+    renderer: &mut Renderer,
+    knob_info: &KnobInfo,
+    half_size: f32,
+    frame: Frame<Renderer>,
+) where
+    Renderer: geometry::Renderer,
+{
+    draw_centered_geometry(renderer, knob_info, half_size, frame.into_geometry());
+}
+
+/// Draws the geometry of a frame spanning `half_size` around the center of
+/// the knob.
+fn draw_centered_geometry<Renderer>(
+    renderer: &mut Renderer,
+    knob_info: &KnobInfo,
+    half_size: f32,
+    geometry: Renderer::Geometry,
+) where
+    Renderer: geometry::Renderer,
+{
+    let center = knob_info.bounds.center();
+
+    renderer.with_translation(
+        Vector::new(
+            center.x - half_size * knob_info.scale.x,
+            center.y - half_size * knob_info.scale.y,
+        ),
+        |renderer| {
+            renderer.draw_geometry(geometry);
+        },
+    );
+}
+
+/// Returns the bounds that the marks, arcs and default marker in
+/// `value_markers` are drawn in around the knob.
+pub fn value_markers_bounds(knob_info: &KnobInfo, value_markers: &ValueMarkers<'_>) -> Rectangle {
+    let tick_marks = value_markers
+        .tick_marks
+        .and(value_markers.tick_marks_style.as_ref())
+        .map(|style| style.offset + style.style.max_length());
+    let mod_range_1 = value_markers
+        .mod_range_1
+        .and(value_markers.mod_range_style_1.as_ref())
+        .map(|style| style.offset + style.width);
+    let mod_range_2 = value_markers
+        .mod_range_2
+        .and(value_markers.mod_range_style_2.as_ref())
+        .map(|style| style.offset + style.width);
+    let mod_sources = value_markers
+        .mod_sources_style
+        .as_ref()
+        .filter(|_| !value_markers.mod_sources.is_empty())
+        .map(|style| {
+            style.offset + value_markers.mod_sources.len() as f32 * (style.width + style.spacing)
+        });
+    let value_arc = value_markers
+        .value_arc_style
+        .as_ref()
+        .map(|style| style.offset + style.width);
+    let default_marker = value_markers
+        .default_marker_style
+        .as_ref()
+        .map(|style| style.offset + style.length + style.width);
+
+    let reach = [
+        tick_marks,
+        mod_range_1,
+        mod_range_2,
+        mod_sources,
+        value_arc,
+        default_marker,
+    ]
+    .into_iter()
+    .flatten()
+    .fold(0.0, f32::max);
+
+    let center = knob_info.bounds.center();
+    let around_center = |half_size: Vector| {
+        Rectangle::new(
+            center - half_size,
+            Size::new(half_size.x * 2.0, half_size.y * 2.0),
+        )
+    };
+
+    let radius = knob_info.radius + reach;
+    let bounds = around_center(Vector::new(
+        radius * knob_info.scale.x,
+        radius * knob_info.scale.y,
+    ));
+
+    // The text of the text marks isn't stretched with the knob, and longer
+    // labels are moved further out to the sides.
+    match value_markers
+        .text_marks
+        .zip(value_markers.text_marks_style.as_ref())
+    {
+        Some((text_marks, style)) => {
+            let longest = text_marks
+                .group
+                .iter()
+                .map(|(_, text)| text.len())
+                .max()
+                .unwrap_or(0);
+            let radius = knob_info.radius + style.offset;
+
+            bounds.union(&around_center(Vector::new(
+                (radius * knob_info.scale.x)
+                    + (f32::from(style.style.bounds_width) / 2.0)
+                    + (longest.saturating_sub(1) as f32 * style.h_char_offset),
+                (radius * knob_info.scale.y)
+                    + (f32::from(style.style.bounds_height) / 2.0)
+                    + style.v_offset.abs(),
+            )))
+        }
+        None => bounds,
+    }
+}
+
+/// Everything the tick marks of a knob are drawn from.
+#[derive(Debug, Clone, PartialEq)]
+struct TickMarksKey {
+    tick_marks: tick_marks::Group,
+    style: TickMarksAppearance,
+    radius: f32,
+    start_angle: f32,
+    angle_span: f32,
+    scale: Vector,
+}
+
+/// Keeps the tick marks of a knob and their geometry until anything they
+/// are drawn from changes, so that only the moving parts of a knob are
+/// rebuilt when its value changes.
+pub struct TickMarksCache<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    cache: canvas::Cache<Renderer>,
+    key: RefCell<Option<TickMarksKey>>,
+    marks: RefCell<Vec<tick_marks::RadialMark>>,
+}
+
+impl<Renderer> Default for TickMarksCache<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn default() -> Self {
+        Self {
+            cache: canvas::Cache::new(),
+            key: RefCell::new(None),
+            marks: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<Renderer> TickMarksCache<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    /// Returns the cached tick marks, or places them again with `place_fn`
+    /// and clears the geometry if `key` changed.
+    fn marks(
+        &self,
+        key: TickMarksKey,
+        place_fn: impl FnOnce() -> Vec<tick_marks::RadialMark>,
+    ) -> Ref<'_, [tick_marks::RadialMark]> {
+        let mut current = self.key.borrow_mut();
+
+        if current.as_ref() != Some(&key) {
+            self.cache.clear();
+            *self.marks.borrow_mut() = place_fn();
+            *current = Some(key);
+        }
+
+        Ref::map(self.marks.borrow(), Vec::as_slice)
+    }
+
+    /// Returns the cached geometry, or draws it again with `draw_fn`.
+    fn draw(
+        &self,
+        renderer: &Renderer,
+        size: Size,
+        draw_fn: impl FnOnce(&mut Frame<Renderer>),
+    ) -> Renderer::Geometry {
+        self.cache.draw(renderer, size, draw_fn)
+    }
+}
+
+pub fn markers<Renderer>(
     renderer: &mut Renderer,
     knob_info: &KnobInfo,
     value_markers: &ValueMarkers<'_>,
-    //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    tick_marks_cache: &TickMarksCache<Renderer>,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font> + geometry::Renderer,
+{
     tick_marks(
         renderer,
         knob_info,
         value_markers.tick_marks,
         &value_markers.tick_marks_style,
-        //tick_marks_cache,
+        tick_marks_cache,
     );
     text_marks(
         renderer,
         knob_info,
         value_markers.text_marks,
         &value_markers.text_marks_style,
-        //text_marks_cache,
+        text_marks_cache,
     );
 
     value_arc(renderer, knob_info, &value_markers.value_arc_style);
@@ -52,74 +293,204 @@ pub fn markers(
         &value_markers.mod_range_style_2,
         value_markers.mod_range_2,
     );
+
+    mod_sources_arcs(
+        renderer,
+        knob_info,
+        &value_markers.mod_sources_style,
+        value_markers.mod_sources,
+    );
+    default_marker(
+        renderer,
+        knob_info,
+        value_markers.default,
+        &value_markers.default_marker_style,
+    );
 }
 
-fn tick_marks(
+fn tick_marks<Renderer>(
     renderer: &mut Renderer,
     knob_info: &KnobInfo,
     tick_marks: Option<&tick_marks::Group>,
     style: &Option<TickMarksAppearance>,
-    //tick_marks_cache: &tick_marks::PrimitiveCache,
-) {
+    tick_marks_cache: &TickMarksCache<Renderer>,
+) where
+    Renderer: geometry::Renderer,
+{
     if let Some(tick_marks) = tick_marks {
         if let Some(style) = style {
-            tick_marks::draw_radial_tick_marks(
-                renderer,
-                knob_info.bounds.center(),
-                knob_info.radius + style.offset,
-                knob_info.start_angle + std::f32::consts::FRAC_PI_2,
-                knob_info.angle_span,
-                false,
-                tick_marks,
-                &style.style,
-                false,
-                //tick_marks_cache,
-            )
+            let radius = knob_info.radius + style.offset;
+
+            let marks = tick_marks_cache.marks(
+                TickMarksKey {
+                    tick_marks: tick_marks.clone(),
+                    style: style.clone(),
+                    radius: knob_info.radius,
+                    start_angle: knob_info.start_angle,
+                    angle_span: knob_info.angle_span,
+                    scale: knob_info.scale,
+                },
+                || {
+                    tick_marks::radial_tick_marks(
+                        radius,
+                        knob_info.start_angle + FRAC_PI_2,
+                        knob_info.angle_span,
+                        false,
+                        tick_marks,
+                        &style.style,
+                        false,
+                    )
+                },
+            );
+
+            let is_line = |mark: &&tick_marks::RadialMark| {
+                matches!(mark, tick_marks::RadialMark::Line { .. })
+            };
+
+            // Every geometry has a cost of its own, so the circles of a knob
+            // that is not stretched are drawn as quads, like the tick marks
+            // of the sliders, and only the lines are left to the geometry.
+            let circles_as_quads = !knob_info.is_stretched();
+
+            if circles_as_quads {
+                let center = knob_info.bounds.center();
+
+                for mark in marks.iter() {
+                    if let tick_marks::RadialMark::Circle {
+                        center: offset,
+                        radius,
+                        color,
+                    } = *mark
+                    {
+                        renderer.fill_quad(
+                            Quad {
+                                bounds: Rectangle::new(
+                                    center + (offset - Point::ORIGIN) - Vector::new(radius, radius),
+                                    Size::new(radius * 2.0, radius * 2.0),
+                                ),
+                                border: Border {
+                                    radius: Radius::new(radius),
+                                    ..Border::default()
+                                },
+                                shadow: Shadow::default(),
+                            },
+                            color,
+                        );
+                    }
+                }
+
+                if !marks.iter().any(|mark| is_line(&mark)) {
+                    return;
+                }
+            }
+
+            let frame_radius = tick_marks::radial_frame_radius(radius, false, &style.style);
+
+            let geometry =
+                tick_marks_cache.draw(renderer, frame_size(knob_info, frame_radius), |frame| {
+                    scale_frame(frame, knob_info);
+
+                    frame.translate(Vector::new(frame_radius, frame_radius));
+
+                    if circles_as_quads {
+                        let lines: Vec<_> = marks.iter().filter(is_line).copied().collect();
+
+                        tick_marks::fill_radial_marks(frame, &lines);
+                    } else {
+                        tick_marks::fill_radial_marks(frame, &marks);
+                    }
+                });
+
+            draw_centered_geometry(renderer, knob_info, frame_radius, geometry);
         }
     }
 }
 
-fn text_marks(
+fn text_marks<Renderer>(
     renderer: &mut Renderer,
     knob_info: &KnobInfo,
     text_marks: Option<&text_marks::Group>,
     style: &Option<TextMarksAppearance>,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
     if let Some(text_marks) = text_marks {
         if let Some(style) = style {
-            text_marks::draw_radial_text_marks(
+            let radius = knob_info.radius + style.offset;
+
+            text_marks::draw_elliptical_text_marks(
                 renderer,
                 Point::new(
                     knob_info.bounds.center_x(),
                     knob_info.bounds.center_y() + style.v_offset,
                 ),
-                knob_info.radius + style.offset,
+                Vector::new(radius * knob_info.scale.x, radius * knob_info.scale.y),
                 knob_info.start_angle,
                 knob_info.angle_span,
                 text_marks,
                 &style.style,
                 style.h_char_offset,
                 false,
-                //text_marks_cache,
+                text_marks_cache,
             )
         }
     }
 }
 
-fn value_arc(renderer: &mut Renderer, knob_info: &KnobInfo, style: &Option<ValueArcAppearance>) {
+/// Returns the angles that an arc with `ends` starts and ends at, inset
+/// from the angle range of the knob by the gap.
+fn arc_range(knob_info: &KnobInfo, ends: &ArcEnds) -> (f32, f32) {
+    let gap = ends.gap.clamp(0.0, knob_info.angle_span / 2.0);
+
+    (
+        knob_info.start_angle + gap,
+        knob_info.start_angle + knob_info.angle_span - gap,
+    )
+}
+
+/// Fills the dots of `ends` at the minimum and maximum of the angle range,
+/// on an arc of `radius` around `center`.
+fn end_dots<Renderer>(
+    frame: &mut Frame<Renderer>,
+    knob_info: &KnobInfo,
+    center: Point,
+    radius: f32,
+    ends: &ArcEnds,
+) where
+    Renderer: geometry::Renderer,
+{
+    if let Some(dots) = ends.dots {
+        for angle in [
+            knob_info.start_angle,
+            knob_info.start_angle + knob_info.angle_span,
+        ] {
+            let (sin, cos) = angle.sin_cos();
+            let dot_center = Point::new(center.x + cos * radius, center.y + sin * radius);
+
+            frame.fill(&Path::circle(dot_center, dots.diameter / 2.0), dots.color);
+        }
+    }
+}
+
+fn value_arc<Renderer>(
+    renderer: &mut Renderer,
+    knob_info: &KnobInfo,
+    style: &Option<ValueArcAppearance>,
+) where
+    Renderer: geometry::Renderer,
+{
     if let Some(style) = style {
         let half_width = style.width / 2.0;
 
-        let end_angle = knob_info.start_angle + knob_info.angle_span;
+        let (start_angle, end_angle) = arc_range(knob_info, &style.ends);
+        let value_angle = knob_info.value_angle.clamp(start_angle, end_angle);
         let arc_radius = knob_info.radius + style.offset + half_width;
 
         let half_frame_size = (arc_radius + half_width).ceil();
-        let frame_size = half_frame_size * 2.0;
-        let frame_offset = half_frame_size - knob_info.radius;
         let center_point = Point::new(half_frame_size, half_frame_size);
 
-        let mut frame = Frame::new(renderer, Size::new(frame_size, frame_size));
+        let mut frame = centered_frame(renderer, knob_info, half_frame_size);
 
         if let Some(empty_color) = style.empty_color {
             let empty_stroke = Stroke {
@@ -132,7 +503,7 @@ fn value_arc(renderer: &mut Renderer, knob_info: &KnobInfo, style: &Option<Value
             let empty_arc = Arc {
                 center: center_point,
                 radius: arc_radius,
-                start_angle: Radians(knob_info.start_angle),
+                start_angle: Radians(start_angle),
                 end_angle: Radians(end_angle),
             };
 
@@ -142,10 +513,12 @@ fn value_arc(renderer: &mut Renderer, knob_info: &KnobInfo, style: &Option<Value
         }
 
         if let Some(right_filled_color) = style.right_filled_color {
-            if knob_info.value.as_f32() < 0.499 || knob_info.value.as_f32() > 0.501 {
-                let half_angle = knob_info.start_angle + (knob_info.angle_span / 2.0);
+            let center_angle = knob_info
+                .bipolar_center_angle()
+                .clamp(start_angle, end_angle);
 
-                if knob_info.value < Normal::CENTER {
+            match knob_info.bipolar_side() {
+                BipolarSide::Left => {
                     let filled_stroke = Stroke {
                         width: style.width,
                         style: canvas::Style::Solid(style.left_filled_color),
@@ -156,14 +529,15 @@ fn value_arc(renderer: &mut Renderer, knob_info: &KnobInfo, style: &Option<Value
                     let filled_arc = Arc {
                         center: center_point,
                         radius: arc_radius,
-                        start_angle: Radians(knob_info.value_angle),
-                        end_angle: Radians(half_angle),
+                        start_angle: Radians(value_angle),
+                        end_angle: Radians(center_angle),
                     };
 
                     let filled_path = Path::new(|path| path.arc(filled_arc));
 
                     frame.stroke(&filled_path, filled_stroke);
-                } else if knob_info.value > Normal::CENTER {
+                }
+                BipolarSide::Right => {
                     let filled_stroke = Stroke {
                         width: style.width,
                         style: canvas::Style::Solid(right_filled_color),
@@ -174,14 +548,15 @@ fn value_arc(renderer: &mut Renderer, knob_info: &KnobInfo, style: &Option<Value
                     let filled_arc = Arc {
                         center: center_point,
                         radius: arc_radius,
-                        start_angle: Radians(half_angle),
-                        end_angle: Radians(knob_info.value_angle),
+                        start_angle: Radians(center_angle),
+                        end_angle: Radians(value_angle),
                     };
 
                     let filled_path = Path::new(|path| path.arc(filled_arc));
 
                     frame.stroke(&filled_path, filled_stroke);
                 }
+                BipolarSide::Center => (),
             }
         } else if knob_info.value != Normal::MIN {
             let filled_stroke = Stroke {
@@ -194,8 +569,8 @@ fn value_arc(renderer: &mut Renderer, knob_info: &KnobInfo, style: &Option<Value
             let filled_arc = Arc {
                 center: center_point,
                 radius: arc_radius,
-                start_angle: Radians(knob_info.start_angle),
-                end_angle: Radians(knob_info.value_angle),
+                start_angle: Radians(start_angle),
+                end_angle: Radians(value_angle),
             };
 
             let filled_path = Path::new(|path| path.arc(filled_arc));
@@ -203,35 +578,34 @@ fn value_arc(renderer: &mut Renderer, knob_info: &KnobInfo, style: &Option<Value
             frame.stroke(&filled_path, filled_stroke);
         }
 
-        renderer.with_translation(
-            Vector::new(
-                knob_info.bounds.x - frame_offset,
-                knob_info.bounds.y - frame_offset,
-            ),
-            |renderer| {
-                renderer.draw_geometry(frame.into_geometry());
-            },
-        );
+        end_dots(&mut frame, knob_info, center_point, arc_radius, &style.ends);
+
+        draw_centered_frame(renderer, knob_info, half_frame_size, frame);
     }
 }
 
-fn mod_range_arc(
+fn mod_range_arc<Renderer>(
     renderer: &mut Renderer,
     knob_info: &KnobInfo,
     style: &Option<ModRangeArcAppearance>,
     mod_range: Option<&ModulationRange>,
-) {
+) where
+    Renderer: geometry::Renderer,
+{
     if let Some(mod_range) = mod_range {
         if let Some(style) = style {
             let half_width = style.width / 2.0;
             let arc_radius = knob_info.radius + style.offset + half_width;
 
             let half_frame_size = (arc_radius + half_width).ceil();
-            let frame_size = half_frame_size * 2.0;
-            let frame_offset = half_frame_size - knob_info.radius;
             let center_point = Point::new(half_frame_size, half_frame_size);
 
-            let mut frame = Frame::new(renderer, Size::new(frame_size, frame_size));
+            let mut frame = centered_frame(renderer, knob_info, half_frame_size);
+
+            let (start_angle, angle_span) = match style.placement {
+                ModRangePlacement::Sweep => (knob_info.start_angle, knob_info.angle_span),
+                ModRangePlacement::Ring => (THREE_HALVES_PI, TAU),
+            };
 
             if let Some(empty_color) = style.empty_color {
                 let empty_stroke = Stroke {
@@ -244,8 +618,8 @@ fn mod_range_arc(
                 let empty_arc = Arc {
                     center: center_point,
                     radius: arc_radius,
-                    start_angle: Radians(knob_info.start_angle),
-                    end_angle: Radians(knob_info.start_angle + knob_info.angle_span),
+                    start_angle: Radians(start_angle),
+                    end_angle: Radians(start_angle + angle_span),
                 };
 
                 let empty_path = Path::new(|path| path.arc(empty_arc));
@@ -253,19 +627,11 @@ fn mod_range_arc(
                 frame.stroke(&empty_path, empty_stroke);
             }
 
-            if mod_range.filled_visible && (mod_range.start != mod_range.end) {
-                let (start, end, color) = if mod_range.start.as_f32() < mod_range.end.as_f32() {
-                    (
-                        mod_range.start.as_f32(),
-                        mod_range.end.as_f32(),
-                        style.filled_color,
-                    )
+            if let Some((start, end)) = mod_range.filled() {
+                let color = if mod_range.is_inverse() {
+                    style.filled_inverse_color
                 } else {
-                    (
-                        mod_range.end.as_f32(),
-                        mod_range.start.as_f32(),
-                        style.filled_inverse_color,
-                    )
+                    style.filled_color
                 };
 
                 let filled_stroke = Stroke {
@@ -278,8 +644,8 @@ fn mod_range_arc(
                 let filled_arc = Arc {
                     center: center_point,
                     radius: arc_radius,
-                    start_angle: Radians(knob_info.start_angle + (knob_info.angle_span * start)),
-                    end_angle: Radians(knob_info.start_angle + (knob_info.angle_span * end)),
+                    start_angle: Radians(start_angle + start.scale(angle_span)),
+                    end_angle: Radians(start_angle + end.scale(angle_span)),
                 };
 
                 let filled_path = Path::new(|path| path.arc(filled_arc));
@@ -287,20 +653,78 @@ fn mod_range_arc(
                 frame.stroke(&filled_path, filled_stroke);
             }
 
-            renderer.with_translation(
-                Vector::new(
-                    knob_info.bounds.x - frame_offset,
-                    knob_info.bounds.y - frame_offset,
-                ),
-                |renderer| {
-                    renderer.draw_geometry(frame.into_geometry());
-                },
-            );
+            draw_centered_frame(renderer, knob_info, half_frame_size, frame);
         }
     }
 }
 
-fn circle_notch(renderer: &mut Renderer, knob_info: &KnobInfo, style: &CircleNotch) {
+fn mod_sources_arcs<Renderer>(
+    renderer: &mut Renderer,
+    knob_info: &KnobInfo,
+    style: &Option<ModSourcesArcAppearance>,
+    mod_sources: &[(ModulationRange, Color)],
+) where
+    Renderer: geometry::Renderer,
+{
+    if let Some(style) = style {
+        for (i, (mod_range, color)) in mod_sources.iter().enumerate() {
+            let ring_style = ModRangeArcAppearance {
+                width: style.width,
+                offset: style.offset + i as f32 * (style.width + style.spacing),
+                empty_color: style.empty_color,
+                filled_color: color.dim(knob_info.opacity),
+                filled_inverse_color: color.dim(knob_info.opacity),
+                cap: style.cap,
+                placement: ModRangePlacement::Sweep,
+            };
+
+            mod_range_arc(renderer, knob_info, &Some(ring_style), Some(mod_range));
+        }
+    }
+}
+
+fn default_marker<Renderer>(
+    renderer: &mut Renderer,
+    knob_info: &KnobInfo,
+    default: Normal,
+    style: &Option<DefaultMarkerAppearance>,
+) where
+    Renderer: geometry::Renderer,
+{
+    if let Some(style) = style {
+        let inner_radius = knob_info.radius + style.offset;
+        let outer_radius = inner_radius + style.length;
+
+        let half_frame_size = (outer_radius + style.width).ceil();
+
+        let mut frame = centered_frame(renderer, knob_info, half_frame_size);
+
+        let (dy, dx) = (knob_info.start_angle + default.scale(knob_info.angle_span)).sin_cos();
+        let point_at = |radius: f32| {
+            Point::new(
+                half_frame_size + (dx * radius),
+                half_frame_size + (dy * radius),
+            )
+        };
+
+        frame.stroke(
+            &Path::line(point_at(inner_radius), point_at(outer_radius)),
+            Stroke {
+                width: style.width,
+                style: canvas::Style::Solid(style.color),
+                line_cap: style.cap,
+                ..Stroke::default()
+            },
+        );
+
+        draw_centered_frame(renderer, knob_info, half_frame_size, frame);
+    }
+}
+
+fn circle_notch<Renderer>(renderer: &mut Renderer, knob_info: &KnobInfo, style: &CircleNotch)
+where
+    Renderer: geometry::Renderer,
+{
     let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
 
     let (dx, dy) = if !(-0.001..=0.001).contains(&value_angle) {
@@ -314,6 +738,40 @@ fn circle_notch(renderer: &mut Renderer, knob_info: &KnobInfo, style: &CircleNot
 
     let offset_radius = knob_info.radius - style.offset.from_knob_diameter(knob_info.bounds.width);
 
+    if knob_info.is_stretched() {
+        // Quads are drawn below geometry, so the notch has to be geometry
+        // too in order to show up on top of the stretched knob.
+        let mut frame = centered_frame(renderer, knob_info, knob_info.radius);
+
+        let center_point = Point::new(
+            knob_info.radius + (dx * offset_radius),
+            knob_info.radius - (dy * offset_radius),
+        );
+
+        frame.fill(
+            &Path::circle(center_point, notch_radius),
+            Fill {
+                style: canvas::Style::Solid(style.color),
+                ..Fill::default()
+            },
+        );
+
+        if style.border_width > 0.0 {
+            frame.stroke(
+                &Path::circle(center_point, notch_radius - style.border_width / 2.0),
+                Stroke {
+                    width: style.border_width,
+                    style: canvas::Style::Solid(style.border_color),
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        draw_centered_frame(renderer, knob_info, knob_info.radius, frame);
+
+        return;
+    }
+
     renderer.fill_quad(
         Quad {
             bounds: Rectangle {
@@ -333,7 +791,10 @@ fn circle_notch(renderer: &mut Renderer, knob_info: &KnobInfo, style: &CircleNot
     );
 }
 
-fn line_notch(renderer: &mut Renderer, knob_info: &KnobInfo, style: &LineNotch) {
+fn line_notch<Renderer>(renderer: &mut Renderer, knob_info: &KnobInfo, style: &LineNotch)
+where
+    Renderer: geometry::Renderer,
+{
     let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
 
     let stroke = Stroke {
@@ -352,10 +813,7 @@ fn line_notch(renderer: &mut Renderer, knob_info: &KnobInfo, style: &LineNotch)
         Point::new(0.0, stroke_begin_y + notch_height),
     );
 
-    let mut frame = Frame::new(
-        renderer,
-        Size::new(knob_info.bounds.width, knob_info.bounds.width),
-    );
+    let mut frame = centered_frame(renderer, knob_info, knob_info.radius);
 
     frame.translate(Vector::new(knob_info.radius, knob_info.radius));
 
@@ -365,79 +823,281 @@ fn line_notch(renderer: &mut Renderer, knob_info: &KnobInfo, style: &LineNotch)
 
     frame.stroke(&path, stroke);
 
-    renderer.with_translation(
-        Vector::new(knob_info.bounds.x, knob_info.bounds.y),
-        |renderer| {
-            renderer.draw_geometry(frame.into_geometry());
+    draw_centered_frame(renderer, knob_info, knob_info.radius, frame);
+}
+
+/// Fills `path`, drawn around the center of the knob while it points
+/// straight up, turned with the value of the knob.
+fn fill_notch_path<Renderer>(
+    renderer: &mut Renderer,
+    knob_info: &KnobInfo,
+    path: &Path,
+    color: Color,
+) where
+    Renderer: geometry::Renderer,
+{
+    let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
+
+    let mut frame = centered_frame(renderer, knob_info, knob_info.radius);
+
+    frame.translate(Vector::new(knob_info.radius, knob_info.radius));
+
+    if !(-0.001..=0.001).contains(&value_angle) {
+        frame.rotate(value_angle);
+    }
+
+    frame.fill(path, color);
+
+    draw_centered_frame(renderer, knob_info, knob_info.radius, frame);
+}
+
+fn triangle_notch<Renderer>(renderer: &mut Renderer, knob_info: &KnobInfo, style: &TriangleNotch)
+where
+    Renderer: geometry::Renderer,
+{
+    let tip_y = -(knob_info.radius - style.offset.from_knob_diameter(knob_info.bounds.width));
+    let base_y = tip_y + style.length.from_knob_diameter(knob_info.bounds.width);
+    let half_width = style.width.from_knob_diameter(knob_info.bounds.width) / 2.0;
+
+    let path = Path::new(|path| {
+        path.move_to(Point::new(0.0, tip_y));
+        path.line_to(Point::new(half_width, base_y));
+        path.line_to(Point::new(-half_width, base_y));
+        path.close();
+    });
+
+    fill_notch_path(renderer, knob_info, &path, style.color);
+}
+
+fn custom_notch<Renderer>(renderer: &mut Renderer, knob_info: &KnobInfo, style: &CustomNotch)
+where
+    Renderer: geometry::Renderer,
+{
+    let path = (style.path)(&NotchInfo {
+        radius: knob_info.radius,
+        value: knob_info.value,
+        angle: knob_info.value_angle + std::f32::consts::FRAC_PI_2,
+    });
+
+    fill_notch_path(renderer, knob_info, &path, style.color);
+}
+
+fn texture_notch<Renderer>(renderer: &mut Renderer, knob_info: &KnobInfo, style: &TextureNotch)
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
+    let (sin, cos) = value_angle.sin_cos();
+
+    let offset_radius = knob_info.radius - style.offset.from_knob_diameter(knob_info.bounds.width);
+
+    // The center of the texture relative to the center of the notch, turned
+    // with the knob if the texture rotates.
+    let image_center = Vector::new(
+        style.image_bounds.x + style.image_bounds.width / 2.0,
+        style.image_bounds.y + style.image_bounds.height / 2.0,
+    );
+    let (image_center, rotation) = if style.rotate {
+        (
+            Vector::new(
+                image_center.x * cos - image_center.y * sin,
+                image_center.x * sin + image_center.y * cos,
+            ),
+            value_angle,
+        )
+    } else {
+        (image_center, 0.0)
+    };
+
+    let center_x =
+        knob_info.bounds.center_x() + (sin * offset_radius + image_center.x) * knob_info.scale.x;
+    let center_y =
+        knob_info.bounds.center_y() + (-cos * offset_radius + image_center.y) * knob_info.scale.y;
+
+    let width = style.image_bounds.width * knob_info.scale.x;
+    let height = style.image_bounds.height * knob_info.scale.y;
+
+    renderer.draw_image(
+        image::Image {
+            rotation: Radians(rotation),
+            opacity: knob_info.opacity,
+            ..image::Image::from(style.image_handle.select(knob_info.scale_factor))
+        },
+        Rectangle {
+            x: center_x - width / 2.0,
+            y: center_y - height / 2.0,
+            width,
+            height,
         },
     );
 }
 
-fn notch(renderer: &mut Renderer, knob_info: &KnobInfo, notch: &NotchShape) {
+fn notch<Renderer>(renderer: &mut Renderer, knob_info: &KnobInfo, notch: &NotchShape)
+where
+    Renderer: image::Renderer<Handle = image::Handle> + geometry::Renderer,
+{
     match notch {
         NotchShape::Circle(style) => circle_notch(renderer, knob_info, style),
         NotchShape::Line(style) => line_notch(renderer, knob_info, style),
+        NotchShape::Texture(style) => texture_notch(renderer, knob_info, style),
+        NotchShape::Triangle(style) => triangle_notch(renderer, knob_info, style),
+        NotchShape::Custom(style) => custom_notch(renderer, knob_info, style),
         NotchShape::None => {}
     }
 }
 
-pub fn circle_style(
+/// The span of the spinner that the arc styles draw up to the notch of an
+/// endless knob.
+const SPINNER_SPAN: f32 = TAU / 8.0;
+
+/// Draws the arc of an arc style of an endless knob, which has no range to
+/// fill: a full circle of `empty_color`, with a spinner of `spinner_color`
+/// that ends at the notch.
+fn endless_arc<Renderer>(
+    renderer: &mut Renderer,
+    knob_info: &KnobInfo,
+    width: f32,
+    cap: canvas::LineCap,
+    empty_color: Color,
+    spinner_color: Color,
+) where
+    Renderer: geometry::Renderer,
+{
+    let center_point = Point::new(knob_info.radius, knob_info.radius);
+    let arc_radius = knob_info.radius - (width / 2.0);
+
+    let mut frame = centered_frame(renderer, knob_info, knob_info.radius);
+
+    frame.stroke(
+        &Path::circle(center_point, arc_radius),
+        Stroke {
+            width,
+            style: canvas::Style::Solid(empty_color),
+            ..Stroke::default()
+        },
+    );
+
+    let spinner_arc = Arc {
+        center: center_point,
+        radius: arc_radius,
+        start_angle: Radians(knob_info.value_angle - SPINNER_SPAN),
+        end_angle: Radians(knob_info.value_angle),
+    };
+
+    frame.stroke(
+        &Path::new(|path| path.arc(spinner_arc)),
+        Stroke {
+            width,
+            style: canvas::Style::Solid(spinner_color),
+            line_cap: cap,
+            ..Stroke::default()
+        },
+    );
+
+    draw_centered_frame(renderer, knob_info, knob_info.radius, frame);
+}
+
+pub fn circle_style<Renderer>(
     renderer: &mut Renderer,
     knob_info: &KnobInfo,
     style: CircleAppearance,
     value_markers: &ValueMarkers<'_>,
-    //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    tick_marks_cache: &TickMarksCache<Renderer>,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer:
+        image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font> + geometry::Renderer,
+{
     markers(
         renderer,
         knob_info,
         value_markers,
-        //tick_marks_cache,
-        //text_marks_cache,
+        tick_marks_cache,
+        text_marks_cache,
     );
 
-    renderer.fill_quad(
-        Quad {
-            bounds: knob_info.bounds,
-            border: Border {
-                color: style.border_color,
-                width: style.border_width,
-                radius: Radius::new(knob_info.radius),
+    if knob_info.is_stretched() {
+        let mut frame = centered_frame(renderer, knob_info, knob_info.radius);
+
+        let center_point = Point::new(knob_info.radius, knob_info.radius);
+
+        frame.fill(
+            &Path::circle(center_point, knob_info.radius),
+            Fill {
+                style: canvas::Style::Solid(style.color),
+                ..Fill::default()
             },
-            shadow: Shadow::default(),
-        },
-        style.color,
-    );
+        );
+
+        if style.border_width > 0.0 {
+            frame.stroke(
+                &Path::circle(center_point, knob_info.radius - style.border_width / 2.0),
+                Stroke {
+                    width: style.border_width,
+                    style: canvas::Style::Solid(style.border_color),
+                    ..Stroke::default()
+                },
+            );
+        }
+
+        draw_centered_frame(renderer, knob_info, knob_info.radius, frame);
+    } else {
+        renderer.fill_quad(
+            Quad {
+                bounds: knob_info.bounds,
+                border: Border {
+                    color: style.border_color,
+                    width: style.border_width,
+                    radius: Radius::new(knob_info.radius),
+                },
+                shadow: Shadow::default(),
+            },
+            style.color,
+        );
+    }
 
     notch(renderer, knob_info, &style.notch);
 }
 
-pub fn arc_style(
+pub fn arc_style<Renderer>(
     renderer: &mut Renderer,
     knob_info: &KnobInfo,
     style: ArcAppearance,
     value_markers: &ValueMarkers<'_>,
-    //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    tick_marks_cache: &TickMarksCache<Renderer>,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer:
+        image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font> + geometry::Renderer,
+{
     markers(
         renderer,
         knob_info,
         value_markers,
-        //tick_marks_cache,
-        //text_marks_cache,
+        tick_marks_cache,
+        text_marks_cache,
     );
 
     let width = style.width.from_knob_diameter(knob_info.bounds.width);
 
+    if knob_info.endless {
+        endless_arc(
+            renderer,
+            knob_info,
+            width,
+            style.cap,
+            style.empty_color,
+            style.filled_color,
+        );
+        notch(renderer, knob_info, &style.notch);
+        return;
+    }
+
     let center_point = Point::new(knob_info.radius, knob_info.radius);
     let arc_radius = knob_info.radius - (width / 2.0);
+    let (start_angle, end_angle) = arc_range(knob_info, &style.ends);
 
-    let mut frame = Frame::new(
-        renderer,
-        Size::new(knob_info.bounds.width, knob_info.bounds.width),
-    );
+    let mut frame = centered_frame(renderer, knob_info, knob_info.radius);
 
     let empty_stroke = Stroke {
         width,
@@ -449,8 +1109,8 @@ pub fn arc_style(
     let empty_arc = Arc {
         center: center_point,
         radius: arc_radius,
-        start_angle: Radians(knob_info.start_angle),
-        end_angle: Radians(knob_info.start_angle + knob_info.angle_span),
+        start_angle: Radians(start_angle),
+        end_angle: Radians(end_angle),
     };
 
     let empty_path = Path::new(|path| path.arc(empty_arc));
@@ -467,51 +1127,63 @@ pub fn arc_style(
     let filled_arc = Arc {
         center: center_point,
         radius: arc_radius,
-        start_angle: Radians(knob_info.start_angle),
-        end_angle: Radians(knob_info.value_angle),
+        start_angle: Radians(start_angle),
+        end_angle: Radians(knob_info.value_angle.clamp(start_angle, end_angle)),
     };
 
     let filled_path = Path::new(|path| path.arc(filled_arc));
 
     frame.stroke(&filled_path, filled_stroke);
 
-    renderer.with_translation(
-        Vector::new(knob_info.bounds.x, knob_info.bounds.y),
-        |renderer| {
-            renderer.draw_geometry(frame.into_geometry());
-        },
-    );
+    end_dots(&mut frame, knob_info, center_point, arc_radius, &style.ends);
+
+    draw_centered_frame(renderer, knob_info, knob_info.radius, frame);
 
     notch(renderer, knob_info, &style.notch);
 }
 
-pub fn arc_bipolar_style(
+pub fn arc_bipolar_style<Renderer>(
     renderer: &mut Renderer,
     knob_info: &KnobInfo,
     style: ArcBipolarAppearance,
     value_markers: &ValueMarkers<'_>,
-    //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    tick_marks_cache: &TickMarksCache<Renderer>,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer:
+        image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font> + geometry::Renderer,
+{
     markers(
         renderer,
         knob_info,
         value_markers,
-        //tick_marks_cache,
-        //text_marks_cache,
+        tick_marks_cache,
+        text_marks_cache,
     );
 
-    let bipolar_state = BipolarState::from_knob_info(knob_info);
-
     let width = style.width.from_knob_diameter(knob_info.bounds.width);
 
+    if knob_info.endless {
+        endless_arc(
+            renderer,
+            knob_info,
+            width,
+            style.cap,
+            style.empty_color,
+            style.right_filled_color,
+        );
+        notch(renderer, knob_info, &style.notch_center);
+        return;
+    }
+
+    let bipolar_side = knob_info.bipolar_side();
+
     let center_point = Point::new(knob_info.radius, knob_info.radius);
     let arc_radius = knob_info.radius - (width / 2.0);
+    let (start_angle, end_angle) = arc_range(knob_info, &style.ends);
+    let value_angle = knob_info.value_angle.clamp(start_angle, end_angle);
 
-    let mut frame = Frame::new(
-        renderer,
-        Size::new(knob_info.bounds.width, knob_info.bounds.width),
-    );
+    let mut frame = centered_frame(renderer, knob_info, knob_info.radius);
 
     let empty_stroke = Stroke {
         width,
@@ -523,22 +1195,20 @@ pub fn arc_bipolar_style(
     let empty_arc = Arc {
         center: center_point,
         radius: arc_radius,
-        start_angle: Radians(knob_info.start_angle),
-        end_angle: Radians(knob_info.start_angle + knob_info.angle_span),
+        start_angle: Radians(start_angle),
+        end_angle: Radians(end_angle),
     };
 
     let empty_path = Path::new(|path| path.arc(empty_arc));
 
     frame.stroke(&empty_path, empty_stroke);
 
-    let center_angle = knob_info.start_angle
-        + knob_info
-            .bipolar_center
-            .unwrap_or_else(|| Normal::from_clipped(0.5))
-            .scale(knob_info.angle_span);
+    let center_angle = knob_info
+        .bipolar_center_angle()
+        .clamp(start_angle, end_angle);
 
-    match bipolar_state {
-        BipolarState::Left => {
+    match bipolar_side {
+        BipolarSide::Left => {
             let filled_stroke = Stroke {
                 width,
                 style: canvas::Style::Solid(style.left_filled_color),
@@ -549,7 +1219,7 @@ pub fn arc_bipolar_style(
             let filled_arc = Arc {
                 center: center_point,
                 radius: arc_radius,
-                start_angle: Radians(knob_info.value_angle),
+                start_angle: Radians(value_angle),
                 end_angle: Radians(center_angle),
             };
 
@@ -557,7 +1227,7 @@ pub fn arc_bipolar_style(
 
             frame.stroke(&filled_path, filled_stroke);
         }
-        BipolarState::Right => {
+        BipolarSide::Right => {
             let filled_stroke = Stroke {
                 width,
                 style: canvas::Style::Solid(style.right_filled_color),
@@ -569,7 +1239,7 @@ pub fn arc_bipolar_style(
                 center: center_point,
                 radius: arc_radius,
                 start_angle: Radians(center_angle),
-                end_angle: Radians(knob_info.value_angle),
+                end_angle: Radians(value_angle),
             };
 
             let filled_path = Path::new(|path| path.arc(filled_arc));
@@ -579,20 +1249,121 @@ pub fn arc_bipolar_style(
         _ => {}
     }
 
-    renderer.with_translation(
-        Vector::new(knob_info.bounds.x, knob_info.bounds.y),
-        |renderer| {
-            renderer.draw_geometry(frame.into_geometry());
-        },
-    );
+    end_dots(&mut frame, knob_info, center_point, arc_radius, &style.ends);
+
+    draw_centered_frame(renderer, knob_info, knob_info.radius, frame);
 
     if let Some((notch_left, notch_right)) = style.notch_left_right {
-        match bipolar_state {
-            BipolarState::Left => notch(renderer, knob_info, &notch_left),
-            BipolarState::Right => notch(renderer, knob_info, &notch_right),
-            BipolarState::Center => notch(renderer, knob_info, &style.notch_center),
+        match bipolar_side {
+            BipolarSide::Left => notch(renderer, knob_info, &notch_left),
+            BipolarSide::Right => notch(renderer, knob_info, &notch_right),
+            BipolarSide::Center => notch(renderer, knob_info, &style.notch_center),
         }
     } else {
         notch(renderer, knob_info, &style.notch_center)
     };
 }
+
+pub fn arc_segmented_style<Renderer>(
+    renderer: &mut Renderer,
+    knob_info: &KnobInfo,
+    style: ArcSegmentedAppearance,
+    value_markers: &ValueMarkers<'_>,
+    tick_marks_cache: &TickMarksCache<Renderer>,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer:
+        image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font> + geometry::Renderer,
+{
+    markers(
+        renderer,
+        knob_info,
+        value_markers,
+        tick_marks_cache,
+        text_marks_cache,
+    );
+
+    let width = style.width.from_knob_diameter(knob_info.bounds.width);
+    let count = usize::from(style.segments);
+    let arc_radius = knob_info.radius - (width / 2.0);
+
+    if count == 0 || arc_radius <= 0.0 {
+        notch(renderer, knob_info, &style.notch);
+        return;
+    }
+
+    // An endless knob has no range to fill, so its segments go all the way
+    // around from the top, and only the one under the notch is lit.
+    let (start_angle, angle_span) = if knob_info.endless {
+        (-FRAC_PI_2, TAU)
+    } else {
+        (knob_info.start_angle, knob_info.angle_span)
+    };
+
+    let segment_span = angle_span / count as f32;
+
+    let is_lit = |segment: usize| {
+        if knob_info.endless {
+            let turned = (knob_info.value_angle - start_angle).rem_euclid(TAU);
+            segment == ((turned / segment_span) as usize).min(count - 1)
+        } else {
+            segment < (knob_info.value.as_f32() * count as f32).round() as usize
+        }
+    };
+
+    let center_point = Point::new(knob_info.radius, knob_info.radius);
+    let gap_span = style.gap / arc_radius;
+    let dot_radius = width.min(arc_radius * segment_span - style.gap) / 2.0;
+
+    let segment = |builder: &mut path::Builder, index: usize| {
+        let segment_start = start_angle + (index as f32 * segment_span);
+
+        match style.shape {
+            SegmentShape::Dot if dot_radius > 0.0 => {
+                let angle = segment_start + (segment_span / 2.0);
+
+                builder.circle(
+                    center_point + (Vector::new(angle.cos(), angle.sin()) * arc_radius),
+                    dot_radius,
+                );
+            }
+            SegmentShape::Dash if segment_span > gap_span => {
+                builder.arc(Arc {
+                    center: center_point,
+                    radius: arc_radius,
+                    start_angle: Radians(segment_start + (gap_span / 2.0)),
+                    end_angle: Radians(segment_start + segment_span - (gap_span / 2.0)),
+                });
+            }
+            _ => {}
+        }
+    };
+
+    let (lit, unlit): (Vec<usize>, Vec<usize>) = (0..count).partition(|&index| is_lit(index));
+
+    let mut frame = centered_frame(renderer, knob_info, knob_info.radius);
+
+    for (segments, color) in [(unlit, style.unlit_color), (lit, style.lit_color)] {
+        let path = Path::new(|builder| {
+            for index in segments {
+                segment(builder, index);
+            }
+        });
+
+        match style.shape {
+            SegmentShape::Dot => frame.fill(&path, color),
+            SegmentShape::Dash => frame.stroke(
+                &path,
+                Stroke {
+                    width,
+                    style: canvas::Style::Solid(color),
+                    ..Stroke::default()
+                },
+            ),
+        }
+    }
+
+    draw_centered_frame(renderer, knob_info, knob_info.radius, frame);
+
+    notch(renderer, knob_info, &style.notch);
+}
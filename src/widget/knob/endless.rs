@@ -0,0 +1,237 @@
+//! The event handling of an endless [`Knob`], which reports how far it was
+//! turned instead of moving a value.
+//!
+//! [`Knob`]: struct.Knob.html
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use iced::{
+    advanced::{graphics::geometry, mouse, Shell},
+    event, keyboard, touch, Event, Point, Rectangle,
+};
+
+use super::{state::State, Catalog, DragMode, Knob};
+use crate::{
+    core::DragClamp,
+    widget::{
+        input::{self, Pointer},
+        virtual_slider::PendingDrag,
+    },
+};
+
+/// The angle of the notch of an endless knob at `position` turns from the
+/// top.
+pub fn position_angle(position: f32) -> f32 {
+    position.rem_euclid(1.0) * TAU - FRAC_PI_2
+}
+
+impl<Message, Theme> Knob<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Handles an event of an endless [`Knob`] whose pointer hit area is
+    /// `hit_bounds`, and that is turned around `center` by a circular drag.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub(super) fn on_endless_event<Renderer: geometry::Renderer>(
+        &mut self,
+        state: &mut State<Renderer>,
+        event: &Event,
+        cursor: mouse::Cursor,
+        center: Point,
+        hit_bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let slider = &mut state.slider;
+
+        let cursor = input::event_cursor(event, cursor);
+        let pointer = Pointer::of(event);
+
+        let is_over = cursor.is_over(hit_bounds);
+        let is_claimed = input::is_press_claimed(event, &slider.press_claim);
+
+        let pressed_button = match *event {
+            Event::Mouse(mouse::Event::ButtonPressed(button))
+                if button == mouse::Button::Left || Some(button) == self.fine_drag_button =>
+            {
+                Some(button)
+            }
+            Event::Touch(touch::Event::FingerPressed { .. }) => Some(mouse::Button::Left),
+            _ => None,
+        };
+
+        if pressed_button.is_some() {
+            slider.is_focused = is_over && !is_claimed;
+            slider.focus_visible = false;
+        }
+
+        match *event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if slider.dragging_status.is_some() && slider.drag_pointer == pointer =>
+            {
+                if let Some(pending) = slider.pending_drag {
+                    if !pending.is_crossed(position, &self.drag_threshold) {
+                        return event::Status::Captured;
+                    }
+
+                    slider.pending_drag = None;
+
+                    if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
+                        shell.publish(message);
+                    }
+                }
+
+                let delta = match self.drag_mode {
+                    DragMode::Vertical => {
+                        -slider.drag.moved_to(position.y, DragClamp::None) * self.sensitivity.scalar
+                    }
+                    DragMode::Circular => slider.circular_drag.moved_to(center, position, TAU),
+                };
+
+                let is_fine =
+                    self.fine_drag_button.is_some() && slider.drag_button == self.fine_drag_button;
+
+                if self.publish_delta(delta, is_fine, slider.pressed_modifiers, shell) {
+                    slider
+                        .dragging_status
+                        .as_mut()
+                        .expect("dragging_status taken")
+                        .moved();
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let status = if slider.dragging_status.is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                };
+
+                if !self.capture_wheel || !is_over || self.sensitivity.wheel_scalar == 0.0 {
+                    return status;
+                }
+
+                if let Some(steps) = slider.scroll.add(delta, self.sensitivity.pixels_per_step) {
+                    self.publish_delta(
+                        steps * self.sensitivity.wheel_scalar,
+                        false,
+                        slider.pressed_modifiers,
+                        shell,
+                    );
+
+                    return event::Status::Captured;
+                }
+
+                return status;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if pressed_button.is_some()
+                    && is_over
+                    && slider.drag_pointer.is_none()
+                    && !is_claimed =>
+            {
+                input::claim_press(event, &slider.press_claim);
+
+                let cursor_position = cursor.position().unwrap();
+
+                // The grab waits until the press drags, or is released.
+                slider.pending_drag =
+                    PendingDrag::new(event, cursor_position, &self.drag_threshold, true);
+
+                if slider.pending_drag.is_none() {
+                    if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
+                        shell.publish(message);
+                    }
+                }
+
+                slider.dragging_status = Some(Default::default());
+                slider.drag_pointer = pointer;
+                slider.drag_button = pressed_button;
+
+                match self.drag_mode {
+                    DragMode::Vertical => slider.drag.start(cursor_position.y, DragClamp::None),
+                    DragMode::Circular => slider.circular_drag.start(center, cursor_position),
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_over && slider.drag_pointer.is_some() && slider.drag_pointer != pointer =>
+            {
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(_))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if slider.drag_pointer == pointer =>
+            {
+                let released_button = match *event {
+                    Event::Mouse(mouse::Event::ButtonReleased(button)) => button,
+                    _ => mouse::Button::Left,
+                };
+
+                if slider.drag_button == Some(released_button) {
+                    slider.drag_pointer = None;
+                    slider.drag_button = None;
+
+                    if slider.pending_drag.take().is_some() {
+                        if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
+                            shell.publish(message);
+                        }
+                    }
+
+                    if let Some(slider_status) = slider.dragging_status.take() {
+                        if self.on_grab.is_some() || slider_status.was_moved() {
+                            if let Some(message) =
+                                self.on_release.as_mut().and_then(|on_release| on_release())
+                            {
+                                shell.publish(message);
+                            }
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                slider.pressed_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Produces the message of `on_delta` with `delta`, which is scaled down
+    /// by the `modifier_scalar` for a fine drag or while the modifier keys
+    /// are held. Returns whether a message was produced.
+    fn publish_delta(
+        &self,
+        delta: f32,
+        is_fine: bool,
+        pressed_modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let Some(on_delta) = &self.on_delta else {
+            return false;
+        };
+
+        if delta.abs() < f32::EPSILON {
+            return false;
+        }
+
+        let delta = match self.sensitivity.held_modifier_scalar(pressed_modifiers) {
+            Some(scalar) => delta * scalar,
+            None if is_fine => delta * self.sensitivity.modifier_scalar,
+            None => delta,
+        };
+
+        shell.publish(on_delta(delta));
+
+        true
+    }
+}
@@ -1,12 +1,38 @@
-use crate::Normal;
-use iced::Rectangle;
+use crate::{BipolarSide, Normal};
+use iced::{Rectangle, Vector};
 
 pub struct KnobInfo {
     pub bounds: Rectangle,
+    /// How much the knob is stretched on each axis around the center of
+    /// `bounds`.
+    pub scale: Vector,
     pub start_angle: f32,
     pub angle_span: f32,
     pub radius: f32,
     pub value: Normal,
-    pub bipolar_center: Option<Normal>,
+    pub bipolar_center: Normal,
     pub value_angle: f32,
+    /// Whether the knob is endless, in which case `value_angle` is the angle
+    /// of its position and it has no range to fill.
+    pub endless: bool,
+    /// The opacity the colors and textures of the knob are dimmed to.
+    pub opacity: f32,
+    /// The scale factor the textures of the knob are picked for.
+    pub scale_factor: f32,
+}
+
+impl KnobInfo {
+    pub fn is_stretched(&self) -> bool {
+        self.scale != Vector::new(1.0, 1.0)
+    }
+
+    /// The side of the bipolar center the value is on.
+    pub fn bipolar_side(&self) -> BipolarSide {
+        self.value.classify_bipolar(self.bipolar_center)
+    }
+
+    /// The angle of the bipolar center.
+    pub fn bipolar_center_angle(&self) -> f32 {
+        self.start_angle + self.bipolar_center.scale(self.angle_span)
+    }
 }
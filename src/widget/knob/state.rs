@@ -1,23 +1,31 @@
-use iced::advanced::{graphics::core::keyboard, mouse};
+use std::cell::Cell;
 
-use crate::{Normal, SliderStatus};
+use iced::advanced::{graphics::geometry, widget::operation::Focusable};
+
+use super::draw::TickMarksCache;
+use crate::{text_marks, widget::virtual_slider, KnobAngleRange, Normal};
 
 /// The local state of a [`Knob`].
 ///
 /// [`Knob`]: struct.Knob.html
-#[derive(Debug, Clone)]
-pub struct State {
-    pub dragging_status: Option<SliderStatus>,
-    pub prev_drag_y: f32,
-    pub prev_normal: Normal,
-    pub continuous_normal: f32,
-    pub pressed_modifiers: keyboard::Modifiers,
-    pub last_click: Option<mouse::Click>,
-    //tick_marks_cache: crate::graphics::tick_marks::PrimitiveCache,
-    //text_marks_cache: crate::graphics::text_marks::PrimitiveCache,
+pub struct State<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    pub slider: virtual_slider::State,
+    pub mod_dragging: bool,
+    pub continuous_mod: f32,
+    pub tick_marks_cache: TickMarksCache<Renderer>,
+    /// The span of the angle range of the last drawn style, which a
+    /// circular drag needs but can't get from the theme.
+    pub angle_span: Cell<f32>,
+    pub text_marks_cache: text_marks::Cache,
 }
 
-impl State {
+impl<Renderer> State<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
     /// Creates a new [`Knob`] state.
     ///
     /// It expects:
@@ -27,14 +35,35 @@ impl State {
     /// [`Knob`]: struct.Knob.html
     pub fn new(normal: Normal) -> Self {
         Self {
-            dragging_status: None,
-            prev_drag_y: 0.0,
-            prev_normal: normal,
-            continuous_normal: normal.as_f32(),
-            pressed_modifiers: Default::default(),
-            last_click: None,
-            //tick_marks_cache: Default::default(),
-            //text_marks_cache: Default::default(),
+            slider: virtual_slider::State::new(normal),
+            mod_dragging: false,
+            continuous_mod: normal.as_f32(),
+            tick_marks_cache: TickMarksCache::default(),
+            angle_span: Cell::new(angle_span(&KnobAngleRange::default())),
+            text_marks_cache: text_marks::Cache::default(),
         }
     }
 }
+
+impl<Renderer> Focusable for State<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        self.slider.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.slider.focus();
+    }
+
+    fn unfocus(&mut self) {
+        self.slider.unfocus();
+    }
+}
+
+/// The span (in radians) between the `min` and `max` angle of an
+/// `angle_range`.
+pub fn angle_span(angle_range: &KnobAngleRange) -> f32 {
+    angle_range.max() - angle_range.min()
+}
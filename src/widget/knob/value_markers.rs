@@ -1,18 +1,66 @@
 use crate::{
     style::knob::{
-        ModRangeArcAppearance, TextMarksAppearance, TickMarksAppearance, ValueArcAppearance,
+        DefaultMarkerAppearance, ModRangeArcAppearance, ModSourcesArcAppearance,
+        TextMarksAppearance, TickMarksAppearance, ValueArcAppearance,
     },
-    text_marks, tick_marks, ModulationRange,
+    style::Dim,
+    text_marks, tick_marks, ModulationRange, Normal,
 };
+use iced::{Color, Rectangle};
+
+use super::KnobInfo;
 
 pub struct ValueMarkers<'a> {
     pub tick_marks: Option<&'a tick_marks::Group>,
     pub text_marks: Option<&'a text_marks::Group>,
     pub mod_range_1: Option<&'a ModulationRange>,
     pub mod_range_2: Option<&'a ModulationRange>,
+    pub mod_sources: &'a [(ModulationRange, Color)],
+    pub default: Normal,
     pub tick_marks_style: Option<TickMarksAppearance>,
     pub text_marks_style: Option<TextMarksAppearance>,
     pub value_arc_style: Option<ValueArcAppearance>,
     pub mod_range_style_1: Option<ModRangeArcAppearance>,
     pub mod_range_style_2: Option<ModRangeArcAppearance>,
+    pub mod_sources_style: Option<ModSourcesArcAppearance>,
+    pub default_marker_style: Option<DefaultMarkerAppearance>,
+}
+
+impl ValueMarkers<'_> {
+    /// Leaves out the marks, mod ranges and default marker when none of the
+    /// markers can be seen in `viewport`, e.g. when the knob is scrolled out
+    /// of view.
+    pub fn cull(&mut self, knob_info: &KnobInfo, viewport: &Rectangle) {
+        if !super::draw::value_markers_bounds(knob_info, self).intersects(viewport) {
+            self.tick_marks = None;
+            self.text_marks = None;
+            self.mod_range_1 = None;
+            self.mod_range_2 = None;
+            self.mod_sources = &[];
+            self.default_marker_style = None;
+        }
+    }
+
+    /// Dims the styles of the markers to `opacity`.
+    pub fn dim(&mut self, opacity: f32) {
+        self.tick_marks_style = self.tick_marks_style.take().dim(opacity);
+        self.text_marks_style = self.text_marks_style.take().dim(opacity);
+        self.value_arc_style = self.value_arc_style.take().dim(opacity);
+        self.mod_range_style_1 = self.mod_range_style_1.take().dim(opacity);
+        self.mod_range_style_2 = self.mod_range_style_2.take().dim(opacity);
+        self.mod_sources_style = self.mod_sources_style.take().dim(opacity);
+        self.default_marker_style = self.default_marker_style.take().dim(opacity);
+    }
+
+    /// Leaves out all of the markers, e.g. of an endless knob, which has no
+    /// range to mark.
+    pub fn leave_out_all(&mut self) {
+        self.tick_marks = None;
+        self.text_marks = None;
+        self.mod_range_1 = None;
+        self.mod_range_2 = None;
+        self.mod_sources = &[];
+        self.value_arc_style = None;
+        self.default_marker_style = None;
+    }
 }
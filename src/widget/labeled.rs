@@ -0,0 +1,550 @@
+//! Display a control together with its title and a readout of its value
+//!
+//! [`LabeledKnob`], [`LabeledSlider`] and [`LabeledHSlider`] bundle the
+//! most common controls with their labels in a single builder, and
+//! [`Labeled::with_control`] labels any other control.
+//!
+//! [`LabeledKnob`]: type.LabeledKnob.html
+//! [`LabeledSlider`]: type.LabeledSlider.html
+//! [`LabeledHSlider`]: type.LabeledHSlider.html
+//! [`Labeled::with_control`]: struct.Labeled.html#method.with_control
+
+#[cfg(any(feature = "h_slider", feature = "knob", feature = "v_slider"))]
+use crate::core::{Normal, NormalParam};
+use crate::style::Dim;
+#[cfg(feature = "h_slider")]
+use crate::widget::h_slider::{self, HSlider};
+#[cfg(feature = "knob")]
+use crate::widget::knob::{self, Knob};
+#[cfg(feature = "v_slider")]
+use crate::widget::v_slider::{self, VSlider};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse,
+        renderer::Style,
+        text::{self, Paragraph, Text},
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    widget::text::{LineHeight, Shaping, Wrapping},
+    Element, Event, Font, Length, Pixels, Point, Rectangle, Size,
+};
+
+pub use crate::style::labeled::{Appearance, Catalog, StyleFn, StyleSheet};
+
+/// The default size of the title and the value readout of a [`Labeled`]
+/// widget.
+///
+/// [`Labeled`]: struct.Labeled.html
+pub const DEFAULT_TEXT_SIZE: f32 = 12.0;
+
+/// The default space between the control, the title and the value readout
+/// of a [`Labeled`] widget.
+///
+/// [`Labeled`]: struct.Labeled.html
+pub const DEFAULT_SPACING: f32 = 4.0;
+
+/// Where the title and the value readout of a [`Labeled`] widget are
+/// placed around its control.
+///
+/// [`Labeled`]: struct.Labeled.html
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Placement {
+    /// The title above the control and the value readout below it.
+    #[default]
+    TitleAbove,
+    /// The title and then the value readout above the control.
+    Above,
+    /// The title and then the value readout below the control.
+    Below,
+}
+
+/// A [`Knob`] with a title above and a readout of its value below.
+///
+/// ```no_run
+/// use iced_audio::{format_hz, FreqRange, LabeledKnob};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Cutoff(iced_audio::Normal),
+/// }
+///
+/// let range = FreqRange::new(20.0, 20_000.0);
+/// let cutoff = range.normal_param(1000.0, 1000.0);
+///
+/// let labeled: LabeledKnob<'_, Message, iced::Theme> = LabeledKnob::new(
+///     "Cutoff",
+///     cutoff,
+///     move |normal| format_hz(range.unmap_to_value(normal)),
+///     Message::Cutoff,
+/// );
+/// ```
+///
+/// [`Knob`]: ../knob/struct.Knob.html
+#[cfg(feature = "knob")]
+pub type LabeledKnob<'a, Message, Theme> = Labeled<'a, Knob<'a, Message, Theme>, Theme>;
+
+/// A [`VSlider`] with a title above and a readout of its value below.
+///
+/// [`VSlider`]: ../v_slider/struct.VSlider.html
+#[cfg(feature = "v_slider")]
+pub type LabeledSlider<'a, Message, Theme> = Labeled<'a, VSlider<'a, Message, Theme>, Theme>;
+
+/// An [`HSlider`] with a title above and a readout of its value below.
+///
+/// [`HSlider`]: ../h_slider/struct.HSlider.html
+#[cfg(feature = "h_slider")]
+pub type LabeledHSlider<'a, Message, Theme> = Labeled<'a, HSlider<'a, Message, Theme>, Theme>;
+
+/// A control together with its title and a readout of its value, laid out
+/// as one widget.
+///
+/// The value readout is text that is formatted when the widget is created,
+/// so it follows the value as the view is rebuilt after every change.
+#[allow(missing_debug_implementations)]
+pub struct Labeled<'a, Control, Theme>
+where
+    Theme: Catalog,
+{
+    control: Control,
+    title: String,
+    value: String,
+    placement: Placement,
+    text_size: Pixels,
+    spacing: f32,
+    font: Font,
+    class: Theme::Class<'a>,
+    opacity: f32,
+}
+
+#[cfg(feature = "knob")]
+impl<'a, Message, Theme> Labeled<'a, Knob<'a, Message, Theme>, Theme>
+where
+    Theme: knob::Catalog + Catalog,
+{
+    /// Creates a new [`LabeledKnob`].
+    ///
+    /// It expects:
+    ///   * the title of the knob, e.g. the name of its parameter
+    ///   * the [`NormalParam`] of the knob
+    ///   * a function that formats the value readout, e.g. [`format_hz`]
+    ///   * a function that will be called when the knob is turned
+    ///
+    /// With the `a11y` feature, the title is also the name of the knob.
+    ///
+    /// [`LabeledKnob`]: type.LabeledKnob.html
+    /// [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+    /// [`format_hz`]: ../core/format/fn.format_hz.html
+    pub fn new(
+        title: impl Into<String>,
+        normal_param: NormalParam,
+        format: impl Fn(Normal) -> String,
+        on_change: impl 'a + Fn(Normal) -> Message,
+    ) -> Self {
+        let title = title.into();
+        let value = format(normal_param.value);
+        let knob = Knob::new(normal_param, on_change);
+        #[cfg(feature = "a11y")]
+        let knob = knob.name(title.clone());
+
+        Self::with_control(title, value, knob)
+    }
+}
+
+#[cfg(feature = "v_slider")]
+impl<'a, Message, Theme> Labeled<'a, VSlider<'a, Message, Theme>, Theme>
+where
+    Theme: v_slider::Catalog + Catalog,
+{
+    /// Creates a new [`LabeledSlider`].
+    ///
+    /// It expects:
+    ///   * the title of the slider, e.g. the name of its parameter
+    ///   * the [`NormalParam`] of the slider
+    ///   * a function that formats the value readout, e.g. [`format_db`]
+    ///   * a function that will be called when the slider is moved
+    ///
+    /// With the `a11y` feature, the title is also the name of the slider.
+    ///
+    /// [`LabeledSlider`]: type.LabeledSlider.html
+    /// [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+    /// [`format_db`]: ../core/format/fn.format_db.html
+    pub fn new(
+        title: impl Into<String>,
+        normal_param: NormalParam,
+        format: impl Fn(Normal) -> String,
+        on_change: impl 'a + Fn(Normal) -> Message,
+    ) -> Self {
+        let title = title.into();
+        let value = format(normal_param.value);
+        let slider = VSlider::new(normal_param, on_change);
+        #[cfg(feature = "a11y")]
+        let slider = slider.name(title.clone());
+
+        Self::with_control(title, value, slider)
+    }
+}
+
+#[cfg(feature = "h_slider")]
+impl<'a, Message, Theme> Labeled<'a, HSlider<'a, Message, Theme>, Theme>
+where
+    Theme: h_slider::Catalog + Catalog,
+{
+    /// Creates a new [`LabeledHSlider`].
+    ///
+    /// It expects:
+    ///   * the title of the slider, e.g. the name of its parameter
+    ///   * the [`NormalParam`] of the slider
+    ///   * a function that formats the value readout, e.g. [`format_db`]
+    ///   * a function that will be called when the slider is moved
+    ///
+    /// With the `a11y` feature, the title is also the name of the slider.
+    ///
+    /// [`LabeledHSlider`]: type.LabeledHSlider.html
+    /// [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
+    /// [`format_db`]: ../core/format/fn.format_db.html
+    pub fn new(
+        title: impl Into<String>,
+        normal_param: NormalParam,
+        format: impl Fn(Normal) -> String,
+        on_change: impl 'a + Fn(Normal) -> Message,
+    ) -> Self {
+        let title = title.into();
+        let value = format(normal_param.value);
+        let slider = HSlider::new(normal_param, on_change);
+        #[cfg(feature = "a11y")]
+        let slider = slider.name(title.clone());
+
+        Self::with_control(title, value, slider)
+    }
+}
+
+impl<'a, Control, Theme> Labeled<'a, Control, Theme>
+where
+    Theme: Catalog,
+{
+    /// Labels any `control` with a `title` and a `value` readout.
+    pub fn with_control(
+        title: impl Into<String>,
+        value: impl Into<String>,
+        control: Control,
+    ) -> Self {
+        Self {
+            control,
+            title: title.into(),
+            value: value.into(),
+            placement: Placement::default(),
+            text_size: Pixels(DEFAULT_TEXT_SIZE),
+            spacing: DEFAULT_SPACING,
+            font: Font::default(),
+            class: Theme::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Changes the control with its own builders, e.g. its size or style.
+    pub fn control(mut self, control: impl FnOnce(Control) -> Control) -> Self {
+        self.control = control(self.control);
+        self
+    }
+
+    /// Sets where the title and the value readout are placed around the
+    /// control. The default is [`Placement::TitleAbove`].
+    ///
+    /// [`Placement::TitleAbove`]: enum.Placement.html#variant.TitleAbove
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the size of the title and the value readout. The default is
+    /// [`DEFAULT_TEXT_SIZE`].
+    ///
+    /// [`DEFAULT_TEXT_SIZE`]: constant.DEFAULT_TEXT_SIZE.html
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets the space between the control, the title and the value readout.
+    /// The default is [`DEFAULT_SPACING`].
+    ///
+    /// [`DEFAULT_SPACING`]: constant.DEFAULT_SPACING.html
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the font of the title and the value readout.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the title and the value readout with a function of
+    /// the theme.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the title and the value readout, e.g. a
+    /// custom [`StyleSheet`].
+    ///
+    /// [`StyleSheet`]: ../../style/labeled/trait.StyleSheet.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the title and the value readout from `0.0` to
+    /// `1.0`, e.g. to dim a bypassed section. The default is `1.0`.
+    ///
+    /// The control has its own opacity, which is set with [`control`]:
+    ///
+    /// ```ignore
+    /// LabeledKnob::new("Drive", param, format, Message::Drive)
+    ///     .opacity(0.4)
+    ///     .control(|knob| knob.opacity(0.4))
+    /// ```
+    ///
+    /// [`control`]: #method.control
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The height of a line of text.
+    fn line_height(&self) -> f32 {
+        LineHeight::default().to_absolute(self.text_size).0
+    }
+
+    /// Returns the `y` of the title and of the value readout, given the
+    /// bounds of the control.
+    fn text_rows(&self, bounds: Rectangle, control: Rectangle) -> (f32, f32) {
+        let row = self.line_height() + self.spacing;
+
+        match self.placement {
+            Placement::TitleAbove => (bounds.y, control.y + control.height + self.spacing),
+            Placement::Above => (bounds.y, bounds.y + row),
+            Placement::Below => {
+                let title = control.y + control.height + self.spacing;
+
+                (title, title + row)
+            }
+        }
+    }
+
+    fn text<T>(&self, content: T) -> Text<T> {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.text_size,
+            line_height: LineHeight::default(),
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Top,
+            shaping: Shaping::Basic,
+            wrapping: Wrapping::None,
+        }
+    }
+}
+
+impl<'a, Control, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Labeled<'a, Control, Theme>
+where
+    Control: Widget<Message, Theme, Renderer>,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        self.control.tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.control.state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.control.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.control.diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.control.size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_height = 2.0 * (self.line_height() + self.spacing);
+
+        let control =
+            self.control
+                .layout(tree, renderer, &limits.shrink(Size::new(0.0, text_height)));
+        let control_size = control.size();
+
+        let width = [&self.title, &self.value]
+            .into_iter()
+            .map(|content| Renderer::Paragraph::with_text(self.text(content)).min_width())
+            .fold(control_size.width, f32::max);
+
+        let y = match self.placement {
+            Placement::TitleAbove => self.line_height() + self.spacing,
+            Placement::Above => text_height,
+            Placement::Below => 0.0,
+        };
+
+        layout::Node::with_children(
+            Size::new(width, control_size.height + text_height),
+            vec![control.move_to(Point::new((width - control_size.width) / 2.0, y))],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let control = layout.children().next().expect("control layout");
+
+        self.control.operate(tree, control, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let control = layout.children().next().expect("control layout");
+
+        self.control.on_event(
+            tree, event, control, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let control = layout.children().next().expect("control layout");
+
+        self.control
+            .mouse_interaction(tree, control, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let control = layout.children().next().expect("control layout");
+
+        self.control
+            .draw(tree, renderer, theme, style, control, cursor, viewport);
+
+        let appearance = theme.style(&self.class).dim(self.opacity);
+        let (title_y, value_y) = self.text_rows(bounds, control.bounds());
+
+        for (content, y, color) in [
+            (&self.title, title_y, appearance.title_color),
+            (&self.value, value_y, appearance.value_color),
+        ] {
+            renderer.fill_text(
+                self.text(content.clone()),
+                Point::new(bounds.center_x(), y),
+                color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Control, Message, Theme, Renderer> From<Labeled<'a, Control, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Control: 'a + Widget<Message, Theme, Renderer>,
+    Theme: 'a + Catalog,
+    Renderer: 'a + text::Renderer<Font = Font>,
+{
+    fn from(labeled: Labeled<'a, Control, Theme>) -> Self {
+        Self::new(labeled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::fixtures::{changed, param, Message, SIZE};
+    use crate::test_utils::Harness;
+    use crate::{widget::labeled::Placement, LabeledKnob};
+    use iced::{Length, Point, Theme, Vector};
+
+    #[test]
+    fn labeled_knob_makes_room_for_its_labels() {
+        let labeled = |placement| {
+            LabeledKnob::new(
+                "Cutoff",
+                param(0.5, 0.5),
+                |normal| format!("{:.2}", normal.as_f32()),
+                Message::Changed,
+            )
+            .control(|knob| knob.size(Length::Fixed(40.0)))
+            .placement(placement)
+        };
+        // Two rows of 12 pixel text with their line height, and the spacing
+        // between the three rows.
+        let height = 40.0 + 2.0 * 12.0 * 1.3 + 2.0 * 4.0;
+
+        for (placement, knob_y) in [
+            (Placement::TitleAbove, 12.0 * 1.3 + 4.0),
+            (Placement::Above, 2.0 * (12.0 * 1.3 + 4.0)),
+            (Placement::Below, 0.0),
+        ] {
+            let mut harness: Harness<'_, Message, Theme> = Harness::new(labeled(placement), SIZE);
+            let bounds = harness.bounds();
+            assert!((bounds.height - height).abs() < 1e-3);
+            assert!(bounds.width >= 40.0);
+
+            let knob = Point::new(bounds.center_x(), bounds.y + knob_y + 20.0);
+            harness.drag(knob, knob - Vector::new(0.0, 20.0));
+            assert!(!changed(&harness.take_messages()).is_empty());
+        }
+
+        // The labels themselves don't turn the knob.
+        let mut harness: Harness<'_, Message, Theme> =
+            Harness::new(labeled(Placement::TitleAbove), SIZE);
+        let bounds = harness.bounds();
+        let title = Point::new(bounds.center_x(), bounds.y + 4.0);
+        harness.drag(title, title - Vector::new(0.0, 20.0));
+        assert!(changed(&harness.take_messages()).is_empty());
+    }
+}
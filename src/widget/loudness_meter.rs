@@ -0,0 +1,476 @@
+//! Display a loudness meter: the momentary, short-term, and integrated
+//! loudness in LUFS as bars, with a target zone.
+//!
+//! The meter only shows the values it is given, so the application measures
+//! the loudness (e.g. following ITU-R BS.1770) and passes the latest values
+//! from the audio thread.
+
+use crate::core::{FloatRange, Normal};
+use crate::style::Dim;
+use iced::{
+    advanced::{
+        layout, mouse,
+        renderer::{self, Quad, Style},
+        widget::Tree,
+        Layout, Widget,
+    },
+    border::Radius,
+    Border, Color, Element, Length, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::loudness_meter::{Appearance, Catalog, StyleFn, StyleSheet};
+
+/// The default width of a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: struct.LoudnessMeter.html
+pub const DEFAULT_WIDTH: f32 = 30.0;
+
+/// The default range in LUFS of a [`LoudnessMeter`].
+///
+/// [`LoudnessMeter`]: struct.LoudnessMeter.html
+pub const DEFAULT_RANGE: FloatRange = FloatRange::new(-36.0, 0.0);
+
+/// The loudness values shown by a [`LoudnessMeter`], in LUFS.
+///
+/// Values below the range of the meter, such as `f32::NEG_INFINITY` for
+/// silence, are shown as empty bars.
+///
+/// [`LoudnessMeter`]: struct.LoudnessMeter.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Loudness {
+    /// The loudness of the last 400 ms
+    pub momentary: f32,
+    /// The loudness of the last 3 s
+    pub short_term: f32,
+    /// The loudness since the measurement started
+    pub integrated: f32,
+}
+
+impl Default for Loudness {
+    fn default() -> Self {
+        Self {
+            momentary: f32::NEG_INFINITY,
+            short_term: f32::NEG_INFINITY,
+            integrated: f32::NEG_INFINITY,
+        }
+    }
+}
+
+/// A target loudness with a tolerance around it, e.g. -14 LUFS ± 1 LU for
+/// streaming.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetZone {
+    /// The target loudness in LUFS
+    pub target: f32,
+    /// How far in LU the loudness may be from the target
+    pub tolerance: f32,
+}
+
+impl TargetZone {
+    /// Creates a new [`TargetZone`].
+    ///
+    /// [`TargetZone`]: struct.TargetZone.html
+    pub fn new(target: f32, tolerance: f32) -> Self {
+        Self {
+            target,
+            tolerance: tolerance.abs(),
+        }
+    }
+
+    /// Returns the lowest loudness in the zone.
+    pub fn low(&self) -> f32 {
+        self.target - self.tolerance
+    }
+
+    /// Returns the highest loudness in the zone.
+    pub fn high(&self) -> f32 {
+        self.target + self.tolerance
+    }
+}
+
+/// How a [`LoudnessMeter`] shows its values.
+///
+/// [`LoudnessMeter`]: struct.LoudnessMeter.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// A bar each for the momentary, short-term, and integrated loudness,
+    /// from left to right.
+    #[default]
+    Bars,
+    /// One bar for the short-term loudness, with a marker line across it at
+    /// the integrated loudness.
+    BarAndMarker,
+}
+
+/// A loudness meter: bars that grow up from the bottom with the loudness,
+/// in front of an optional target zone. The parts of the bars above the
+/// target zone are drawn in the over color.
+///
+/// It is not interactive and produces no messages.
+#[allow(missing_debug_implementations)]
+pub struct LoudnessMeter<'a, Theme>
+where
+    Theme: Catalog,
+{
+    loudness: Loudness,
+    range: FloatRange,
+    target: Option<TargetZone>,
+    mode: Mode,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    opacity: f32,
+}
+
+impl<'a, Theme> LoudnessMeter<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`LoudnessMeter`] showing the `loudness`.
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    pub fn new(loudness: Loudness) -> Self {
+        LoudnessMeter {
+            loudness,
+            range: DEFAULT_RANGE,
+            target: None,
+            mode: Mode::default(),
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Fill,
+            class: Theme::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets the range in LUFS from the bottom to the top of the
+    /// [`LoudnessMeter`].
+    ///
+    /// The default is [`DEFAULT_RANGE`].
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    /// [`DEFAULT_RANGE`]: constant.DEFAULT_RANGE.html
+    pub fn range(mut self, range: FloatRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Shows the [`TargetZone`] behind the bars, and draws the parts of the
+    /// bars above it in the over color.
+    ///
+    /// [`TargetZone`]: struct.TargetZone.html
+    pub fn target(mut self, target: TargetZone) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets how the [`LoudnessMeter`] shows its values.
+    ///
+    /// The default is [`Mode::Bars`].
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    /// [`Mode::Bars`]: enum.Mode.html#variant.Bars
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the width of the [`LoudnessMeter`].
+    ///
+    /// The default width is [`DEFAULT_WIDTH`].
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    /// [`DEFAULT_WIDTH`]: constant.DEFAULT_WIDTH.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LoudnessMeter`].
+    ///
+    /// The default height is `Length::Fill`.
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`LoudnessMeter`].
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    pub fn style(mut self, style: impl Fn(&Theme) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`LoudnessMeter`].
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`LoudnessMeter`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the meters of a muted channel. The default is `1.0`.
+    ///
+    /// [`LoudnessMeter`]: struct.LoudnessMeter.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns where `loudness` is from the bottom of the meter. Values that
+    /// are not numbers are shown at the bottom.
+    fn normal(&self, loudness: f32) -> Normal {
+        if loudness.is_nan() {
+            Normal::MIN
+        } else {
+            self.range.map_to_normal(loudness)
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for LoudnessMeter<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let appearance = theme.style(&self.class).dim(self.opacity);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.border_color,
+                    width: appearance.border_width,
+                    radius: Radius::new(appearance.border_radius),
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.back_color,
+        );
+
+        let inner = bounds.shrink(appearance.border_width);
+
+        if inner.width <= 0.0 || inner.height <= 0.0 {
+            return;
+        }
+
+        // The y position of `normal`, rounded to whole pixels.
+        let y = |normal: Normal| inner.y + normal.scale_inv(inner.height).round();
+
+        if let Some(target) = self.target {
+            let top = y(self.normal(target.high()));
+            let bottom = y(self.normal(target.low()));
+
+            fill(
+                renderer,
+                Rectangle {
+                    y: top,
+                    height: bottom - top,
+                    ..inner
+                },
+                appearance.target_zone_color,
+            );
+        }
+
+        let over = self.target.map(|target| y(self.normal(target.high())));
+
+        let bars: &[f32] = match self.mode {
+            Mode::Bars => &[
+                self.loudness.momentary,
+                self.loudness.short_term,
+                self.loudness.integrated,
+            ],
+            Mode::BarAndMarker => &[self.loudness.short_term],
+        };
+
+        let spacing = appearance.bar_spacing;
+        let bar_width =
+            ((inner.width - spacing * (bars.len() - 1) as f32) / bars.len() as f32).max(0.0);
+
+        for (i, &loudness) in bars.iter().enumerate() {
+            let x = inner.x + (bar_width + spacing) * i as f32;
+            let top = y(self.normal(loudness));
+            let bottom = inner.y + inner.height;
+
+            // The part of the bar above the target zone is drawn in the over
+            // color.
+            let split = over.map_or(top, |over| over.clamp(top, bottom));
+
+            fill(
+                renderer,
+                Rectangle::new(Point::new(x, top), Size::new(bar_width, split - top)),
+                appearance.over_color,
+            );
+            fill(
+                renderer,
+                Rectangle::new(Point::new(x, split), Size::new(bar_width, bottom - split)),
+                appearance.bar_color,
+            );
+        }
+
+        if self.mode == Mode::BarAndMarker && self.loudness.integrated.is_finite() {
+            let marker_y = y(self.normal(self.loudness.integrated));
+
+            fill(
+                renderer,
+                Rectangle {
+                    y: (marker_y - appearance.marker_height / 2.0)
+                        .min(inner.y + inner.height - appearance.marker_height)
+                        .max(inner.y),
+                    height: appearance.marker_height,
+                    ..inner
+                },
+                appearance.marker_color,
+            );
+        }
+    }
+}
+
+/// Fills `bounds` with `color` if it is not empty.
+fn fill<Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color)
+where
+    Renderer: renderer::Renderer,
+{
+    if bounds.width > 0.0 && bounds.height > 0.0 {
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LoudnessMeter<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: 'a + Catalog,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(loudness_meter: LoudnessMeter<'a, Theme>) -> Self {
+        Self::new(loudness_meter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::fixtures::{Message, BLUE, GRAY, LIGHT, ORANGE, SIZE};
+    use crate::test_utils::Harness;
+    use crate::{
+        loudness_meter::{self, Loudness, LoudnessMeter, TargetZone},
+        FloatRange,
+    };
+    use iced::{Color, Length, Theme};
+
+    #[test]
+    fn loudness_meter_target_zone() {
+        let loudness = Loudness {
+            momentary: -10.0,
+            short_term: -20.0,
+            integrated: f32::NEG_INFINITY,
+        };
+        let meter = |mode| {
+            LoudnessMeter::new(loudness)
+                .range(FloatRange::new(-40.0, 0.0))
+                .target(TargetZone::new(-14.0, 1.0))
+                .mode(mode)
+                .height(Length::Fixed(100.0))
+                .style(|_| loudness_meter::Appearance {
+                    back_color: LIGHT,
+                    border_width: 0.0,
+                    border_radius: 0.0,
+                    border_color: GRAY,
+                    bar_color: BLUE,
+                    over_color: ORANGE,
+                    bar_spacing: 0.0,
+                    target_zone_color: GRAY,
+                    marker_color: Color::BLACK,
+                    marker_height: 2.0,
+                })
+        };
+
+        let mut harness: Harness<'_, Message, Theme> =
+            Harness::new(meter(loudness_meter::Mode::Bars), SIZE);
+        let snapshot = harness.snapshot(&Theme::Light);
+        let pixel = |x, y| snapshot.pixel(x, y).map(Color::into_rgba8);
+
+        // The momentary bar is above the target zone, which spans -13 to -15
+        // LUFS at 33 to 38 pixels from the top.
+        assert_eq!(pixel(5, 28), Some(ORANGE.into_rgba8()));
+        assert_eq!(pixel(5, 60), Some(BLUE.into_rgba8()));
+
+        // The short-term bar is below it, and no integrated loudness has
+        // been measured yet.
+        assert_eq!(pixel(15, 35), Some(GRAY.into_rgba8()));
+        assert_eq!(pixel(15, 60), Some(BLUE.into_rgba8()));
+        assert_eq!(pixel(25, 35), Some(GRAY.into_rgba8()));
+        assert_eq!(pixel(25, 90), Some(LIGHT.into_rgba8()));
+
+        let loudness = Loudness {
+            integrated: -30.0,
+            ..loudness
+        };
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(
+            LoudnessMeter::new(loudness)
+                .range(FloatRange::new(-40.0, 0.0))
+                .mode(loudness_meter::Mode::BarAndMarker)
+                .height(Length::Fixed(100.0))
+                .style(move |theme| loudness_meter::Appearance {
+                    border_width: 0.0,
+                    marker_color: Color::BLACK,
+                    bar_color: BLUE,
+                    ..crate::style::loudness_meter::default(theme)
+                }),
+            SIZE,
+        );
+        let snapshot = harness.snapshot(&Theme::Light);
+
+        // One bar for the short-term loudness with a marker at the
+        // integrated loudness across it.
+        assert_eq!(
+            snapshot.pixel(15, 60).map(Color::into_rgba8),
+            Some(BLUE.into_rgba8())
+        );
+        assert_eq!(
+            snapshot.pixel(15, 75).map(Color::into_rgba8),
+            Some(Color::BLACK.into_rgba8())
+        );
+    }
+}
@@ -0,0 +1,406 @@
+//! Display a [`tick_marks::Group`] or a [`text_marks::Group`] on its own,
+//! e.g. as a ruler next to a waveform view or a widget of another crate.
+//!
+//! [`tick_marks::Group`]: ../tick_marks/struct.Group.html
+//! [`text_marks::Group`]: ../text_marks/struct.Group.html
+
+use crate::core::{text_marks, tick_marks};
+use iced::{
+    advanced::{
+        layout, mouse, renderer, text,
+        widget::{tree, Tree},
+        Layout, Widget,
+    },
+    Element, Font, Length, Rectangle, Size,
+};
+
+/// The direction in which the marks go from `0.0` to `1.0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// From left to right, like an [`HSlider`].
+    ///
+    /// [`HSlider`]: ../h_slider/struct.HSlider.html
+    #[default]
+    Horizontal,
+    /// From bottom to top, like a [`VSlider`].
+    ///
+    /// [`VSlider`]: ../v_slider/struct.VSlider.html
+    Vertical,
+}
+
+/// A widget that draws a [`tick_marks::Group`] inside of its bounds.
+///
+/// It fills the available length along its [`Orientation`], and is as thick
+/// as its longest tick mark.
+///
+/// [`tick_marks::Group`]: ../tick_marks/struct.Group.html
+/// [`Orientation`]: enum.Orientation.html
+#[allow(missing_debug_implementations)]
+pub struct TickMarks<'a> {
+    tick_marks: &'a tick_marks::Group,
+    orientation: Orientation,
+    width: Length,
+    height: Length,
+    style: tick_marks::Appearance,
+    placement: tick_marks::Placement,
+    inverse: bool,
+}
+
+impl<'a> TickMarks<'a> {
+    /// Creates a new [`TickMarks`] widget.
+    ///
+    /// It expects:
+    ///   * the [`tick_marks::Group`] to draw
+    ///   * the [`Orientation`] of the marks
+    ///
+    /// [`TickMarks`]: struct.TickMarks.html
+    /// [`tick_marks::Group`]: ../tick_marks/struct.Group.html
+    /// [`Orientation`]: enum.Orientation.html
+    pub fn new(tick_marks: &'a tick_marks::Group, orientation: Orientation) -> Self {
+        let (width, height) = main_axis_fill(orientation);
+
+        TickMarks {
+            tick_marks,
+            orientation,
+            width,
+            height,
+            style: tick_marks::Appearance::default(),
+            placement: tick_marks::Placement::Center {
+                offset: Default::default(),
+                fill_length: false,
+            },
+            inverse: false,
+        }
+    }
+
+    /// Sets the width of the [`TickMarks`].
+    ///
+    /// [`TickMarks`]: struct.TickMarks.html
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TickMarks`].
+    ///
+    /// [`TickMarks`]: struct.TickMarks.html
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Appearance`] of the tick marks.
+    ///
+    /// [`Appearance`]: ../tick_marks/struct.Appearance.html
+    pub fn style(mut self, style: tick_marks::Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the [`Placement`] of the tick marks relative to the bounds of the
+    /// [`TickMarks`]. It is `Placement::Center` by default.
+    ///
+    /// [`Placement`]: ../tick_marks/enum.Placement.html
+    /// [`TickMarks`]: struct.TickMarks.html
+    pub fn placement(mut self, placement: tick_marks::Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets whether to draw the marks in the opposite direction.
+    pub fn inverse(mut self, inverse: bool) -> Self {
+        self.inverse = inverse;
+        self
+    }
+
+    fn thickness(&self) -> f32 {
+        [self.style.tier_1, self.style.tier_2, self.style.tier_3]
+            .into_iter()
+            .map(|shape| match shape {
+                tick_marks::Shape::None => 0.0,
+                tick_marks::Shape::Line { length, .. } => length,
+                tick_marks::Shape::Circle { diameter, .. } => diameter,
+            })
+            .fold(0.0, f32::max)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for TickMarks<'a>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::stateless()
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let intrinsic_size = cross_axis_size(self.orientation, self.thickness());
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic_size))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        match self.orientation {
+            Orientation::Horizontal => tick_marks::draw_horizontal_tick_marks(
+                renderer,
+                &bounds,
+                self.tick_marks,
+                &self.style,
+                &self.placement,
+                self.inverse,
+            ),
+            Orientation::Vertical => tick_marks::draw_vertical_tick_marks(
+                renderer,
+                &bounds,
+                self.tick_marks,
+                &self.style,
+                &self.placement,
+                self.inverse,
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TickMarks<'a>> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(tick_marks: TickMarks<'a>) -> Self {
+        Self::new(tick_marks)
+    }
+}
+
+/// A widget that draws a [`text_marks::Group`] inside of its bounds.
+///
+/// It fills the available length along its [`Orientation`], and is as thick
+/// as the text bounds of its style.
+///
+/// [`text_marks::Group`]: ../text_marks/struct.Group.html
+/// [`Orientation`]: enum.Orientation.html
+#[allow(missing_debug_implementations)]
+pub struct TextMarks<'a> {
+    text_marks: &'a text_marks::Group,
+    orientation: Orientation,
+    width: Length,
+    height: Length,
+    style: text_marks::Appearance,
+    placement: text_marks::Placement,
+    inverse: bool,
+}
+
+impl<'a> TextMarks<'a> {
+    /// Creates a new [`TextMarks`] widget.
+    ///
+    /// It expects:
+    ///   * the [`text_marks::Group`] to draw
+    ///   * the [`Orientation`] of the marks
+    ///
+    /// [`TextMarks`]: struct.TextMarks.html
+    /// [`text_marks::Group`]: ../text_marks/struct.Group.html
+    /// [`Orientation`]: enum.Orientation.html
+    pub fn new(text_marks: &'a text_marks::Group, orientation: Orientation) -> Self {
+        let (width, height) = main_axis_fill(orientation);
+
+        TextMarks {
+            text_marks,
+            orientation,
+            width,
+            height,
+            style: text_marks::Appearance::default(),
+            placement: text_marks::Placement::Center {
+                align: text_marks::Align::Center,
+                offset: Default::default(),
+            },
+            inverse: false,
+        }
+    }
+
+    /// Sets the width of the [`TextMarks`].
+    ///
+    /// [`TextMarks`]: struct.TextMarks.html
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TextMarks`].
+    ///
+    /// [`TextMarks`]: struct.TextMarks.html
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Appearance`] of the text marks.
+    ///
+    /// [`Appearance`]: ../text_marks/struct.Appearance.html
+    pub fn style(mut self, style: text_marks::Appearance) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the [`Placement`] of the text marks relative to the bounds of the
+    /// [`TextMarks`]. It is `Placement::Center` by default.
+    ///
+    /// [`Placement`]: ../text_marks/enum.Placement.html
+    /// [`TextMarks`]: struct.TextMarks.html
+    pub fn placement(mut self, placement: text_marks::Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets whether to draw the marks in the opposite direction.
+    pub fn inverse(mut self, inverse: bool) -> Self {
+        self.inverse = inverse;
+        self
+    }
+
+    fn thickness(&self) -> f32 {
+        match self.orientation {
+            Orientation::Horizontal => f32::from(self.style.bounds_height),
+            Orientation::Vertical => f32::from(self.style.bounds_width),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for TextMarks<'a>
+where
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<text_marks::Cache>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(text_marks::Cache::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let intrinsic_size = cross_axis_size(self.orientation, self.thickness());
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic_size))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let cache = tree.state.downcast_ref::<text_marks::Cache>();
+        let bounds = layout.bounds();
+
+        match self.orientation {
+            Orientation::Horizontal => text_marks::draw_horizontal_text_marks(
+                renderer,
+                &bounds,
+                self.text_marks,
+                &self.style,
+                &self.placement,
+                self.inverse,
+                cache,
+            ),
+            Orientation::Vertical => text_marks::draw_vertical_text_marks(
+                renderer,
+                &bounds,
+                self.text_marks,
+                &self.style,
+                &self.placement,
+                self.inverse,
+                cache,
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TextMarks<'a>> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + text::Renderer<Font = Font>,
+{
+    fn from(text_marks: TextMarks<'a>) -> Self {
+        Self::new(text_marks)
+    }
+}
+
+/// The default `(width, height)` of marks with the given [`Orientation`]:
+/// filling the main axis and shrinking to the marks on the other.
+fn main_axis_fill(orientation: Orientation) -> (Length, Length) {
+    match orientation {
+        Orientation::Horizontal => (Length::Fill, Length::Shrink),
+        Orientation::Vertical => (Length::Shrink, Length::Fill),
+    }
+}
+
+/// The intrinsic size of marks that are `thickness` thick across the main
+/// axis of the [`Orientation`].
+fn cross_axis_size(orientation: Orientation, thickness: f32) -> Size {
+    match orientation {
+        Orientation::Horizontal => Size::new(0.0, thickness),
+        Orientation::Vertical => Size::new(thickness, 0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::fixtures::{Message, SIZE};
+    use crate::test_utils::{Harness, Snapshot};
+    use crate::{marks, tick_marks, TickMarks};
+    use iced::{Size, Theme};
+
+    #[test]
+    fn standalone_tick_marks() {
+        let group = tick_marks::Group::center(tick_marks::Tier::One);
+
+        let horizontal = TickMarks::new(&group, marks::Orientation::Horizontal);
+        let mut harness = Harness::<'_, Message, Theme>::new(horizontal, SIZE);
+        assert_eq!(harness.bounds().size(), Size::new(SIZE.width, 4.0));
+
+        let visible =
+            |snapshot: &Snapshot, x, y| snapshot.pixel(x, y).is_some_and(|color| color.a > 0.5);
+        let snapshot = harness.snapshot(&Theme::Light);
+        assert!(visible(&snapshot, 100, 2) && !visible(&snapshot, 10, 2));
+
+        let vertical = TickMarks::new(&group, marks::Orientation::Vertical);
+        let harness = Harness::<'_, Message, Theme>::new(vertical, SIZE);
+        assert_eq!(harness.bounds().size(), Size::new(4.0, SIZE.height));
+    }
+}
@@ -0,0 +1,149 @@
+//! Layout of the bar widgets that make room for their tick and text marks
+//!
+//! The marks are styled by the theme, which is only known when the widget is
+//! drawn. So the widget remembers how far its marks reach outside of it when
+//! it is drawn, and asks for a new layout on the next event when that
+//! changed.
+
+use std::cell::Cell;
+
+use crate::core::{text_marks, tick_marks};
+use iced::{
+    advanced::{layout, Layout, Shell},
+    window, Event, Length, Padding, Point, Rectangle, Size,
+};
+
+/// The state of a widget that includes its marks in its layout.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MarksLayout {
+    /// How far the marks reached outside of the widget when it was last
+    /// drawn, or `None` before it is drawn.
+    drawn: Cell<Option<Padding>>,
+    /// The room for the marks that the widget was last laid out with.
+    laid_out: Padding,
+}
+
+impl MarksLayout {
+    /// Lays out a bar widget with room for its marks around its body. The
+    /// body is the only child of the node.
+    pub fn layout(
+        &mut self,
+        limits: &layout::Limits,
+        width: Length,
+        height: Length,
+        intrinsic_size: Size,
+    ) -> layout::Node {
+        let padding = self.drawn.get().unwrap_or(Padding::ZERO);
+        self.laid_out = padding;
+
+        let body = limits
+            .shrink(padding)
+            .resolve(width, height, intrinsic_size);
+
+        layout::Node::with_children(
+            body.expand(padding),
+            vec![layout::Node::new(body).move_to(Point::new(padding.left, padding.top))],
+        )
+    }
+
+    /// Remembers how far the marks of the widget reach outside of its
+    /// `body` when it is drawn.
+    pub fn drawn(
+        &self,
+        body: Size,
+        vertical: bool,
+        tick_marks: Option<(&tick_marks::Appearance, &tick_marks::Placement)>,
+        text_marks: Option<(&text_marks::Appearance, &text_marks::Placement)>,
+    ) {
+        self.drawn
+            .set(Some(overhang(body, vertical, tick_marks, text_marks)));
+    }
+
+    /// Asks for a new layout when the marks reach further than the widget
+    /// was laid out for, and for another frame before the widget is drawn
+    /// for the first time.
+    pub fn on_event<Message>(&self, event: &Event, shell: &mut Shell<'_, Message>) {
+        match self.drawn.get() {
+            Some(padding) if padding != self.laid_out => shell.invalidate_layout(),
+            None if matches!(event, Event::Window(window::Event::RedrawRequested(_))) => {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the bounds of the body of a bar widget, without the room for its
+/// marks.
+pub(crate) fn body_bounds(layout: Layout<'_>) -> Rectangle {
+    layout
+        .children()
+        .next()
+        .map_or_else(|| layout.bounds(), |body| body.bounds())
+}
+
+/// Returns the bounds that the marks of a bar widget with the given `body`
+/// are drawn in.
+pub(crate) fn marks_bounds(
+    body: &Rectangle,
+    vertical: bool,
+    tick_marks: Option<(&tick_marks::Appearance, &tick_marks::Placement)>,
+    text_marks: Option<(&text_marks::Appearance, &text_marks::Placement)>,
+) -> Rectangle {
+    body.expand(overhang(body.size(), vertical, tick_marks, text_marks))
+}
+
+/// Returns how far the marks reach outside of the `body` of a bar widget.
+///
+/// Text marks are centered on their positions, so the ones at the ends also
+/// reach half of their width past the ends of the widget.
+fn overhang(
+    body: Size,
+    vertical: bool,
+    tick_marks: Option<(&tick_marks::Appearance, &tick_marks::Placement)>,
+    text_marks: Option<(&text_marks::Appearance, &text_marks::Placement)>,
+) -> Padding {
+    let thickness = if vertical { body.width } else { body.height };
+
+    let mut start = 0.0_f32;
+    let mut end = thickness;
+    let mut along = 0.0;
+
+    if let Some((appearance, placement)) = tick_marks {
+        let (from, to) = placement.span(appearance, thickness, vertical);
+        start = start.min(from);
+        end = end.max(to);
+    }
+
+    if let Some((appearance, placement)) = text_marks {
+        let (from, to) = placement.span(appearance, thickness, vertical);
+        start = start.min(from);
+        end = end.max(to);
+
+        along = if vertical {
+            f32::from(appearance.bounds_height)
+        } else {
+            f32::from(appearance.bounds_width)
+        } / 2.0;
+    }
+
+    let before = (-start).ceil();
+    let after = (end - thickness).ceil();
+    let along = along.ceil();
+
+    if vertical {
+        Padding {
+            top: along,
+            right: after,
+            bottom: along,
+            left: before,
+        }
+    } else {
+        Padding {
+            top: before,
+            right: along,
+            bottom: after,
+            left: along,
+        }
+    }
+}
@@ -0,0 +1,492 @@
+//! Display a meter bridge: the levels of many channels as a compact bar
+//! graph, with peak holds.
+//!
+//! A single [`MeterBridge`] draws all the channels of e.g. a mixer, which
+//! scales much better than a meter widget per channel. All the bars share
+//! one scale, so map the levels to a [`Normal`] with the same range, e.g.
+//! `FaderRange::default().map_to_normal(level_db)`.
+//!
+//! [`MeterBridge`]: struct.MeterBridge.html
+//! [`Normal`]: ../../core/struct.Normal.html
+
+use crate::core::Normal;
+use crate::style::Dim;
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse,
+        renderer::{self, Quad, Style},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    border::Radius,
+    time::{Duration, Instant},
+    window, Border, Color, Element, Event, Length, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::meter_bridge::{Appearance, Catalog, StyleFn, StyleSheet};
+
+/// The default height of a [`MeterBridge`].
+///
+/// [`MeterBridge`]: struct.MeterBridge.html
+pub const DEFAULT_HEIGHT: f32 = 80.0;
+
+/// The default time a [`MeterBridge`] holds the peak of each channel.
+///
+/// [`MeterBridge`]: struct.MeterBridge.html
+pub const DEFAULT_PEAK_HOLD: Duration = Duration::from_millis(1500);
+
+/// A meter bridge: a bar per channel that grows up from the bottom with its
+/// level, with a line that holds the peak of the level for a while.
+///
+/// It produces a message when the cursor moves to another channel, with
+/// [`on_hover`], and highlights the channel under the cursor.
+///
+/// [`on_hover`]: #method.on_hover
+#[allow(missing_debug_implementations)]
+pub struct MeterBridge<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    levels: &'a [Normal],
+    peak_hold: Option<Duration>,
+    on_hover: Option<Box<dyn Fn(Option<usize>) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    opacity: f32,
+}
+
+impl<'a, Message, Theme> MeterBridge<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`MeterBridge`] with a bar for each of the `levels`,
+    /// from left to right.
+    ///
+    /// [`MeterBridge`]: struct.MeterBridge.html
+    pub fn new(levels: &'a [Normal]) -> Self {
+        MeterBridge {
+            levels,
+            peak_hold: Some(DEFAULT_PEAK_HOLD),
+            on_hover: None,
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            class: Theme::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets how long the [`MeterBridge`] holds the peak of each channel
+    /// before it falls back to the level. `None` shows no peaks.
+    ///
+    /// The default is [`DEFAULT_PEAK_HOLD`].
+    ///
+    /// [`MeterBridge`]: struct.MeterBridge.html
+    /// [`DEFAULT_PEAK_HOLD`]: constant.DEFAULT_PEAK_HOLD.html
+    pub fn peak_hold(mut self, peak_hold: Option<Duration>) -> Self {
+        self.peak_hold = peak_hold;
+        self
+    }
+
+    /// Sets the message produced when the cursor moves to another channel
+    /// of the [`MeterBridge`], with the index of the channel, or `None` when
+    /// the cursor leaves it.
+    ///
+    /// [`MeterBridge`]: struct.MeterBridge.html
+    pub fn on_hover<F>(mut self, on_hover: F) -> Self
+    where
+        F: 'a + Fn(Option<usize>) -> Message,
+    {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the width of the [`MeterBridge`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`MeterBridge`]: struct.MeterBridge.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`MeterBridge`].
+    ///
+    /// The default height is [`DEFAULT_HEIGHT`].
+    ///
+    /// [`MeterBridge`]: struct.MeterBridge.html
+    /// [`DEFAULT_HEIGHT`]: constant.DEFAULT_HEIGHT.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`MeterBridge`].
+    ///
+    /// [`MeterBridge`]: struct.MeterBridge.html
+    pub fn style(mut self, style: impl Fn(&Theme) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MeterBridge`].
+    ///
+    /// [`MeterBridge`]: struct.MeterBridge.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`MeterBridge`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the meters of a muted bus. The default is `1.0`.
+    ///
+    /// [`MeterBridge`]: struct.MeterBridge.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the channel at `x` in `bounds`, where each channel takes an
+    /// equal share of the width.
+    fn channel_at(&self, bounds: Rectangle, x: f32) -> Option<usize> {
+        let count = self.levels.len();
+
+        if count == 0 || bounds.width <= 0.0 {
+            return None;
+        }
+
+        let index = ((x - bounds.x) / bounds.width * count as f32).floor();
+
+        (index >= 0.0).then(|| (index as usize).min(count - 1))
+    }
+}
+
+/// The held peak of a channel.
+#[derive(Debug, Clone, Copy)]
+struct Peak {
+    level: Normal,
+    since: Instant,
+}
+
+/// The local state of a [`MeterBridge`].
+///
+/// [`MeterBridge`]: struct.MeterBridge.html
+#[derive(Debug, Clone, Default)]
+struct State {
+    peaks: Vec<Peak>,
+    hovered: Option<usize>,
+}
+
+impl State {
+    /// Holds the peaks of the `levels` at the frame drawn at `now`, and
+    /// returns when the next held peak falls back to its level.
+    fn hold(&mut self, levels: &[Normal], now: Instant, hold: Duration) -> Option<Instant> {
+        self.peaks.resize(
+            levels.len(),
+            Peak {
+                level: Normal::MIN,
+                since: now,
+            },
+        );
+
+        for (peak, &level) in self.peaks.iter_mut().zip(levels) {
+            if level >= peak.level || now.saturating_duration_since(peak.since) >= hold {
+                *peak = Peak { level, since: now };
+            }
+        }
+
+        self.peaks
+            .iter()
+            .zip(levels)
+            .filter(|(peak, &level)| peak.level > level)
+            .map(|(peak, _)| peak.since + hold)
+            .min()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MeterBridge<'a, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = state.state.downcast_mut::<State>();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => match self.peak_hold {
+                Some(hold) => {
+                    if let Some(fall) = state.hold(self.levels, now, hold) {
+                        shell.request_redraw(window::RedrawRequest::At(fall));
+                    }
+                }
+                None => state.peaks.clear(),
+            },
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                let bounds = layout.bounds();
+                let hovered = cursor
+                    .position_over(bounds)
+                    .and_then(|position| self.channel_at(bounds, position.x));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+
+                    if let Some(on_hover) = &self.on_hover {
+                        shell.publish(on_hover(hovered));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = theme.style(&self.class).dim(self.opacity);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.border_color,
+                    width: appearance.border_width,
+                    radius: Radius::new(appearance.border_radius),
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.back_color,
+        );
+
+        let inner = bounds.shrink(appearance.border_width);
+        let count = self.levels.len();
+
+        if count == 0 || inner.width <= 0.0 || inner.height <= 0.0 {
+            return;
+        }
+
+        let spacing = appearance.bar_spacing;
+        let bar_width = ((inner.width - spacing * (count - 1) as f32) / count as f32).max(0.0);
+        let bottom = inner.y + inner.height;
+
+        // The y position of `normal`, rounded to whole pixels.
+        let y = |normal: Normal| inner.y + normal.scale_inv(inner.height).round();
+
+        for (i, &level) in self.levels.iter().enumerate() {
+            let column = Rectangle {
+                x: inner.x + (bar_width + spacing) * i as f32,
+                width: bar_width,
+                ..inner
+            };
+
+            if state.hovered == Some(i) {
+                fill(renderer, column, appearance.highlight_color);
+            }
+
+            let top = y(level);
+
+            fill(
+                renderer,
+                Rectangle {
+                    y: top,
+                    height: bottom - top,
+                    ..column
+                },
+                appearance.bar_color,
+            );
+
+            let peak = state.peaks.get(i).map(|peak| peak.level);
+
+            if let Some(peak) = peak.filter(|&peak| self.peak_hold.is_some() && peak > Normal::MIN)
+            {
+                fill(
+                    renderer,
+                    Rectangle {
+                        y: y(peak).min(bottom - appearance.peak_height),
+                        height: appearance.peak_height,
+                        ..column
+                    },
+                    appearance.peak_color,
+                );
+            }
+        }
+    }
+}
+
+/// Fills `bounds` with `color` if it is not empty.
+fn fill<Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color)
+where
+    Renderer: renderer::Renderer,
+{
+    if bounds.width > 0.0 && bounds.height > 0.0 {
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MeterBridge<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(meter_bridge: MeterBridge<'a, Message, Theme>) -> Self {
+        Self::new(meter_bridge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Duration, Instant, Normal, State};
+    use crate::test_utils::fixtures::{Message, BLUE, GRAY, LIGHT, ORANGE, SIZE};
+    use crate::test_utils::Harness;
+    use crate::{meter_bridge, MeterBridge};
+    use iced::{window, Color, Length, Point, Theme};
+
+    #[test]
+    fn peaks_hold_and_fall() {
+        let start = Instant::now();
+        let hold = Duration::from_millis(100);
+        let mut state = State::default();
+
+        let loud = [Normal::from_clipped(0.8), Normal::from_clipped(0.2)];
+        assert_eq!(state.hold(&loud, start, hold), None);
+
+        // The first channel gets quieter, so its peak is held until it
+        // falls back to the level once the hold time is over.
+        let quiet = [Normal::from_clipped(0.3), Normal::from_clipped(0.2)];
+        let later = start + Duration::from_millis(50);
+        assert_eq!(state.hold(&quiet, later, hold), Some(start + hold));
+        assert_eq!(state.peaks[0].level, Normal::from_clipped(0.8));
+
+        assert_eq!(state.hold(&quiet, start + hold, hold), None);
+        assert_eq!(state.peaks[0].level, Normal::from_clipped(0.3));
+    }
+
+    #[test]
+    fn meter_bridge_hover_and_peaks() {
+        let start = Instant::now();
+        let loud = [0.5, 1.0, 0.0, 0.25].map(Normal::from_clipped);
+        let quiet = [0.2, 1.0, 0.0, 0.25].map(Normal::from_clipped);
+        let meter = |levels| {
+            MeterBridge::new(levels)
+                .width(Length::Fixed(40.0))
+                .height(Length::Fixed(100.0))
+                .on_hover(Message::Hovered)
+                .style(|_| meter_bridge::Appearance {
+                    back_color: LIGHT,
+                    border_width: 0.0,
+                    border_radius: 0.0,
+                    border_color: GRAY,
+                    bar_color: BLUE,
+                    bar_spacing: 0.0,
+                    peak_color: ORANGE,
+                    peak_height: 2.0,
+                    highlight_color: GRAY,
+                })
+        };
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(meter(&loud), SIZE);
+
+        // Each of the four channels is 10 pixels wide, and only moving to
+        // another one produces a message.
+        let _ = harness.move_cursor(Point::new(15.0, 10.0));
+        let _ = harness.move_cursor(Point::new(18.0, 10.0));
+        let _ = harness.move_cursor(Point::new(25.0, 10.0));
+        let _ = harness.move_cursor(Point::new(60.0, 10.0));
+        assert_eq!(
+            harness.take_messages(),
+            vec![
+                Message::Hovered(Some(1)),
+                Message::Hovered(Some(2)),
+                Message::Hovered(None)
+            ]
+        );
+
+        let _ = harness.move_cursor(Point::new(5.0, 10.0));
+        assert_eq!(harness.redraw(start), None);
+
+        // The first channel gets quieter, and its peak is held at 0.5 until
+        // the hold time is over.
+        harness.rebuild(meter(&quiet));
+        assert_eq!(
+            harness.redraw(start + Duration::from_millis(100)),
+            Some(window::RedrawRequest::At(
+                start + meter_bridge::DEFAULT_PEAK_HOLD
+            ))
+        );
+
+        let snapshot = harness.snapshot(&Theme::Light);
+        let pixel = |x, y| snapshot.pixel(x, y).map(Color::into_rgba8);
+        assert_eq!(pixel(5, 10), Some(GRAY.into_rgba8()));
+        assert_eq!(pixel(5, 51), Some(ORANGE.into_rgba8()));
+        assert_eq!(pixel(5, 90), Some(BLUE.into_rgba8()));
+        assert_eq!(pixel(15, 10), Some(BLUE.into_rgba8()));
+        assert_eq!(pixel(25, 90), Some(LIGHT.into_rgba8()));
+
+        let _ = harness.redraw(start + meter_bridge::DEFAULT_PEAK_HOLD);
+        let snapshot = harness.snapshot(&Theme::Light);
+        assert_eq!(
+            snapshot.pixel(5, 51).map(Color::into_rgba8),
+            Some(GRAY.into_rgba8())
+        );
+    }
+}
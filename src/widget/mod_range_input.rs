@@ -2,27 +2,79 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
-use crate::core::{Normal, NormalParam, SliderStatus};
+#[cfg(feature = "a11y")]
+use crate::a11y;
+use crate::{
+    core::{
+        sensitivity::DEFAULT_PIXELS_PER_STEP, Change, DoubleClick, DragThreshold, HitShape,
+        ModifierMatch, Normal, NormalParam, Param, SliderSensitivity, ViewWindow,
+    },
+    defaults,
+    operation::{DragState, HandleState, ValueState},
+    style::Dim,
+    widget::{
+        focus_ring,
+        virtual_slider::{Axis, State, VirtualSlider},
+    },
+};
 use iced::{
     advanced::{
-        graphics::core::{event, keyboard, touch},
+        graphics::core::{event, keyboard},
         layout, mouse,
-        renderer::{Quad, Style},
-        widget::{tree, Tree},
-        Clipboard, Layout, Renderer as _, Shell, Widget,
+        renderer::{self, Quad, Style},
+        widget::{tree, Id, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
     },
     border::Radius,
-    Border, Element, Event, Length, Rectangle, Renderer, Shadow, Size,
+    time::Duration,
+    Border, Element, Event, Length, Padding, Rectangle, Shadow, Size,
 };
 
 pub use crate::style::mod_range_input::{
-    Appearance, CircleAppearance, SquareAppearance, StyleSheet,
+    Appearance, Catalog, CircleAppearance, SquareAppearance, Status, StyleFn, StyleSheet,
+};
+
+/// The default width and height of the [`ModRangeInput`].
+///
+/// [`ModRangeInput`]: struct.ModRangeInput.html
+pub const DEFAULT_SIZE: f32 = 10.0;
+
+/// The default `scalar` of the [`ModRangeInput`], i.e. how much the value
+/// changes per pixel the mouse is dragged vertically.
+///
+/// Touch screens are smaller and fingers less precise than a mouse, so the
+/// web (`wasm32`) needs less travel to sweep the whole range.
+///
+/// [`ModRangeInput`]: struct.ModRangeInput.html
+pub const DEFAULT_SCALAR: f32 = if cfg!(target_arch = "wasm32") {
+    0.0077 / 2.0
+} else {
+    0.00385 / 2.0
 };
 
-static DEFAULT_SIZE: f32 = 10.0;
-static DEFAULT_SCALAR: f32 = 0.00385 / 2.0;
-static DEFAULT_WHEEL_SCALAR: f32 = 0.01 / 2.0;
-static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+/// The default `wheel_scalar` of the [`ModRangeInput`].
+///
+/// [`ModRangeInput`]: struct.ModRangeInput.html
+pub const DEFAULT_WHEEL_SCALAR: f32 = 0.01 / 2.0;
+
+/// The default `modifier_scalar` of the [`ModRangeInput`].
+///
+/// [`ModRangeInput`]: struct.ModRangeInput.html
+pub const DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// The default [`SliderSensitivity`] of the [`ModRangeInput`].
+///
+/// [`ModRangeInput`]: struct.ModRangeInput.html
+/// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+pub const DEFAULT_SENSITIVITY: SliderSensitivity = SliderSensitivity {
+    scalar: DEFAULT_SCALAR,
+    wheel_scalar: DEFAULT_WHEEL_SCALAR,
+    pixels_per_step: DEFAULT_PIXELS_PER_STEP,
+    modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+    modifier_keys: keyboard::Modifiers::CTRL,
+    modifier_match: ModifierMatch::Contains,
+    extra_modifiers: &[],
+};
 
 /// An interactive dot that controls an [`NormalParam`]
 ///
@@ -30,23 +82,34 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 #[allow(missing_debug_implementations)]
 pub struct ModRangeInput<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     normal_param: NormalParam,
     size: Length,
-    on_change: Box<dyn 'a + Fn(Normal) -> Message>,
+    hit_padding: Padding,
+    hit_shape: HitShape,
+    on_change: Box<dyn 'a + Fn(Change) -> Message>,
     on_grab: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
     on_release: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
-    scalar: f32,
-    wheel_scalar: f32,
-    modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
-    style: <Theme as StyleSheet>::Style,
+    sensitivity: SliderSensitivity,
+    capture_wheel: bool,
+    double_click: DoubleClick,
+    drag_threshold: DragThreshold,
+    wheel_gesture: Option<Duration>,
+    id: Option<Id>,
+    #[cfg(feature = "a11y")]
+    name: Option<String>,
+    #[cfg(feature = "a11y")]
+    description: Option<String>,
+    #[cfg(feature = "a11y")]
+    announced_value: Option<Box<dyn 'a + Fn(Normal) -> String>>,
+    class: Theme::Class<'a>,
+    opacity: f32,
 }
 
 impl<'a, Message, Theme> ModRangeInput<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     /// Creates a new [`ModRangeInput`].
     ///
@@ -60,20 +123,68 @@ where
     where
         F: 'a + Fn(Normal) -> Message,
     {
+        let defaults = defaults::get();
+
         ModRangeInput {
             normal_param,
-            size: Length::Fixed(DEFAULT_SIZE),
-            on_change: Box::new(on_change),
+            size: Length::Fixed(defaults.mod_range_input_size),
+            hit_padding: Padding::ZERO,
+            hit_shape: HitShape::Rectangle,
+            on_change: Box::new(move |change: Change| on_change(change.normal)),
             on_grab: None,
             on_release: None,
-            scalar: DEFAULT_SCALAR,
-            wheel_scalar: DEFAULT_WHEEL_SCALAR,
-            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers::CTRL,
-            style: Default::default(),
+            sensitivity: defaults.mod_range_input_sensitivity,
+            capture_wheel: true,
+            double_click: defaults.double_click,
+            drag_threshold: defaults.drag_threshold,
+            wheel_gesture: defaults.wheel_gesture,
+            id: None,
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            announced_value: None,
+            class: Theme::default(),
+            opacity: 1.0,
         }
     }
 
+    /// Creates a new [`ModRangeInput`] from a [`Param`].
+    ///
+    /// Unlike `ModRangeInput::new()`, `on_change` is called with the (snapped) value of
+    /// the parameter instead of a [`Normal`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Param`]: ../../core/param/trait.Param.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn from_param<P, F>(param: P, on_change: F) -> Self
+    where
+        P: 'a + Param,
+        F: 'a + Fn(P::Value) -> Message,
+    {
+        let normal_param = param.normal_param();
+
+        Self::new(normal_param, move |normal| {
+            on_change(param.unmap_to_value(normal))
+        })
+    }
+
+    /// Sets the message to produce when the [`ModRangeInput`] is moved, replacing
+    /// the `on_change` function given to `ModRangeInput::new()`.
+    ///
+    /// Unlike `on_change`, `on_change_full` receives a [`Change`] with the
+    /// previous value and whether the value was dragged, scrolled or reset.
+    /// This is useful to implement undo, or to treat wheel nudges
+    /// differently from drags.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Change`]: ../../core/struct.Change.html
+    pub fn on_change_full(mut self, on_change_full: impl 'a + Fn(Change) -> Message) -> Self {
+        self.on_change = Box::new(on_change_full);
+        self
+    }
+
     /// Sets the grab message of the [`ModRangeInput`].
     /// This is called when the mouse grabs from the mod range input.
     ///
@@ -105,23 +216,132 @@ where
         self
     }
 
-    /// Sets the style of the [`ModRangeInput`].
+    /// Sets extra space around the [`ModRangeInput`] that also reacts to the mouse
+    /// and to touches, without changing its size in the layout or how it is
+    /// drawn. There is no hit padding by default.
+    ///
+    /// This makes small controls easier to hit on touch screens. The padded
+    /// area may overlap neighbouring widgets.
     ///
     /// [`ModRangeInput`]: struct.ModRangeInput.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    pub fn hit_padding(mut self, hit_padding: impl Into<Padding>) -> Self {
+        self.hit_padding = hit_padding.into();
+        self
+    }
+
+    /// Sets the shape of the area of the [`ModRangeInput`] that reacts to the
+    /// mouse and to touches, within its bounds and hit padding. The default
+    /// is [`HitShape::Rectangle`].
+    ///
+    /// [`HitShape::Circle`] suits the circle style, so that presses in its
+    /// corners reach e.g. the knob the [`ModRangeInput`] sits next to.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`HitShape::Rectangle`]: ../core/hit_shape/enum.HitShape.html#variant.Rectangle
+    /// [`HitShape::Circle`]: ../core/hit_shape/enum.HitShape.html#variant.Circle
+    pub fn hit_shape(mut self, hit_shape: HitShape) -> Self {
+        self.hit_shape = hit_shape;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`ModRangeInput`], e.g. to check whether it is being
+    /// dragged with [`operation::is_dragging()`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Id`]: https://docs.rs/iced/0.13/iced/advanced/widget/struct.Id.html
+    /// [`operation::is_dragging()`]: ../../operation/fn.is_dragging.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the name of the [`ModRangeInput`] that screen readers announce, e.g. the
+    /// name of the parameter it controls.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    #[cfg(feature = "a11y")]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the [`ModRangeInput`] that screen readers announce
+    /// after its name.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    #[cfg(feature = "a11y")]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets how screen readers announce the value of the [`ModRangeInput`], e.g. as
+    /// `"-6 dB"` or `"440 Hz"` for the mapped value of its parameter, instead
+    /// of the raw [`Normal`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    #[cfg(feature = "a11y")]
+    pub fn announced_value(mut self, format: impl 'a + Fn(Normal) -> String) -> Self {
+        self.announced_value = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the [`ModRangeInput`] with a function of the theme and the
+    /// [`Status`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Status`]: ../../style/enum.Status.html
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ModRangeInput`], e.g. a custom [`StyleSheet`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`StyleSheet`]: ../../style/mod_range_input/trait.StyleSheet.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`ModRangeInput`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim an unassigned modulation slot. The default is `1.0`.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the [`SliderSensitivity`] of the [`ModRangeInput`], i.e. its `scalar`,
+    /// `wheel_scalar`, `pixels_per_step`, `modifier_scalar` and `modifier_keys`
+    /// at once.
+    ///
+    /// The default is [`DEFAULT_SENSITIVITY`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`SliderSensitivity`]: ../../core/struct.SliderSensitivity.html
+    /// [`DEFAULT_SENSITIVITY`]: constant.DEFAULT_SENSITIVITY.html
+    pub fn sensitivity(mut self, sensitivity: SliderSensitivity) -> Self {
+        self.sensitivity = sensitivity;
         self
     }
 
     /// Sets how much the [`Normal`] value will change for the [`ModRangeInput`] per `y`
     /// pixel movement of the mouse.
     ///
-    /// The default value is `0.001925`
+    /// The default value is `0.001925`, or `0.00385` on the web (`wasm32`)
     ///
     /// [`ModRangeInput`]: struct.ModRangeInput.html
     /// [`Normal`]: ../../core/struct.Normal.html
     pub fn scalar(mut self, scalar: f32) -> Self {
-        self.scalar = scalar;
+        self.sensitivity.scalar = scalar;
         self
     }
 
@@ -135,7 +355,72 @@ where
     /// [`ModRangeInput`]: struct.ModRangeInput.html
     /// [`Normal`]: ../../core/struct.Normal.html
     pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
-        self.wheel_scalar = wheel_scalar;
+        self.sensitivity.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how many pixels a pixel based scroll delta (e.g. from a
+    /// touchpad) has to add up to before the [`ModRangeInput`] moves by one
+    /// `wheel_scalar` step.
+    ///
+    /// The default value is [`DEFAULT_PIXELS_PER_STEP`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`DEFAULT_PIXELS_PER_STEP`]: ../../core/sensitivity/constant.DEFAULT_PIXELS_PER_STEP.html
+    pub fn pixels_per_step(mut self, pixels_per_step: f32) -> Self {
+        self.sensitivity.pixels_per_step = pixels_per_step;
+        self
+    }
+
+    /// Sets whether the [`ModRangeInput`] captures the wheel events that move
+    /// it, so a scrollable parent doesn't scroll at the same time. With
+    /// `false`, the wheel scrolls the parent instead of moving the
+    /// [`ModRangeInput`].
+    ///
+    /// Wheel events are always captured while the [`ModRangeInput`] is dragged.
+    /// The default value is `true`.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn capture_wheel(mut self, capture_wheel: bool) -> Self {
+        self.capture_wheel = capture_wheel;
+        self
+    }
+
+    /// Sets when two presses of the [`ModRangeInput`] count as a double click, which
+    /// resets it to its default value.
+    ///
+    /// The default is the `double_click` of the [`Defaults`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn double_click(mut self, double_click: DoubleClick) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
+    /// Sets how far the pointer has to move from a press of the [`ModRangeInput`]
+    /// before it drags the value, so that a click or a tap doesn't move it.
+    ///
+    /// The default is the `drag_threshold` of the [`Defaults`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn drag_threshold(mut self, drag_threshold: DragThreshold) -> Self {
+        self.drag_threshold = drag_threshold;
+        self
+    }
+
+    /// Groups consecutive wheel steps of the [`ModRangeInput`] into one gesture, which
+    /// fires `on_grab` at the first step and `on_release` once the wheel is
+    /// idle for `idle_timeout`, e.g. for hosts that create an undo entry per
+    /// gesture. With `None`, every wheel step fires both.
+    ///
+    /// The default is the `wheel_gesture` of the [`Defaults`].
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn wheel_gesture(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.wheel_gesture = idle_timeout;
         self
     }
 
@@ -145,7 +430,7 @@ where
     ///
     /// [`ModRangeInput`]: struct.ModRangeInput.html
     pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
+        self.sensitivity.modifier_keys = modifier_keys;
         self
     }
 
@@ -161,80 +446,43 @@ where
     ///
     /// [`ModRangeInput`]: struct.ModRangeInput.html
     pub fn modifier_scalar(mut self, scalar: f32) -> Self {
-        self.modifier_scalar = scalar;
+        self.sensitivity.modifier_scalar = scalar;
         self
     }
 
-    fn move_virtual_slider(&mut self, state: &mut State, mut normal_delta: f32) -> SliderStatus {
-        if normal_delta.abs() < f32::EPSILON {
-            return SliderStatus::Unchanged;
-        }
-
-        if state.pressed_modifiers.contains(self.modifier_keys) {
-            normal_delta *= self.modifier_scalar;
-        }
-
-        self.normal_param
-            .value
-            .set_clipped(state.continuous_normal - normal_delta);
-        state.continuous_normal = self.normal_param.value.as_f32();
-
-        SliderStatus::Moved
-    }
-
-    fn maybe_fire_on_grab(&mut self, shell: &mut Shell<'_, Message>) {
-        if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
-            shell.publish(message);
-        }
-    }
-
-    fn fire_on_change(&self, shell: &mut Shell<'_, Message>) {
-        shell.publish((self.on_change)(self.normal_param.value));
-    }
-
-    fn maybe_fire_on_release(&mut self, shell: &mut Shell<'_, Message>) {
-        if let Some(message) = self.on_release.as_mut().and_then(|on_release| on_release()) {
-            shell.publish(message);
-        }
-    }
-}
-
-/// The local state of an [`ModRangeInput`].
-///
-/// [`ModRangeInput`]: struct.ModRangeInput.html
-#[derive(Debug, Copy, Clone)]
-struct State {
-    dragging_status: Option<SliderStatus>,
-    prev_drag_y: f32,
-    prev_normal: Normal,
-    continuous_normal: f32,
-    pressed_modifiers: keyboard::Modifiers,
-    last_click: Option<mouse::Click>,
-}
-
-impl State {
-    /// Creates a new [`ModRangeInput`] state.
-    ///
-    /// It expects:
-    /// * current [`Normal`] value for the [`ModRangeInput`]
-    ///
-    /// [`Normal`]: ../../core/normal/struct.Normal.html
-    /// [`ModRangeInput`]: struct.ModRangeInput.html
-    fn new(normal: Normal) -> Self {
-        Self {
-            dragging_status: None,
-            prev_drag_y: 0.0,
-            prev_normal: normal,
-            continuous_normal: normal.as_f32(),
-            pressed_modifiers: Default::default(),
-            last_click: None,
+    fn virtual_slider(&mut self) -> VirtualSlider<'_, 'a, Message> {
+        VirtualSlider {
+            normal_param: &mut self.normal_param,
+            sensitivity: self.sensitivity,
+            on_change: &*self.on_change,
+            on_grab: self.on_grab.as_deref_mut(),
+            on_release: self.on_release.as_deref_mut(),
+            steps: None,
+            free_modifier_keys: keyboard::Modifiers::empty(),
+            fine_drag_button: None,
+            double_click: self.double_click,
+            drag_threshold: self.drag_threshold,
+            detents: None,
+            on_type_in: None,
+            view_window: ViewWindow::FULL,
+            inverse: false,
+            on_drop: None,
+            group: None,
+            on_group_change: None,
+            capture_wheel: self.capture_wheel,
+            horizontal_wheel_scalar: 0.0,
+            spring_back: None,
+            wheel_gesture: self.wheel_gesture,
+            hit_shape: self.hit_shape,
         }
     }
 }
 
-impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for ModRangeInput<'a, Message, Theme>
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ModRangeInput<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -260,6 +508,57 @@ where
         layout::Node::new(limits.resolve(self.size, self.size, Size::ZERO))
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = state.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref());
+
+        operation.custom(
+            &mut DragState {
+                status: state.dragging_status,
+            },
+            self.id.as_ref(),
+        );
+
+        operation.custom(
+            &mut ValueState {
+                normal: self.normal_param.value,
+                default: self.normal_param.default,
+            },
+            self.id.as_ref(),
+        );
+
+        operation.custom(
+            &mut HandleState {
+                center: layout.bounds().center(),
+            },
+            self.id.as_ref(),
+        );
+
+        #[cfg(feature = "a11y")]
+        operation.custom(
+            &mut a11y::slider_node(
+                self.name.as_deref(),
+                self.description.as_deref(),
+                self.normal_param.value,
+                self.announced_value
+                    .as_ref()
+                    .map(|format| format(self.normal_param.value))
+                    .as_deref(),
+                (self.sensitivity.wheel_scalar > 0.0).then_some(self.sensitivity.wheel_scalar),
+                None,
+                layout.bounds(),
+            ),
+            self.id.as_ref(),
+        );
+    }
+
     fn on_event(
         &mut self,
         state: &mut Tree,
@@ -272,152 +571,11 @@ where
         _viewport: &Rectangle,
     ) -> event::Status {
         let state = state.state.downcast_mut::<State>();
+        let hit_bounds = layout.bounds().expand(self.hit_padding);
+        let axis = Some(Axis::vertical(self.sensitivity.scalar));
 
-        let is_over = cursor.is_over(layout.bounds());
-
-        // Update state after a discontinuity
-        if state.dragging_status.is_none() && state.prev_normal != self.normal_param.value {
-            state.prev_normal = self.normal_param.value;
-            state.continuous_normal = self.normal_param.value.as_f32();
-        }
-
-        match event {
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if state.dragging_status.is_some() {
-                    let normal_delta = (position.y - state.prev_drag_y) * self.scalar;
-
-                    state.prev_drag_y = position.y;
-
-                    if self.move_virtual_slider(state, normal_delta).was_moved() {
-                        self.fire_on_change(shell);
-
-                        state
-                            .dragging_status
-                            .as_mut()
-                            .expect("dragging_status taken")
-                            .moved();
-                    }
-
-                    return event::Status::Captured;
-                }
-            }
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                if self.wheel_scalar == 0.0 {
-                    return event::Status::Ignored;
-                }
-
-                if is_over {
-                    let lines = match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => y,
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            if y > 0.0 {
-                                1.0
-                            } else if y < 0.0 {
-                                -1.0
-                            } else {
-                                0.0
-                            }
-                        }
-                    };
-
-                    if lines != 0.0 {
-                        let normal_delta = -lines * self.wheel_scalar;
-
-                        if self.move_virtual_slider(state, normal_delta).was_moved() {
-                            if state.dragging_status.is_none() {
-                                self.maybe_fire_on_grab(shell);
-                            }
-
-                            self.fire_on_change(shell);
-
-                            if let Some(slider_status) = state.dragging_status.as_mut() {
-                                // Widget was grabbed => keep it grabbed
-                                slider_status.moved();
-                            } else {
-                                self.maybe_fire_on_release(shell);
-                            }
-                        }
-
-                        return event::Status::Captured;
-                    }
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if is_over {
-                    let cursor_position = cursor.position().unwrap();
-
-                    let click =
-                        mouse::Click::new(cursor_position, mouse::Button::Left, state.last_click);
-
-                    match click.kind() {
-                        mouse::click::Kind::Single => {
-                            self.maybe_fire_on_grab(shell);
-
-                            state.dragging_status = Some(Default::default());
-                            state.prev_drag_y = cursor_position.y;
-                        }
-                        _ => {
-                            // Reset to default
-
-                            let prev_dragging_status = state.dragging_status.take();
-
-                            if self.normal_param.value != self.normal_param.default {
-                                if prev_dragging_status.is_none() {
-                                    self.maybe_fire_on_grab(shell);
-                                }
-
-                                self.normal_param.value = self.normal_param.default;
-
-                                self.fire_on_change(shell);
-
-                                self.maybe_fire_on_release(shell);
-                            } else if prev_dragging_status.is_some() {
-                                self.maybe_fire_on_release(shell);
-                            }
-                        }
-                    }
-
-                    state.last_click = Some(click);
-
-                    return event::Status::Captured;
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                if let Some(slider_status) = state.dragging_status.take() {
-                    if self.on_grab.is_some() || slider_status.was_moved() {
-                        // maybe fire on release if `on_grab` is defined
-                        // so as to terminate the action, regardless of the actual user movement.
-                        self.maybe_fire_on_release(shell);
-                    }
-
-                    return event::Status::Captured;
-                }
-            }
-            Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::KeyReleased { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::ModifiersChanged(modifiers) => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-            },
-            _ => {}
-        }
-
-        event::Status::Ignored
+        self.virtual_slider()
+            .on_event(state, &event, cursor, hit_bounds, axis, shell)
     }
 
     fn draw(
@@ -432,16 +590,25 @@ where
     ) {
         let state = state.state.downcast_ref::<State>();
         let bounds = layout.bounds();
-        let is_over = cursor.is_over(layout.bounds());
+        let hit_bounds = layout.bounds().expand(self.hit_padding);
+        let is_over = cursor
+            .position()
+            .is_some_and(|position| self.hit_shape.contains(hit_bounds, position));
 
-        let appearance = if state.dragging_status.is_some() {
-            theme.dragging(&self.style)
+        let status = if state.dragging_status.is_some() {
+            Status::Dragged
         } else if is_over {
-            theme.hovered(&self.style)
+            Status::Hovered
+        } else if state.is_focused {
+            Status::Focused
         } else {
-            theme.active(&self.style)
+            Status::Active
         };
 
+        let appearance = theme.style(&self.class, status).dim(self.opacity);
+
+        let is_round = matches!(appearance, Appearance::Circle(_));
+
         match appearance {
             Appearance::Circle(style) => {
                 let bounds_x = bounds.x.floor();
@@ -493,14 +660,21 @@ where
             }
             Appearance::Invisible => {}
         };
+
+        if state.is_focused && state.focus_visible {
+            if let Some(focus_ring) = theme.focus_ring_appearance(&self.class) {
+                focus_ring::draw(renderer, bounds, &focus_ring.dim(self.opacity), is_round);
+            }
+        }
     }
 }
 
-impl<'a, Message, Theme> From<ModRangeInput<'a, Message, Theme>>
+impl<'a, Message, Theme, Renderer> From<ModRangeInput<'a, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
+    Renderer: 'a + renderer::Renderer,
 {
     fn from(mod_range_input: ModRangeInput<'a, Message, Theme>) -> Self {
         Self::new(mod_range_input)
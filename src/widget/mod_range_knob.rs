@@ -0,0 +1,340 @@
+//! Display a [`Knob`] with a [`ModRangeInput`] next to its rim, laid out as
+//! one widget
+//!
+//! [`Knob`]: ../knob/struct.Knob.html
+//! [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+
+use crate::widget::{
+    knob::{self, Knob},
+    mod_range_input::{self, ModRangeInput},
+};
+use iced::{
+    advanced::{
+        graphics::{core::event, geometry},
+        image, layout, mouse,
+        renderer::{self, Style},
+        text,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Element, Event, Font, Length, Point, Rectangle, Size, Vector,
+};
+
+/// The default angle of the [`ModRangeInput`] around the [`Knob`] of a
+/// [`ModRangeKnob`], straight below it in the gap of the default
+/// [`KnobAngleRange`].
+///
+/// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+/// [`Knob`]: ../knob/struct.Knob.html
+/// [`ModRangeKnob`]: struct.ModRangeKnob.html
+/// [`KnobAngleRange`]: ../core/knob_angle_range/struct.KnobAngleRange.html
+pub const DEFAULT_ANGLE: f32 = 0.0;
+
+/// The default gap between the rim of the [`Knob`] and the
+/// [`ModRangeInput`] of a [`ModRangeKnob`].
+///
+/// [`Knob`]: ../knob/struct.Knob.html
+/// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+/// [`ModRangeKnob`]: struct.ModRangeKnob.html
+pub const DEFAULT_GAP: f32 = 2.0;
+
+/// A [`Knob`] with a [`ModRangeInput`] placed next to its rim.
+///
+/// The [`ModRangeInput`] sits at an [`angle`] around the center of the
+/// [`Knob`], a [`gap`] away from its rim, and the [`ModRangeKnob`] takes up
+/// the space of both. Both keep their own messages and styles.
+///
+/// [`Knob`]: ../knob/struct.Knob.html
+/// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+/// [`angle`]: #method.angle
+/// [`gap`]: #method.gap
+/// [`ModRangeKnob`]: struct.ModRangeKnob.html
+#[allow(missing_debug_implementations)]
+pub struct ModRangeKnob<'a, Message, Theme, Renderer> {
+    knob: Element<'a, Message, Theme, Renderer>,
+    input: Element<'a, Message, Theme, Renderer>,
+    angle: f32,
+    gap: f32,
+}
+
+impl<'a, Message, Theme, Renderer> ModRangeKnob<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + knob::Catalog + mod_range_input::Catalog,
+    Renderer: 'static
+        + image::Renderer<Handle = image::Handle>
+        + text::Renderer<Font = Font>
+        + geometry::Renderer,
+{
+    /// Creates a new [`ModRangeKnob`] from a [`Knob`] and the
+    /// [`ModRangeInput`] to place next to it.
+    ///
+    /// [`ModRangeKnob`]: struct.ModRangeKnob.html
+    /// [`Knob`]: ../knob/struct.Knob.html
+    /// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+    pub fn new(knob: Knob<'a, Message, Theme>, input: ModRangeInput<'a, Message, Theme>) -> Self {
+        Self {
+            knob: knob.into(),
+            input: input.into(),
+            angle: DEFAULT_ANGLE,
+            gap: DEFAULT_GAP,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> ModRangeKnob<'a, Message, Theme, Renderer> {
+    /// Sets the angle (in radians) of the [`ModRangeInput`] around the
+    /// center of the [`Knob`], where `0.0` points straight down and the
+    /// angles rotate clockwise, like a [`KnobAngleRange`].
+    ///
+    /// The default is [`DEFAULT_ANGLE`].
+    ///
+    /// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+    /// [`Knob`]: ../knob/struct.Knob.html
+    /// [`KnobAngleRange`]: ../core/knob_angle_range/struct.KnobAngleRange.html
+    /// [`DEFAULT_ANGLE`]: constant.DEFAULT_ANGLE.html
+    pub fn angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    /// Sets the gap between the rim of the [`Knob`] and the edge of the
+    /// [`ModRangeInput`]. A negative gap moves the [`ModRangeInput`] onto
+    /// the rim.
+    ///
+    /// The default is [`DEFAULT_GAP`].
+    ///
+    /// [`Knob`]: ../knob/struct.Knob.html
+    /// [`ModRangeInput`]: ../mod_range_input/struct.ModRangeInput.html
+    /// [`DEFAULT_GAP`]: constant.DEFAULT_GAP.html
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    fn elements(&self) -> [&Element<'a, Message, Theme, Renderer>; 2] {
+        [&self.knob, &self.input]
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ModRangeKnob<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.elements().into_iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.elements());
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.knob.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let knob = self
+            .knob
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        let input = self
+            .input
+            .as_widget()
+            .layout(&mut tree.children[1], renderer, &limits.loose());
+
+        let knob_bounds = knob.bounds();
+        let input_size = input.size();
+
+        // The input is placed on the line from the center of the knob at
+        // the angle, with its center one input radius past the gap.
+        let distance = knob_bounds.width.min(knob_bounds.height) / 2.0
+            + self.gap
+            + input_size.width.max(input_size.height) / 2.0;
+        let center =
+            knob_bounds.center() + Vector::new(-self.angle.sin(), self.angle.cos()) * distance;
+        let input_bounds = Rectangle::new(
+            center - Vector::new(input_size.width, input_size.height) * 0.5,
+            input_size,
+        );
+
+        let bounds = knob_bounds.union(&input_bounds);
+        let origin = Vector::new(-bounds.x, -bounds.y);
+
+        layout::Node::with_children(
+            bounds.size(),
+            vec![
+                knob.move_to(Point::ORIGIN + origin),
+                input.move_to(input_bounds.position() + origin),
+            ],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.elements()
+                .into_iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let (knob_layout, input_layout) = (
+            children.next().expect("knob layout"),
+            children.next().expect("input layout"),
+        );
+
+        // The input is drawn over the knob, so it gets the event first and
+        // the knob doesn't move with what the input captures.
+        let status = self.input.as_widget_mut().on_event(
+            &mut tree.children[1],
+            event.clone(),
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        self.knob.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            knob_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.elements()
+            .into_iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .elements()
+            .into_iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ModRangeKnob<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(mod_range_knob: ModRangeKnob<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(mod_range_knob)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::fixtures::{changed, param, Message, SIZE};
+    use crate::test_utils::Harness;
+    use crate::{HitShape, Knob, ModRangeInput, ModRangeKnob};
+    use iced::{Length, Point, Size, Theme, Vector};
+
+    #[test]
+    fn mod_range_knob_places_input_at_rim() {
+        let mod_range_knob = |gap| {
+            ModRangeKnob::new(
+                Knob::new(param(0.5, 0.5), Message::Changed).size(Length::Fixed(40.0)),
+                ModRangeInput::new(param(0.5, 0.5), |normal| Message::Turned(normal.as_f32()))
+                    .size(Length::Fixed(10.0))
+                    .hit_shape(HitShape::Circle),
+            )
+            .gap(gap)
+        };
+
+        // Straight below the knob, two pixels away from its rim.
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(mod_range_knob(2.0), SIZE);
+        assert_eq!(harness.bounds().size(), Size::new(40.0, 52.0));
+        let input = Point::new(20.0, 47.0);
+        harness.drag(input, input - Vector::new(0.0, 20.0));
+        let messages = harness.take_messages();
+        assert!(matches!(messages[..], [Message::Turned(turned), ..] if turned > 0.5));
+        assert!(changed(&messages).is_empty());
+
+        // Overlapping the rim, the corners of the round input still reach
+        // the knob.
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(mod_range_knob(-6.0), SIZE);
+        let corner = Point::new(15.5, 34.5);
+        harness.drag(corner, corner - Vector::new(0.0, 20.0));
+        let messages = harness.take_messages();
+        assert!(!changed(&messages).is_empty());
+        assert!(!messages
+            .iter()
+            .any(|message| matches!(message, Message::Turned(_))));
+    }
+}
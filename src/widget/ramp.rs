@@ -3,33 +3,97 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
-use crate::core::{Normal, NormalParam, SliderStatus};
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+#[cfg(feature = "a11y")]
+use crate::a11y;
+use crate::{
+    core::{
+        sensitivity::DEFAULT_PIXELS_PER_STEP, BipolarSide, Change, DoubleClick, DragThreshold,
+        HitShape, ModifierMatch, Normal, NormalParam, Param, SliderSensitivity, ViewWindow,
+    },
+    defaults,
+    operation::{DragState, ValueState},
+    style::Dim,
+    text_marks, tick_marks,
+    widget::{
+        focus_ring,
+        virtual_slider::{Axis, State, VirtualSlider},
+    },
+};
 use iced::{
     advanced::{
         graphics::{
-            core::{event, keyboard, touch},
-            geometry::Renderer as _,
+            cache::{Cached, Group},
+            core::{event, keyboard},
+            geometry,
         },
         layout, mouse,
         renderer::{Quad, Style},
-        widget::{tree, Tree},
-        Clipboard, Layout, Renderer as _, Shell, Widget,
+        text,
+        widget::{tree, Id, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
     },
     border::Radius,
+    time::Duration,
     widget::canvas::{self, Frame, LineCap, Path, Stroke},
-    Border, Element, Event, Length, Point, Rectangle, Renderer, Shadow, Size, Vector,
+    Border, Color, Element, Event, Font, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+
+pub use crate::style::ramp::{
+    Appearance, Catalog, Status, StyleFn, StyleSheet, TextMarksAppearance, TickMarksAppearance,
+    DEFAULT_VALUE_QUANTIZATION,
 };
 
-pub use crate::style::ramp::{Appearance, StyleSheet};
+/// The default width of the [`Ramp`].
+///
+/// [`Ramp`]: struct.Ramp.html
+pub const DEFAULT_WIDTH: f32 = 40.0;
+
+/// The default height of the [`Ramp`].
+///
+/// [`Ramp`]: struct.Ramp.html
+pub const DEFAULT_HEIGHT: f32 = 20.0;
+
+/// The default `scalar` of the [`Ramp`], i.e. how much the value changes per
+/// pixel the mouse is dragged vertically.
+///
+/// Touch screens are smaller and fingers less precise than a mouse, so the
+/// web (`wasm32`) needs less travel to sweep the whole range.
+///
+/// [`Ramp`]: struct.Ramp.html
+pub const DEFAULT_SCALAR: f32 = if cfg!(target_arch = "wasm32") {
+    0.0077
+} else {
+    0.00385
+};
+
+/// The default `wheel_scalar` of the [`Ramp`].
+///
+/// [`Ramp`]: struct.Ramp.html
+pub const DEFAULT_WHEEL_SCALAR: f32 = 0.01;
+
+/// The default `modifier_scalar` of the [`Ramp`].
+///
+/// [`Ramp`]: struct.Ramp.html
+pub const DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 
-static DEFAULT_WIDTH: f32 = 40.0;
-static DEFAULT_HEIGHT: f32 = 20.0;
-static DEFAULT_SCALAR: f32 = 0.00385;
-static DEFAULT_WHEEL_SCALAR: f32 = 0.01;
-static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+/// The default [`SliderSensitivity`] of the [`Ramp`].
+///
+/// [`Ramp`]: struct.Ramp.html
+/// [`SliderSensitivity`]: ../struct.SliderSensitivity.html
+pub const DEFAULT_SENSITIVITY: SliderSensitivity = SliderSensitivity {
+    scalar: DEFAULT_SCALAR,
+    wheel_scalar: DEFAULT_WHEEL_SCALAR,
+    pixels_per_step: DEFAULT_PIXELS_PER_STEP,
+    modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+    modifier_keys: keyboard::Modifiers::CTRL,
+    modifier_match: ModifierMatch::Contains,
+    extra_modifiers: &[],
+};
 
 /// The direction of a [`Ramp`] widget.
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 pub enum RampDirection {
     /// The line points upwards from `bottom-left` to `top-right`.
     #[default]
@@ -46,25 +110,36 @@ pub enum RampDirection {
 #[allow(missing_debug_implementations)]
 pub struct Ramp<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     normal_param: NormalParam,
-    on_change: Box<dyn 'a + Fn(Normal) -> Message>,
+    on_change: Box<dyn 'a + Fn(Change) -> Message>,
     on_grab: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
     on_release: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
-    scalar: f32,
-    wheel_scalar: f32,
-    modifier_scalar: f32,
-    modifier_keys: keyboard::Modifiers,
+    sensitivity: SliderSensitivity,
+    capture_wheel: bool,
+    double_click: DoubleClick,
+    drag_threshold: DragThreshold,
+    wheel_gesture: Option<Duration>,
     width: Length,
     height: Length,
-    style: <Theme as StyleSheet>::Style,
+    id: Option<Id>,
+    #[cfg(feature = "a11y")]
+    name: Option<String>,
+    #[cfg(feature = "a11y")]
+    description: Option<String>,
+    #[cfg(feature = "a11y")]
+    announced_value: Option<Box<dyn 'a + Fn(Normal) -> String>>,
+    class: Theme::Class<'a>,
+    opacity: f32,
     direction: RampDirection,
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
 }
 
 impl<'a, Message, Theme> Ramp<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     /// Creates a new [`Ramp`].
     ///
@@ -80,24 +155,74 @@ where
     /// [`Ramp`]: struct.Ramp.html
     pub fn new<F>(normal_param: NormalParam, on_change: F, direction: RampDirection) -> Self
     where
-        F: 'static + Fn(Normal) -> Message,
+        F: 'a + Fn(Normal) -> Message,
     {
+        let defaults = defaults::get();
+
         Ramp {
             normal_param,
-            on_change: Box::new(on_change),
+            on_change: Box::new(move |change: Change| on_change(change.normal)),
             on_grab: None,
             on_release: None,
-            scalar: DEFAULT_SCALAR,
-            wheel_scalar: DEFAULT_WHEEL_SCALAR,
-            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
-            modifier_keys: keyboard::Modifiers::CTRL,
-            width: Length::Fixed(DEFAULT_WIDTH),
-            height: Length::Fixed(DEFAULT_HEIGHT),
-            style: Default::default(),
+            sensitivity: defaults.ramp_sensitivity,
+            capture_wheel: true,
+            double_click: defaults.double_click,
+            drag_threshold: defaults.drag_threshold,
+            wheel_gesture: defaults.wheel_gesture,
+            width: Length::Fixed(defaults.ramp_width),
+            height: Length::Fixed(defaults.ramp_height),
+            id: None,
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            announced_value: None,
+            class: Theme::default(),
+            opacity: 1.0,
             direction,
+            tick_marks: None,
+            text_marks: None,
         }
     }
 
+    /// Creates a new [`Ramp`] from a [`Param`].
+    ///
+    /// Unlike `Ramp::new()`, `on_change` is called with the (snapped) value of
+    /// the parameter instead of a [`Normal`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Param`]: ../../core/param/trait.Param.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn from_param<P, F>(param: P, on_change: F, direction: RampDirection) -> Self
+    where
+        P: 'a + Param,
+        F: 'a + Fn(P::Value) -> Message,
+    {
+        let normal_param = param.normal_param();
+
+        Self::new(
+            normal_param,
+            move |normal| on_change(param.unmap_to_value(normal)),
+            direction,
+        )
+    }
+
+    /// Sets the message to produce when the [`Ramp`] is moved, replacing
+    /// the `on_change` function given to `Ramp::new()`.
+    ///
+    /// Unlike `on_change`, `on_change_full` receives a [`Change`] with the
+    /// previous value and whether the value was dragged, scrolled or reset.
+    /// This is useful to implement undo, or to treat wheel nudges
+    /// differently from drags.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Change`]: ../../core/struct.Change.html
+    pub fn on_change_full(mut self, on_change_full: impl 'a + Fn(Change) -> Message) -> Self {
+        self.on_change = Box::new(on_change_full);
+        self
+    }
+
     /// Sets the grab message of the [`Ramp`].
     /// This is called when the mouse grabs from the ramp.
     ///
@@ -138,23 +263,130 @@ where
         self
     }
 
-    /// Sets the style of the [`Ramp`].
+    /// Sets the [`Id`] of the [`Ramp`], e.g. to check whether it is being
+    /// dragged with [`operation::is_dragging()`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Id`]: https://docs.rs/iced/0.13/iced/advanced/widget/struct.Id.html
+    /// [`operation::is_dragging()`]: ../../operation/fn.is_dragging.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the name of the [`Ramp`] that screen readers announce, e.g. the
+    /// name of the parameter it controls.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    #[cfg(feature = "a11y")]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the [`Ramp`] that screen readers announce
+    /// after its name.
     ///
     /// [`Ramp`]: struct.Ramp.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    #[cfg(feature = "a11y")]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets how screen readers announce the value of the [`Ramp`], e.g. as
+    /// `"-6 dB"` or `"440 Hz"` for the mapped value of its parameter, instead
+    /// of the raw [`Normal`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    #[cfg(feature = "a11y")]
+    pub fn announced_value(mut self, format: impl 'a + Fn(Normal) -> String) -> Self {
+        self.announced_value = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the [`Ramp`] with a function of the theme and the
+    /// [`Status`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Status`]: ../../style/enum.Status.html
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Ramp`], e.g. a custom [`StyleSheet`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`StyleSheet`]: ../../style/ramp/trait.StyleSheet.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Ramp`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the envelope of a bypassed section. The default is `1.0`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the tick marks to display along the bottom edge of the
+    /// [`Ramp`], from the minimum value on the left to the maximum value on
+    /// the right. Note your [`StyleSheet`] must also implement
+    /// `tick_marks_appearance(&self) -> Option<TickMarksAppearance>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`StyleSheet`]: ../../style/ramp/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display along the bottom edge of the
+    /// [`Ramp`], e.g. the curve amounts `-1`, `0` and `+1`. Note your
+    /// [`StyleSheet`] must also implement
+    /// `text_marks_appearance(&self) -> Option<TextMarksAppearance>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`StyleSheet`]: ../../style/ramp/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    /// Sets the [`SliderSensitivity`] of the [`Ramp`], i.e. its `scalar`,
+    /// `wheel_scalar`, `pixels_per_step`, `modifier_scalar` and `modifier_keys`
+    /// at once.
+    ///
+    /// The default is [`DEFAULT_SENSITIVITY`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`SliderSensitivity`]: ../../core/struct.SliderSensitivity.html
+    /// [`DEFAULT_SENSITIVITY`]: constant.DEFAULT_SENSITIVITY.html
+    pub fn sensitivity(mut self, sensitivity: SliderSensitivity) -> Self {
+        self.sensitivity = sensitivity;
         self
     }
 
     /// Sets how much the [`Normal`] value will change for the [`Ramp`] per `y`
     /// pixel movement of the mouse.
     ///
-    /// The default value is `0.00385`
+    /// The default value is `0.00385`, or `0.0077` on the web (`wasm32`)
     ///
     /// [`Ramp`]: struct.Ramp.html
     /// [`Normal`]: ../../core/struct.Normal.html
     pub fn scalar(mut self, scalar: f32) -> Self {
-        self.scalar = scalar;
+        self.sensitivity.scalar = scalar;
         self
     }
 
@@ -168,7 +400,71 @@ where
     /// [`Ramp`]: struct.Ramp.html
     /// [`Normal`]: ../../core/struct.Normal.html
     pub fn wheel_scalar(mut self, wheel_scalar: f32) -> Self {
-        self.wheel_scalar = wheel_scalar;
+        self.sensitivity.wheel_scalar = wheel_scalar;
+        self
+    }
+
+    /// Sets how many pixels a pixel based scroll delta (e.g. from a
+    /// touchpad) has to add up to before the [`Ramp`] moves by one
+    /// `wheel_scalar` step.
+    ///
+    /// The default value is [`DEFAULT_PIXELS_PER_STEP`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`DEFAULT_PIXELS_PER_STEP`]: ../../core/sensitivity/constant.DEFAULT_PIXELS_PER_STEP.html
+    pub fn pixels_per_step(mut self, pixels_per_step: f32) -> Self {
+        self.sensitivity.pixels_per_step = pixels_per_step;
+        self
+    }
+
+    /// Sets whether the [`Ramp`] captures the wheel events that move it, so a
+    /// scrollable parent doesn't scroll at the same time. With `false`, the
+    /// wheel scrolls the parent instead of moving the [`Ramp`].
+    ///
+    /// Wheel events are always captured while the [`Ramp`] is dragged. The
+    /// default value is `true`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn capture_wheel(mut self, capture_wheel: bool) -> Self {
+        self.capture_wheel = capture_wheel;
+        self
+    }
+
+    /// Sets when two presses of the [`Ramp`] count as a double click, which
+    /// resets it to its default value.
+    ///
+    /// The default is the `double_click` of the [`Defaults`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn double_click(mut self, double_click: DoubleClick) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
+    /// Sets how far the pointer has to move from a press of the [`Ramp`]
+    /// before it drags the value, so that a click or a tap doesn't move it.
+    ///
+    /// The default is the `drag_threshold` of the [`Defaults`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn drag_threshold(mut self, drag_threshold: DragThreshold) -> Self {
+        self.drag_threshold = drag_threshold;
+        self
+    }
+
+    /// Groups consecutive wheel steps of the [`Ramp`] into one gesture, which
+    /// fires `on_grab` at the first step and `on_release` once the wheel is
+    /// idle for `idle_timeout`, e.g. for hosts that create an undo entry per
+    /// gesture. With `None`, every wheel step fires both.
+    ///
+    /// The default is the `wheel_gesture` of the [`Defaults`].
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn wheel_gesture(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.wheel_gesture = idle_timeout;
         self
     }
 
@@ -178,7 +474,7 @@ where
     ///
     /// [`Ramp`]: struct.Ramp.html
     pub fn modifier_keys(mut self, modifier_keys: keyboard::Modifiers) -> Self {
-        self.modifier_keys = modifier_keys;
+        self.sensitivity.modifier_keys = modifier_keys;
         self
     }
 
@@ -194,84 +490,44 @@ where
     ///
     /// [`Ramp`]: struct.Ramp.html
     pub fn modifier_scalar(mut self, scalar: f32) -> Self {
-        self.modifier_scalar = scalar;
+        self.sensitivity.modifier_scalar = scalar;
         self
     }
 
-    fn move_virtual_slider(&mut self, state: &mut State, mut normal_delta: f32) -> SliderStatus {
-        if normal_delta.abs() < f32::EPSILON {
-            return SliderStatus::Unchanged;
-        }
-
-        if state.pressed_modifiers.contains(self.modifier_keys) {
-            normal_delta *= self.modifier_scalar;
-        }
-
-        self.normal_param
-            .value
-            .set_clipped(state.continuous_normal - normal_delta);
-        state.continuous_normal = self.normal_param.value.as_f32();
-
-        SliderStatus::Moved
-    }
-
-    fn maybe_fire_on_grab(&mut self, shell: &mut Shell<'_, Message>) {
-        if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
-            shell.publish(message);
-        }
-    }
-
-    fn fire_on_change(&self, shell: &mut Shell<'_, Message>) {
-        shell.publish((self.on_change)(self.normal_param.value));
-    }
-
-    fn maybe_fire_on_release(&mut self, shell: &mut Shell<'_, Message>) {
-        if let Some(message) = self.on_release.as_mut().and_then(|on_release| on_release()) {
-            shell.publish(message);
+    fn virtual_slider(&mut self) -> VirtualSlider<'_, 'a, Message> {
+        VirtualSlider {
+            normal_param: &mut self.normal_param,
+            sensitivity: self.sensitivity,
+            on_change: &*self.on_change,
+            on_grab: self.on_grab.as_deref_mut(),
+            on_release: self.on_release.as_deref_mut(),
+            steps: None,
+            free_modifier_keys: keyboard::Modifiers::empty(),
+            fine_drag_button: None,
+            double_click: self.double_click,
+            drag_threshold: self.drag_threshold,
+            detents: None,
+            on_type_in: None,
+            view_window: ViewWindow::FULL,
+            inverse: false,
+            on_drop: None,
+            group: None,
+            on_group_change: None,
+            capture_wheel: self.capture_wheel,
+            horizontal_wheel_scalar: 0.0,
+            spring_back: None,
+            wheel_gesture: self.wheel_gesture,
+            hit_shape: HitShape::Rectangle,
         }
     }
 }
 
-/// The local state of a [`Ramp`].
-///
-/// [`Ramp`]: struct.Ramp.html
-#[derive(Debug, Copy, Clone)]
-struct State {
-    dragging_status: Option<SliderStatus>,
-    prev_drag_y: f32,
-    prev_normal: Normal,
-    continuous_normal: f32,
-    pressed_modifiers: keyboard::Modifiers,
-    last_click: Option<mouse::Click>,
-}
-
-impl State {
-    /// Creates a new [`Ramp`] state.
-    ///
-    /// It expects:
-    /// * current [`Normal`] value for the [`Ramp`].
-    ///   A [`Normal`] value of `0.5` represents a straight line,
-    ///   `0.0` is curved downward all the way,
-    ///   and `1.0` is curved upward all the way.
-    ///
-    /// [`Normal`]: ../../core/struct.Normal.html
-    /// [`Ramp`]: struct.Ramp.html
-    fn new(normal: Normal) -> Self {
-        Self {
-            dragging_status: None,
-            prev_drag_y: 0.0,
-            prev_normal: normal,
-            continuous_normal: normal.as_f32(),
-            pressed_modifiers: Default::default(),
-            last_click: None,
-        }
-    }
-}
-
-impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for Ramp<'a, Message, Theme>
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Ramp<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: StyleSheet,
+    Theme: Catalog,
+    Renderer: geometry::Renderer + text::Renderer<Font = Font>,
+    <Renderer::Geometry as Cached>::Cache: 'static,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -297,6 +553,51 @@ where
         layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
     }
 
+    #[cfg_attr(not(feature = "a11y"), allow(unused_variables))]
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = state.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref());
+
+        operation.custom(
+            &mut DragState {
+                status: state.dragging_status,
+            },
+            self.id.as_ref(),
+        );
+
+        operation.custom(
+            &mut ValueState {
+                normal: self.normal_param.value,
+                default: self.normal_param.default,
+            },
+            self.id.as_ref(),
+        );
+
+        #[cfg(feature = "a11y")]
+        operation.custom(
+            &mut a11y::slider_node(
+                self.name.as_deref(),
+                self.description.as_deref(),
+                self.normal_param.value,
+                self.announced_value
+                    .as_ref()
+                    .map(|format| format(self.normal_param.value))
+                    .as_deref(),
+                (self.sensitivity.wheel_scalar > 0.0).then_some(self.sensitivity.wheel_scalar),
+                None,
+                layout.bounds(),
+            ),
+            self.id.as_ref(),
+        );
+    }
+
     fn on_event(
         &mut self,
         state: &mut Tree,
@@ -309,152 +610,10 @@ where
         _viewport: &Rectangle,
     ) -> event::Status {
         let state = state.state.downcast_mut::<State>();
+        let axis = Some(Axis::vertical(self.sensitivity.scalar));
 
-        let is_over = cursor.is_over(layout.bounds());
-
-        // Update state after a discontinuity
-        if state.dragging_status.is_none() && state.prev_normal != self.normal_param.value {
-            state.prev_normal = self.normal_param.value;
-            state.continuous_normal = self.normal_param.value.as_f32();
-        }
-
-        match event {
-            Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if state.dragging_status.is_some() {
-                    let normal_delta = (position.y - state.prev_drag_y) * self.scalar;
-
-                    state.prev_drag_y = position.y;
-
-                    if self.move_virtual_slider(state, normal_delta).was_moved() {
-                        self.fire_on_change(shell);
-
-                        state
-                            .dragging_status
-                            .as_mut()
-                            .expect("dragging_status taken")
-                            .moved();
-                    }
-
-                    return event::Status::Captured;
-                }
-            }
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                if self.wheel_scalar == 0.0 {
-                    return event::Status::Ignored;
-                }
-
-                if is_over {
-                    let lines = match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => y,
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            if y > 0.0 {
-                                1.0
-                            } else if y < 0.0 {
-                                -1.0
-                            } else {
-                                0.0
-                            }
-                        }
-                    };
-
-                    if lines != 0.0 {
-                        let normal_delta = -lines * self.wheel_scalar;
-
-                        if self.move_virtual_slider(state, normal_delta).was_moved() {
-                            if state.dragging_status.is_none() {
-                                self.maybe_fire_on_grab(shell);
-                            }
-
-                            self.fire_on_change(shell);
-
-                            if let Some(slider_status) = state.dragging_status.as_mut() {
-                                // Widget was grabbed => keep it grabbed
-                                slider_status.moved();
-                            } else {
-                                self.maybe_fire_on_release(shell);
-                            }
-                        }
-
-                        return event::Status::Captured;
-                    }
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if is_over {
-                    let cursor_position = cursor.position().unwrap();
-
-                    let click =
-                        mouse::Click::new(cursor_position, mouse::Button::Left, state.last_click);
-
-                    match click.kind() {
-                        mouse::click::Kind::Single => {
-                            self.maybe_fire_on_grab(shell);
-
-                            state.dragging_status = Some(Default::default());
-                            state.prev_drag_y = cursor_position.y;
-                        }
-                        _ => {
-                            // Reset to default
-
-                            let prev_dragging_status = state.dragging_status.take();
-
-                            if self.normal_param.value != self.normal_param.default {
-                                if prev_dragging_status.is_none() {
-                                    self.maybe_fire_on_grab(shell);
-                                }
-
-                                self.normal_param.value = self.normal_param.default;
-
-                                self.fire_on_change(shell);
-
-                                self.maybe_fire_on_release(shell);
-                            } else if prev_dragging_status.is_some() {
-                                self.maybe_fire_on_release(shell);
-                            }
-                        }
-                    }
-
-                    state.last_click = Some(click);
-
-                    return event::Status::Captured;
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                if let Some(slider_status) = state.dragging_status.take() {
-                    if self.on_grab.is_some() || slider_status.was_moved() {
-                        // maybe fire on release if `on_grab` is defined
-                        // so as to terminate the action, regardless of the actual user movement.
-                        self.maybe_fire_on_release(shell);
-                    }
-
-                    return event::Status::Captured;
-                }
-            }
-            Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::KeyReleased { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::ModifiersChanged(modifiers) => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-            },
-            _ => {}
-        }
-
-        event::Status::Ignored
+        self.virtual_slider()
+            .on_event(state, &event, cursor, layout.bounds(), axis, shell)
     }
 
     fn draw(
@@ -471,14 +630,18 @@ where
         let bounds = layout.bounds();
         let is_over = cursor.is_over(layout.bounds());
 
-        let appearance = if state.dragging_status.is_some() {
-            theme.dragging(&self.style)
+        let status = if state.dragging_status.is_some() {
+            Status::Dragged
         } else if is_over {
-            theme.hovered(&self.style)
+            Status::Hovered
+        } else if state.is_focused {
+            Status::Focused
         } else {
-            theme.active(&self.style)
+            Status::Active
         };
 
+        let appearance = theme.style(&self.class, status).dim(self.opacity);
+
         let bounds_x = bounds.x.floor();
         let bounds_y = bounds.y.floor();
 
@@ -509,189 +672,313 @@ where
         let range_width = bounds_width - twice_border_width;
         let range_height = bounds_height - twice_border_width;
 
-        let normal = self.normal_param.value;
-
-        match self.direction {
-            RampDirection::Up => {
-                if normal.as_f32() < 0.449 {
-                    let stroke = Stroke {
-                        width: appearance.line_width,
-                        style: canvas::Style::Solid(appearance.line_down_color),
-                        line_cap: LineCap::Square,
-                        ..Stroke::default()
-                    };
-
-                    let control = Point::new(range_width * (1.0 - (normal.as_f32() * 2.0)), 0.0);
-                    let to = Point::new(range_width, -range_height);
-
-                    let path = Path::new(|p| {
-                        p.move_to(to);
-                        p.quadratic_curve_to(control, Point::ORIGIN)
-                    });
-
-                    let mut frame = Frame::new(renderer, Size::new(range_width, range_height));
-
-                    frame.translate(Vector::new(0.0, range_height));
-
-                    frame.stroke(&path, stroke);
-
-                    renderer.with_translation(
-                        Vector::new(bounds_x + border_width, bounds_y + border_width),
-                        |renderer| {
-                            renderer.draw_geometry(frame.into_geometry());
-                        },
-                    );
-                } else if normal.as_f32() > 0.501 {
-                    let stroke = Stroke {
-                        width: appearance.line_width,
-                        style: canvas::Style::Solid(appearance.line_up_color),
-                        line_cap: LineCap::Square,
-                        ..Stroke::default()
-                    };
-
-                    let control = Point::new(
-                        range_width * (1.0 - ((normal.as_f32() - 0.5) * 2.0)),
-                        -range_height,
-                    );
-                    let to = Point::new(range_width, -range_height);
-
-                    let path = Path::new(|p| {
-                        p.move_to(to);
-                        p.quadratic_curve_to(control, Point::ORIGIN)
-                    });
-
-                    let mut frame = Frame::new(renderer, Size::new(range_width, range_height));
-
-                    frame.translate(Vector::new(0.0, range_height));
-
-                    frame.stroke(&path, stroke);
-
-                    renderer.with_translation(
-                        Vector::new(bounds_x + border_width, bounds_y + border_width),
-                        |renderer| {
-                            renderer.draw_geometry(frame.into_geometry());
-                        },
-                    );
-                } else {
-                    let stroke = Stroke {
-                        width: appearance.line_width,
-                        style: canvas::Style::Solid(appearance.line_center_color),
-                        line_cap: LineCap::Square,
-                        ..Stroke::default()
-                    };
-
-                    let path =
-                        Path::line(Point::new(0.0, 0.0), Point::new(range_width, -range_height));
-
-                    let mut frame = Frame::new(renderer, Size::new(range_width, range_height));
-
-                    frame.translate(Vector::new(0.0, range_height));
-
-                    frame.stroke(&path, stroke);
-
-                    renderer.with_translation(
-                        Vector::new(bounds_x + border_width, bounds_y + border_width),
-                        |renderer| {
-                            renderer.draw_geometry(frame.into_geometry());
-                        },
-                    );
-                }
+        let marks_bounds = Rectangle {
+            x: bounds_x + border_width,
+            y: bounds_y,
+            width: range_width,
+            height: bounds_height,
+        };
+
+        if let Some(tick_marks) = self.tick_marks {
+            if let Some(style) = theme.tick_marks_appearance(&self.class).dim(self.opacity) {
+                tick_marks::draw_horizontal_tick_marks(
+                    renderer,
+                    &marks_bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                );
+            }
+        }
+
+        if let Some(text_marks) = self.text_marks {
+            if let Some(style) = theme.text_marks_appearance(&self.class).dim(self.opacity) {
+                text_marks::draw_horizontal_text_marks(
+                    renderer,
+                    &marks_bounds,
+                    text_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                    &state.text_marks_cache,
+                );
+            }
+        }
+
+        let line = Line {
+            normal: quantized(self.normal_param.value, appearance.value_quantization),
+            size: Size::new(range_width, range_height),
+            direction: self.direction,
+            width: appearance.line_width,
+            colors: [
+                appearance.line_down_color,
+                appearance.line_center_color,
+                appearance.line_up_color,
+            ],
+        };
+
+        let geometry = state
+            .ramp_line
+            .draw::<Renderer>(line, || line.geometry(renderer));
+
+        renderer.with_translation(
+            Vector::new(bounds_x + border_width, bounds_y + border_width),
+            |renderer| {
+                renderer.draw_geometry(geometry);
+            },
+        );
+
+        if state.is_focused && state.focus_visible {
+            if let Some(focus_ring) = theme.focus_ring_appearance(&self.class) {
+                focus_ring::draw(
+                    renderer,
+                    Rectangle {
+                        x: bounds_x,
+                        y: bounds_y,
+                        width: bounds_width,
+                        height: bounds_height,
+                    },
+                    &focus_ring.dim(self.opacity),
+                    false,
+                );
+            }
+        }
+    }
+}
+
+/// Returns `normal` rounded to a multiple of `quantization`, unless it is
+/// `0.0`.
+fn quantized(normal: Normal, quantization: f32) -> Normal {
+    if quantization > 0.0 {
+        Normal::from_clipped((normal.as_f32() / quantization).round() * quantization)
+    } else {
+        normal
+    }
+}
+
+/// Everything that the ramp line is drawn from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Line {
+    normal: Normal,
+    size: Size,
+    direction: RampDirection,
+    width: f32,
+    /// The colors of the line when it bends down, when it is straight, and
+    /// when it bends up.
+    colors: [Color; 3],
+}
+
+impl Line {
+    /// Strokes the line into new geometry.
+    fn geometry<Renderer>(&self, renderer: &Renderer) -> Renderer::Geometry
+    where
+        Renderer: geometry::Renderer,
+    {
+        let Size {
+            width: range_width,
+            height: range_height,
+        } = self.size;
+        let normal = self.normal.as_f32();
+        let side = self.normal.classify_bipolar(Normal::CENTER);
+
+        let path = match (self.direction, side) {
+            (RampDirection::Up, BipolarSide::Left) => {
+                let control = Point::new(range_width * (1.0 - (normal * 2.0)), 0.0);
+                let to = Point::new(range_width, -range_height);
+
+                Path::new(|p| {
+                    p.move_to(to);
+                    p.quadratic_curve_to(control, Point::ORIGIN)
+                })
+            }
+            (RampDirection::Up, BipolarSide::Right) => {
+                let control =
+                    Point::new(range_width * (1.0 - ((normal - 0.5) * 2.0)), -range_height);
+                let to = Point::new(range_width, -range_height);
+
+                Path::new(|p| {
+                    p.move_to(to);
+                    p.quadratic_curve_to(control, Point::ORIGIN)
+                })
+            }
+            (RampDirection::Up, BipolarSide::Center) => {
+                Path::line(Point::new(0.0, 0.0), Point::new(range_width, -range_height))
+            }
+            (RampDirection::Down, BipolarSide::Left) => {
+                let control = Point::new(range_width * (normal * 2.0), 0.0);
+                let from = Point::new(0.0, -range_height);
+                let to = Point::new(range_width, 0.0);
+
+                Path::new(|p| {
+                    p.move_to(from);
+                    p.quadratic_curve_to(control, to)
+                })
             }
-            RampDirection::Down => {
-                if normal.as_f32() < 0.449 {
-                    let stroke = Stroke {
-                        width: appearance.line_width,
-                        style: canvas::Style::Solid(appearance.line_down_color),
-                        line_cap: LineCap::Square,
-                        ..Stroke::default()
-                    };
-
-                    let control = Point::new(range_width * (normal.as_f32() * 2.0), 0.0);
-                    let from = Point::new(0.0, -range_height);
-                    let to = Point::new(range_width, 0.0);
-
-                    let path = Path::new(|p| {
-                        p.move_to(from);
-                        p.quadratic_curve_to(control, to)
-                    });
-
-                    let mut frame = Frame::new(renderer, Size::new(range_width, range_height));
-
-                    frame.translate(Vector::new(0.0, range_height));
-
-                    frame.stroke(&path, stroke);
-
-                    renderer.with_translation(
-                        Vector::new(bounds_x + border_width, bounds_y + border_width),
-                        |renderer| {
-                            renderer.draw_geometry(frame.into_geometry());
-                        },
-                    );
-                } else if normal.as_f32() > 0.501 {
-                    let stroke = Stroke {
-                        width: appearance.line_width,
-                        style: canvas::Style::Solid(appearance.line_up_color),
-                        line_cap: LineCap::Square,
-                        ..Stroke::default()
-                    };
-
-                    let control =
-                        Point::new(range_width * ((normal.as_f32() - 0.5) * 2.0), -range_height);
-                    let from = Point::new(0.0, -range_height);
-                    let to = Point::new(range_width, 0.0);
-
-                    let path = Path::new(|p| {
-                        p.move_to(to);
-                        p.quadratic_curve_to(control, from)
-                    });
-
-                    let mut frame = Frame::new(renderer, Size::new(range_width, range_height));
-
-                    frame.translate(Vector::new(0.0, range_height));
-
-                    frame.stroke(&path, stroke);
-
-                    renderer.with_translation(
-                        Vector::new(bounds_x + border_width, bounds_y + border_width),
-                        |renderer| {
-                            renderer.draw_geometry(frame.into_geometry());
-                        },
-                    );
-                } else {
-                    let stroke = Stroke {
-                        width: appearance.line_width,
-                        style: canvas::Style::Solid(appearance.line_center_color),
-                        line_cap: LineCap::Square,
-                        ..Stroke::default()
-                    };
-
-                    let path =
-                        Path::line(Point::new(0.0, -range_height), Point::new(range_width, 0.0));
-
-                    let mut frame = Frame::new(renderer, Size::new(range_width, range_height));
-
-                    frame.translate(Vector::new(0.0, range_height));
-
-                    frame.stroke(&path, stroke);
-
-                    renderer.with_translation(
-                        Vector::new(bounds_x + border_width, bounds_y + border_width),
-                        |renderer| {
-                            renderer.draw_geometry(frame.into_geometry());
-                        },
-                    );
+            (RampDirection::Down, BipolarSide::Right) => {
+                let control = Point::new(range_width * ((normal - 0.5) * 2.0), -range_height);
+                let from = Point::new(0.0, -range_height);
+                let to = Point::new(range_width, 0.0);
+
+                Path::new(|p| {
+                    p.move_to(to);
+                    p.quadratic_curve_to(control, from)
+                })
+            }
+            (RampDirection::Down, BipolarSide::Center) => {
+                Path::line(Point::new(0.0, -range_height), Point::new(range_width, 0.0))
+            }
+        };
+
+        let color = match side {
+            BipolarSide::Left => self.colors[0],
+            BipolarSide::Center => self.colors[1],
+            BipolarSide::Right => self.colors[2],
+        };
+
+        let stroke = Stroke {
+            width: self.width,
+            style: canvas::Style::Solid(color),
+            line_cap: LineCap::Square,
+            ..Stroke::default()
+        };
+
+        let mut frame = Frame::new(renderer, self.size);
+
+        frame.translate(Vector::new(0.0, range_height));
+
+        frame.stroke(&path, stroke);
+
+        frame.into_geometry()
+    }
+}
+
+/// The geometry of the ramp line that was drawn last, so that it is not
+/// stroked again until the [`Line`] changes.
+///
+/// The geometry depends on the renderer, so it is stored without its type.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LineCache {
+    cached: RefCell<Option<CachedLine>>,
+}
+
+/// A [`Line`] with its geometry.
+#[derive(Debug, Clone)]
+struct CachedLine {
+    line: Line,
+    group: Group,
+    geometry: Rc<dyn Any>,
+}
+
+impl LineCache {
+    /// Returns the geometry of `line`, which is drawn with `draw` unless it
+    /// is cached.
+    fn draw<Renderer>(
+        &self,
+        line: Line,
+        draw: impl FnOnce() -> Renderer::Geometry,
+    ) -> Renderer::Geometry
+    where
+        Renderer: geometry::Renderer,
+        <Renderer::Geometry as Cached>::Cache: 'static,
+    {
+        let mut cached = self.cached.borrow_mut();
+
+        if let Some(cached) = cached.as_ref() {
+            if cached.line == line {
+                if let Some(geometry) = cached.geometry.downcast_ref() {
+                    return Cached::load(geometry);
                 }
             }
+        }
+
+        // The previous geometry lets the renderer reuse its storage.
+        let (group, previous) = match cached.take() {
+            Some(cached) => (cached.group, cached.geometry.downcast_ref().cloned()),
+            None => (Group::unique(), None),
         };
+
+        let geometry = draw().cache(group, previous);
+        let loaded = Cached::load(&geometry);
+
+        *cached = Some(CachedLine {
+            line,
+            group,
+            geometry: Rc::new(geometry),
+        });
+
+        loaded
     }
 }
 
-impl<'a, Message, Theme> From<Ramp<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer> From<Ramp<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
+    Renderer: 'a + geometry::Renderer + text::Renderer<Font = Font>,
+    <Renderer::Geometry as Cached>::Cache: 'static,
 {
     fn from(ramp: Ramp<'a, Message, Theme>) -> Self {
         Self::new(ramp)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{quantized, Line, LineCache, RampDirection};
+    use crate::test_utils::fixtures::{param, Message, SIZE};
+    use crate::test_utils::Harness;
+    use crate::Normal;
+    use crate::{tick_marks, Ramp};
+    use iced::{widget::container, Padding, Theme};
+    use iced::{Color, Font, Pixels, Size};
+    use iced_tiny_skia::Renderer;
+
+    #[test]
+    fn line_is_stroked_when_it_changes() {
+        let renderer = Renderer::new(Font::DEFAULT, Pixels(16.0));
+        let cache = LineCache::default();
+        let mut stroked = 0;
+
+        let mut draw = |value: f32, width: f32| {
+            let line = Line {
+                normal: quantized(Normal::from_clipped(value), 0.01),
+                size: Size::new(width, 20.0),
+                direction: RampDirection::Up,
+                width: 2.0,
+                colors: [Color::BLACK; 3],
+            };
+
+            let _ = cache.draw::<Renderer>(line, || {
+                stroked += 1;
+                line.geometry(&renderer)
+            });
+        };
+
+        draw(0.3, 40.0);
+        draw(0.3, 40.0);
+        draw(0.301, 40.0);
+        draw(0.31, 40.0);
+        draw(0.31, 50.0);
+
+        assert_eq!(stroked, 3);
+    }
+
+    #[test]
+    fn ramp_marks() {
+        let tick_marks = tick_marks::Group::center(tick_marks::Tier::One);
+        let ramp =
+            Ramp::new(param(0.5, 0.5), Message::Changed, RampDirection::Up).tick_marks(&tick_marks);
+        let ramp = container(ramp).padding(Padding {
+            bottom: 10.0,
+            ..Padding::ZERO
+        });
+        let snapshot = Harness::<'_, Message, Theme>::new(ramp, SIZE).snapshot(&Theme::Light);
+
+        // The tick mark is drawn below the center of the bottom edge.
+        let alpha = |x| snapshot.pixel(x, 22).map_or(0.0, |color| color.a);
+        assert!(alpha(20) > 0.0);
+        assert_eq!(alpha(10), 0.0);
+    }
+}
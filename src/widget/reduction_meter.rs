@@ -0,0 +1,577 @@
+//! Display a gain reduction meter, e.g. for a compressor: a bar that grows
+//! down from 0 dB, with an optional history graph.
+//!
+//! The meter smooths the value it is given with its [`Ballistics`] on every
+//! frame, so the application can simply pass the latest reduction from the
+//! audio thread.
+//!
+//! [`Ballistics`]: struct.Ballistics.html
+
+use std::collections::VecDeque;
+
+use crate::core::Normal;
+use crate::defaults;
+use crate::style::Dim;
+use iced::{
+    advanced::{
+        graphics::{core::event, geometry},
+        layout, mouse,
+        renderer::{self, Quad, Style},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    border::Radius,
+    time::{Duration, Instant},
+    widget::canvas::{self, Fill, Frame, Path},
+    window, Border, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+
+pub use crate::style::reduction_meter::{Appearance, Catalog, StyleFn, StyleSheet};
+
+/// The default width of a [`ReductionMeter`] without a history.
+///
+/// [`ReductionMeter`]: struct.ReductionMeter.html
+pub const DEFAULT_WIDTH: f32 = 10.0;
+
+/// The default [`Ballistics`] of a [`ReductionMeter`].
+///
+/// [`Ballistics`]: struct.Ballistics.html
+/// [`ReductionMeter`]: struct.ReductionMeter.html
+pub const DEFAULT_BALLISTICS: Ballistics = Ballistics {
+    attack: Duration::from_millis(10),
+    release: Duration::from_millis(300),
+};
+
+/// The smallest difference between the displayed and the actual reduction
+/// that is still animated.
+const SETTLE_DISTANCE: f32 = 0.0001;
+
+/// How fast the displayed reduction of a [`ReductionMeter`] follows the
+/// actual reduction.
+///
+/// [`ReductionMeter`]: struct.ReductionMeter.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ballistics {
+    /// The time it takes the bar to grow about two thirds of the way to a
+    /// larger reduction. `Duration::ZERO` follows it immediately.
+    pub attack: Duration,
+    /// The time it takes the bar to shrink about two thirds of the way to a
+    /// smaller reduction. `Duration::ZERO` follows it immediately.
+    pub release: Duration,
+}
+
+impl Ballistics {
+    /// Ballistics that show the reduction without any smoothing.
+    pub const NONE: Self = Self {
+        attack: Duration::ZERO,
+        release: Duration::ZERO,
+    };
+
+    /// Returns the displayed value after moving it from `displayed` toward
+    /// `target` for `elapsed` time.
+    pub fn step(&self, displayed: f32, target: f32, elapsed: Duration) -> f32 {
+        let time = if target > displayed {
+            self.attack
+        } else {
+            self.release
+        };
+
+        if time.is_zero() || (target - displayed).abs() < SETTLE_DISTANCE {
+            return target;
+        }
+
+        let amount = 1.0 - (-elapsed.as_secs_f32() / time.as_secs_f32()).exp();
+        let next = displayed + (target - displayed) * amount;
+
+        if (target - next).abs() < SETTLE_DISTANCE {
+            target
+        } else {
+            next
+        }
+    }
+}
+
+impl Default for Ballistics {
+    fn default() -> Self {
+        DEFAULT_BALLISTICS
+    }
+}
+
+/// A gain reduction meter: a bar that grows down from the top as the
+/// reduction increases, with an optional history graph to the left of it.
+///
+/// It is not interactive and produces no messages.
+#[allow(missing_debug_implementations)]
+pub struct ReductionMeter<'a, Theme>
+where
+    Theme: Catalog,
+{
+    reduction: Normal,
+    ballistics: Ballistics,
+    reduced_motion: bool,
+    history: Option<Duration>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    opacity: f32,
+}
+
+impl<'a, Theme> ReductionMeter<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`ReductionMeter`] showing the `reduction`.
+    ///
+    /// `Normal::MIN` is no reduction (0 dB), and `Normal::MAX` the largest
+    /// reduction the meter shows. Map decibels to a [`Normal`] with a range,
+    /// e.g. `FloatRange::new(0.0, 24.0).map_to_normal(reduction_db)`.
+    ///
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn new(reduction: Normal) -> Self {
+        ReductionMeter {
+            reduction,
+            ballistics: DEFAULT_BALLISTICS,
+            reduced_motion: defaults::get().reduced_motion,
+            history: None,
+            width: Length::Fixed(DEFAULT_WIDTH),
+            height: Length::Fill,
+            class: Theme::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets how fast the displayed reduction of the [`ReductionMeter`]
+    /// follows the actual reduction.
+    ///
+    /// The default is [`DEFAULT_BALLISTICS`].
+    ///
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    /// [`DEFAULT_BALLISTICS`]: constant.DEFAULT_BALLISTICS.html
+    pub fn ballistics(mut self, ballistics: Ballistics) -> Self {
+        self.ballistics = ballistics;
+        self
+    }
+
+    /// Skips the [`Ballistics`] of the [`ReductionMeter`], so that the bar
+    /// shows the reduction it is given without smoothing. The history graph
+    /// still scrolls, as it shows the reduction over time.
+    ///
+    /// The default is the `reduced_motion` of the [`Defaults`].
+    ///
+    /// [`Ballistics`]: struct.Ballistics.html
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    /// Shows the displayed reduction of the last `length` of time as a graph
+    /// to the left of the bar, with the newest values on the right.
+    ///
+    /// The graph fills the width of the [`ReductionMeter`] that is not taken
+    /// by the bar, so give it a wider `width` as well.
+    ///
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    pub fn history(mut self, length: Duration) -> Self {
+        self.history = Some(length);
+        self
+    }
+
+    /// Sets the width of the [`ReductionMeter`].
+    ///
+    /// The default width is [`DEFAULT_WIDTH`].
+    ///
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    /// [`DEFAULT_WIDTH`]: constant.DEFAULT_WIDTH.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ReductionMeter`].
+    ///
+    /// The default height is `Length::Fill`.
+    ///
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ReductionMeter`].
+    ///
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    pub fn style(mut self, style: impl Fn(&Theme) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`ReductionMeter`].
+    ///
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`ReductionMeter`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the meter of a bypassed compressor. The default is `1.0`.
+    ///
+    /// [`ReductionMeter`]: struct.ReductionMeter.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// The local state of a [`ReductionMeter`].
+///
+/// [`ReductionMeter`]: struct.ReductionMeter.html
+#[derive(Debug, Clone)]
+struct State {
+    displayed: f32,
+    last_frame: Option<Instant>,
+    is_animating: bool,
+    history: VecDeque<(Instant, f32)>,
+}
+
+impl State {
+    /// Moves the displayed reduction toward `target` at the frame drawn at
+    /// `now`, returning whether the meter still changes on the next frame.
+    fn advance(
+        &mut self,
+        now: Instant,
+        target: f32,
+        ballistics: &Ballistics,
+        history: Option<Duration>,
+    ) -> bool {
+        // A meter that was settled starts moving from this frame on, instead
+        // of catching up on all the time it did not move.
+        let elapsed = match self.last_frame {
+            Some(last_frame) if self.is_animating => now.saturating_duration_since(last_frame),
+            _ => Duration::ZERO,
+        };
+
+        self.last_frame = Some(now);
+        self.displayed = ballistics.step(self.displayed, target, elapsed);
+
+        match history {
+            Some(length) => {
+                self.history.push_back((now, self.displayed));
+
+                // Keep one value older than `length` to draw up to the left
+                // edge of the graph.
+                while self.history.len() > 2
+                    && now.saturating_duration_since(self.history[1].0) > length
+                {
+                    let _ = self.history.pop_front();
+                }
+            }
+            None => self.history.clear(),
+        }
+
+        self.is_animating =
+            self.displayed != target || self.history.iter().any(|&(_, value)| value != target);
+
+        self.is_animating
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ReductionMeter<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer + geometry::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            displayed: self.reduction.as_f32(),
+            last_frame: None,
+            is_animating: false,
+            history: VecDeque::new(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = state.state.downcast_mut::<State>();
+
+            let ballistics = if self.reduced_motion {
+                &Ballistics::NONE
+            } else {
+                &self.ballistics
+            };
+
+            if state.advance(now, self.reduction.as_f32(), ballistics, self.history) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = theme.style(&self.class).dim(self.opacity);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.border_color,
+                    width: appearance.border_width,
+                    radius: Radius::new(appearance.border_radius),
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.back_color,
+        );
+
+        let inner = bounds.shrink(appearance.border_width);
+
+        let bar = if let Some(length) = self.history {
+            let bar_width = appearance.bar_width.min(inner.width);
+
+            let history_bounds = Rectangle {
+                width: (inner.width - bar_width - appearance.history_spacing).max(0.0),
+                ..inner
+            };
+
+            history(renderer, state, length, history_bounds, &appearance);
+
+            Rectangle {
+                x: inner.x + inner.width - bar_width,
+                width: bar_width,
+                ..inner
+            }
+        } else {
+            inner
+        };
+
+        let bar_height = (state.displayed * bar.height).round();
+
+        if bar_height > 0.0 {
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        height: bar_height,
+                        ..bar
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                appearance.bar_color,
+            );
+        }
+    }
+}
+
+/// Draws the last `length` of the history of the displayed reduction as a
+/// graph filled down from the top of `bounds`.
+fn history<Renderer>(
+    renderer: &mut Renderer,
+    state: &State,
+    length: Duration,
+    bounds: Rectangle,
+    appearance: &Appearance,
+) where
+    Renderer: geometry::Renderer,
+{
+    let Some(now) = state.last_frame else {
+        return;
+    };
+
+    if state.history.is_empty() || length.is_zero() || bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return;
+    }
+
+    let point = |time: Instant, value: f32| {
+        let age = now.saturating_duration_since(time).as_secs_f32() / length.as_secs_f32();
+
+        Point::new((bounds.width * (1.0 - age)).max(0.0), value * bounds.height)
+    };
+
+    let path = Path::new(|path| {
+        path.move_to(Point::new(bounds.width, 0.0));
+
+        for &(time, value) in state.history.iter().rev() {
+            path.line_to(point(time, value));
+        }
+
+        if let Some(&(time, _)) = state.history.front() {
+            path.line_to(Point::new(point(time, 0.0).x, 0.0));
+        }
+
+        path.close();
+    });
+
+    let mut frame = Frame::new(renderer, bounds.size());
+
+    frame.fill(
+        &path,
+        Fill {
+            style: canvas::Style::Solid(appearance.history_color),
+            ..Fill::default()
+        },
+    );
+
+    renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+        renderer.draw_geometry(frame.into_geometry());
+    });
+}
+
+impl<'a, Message, Theme, Renderer> From<ReductionMeter<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: 'a + Catalog,
+    Renderer: 'a + renderer::Renderer + geometry::Renderer,
+{
+    fn from(reduction_meter: ReductionMeter<'a, Theme>) -> Self {
+        Self::new(reduction_meter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ballistics, Duration};
+    use crate::reduction_meter;
+    use crate::test_utils::fixtures::{reduction_meter, Message, BLUE, LIGHT, ORANGE, SIZE};
+    use crate::test_utils::Harness;
+    use iced::{time::Instant, window, Color, Length, Theme};
+
+    #[test]
+    fn ballistics_attack_and_release() {
+        let ballistics = Ballistics {
+            attack: Duration::from_millis(10),
+            release: Duration::from_millis(100),
+        };
+
+        // After one time constant, about two thirds of the way is covered.
+        let attacked = ballistics.step(0.0, 1.0, Duration::from_millis(10));
+        assert!((attacked - 0.632).abs() < 0.001);
+
+        let released = ballistics.step(1.0, 0.0, Duration::from_millis(10));
+        assert!((released - 0.905).abs() < 0.001);
+
+        // Close enough snaps to the target, so that the meter settles.
+        assert_eq!(ballistics.step(0.99995, 1.0, Duration::ZERO), 1.0);
+        assert_eq!(Ballistics::NONE.step(0.0, 0.5, Duration::ZERO), 0.5);
+    }
+
+    #[test]
+    fn reduction_meter_ballistics() {
+        let start = Instant::now();
+        let after = |millis| start + Duration::from_millis(millis);
+        let bar_length = |harness: &mut Harness<'_, Message, Theme>| {
+            let snapshot = harness.snapshot(&Theme::Light);
+            (0..100)
+                .take_while(|&y| {
+                    snapshot.pixel(5, y).map(Color::into_rgba8) == Some(BLUE.into_rgba8())
+                })
+                .count()
+        };
+
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(reduction_meter(0.0), SIZE);
+        assert_eq!(harness.redraw(start), None);
+
+        // The bar starts growing on the next frame after the reduction rises...
+        harness.rebuild(
+            reduction_meter(1.0).ballistics(reduction_meter::Ballistics {
+                attack: Duration::from_millis(10),
+                release: Duration::from_millis(100),
+            }),
+        );
+        assert_eq!(
+            harness.redraw(after(1000)),
+            Some(window::RedrawRequest::NextFrame)
+        );
+        assert_eq!(bar_length(&mut harness), 0);
+
+        let _ = harness.redraw(after(1010));
+        assert_eq!(bar_length(&mut harness), 63);
+
+        // ...and stops asking for frames once it is there.
+        let _ = harness.redraw(after(1100));
+        assert_eq!(harness.redraw(after(1200)), None);
+        assert_eq!(bar_length(&mut harness), 100);
+    }
+
+    #[test]
+    fn reduction_meter_history() {
+        let start = Instant::now();
+        let meter = |reduction| {
+            reduction_meter(reduction)
+                .ballistics(reduction_meter::Ballistics::NONE)
+                .history(Duration::from_secs(1))
+                .width(Length::Fixed(110.0))
+        };
+
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(meter(0.5), SIZE);
+        let _ = harness.redraw(start);
+        harness.rebuild(meter(0.0));
+
+        // The graph keeps scrolling while it shows a past reduction.
+        assert_eq!(
+            harness.redraw(start + Duration::from_millis(500)),
+            Some(window::RedrawRequest::NextFrame)
+        );
+
+        let snapshot = harness.snapshot(&Theme::Light);
+        let pixel = |x, y| snapshot.pixel(x, y).map(Color::into_rgba8);
+
+        // Half a second ago the reduction was at half the height, in the
+        // middle of the graph, and it has been falling to none since then.
+        assert_eq!(pixel(60, 10), Some(ORANGE.into_rgba8()));
+        assert_eq!(pixel(60, 45), Some(LIGHT.into_rgba8()));
+        assert_eq!(pixel(40, 10), Some(LIGHT.into_rgba8()));
+
+        // The bar on the right shows no reduction.
+        assert_eq!(pixel(105, 10), Some(LIGHT.into_rgba8()));
+    }
+}
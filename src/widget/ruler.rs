@@ -0,0 +1,461 @@
+//! Display a scale for a [`Range`] with tick marks, text marks and a unit
+//! caption, e.g. to line up a spectrogram or a meter with its values.
+//!
+//! [`Range`]: ../trait.Range.html
+
+use crate::{
+    core::{text_marks, tick_marks, Normal, Range},
+    widget::marks::Orientation,
+};
+use iced::{
+    advanced::{
+        layout, mouse, renderer, text,
+        widget::{tree, Tree},
+        Layout, Text, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    widget::text::{LineHeight, Shaping, Wrapping},
+    Element, Font, Length, Pixels, Point, Rectangle, Size,
+};
+
+/// The number of divisions of a [`Ruler`] that is not given any values.
+///
+/// [`Ruler`]: struct.Ruler.html
+pub const DEFAULT_DIVISIONS: usize = 10;
+
+/// The minimum distance in pixels between two tick marks of a [`Ruler`].
+/// Tick marks that are closer are left out.
+///
+/// [`Ruler`]: struct.Ruler.html
+pub const MIN_TICK_SPACING: f32 = 3.0;
+
+/// A scale for a [`Range`], with a tick mark and a label for each value.
+///
+/// The tick marks are drawn along the top or left edge, with the labels below
+/// or to the right of them. When there is not enough space, labels that would
+/// overlap are left out and their tick marks become smaller.
+///
+/// [`Range`]: ../trait.Range.html
+#[allow(missing_debug_implementations)]
+pub struct Ruler<'a, R: Range> {
+    range: &'a R,
+    orientation: Orientation,
+    marks: Vec<(Normal, String)>,
+    unit: Option<String>,
+    width: Length,
+    height: Length,
+    tick_style: tick_marks::Appearance,
+    text_style: text_marks::Appearance,
+}
+
+impl<'a, R: Range> Ruler<'a, R> {
+    /// Creates a new [`Ruler`] with [`DEFAULT_DIVISIONS`] evenly spaced
+    /// divisions of the `range`.
+    ///
+    /// [`Ruler`]: struct.Ruler.html
+    /// [`DEFAULT_DIVISIONS`]: constant.DEFAULT_DIVISIONS.html
+    pub fn new(range: &'a R, orientation: Orientation) -> Self {
+        let (width, height) = match orientation {
+            Orientation::Horizontal => (Length::Fill, Length::Shrink),
+            Orientation::Vertical => (Length::Shrink, Length::Fill),
+        };
+
+        Ruler {
+            range,
+            orientation,
+            marks: Vec::new(),
+            unit: None,
+            width,
+            height,
+            tick_style: tick_marks::Appearance::default(),
+            text_style: text_marks::Appearance::default(),
+        }
+        .divisions(DEFAULT_DIVISIONS)
+    }
+
+    /// Marks `divisions + 1` evenly spaced positions, including both ends of
+    /// the range.
+    pub fn divisions(mut self, divisions: usize) -> Self {
+        let divisions = divisions.max(1);
+
+        self.marks = (0..=divisions)
+            .map(|i| self.mark(Normal::from_clipped(i as f32 / divisions as f32)))
+            .collect();
+        self
+    }
+
+    /// Marks the given values of the range instead of evenly spaced
+    /// divisions.
+    pub fn values(mut self, values: impl IntoIterator<Item = R::Value>) -> Self {
+        self.marks = values
+            .into_iter()
+            .map(|value| self.mark(self.range.map_to_normal(value)))
+            .collect();
+        self.marks
+            .sort_by(|a, b| a.0.as_f32().total_cmp(&b.0.as_f32()));
+        self
+    }
+
+    /// Sets the unit caption, drawn at the maximum end of the labels, e.g.
+    /// `"Hz"` or `"dB"`.
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Sets the width of the [`Ruler`].
+    ///
+    /// [`Ruler`]: struct.Ruler.html
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Ruler`].
+    ///
+    /// [`Ruler`]: struct.Ruler.html
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Appearance`] of the tick marks. Labeled values use the
+    /// `tier_1` shape, the others the `tier_2` shape.
+    ///
+    /// [`Appearance`]: ../tick_marks/struct.Appearance.html
+    pub fn tick_style(mut self, style: tick_marks::Appearance) -> Self {
+        self.tick_style = style;
+        self
+    }
+
+    /// Sets the [`Appearance`] of the labels and the unit caption.
+    ///
+    /// [`Appearance`]: ../text_marks/struct.Appearance.html
+    pub fn text_style(mut self, style: text_marks::Appearance) -> Self {
+        self.text_style = style;
+        self
+    }
+
+    fn mark(&self, normal: Normal) -> (Normal, String) {
+        let value = self.range.unmap_to_value(normal);
+
+        (normal, self.range.format(&value))
+    }
+
+    fn tick_length(&self) -> f32 {
+        [self.tick_style.tier_1, self.tick_style.tier_2]
+            .into_iter()
+            .map(|shape| match shape {
+                tick_marks::Shape::None => 0.0,
+                tick_marks::Shape::Line { length, .. } => length,
+                tick_marks::Shape::Circle { diameter, .. } => diameter,
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// The space a label takes along the ruler.
+    fn label_extent(&self) -> f32 {
+        match self.orientation {
+            Orientation::Horizontal => f32::from(self.text_style.bounds_width),
+            Orientation::Vertical => f32::from(self.text_style.bounds_height),
+        }
+    }
+}
+
+impl<'a, R, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Ruler<'a, R>
+where
+    R: Range,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<text_marks::Cache>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(text_marks::Cache::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let tick_length = self.tick_length();
+
+        let intrinsic_size = match self.orientation {
+            Orientation::Horizontal => {
+                Size::new(0.0, tick_length + f32::from(self.text_style.bounds_height))
+            }
+            Orientation::Vertical => {
+                Size::new(tick_length + f32::from(self.text_style.bounds_width), 0.0)
+            }
+        };
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic_size))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let cache = tree.state.downcast_ref::<text_marks::Cache>();
+        let bounds = layout.bounds();
+        let tick_length = self.tick_length();
+
+        let (length, tick_bounds) = match self.orientation {
+            Orientation::Horizontal => (
+                bounds.width,
+                Rectangle {
+                    height: tick_length,
+                    ..bounds
+                },
+            ),
+            Orientation::Vertical => (
+                bounds.height,
+                Rectangle {
+                    width: tick_length,
+                    ..bounds
+                },
+            ),
+        };
+
+        let reserved_end = if self.unit.is_some() {
+            self.label_extent()
+        } else {
+            0.0
+        };
+
+        let visible = thin_out(&self.marks, length, self.label_extent(), reserved_end);
+
+        let tick_marks: tick_marks::Group = visible
+            .iter()
+            .filter_map(|visibility| match visibility {
+                Visibility::Labeled(normal) => Some((*normal, tick_marks::Tier::One)),
+                Visibility::Tick(normal) => Some((*normal, tick_marks::Tier::Two)),
+                Visibility::Hidden => None,
+            })
+            .collect::<Vec<_>>()
+            .into();
+
+        let text_marks: text_marks::Group = self
+            .marks
+            .iter()
+            .zip(&visible)
+            .filter(|(_, visibility)| matches!(visibility, Visibility::Labeled(_)))
+            .map(|((normal, label), _)| (*normal, label.clone()))
+            .collect::<Vec<_>>()
+            .into();
+
+        let tick_placement = tick_marks::Placement::Center {
+            offset: Default::default(),
+            fill_length: false,
+        };
+        let text_placement = text_marks::Placement::RightOrBottom {
+            inside: false,
+            offset: Default::default(),
+        };
+
+        match self.orientation {
+            Orientation::Horizontal => {
+                tick_marks::draw_horizontal_tick_marks(
+                    renderer,
+                    &tick_bounds,
+                    &tick_marks,
+                    &self.tick_style,
+                    &tick_placement,
+                    false,
+                );
+                text_marks::draw_horizontal_text_marks(
+                    renderer,
+                    &tick_bounds,
+                    &text_marks,
+                    &self.text_style,
+                    &text_placement,
+                    false,
+                    cache,
+                );
+            }
+            Orientation::Vertical => {
+                tick_marks::draw_vertical_tick_marks(
+                    renderer,
+                    &tick_bounds,
+                    &tick_marks,
+                    &self.tick_style,
+                    &tick_placement,
+                    false,
+                );
+                text_marks::draw_vertical_text_marks(
+                    renderer,
+                    &tick_bounds,
+                    &text_marks,
+                    &self.text_style,
+                    &text_placement,
+                    false,
+                    cache,
+                );
+            }
+        }
+
+        if let Some(unit) = &self.unit {
+            let (position, horizontal_alignment) = match self.orientation {
+                Orientation::Horizontal => (
+                    Point::new(bounds.x + bounds.width, bounds.y + tick_length),
+                    Horizontal::Right,
+                ),
+                Orientation::Vertical => (
+                    Point::new(bounds.x + tick_length, bounds.y),
+                    Horizontal::Left,
+                ),
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: unit.clone(),
+                    size: Pixels(f32::from(self.text_style.text_size)),
+                    bounds: Size::new(
+                        f32::from(self.text_style.bounds_width),
+                        f32::from(self.text_style.bounds_height),
+                    ),
+                    horizontal_alignment,
+                    vertical_alignment: Vertical::Top,
+                    line_height: LineHeight::default(),
+                    wrapping: Wrapping::default(),
+                    shaping: Shaping::Basic,
+                    font: self.text_style.font,
+                },
+                position,
+                self.text_style.color,
+                bounds,
+            );
+        }
+    }
+}
+
+impl<'a, R, Message, Theme, Renderer> From<Ruler<'a, R>> for Element<'a, Message, Theme, Renderer>
+where
+    R: Range,
+    Renderer: 'a + text::Renderer<Font = Font>,
+{
+    fn from(ruler: Ruler<'a, R>) -> Self {
+        Self::new(ruler)
+    }
+}
+
+/// How a mark of a [`Ruler`] is drawn.
+///
+/// [`Ruler`]: struct.Ruler.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Visibility {
+    Labeled(Normal),
+    Tick(Normal),
+    Hidden,
+}
+
+/// Decides which of the sorted `marks` get a label and which a tick mark on
+/// a ruler that is `length` pixels long, keeping labels `extent` pixels
+/// apart and out of the last `reserved_end` pixels.
+fn thin_out(
+    marks: &[(Normal, String)],
+    length: f32,
+    extent: f32,
+    reserved_end: f32,
+) -> Vec<Visibility> {
+    let mut label_end = f32::NEG_INFINITY;
+    let mut last_tick = f32::NEG_INFINITY;
+
+    marks
+        .iter()
+        .map(|(normal, _)| {
+            let position = normal.scale(length);
+
+            if position - extent / 2.0 >= label_end
+                && (reserved_end == 0.0 || position + extent / 2.0 <= length - reserved_end)
+            {
+                label_end = position + extent / 2.0;
+                last_tick = position;
+
+                Visibility::Labeled(*normal)
+            } else if position - last_tick >= MIN_TICK_SPACING {
+                last_tick = position;
+
+                Visibility::Tick(*normal)
+            } else {
+                Visibility::Hidden
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{thin_out, Visibility};
+    use crate::test_utils::fixtures::{Message, SIZE};
+    use crate::test_utils::Harness;
+    use crate::Normal;
+    use crate::{marks, FloatRange, Ruler};
+    use iced::{Size, Theme};
+
+    fn marks(count: usize) -> Vec<(Normal, String)> {
+        (0..=count)
+            .map(|i| (Normal::from_clipped(i as f32 / count as f32), i.to_string()))
+            .collect()
+    }
+
+    fn labeled(visibility: &[Visibility]) -> usize {
+        visibility
+            .iter()
+            .filter(|visibility| matches!(visibility, Visibility::Labeled(_)))
+            .count()
+    }
+
+    #[test]
+    fn thin_out_keeps_labels_apart() {
+        // 11 labels 30 pixels wide fit on 400 pixels, but only every other
+        // one fits on 200 pixels.
+        assert_eq!(labeled(&thin_out(&marks(10), 400.0, 30.0, 0.0)), 11);
+        assert_eq!(labeled(&thin_out(&marks(10), 200.0, 30.0, 0.0)), 6);
+
+        // The other marks still get a tick, until they are too close.
+        let short = thin_out(&marks(10), 20.0, 30.0, 0.0);
+        assert_eq!(labeled(&short), 1);
+        assert!(short.contains(&Visibility::Tick(Normal::from_clipped(0.2))));
+        assert!(short.contains(&Visibility::Hidden));
+    }
+
+    #[test]
+    fn thin_out_leaves_room_for_the_unit() {
+        let visibility = thin_out(&marks(10), 400.0, 30.0, 30.0);
+
+        assert_eq!(visibility[10], Visibility::Tick(Normal::MAX));
+        assert_eq!(visibility[0], Visibility::Labeled(Normal::MIN));
+    }
+
+    #[test]
+    fn ruler_layout() {
+        let range = FloatRange::new(-12.0, 12.0);
+
+        let horizontal = Ruler::new(&range, marks::Orientation::Horizontal).unit("dB");
+        let mut harness = Harness::<'_, Message, Theme>::new(horizontal, SIZE);
+        // The tier 1 tick marks and a row of labels.
+        assert_eq!(harness.bounds().size(), Size::new(SIZE.width, 4.0 + 14.0));
+        let _ = harness.snapshot(&Theme::Light);
+
+        let vertical = Ruler::new(&range, marks::Orientation::Vertical).divisions(4);
+        let harness = Harness::<'_, Message, Theme>::new(vertical, SIZE);
+        assert_eq!(harness.bounds().size(), Size::new(4.0 + 30.0, SIZE.height));
+    }
+}
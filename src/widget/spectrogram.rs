@@ -0,0 +1,542 @@
+//! Display a spectrogram: the magnitudes of the frequencies of a signal over
+//! time, as a color-mapped image.
+//!
+//! The application pushes the magnitudes of each analysis frame, e.g. the
+//! bins of an FFT mapped to a [`Normal`], as a column into a
+//! [`SpectrogramData`], and the [`Spectrogram`] scrolls the columns in from
+//! the right. The whole spectrogram is drawn as a single image. Only the
+//! columns pushed since the last frame are colored, but the image is still
+//! copied and handed to the renderer in full whenever one is pushed, as
+//! iced can't update part of an image.
+//!
+//! [`Spectrogram`]: struct.Spectrogram.html
+//! [`SpectrogramData`]: struct.SpectrogramData.html
+//! [`Normal`]: ../../core/struct.Normal.html
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    ops::Range,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::core::{FreqRange, Normal};
+use crate::style::Dim;
+use iced::{
+    advanced::{
+        image, layout, mouse,
+        renderer::{self, Quad, Style},
+        widget::{tree, Tree},
+        Layout, Widget,
+    },
+    Border, Element, Length, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::spectrogram::{Appearance, Catalog, Colormap, StyleFn, StyleSheet};
+
+/// The default height of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+pub const DEFAULT_HEIGHT: f32 = 120.0;
+
+/// Returns a new id for a [`SpectrogramData`], so that a [`Spectrogram`]
+/// notices when it is replaced or cleared.
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+/// [`SpectrogramData`]: struct.SpectrogramData.html
+fn next_id() -> u64 {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// The columns shown by a [`Spectrogram`], oldest first.
+///
+/// Each column holds the magnitudes of `bins` frequencies evenly spaced from
+/// 0 Hz to the Nyquist frequency, like the bins of a real FFT. Once there
+/// are `capacity` columns, pushing a new one drops the oldest.
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug)]
+pub struct SpectrogramData {
+    id: u64,
+    capacity: usize,
+    bins: usize,
+    nyquist: f32,
+    columns: VecDeque<Box<[Normal]>>,
+    pushed: u64,
+}
+
+impl SpectrogramData {
+    /// Creates an empty [`SpectrogramData`] that keeps the last `capacity`
+    /// columns of `bins` magnitudes, from 0 Hz to `nyquist` Hz (half the
+    /// sample rate).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `capacity` or `bins` is `0`, or if `nyquist` is not
+    /// positive.
+    ///
+    /// [`SpectrogramData`]: struct.SpectrogramData.html
+    pub fn new(capacity: usize, bins: usize, nyquist: f32) -> Self {
+        assert!(capacity > 0 && bins > 0 && nyquist > 0.0);
+
+        Self {
+            id: next_id(),
+            capacity,
+            bins,
+            nyquist,
+            columns: VecDeque::with_capacity(capacity),
+            pushed: 0,
+        }
+    }
+
+    /// Pushes the `magnitudes` of the bins of a new column, from the lowest
+    /// frequency up. Missing bins are silent and extra bins are ignored.
+    pub fn push(&mut self, magnitudes: &[Normal]) {
+        let mut column = vec![Normal::MIN; self.bins].into_boxed_slice();
+        let len = magnitudes.len().min(self.bins);
+        column[..len].copy_from_slice(&magnitudes[..len]);
+
+        if self.columns.len() == self.capacity {
+            let _ = self.columns.pop_front();
+        }
+
+        self.columns.push_back(column);
+        self.pushed += 1;
+    }
+
+    /// Removes all the columns.
+    pub fn clear(&mut self) {
+        self.columns.clear();
+        self.id = next_id();
+        self.pushed = 0;
+    }
+
+    /// Returns the number of columns.
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns `true` if there are no columns.
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Returns the maximum number of columns.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of bins in each column.
+    pub fn bins(&self) -> usize {
+        self.bins
+    }
+
+    /// Returns the frequency of the highest bin in Hz.
+    pub fn nyquist(&self) -> f32 {
+        self.nyquist
+    }
+
+    /// Returns the bins of each of `height` rows, from the top, where the
+    /// rows split the `freq_range` evenly on a log scale. The rows above
+    /// the highest bin are empty.
+    fn rows(&self, freq_range: &FreqRange, height: usize) -> Vec<Range<usize>> {
+        let last = (self.bins - 1) as f32;
+        let bin = |y: usize| {
+            let normal = Normal::from_clipped(1.0 - y as f32 / height as f32);
+            (freq_range.unmap_to_value(normal) / self.nyquist * last).round() as usize
+        };
+
+        (0..height)
+            .map(|y| {
+                let (low, high) = (bin(y + 1), bin(y));
+                low.min(self.bins)..(high + 1).min(self.bins)
+            })
+            .collect()
+    }
+}
+
+/// A spectrogram: the columns of a [`SpectrogramData`] side by side, the
+/// newest on the right, with the frequencies of a [`FreqRange`] on a log
+/// scale from the bottom to the top, colored by the [`Colormap`] of its
+/// style.
+///
+/// It is not interactive and produces no messages.
+///
+/// [`SpectrogramData`]: struct.SpectrogramData.html
+/// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+/// [`Colormap`]: enum.Colormap.html
+#[allow(missing_debug_implementations)]
+pub struct Spectrogram<'a, Theme>
+where
+    Theme: Catalog,
+{
+    data: &'a SpectrogramData,
+    freq_range: FreqRange,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    opacity: f32,
+}
+
+impl<'a, Theme> Spectrogram<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Spectrogram`] showing the columns of `data`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn new(data: &'a SpectrogramData) -> Self {
+        Spectrogram {
+            data,
+            freq_range: FreqRange::default(),
+            width: Length::Fill,
+            height: Length::Fixed(DEFAULT_HEIGHT),
+            class: Theme::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets the frequencies from the bottom to the top of the
+    /// [`Spectrogram`].
+    ///
+    /// The default is `FreqRange::default()`, from 20 Hz to 20 kHz.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn freq_range(mut self, freq_range: FreqRange) -> Self {
+        self.freq_range = freq_range;
+        self
+    }
+
+    /// Sets the width of the [`Spectrogram`].
+    ///
+    /// The default width is `Length::Fill`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Spectrogram`].
+    ///
+    /// The default height is [`DEFAULT_HEIGHT`].
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    /// [`DEFAULT_HEIGHT`]: constant.DEFAULT_HEIGHT.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn style(mut self, style: impl Fn(&Theme) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Spectrogram`].
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Spectrogram`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim a frozen analyzer. The default is `1.0`.
+    ///
+    /// [`Spectrogram`]: struct.Spectrogram.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// What the pixels of an [`Image`] were colored for. All of them are colored
+/// again when it changes.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone, PartialEq)]
+struct ImageKey {
+    data_id: u64,
+    height: usize,
+    freq_range: (f32, f32),
+    colormap: Colormap,
+}
+
+/// The image of a [`Spectrogram`], one pixel per column and per row.
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug, Default)]
+struct Image {
+    key: Option<ImageKey>,
+    pushed: u64,
+    rows: Vec<Range<usize>>,
+    colors: Vec<[u8; 4]>,
+    pixels: Vec<u8>,
+    handle: Option<image::Handle>,
+}
+
+impl Image {
+    /// Colors the columns of `data` pushed since the last update, after
+    /// scrolling the others to the left, and returns a new handle to a copy
+    /// of the whole image, or the previous one if nothing was pushed.
+    fn update(
+        &mut self,
+        data: &SpectrogramData,
+        key: ImageKey,
+        freq_range: &FreqRange,
+    ) -> image::Handle {
+        let width = data.capacity;
+
+        if self.key.as_ref() != Some(&key) {
+            self.rows = data.rows(freq_range, key.height);
+            self.colors = (0..=u8::MAX)
+                .map(|i| {
+                    let magnitude = Normal::from_clipped(f32::from(i) / f32::from(u8::MAX));
+                    key.colormap.color(magnitude).into_rgba8()
+                })
+                .collect();
+            self.pixels = vec![0; width * key.height * 4];
+            self.pushed = 0;
+            self.handle = None;
+            self.key = Some(key);
+        }
+
+        let new = (data.pushed - self.pushed).min(data.len() as u64) as usize;
+
+        if let Some(handle) = self.handle.as_ref().filter(|_| new == 0) {
+            return handle.clone();
+        }
+
+        let stride = width * 4;
+
+        for row in self.pixels.chunks_exact_mut(stride) {
+            row.copy_within(new * 4.., 0);
+        }
+
+        for (i, column) in data.columns.iter().skip(data.len() - new).enumerate() {
+            let x = (width - new + i) * 4;
+
+            for (y, bins) in self.rows.iter().enumerate() {
+                let pixel = if bins.is_empty() {
+                    [0; 4]
+                } else {
+                    let magnitude = column[bins.clone()]
+                        .iter()
+                        .map(Normal::as_f32)
+                        .fold(0.0, f32::max);
+
+                    self.colors[(magnitude * f32::from(u8::MAX)).round() as usize]
+                };
+
+                self.pixels[y * stride + x..][..4].copy_from_slice(&pixel);
+            }
+        }
+
+        self.pushed = data.pushed;
+
+        let handle =
+            image::Handle::from_rgba(width as u32, self.rows.len() as u32, self.pixels.clone());
+        self.handle = Some(handle.clone());
+
+        handle
+    }
+}
+
+/// The local state of a [`Spectrogram`].
+///
+/// [`Spectrogram`]: struct.Spectrogram.html
+#[derive(Debug, Default)]
+struct State {
+    image: RefCell<Image>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Spectrogram<'a, Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer + image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let appearance = theme.style(&self.class).dim(self.opacity);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    color: appearance.border_color,
+                    width: appearance.border_width,
+                    radius: 0.0.into(),
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.back_color,
+        );
+
+        let inner = bounds.shrink(appearance.border_width);
+        let height = inner.height.round() as usize;
+
+        if inner.width < 1.0 || height == 0 {
+            return;
+        }
+
+        let key = ImageKey {
+            data_id: self.data.id,
+            height,
+            freq_range: (
+                self.freq_range.unmap_to_value(Normal::MIN),
+                self.freq_range.unmap_to_value(Normal::MAX),
+            ),
+            colormap: appearance.colormap,
+        };
+
+        let handle = state
+            .image
+            .borrow_mut()
+            .update(self.data, key, &self.freq_range);
+
+        // Each column stays a sharp band, rather than blurring into the next.
+        renderer.draw_image(
+            image::Image::new(handle)
+                .filter_method(image::FilterMethod::Nearest)
+                .opacity(self.opacity),
+            inner,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spectrogram<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: 'a + Catalog,
+    Renderer: 'a + renderer::Renderer + image::Renderer<Handle = image::Handle>,
+{
+    fn from(spectrogram: Spectrogram<'a, Theme>) -> Self {
+        Self::new(spectrogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FreqRange, Image, ImageKey, Normal, SpectrogramData};
+    use crate::spectrogram::{self, Spectrogram};
+    use crate::test_utils::fixtures::{Message, BLUE, GRAY, LIGHT, ORANGE, SIZE};
+    use crate::test_utils::{Harness, Snapshot};
+    use iced::{Color, Length, Theme};
+
+    #[test]
+    fn only_new_columns_are_colored() {
+        let mut data = SpectrogramData::new(4, 2, 20_480.0);
+        let mut image = Image::default();
+        let key = ImageKey {
+            data_id: data.id,
+            height: 1,
+            freq_range: (20.0, 20_480.0),
+            colormap: super::Colormap::Grayscale,
+        };
+        // A single row covers both bins, so it shows the louder one.
+        let range = FreqRange::new(20.0, 20_480.0);
+        let gray = |pixels: &[u8]| pixels.chunks(4).map(|p| p[0]).collect::<Vec<_>>();
+
+        data.push(&[Normal::MAX, Normal::MIN]);
+        let _ = image.update(&data, key.clone(), &range);
+        assert_eq!(gray(&image.pixels), [0, 0, 0, 255]);
+        assert_eq!(image.pixels[..4], [0; 4]);
+
+        data.push(&[Normal::MIN, Normal::from_clipped(0.5)]);
+        let _ = image.update(&data, key.clone(), &range);
+        assert_eq!(gray(&image.pixels), [0, 0, 255, 128]);
+
+        // A new column scrolls the others to the left, and the oldest ones
+        // drop off once the data is full.
+        for _ in 0..3 {
+            data.push(&[Normal::MIN]);
+        }
+        let _ = image.update(&data, key, &range);
+        assert_eq!(gray(&image.pixels), [128, 0, 0, 0]);
+        assert_eq!(image.pixels[3], 255);
+    }
+
+    #[test]
+    fn spectrogram_scrolls_in_columns() {
+        let mut first = SpectrogramData::new(4, 2, 20_480.0);
+        let mut second = SpectrogramData::new(4, 2, 20_480.0);
+        let spectrogram = |data| {
+            Spectrogram::new(data)
+                .freq_range(FreqRange::new(20.0, 20_480.0))
+                .width(Length::Fixed(40.0))
+                .height(Length::Fixed(20.0))
+                .style(|_| spectrogram::Appearance {
+                    colormap: spectrogram::Colormap::Gradient(vec![BLUE, ORANGE]),
+                    back_color: LIGHT,
+                    border_width: 0.0,
+                    border_color: GRAY,
+                })
+        };
+
+        // The upper bin covers the top octave of the 20 pixels high log
+        // scale, so the two top rows.
+        for data in [&mut first, &mut second] {
+            data.push(&[Normal::MIN, Normal::MAX]);
+        }
+        second.push(&[Normal::MAX, Normal::MIN]);
+
+        let mut harness: Harness<'_, Message, Theme> = Harness::new(spectrogram(&first), SIZE);
+        let snapshot = harness.snapshot(&Theme::Light);
+        let pixel = |snapshot: &Snapshot, x, y| snapshot.pixel(x, y).map(Color::into_rgba8);
+        assert_eq!(pixel(&snapshot, 35, 0), Some(ORANGE.into_rgba8()));
+        assert_eq!(pixel(&snapshot, 35, 15), Some(BLUE.into_rgba8()));
+        assert_eq!(pixel(&snapshot, 5, 15), Some(LIGHT.into_rgba8()));
+
+        // Each new column scrolls the older ones to the left.
+        harness.rebuild(spectrogram(&second));
+        let snapshot = harness.snapshot(&Theme::Light);
+        assert_eq!(pixel(&snapshot, 35, 15), Some(ORANGE.into_rgba8()));
+        assert_eq!(pixel(&snapshot, 25, 0), Some(ORANGE.into_rgba8()));
+        assert_eq!(pixel(&snapshot, 25, 15), Some(BLUE.into_rgba8()));
+    }
+}
@@ -0,0 +1,889 @@
+//! Display a linked pair of vertical sliders that control the left and right
+//! channels of a stereo parameter
+//!
+//! [`NormalParam`]: ../core/normal_param/struct.Param.html
+
+use std::cell::Cell;
+
+use crate::{
+    core::{
+        Change, ChangeSource, DoubleClick, DragThreshold, HitShape, Normal, NormalParam,
+        SliderSensitivity, ViewWindow,
+    },
+    defaults,
+    style::{stereo_slider, Dim},
+    tick_marks,
+    widget::{
+        v_slider::{self, draw, value_markers::ValueMarkers},
+        virtual_slider::{Axis, State as ChannelState, VirtualSlider},
+    },
+};
+use iced::{
+    advanced::{
+        graphics::core::{event, keyboard},
+        image, layout, mouse,
+        renderer::{self, Quad},
+        text::{self, LineHeight, Shaping, Text, Wrapping},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    border::{Border, Radius},
+    time::Duration,
+    touch, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::stereo_slider::{Appearance, Catalog, Status, StyleFn, StyleSheet};
+
+/// The default gap between the two sliders of a [`StereoSlider`], which
+/// also holds its link toggle.
+///
+/// [`StereoSlider`]: struct.StereoSlider.html
+pub const DEFAULT_SPACING: f32 = 16.0;
+
+/// The height of the row below the sliders of a [`StereoSlider`] that holds
+/// the channel labels and the link toggle.
+///
+/// [`StereoSlider`]: struct.StereoSlider.html
+pub const FOOTER_HEIGHT: f32 = 16.0;
+
+/// The width and height of the link toggle of a [`StereoSlider`].
+///
+/// [`StereoSlider`]: struct.StereoSlider.html
+pub const LINK_SIZE: f32 = 10.0;
+
+/// What the two sliders of a [`StereoSlider`] control.
+///
+/// [`StereoSlider`]: struct.StereoSlider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StereoMode {
+    /// The sliders control the left and the right channel.
+    #[default]
+    LeftRight,
+    /// The first slider controls the mid, the average of both channels, and
+    /// the second the side, which is at its center while both channels are
+    /// equal and moves the left channel up and the right channel down.
+    MidSide,
+}
+
+impl StereoMode {
+    /// The labels drawn below the two sliders.
+    fn labels(self) -> [&'static str; 2] {
+        match self {
+            StereoMode::LeftRight => ["L", "R"],
+            StereoMode::MidSide => ["M", "S"],
+        }
+    }
+
+    /// Converts the left and right values into the values of the two sliders.
+    fn shown(self, left: Normal, right: Normal) -> [Normal; 2] {
+        match self {
+            StereoMode::LeftRight => [left, right],
+            StereoMode::MidSide => {
+                let (left, right) = (left.as_f32(), right.as_f32());
+
+                [
+                    Normal::from_clipped((left + right) / 2.0),
+                    Normal::from_clipped(0.5 + (left - right) / 2.0),
+                ]
+            }
+        }
+    }
+
+    /// Converts the values of the two sliders back into the left and right
+    /// values.
+    fn left_right(self, shown: [Normal; 2]) -> (Normal, Normal) {
+        match self {
+            StereoMode::LeftRight => (shown[0], shown[1]),
+            StereoMode::MidSide => {
+                let mid = shown[0].as_f32();
+                let side = shown[1].as_f32() - 0.5;
+
+                (
+                    Normal::from_clipped(mid + side),
+                    Normal::from_clipped(mid - side),
+                )
+            }
+        }
+    }
+}
+
+/// The local state of a [`StereoSlider`], shared by both of its sliders.
+///
+/// [`StereoSlider`]: struct.StereoSlider.html
+#[derive(Debug, Clone)]
+struct State {
+    channels: [ChannelState; 2],
+}
+
+/// A pair of vertical sliders that control the left and the right channel of
+/// a stereo parameter, e.g. the volume of a channel strip.
+///
+/// While the channels are [`linked`], dragging or scrolling either slider
+/// moves both by the same amount, and resetting one resets both. In
+/// [`StereoMode::MidSide`], the sliders control the mid and the side instead,
+/// but `on_change` is still called with the left and right values.
+///
+/// The sliders are drawn with the [`Catalog`] of a [`VSlider`].
+///
+/// [`linked`]: #method.linked
+/// [`StereoMode::MidSide`]: enum.StereoMode.html#variant.MidSide
+/// [`Catalog`]: ../v_slider/trait.Catalog.html
+/// [`VSlider`]: ../v_slider/struct.VSlider.html
+#[allow(missing_debug_implementations)]
+pub struct StereoSlider<'a, Message, Theme>
+where
+    Theme: v_slider::Catalog + stereo_slider::Catalog,
+{
+    left: NormalParam,
+    right: NormalParam,
+    on_change: Box<dyn 'a + Fn(Normal, Normal) -> Message>,
+    on_grab: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
+    on_release: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
+    on_link_toggle: Option<Box<dyn 'a + Fn(bool) -> Message>>,
+    linked: bool,
+    mode: StereoMode,
+    sensitivity: SliderSensitivity,
+    capture_wheel: bool,
+    double_click: DoubleClick,
+    drag_threshold: DragThreshold,
+    wheel_gesture: Option<Duration>,
+    slider_width: f32,
+    spacing: f32,
+    height: Length,
+    class: <Theme as v_slider::Catalog>::Class<'a>,
+    footer_class: <Theme as stereo_slider::Catalog>::Class<'a>,
+    opacity: f32,
+    scale_factor: f32,
+    tick_marks: Option<&'a tick_marks::Group>,
+}
+
+impl<'a, Message, Theme> StereoSlider<'a, Message, Theme>
+where
+    Theme: v_slider::Catalog + stereo_slider::Catalog,
+{
+    /// Creates a new [`StereoSlider`].
+    ///
+    /// It expects:
+    ///   * the [`NormalParam`] of the left channel
+    ///   * the [`NormalParam`] of the right channel
+    ///   * a function that will be called with the new left and right values
+    ///     when either slider is moved.
+    ///
+    /// [`NormalParam`]: struct.NormalParam.html
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    pub fn new<F>(left: NormalParam, right: NormalParam, on_change: F) -> Self
+    where
+        F: 'a + Fn(Normal, Normal) -> Message,
+    {
+        let defaults = defaults::get();
+
+        StereoSlider {
+            left,
+            right,
+            on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            on_link_toggle: None,
+            linked: false,
+            mode: StereoMode::default(),
+            sensitivity: defaults.v_slider_sensitivity,
+            capture_wheel: true,
+            double_click: defaults.double_click,
+            drag_threshold: defaults.drag_threshold,
+            wheel_gesture: defaults.wheel_gesture,
+            slider_width: defaults.v_slider_width,
+            spacing: DEFAULT_SPACING,
+            height: Length::Fill,
+            class: <Theme as v_slider::Catalog>::default(),
+            footer_class: <Theme as stereo_slider::Catalog>::default(),
+            opacity: 1.0,
+            scale_factor: 1.0,
+            tick_marks: None,
+        }
+    }
+
+    /// Links the two channels, so that moving either slider moves both.
+    ///
+    /// The channels are not linked by default.
+    pub fn linked(mut self, linked: bool) -> Self {
+        self.linked = linked;
+        self
+    }
+
+    /// Shows a link toggle between the sliders, which produces the message
+    /// of `on_link_toggle` with the new link state when clicked.
+    ///
+    /// Without it, no toggle is drawn and the link can only be set with
+    /// [`linked`].
+    ///
+    /// [`linked`]: #method.linked
+    pub fn on_link_toggle(mut self, on_link_toggle: impl 'a + Fn(bool) -> Message) -> Self {
+        self.on_link_toggle = Some(Box::new(on_link_toggle));
+        self
+    }
+
+    /// Sets what the two sliders control. The default is
+    /// [`StereoMode::LeftRight`].
+    ///
+    /// [`StereoMode::LeftRight`]: enum.StereoMode.html#variant.LeftRight
+    pub fn mode(mut self, mode: StereoMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the grab message of the [`StereoSlider`].
+    /// This is called when the mouse grabs either slider.
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    pub fn on_grab(mut self, on_grab: impl 'a + FnMut() -> Option<Message>) -> Self {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets the release message of the [`StereoSlider`].
+    /// This is called when the mouse is released from either slider.
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    pub fn on_release(mut self, on_release: impl 'a + FnMut() -> Option<Message>) -> Self {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets the width of each of the two sliders. The default is the width
+    /// of a [`VSlider`].
+    ///
+    /// [`VSlider`]: ../v_slider/struct.VSlider.html
+    pub fn slider_width(mut self, slider_width: f32) -> Self {
+        self.slider_width = slider_width;
+        self
+    }
+
+    /// Sets the gap between the two sliders, which also holds the link
+    /// toggle. The default is [`DEFAULT_SPACING`].
+    ///
+    /// [`DEFAULT_SPACING`]: constant.DEFAULT_SPACING.html
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the height of the [`StereoSlider`], including the labels below
+    /// the sliders. The default height is `Length::Fill`.
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the scalar to use when the user drags a slider while holding
+    /// down the modifier key.
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    pub fn modifier_scalar(mut self, scalar: f32) -> Self {
+        self.sensitivity.modifier_scalar = scalar;
+        self
+    }
+
+    /// Sets whether the [`StereoSlider`] captures the wheel events that move
+    /// it, so a scrollable parent doesn't scroll at the same time. With
+    /// `false`, the wheel scrolls the parent instead of moving the
+    /// [`StereoSlider`].
+    ///
+    /// Wheel events are always captured while the [`StereoSlider`] is dragged.
+    /// The default value is `true`.
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    pub fn capture_wheel(mut self, capture_wheel: bool) -> Self {
+        self.capture_wheel = capture_wheel;
+        self
+    }
+
+    /// Sets when two presses of the [`StereoSlider`] count as a double click, which
+    /// resets it to its default value.
+    ///
+    /// The default is the `double_click` of the [`Defaults`].
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn double_click(mut self, double_click: DoubleClick) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
+    /// Sets how far the pointer has to move from a press of the [`StereoSlider`]
+    /// before it drags the value, so that a click or a tap doesn't move it.
+    ///
+    /// The default is the `drag_threshold` of the [`Defaults`].
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn drag_threshold(mut self, drag_threshold: DragThreshold) -> Self {
+        self.drag_threshold = drag_threshold;
+        self
+    }
+
+    /// Groups consecutive wheel steps of the [`StereoSlider`] into one gesture, which
+    /// fires `on_grab` at the first step and `on_release` once the wheel is
+    /// idle for `idle_timeout`, e.g. for hosts that create an undo entry per
+    /// gesture. With `None`, every wheel step fires both.
+    ///
+    /// The default is the `wheel_gesture` of the [`Defaults`].
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn wheel_gesture(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.wheel_gesture = idle_timeout;
+        self
+    }
+
+    /// Sets the tick marks to display next to both sliders.
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the style of the sliders of the [`StereoSlider`].
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> v_slider::Appearance + 'a) -> Self
+    where
+        <Theme as v_slider::Catalog>::Class<'a>: From<v_slider::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as v_slider::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the sliders of the [`StereoSlider`].
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as v_slider::Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style of the labels and the link toggle of the
+    /// [`StereoSlider`].
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    #[must_use]
+    pub fn footer_style(mut self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        <Theme as stereo_slider::Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.footer_class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the labels and the link toggle of the
+    /// [`StereoSlider`].
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    #[must_use]
+    pub fn footer_class(
+        mut self,
+        class: impl Into<<Theme as stereo_slider::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.footer_class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`StereoSlider`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of the colors and textures of both sliders and
+    /// of the footer, e.g. to dim the channel strip of a muted track. The
+    /// default is `1.0`.
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    #[must_use]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the scale factor of the window the [`StereoSlider`] is drawn in, which
+    /// picks the version of the [`TextureHandle`]s of its style to draw for both sliders. The
+    /// default is `1.0`.
+    ///
+    /// Values that aren't positive are ignored.
+    ///
+    /// [`StereoSlider`]: struct.StereoSlider.html
+    /// [`TextureHandle`]: ../../style/texture/struct.TextureHandle.html
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        if scale_factor > 0.0 {
+            self.scale_factor = scale_factor;
+        }
+        self
+    }
+
+    /// The [`NormalParam`]s of the two sliders in the current mode.
+    ///
+    /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+    fn shown_params(&self) -> [NormalParam; 2] {
+        let values = self.mode.shown(self.left.value, self.right.value);
+        let defaults = self.mode.shown(self.left.default, self.right.default);
+
+        [0, 1].map(|channel| NormalParam {
+            value: values[channel],
+            default: defaults[channel],
+        })
+    }
+
+    /// The bounds of the two sliders and of the footer below them.
+    fn split(&self, bounds: Rectangle) -> ([Rectangle; 2], Rectangle) {
+        let slider_height = (bounds.height - FOOTER_HEIGHT).max(0.0);
+
+        let left = Rectangle {
+            x: bounds.x,
+            y: bounds.y,
+            width: self.slider_width,
+            height: slider_height,
+        };
+        let right = Rectangle {
+            x: bounds.x + self.slider_width + self.spacing,
+            ..left
+        };
+        let footer = Rectangle {
+            y: bounds.y + slider_height,
+            height: bounds.height - slider_height,
+            ..bounds
+        };
+
+        ([left, right], footer)
+    }
+
+    /// The bounds of the link toggle, centered in the gap between the
+    /// sliders, if there is one.
+    fn link_bounds(&self, footer: Rectangle) -> Option<Rectangle> {
+        self.on_link_toggle.as_ref().map(|_| Rectangle {
+            x: (footer.x + self.slider_width + (self.spacing - LINK_SIZE) / 2.0).round(),
+            y: (footer.y + (footer.height - LINK_SIZE) / 2.0).round(),
+            width: LINK_SIZE,
+            height: LINK_SIZE,
+        })
+    }
+}
+
+/// Returns the values of both sliders after `channel` was moved by `change`,
+/// moving the other one along if they are `linked`.
+fn linked_values(
+    params: &[NormalParam; 2],
+    channel: usize,
+    change: Change,
+    linked: bool,
+) -> [Normal; 2] {
+    let other = 1 - channel;
+
+    let mut values = [params[0].value, params[1].value];
+    values[channel] = change.normal;
+
+    if linked {
+        values[other] = match change.via {
+            ChangeSource::Reset => params[other].default,
+            _ => Normal::from_clipped(
+                values[other].as_f32() + change.normal.as_f32() - change.previous.as_f32(),
+            ),
+        };
+    }
+
+    values
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for StereoSlider<'a, Message, Theme>
+where
+    Theme: v_slider::Catalog + stereo_slider::Catalog,
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let [left, right] = self.shown_params();
+
+        tree::State::new(State {
+            channels: [
+                ChannelState::new(left.value),
+                ChannelState::new(right.value),
+            ],
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(2.0 * self.slider_width + self.spacing),
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let width = 2.0 * self.slider_width + self.spacing;
+        let intrinsic_size = Size::new(width, v_slider::DEFAULT_SHRINK_LENGTH + FOOTER_HEIGHT);
+
+        layout::Node::new(limits.resolve(Length::Fixed(width), self.height, intrinsic_size))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = state.state.downcast_mut::<State>();
+        let (slider_bounds, footer) = self.split(layout.bounds());
+
+        if let (Some(link_bounds), Some(on_link_toggle)) =
+            (self.link_bounds(footer), self.on_link_toggle.as_ref())
+        {
+            if matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                    | Event::Touch(touch::Event::FingerPressed { .. })
+            ) && cursor.is_over(link_bounds)
+            {
+                shell.publish(on_link_toggle(!self.linked));
+
+                return event::Status::Captured;
+            }
+        }
+
+        let mut status = event::Status::Ignored;
+
+        for (channel, bounds) in slider_bounds.into_iter().enumerate() {
+            let params = self.shown_params();
+            let mut normal_param = params[channel];
+            let moved = Cell::new(None);
+
+            let on_change = |change: Change| {
+                let values = linked_values(&params, channel, change, self.linked);
+                moved.set(Some(values));
+
+                let (left, right) = self.mode.left_right(values);
+                (self.on_change)(left, right)
+            };
+
+            let channel_status = VirtualSlider {
+                normal_param: &mut normal_param,
+                sensitivity: self.sensitivity,
+                on_change: &on_change,
+                on_grab: self.on_grab.as_deref_mut(),
+                on_release: self.on_release.as_deref_mut(),
+                steps: None,
+                free_modifier_keys: keyboard::Modifiers::empty(),
+                fine_drag_button: None,
+                double_click: self.double_click,
+                drag_threshold: self.drag_threshold,
+                detents: None,
+                on_type_in: None,
+                view_window: ViewWindow::FULL,
+                inverse: false,
+                on_drop: None,
+                group: None,
+                on_group_change: None,
+                capture_wheel: self.capture_wheel,
+                horizontal_wheel_scalar: 0.0,
+                spring_back: None,
+                wheel_gesture: self.wheel_gesture,
+                hit_shape: HitShape::Rectangle,
+            }
+            .on_event(
+                &mut state.channels[channel],
+                &event,
+                cursor,
+                bounds,
+                Axis::across_height(&bounds, self.sensitivity.scalar, false),
+                shell,
+            );
+
+            if let Some(values) = moved.take() {
+                let (left, right) = self.mode.left_right(values);
+                self.left.value = left;
+                self.right.value = right;
+            }
+
+            status = status.merge(channel_status);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<State>();
+        let (slider_bounds, footer) = self.split(layout.bounds());
+        let params = self.shown_params();
+
+        for (channel, bounds) in slider_bounds.into_iter().enumerate() {
+            let channel_state = &state.channels[channel];
+
+            let mut value_markers = ValueMarkers {
+                tick_marks: self.tick_marks,
+                text_marks: None,
+                mod_range_1: None,
+                mod_range_2: None,
+                default: Some(params[channel].default),
+                tick_marks_style: theme.tick_marks_appearance(&self.class),
+                text_marks_style: None,
+                mod_range_style_1: None,
+                mod_range_style_2: None,
+                default_marker_style: theme.default_marker_appearance(&self.class),
+                text_mark_bounds: None,
+                opacity: 1.0,
+                scale_factor: self.scale_factor,
+            };
+            value_markers.dim(self.opacity);
+            value_markers.cull(&bounds, viewport);
+
+            let status = if channel_state.dragging_status.is_some() {
+                Status::Dragged
+            } else if cursor.is_over(bounds) {
+                Status::Hovered
+            } else if channel_state.is_focused {
+                Status::Focused
+            } else {
+                Status::Active
+            };
+
+            let bounds = Rectangle {
+                x: bounds.x.round(),
+                y: bounds.y.round(),
+                width: bounds.width.round(),
+                height: bounds.height.round(),
+            };
+            let normal = params[channel].value;
+
+            match v_slider::Catalog::style(theme, &self.class, status).dim(self.opacity) {
+                v_slider::Appearance::Texture(style) => draw::texture_style(
+                    renderer,
+                    normal,
+                    false,
+                    &bounds,
+                    style,
+                    &value_markers,
+                    &channel_state.text_marks_cache,
+                ),
+                v_slider::Appearance::Classic(style) => draw::classic_style(
+                    renderer,
+                    normal,
+                    false,
+                    &bounds,
+                    &style,
+                    &value_markers,
+                    &channel_state.text_marks_cache,
+                ),
+                v_slider::Appearance::Rect(style) => draw::rect_style(
+                    renderer,
+                    normal,
+                    false,
+                    &bounds,
+                    &style,
+                    &value_markers,
+                    &channel_state.text_marks_cache,
+                ),
+                v_slider::Appearance::RectBipolar(style) => draw::rect_bipolar_style(
+                    renderer,
+                    normal,
+                    Normal::CENTER,
+                    false,
+                    &bounds,
+                    &style,
+                    &value_markers,
+                    &channel_state.text_marks_cache,
+                ),
+                v_slider::Appearance::RectSplit(style) => draw::rect_split_style(
+                    renderer,
+                    normal,
+                    Normal::CENTER,
+                    false,
+                    &bounds,
+                    &style,
+                    &value_markers,
+                    &channel_state.text_marks_cache,
+                ),
+            }
+        }
+
+        let link_bounds = self.link_bounds(footer);
+
+        let status = if link_bounds.is_some_and(|bounds| cursor.is_over(bounds)) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let appearance =
+            stereo_slider::Catalog::style(theme, &self.footer_class, status).dim(self.opacity);
+
+        for (label, bounds) in self.mode.labels().into_iter().zip(slider_bounds) {
+            renderer.fill_text(
+                Text {
+                    content: label.to_string(),
+                    size: Pixels(appearance.label_size),
+                    bounds: Size::new(bounds.width, footer.height),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: LineHeight::default(),
+                    wrapping: Wrapping::default(),
+                    shaping: Shaping::Basic,
+                    font: renderer.default_font(),
+                },
+                Point::new(bounds.center_x(), footer.center_y()),
+                appearance.label_color,
+                footer,
+            );
+        }
+
+        if let Some(bounds) = link_bounds {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border {
+                        color: appearance.link_border_color,
+                        width: appearance.link_border_width,
+                        radius: Radius::new(appearance.link_border_radius),
+                    },
+                    shadow: Shadow::default(),
+                },
+                if self.linked {
+                    appearance.linked_color
+                } else {
+                    appearance.link_color
+                },
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StereoSlider<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + v_slider::Catalog + stereo_slider::Catalog,
+    Renderer: 'a + image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font>,
+{
+    fn from(stereo_slider: StereoSlider<'a, Message, Theme>) -> Self {
+        Self::new(stereo_slider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::fixtures::{assert_close, param, Message, SIZE};
+    use crate::test_utils::Harness;
+    use crate::{stereo_slider::StereoMode, v_slider, StereoSlider};
+    use iced::{event, Length, Point, Size, Theme};
+
+    /// A [`StereoSlider`] with 100 pixels tall sliders at `x` 0 to 14 and 30
+    /// to 44, and the link toggle at `x` 17 to 27 and `y` 103 to 113.
+    fn stereo_slider<'a>(left: f32, right: f32) -> StereoSlider<'a, Message, Theme> {
+        StereoSlider::new(param(left, 0.5), param(right, 0.25), Message::ChangedPair)
+            .height(Length::Fixed(116.0))
+    }
+
+    fn changed_pairs(messages: &[Message]) -> Vec<(f32, f32)> {
+        messages
+            .iter()
+            .filter_map(|message| match message {
+                Message::ChangedPair(left, right) => Some((left.as_f32(), right.as_f32())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn stereo_slider_moves_one_channel_unlinked() {
+        let mut harness = Harness::new(stereo_slider(0.3, 0.4), Size::new(44.0, 116.0));
+
+        harness.drag(Point::new(7.0, 50.0), Point::new(7.0, 0.0));
+
+        let pairs = changed_pairs(&harness.take_messages());
+        let (left, right) = *pairs.last().unwrap();
+        assert_close(left, 0.3 + 0.5 * v_slider::DEFAULT_SCALAR);
+        assert_close(right, 0.4);
+    }
+
+    #[test]
+    fn stereo_slider_moves_both_channels_linked() {
+        let mut harness = Harness::new(stereo_slider(0.3, 0.4).linked(true), SIZE);
+
+        harness.drag(Point::new(37.0, 50.0), Point::new(37.0, 60.0));
+
+        let pairs = changed_pairs(&harness.take_messages());
+        let (left, right) = *pairs.last().unwrap();
+        let delta = 0.1 * v_slider::DEFAULT_SCALAR;
+        assert_close(left, 0.3 - delta);
+        assert_close(right, 0.4 - delta);
+
+        // Dragging again continues from both moved values.
+        harness.drag(Point::new(7.0, 50.0), Point::new(7.0, 60.0));
+
+        let (left, right) = *changed_pairs(&harness.take_messages()).last().unwrap();
+        assert_close(left, 0.3 - 2.0 * delta);
+        assert_close(right, 0.4 - 2.0 * delta);
+
+        // A double click resets both channels to their defaults.
+        let _ = harness.press();
+        let _ = harness.release();
+        let _ = harness.press();
+
+        assert_eq!(
+            changed_pairs(&harness.take_messages()).last(),
+            Some(&(0.5, 0.25))
+        );
+    }
+
+    #[test]
+    fn stereo_slider_mid_side() {
+        let mut harness = Harness::new(
+            stereo_slider(0.4, 0.4).mode(StereoMode::MidSide),
+            Size::new(44.0, 116.0),
+        );
+
+        // Moving the side up moves the left channel up and the right one down.
+        harness.drag(Point::new(37.0, 50.0), Point::new(37.0, 40.0));
+
+        let (left, right) = *changed_pairs(&harness.take_messages()).last().unwrap();
+        let side = 0.1 * v_slider::DEFAULT_SCALAR;
+        assert_close(left, 0.4 + side);
+        assert_close(right, 0.4 - side);
+    }
+
+    #[test]
+    fn stereo_slider_link_toggle() {
+        let slider = || stereo_slider(0.3, 0.4).on_link_toggle(Message::Linked);
+        let mut harness = Harness::new(slider(), Size::new(44.0, 116.0));
+
+        let _ = harness.move_cursor(Point::new(22.0, 108.0));
+        assert_eq!(harness.press(), event::Status::Captured);
+        assert_eq!(harness.take_messages(), vec![Message::Linked(true)]);
+
+        harness.rebuild(slider().linked(true));
+        let _ = harness.press();
+        assert_eq!(harness.take_messages(), vec![Message::Linked(false)]);
+
+        // Without `on_link_toggle` there is nothing to click.
+        harness.rebuild(stereo_slider(0.3, 0.4));
+        assert_eq!(harness.press(), event::Status::Ignored);
+    }
+}
@@ -1,33 +1,54 @@
+use std::cell::RefCell;
+
 use iced::{
-    advanced::{image::Renderer as _, renderer::Quad, Renderer as _},
+    advanced::{
+        image,
+        renderer::{self, Quad},
+        text,
+    },
     border::Radius,
     widget::canvas::Image,
-    Border, Color, Rectangle, Renderer, Shadow,
+    Border, Color, Font, Rectangle, Shadow,
 };
 
 use crate::{
     core::{text_marks, tick_marks},
     style::v_slider::{
-        ClassicAppearance, ClassicRail, ModRangeAppearance, ModRangePlacement, RectAppearance,
-        RectBipolarAppearance, TextMarksAppearance, TextureAppearance, TickMarksAppearance,
+        ClassicAppearance, ClassicRail, DefaultMarkerAppearance, ModRangeAppearance,
+        ModRangePlacement, RectAppearance, RectBipolarAppearance, RectSplitAppearance,
+        TextMarksAppearance, TextureAppearance, TickMarksAppearance,
     },
-    widget::v_slider::ValueMarkers,
-    ModulationRange, Normal,
+    widget::{marks_layout, v_slider::ValueMarkers},
+    BipolarSide, ModulationRange, Normal,
 };
 
-fn markers(
+/// Returns where `normal` is shown along the slider, which is mirrored if
+/// the slider is `inverse`.
+fn shown(normal: Normal, inverse: bool) -> Normal {
+    if inverse {
+        Normal::from_clipped(normal.as_f32_inv())
+    } else {
+        normal
+    }
+}
+
+fn markers<Renderer>(
     renderer: &mut Renderer,
     mark_bounds: &Rectangle,
     mod_bounds: &Rectangle,
     value_markers: &ValueMarkers<'_>,
+    inverse: bool,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
     tick_marks(
         renderer,
         mark_bounds,
         value_markers.tick_marks,
         &value_markers.tick_marks_style,
+        inverse,
         //tick_marks_cache,
     );
     text_marks(
@@ -35,29 +56,43 @@ fn markers(
         mark_bounds,
         value_markers.text_marks,
         &value_markers.text_marks_style,
-        //text_marks_cache,
+        value_markers.text_mark_bounds,
+        inverse,
+        text_marks_cache,
     );
     modulation(
         renderer,
         mod_bounds,
         value_markers.mod_range_1,
         &value_markers.mod_range_style_1,
+        inverse,
     );
     modulation(
         renderer,
         mod_bounds,
         value_markers.mod_range_2,
         &value_markers.mod_range_style_2,
+        inverse,
+    );
+    default_marker(
+        renderer,
+        mod_bounds,
+        value_markers.default,
+        &value_markers.default_marker_style,
+        inverse,
     );
 }
 
-fn tick_marks(
+fn tick_marks<Renderer>(
     renderer: &mut Renderer,
     bounds: &Rectangle,
     tick_marks: Option<&tick_marks::Group>,
     tick_marks_style: &Option<TickMarksAppearance>,
+    inverse: bool,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-) {
+) where
+    Renderer: renderer::Renderer,
+{
     if let Some(tick_marks) = tick_marks {
         if let Some(style) = tick_marks_style {
             tick_marks::draw_vertical_tick_marks(
@@ -66,55 +101,126 @@ fn tick_marks(
                 tick_marks,
                 &style.style,
                 &style.placement,
-                false,
+                inverse,
                 //tick_marks_cache,
             )
         }
     }
 }
 
-fn text_marks(
+fn text_marks<Renderer>(
     renderer: &mut Renderer,
     bounds: &Rectangle,
     text_marks: Option<&text_marks::Group>,
     text_marks_style: &Option<TextMarksAppearance>,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
-    if let Some(text_marks) = text_marks {
-        if let Some(style) = text_marks_style {
-            text_marks::draw_vertical_text_marks(
-                renderer,
+    text_mark_bounds: Option<&RefCell<Vec<(Normal, Rectangle)>>>,
+    inverse: bool,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let marks = text_marks.zip(text_marks_style.as_ref());
+
+    if let Some(text_mark_bounds) = text_mark_bounds {
+        *text_mark_bounds.borrow_mut() = marks.map_or_else(Vec::new, |(text_marks, style)| {
+            text_marks::vertical_text_mark_bounds(
                 bounds,
                 text_marks,
                 &style.style,
                 &style.placement,
-                false,
-                //text_marks_cache,
+                inverse,
             )
+        });
+    }
+
+    if let Some((text_marks, style)) = marks {
+        text_marks::draw_vertical_text_marks(
+            renderer,
+            bounds,
+            text_marks,
+            &style.style,
+            &style.placement,
+            inverse,
+            text_marks_cache,
+        )
+    }
+}
+
+/// Returns the bounds that the marks, mod ranges and default marker in
+/// `value_markers` are drawn in around a slider with the given `bounds`.
+pub fn value_markers_bounds(bounds: &Rectangle, value_markers: &ValueMarkers<'_>) -> Rectangle {
+    let marks_bounds = marks_layout::marks_bounds(
+        bounds,
+        true,
+        value_markers
+            .tick_marks
+            .and(value_markers.tick_marks_style.as_ref())
+            .map(|style| (&style.style, &style.placement)),
+        value_markers
+            .text_marks
+            .and(value_markers.text_marks_style.as_ref())
+            .map(|style| (&style.style, &style.placement)),
+    );
+
+    // The default marker is centered on its position, so it reaches half of
+    // its width past the ends of the slider.
+    let spans = [
+        value_markers
+            .mod_range_1
+            .and(value_markers.mod_range_style_1.as_ref())
+            .map(|style| (&style.placement, 0.0)),
+        value_markers
+            .mod_range_2
+            .and(value_markers.mod_range_style_2.as_ref())
+            .map(|style| (&style.placement, 0.0)),
+        value_markers
+            .default
+            .and(value_markers.default_marker_style.as_ref())
+            .map(|style| (&style.placement, style.height / 2.0)),
+    ];
+
+    spans
+        .into_iter()
+        .flatten()
+        .fold(marks_bounds, |markers_bounds, (placement, overhang)| {
+            let (x, width) = placement_span(bounds, placement);
+
+            markers_bounds.union(&Rectangle {
+                x,
+                y: bounds.y - overhang,
+                width,
+                height: bounds.height + (overhang * 2.0),
+            })
+        })
+}
+
+/// Returns the `x` and the width of a mod range or default marker with the
+/// given `placement`.
+fn placement_span(bounds: &Rectangle, placement: &ModRangePlacement) -> (f32, f32) {
+    match *placement {
+        ModRangePlacement::Center { width, offset } => {
+            (bounds.x + offset + ((bounds.width - width) / 2.0), width)
         }
+        ModRangePlacement::CenterFilled { edge_padding } => {
+            (bounds.x + edge_padding, bounds.width - (edge_padding * 2.0))
+        }
+        ModRangePlacement::Left { width, offset } => (bounds.x + offset - width, width),
+        ModRangePlacement::Right { width, offset } => (bounds.x + bounds.width + offset, width),
     }
 }
 
-fn modulation(
+fn modulation<Renderer>(
     renderer: &mut Renderer,
     bounds: &Rectangle,
     mod_range: Option<&ModulationRange>,
     style: &Option<ModRangeAppearance>,
-) {
+    inverse: bool,
+) where
+    Renderer: renderer::Renderer,
+{
     if let Some(mod_range) = mod_range {
         if let Some(style) = style {
-            let (x, width) = match style.placement {
-                ModRangePlacement::Center { width, offset } => {
-                    (bounds.x + offset + ((bounds.width - width) / 2.0), width)
-                }
-                ModRangePlacement::CenterFilled { edge_padding } => {
-                    (bounds.x + edge_padding, bounds.width - (edge_padding * 2.0))
-                }
-                ModRangePlacement::Left { width, offset } => (bounds.x + offset - width, width),
-                ModRangePlacement::Right { width, offset } => {
-                    (bounds.x + bounds.width + offset, width)
-                }
-            };
+            let (x, width) = placement_span(bounds, &style.placement);
 
             if let Some(back_color) = style.back_color {
                 renderer.fill_quad(
@@ -136,23 +242,21 @@ fn modulation(
                 );
             }
 
-            if mod_range.filled_visible && (mod_range.start.as_f32() != mod_range.end.as_f32()) {
-                let (start, end, color) = if mod_range.start.as_f32() > mod_range.end.as_f32() {
-                    (
-                        mod_range.start.as_f32_inv(),
-                        mod_range.end.as_f32_inv(),
-                        style.filled_color,
-                    )
+            if let Some((start, end)) = mod_range.filled() {
+                let color = if mod_range.is_inverse() {
+                    style.filled_inverse_color
                 } else {
-                    (
-                        mod_range.end.as_f32_inv(),
-                        mod_range.start.as_f32_inv(),
-                        style.filled_inverse_color,
-                    )
+                    style.filled_color
                 };
 
-                let start_offset = bounds.height * start;
-                let filled_height = (bounds.height * end) - start_offset;
+                let (start, end) = if inverse {
+                    (shown(end, inverse), shown(start, inverse))
+                } else {
+                    (start, end)
+                };
+
+                let start_offset = end.scale_inv(bounds.height);
+                let filled_height = start.scale_inv(bounds.height) - start_offset;
 
                 renderer.fill_quad(
                     Quad {
@@ -176,15 +280,56 @@ fn modulation(
     }
 }
 
-pub fn texture_style(
+fn default_marker<Renderer>(
+    renderer: &mut Renderer,
+    bounds: &Rectangle,
+    default: Option<Normal>,
+    style: &Option<DefaultMarkerAppearance>,
+    inverse: bool,
+) where
+    Renderer: renderer::Renderer,
+{
+    if let Some(default) = default {
+        if let Some(style) = style {
+            let (x, width) = placement_span(bounds, &style.placement);
+
+            let y =
+                bounds.y + shown(default, inverse).scale_inv(bounds.height) - (style.height / 2.0);
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: y.round(),
+                        width,
+                        height: style.height,
+                    },
+                    border: Border {
+                        color: Color::TRANSPARENT,
+                        width: 0.0,
+                        radius: Radius::new(style.border_radius),
+                    },
+                    shadow: Shadow::default(),
+                },
+                style.color,
+            );
+        }
+    }
+}
+
+pub fn texture_style<Renderer>(
     renderer: &mut Renderer,
     normal: Normal,
+    inverse: bool,
     bounds: &Rectangle,
     style: TextureAppearance,
     value_markers: &ValueMarkers<'_>,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font>,
+{
+    let normal = shown(normal, inverse);
     let value_bounds = Rectangle {
         x: bounds.x,
         y: (bounds.y + (f32::from(style.handle_height) / 2.0)).round(),
@@ -197,14 +342,16 @@ pub fn texture_style(
         &value_bounds,
         &value_bounds,
         value_markers,
+        inverse,
         //tick_marks_cache,
-        //text_marks_cache,
+        text_marks_cache,
     );
 
     classic_rail(renderer, bounds, &style.rail);
 
     renderer.draw_image(
-        Image::from(&style.image_handle),
+        Image::from(style.image_handle.select(value_markers.scale_factor))
+            .opacity(value_markers.opacity),
         Rectangle {
             x: (bounds.center_x() + style.image_bounds.x).round(),
             y: (value_bounds.y + style.image_bounds.y + normal.scale_inv(value_bounds.height))
@@ -215,15 +362,19 @@ pub fn texture_style(
     )
 }
 
-pub fn classic_style(
+pub fn classic_style<Renderer>(
     renderer: &mut Renderer,
     normal: Normal,
+    inverse: bool,
     bounds: &Rectangle,
     style: &ClassicAppearance,
     value_markers: &ValueMarkers<'_>,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: image::Renderer<Handle = image::Handle> + text::Renderer<Font = Font>,
+{
+    let normal = shown(normal, inverse);
     let handle_height = f32::from(style.handle.height);
 
     let value_bounds = Rectangle {
@@ -238,8 +389,9 @@ pub fn classic_style(
         &value_bounds,
         &value_bounds,
         value_markers,
+        inverse,
         //tick_marks_cache,
-        //text_marks_cache,
+        text_marks_cache,
     );
 
     classic_rail(renderer, bounds, &style.rail);
@@ -265,20 +417,32 @@ pub fn classic_style(
         style.handle.color,
     );
 
-    if style.handle.notch_width != 0.0 {
+    let notch_center_y = bounds.y + handle_offset + (handle_height / 2.0);
+
+    if let Some(notch) = &style.handle.notch_texture {
+        renderer.draw_image(
+            Image::from(notch.image_handle.select(value_markers.scale_factor))
+                .opacity(value_markers.opacity),
+            Rectangle {
+                x: (bounds.center_x() + notch.image_bounds.x).round(),
+                y: (notch_center_y + notch.image_bounds.y).round(),
+                width: notch.image_bounds.width,
+                height: notch.image_bounds.height,
+            },
+        );
+    } else if style.handle.notch_width != 0.0 {
         renderer.fill_quad(
             Quad {
                 bounds: Rectangle {
                     x: bounds.x,
-                    y: (bounds.y + handle_offset + (handle_height / 2.0) - (notch_width / 2.0))
-                        .round(),
+                    y: (notch_center_y - (notch_width / 2.0)).round(),
                     width: bounds.width,
                     height: notch_width,
                 },
                 border: Border {
                     color: Color::TRANSPARENT,
                     width: 0.0,
-                    radius: Radius::new(0.0),
+                    radius: Radius::new(style.handle.notch_radius),
                 },
                 shadow: Shadow::default(),
             },
@@ -287,15 +451,19 @@ pub fn classic_style(
     }
 }
 
-pub fn rect_style(
+pub fn rect_style<Renderer>(
     renderer: &mut Renderer,
     normal: Normal,
+    inverse: bool,
     bounds: &Rectangle,
     style: &RectAppearance,
     value_markers: &ValueMarkers<'_>,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let normal = shown(normal, inverse);
     let handle_height = f32::from(style.handle_height);
     let border_width = style.back_border_width;
     let twice_border_width = border_width * 2.0;
@@ -329,15 +497,23 @@ pub fn rect_style(
         .scale_inv(value_bounds.height - twice_border_width)
         .round();
 
-    let filled_offset = handle_offset + handle_height + style.handle_filled_gap;
+    // The filled portion is on the other side of the handle when the slider
+    // is inverse.
+    let (filled_y, filled_height) = if inverse {
+        (bounds.y, handle_offset - style.handle_filled_gap)
+    } else {
+        let filled_offset = handle_offset + handle_height + style.handle_filled_gap;
+
+        (bounds.y + filled_offset, bounds.height - filled_offset)
+    };
 
     renderer.fill_quad(
         Quad {
             bounds: Rectangle {
                 x: bounds.x,
-                y: bounds.y + filled_offset,
+                y: filled_y,
                 width: bounds.width,
-                height: bounds.height - filled_offset,
+                height: filled_height,
             },
             border: Border {
                 color: Color::TRANSPARENT,
@@ -372,20 +548,26 @@ pub fn rect_style(
         &value_bounds,
         bounds,
         value_markers,
+        inverse,
         //tick_marks_cache,
-        //text_marks_cache,
+        text_marks_cache,
     );
 }
 
-pub fn rect_bipolar_style(
+#[allow(clippy::too_many_arguments)]
+pub fn rect_bipolar_style<Renderer>(
     renderer: &mut Renderer,
     normal: Normal,
+    bipolar_center: Normal,
+    inverse: bool,
     bounds: &Rectangle,
     style: &RectBipolarAppearance,
     value_markers: &ValueMarkers<'_>,
     //tick_marks_cache: &tick_marks::PrimitiveCache,
-    //text_marks_cache: &text_marks::PrimitiveCache,
-) {
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
     let handle_height = f32::from(style.handle_height);
     let border_width = style.back_border_width;
     let twice_border_width = border_width * 2.0;
@@ -402,8 +584,9 @@ pub fn rect_bipolar_style(
         &value_bounds,
         bounds,
         value_markers,
+        inverse,
         //tick_marks_cache,
-        //text_marks_cache,
+        text_marks_cache,
     );
 
     renderer.fill_quad(
@@ -424,11 +607,28 @@ pub fn rect_bipolar_style(
         style.back_color,
     );
 
+    // The handle keeps the color of the side of the center that the value
+    // is on, and so do the filled portions when the slider is inverse.
+    let bipolar_side = normal.classify_bipolar(bipolar_center);
+    let (top_filled_color, bottom_filled_color) = if inverse {
+        (style.bottom_filled_color, style.top_filled_color)
+    } else {
+        (style.top_filled_color, style.bottom_filled_color)
+    };
+
+    let normal = shown(normal, inverse);
+    let bipolar_center = shown(bipolar_center, inverse);
+
     let handle_offset = normal
         .scale_inv(value_bounds.height - twice_border_width)
         .round();
+    let center_offset = bipolar_center
+        .scale_inv(value_bounds.height - twice_border_width)
+        .round()
+        + (handle_height / 2.0)
+        + border_width;
 
-    if normal.as_f32() > 0.5 {
+    if normal > bipolar_center {
         let filled_rect_offset = handle_offset + handle_height + style.handle_filled_gap;
 
         renderer.fill_quad(
@@ -437,8 +637,7 @@ pub fn rect_bipolar_style(
                     x: bounds.x,
                     y: bounds.y + filled_rect_offset,
                     width: bounds.width,
-                    height: ((bounds.height / 2.0) - filled_rect_offset + twice_border_width)
-                        .round(),
+                    height: (center_offset - filled_rect_offset + twice_border_width).round(),
                 },
                 border: Border {
                     color: Color::TRANSPARENT,
@@ -447,10 +646,10 @@ pub fn rect_bipolar_style(
                 },
                 shadow: Shadow::default(),
             },
-            style.top_filled_color,
+            top_filled_color,
         );
     } else {
-        let filled_rect_offset = (bounds.height / 2.0).round() - border_width;
+        let filled_rect_offset = center_offset.round() - border_width;
         renderer.fill_quad(
             Quad {
                 bounds: Rectangle {
@@ -467,18 +666,163 @@ pub fn rect_bipolar_style(
                 },
                 shadow: Shadow::default(),
             },
-            style.bottom_filled_color,
+            bottom_filled_color,
         );
     };
 
-    let handle_color = if normal.as_f32() > 0.499 && normal.as_f32() < 0.501 {
-        style.handle_center_color
-    } else if normal.as_f32() > 0.5 {
-        style.handle_top_color
+    let handle_color = match bipolar_side {
+        BipolarSide::Left => style.handle_bottom_color,
+        BipolarSide::Right => style.handle_top_color,
+        BipolarSide::Center => style.handle_center_color,
+    };
+
+    renderer.fill_quad(
+        Quad {
+            bounds: Rectangle {
+                x: bounds.x,
+                y: bounds.y + handle_offset,
+                width: bounds.width,
+                height: handle_height + twice_border_width,
+            },
+            border: Border {
+                color: Color::TRANSPARENT,
+                width: style.back_border_width,
+                radius: Radius::new(style.back_border_radius),
+            },
+            shadow: Shadow::default(),
+        },
+        handle_color,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn rect_split_style<Renderer>(
+    renderer: &mut Renderer,
+    normal: Normal,
+    bipolar_center: Normal,
+    inverse: bool,
+    bounds: &Rectangle,
+    style: &RectSplitAppearance,
+    value_markers: &ValueMarkers<'_>,
+    //tick_marks_cache: &tick_marks::PrimitiveCache,
+    text_marks_cache: &text_marks::Cache,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    let handle_height = f32::from(style.handle_height);
+    let border_width = style.back_border_width;
+    let twice_border_width = border_width * 2.0;
+
+    let value_bounds = Rectangle {
+        x: bounds.x,
+        y: (bounds.y + (handle_height / 2.0)).round(),
+        width: bounds.width,
+        height: bounds.height - handle_height,
+    };
+
+    markers(
+        renderer,
+        &value_bounds,
+        bounds,
+        value_markers,
+        inverse,
+        //tick_marks_cache,
+        text_marks_cache,
+    );
+
+    renderer.fill_quad(
+        Quad {
+            bounds: *bounds,
+            border: Border {
+                color: style.back_border_color,
+                width: style.back_border_width,
+                radius: Radius::new(style.back_border_radius),
+            },
+            shadow: Shadow::default(),
+        },
+        style.back_color,
+    );
+
+    // The colors follow the side of the center that the value is on, on
+    // whichever side an inverse slider shows it.
+    let (filled_color, handle_color) = match normal.classify_bipolar(bipolar_center) {
+        BipolarSide::Left => (style.negative_filled_color, style.handle_negative_color),
+        BipolarSide::Right => (style.positive_filled_color, style.handle_positive_color),
+        BipolarSide::Center => (Color::TRANSPARENT, style.origin_color),
+    };
+
+    let normal = shown(normal, inverse);
+    let bipolar_center = shown(bipolar_center, inverse);
+
+    let handle_offset = normal
+        .scale_inv(value_bounds.height - twice_border_width)
+        .round();
+    let center_offset = bipolar_center
+        .scale_inv(value_bounds.height - twice_border_width)
+        .round()
+        + (handle_height / 2.0)
+        + border_width;
+
+    // The filled portion runs from the origin to the handle, and only its
+    // end at the handle is rounded.
+    let radius = style.filled_radius;
+    let (filled_start, filled_end, filled_radius) = if normal > bipolar_center {
+        (
+            handle_offset + handle_height + twice_border_width + style.handle_filled_gap,
+            center_offset,
+            Radius {
+                top_left: radius,
+                top_right: radius,
+                ..Radius::default()
+            },
+        )
     } else {
-        style.handle_bottom_color
+        (
+            center_offset,
+            handle_offset - style.handle_filled_gap,
+            Radius {
+                bottom_right: radius,
+                bottom_left: radius,
+                ..Radius::default()
+            },
+        )
     };
 
+    if filled_end > filled_start {
+        renderer.fill_quad(
+            Quad {
+                bounds: Rectangle {
+                    x: bounds.x + border_width,
+                    y: bounds.y + filled_start,
+                    width: bounds.width - twice_border_width,
+                    height: filled_end - filled_start,
+                },
+                border: Border {
+                    radius: filled_radius,
+                    ..Border::default()
+                },
+                shadow: Shadow::default(),
+            },
+            filled_color,
+        );
+    }
+
+    if style.origin_width > 0.0 {
+        renderer.fill_quad(
+            Quad {
+                bounds: Rectangle {
+                    x: bounds.x + border_width,
+                    y: (bounds.y + center_offset - (style.origin_width / 2.0)).round(),
+                    width: bounds.width - twice_border_width,
+                    height: style.origin_width,
+                },
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            style.origin_color,
+        );
+    }
+
     renderer.fill_quad(
         Quad {
             bounds: Rectangle {
@@ -498,7 +842,10 @@ pub fn rect_bipolar_style(
     );
 }
 
-fn classic_rail(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail) {
+fn classic_rail<Renderer>(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail)
+where
+    Renderer: renderer::Renderer,
+{
     let (left_width, right_width) = style.rail_widths;
     let (left_color, right_color) = style.rail_colors;
 
@@ -509,6 +856,18 @@ fn classic_rail(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail
     let y = bounds.y + style.rail_padding;
     let height = bounds.height - (style.rail_padding * 2.0);
 
+    // The ends are rounded by the outer corners of both halves of the rail.
+    let end_radius = |half_width: f32| {
+        if style.rounded_ends {
+            half_width.min(full_width / 2.0)
+        } else {
+            0.0
+        }
+    };
+
+    let left_radius = end_radius(left_width);
+    let right_radius = end_radius(right_width);
+
     renderer.fill_quad(
         Quad {
             bounds: Rectangle {
@@ -520,7 +879,11 @@ fn classic_rail(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail
             border: Border {
                 color: Color::TRANSPARENT,
                 width: 0.0,
-                radius: Radius::new(0.0),
+                radius: Radius {
+                    top_left: left_radius,
+                    bottom_left: left_radius,
+                    ..Radius::default()
+                },
             },
             shadow: Shadow::default(),
         },
@@ -538,7 +901,11 @@ fn classic_rail(renderer: &mut Renderer, bounds: &Rectangle, style: &ClassicRail
             border: Border {
                 color: Color::TRANSPARENT,
                 width: 0.0,
-                radius: Radius::new(0.0),
+                radius: Radius {
+                    top_right: right_radius,
+                    bottom_right: right_radius,
+                    ..Radius::default()
+                },
             },
             shadow: Shadow::default(),
         },
@@ -1,16 +1,58 @@
+use std::cell::RefCell;
+
 use crate::{
     core::{text_marks, tick_marks},
-    style::v_slider::{ModRangeAppearance, TextMarksAppearance, TickMarksAppearance},
-    ModulationRange,
+    style::{
+        v_slider::{
+            DefaultMarkerAppearance, ModRangeAppearance, TextMarksAppearance, TickMarksAppearance,
+        },
+        Dim,
+    },
+    ModulationRange, Normal,
 };
+use iced::Rectangle;
 
 pub struct ValueMarkers<'a> {
     pub tick_marks: Option<&'a tick_marks::Group>,
     pub text_marks: Option<&'a text_marks::Group>,
     pub mod_range_1: Option<&'a ModulationRange>,
     pub mod_range_2: Option<&'a ModulationRange>,
+    pub default: Option<Normal>,
     pub tick_marks_style: Option<TickMarksAppearance>,
     pub text_marks_style: Option<TextMarksAppearance>,
     pub mod_range_style_1: Option<ModRangeAppearance>,
     pub mod_range_style_2: Option<ModRangeAppearance>,
+    pub default_marker_style: Option<DefaultMarkerAppearance>,
+    /// Where the bounds of the text marks are kept when they are drawn, if
+    /// they are clickable.
+    pub text_mark_bounds: Option<&'a RefCell<Vec<(Normal, Rectangle)>>>,
+    /// The opacity the textures of the slider are drawn with.
+    pub opacity: f32,
+    /// The scale factor the textures of the slider are picked for.
+    pub scale_factor: f32,
+}
+
+impl ValueMarkers<'_> {
+    /// Leaves out the markers when none of them can be seen in `viewport`,
+    /// e.g. when the slider is scrolled out of view.
+    pub fn cull(&mut self, bounds: &Rectangle, viewport: &Rectangle) {
+        if !super::draw::value_markers_bounds(bounds, self).intersects(viewport) {
+            self.tick_marks = None;
+            self.text_marks = None;
+            self.mod_range_1 = None;
+            self.mod_range_2 = None;
+            self.default = None;
+        }
+    }
+
+    /// Dims the styles of the markers and the textures of the slider to
+    /// `opacity`.
+    pub fn dim(&mut self, opacity: f32) {
+        self.tick_marks_style = self.tick_marks_style.take().dim(opacity);
+        self.text_marks_style = self.text_marks_style.take().dim(opacity);
+        self.mod_range_style_1 = self.mod_range_style_1.take().dim(opacity);
+        self.mod_range_style_2 = self.mod_range_style_2.take().dim(opacity);
+        self.default_marker_style = self.default_marker_style.take().dim(opacity);
+        self.opacity *= opacity;
+    }
 }
@@ -0,0 +1,72 @@
+//! Draw the value text of the slider widgets at their handle
+
+use iced::{
+    advanced::text::{self, Text},
+    alignment::{Horizontal, Vertical},
+    widget::text::{LineHeight, Shaping, Wrapping},
+    Font, Pixels, Point, Rectangle, Size,
+};
+
+use crate::style::{
+    value_text::{Appearance, Placement},
+    Status,
+};
+
+/// Draws `content` at the handle of a slider with the body `bounds`, where
+/// `handle` is the position of the handle along the slider. `vertical` is
+/// whether the slider is vertical.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    bounds: &Rectangle,
+    handle: f32,
+    vertical: bool,
+    content: String,
+    appearance: &Appearance,
+    status: Status,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer<Font = Font>,
+{
+    if appearance.on_hover_only && !matches!(status, Status::Hovered | Status::Dragged) {
+        return;
+    }
+
+    let (position, horizontal_alignment, vertical_alignment) = if vertical {
+        let (x, alignment) = match appearance.placement {
+            Placement::Handle => (bounds.center_x(), Horizontal::Center),
+            Placement::LeftOrTop => (bounds.x, Horizontal::Right),
+            Placement::RightOrBottom => (bounds.x + bounds.width, Horizontal::Left),
+        };
+
+        (Point::new(x, handle), alignment, Vertical::Center)
+    } else {
+        let (y, alignment) = match appearance.placement {
+            Placement::Handle => (bounds.center_y(), Vertical::Center),
+            Placement::LeftOrTop => (bounds.y, Vertical::Bottom),
+            Placement::RightOrBottom => (bounds.y + bounds.height, Vertical::Top),
+        };
+
+        (Point::new(handle, y), Horizontal::Center, alignment)
+    };
+
+    renderer.fill_text(
+        Text {
+            content,
+            size: Pixels(appearance.text_size),
+            bounds: Size::INFINITY,
+            horizontal_alignment,
+            vertical_alignment,
+            line_height: LineHeight::default(),
+            wrapping: Wrapping::None,
+            shaping: Shaping::Basic,
+            font: appearance.font,
+        },
+        Point::new(
+            position.x + appearance.offset.x,
+            position.y + appearance.offset.y,
+        ),
+        appearance.color,
+        *viewport,
+    );
+}
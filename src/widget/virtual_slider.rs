@@ -0,0 +1,968 @@
+//! The event handling shared by the widgets that move a single [`Normal`]
+//! value when dragged, scrolled, or double-clicked.
+//!
+//! [`Normal`]: ../../core/normal/struct.Normal.html
+
+#[cfg(any(feature = "h_slider", feature = "v_slider"))]
+use std::cell::RefCell;
+
+use iced::{
+    advanced::{mouse, widget::operation::Focusable, Shell},
+    event, keyboard,
+    time::{Duration, Instant},
+    touch, window, Event, Point, Rectangle,
+};
+
+use crate::{
+    core::{
+        tick_marks, Change, ChangeSource, DetentDrag, Detents, DoubleClick, Drag, DragClamp,
+        DragThreshold, HitShape, Normal, NormalParam, ParamSnapshot, SelectionGroup,
+        SliderSensitivity, ViewWindow,
+    },
+    widget::{
+        animation::Transition,
+        input::{self, Click, Pointer, PressClaim, ScrollSteps, WheelGesture},
+    },
+    SliderStatus,
+};
+
+#[cfg(any(feature = "h_slider", feature = "v_slider", feature = "ramp"))]
+use crate::text_marks;
+#[cfg(any(feature = "h_slider", feature = "v_slider"))]
+use crate::widget::marks_layout::MarksLayout;
+#[cfg(feature = "ramp")]
+use crate::widget::ramp::LineCache;
+
+/// The local state of a virtual slider.
+#[derive(Debug, Clone)]
+pub(crate) struct State {
+    pub dragging_status: Option<SliderStatus>,
+    pub drag_pointer: Option<Pointer>,
+    pub press_claim: PressClaim,
+    /// The mouse button that started the drag, where touches count as the
+    /// left button.
+    pub drag_button: Option<mouse::Button>,
+    /// The press that hasn't moved past the drag threshold yet, while the
+    /// widget is dragged.
+    pub pending_drag: Option<PendingDrag>,
+    pub drag: Drag,
+    pub detent_drag: DetentDrag,
+    #[cfg(any(feature = "arc_slider", feature = "knob"))]
+    pub circular_drag: crate::core::CircularDrag,
+    pub scroll: ScrollSteps,
+    pub prev_normal: Normal,
+    pub continuous_normal: f64,
+    pub pressed_modifiers: keyboard::Modifiers,
+    pub last_click: Option<Click>,
+    pub is_focused: bool,
+    /// Whether the focus should be shown with a focus ring, which it is only
+    /// when it came from an operation rather than a click.
+    pub focus_visible: bool,
+    /// The selection group as it was when the widget was grabbed, which its
+    /// members are moved from.
+    pub group_start: Option<SelectionGroup>,
+    /// The value springing back to the default after a release, which
+    /// still belongs to the gesture of the drag.
+    pub spring: Option<Spring>,
+    pub wheel_gesture: WheelGesture,
+    #[cfg(any(feature = "h_slider", feature = "v_slider"))]
+    pub marks_layout: MarksLayout,
+    /// The bounds of the text marks and their positions in the view window
+    /// when the widget was last drawn, if they are clickable.
+    #[cfg(any(feature = "h_slider", feature = "v_slider"))]
+    pub text_mark_bounds: RefCell<Vec<(Normal, Rectangle)>>,
+    #[cfg(any(feature = "h_slider", feature = "v_slider", feature = "ramp"))]
+    pub text_marks_cache: text_marks::Cache,
+    #[cfg(feature = "ramp")]
+    pub ramp_line: LineCache,
+}
+
+impl State {
+    /// Creates a new virtual slider state with the current [`Normal`] value
+    /// of the widget.
+    ///
+    /// [`Normal`]: ../../core/normal/struct.Normal.html
+    pub fn new(normal: Normal) -> Self {
+        Self {
+            dragging_status: None,
+            drag_pointer: None,
+            press_claim: PressClaim::default(),
+            drag_button: None,
+            pending_drag: None,
+            drag: Drag::default(),
+            detent_drag: DetentDrag::default(),
+            #[cfg(any(feature = "arc_slider", feature = "knob"))]
+            circular_drag: Default::default(),
+            scroll: ScrollSteps::default(),
+            prev_normal: normal,
+            continuous_normal: normal.as_f64(),
+            pressed_modifiers: Default::default(),
+            last_click: None,
+            is_focused: false,
+            focus_visible: false,
+            group_start: None,
+            spring: None,
+            wheel_gesture: WheelGesture::default(),
+            #[cfg(any(feature = "h_slider", feature = "v_slider"))]
+            marks_layout: MarksLayout::default(),
+            #[cfg(any(feature = "h_slider", feature = "v_slider"))]
+            text_mark_bounds: RefCell::default(),
+            #[cfg(any(feature = "h_slider", feature = "v_slider", feature = "ramp"))]
+            text_marks_cache: text_marks::Cache::default(),
+            #[cfg(feature = "ramp")]
+            ramp_line: LineCache::default(),
+        }
+    }
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.focus_visible = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.focus_visible = false;
+    }
+}
+
+/// A press that doesn't move the value until the pointer moves past the
+/// [`DragThreshold`] of the widget.
+///
+/// [`DragThreshold`]: ../../core/drag/struct.DragThreshold.html
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PendingDrag {
+    position: Point,
+    is_touch: bool,
+    /// Whether the `on_grab` message waits for the drag.
+    pub grab: bool,
+}
+
+impl PendingDrag {
+    /// Returns the pending drag of the press of `event` at `position`, or
+    /// `None` if the press drags right away.
+    pub fn new(
+        event: &Event,
+        position: Point,
+        threshold: &DragThreshold,
+        grab: bool,
+    ) -> Option<Self> {
+        let is_touch = matches!(event, Event::Touch(_));
+
+        (threshold.distance(is_touch) > 0.0).then_some(Self {
+            position,
+            is_touch,
+            grab,
+        })
+    }
+
+    /// Returns whether the pointer moved past the `threshold` at `position`.
+    pub fn is_crossed(&self, position: Point, threshold: &DragThreshold) -> bool {
+        threshold.is_crossed(self.position.distance(position), self.is_touch)
+    }
+}
+
+/// A value springing back to the default of a virtual slider.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Spring {
+    /// The value when the widget was released.
+    from: Normal,
+    transition: Transition,
+}
+
+/// How the position of a dragged pointer moves the value.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Axis {
+    /// Follows one coordinate of the pointer.
+    #[cfg_attr(
+        not(any(
+            feature = "h_slider",
+            feature = "knob",
+            feature = "mod_range_input",
+            feature = "ramp",
+            feature = "v_slider"
+        )),
+        allow(dead_code)
+    )]
+    Linear {
+        /// Picks the coordinate to follow from the pointer position.
+        position: fn(Point) -> f32,
+        clamp: DragClamp,
+        /// How much the value moves down for every pixel the coordinate grows.
+        scalar: f32,
+    },
+    /// Follows the angle of the pointer around `center`, where turning it
+    /// clockwise by `span` radians moves the value over its whole range.
+    #[cfg(any(feature = "arc_slider", feature = "knob"))]
+    Circular { center: Point, span: f32 },
+}
+
+impl Axis {
+    /// Dragging up moves the value up by `scalar` for every pixel, no matter
+    /// the size of the widget.
+    #[cfg(any(feature = "knob", feature = "mod_range_input", feature = "ramp"))]
+    pub fn vertical(scalar: f32) -> Self {
+        Self::Linear {
+            position: |position| position.y,
+            clamp: DragClamp::None,
+            scalar,
+        }
+    }
+
+    /// Dragging right over the whole width of `bounds` moves the value up by
+    /// `scalar`, or down if `inverse`. Returns `None` if `bounds` has no
+    /// width.
+    #[cfg(feature = "h_slider")]
+    pub fn across_width(bounds: &Rectangle, scalar: f32, inverse: bool) -> Option<Self> {
+        (bounds.width > 0.0).then(|| Self::Linear {
+            position: |position| position.x,
+            clamp: DragClamp::Range {
+                min: bounds.x,
+                max: bounds.x + bounds.width,
+            },
+            scalar: if inverse { scalar } else { -scalar } / bounds.width,
+        })
+    }
+
+    /// Dragging up over the whole height of `bounds` moves the value up by
+    /// `scalar`, or down if `inverse`. Returns `None` if `bounds` has no
+    /// height.
+    #[cfg(feature = "v_slider")]
+    pub fn across_height(bounds: &Rectangle, scalar: f32, inverse: bool) -> Option<Self> {
+        (bounds.height > 0.0).then(|| Self::Linear {
+            position: |position| position.y,
+            clamp: DragClamp::Range {
+                min: bounds.y,
+                max: bounds.y + bounds.height,
+            },
+            scalar: if inverse { -scalar } else { scalar } / bounds.height,
+        })
+    }
+}
+
+/// The parts of a widget that a virtual slider moves and reports to.
+pub(crate) struct VirtualSlider<'b, 'a, Message> {
+    pub normal_param: &'b mut NormalParam,
+    pub sensitivity: SliderSensitivity,
+    pub on_change: &'b dyn Fn(Change) -> Message,
+    pub on_grab: Option<&'b mut (dyn 'a + FnMut() -> Option<Message>)>,
+    pub on_release: Option<&'b mut (dyn 'a + FnMut() -> Option<Message>)>,
+    /// Snaps the value to this many equal steps, if any.
+    pub steps: Option<u32>,
+    /// The modifier keys that bypass `steps` while held.
+    pub free_modifier_keys: keyboard::Modifiers,
+    /// The mouse button that drags with the fine `modifier_scalar` of the
+    /// sensitivity, without holding its modifier keys.
+    pub fine_drag_button: Option<mouse::Button>,
+    /// When two presses count as a double click, which resets the value.
+    pub double_click: DoubleClick,
+    /// How far the pointer moves from a press before it drags the value.
+    pub drag_threshold: DragThreshold,
+    /// The tick marks that stick while the value is dragged along a linear
+    /// axis, and how much.
+    pub detents: Option<(&'b tick_marks::Group, Detents)>,
+    /// Produces a message when the first characters of a value are typed
+    /// while the widget is focused.
+    pub on_type_in: Option<&'b dyn Fn(String) -> Message>,
+    /// The sub-range that is shown. Drags and unstepped scrolls move the
+    /// value across the window instead of the whole range, and the value is
+    /// kept in the window while it is moved.
+    pub view_window: ViewWindow,
+    /// Whether the widget shows its value in the inverse direction, in
+    /// which case scrolling up moves the value down.
+    pub inverse: bool,
+    /// The payload of a drag and drop of the application, and the function
+    /// that produces a message when it is dropped on the widget.
+    pub on_drop: Option<(u64, &'b dyn Fn(u64) -> Message)>,
+    /// The selection group of the widget and its id in the group. When the
+    /// widget is a member, moving it produces the message of
+    /// `on_group_change` with the values of all members instead of
+    /// `on_change`.
+    pub group: Option<(&'b SelectionGroup, u64)>,
+    pub on_group_change: Option<&'b dyn Fn(ParamSnapshot<u64>) -> Message>,
+    /// Whether wheel events over the widget are captured, so a scrollable
+    /// parent doesn't scroll while the value moves. Wheel events are always
+    /// captured while the widget is dragged.
+    pub capture_wheel: bool,
+    /// How much the value moves per line scrolled to the right, where `0.0`
+    /// ignores horizontal scrolling.
+    pub horizontal_wheel_scalar: f32,
+    /// How long the value takes to spring back to the default when the
+    /// widget is released after a drag, if it does.
+    pub spring_back: Option<Duration>,
+    /// How long the wheel has to be idle to end a gesture of wheel steps,
+    /// if they are grouped. Otherwise every wheel step is a gesture.
+    pub wheel_gesture: Option<Duration>,
+    /// The shape of the pointer hit area within its bounds.
+    pub hit_shape: HitShape,
+}
+
+impl<'b, 'a, Message> VirtualSlider<'b, 'a, Message> {
+    /// Returns the number of steps to snap to, unless there are none or the
+    /// free modifier keys are held.
+    fn active_steps(&self, state: &State) -> Option<u32> {
+        let is_free = !self.free_modifier_keys.is_empty()
+            && state.pressed_modifiers.contains(self.free_modifier_keys);
+
+        self.steps.filter(|&steps| steps > 0 && !is_free)
+    }
+
+    /// Moves the value down by `normal_delta`, which is scaled down while
+    /// the modifier keys of the sensitivity are held.
+    ///
+    /// With steps, the value only moves once the unsnapped value crosses
+    /// into another step.
+    pub fn move_by(&mut self, state: &mut State, normal_delta: f32) -> SliderStatus {
+        if normal_delta.abs() < f32::EPSILON {
+            return SliderStatus::Unchanged;
+        }
+
+        let normal_delta = self.scaled(state, normal_delta);
+
+        self.move_scaled(state, normal_delta)
+    }
+
+    /// Scales `normal_delta` down while the modifier keys of the sensitivity
+    /// are held, or while dragging with the fine drag button.
+    fn scaled(&self, state: &State, normal_delta: f32) -> f32 {
+        let is_fine_drag =
+            self.fine_drag_button.is_some() && state.drag_button == self.fine_drag_button;
+
+        let held = self
+            .sensitivity
+            .held_modifier_scalar(state.pressed_modifiers);
+
+        match held {
+            Some(scalar) => normal_delta * scalar,
+            None if is_fine_drag => normal_delta * self.sensitivity.modifier_scalar,
+            None => normal_delta,
+        }
+    }
+
+    /// Moves the value down by an already scaled `normal_delta`.
+    fn move_scaled(&mut self, state: &mut State, normal_delta: f32) -> SliderStatus {
+        if normal_delta.abs() < f32::EPSILON {
+            return SliderStatus::Unchanged;
+        }
+
+        let continuous = (state.continuous_normal - f64::from(normal_delta)).clamp(
+            self.view_window.start.as_f64(),
+            self.view_window.end.as_f64(),
+        );
+        state.continuous_normal = continuous;
+
+        match self.active_steps(state) {
+            Some(steps) => {
+                let steps = f64::from(steps);
+                let snapped = Normal::from_f64((continuous * steps).round() / steps);
+
+                if snapped == self.normal_param.value {
+                    return SliderStatus::Unchanged;
+                }
+
+                self.normal_param.value = snapped;
+            }
+            None => self.normal_param.value = Normal::from_f64(continuous),
+        }
+
+        SliderStatus::Moved
+    }
+
+    /// Moves the value along `axis` to the pointer `position`.
+    fn drag_to(&mut self, state: &mut State, axis: Axis, position: Point) -> SliderStatus {
+        match axis {
+            Axis::Linear {
+                position: coordinate,
+                clamp,
+                scalar,
+            } => {
+                let span = self.view_window.span();
+                let normal_delta = state.drag.moved_to(coordinate(position), clamp) * scalar * span;
+
+                if normal_delta.abs() < f32::EPSILON {
+                    return SliderStatus::Unchanged;
+                }
+
+                let mut normal_delta = self.scaled(state, normal_delta);
+
+                if let Some((group, detents)) = self.detents {
+                    let positions: Vec<f32> = group
+                        .iter()
+                        .map(|(position, _)| position)
+                        .filter(|position| self.view_window.contains(*position))
+                        .map(|position| position.as_f32())
+                        .collect();
+
+                    let from = state.continuous_normal as f32;
+                    let to = state.detent_drag.moved(
+                        &detents.scaled(span),
+                        positions.into_iter(),
+                        from,
+                        -normal_delta,
+                        Instant::now(),
+                    );
+
+                    normal_delta = from - to;
+                }
+
+                self.move_scaled(state, normal_delta)
+            }
+            #[cfg(any(feature = "arc_slider", feature = "knob"))]
+            Axis::Circular { center, span } => {
+                let turned = state.circular_drag.moved_to(center, position, span);
+
+                // The value may overshoot its ends by up to the gap of the
+                // knob, so that it does not jump while the gap is crossed.
+                let gap = (crate::core::math::TWO_PI - span).max(0.0) / span;
+
+                let change = state.circular_drag.limit(
+                    state.continuous_normal as f32,
+                    self.scaled(state, turned),
+                    gap,
+                );
+
+                self.move_scaled(state, -change)
+            }
+        }
+    }
+
+    /// Adds `delta` to the scroll steps of `state`, returning the number of
+    /// whole steps to move by and the same steps scaled by the wheel scalars,
+    /// or `None` if it doesn't scroll along an axis with a wheel scalar.
+    fn wheel_steps(&self, state: &mut State, delta: mouse::ScrollDelta) -> Option<(f32, f32)> {
+        let pixels_per_step = self.sensitivity.pixels_per_step;
+
+        let vertical = (self.sensitivity.wheel_scalar != 0.0)
+            .then(|| state.scroll.add(delta, pixels_per_step))
+            .flatten()
+            .map(|steps| (steps, steps * self.sensitivity.wheel_scalar));
+        let horizontal = (self.horizontal_wheel_scalar != 0.0)
+            .then(|| state.scroll.add_horizontal(delta, pixels_per_step))
+            .flatten()
+            .map(|steps| (steps, steps * self.horizontal_wheel_scalar));
+
+        match (vertical, horizontal) {
+            (Some(vertical), Some(horizontal)) => {
+                Some((vertical.0 + horizontal.0, vertical.1 + horizontal.1))
+            }
+            (vertical, horizontal) => vertical.or(horizontal),
+        }
+    }
+
+    /// Returns the mouse button of a press that starts a drag, where touches
+    /// count as the left button.
+    fn pressed_button(&self, event: &Event) -> Option<mouse::Button> {
+        match *event {
+            Event::Mouse(mouse::Event::ButtonPressed(button))
+                if button == mouse::Button::Left || Some(button) == self.fine_drag_button =>
+            {
+                Some(button)
+            }
+            Event::Touch(touch::Event::FingerPressed { .. }) => Some(mouse::Button::Left),
+            _ => None,
+        }
+    }
+
+    fn maybe_fire_on_grab(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
+            shell.publish(message);
+        }
+    }
+
+    fn fire_on_change(
+        &self,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+        previous: Normal,
+        via: ChangeSource,
+    ) {
+        if let (Some((group, id)), Some(on_group_change)) = (self.group, self.on_group_change) {
+            if group.contains(id) {
+                let start = state.group_start.get_or_insert_with(|| group.clone());
+
+                shell.publish(on_group_change(start.moved(id, self.normal_param.value)));
+
+                return;
+            }
+        }
+
+        shell.publish((self.on_change)(Change {
+            normal: self.normal_param.value,
+            previous,
+            via,
+        }));
+    }
+
+    fn maybe_fire_on_release(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(message) = self.on_release.as_mut().and_then(|on_release| on_release()) {
+            shell.publish(message);
+        }
+    }
+
+    /// Moves the value to the text mark under a left press, if the press is
+    /// on one of the text marks of `state`.
+    ///
+    /// The text marks are usually outside of the hit area of the widget, so
+    /// this is handled before [`on_event`].
+    ///
+    /// [`on_event`]: #method.on_event
+    #[cfg(any(feature = "h_slider", feature = "v_slider"))]
+    pub fn on_mark_click(
+        &mut self,
+        state: &mut State,
+        event: &Event,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if self.pressed_button(event) != Some(mouse::Button::Left)
+            || input::is_press_claimed(event, &state.press_claim)
+        {
+            return event::Status::Ignored;
+        }
+
+        let Some(position) = input::event_cursor(event, cursor).position() else {
+            return event::Status::Ignored;
+        };
+
+        let mark = state
+            .text_mark_bounds
+            .borrow()
+            .iter()
+            .find(|(_, bounds)| bounds.contains(position))
+            .map(|(normal, _)| *normal);
+
+        let Some(mark) = mark else {
+            return event::Status::Ignored;
+        };
+
+        input::claim_press(event, &state.press_claim);
+
+        let normal = self.view_window.from_view(mark);
+
+        if normal != self.normal_param.value && state.dragging_status.is_none() {
+            if state.wheel_gesture.end() {
+                self.maybe_fire_on_release(shell);
+            }
+
+            self.maybe_fire_on_grab(shell);
+
+            let previous = self.normal_param.value;
+
+            self.normal_param.value = normal;
+            state.prev_normal = normal;
+            state.continuous_normal = normal.as_f64();
+
+            self.fire_on_change(state, shell, previous, ChangeSource::MarkClick);
+
+            self.maybe_fire_on_release(shell);
+        }
+
+        event::Status::Captured
+    }
+
+    /// Handles an event of a widget whose pointer hit area is `hit_bounds`.
+    ///
+    /// A drag moves the value along `axis`, and is ignored if there is none.
+    #[allow(clippy::too_many_arguments)]
+    pub fn on_event(
+        &mut self,
+        state: &mut State,
+        event: &Event,
+        cursor: mouse::Cursor,
+        hit_bounds: Rectangle,
+        axis: Option<Axis>,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let cursor = input::event_cursor(event, cursor);
+        let pointer = Pointer::of(event);
+
+        let is_over = cursor
+            .position()
+            .is_some_and(|position| self.hit_shape.contains(hit_bounds, position));
+        let pressed_button = self.pressed_button(event);
+
+        let is_claimed = input::is_press_claimed(event, &state.press_claim);
+
+        if pressed_button.is_some() {
+            state.is_focused = is_over && !is_claimed;
+            state.focus_visible = false;
+        }
+
+        let released_button = match *event {
+            Event::Mouse(mouse::Event::ButtonReleased(button)) => Some(button),
+            Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                Some(mouse::Button::Left)
+            }
+            _ => None,
+        };
+
+        // A group is only moved from where it was grabbed while the widget
+        // is dragged, springs back or is scrolled.
+        if state.dragging_status.is_none()
+            && state.spring.is_none()
+            && !state.wheel_gesture.is_active()
+        {
+            state.group_start = None;
+        }
+
+        // Update state after a discontinuity
+        if state.dragging_status.is_none() && state.prev_normal != self.normal_param.value {
+            state.prev_normal = self.normal_param.value;
+            state.continuous_normal = self.normal_param.value.as_f64();
+        }
+
+        match *event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(spring) = state.spring.as_mut() {
+                    let progress = spring.transition.progress(now, shell);
+                    let from = spring.from.as_f32();
+                    let default = self.normal_param.default.as_f32();
+
+                    let normal = Normal::from_clipped(from + (default - from) * progress);
+
+                    if progress >= 1.0 {
+                        state.spring = None;
+                    }
+
+                    let previous = self.normal_param.value;
+
+                    if normal != previous {
+                        self.normal_param.value = normal;
+                        state.prev_normal = normal;
+                        state.continuous_normal = normal.as_f64();
+
+                        self.fire_on_change(state, shell, previous, ChangeSource::SpringBack);
+                    }
+
+                    if state.spring.is_none() {
+                        self.maybe_fire_on_release(shell);
+                    }
+                }
+
+                if state.wheel_gesture.redraw(now, shell) {
+                    self.maybe_fire_on_release(shell);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.dragging_status.is_some() && state.drag_pointer == pointer =>
+            {
+                if let Some(axis) = axis {
+                    if let Some(pending) = state.pending_drag {
+                        if !pending.is_crossed(position, &self.drag_threshold) {
+                            return event::Status::Captured;
+                        }
+
+                        state.pending_drag = None;
+
+                        if pending.grab {
+                            self.maybe_fire_on_grab(shell);
+                        }
+                    }
+
+                    let previous = self.normal_param.value;
+
+                    if self.drag_to(state, axis, position).was_moved() {
+                        self.fire_on_change(state, shell, previous, ChangeSource::Drag);
+
+                        state
+                            .dragging_status
+                            .as_mut()
+                            .expect("dragging_status taken")
+                            .moved();
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                // A scrollable parent must not move the widget away from
+                // under the pointer that drags it.
+                let status = if state.dragging_status.is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                };
+
+                if !self.capture_wheel {
+                    return status;
+                }
+
+                // The wheel doesn't fight a value that springs back.
+                if state.spring.is_some() {
+                    return if is_over {
+                        event::Status::Captured
+                    } else {
+                        status
+                    };
+                }
+
+                if is_over {
+                    if let Some((steps, scaled_steps)) = self.wheel_steps(state, delta) {
+                        // A stepped widget moves one whole step per wheel step.
+                        let (steps, scaled_steps) = if self.inverse {
+                            (-steps, -scaled_steps)
+                        } else {
+                            (steps, scaled_steps)
+                        };
+
+                        let previous = self.normal_param.value;
+
+                        let slider_status = match self.active_steps(state) {
+                            Some(value_steps) => {
+                                state.continuous_normal = self.normal_param.value.as_f64();
+
+                                // A fine modifier slows the wheel down, but
+                                // can't round a wheel step away.
+                                let step = 1.0 / value_steps as f32;
+                                let normal_delta = self.scaled(state, -steps * step);
+                                let normal_delta = if steps == 0.0 {
+                                    0.0
+                                } else {
+                                    normal_delta.abs().max(step).copysign(-steps)
+                                };
+
+                                self.move_scaled(state, normal_delta)
+                            }
+                            None => self.move_by(state, -scaled_steps * self.view_window.span()),
+                        };
+
+                        if slider_status.was_moved() {
+                            // Grouped wheel steps are released once the
+                            // wheel is idle.
+                            let starts_gesture = match self.wheel_gesture {
+                                // A press that waits for its drag grabs now.
+                                _ if state.dragging_status.is_some() => state
+                                    .pending_drag
+                                    .as_mut()
+                                    .is_some_and(|pending| std::mem::take(&mut pending.grab)),
+                                Some(timeout) => state.wheel_gesture.step(timeout, shell),
+                                None => true,
+                            };
+
+                            if starts_gesture {
+                                self.maybe_fire_on_grab(shell);
+                            }
+
+                            self.fire_on_change(state, shell, previous, ChangeSource::Wheel);
+
+                            if let Some(slider_status) = state.dragging_status.as_mut() {
+                                // Widget was grabbed => keep it grabbed
+                                slider_status.moved();
+                            } else if self.wheel_gesture.is_none() {
+                                self.maybe_fire_on_release(shell);
+                            }
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                return status;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if pressed_button.is_some()
+                    && is_over
+                    && (state.drag_pointer.is_none() || state.drag_pointer == pointer)
+                    && !is_claimed =>
+            {
+                input::claim_press(event, &state.press_claim);
+
+                // A press ends the gesture of the wheel before its own.
+                if state.wheel_gesture.end() {
+                    self.maybe_fire_on_release(shell);
+                }
+
+                // Grabbing a value that springs back stops it where it is,
+                // and continues its gesture.
+                let was_springing = state.spring.take().is_some();
+
+                let cursor_position = cursor.position().unwrap();
+                let button = pressed_button.expect("pressed_button checked");
+
+                let click = Click::new(
+                    event,
+                    cursor_position,
+                    button,
+                    state.last_click,
+                    &self.double_click,
+                );
+
+                // Only the left button resets with a double click.
+                let kind = if button == mouse::Button::Left {
+                    click.kind()
+                } else {
+                    mouse::click::Kind::Single
+                };
+
+                match kind {
+                    mouse::click::Kind::Single => {
+                        // The grab waits until the press drags, or is
+                        // released.
+                        state.pending_drag = PendingDrag::new(
+                            event,
+                            cursor_position,
+                            &self.drag_threshold,
+                            !was_springing,
+                        );
+
+                        if !was_springing && state.pending_drag.is_none() {
+                            self.maybe_fire_on_grab(shell);
+                        }
+
+                        state.dragging_status = Some(Default::default());
+                        state.drag_pointer = pointer;
+                        state.drag_button = Some(button);
+
+                        match axis {
+                            Some(Axis::Linear {
+                                position, clamp, ..
+                            }) => {
+                                state.drag.start(position(cursor_position), clamp);
+                                state.detent_drag.start(Instant::now());
+                            }
+                            #[cfg(any(feature = "arc_slider", feature = "knob"))]
+                            Some(Axis::Circular { center, .. }) => {
+                                state.circular_drag.start(center, cursor_position)
+                            }
+                            None => {}
+                        }
+                    }
+                    _ => {
+                        // Reset to default
+
+                        let prev_dragging_status = state.dragging_status.take();
+                        state.drag_pointer = None;
+                        state.drag_button = None;
+                        state.pending_drag = None;
+
+                        let was_grabbed = prev_dragging_status.is_some() || was_springing;
+
+                        if self.normal_param.value != self.normal_param.default {
+                            if !was_grabbed {
+                                self.maybe_fire_on_grab(shell);
+                            }
+
+                            let previous = self.normal_param.value;
+
+                            self.normal_param.value = self.normal_param.default;
+
+                            self.fire_on_change(state, shell, previous, ChangeSource::Reset);
+
+                            self.maybe_fire_on_release(shell);
+                        } else if was_grabbed {
+                            self.maybe_fire_on_release(shell);
+                        }
+                    }
+                }
+
+                state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_over && state.drag_pointer.is_some() && state.drag_pointer != pointer =>
+            {
+                // Another pointer drags the widget, so the press must not
+                // reach what is underneath it either.
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if is_over && state.dragging_status.is_none() && self.on_drop.is_some() =>
+            {
+                if let Some((payload, on_drop)) = self.on_drop {
+                    shell.publish(on_drop(payload));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(_))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag_pointer == pointer
+                    && (state.drag_button.is_none() || released_button == state.drag_button) =>
+            {
+                state.drag_pointer = None;
+                state.drag_button = None;
+
+                // A press that never dragged still grabs before it releases.
+                if state
+                    .pending_drag
+                    .take()
+                    .is_some_and(|pending| pending.grab)
+                {
+                    self.maybe_fire_on_grab(shell);
+                }
+
+                if let Some(slider_status) = state.dragging_status.take() {
+                    // The release message waits until the value is back at
+                    // the default.
+                    if let Some(duration) = self
+                        .spring_back
+                        .filter(|_| self.normal_param.value != self.normal_param.default)
+                    {
+                        state.spring = Some(Spring {
+                            from: self.normal_param.value,
+                            transition: Transition::start(duration, shell),
+                        });
+
+                        return event::Status::Captured;
+                    }
+
+                    if self.on_grab.is_some() || slider_status.was_moved() {
+                        // maybe fire on release if `on_grab` is defined
+                        // so as to terminate the action, regardless of the actual user movement.
+                        self.maybe_fire_on_release(shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                ref text,
+                modifiers,
+                ..
+            }) if state.is_focused && !modifiers.command() && !modifiers.alt() => {
+                // Only the start of a value is captured, so other keys still
+                // reach shortcuts and other widgets.
+                if let (Some(on_type_in), Some(text)) = (self.on_type_in, text.as_deref()) {
+                    if starts_value(text) {
+                        shell.publish(on_type_in(text.to_owned()));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                // Only observe the modifiers, so key events still reach
+                // other widgets such as text inputs.
+                state.pressed_modifiers = modifiers;
+
+                // Continue from the shown value when steps are toggled.
+                state.continuous_normal = self.normal_param.value.as_f64();
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+}
+
+/// Whether typing `text` starts entering a value, i.e. it is a digit, a
+/// minus sign, or a decimal point.
+fn starts_value(text: &str) -> bool {
+    let mut chars = text.chars();
+
+    matches!(
+        (chars.next(), chars.next()),
+        (Some('0'..='9' | '-' | '.'), None)
+    )
+}
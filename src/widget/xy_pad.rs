@@ -3,22 +3,50 @@
 //!
 //! [`NormalParam`]: ../core/normal_param/struct.NormalParam.html
 
-use crate::core::{Normal, NormalParam, SliderStatus};
+#[cfg(feature = "a11y")]
+use crate::a11y::{self, accesskit};
+use crate::{
+    core::{
+        sensitivity::DEFAULT_PIXELS_PER_STEP, DoubleClick, ModifierMatch, Normal, NormalParam,
+        Param, SliderStatus,
+    },
+    defaults,
+    operation::{DragState, HandleState},
+    style::Dim,
+    widget::{focus_ring, input},
+};
 use iced::{
     advanced::{
         graphics::core::{event, keyboard, touch},
-        layout, mouse,
+        image, layout, mouse,
         renderer::{Quad, Style},
-        widget::{tree, Tree},
-        Clipboard, Layout, Renderer as _, Shell, Widget,
+        widget::{operation::Focusable, tree, Id, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
     },
     border::Radius,
-    Border, Color, Element, Event, Length, Rectangle, Renderer, Shadow, Size,
+    time::Duration,
+    window, Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size,
 };
 
-pub use crate::style::xy_pad::{Appearance, HandleCircle, HandleShape, HandleSquare, StyleSheet};
+pub use crate::style::xy_pad::{
+    Appearance, Catalog, HandleCircle, HandleShape, HandleSquare, HandleTexture, Status, StyleFn,
+    StyleSheet,
+};
+
+/// The default `modifier_scalar` of the [`XYPad`].
+///
+/// [`XYPad`]: struct.XYPad.html
+pub const DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+
+/// The default `wheel_scalar_x` and `wheel_scalar_y` of the [`XYPad`].
+///
+/// [`XYPad`]: struct.XYPad.html
+pub const DEFAULT_WHEEL_SCALAR: f32 = 0.01;
 
-static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+/// The default `grab_radius` of the [`XYPad`], in logical pixels.
+///
+/// [`XYPad`]: struct.XYPad.html
+pub const DEFAULT_GRAB_RADIUS: f32 = 12.0;
 
 /// A 2D XY pad GUI widget that controls two [`NormalParam`] parameters at
 /// once. One in the `x` coordinate and one in the `y` coordinate.
@@ -31,7 +59,7 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 #[allow(missing_debug_implementations)]
 pub struct XYPad<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     normal_param_x: NormalParam,
     normal_param_y: NormalParam,
@@ -40,13 +68,34 @@ where
     on_release: Option<Box<dyn 'a + FnMut() -> Option<Message>>>,
     modifier_scalar: f32,
     modifier_keys: keyboard::Modifiers,
+    modifier_match: ModifierMatch,
+    wheel_scalar_x: f32,
+    wheel_scalar_y: f32,
+    pixels_per_step: f32,
+    capture_wheel: bool,
+    double_click: DoubleClick,
+    wheel_gesture: Option<Duration>,
+    epsilon: f32,
+    deadzone_x: f32,
+    deadzone_y: f32,
+    jump_to_press: bool,
+    grab_radius: f32,
     size: Length,
-    style: <Theme as StyleSheet>::Style,
+    id: Option<Id>,
+    #[cfg(feature = "a11y")]
+    name: Option<String>,
+    #[cfg(feature = "a11y")]
+    description: Option<String>,
+    #[cfg(feature = "a11y")]
+    announced_value: Option<Box<dyn 'a + Fn(Normal) -> String>>,
+    class: Theme::Class<'a>,
+    opacity: f32,
+    scale_factor: f32,
 }
 
 impl<'a, Message, Theme> XYPad<'a, Message, Theme>
 where
-    Theme: StyleSheet,
+    Theme: Catalog,
 {
     /// Creates a new [`XYPad`].
     ///
@@ -66,13 +115,59 @@ where
             on_change: Box::new(on_change),
             on_grab: None,
             on_release: None,
-            modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            modifier_scalar: defaults::get().xy_pad_modifier_scalar,
             modifier_keys: keyboard::Modifiers::CTRL,
+            modifier_match: ModifierMatch::Contains,
+            wheel_scalar_x: DEFAULT_WHEEL_SCALAR,
+            wheel_scalar_y: DEFAULT_WHEEL_SCALAR,
+            pixels_per_step: DEFAULT_PIXELS_PER_STEP,
+            capture_wheel: true,
+            double_click: defaults::get().double_click,
+            wheel_gesture: defaults::get().wheel_gesture,
+            epsilon: 0.0,
+            deadzone_x: 0.0,
+            deadzone_y: 0.0,
+            jump_to_press: true,
+            grab_radius: DEFAULT_GRAB_RADIUS,
             size: Length::Fill,
-            style: Default::default(),
+            id: None,
+            #[cfg(feature = "a11y")]
+            name: None,
+            #[cfg(feature = "a11y")]
+            description: None,
+            #[cfg(feature = "a11y")]
+            announced_value: None,
+            class: Theme::default(),
+            opacity: 1.0,
+            scale_factor: 1.0,
         }
     }
 
+    /// Creates a new [`XYPad`] from two [`Param`]s.
+    ///
+    /// Unlike `XYPad::new()`, `on_change` is called with the (snapped) values
+    /// of the parameters instead of [`Normal`]s.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Param`]: ../../core/param/trait.Param.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn from_params<X, Y, F>(param_x: X, param_y: Y, on_change: F) -> Self
+    where
+        X: 'a + Param,
+        Y: 'a + Param,
+        F: 'a + Fn(X::Value, Y::Value) -> Message,
+    {
+        let normal_param_x = param_x.normal_param();
+        let normal_param_y = param_y.normal_param();
+
+        Self::new(normal_param_x, normal_param_y, move |normal_x, normal_y| {
+            on_change(
+                param_x.unmap_to_value(normal_x),
+                param_y.unmap_to_value(normal_y),
+            )
+        })
+    }
+
     /// Sets the grab message of the [`XYPad`].
     /// This is called when the mouse grabs from the xy pad.
     ///
@@ -103,11 +198,93 @@ where
         self
     }
 
-    /// Sets the style of the [`XYPad`].
+    /// Sets the [`Id`] of the [`XYPad`], e.g. to check whether it is being
+    /// dragged with [`operation::is_dragging()`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Id`]: https://docs.rs/iced/0.13/iced/advanced/widget/struct.Id.html
+    /// [`operation::is_dragging()`]: ../../operation/fn.is_dragging.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the name of the [`XYPad`] that screen readers announce, e.g. the
+    /// name of the parameter it controls.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    #[cfg(feature = "a11y")]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description of the [`XYPad`] that screen readers announce
+    /// after its name.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    #[cfg(feature = "a11y")]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets how screen readers announce the values of the [`XYPad`], e.g. as
+    /// `"440 Hz"` for the mapped values of its parameters, instead of the raw
+    /// [`Normal`]s. Both axes are formatted by `format`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    #[cfg(feature = "a11y")]
+    pub fn announced_value(mut self, format: impl 'a + Fn(Normal) -> String) -> Self {
+        self.announced_value = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the style of the [`XYPad`] with a function of the theme and the
+    /// [`Status`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Status`]: ../../style/enum.Status.html
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Appearance + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`XYPad`], e.g. a custom [`StyleSheet`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`StyleSheet`]: ../../style/xy_pad/trait.StyleSheet.html
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the opacity of the [`XYPad`] from `0.0` to `1.0`, which
+    /// multiplies the alpha of all its colors, text, and textures, e.g. to
+    /// dim the pad of a bypassed effect. The default is `1.0`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the scale factor of the window the [`XYPad`] is drawn in, which
+    /// picks the version of the [`TextureHandle`]s of its style to draw. The
+    /// default is `1.0`.
+    ///
+    /// Values that aren't positive are ignored.
     ///
     /// [`XYPad`]: struct.XYPad.html
-    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
-        self.style = style.into();
+    /// [`TextureHandle`]: ../../style/texture/struct.TextureHandle.html
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        if scale_factor > 0.0 {
+            self.scale_factor = scale_factor;
+        }
         self
     }
 
@@ -121,6 +298,19 @@ where
         self
     }
 
+    /// Sets how the held modifier keys have to match the modifier keys of
+    /// the [`XYPad`].
+    ///
+    /// The default is [`ModifierMatch::Contains`], so holding other keys as
+    /// well still switches to the modifier scalar.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`ModifierMatch::Contains`]: ../core/sensitivity/enum.ModifierMatch.html#variant.Contains
+    pub fn modifier_match(mut self, modifier_match: ModifierMatch) -> Self {
+        self.modifier_match = modifier_match;
+        self
+    }
+
     /// Sets the scalar to use when the user drags the slider while holding down
     /// the modifier key.
     ///
@@ -135,6 +325,232 @@ where
         self
     }
 
+    /// Sets how much the [`Normal`] value of the x axis of the [`XYPad`]
+    /// changes per line scrolled to the right, e.g. with a horizontal swipe
+    /// on a touchpad. Scrolling to the left moves it down.
+    ///
+    /// This can be set to `0.0` to disable horizontal scrolling. The default
+    /// value is `0.01`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar_x(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar_x = wheel_scalar;
+        self
+    }
+
+    /// Sets how much the [`Normal`] value of the y axis of the [`XYPad`]
+    /// changes per line scrolled up by the mouse wheel.
+    ///
+    /// This can be set to `0.0` to disable vertical scrolling. The default
+    /// value is `0.01`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn wheel_scalar_y(mut self, wheel_scalar: f32) -> Self {
+        self.wheel_scalar_y = wheel_scalar;
+        self
+    }
+
+    /// Sets how many pixels a pixel based scroll delta (e.g. from a
+    /// touchpad) has to add up to before an axis of the [`XYPad`] moves by
+    /// one wheel step.
+    ///
+    /// The default value is [`DEFAULT_PIXELS_PER_STEP`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`DEFAULT_PIXELS_PER_STEP`]: ../../core/sensitivity/constant.DEFAULT_PIXELS_PER_STEP.html
+    pub fn pixels_per_step(mut self, pixels_per_step: f32) -> Self {
+        self.pixels_per_step = pixels_per_step;
+        self
+    }
+
+    /// Sets whether the [`XYPad`] captures the wheel events that move it,
+    /// so a scrollable parent doesn't scroll at the same time. With
+    /// `false`, the wheel scrolls the parent instead of moving the
+    /// [`XYPad`].
+    ///
+    /// Wheel events are always captured while the [`XYPad`] is dragged.
+    /// The default value is `true`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn capture_wheel(mut self, capture_wheel: bool) -> Self {
+        self.capture_wheel = capture_wheel;
+        self
+    }
+
+    /// Sets when two presses of the [`XYPad`] count as a double click, which
+    /// resets it to its default value.
+    ///
+    /// The default is the `double_click` of the [`Defaults`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn double_click(mut self, double_click: DoubleClick) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
+    /// Groups consecutive wheel steps of the [`XYPad`] into one gesture, which
+    /// fires `on_grab` at the first step and `on_release` once the wheel is
+    /// idle for `idle_timeout`, e.g. for hosts that create an undo entry per
+    /// gesture. With `None`, every wheel step fires both.
+    ///
+    /// The default is the `wheel_gesture` of the [`Defaults`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Defaults`]: ../defaults/struct.Defaults.html
+    pub fn wheel_gesture(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.wheel_gesture = idle_timeout;
+        self
+    }
+
+    /// Sets how much an axis of the [`XYPad`] has to move before it
+    /// changes and a message is produced. A message is only produced when
+    /// at least one axis changed, and the other axis keeps its value unless
+    /// it also moved by more than `epsilon`.
+    ///
+    /// This avoids floods of messages for movements that are too small to
+    /// matter. The ends and the defaults of the axes are always reached.
+    /// The default is `0.0`, so only movements that leave both values as
+    /// they are produce no message.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn epsilon(mut self, epsilon: f32) -> Self {
+        self.epsilon = epsilon.abs();
+        self
+    }
+
+    /// Sets how close to its default the x axis of the [`XYPad`] snaps to
+    /// the default, as a distance in [`Normal`] units. A drag moves on
+    /// unsnapped once it leaves the deadzone. There is no deadzone by
+    /// default.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn deadzone_x(mut self, deadzone: f32) -> Self {
+        self.deadzone_x = deadzone.abs();
+        self
+    }
+
+    /// Sets how close to its default the y axis of the [`XYPad`] snaps to
+    /// the default, as a distance in [`Normal`] units. A drag moves on
+    /// unsnapped once it leaves the deadzone. There is no deadzone by
+    /// default.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn deadzone_y(mut self, deadzone: f32) -> Self {
+        self.deadzone_y = deadzone.abs();
+        self
+    }
+
+    /// Sets whether a press of the [`XYPad`] moves the handle to the
+    /// pointer. The default is `true`.
+    ///
+    /// With `false`, a click leaves the values as they are, and only a press
+    /// within the `grab_radius` of the handle drags it, from where it is.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn jump_to_press(mut self, jump_to_press: bool) -> Self {
+        self.jump_to_press = jump_to_press;
+        self
+    }
+
+    /// Sets how far from the center of the handle of the [`XYPad`] a press
+    /// grabs it, in logical pixels, when it doesn't `jump_to_press`. The
+    /// default is [`DEFAULT_GRAB_RADIUS`].
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`DEFAULT_GRAB_RADIUS`]: constant.DEFAULT_GRAB_RADIUS.html
+    pub fn grab_radius(mut self, grab_radius: f32) -> Self {
+        self.grab_radius = grab_radius.abs();
+        self
+    }
+
+    /// Moves the values to the continuous values of `state`, snapped to
+    /// their defaults inside of the deadzones. Returns whether an axis
+    /// changed.
+    fn move_to_continuous(&mut self, state: &State) -> bool {
+        let moved_x = move_axis(
+            &mut self.normal_param_x,
+            state.continuous_normal_x,
+            self.deadzone_x,
+            self.epsilon,
+        );
+        let moved_y = move_axis(
+            &mut self.normal_param_y,
+            state.continuous_normal_y,
+            self.deadzone_y,
+            self.epsilon,
+        );
+
+        moved_x || moved_y
+    }
+
+    /// Moves the axes by the steps scrolled by `delta`, returning `None` if
+    /// it doesn't scroll along an axis with a wheel scalar, or else whether
+    /// an axis changed.
+    fn scroll_by(&mut self, state: &mut State, delta: mouse::ScrollDelta) -> Option<bool> {
+        let steps_x = (self.wheel_scalar_x != 0.0)
+            .then(|| state.scroll.add_horizontal(delta, self.pixels_per_step))
+            .flatten();
+        let steps_y = (self.wheel_scalar_y != 0.0)
+            .then(|| state.scroll.add(delta, self.pixels_per_step))
+            .flatten();
+
+        if steps_x.is_none() && steps_y.is_none() {
+            return None;
+        }
+
+        let scalar = if self
+            .modifier_match
+            .matches(state.pressed_modifiers, self.modifier_keys)
+        {
+            self.modifier_scalar
+        } else {
+            1.0
+        };
+
+        // Continue from the unsnapped values of the last wheel step, unless
+        // the values changed since.
+        if state.wheel_values != Some((self.normal_param_x.value, self.normal_param_y.value)) {
+            state.continuous_normal_x = self.normal_param_x.value.as_f32();
+            state.continuous_normal_y = self.normal_param_y.value.as_f32();
+        }
+
+        state.continuous_normal_x = (state.continuous_normal_x
+            + steps_x.unwrap_or(0.0) * self.wheel_scalar_x * scalar)
+            .clamp(0.0, 1.0);
+        state.continuous_normal_y = (state.continuous_normal_y
+            + steps_y.unwrap_or(0.0) * self.wheel_scalar_y * scalar)
+            .clamp(0.0, 1.0);
+
+        let moved = self.move_to_continuous(state);
+
+        state.wheel_values = Some((self.normal_param_x.value, self.normal_param_y.value));
+
+        Some(moved)
+    }
+
+    /// Starts a drag with `pointer` at `position`.
+    fn grab(
+        &mut self,
+        state: &mut State,
+        pointer: Option<input::Pointer>,
+        position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        self.maybe_fire_on_grab(shell);
+
+        state.dragging_status = Some(Default::default());
+        state.drag_pointer = pointer;
+        state.prev_drag_x = position.x;
+        state.prev_drag_y = position.y;
+        state.continuous_normal_x = self.normal_param_x.value.as_f32();
+        state.continuous_normal_y = self.normal_param_y.value.as_f32();
+    }
+
     fn maybe_fire_on_grab(&mut self, shell: &mut Shell<'_, Message>) {
         if let Some(message) = self.on_grab.as_mut().and_then(|on_grab| on_grab()) {
             shell.publish(message);
@@ -155,18 +571,70 @@ where
     }
 }
 
+/// Moves the value of `normal_param` to `continuous`, or to its default if
+/// it is within `deadzone` of it, unless that moves it by no more than
+/// `epsilon`. Returns whether the value changed.
+fn move_axis(normal_param: &mut NormalParam, continuous: f32, deadzone: f32, epsilon: f32) -> bool {
+    let default = normal_param.default;
+
+    let next = if deadzone > 0.0 && (continuous - default.as_f32()).abs() <= deadzone {
+        default
+    } else {
+        Normal::from_clipped(continuous)
+    };
+
+    if next == normal_param.value {
+        return false;
+    }
+
+    // The ends and the default are always reached, even when they are
+    // closer than `epsilon`.
+    let is_landmark = next == default || next == Normal::MIN || next == Normal::MAX;
+
+    if !is_landmark && (next.as_f32() - normal_param.value.as_f32()).abs() <= epsilon {
+        return false;
+    }
+
+    normal_param.value = next;
+
+    true
+}
+
+/// Returns the center of the handle of the values `normal_x` and
+/// `normal_y` in an [`XYPad`] with `bounds`.
+///
+/// [`XYPad`]: struct.XYPad.html
+fn handle_center(bounds: Rectangle, normal_x: Normal, normal_y: Normal) -> Point {
+    let bounds_size = bounds.width.min(bounds.height);
+
+    Point::new(
+        bounds.x + bounds_size * normal_x.as_f32(),
+        bounds.y + bounds_size * (1.0 - normal_y.as_f32()),
+    )
+}
+
 /// The local state of a [`XYPad`].
 ///
 /// [`XYPad`]: struct.XYPad.html
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 struct State {
     dragging_status: Option<SliderStatus>,
+    drag_pointer: Option<input::Pointer>,
+    press_claim: input::PressClaim,
     prev_drag_x: f32,
     prev_drag_y: f32,
     continuous_normal_x: f32,
     continuous_normal_y: f32,
     pressed_modifiers: keyboard::Modifiers,
-    last_click: Option<mouse::Click>,
+    last_click: Option<input::Click>,
+    is_focused: bool,
+    /// Whether the focus should be shown with a focus ring, which it is only
+    /// when it came from an operation rather than a click.
+    focus_visible: bool,
+    scroll: input::ScrollSteps,
+    /// The values after the last wheel step.
+    wheel_values: Option<(Normal, Normal)>,
+    wheel_gesture: input::WheelGesture,
 }
 
 impl State {
@@ -180,20 +648,44 @@ impl State {
     fn new(normal_x: Normal, normal_y: Normal) -> Self {
         Self {
             dragging_status: None,
+            drag_pointer: None,
+            press_claim: input::PressClaim::default(),
             prev_drag_x: 0.0,
             prev_drag_y: 0.0,
             continuous_normal_x: normal_x.as_f32(),
             continuous_normal_y: normal_y.as_f32(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            is_focused: false,
+            focus_visible: false,
+            scroll: input::ScrollSteps::default(),
+            wheel_values: None,
+            wheel_gesture: input::WheelGesture::default(),
         }
     }
 }
 
-impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for XYPad<'a, Message, Theme>
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.focus_visible = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.focus_visible = false;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for XYPad<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: StyleSheet,
+    Theme: Catalog,
+    Renderer: image::Renderer<Handle = image::Handle>,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -230,6 +722,69 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = state.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref());
+
+        operation.custom(
+            &mut DragState {
+                status: state.dragging_status,
+            },
+            self.id.as_ref(),
+        );
+
+        let bounds = layout.bounds();
+
+        operation.custom(
+            &mut HandleState {
+                center: Point::new(
+                    bounds.x + self.normal_param_x.value.scale(bounds.width),
+                    bounds.y + self.normal_param_y.value.scale_inv(bounds.height),
+                ),
+            },
+            self.id.as_ref(),
+        );
+
+        #[cfg(feature = "a11y")]
+        for (normal, axis, orientation) in [
+            (
+                self.normal_param_x.value,
+                "X",
+                accesskit::Orientation::Horizontal,
+            ),
+            (
+                self.normal_param_y.value,
+                "Y",
+                accesskit::Orientation::Vertical,
+            ),
+        ] {
+            let name = self.name.as_ref().map(|name| format!("{name} {axis}"));
+
+            operation.custom(
+                &mut a11y::slider_node(
+                    name.as_deref(),
+                    self.description.as_deref(),
+                    normal,
+                    self.announced_value
+                        .as_ref()
+                        .map(|format| format(normal))
+                        .as_deref(),
+                    None,
+                    Some(orientation),
+                    layout.bounds(),
+                ),
+                self.id.as_ref(),
+            );
+        }
+    }
+
     fn on_event(
         &mut self,
         state: &mut Tree,
@@ -242,42 +797,61 @@ where
         _viewport: &Rectangle,
     ) -> event::Status {
         let state = state.state.downcast_mut::<State>();
+        let cursor = input::event_cursor(&event, cursor);
+        let pointer = input::Pointer::of(&event);
 
         let is_over = cursor.is_over(layout.bounds());
+        let is_claimed = input::is_press_claimed(&event, &state.press_claim);
+
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            state.is_focused = is_over && !is_claimed;
+            state.focus_visible = false;
+        }
 
         match event {
+            Event::Window(window::Event::RedrawRequested(now))
+                if state.wheel_gesture.redraw(now, shell) =>
+            {
+                self.maybe_fire_on_release(shell);
+            }
             Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if state.dragging_status.is_some() {
-                    let bounds_size = {
-                        if layout.bounds().width <= layout.bounds().height {
-                            layout.bounds().width
-                        } else {
-                            layout.bounds().height
-                        }
-                    };
-                    if bounds_size != 0.0 {
-                        let mut movement_x = (position.x - state.prev_drag_x) / bounds_size;
-
-                        let mut movement_y = (position.y - state.prev_drag_y) / bounds_size;
-
-                        if state.pressed_modifiers.contains(self.modifier_keys) {
-                            movement_x *= self.modifier_scalar;
-                            movement_y *= self.modifier_scalar;
-                        }
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.dragging_status.is_some() && state.drag_pointer == pointer =>
+            {
+                let bounds_size = {
+                    if layout.bounds().width <= layout.bounds().height {
+                        layout.bounds().width
+                    } else {
+                        layout.bounds().height
+                    }
+                };
+                if bounds_size != 0.0 {
+                    let mut movement_x = (position.x - state.prev_drag_x) / bounds_size;
+
+                    let mut movement_y = (position.y - state.prev_drag_y) / bounds_size;
+
+                    if self
+                        .modifier_match
+                        .matches(state.pressed_modifiers, self.modifier_keys)
+                    {
+                        movement_x *= self.modifier_scalar;
+                        movement_y *= self.modifier_scalar;
+                    }
 
-                        let normal_x = state.continuous_normal_x + movement_x;
-                        let normal_y = state.continuous_normal_y - movement_y;
+                    let normal_x = state.continuous_normal_x + movement_x;
+                    let normal_y = state.continuous_normal_y - movement_y;
 
-                        state.prev_drag_x = position.x;
-                        state.prev_drag_y = position.y;
+                    state.prev_drag_x = position.x;
+                    state.prev_drag_y = position.y;
 
-                        state.continuous_normal_x = normal_x;
-                        self.normal_param_x.value.set_clipped(normal_x);
-
-                        state.continuous_normal_y = normal_y;
-                        self.normal_param_y.value.set_clipped(normal_y);
+                    state.continuous_normal_x = normal_x;
+                    state.continuous_normal_y = normal_y;
 
+                    if self.move_to_continuous(state) {
                         self.fire_on_change(shell);
 
                         state
@@ -285,84 +859,145 @@ where
                             .as_mut()
                             .expect("dragging_status taken")
                             .moved();
-
-                        return event::Status::Captured;
                     }
+
+                    return event::Status::Captured;
                 }
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if is_over {
-                    let cursor_position = cursor.position().unwrap();
-
-                    let click =
-                        mouse::Click::new(cursor_position, mouse::Button::Left, state.last_click);
-
-                    match click.kind() {
-                        mouse::click::Kind::Single => {
-                            self.maybe_fire_on_grab(shell);
-
-                            state.dragging_status = Some(Default::default());
-                            state.prev_drag_x = cursor_position.x;
-                            state.prev_drag_y = cursor_position.y;
-                            state.continuous_normal_x = self.normal_param_x.value.as_f32();
-                            state.continuous_normal_y = self.normal_param_y.value.as_f32();
-
-                            let bounds_size = {
-                                if layout.bounds().width <= layout.bounds().height {
-                                    layout.bounds().width
-                                } else {
-                                    layout.bounds().height
-                                }
-                            };
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_over
+                    && (state.drag_pointer.is_none() || state.drag_pointer == pointer)
+                    && !is_claimed =>
+            {
+                input::claim_press(&event, &state.press_claim);
+
+                // A press ends the gesture of the wheel before its own.
+                if state.wheel_gesture.end() {
+                    self.maybe_fire_on_release(shell);
+                }
 
-                            let normal_x = (cursor_position.x - layout.bounds().x) / bounds_size;
+                let cursor_position = cursor.position().unwrap();
 
-                            let normal_y =
-                                1.0 - ((cursor_position.y - layout.bounds().y) / bounds_size);
+                let click = input::Click::new(
+                    &event,
+                    cursor_position,
+                    mouse::Button::Left,
+                    state.last_click,
+                    &self.double_click,
+                );
 
-                            state.continuous_normal_x = normal_x;
-                            self.normal_param_x.value.set_clipped(normal_x);
+                match click.kind() {
+                    mouse::click::Kind::Single if !self.jump_to_press => {
+                        let handle = handle_center(
+                            layout.bounds(),
+                            self.normal_param_x.value,
+                            self.normal_param_y.value,
+                        );
+
+                        // Only a press on the handle grabs it, and the
+                        // values stay where they are until it moves.
+                        if handle.distance(cursor_position) <= self.grab_radius {
+                            self.grab(state, pointer, cursor_position, shell);
+                        }
+                    }
+                    mouse::click::Kind::Single => {
+                        self.grab(state, pointer, cursor_position, shell);
+
+                        let bounds_size = {
+                            if layout.bounds().width <= layout.bounds().height {
+                                layout.bounds().width
+                            } else {
+                                layout.bounds().height
+                            }
+                        };
+
+                        let normal_x = (cursor_position.x - layout.bounds().x) / bounds_size;
 
-                            state.continuous_normal_y = normal_y;
-                            self.normal_param_y.value.set_clipped(normal_y);
+                        let normal_y =
+                            1.0 - ((cursor_position.y - layout.bounds().y) / bounds_size);
 
-                            shell.publish((self.on_change)(
-                                self.normal_param_x.value,
-                                self.normal_param_y.value,
-                            ));
+                        state.continuous_normal_x = normal_x;
+                        state.continuous_normal_y = normal_y;
+
+                        if self.move_to_continuous(state) {
+                            self.fire_on_change(shell);
                         }
-                        _ => {
-                            // Reset to default
+                    }
+                    _ => {
+                        // Reset to default
 
-                            let prev_dragging_status = state.dragging_status.take();
+                        let prev_dragging_status = state.dragging_status.take();
+                        state.drag_pointer = None;
 
-                            if (self.normal_param_x.value != self.normal_param_x.default)
-                                && (self.normal_param_y.value != self.normal_param_y.default)
-                            {
-                                self.normal_param_x.value = self.normal_param_x.default;
-                                state.continuous_normal_x = self.normal_param_x.default.as_f32();
+                        if (self.normal_param_x.value != self.normal_param_x.default)
+                            && (self.normal_param_y.value != self.normal_param_y.default)
+                        {
+                            self.normal_param_x.value = self.normal_param_x.default;
+                            state.continuous_normal_x = self.normal_param_x.default.as_f32();
 
-                                self.normal_param_y.value = self.normal_param_y.default;
-                                state.continuous_normal_y = self.normal_param_y.default.as_f32();
+                            self.normal_param_y.value = self.normal_param_y.default;
+                            state.continuous_normal_y = self.normal_param_y.default.as_f32();
 
-                                self.fire_on_change(shell);
+                            self.fire_on_change(shell);
 
-                                self.maybe_fire_on_release(shell);
-                            } else if prev_dragging_status.is_some() {
+                            self.maybe_fire_on_release(shell);
+                        } else if prev_dragging_status.is_some() {
+                            self.maybe_fire_on_release(shell);
+                        }
+                    }
+                }
+
+                state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_over && state.drag_pointer.is_some() && state.drag_pointer != pointer =>
+            {
+                // Another pointer drags the pad, so the press must not reach
+                // what is underneath it either.
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { .. }) if state.dragging_status.is_some() => {
+                // A scrollable parent must not move the pad away from under
+                // the pointer that drags it.
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_over && self.capture_wheel =>
+            {
+                if let Some(moved) = self.scroll_by(state, delta) {
+                    if moved {
+                        // Grouped wheel steps are released once the wheel
+                        // is idle.
+                        match self.wheel_gesture {
+                            Some(timeout) => {
+                                if state.wheel_gesture.step(timeout, shell) {
+                                    self.maybe_fire_on_grab(shell);
+                                }
+
+                                self.fire_on_change(shell);
+                            }
+                            None => {
+                                self.maybe_fire_on_grab(shell);
+                                self.fire_on_change(shell);
                                 self.maybe_fire_on_release(shell);
                             }
                         }
                     }
 
-                    state.last_click = Some(click);
-
                     return event::Status::Captured;
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag_pointer == pointer =>
+            {
+                state.drag_pointer = None;
+
                 if let Some(slider_status) = state.dragging_status.take() {
                     if self.on_grab.is_some() || slider_status.was_moved() {
                         // maybe fire on release if `on_grab` is defined
@@ -376,23 +1011,11 @@ where
                     return event::Status::Captured;
                 }
             }
-            Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::KeyReleased { modifiers, .. } => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-                keyboard::Event::ModifiersChanged(modifiers) => {
-                    state.pressed_modifiers = modifiers;
-
-                    return event::Status::Captured;
-                }
-            },
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                // Only observe the modifiers, so key events still reach
+                // other widgets such as text inputs.
+                state.pressed_modifiers = modifiers;
+            }
             _ => {}
         }
 
@@ -413,14 +1036,18 @@ where
         let bounds = layout.bounds();
         let is_over = cursor.is_over(layout.bounds());
 
-        let appearance = if state.dragging_status.is_some() {
-            theme.dragging(&self.style)
+        let status = if state.dragging_status.is_some() {
+            Status::Dragged
         } else if is_over {
-            theme.hovered(&self.style)
+            Status::Hovered
+        } else if state.is_focused {
+            Status::Focused
         } else {
-            theme.active(&self.style)
+            Status::Active
         };
 
+        let appearance = theme.style(&self.class, status).dim(self.opacity);
+
         let bounds_x = bounds.x.floor();
         let bounds_y = bounds.y.floor();
 
@@ -583,16 +1210,189 @@ where
                     square.color,
                 );
             }
+            HandleShape::Texture(texture) => {
+                renderer.draw_image(
+                    image::Image::from(texture.image_handle.select(self.scale_factor))
+                        .opacity(self.opacity),
+                    Rectangle {
+                        x: (handle_x + texture.image_bounds.x).round(),
+                        y: (handle_y + texture.image_bounds.y).round(),
+                        width: texture.image_bounds.width,
+                        height: texture.image_bounds.height,
+                    },
+                );
+            }
+        }
+
+        if state.is_focused && state.focus_visible {
+            if let Some(focus_ring) = theme.focus_ring_appearance(&self.class) {
+                focus_ring::draw(
+                    renderer,
+                    Rectangle {
+                        x: bounds_x,
+                        y: bounds_y,
+                        width: bounds_size,
+                        height: bounds_size,
+                    },
+                    &focus_ring.dim(self.opacity),
+                    false,
+                );
+            }
         }
     }
 }
 
-impl<'a, Message, Theme> From<XYPad<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+impl<'a, Message, Theme, Renderer> From<XYPad<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
-    Theme: 'a + StyleSheet,
+    Theme: 'a + Catalog,
+    Renderer: 'a + image::Renderer<Handle = image::Handle>,
 {
     fn from(xy_pad: XYPad<'a, Message, Theme>) -> Self {
         Self::new(xy_pad)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::fixtures::{assert_close, changed, key_events, param, wheel, Message};
+    use crate::test_utils::Harness;
+    use crate::{Normal, XYPad};
+    use iced::{event, keyboard, Point, Size, Theme};
+
+    #[test]
+    fn xy_pad_drag() {
+        let xy_pad: XYPad<'_, _, Theme> = XYPad::new(param(0.5, 0.5), param(0.5, 0.5), |x, y| {
+            Message::Changed(Normal::from_clipped(x.as_f32() + y.as_f32()))
+        });
+        let mut harness = Harness::new(xy_pad, Size::new(100.0, 100.0));
+
+        harness.drag(Point::new(50.0, 50.0), Point::new(100.0, 0.0));
+
+        assert_eq!(changed(&harness.take_messages()).last(), Some(&1.0));
+    }
+
+    #[test]
+    fn xy_pad_grabs_only_the_handle() {
+        let xy_pad: XYPad<'_, _, Theme> =
+            XYPad::new(param(0.5, 0.5), param(0.5, 0.5), Message::ChangedPair)
+                .jump_to_press(false)
+                .grab_radius(5.0)
+                .on_grab(|| Some(Message::Grabbed));
+        let mut harness = Harness::new(xy_pad, Size::new(100.0, 100.0));
+
+        // A click away from the handle leaves it where it is...
+        harness.drag(Point::new(20.0, 20.0), Point::new(30.0, 30.0));
+        assert!(harness.take_messages().is_empty());
+
+        // ...and a press on it drags it from where it is.
+        let _ = harness.move_cursor(Point::new(53.0, 50.0));
+        let _ = harness.press();
+        assert_eq!(harness.take_messages(), vec![Message::Grabbed]);
+
+        let _ = harness.move_cursor(Point::new(63.0, 40.0));
+        let moved = Normal::from_clipped(0.6);
+        assert_eq!(
+            harness.take_messages(),
+            vec![Message::ChangedPair(moved, moved)]
+        );
+    }
+
+    #[test]
+    fn xy_pad_wheel() {
+        let xy_pad: XYPad<'_, _, Theme> =
+            XYPad::new(param(0.5, 0.5), param(0.5, 0.5), Message::ChangedPair)
+                .wheel_scalar_y(0.02)
+                .deadzone_x(0.015);
+        let mut harness = Harness::new(xy_pad, Size::new(100.0, 100.0));
+        let _ = harness.move_cursor(Point::new(50.0, 50.0));
+
+        let mut scroll = |x, y| {
+            let status = harness.event(wheel(x, y));
+
+            match harness.take_messages()[..] {
+                [Message::ChangedPair(x, y)] => Some((x.as_f32(), y.as_f32())),
+                [] => {
+                    assert_eq!(status, event::Status::Captured);
+                    None
+                }
+                ref messages => panic!("unexpected messages: {messages:?}"),
+            }
+        };
+
+        // A diagonal swipe moves both axes with their own scalars, where
+        // scrolling to the right moves the x axis up.
+        let (x, y) = scroll(-20.0, 20.0).unwrap();
+        assert_eq!(x, 0.5);
+        assert_close(y, 0.52);
+
+        // Steps within the deadzone add up until they leave it.
+        let (x, _) = scroll(-40.0, 0.0).unwrap();
+        assert_close(x, 0.53);
+        assert_eq!(scroll(0.0, -10.0), None);
+    }
+
+    #[test]
+    fn xy_pad_epsilon_and_deadzone() {
+        let xy_pad: XYPad<'_, _, Theme> =
+            XYPad::new(param(0.5, 0.5), param(0.5, 0.5), Message::ChangedPair)
+                .epsilon(0.05)
+                .deadzone_y(0.1);
+        let mut harness = Harness::new(xy_pad, Size::new(100.0, 100.0));
+
+        // Pressing where the values already are changes nothing.
+        let _ = harness.move_cursor(Point::new(50.0, 50.0));
+        let _ = harness.press();
+        assert_eq!(harness.take_messages().len(), 0);
+
+        let mut move_to = |x, y| {
+            let _ = harness.move_cursor(Point::new(x, y));
+
+            let pairs: Vec<_> = harness
+                .take_messages()
+                .into_iter()
+                .filter_map(|message| match message {
+                    Message::ChangedPair(x, y) => Some((x.as_f32(), y.as_f32())),
+                    _ => None,
+                })
+                .collect();
+
+            match pairs[..] {
+                [] => None,
+                [pair] => Some(pair),
+                _ => panic!("more than one change: {pairs:?}"),
+            }
+        };
+        let assert_moved_to = |moved: Option<(f32, f32)>, (x, y)| {
+            let (moved_x, moved_y) = moved.expect("no change");
+            assert_close(moved_x, x);
+            assert_close(moved_y, y);
+        };
+
+        // Small movements are left out until they add up.
+        assert_eq!(move_to(53.0, 50.0), None);
+        assert_moved_to(move_to(56.0, 50.0), (0.56, 0.5));
+
+        // The y axis snaps to its default inside of the deadzone.
+        assert_eq!(move_to(56.0, 42.0), None);
+        assert_moved_to(move_to(56.0, 38.0), (0.56, 0.62));
+        assert_moved_to(move_to(56.0, 45.0), (0.56, 0.5));
+    }
+
+    #[test]
+    fn xy_pad_leaves_key_events() {
+        let xy_pad: XYPad<'_, _, Theme> =
+            XYPad::new(param(0.5, 0.5), param(0.5, 0.5), |_, _| Message::Grabbed);
+        let mut harness = Harness::new(xy_pad, Size::new(100.0, 100.0));
+        let _ = harness.move_cursor(Point::new(50.0, 50.0));
+
+        for event in key_events(keyboard::Modifiers::CTRL) {
+            assert_eq!(harness.event(event), event::Status::Ignored);
+        }
+        assert_eq!(
+            harness.modifiers(keyboard::Modifiers::CTRL),
+            event::Status::Ignored
+        );
+    }
+}